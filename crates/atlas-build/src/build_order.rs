@@ -97,10 +97,14 @@ impl BuildGraph {
         let mut queue = VecDeque::new();
         let mut result = Vec::new();
 
+        // Visit modules by name so the order does not depend on hash map iteration
+        let mut names: Vec<&String> = self.modules.keys().collect();
+        names.sort();
+
         // Start with modules that have no dependencies (in-degree = 0)
-        for (module_name, degree) in &in_degree {
-            if *degree == 0 {
-                queue.push_back(module_name.clone());
+        for module_name in &names {
+            if in_degree[*module_name] == 0 {
+                queue.push_back((*module_name).clone());
             }
         }
 
@@ -108,12 +112,13 @@ impl BuildGraph {
             result.push(module_name.clone());
 
             // For each module that depends on the current module
-            for (dependent, module) in &self.modules {
+            for dependent in &names {
+                let module = &self.modules[*dependent];
                 if module.dependencies.contains(&module_name) {
-                    if let Some(degree) = in_degree.get_mut(dependent) {
+                    if let Some(degree) = in_degree.get_mut(*dependent) {
                         *degree -= 1;
                         if *degree == 0 {
-                            queue.push_back(dependent.clone());
+                            queue.push_back((*dependent).clone());
                         }
                     }
                 }
@@ -461,4 +466,22 @@ mod tests {
         assert!(order.contains(&"b".to_string()));
         assert!(order.contains(&"c".to_string()));
     }
+
+    #[test]
+    fn test_build_order_of_independent_modules_is_sorted() {
+        let mut graph = BuildGraph::new();
+        for name in ["zeta", "alpha", "mid", "beta"] {
+            graph.add_module(ModuleNode::new(
+                name,
+                PathBuf::from(format!("{}.atlas", name)),
+            ));
+        }
+        graph.add_module(
+            ModuleNode::new("main", PathBuf::from("main.atlas"))
+                .with_dependencies(vec!["zeta".to_string(), "alpha".to_string()]),
+        );
+
+        let order = graph.compute_build_order().unwrap();
+        assert_eq!(order, vec!["alpha", "beta", "mid", "zeta", "main"]);
+    }
 }
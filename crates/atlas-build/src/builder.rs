@@ -2,24 +2,28 @@
 use crate::build_order::{BuildGraph, ModuleNode};
 use crate::cache::BuildCache;
 use crate::error::{BuildError, BuildResult};
-use crate::fingerprint::FingerprintConfig;
+use crate::fingerprint::{compute_fingerprint, compute_hash, Fingerprint, FingerprintConfig};
 use crate::incremental::{IncrementalEngine, IncrementalStats};
 use crate::module_resolver::ModuleResolver;
-use crate::output::OutputMode;
+use crate::output::{BuildEvent, BuildSummary, EventStream, OutputMode};
+use crate::plan::{BuildPlan, PlanTarget, PlanUnit};
 use crate::profile::{Profile, ProfileManager};
-use crate::script::{BuildScript, ScriptContext, ScriptExecutor, ScriptPhase};
+use crate::script::{
+    BuildScript, ScriptContext, ScriptDirective, ScriptExecutor, ScriptPhase, ScriptResult,
+};
 use crate::targets::{ArtifactMetadata, BuildArtifact, BuildTarget, TargetKind};
 
 use atlas_package::manifest::PackageManifest;
+use atlas_runtime::ast::{ExportDecl, ExportItem, Item, Program};
 use atlas_runtime::module_loader::ModuleRegistry;
 use atlas_runtime::{
     Binder, Bytecode, Compiler, Diagnostic, Lexer, Parser, SymbolTable, TypeChecker,
 };
 
-// Note: Parallel compilation disabled for now due to Bytecode containing non-Send types (Rc<>)
-// use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
 use walkdir::WalkDir;
 
@@ -59,8 +63,16 @@ pub struct BuildConfig {
     pub optimization_level: OptLevel,
     /// Enable parallel compilation
     pub parallel: bool,
+    /// Maximum number of modules compiled concurrently
+    pub jobs: usize,
     /// Verbose output
     pub verbose: bool,
+    /// Output target kind override (e.g. `--target wasm`)
+    pub target: Option<TargetKind>,
+    /// Environment variables visible to compilation (part of the fingerprint)
+    pub env_vars: BTreeMap<String, String>,
+    /// Content-addressed cache shared between projects (incremental builds)
+    pub shared_cache: Option<PathBuf>,
 }
 
 impl Default for BuildConfig {
@@ -69,7 +81,11 @@ impl Default for BuildConfig {
             target_dir: PathBuf::from("target/debug"),
             optimization_level: OptLevel::O0,
             parallel: true,
+            jobs: default_jobs(),
             verbose: false,
+            target: None,
+            env_vars: BTreeMap::new(),
+            shared_cache: None,
         }
     }
 }
@@ -83,6 +99,10 @@ pub struct BuildContext {
     pub stats: BuildStats,
     /// Build artifacts produced
     pub artifacts: Vec<BuildArtifact>,
+    /// Incremental statistics (set by incremental builds)
+    pub incremental: Option<IncrementalStats>,
+    /// Directives emitted by build scripts (set by `build_with_profile`)
+    pub script_directives: Vec<ScriptDirective>,
 }
 
 /// Build statistics
@@ -100,6 +120,26 @@ pub struct BuildStats {
     pub compilation_time: Duration,
     /// Time spent linking
     pub linking_time: Duration,
+    /// Most modules compiling at the same time
+    pub peak_parallelism: usize,
+    /// When each module compiled, relative to the start of compilation
+    pub module_timings: BTreeMap<String, ModuleTiming>,
+}
+
+/// Compilation timing for a single module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleTiming {
+    /// Offset from the start of compilation
+    pub start: Duration,
+    /// Time spent compiling
+    pub duration: Duration,
+}
+
+impl ModuleTiming {
+    /// Offset from the start of compilation at which the module finished
+    pub fn end(&self) -> Duration {
+        self.start + self.duration
+    }
 }
 
 impl BuildStats {
@@ -112,6 +152,8 @@ impl BuildStats {
             total_time: Duration::ZERO,
             compilation_time: Duration::ZERO,
             linking_time: Duration::ZERO,
+            peak_parallelism: 0,
+            module_timings: BTreeMap::new(),
         }
     }
 }
@@ -129,9 +171,16 @@ struct CompiledModule {
     name: String,
     path: PathBuf,
     bytecode: Bytecode,
+    /// When compilation of this module started
+    started: Instant,
     compile_time: Duration,
+    /// Top-level functions whose code was generated
+    functions_compiled: usize,
 }
 
+/// Outcome of compiling one module on a worker
+type CompileOutcome = BuildResult<(CompiledModule, SymbolTable)>;
+
 /// Main builder for orchestrating builds
 pub struct Builder {
     /// Project root directory
@@ -140,6 +189,8 @@ pub struct Builder {
     manifest: PackageManifest,
     /// Build configuration
     config: BuildConfig,
+    /// Sink for JSON progress events, if streaming
+    events: Option<EventStream>,
 }
 
 impl Builder {
@@ -156,9 +207,15 @@ impl Builder {
             root_dir,
             manifest,
             config: BuildConfig::default(),
+            events: None,
         })
     }
 
+    /// Get the build configuration
+    pub fn config(&self) -> &BuildConfig {
+        &self.config
+    }
+
     /// Set build configuration
     pub fn with_config(mut self, config: BuildConfig) -> Self {
         self.config = config;
@@ -183,14 +240,50 @@ impl Builder {
         self
     }
 
+    /// Set the maximum number of modules compiled concurrently
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.config.jobs = jobs;
+        self
+    }
+
     /// Enable/disable verbose output
     pub fn with_verbose(mut self, verbose: bool) -> Self {
         self.config.verbose = verbose;
         self
     }
 
+    /// Set the output target kind
+    pub fn with_target(mut self, target: TargetKind) -> Self {
+        self.config.target = Some(target);
+        self
+    }
+
+    /// Use a content-addressed artifact cache shared with other projects
+    pub fn with_shared_cache(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.config.shared_cache = Some(cache_dir.into());
+        self
+    }
+
+    /// Set an environment variable visible to compilation
+    pub fn with_env_var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.env_vars.insert(key.into(), value.into());
+        self
+    }
+
+    /// Stream build progress as JSON events to `events`
+    pub fn with_event_stream(mut self, events: EventStream) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Execute the build
     pub fn build(&mut self) -> BuildResult<BuildContext> {
+        let result = self.run_build();
+        self.finish_events(&result);
+        result
+    }
+
+    fn run_build(&mut self) -> BuildResult<BuildContext> {
         let build_start = Instant::now();
 
         if self.config.verbose {
@@ -231,7 +324,12 @@ impl Builder {
 
         // Compile modules
         let compile_start = Instant::now();
-        let compiled_modules = self.compile_modules(&graph, &build_order)?;
+        let jobs = if self.config.parallel {
+            self.config.jobs.max(1)
+        } else {
+            1
+        };
+        let (compiled_modules, peak_parallelism) = self.compile_modules(&graph, jobs)?;
         let compilation_time = compile_start.elapsed();
 
         if self.config.verbose {
@@ -260,6 +358,8 @@ impl Builder {
             total_time,
             compilation_time,
             linking_time,
+            peak_parallelism,
+            module_timings: module_timings(&compiled_modules, compile_start),
         };
 
         if self.config.verbose {
@@ -270,11 +370,19 @@ impl Builder {
             manifest: self.manifest.clone(),
             stats,
             artifacts,
+            incremental: None,
+            script_directives: Vec::new(),
         })
     }
 
     /// Build with incremental compilation (recompile only changed modules)
     pub fn build_incremental(&mut self) -> BuildResult<BuildContext> {
+        let result = self.run_incremental();
+        self.finish_events(&result);
+        result
+    }
+
+    fn run_incremental(&mut self) -> BuildResult<BuildContext> {
         let build_start = Instant::now();
 
         if self.config.verbose {
@@ -298,12 +406,7 @@ impl Builder {
         graph.validate()?;
 
         // Initialize incremental engine
-        let state_dir = self.config.target_dir.join("incremental");
-        let fp_config = FingerprintConfig {
-            optimization: format!("{:?}", self.config.optimization_level),
-            ..Default::default()
-        };
-        let mut engine = IncrementalEngine::new(state_dir, fp_config);
+        let mut engine = self.incremental_engine();
 
         // Analyze what needs recompilation
         let analysis_start = Instant::now();
@@ -325,10 +428,18 @@ impl Builder {
         // Load build cache for artifact caching
         let cache_dir = self.config.target_dir.join("cache");
         let mut cache = BuildCache::load(&cache_dir)?;
+        let mut shared_cache = self
+            .config
+            .shared_cache
+            .as_ref()
+            .map(BuildCache::shared)
+            .transpose()?;
 
         // Compile modules in topological order with cross-module resolution
         let compile_start = Instant::now();
         let mut compiled_modules = Vec::new();
+        let mut functions_recompiled = 0;
+        let mut shared_cache_hits = 0;
         let mut resolver = ModuleResolver::new();
         let recompile_set: std::collections::HashSet<String> =
             plan.recompile.iter().cloned().collect();
@@ -337,17 +448,50 @@ impl Builder {
         for module_name in &topo_order {
             let node = graph.get_module(module_name).unwrap();
             let registry = resolver.build_registry_for(&node.dependencies);
+            self.emit(BuildEvent::compile_started(module_name));
 
             if recompile_set.contains(module_name) {
-                // Recompile this module
-                let compiled = self.compile_single_module(module_name, &node.path, &registry)?;
-
                 // Read source for fingerprint + cache
                 let source =
                     fs::read_to_string(&node.path).map_err(|e| BuildError::io(&node.path, e))?;
+                let dep_hashes = engine.dependency_hashes(&node.dependencies);
+
+                // Recompile only the changed functions when the cached bytecode allows it
+                let cached = plan
+                    .changed_functions
+                    .get(module_name)
+                    .and_then(|functions| {
+                        let bytes = cache.peek(module_name)?;
+                        let bytecode = Bytecode::from_bytes(bytes).ok()?;
+                        Some((bytecode, functions))
+                    });
+                let compiled = match (cached, shared_cache.as_mut()) {
+                    (Some((bytecode, functions)), _) => self.recompile_functions(
+                        module_name,
+                        &node.path,
+                        &registry,
+                        bytecode,
+                        functions,
+                    )?,
+                    (None, Some(shared)) => {
+                        let fingerprint = compute_fingerprint(
+                            &node.path,
+                            &source,
+                            dep_hashes.clone(),
+                            engine.config(),
+                        );
+                        let (compiled, hit) =
+                            self.compile_shared(shared, &fingerprint, node, &registry)?;
+                        shared_cache_hits += usize::from(hit);
+                        compiled
+                    }
+                    (None, None) => {
+                        self.compile_single_module(module_name, &node.path, &registry)?
+                    }
+                };
+                functions_recompiled += compiled.functions_compiled;
 
                 // Record in incremental engine
-                let dep_hashes = self.gather_dependency_hashes(&node.dependencies, &engine);
                 engine.record_compilation(module_name, &node.path, &source, dep_hashes);
 
                 // Store in build cache
@@ -369,6 +513,12 @@ impl Builder {
                 let compiled = self.compile_single_module(module_name, &node.path, &registry)?;
                 compiled_modules.push(compiled);
             }
+            if let Some(compiled) = compiled_modules.last() {
+                self.emit(BuildEvent::compile_finished(
+                    module_name,
+                    compiled.compile_time,
+                ));
+            }
 
             // Register this module's exports for downstream dependents
             let symbol_table = self.extract_symbol_table(module_name, &node.path, &registry)?;
@@ -381,6 +531,9 @@ impl Builder {
         engine.update_state(&graph);
         engine.save()?;
         cache.save()?;
+        if let Some(shared) = &shared_cache {
+            shared.save()?;
+        }
 
         // Create build targets
         let targets = self.create_build_targets(&source_files)?;
@@ -400,18 +553,23 @@ impl Builder {
             total_time,
             compilation_time,
             linking_time,
+            peak_parallelism: 1,
+            module_timings: module_timings(&compiled_modules, compile_start),
+        };
+
+        let inc_stats = IncrementalStats {
+            total_modules: graph.len(),
+            recompiled: recompile_set.len(),
+            from_cache: graph.len() - recompile_set.len(),
+            functions_recompiled,
+            shared_cache_hits,
+            analysis_time,
+            compilation_time,
+            time_saved: Duration::ZERO, // Would need baseline to compute
+            was_full_rebuild: recompile_set.len() == graph.len(),
         };
 
         if self.config.verbose {
-            let inc_stats = IncrementalStats {
-                total_modules: graph.len(),
-                recompiled: recompile_set.len(),
-                from_cache: graph.len() - recompile_set.len(),
-                analysis_time,
-                compilation_time,
-                time_saved: Duration::ZERO, // Would need baseline to compute
-                was_full_rebuild: recompile_set.len() == graph.len(),
-            };
             println!("{}", inc_stats.summary());
         }
 
@@ -419,24 +577,117 @@ impl Builder {
             manifest: self.manifest.clone(),
             stats,
             artifacts,
+            incremental: Some(inc_stats),
+            script_directives: Vec::new(),
         })
     }
 
-    /// Gather dependency hashes for fingerprinting
-    fn gather_dependency_hashes(
-        &self,
-        dependencies: &[String],
-        engine: &IncrementalEngine,
-    ) -> std::collections::BTreeMap<String, String> {
-        dependencies
-            .iter()
-            .filter_map(|dep| {
-                engine
-                    .fingerprint_db()
-                    .get(dep)
-                    .map(|fp| (dep.clone(), fp.hash.clone()))
+    /// Compute what an incremental build would do, without compiling anything
+    ///
+    /// Units are listed in compilation order; each is a cache hit unless the
+    /// incremental engine found a reason to rebuild it.
+    pub fn compute_plan(&self) -> BuildResult<BuildPlan> {
+        let source_files = self.discover_source_files()?;
+        if source_files.is_empty() {
+            return Err(BuildError::BuildFailed(
+                "No source files found in src/ directory".to_string(),
+            ));
+        }
+
+        let graph = self.build_dependency_graph(&source_files)?;
+        graph.validate()?;
+        let targets = self.create_build_targets(&source_files)?;
+        let mut incremental = self.incremental_engine().plan(&graph)?;
+
+        let units = graph
+            .compute_build_order()?
+            .into_iter()
+            .map(|name| {
+                let node = &graph.modules()[&name];
+                let reason = incremental.reasons.remove(&name);
+                PlanUnit {
+                    path: node.path.clone(),
+                    dependencies: node.dependencies.clone(),
+                    targets: targets
+                        .iter()
+                        .filter(|target| target.sources.contains(&node.path))
+                        .map(|target| target.kind)
+                        .collect(),
+                    cache_hit: reason.is_none(),
+                    reason,
+                    changed_functions: incremental
+                        .changed_functions
+                        .remove(&name)
+                        .unwrap_or_default(),
+                    module: name,
+                }
             })
-            .collect()
+            .collect();
+
+        Ok(BuildPlan {
+            package: self.manifest.package.name.clone(),
+            targets: targets
+                .into_iter()
+                .map(|target| PlanTarget {
+                    name: target.name,
+                    kind: target.kind,
+                    entry_point: target.entry_point,
+                })
+                .collect(),
+            units,
+        })
+    }
+
+    /// Incremental engine over this build's state directory and configuration
+    fn incremental_engine(&self) -> IncrementalEngine {
+        let state_dir = self.config.target_dir.join("incremental");
+        let fp_config = FingerprintConfig {
+            optimization: format!("{:?}", self.config.optimization_level),
+            env_vars: self.config.env_vars.clone(),
+            ..Default::default()
+        };
+        IncrementalEngine::new(state_dir, fp_config)
+    }
+
+    /// Load a module from the shared cache by fingerprint, or compile and store it
+    ///
+    /// Returns the module and whether it came from the shared cache.
+    fn compile_shared(
+        &self,
+        shared: &mut BuildCache,
+        fingerprint: &Fingerprint,
+        node: &ModuleNode,
+        registry: &ModuleRegistry,
+    ) -> BuildResult<(CompiledModule, bool)> {
+        let started = Instant::now();
+        let cached = shared
+            .get_shared(fingerprint, &node.path)
+            .and_then(|bytes| Bytecode::from_bytes(&bytes).ok());
+
+        if let Some(bytecode) = cached {
+            if self.config.verbose {
+                println!("  Shared cache hit: {}", node.name);
+            }
+            let module = CompiledModule {
+                name: node.name.clone(),
+                path: node.path.clone(),
+                bytecode,
+                started,
+                compile_time: started.elapsed(),
+                functions_compiled: 0,
+            };
+            return Ok((module, true));
+        }
+
+        let compiled = self.compile_single_module(&node.name, &node.path, registry)?;
+        shared.store_shared(
+            fingerprint,
+            node.path.clone(),
+            serialize_bytecode(&compiled.bytecode)?,
+            node.dependencies.clone(),
+            compiled.compile_time,
+        )?;
+        Ok((compiled, false))
     }
 
     /// Compile a single module (standalone, no cross-module resolution).
@@ -513,16 +764,19 @@ impl Builder {
                 ));
             }
 
-            // Extract dependencies from imports
+            // Extract dependencies from imports and re-exports
             let dependencies = program
                 .items
                 .iter()
-                .filter_map(|item| {
-                    if let atlas_runtime::ast::Item::Import(import_decl) = item {
+                .filter_map(|item| match item {
+                    atlas_runtime::ast::Item::Import(import_decl) => {
                         Some(import_decl.source.clone())
-                    } else {
-                        None
                     }
+                    atlas_runtime::ast::Item::Export(atlas_runtime::ast::ExportDecl {
+                        item: atlas_runtime::ast::ExportItem::ReExport(re_export),
+                        ..
+                    }) => Some(re_export.source.clone()),
+                    _ => None,
                 })
                 .collect();
 
@@ -535,49 +789,153 @@ impl Builder {
         Ok(graph)
     }
 
-    /// Compile modules in topological order with cross-module symbol resolution.
+    /// Compile modules on a pool of `jobs` workers with cross-module symbol resolution.
     ///
-    /// After each module compiles, its exports are registered so that
-    /// dependent modules can resolve their imports during binding.
+    /// A module is dispatched once all of its dependencies have compiled and
+    /// registered their exports, so independent modules compile concurrently.
+    /// Returns the compiled modules in topological order and the peak number of
+    /// modules in flight.
     fn compile_modules(
         &self,
         graph: &BuildGraph,
-        build_order: &[Vec<String>],
-    ) -> BuildResult<Vec<CompiledModule>> {
-        let mut compiled = Vec::new();
+        jobs: usize,
+    ) -> BuildResult<(Vec<CompiledModule>, usize)> {
+        let order = graph.compute_build_order()?;
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .map_err(|e| BuildError::BuildFailed(format!("Failed to start workers: {}", e)))?;
+
+        // Remaining dependency count per module, and who depends on it
+        let mut remaining: HashMap<&str, usize> = HashMap::new();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+        for name in &order {
+            let node = graph
+                .get_module(name)
+                .ok_or_else(|| BuildError::module_not_found(name))?;
+            remaining.insert(name.as_str(), node.dependencies.len());
+            for dep in &node.dependencies {
+                dependents
+                    .entry(dep.as_str())
+                    .or_default()
+                    .push(name.as_str());
+            }
+        }
+        let mut ready: Vec<&str> = order
+            .iter()
+            .rev()
+            .map(String::as_str)
+            .filter(|name| remaining[name] == 0)
+            .collect();
+
         let mut resolver = ModuleResolver::new();
+        let mut compiled = Vec::with_capacity(order.len());
+        let mut first_error = None;
+        let mut in_flight = 0;
+        let mut peak = 0;
+        let (sender, receiver) = mpsc::channel::<(String, CompileOutcome)>();
+
+        pool.in_place_scope(|scope| loop {
+            // Dispatch ready modules while workers are free
+            while first_error.is_none() && in_flight < jobs {
+                let Some(name) = ready.pop() else { break };
+                let node = &graph.modules()[name];
+                let registry = resolver.build_registry_for(&node.dependencies);
+                let sender = sender.clone();
+                in_flight += 1;
+                peak = peak.max(in_flight);
+                self.emit(BuildEvent::compile_started(name));
+                scope.spawn(move |_| {
+                    let outcome = self
+                        .compile_module_with_imports(name, &node.path, &registry)
+                        .and_then(|module| {
+                            let symbols = self.extract_symbol_table(name, &node.path, &registry)?;
+                            Ok((module, symbols))
+                        });
+                    // The receiver outlives the scope, so this cannot fail
+                    let _ = sender.send((name.to_string(), outcome));
+                });
+            }
 
-        for (group_idx, group) in build_order.iter().enumerate() {
-            if self.config.verbose {
-                println!(
-                    "Compiling group {} ({} modules)",
-                    group_idx + 1,
-                    group.len()
-                );
+            if in_flight == 0 {
+                break;
             }
+            let Ok((name, outcome)) = receiver.recv() else {
+                break;
+            };
+            in_flight -= 1;
+
+            match outcome {
+                Ok((module, symbols)) => {
+                    self.emit(BuildEvent::compile_finished(&name, module.compile_time));
+                    resolver.register_module(name.clone(), module.path.clone(), symbols);
+                    compiled.push(module);
+                    for dependent in dependents.get(name.as_str()).into_iter().flatten() {
+                        let count = remaining.get_mut(dependent).expect("dependent in graph");
+                        *count -= 1;
+                        if *count == 0 {
+                            ready.push(dependent);
+                        }
+                    }
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        });
 
-            // Compile each module in the group, injecting dependency exports
-            for module_name in group {
-                let module = graph
-                    .get_module(module_name)
-                    .ok_or_else(|| BuildError::module_not_found(module_name))?;
+        if let Some(error) = first_error {
+            return Err(error);
+        }
+        if compiled.len() != order.len() {
+            return Err(BuildError::BuildFailed(
+                "Not all modules could be scheduled for compilation".to_string(),
+            ));
+        }
 
-                // Build a registry containing only this module's dependencies
-                let registry = resolver.build_registry_for(&module.dependencies);
+        // Report modules in a deterministic order regardless of completion order
+        let position: HashMap<&str, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+        compiled.sort_by_key(|module| position[module.name.as_str()]);
 
-                let compiled_module =
-                    self.compile_module_with_imports(module_name, &module.path, &registry)?;
+        if self.config.verbose {
+            println!("Compiled with {} workers (peak {} in flight)", jobs, peak);
+        }
 
-                // Register this module's symbol table for downstream dependents
-                let symbol_table =
-                    self.extract_symbol_table(module_name, &module.path, &registry)?;
-                resolver.register_module(module_name.clone(), module.path.clone(), symbol_table);
+        Ok((compiled, peak))
+    }
 
-                compiled.push(compiled_module);
-            }
+    /// Write a progress event, if streaming
+    fn emit(&self, event: BuildEvent) {
+        if let Some(events) = &self.events {
+            events.emit(&event);
         }
+    }
 
-        Ok(compiled)
+    /// Report the outcome of a build: its artifacts and summary, or its error
+    fn finish_events(&self, result: &BuildResult<BuildContext>) {
+        if self.events.is_none() {
+            return;
+        }
+        match result {
+            Ok(context) => {
+                for artifact in &context.artifacts {
+                    self.emit(BuildEvent::artifact(artifact));
+                }
+                let summary = BuildSummary::from_build(&context.stats, &context.artifacts);
+                self.emit(summary.to_event());
+            }
+            Err(error) => {
+                let unit = match error {
+                    BuildError::CompilationError { module, .. } => Some(module.as_str()),
+                    _ => None,
+                };
+                self.emit(BuildEvent::error(unit, error));
+            }
+        }
     }
 
     /// Compile a single module with cross-module import resolution.
@@ -593,6 +951,88 @@ impl Builder {
             println!("  Compiling {}", module_name);
         }
 
+        let program = self.check_module(module_name, source_path, registry)?;
+
+        // Compile to bytecode
+        let bytecode = self
+            .new_compiler()
+            .compile(&program)
+            .map_err(|diagnostics| {
+                BuildError::compilation(module_name, format_diagnostics(&diagnostics))
+            })?;
+
+        let compile_time = compile_start.elapsed();
+
+        Ok(CompiledModule {
+            name: module_name.to_string(),
+            path: source_path.to_path_buf(),
+            bytecode,
+            started: compile_start,
+            compile_time,
+            functions_compiled: top_level_functions(&program).count(),
+        })
+    }
+
+    /// Recompile only the named top-level functions and relink them into `cached`.
+    ///
+    /// The whole module is still checked so errors anywhere are reported; only
+    /// code generation is limited to the changed functions.
+    fn recompile_functions(
+        &self,
+        module_name: &str,
+        source_path: &Path,
+        registry: &ModuleRegistry,
+        mut cached: Bytecode,
+        functions: &[String],
+    ) -> BuildResult<CompiledModule> {
+        let compile_start = Instant::now();
+
+        if self.config.verbose {
+            println!("  Compiling {} ({})", module_name, functions.join(", "));
+        }
+
+        let program = self.check_module(module_name, source_path, registry)?;
+
+        let changed = Program {
+            items: top_level_functions(&program)
+                .filter(|(name, _)| functions.iter().any(|f| f == name))
+                .map(|(_, item)| item.clone())
+                .collect(),
+        };
+        let patch = self
+            .new_compiler()
+            .compile(&changed)
+            .map_err(|diagnostics| {
+                BuildError::compilation(module_name, format_diagnostics(&diagnostics))
+            })?;
+        cached.replace_functions(patch, functions);
+
+        Ok(CompiledModule {
+            name: module_name.to_string(),
+            path: source_path.to_path_buf(),
+            bytecode: cached,
+            started: compile_start,
+            compile_time: compile_start.elapsed(),
+            functions_compiled: changed.items.len(),
+        })
+    }
+
+    /// Create a compiler for the configured optimization level
+    fn new_compiler(&self) -> Compiler {
+        if self.config.optimization_level.should_optimize() {
+            Compiler::with_optimization()
+        } else {
+            Compiler::new()
+        }
+    }
+
+    /// Lex, parse, bind, and type check a module with cross-module import resolution.
+    fn check_module(
+        &self,
+        module_name: &str,
+        source_path: &Path,
+        registry: &ModuleRegistry,
+    ) -> BuildResult<Program> {
         // Read source
         let source = fs::read_to_string(source_path).map_err(|e| BuildError::io(source_path, e))?;
 
@@ -641,25 +1081,7 @@ impl Builder {
             ));
         }
 
-        // Compile to bytecode
-        let mut compiler = if self.config.optimization_level.should_optimize() {
-            Compiler::with_optimization()
-        } else {
-            Compiler::new()
-        };
-
-        let bytecode = compiler.compile(&program).map_err(|diagnostics| {
-            BuildError::compilation(module_name, format_diagnostics(&diagnostics))
-        })?;
-
-        let compile_time = compile_start.elapsed();
-
-        Ok(CompiledModule {
-            name: module_name.to_string(),
-            path: source_path.to_path_buf(),
-            bytecode,
-            compile_time,
-        })
+        Ok(program)
     }
 
     /// Extract a module's symbol table (for registering exports).
@@ -709,6 +1131,20 @@ impl Builder {
             targets.push(target);
         }
 
+        if self.config.target == Some(TargetKind::Wasm) {
+            // WebAssembly replaces the library/binary targets
+            if !has_main {
+                return Err(BuildError::MissingEntryPoint {
+                    target: self.manifest.package.name.clone(),
+                });
+            }
+            let target = BuildTarget::new(self.manifest.package.name.as_str(), TargetKind::Wasm)
+                .with_entry_point("src/main.atlas")
+                .with_sources(source_files.to_vec());
+            target.validate().map_err(BuildError::InvalidTarget)?;
+            return Ok(vec![target]);
+        }
+
         if has_main {
             // Binary target
             let target = BuildTarget::new(self.manifest.package.name.as_str(), TargetKind::Binary)
@@ -756,6 +1192,10 @@ impl Builder {
                 total_compile_time += module.compile_time;
             }
 
+            if target.kind == TargetKind::Wasm {
+                combined_bytecode = lower_to_wasm(compiled_modules, &combined_bytecode)?;
+            }
+
             // Create output directory
             let output_dir = self.config.target_dir.join(target.kind.output_dir_name());
             fs::create_dir_all(&output_dir).map_err(|e| BuildError::io(&output_dir, e))?;
@@ -765,11 +1205,16 @@ impl Builder {
             fs::write(&output_path, &combined_bytecode)
                 .map_err(|e| BuildError::io(&output_path, e))?;
 
-            let metadata = ArtifactMetadata::new(
+            let mut metadata = ArtifactMetadata::new(
                 total_compile_time,
                 compiled_modules.len(),
                 combined_bytecode.len(),
-            );
+            )
+            .with_content_digest(&combined_bytecode)
+            .with_inputs_digest(self.inputs_digest(target, compiled_modules)?);
+            if let Some(triple) = target.kind.target_triple() {
+                metadata = metadata.with_target_triple(triple);
+            }
 
             artifacts.push(BuildArtifact::new(
                 target.clone(),
@@ -782,6 +1227,83 @@ impl Builder {
         Ok(artifacts)
     }
 
+    /// Digest of everything an artifact is built from
+    ///
+    /// Covers the compiler version, optimization level, target and each
+    /// module's name and source in link order. Source paths are reduced to
+    /// module names, so the same project checked out in two directories
+    /// produces the same digest.
+    fn inputs_digest(
+        &self,
+        target: &BuildTarget,
+        compiled_modules: &[CompiledModule],
+    ) -> BuildResult<String> {
+        let mut inputs = format!(
+            "atlas {}\nopt {:?}\ntarget {} {}\n",
+            env!("CARGO_PKG_VERSION"),
+            self.config.optimization_level,
+            target.kind,
+            target.name
+        );
+        for module in compiled_modules {
+            let source =
+                fs::read_to_string(&module.path).map_err(|e| BuildError::io(&module.path, e))?;
+            inputs.push_str(&format!(
+                "module {} {}\n",
+                module.name,
+                compute_hash(&source)
+            ));
+        }
+        Ok(compute_hash(&inputs))
+    }
+
+    /// Check that two builds of the same project produced identical artifacts
+    ///
+    /// Artifacts are matched by target name and kind. Fails with
+    /// [`BuildError::NotReproducible`] naming the first artifact whose inputs
+    /// or content digest differ, or that only one build produced.
+    pub fn verify_reproducible(run1: &BuildContext, run2: &BuildContext) -> BuildResult<()> {
+        if run1.artifacts.len() != run2.artifacts.len() {
+            return Err(BuildError::NotReproducible(format!(
+                "first build produced {} artifacts, second produced {}",
+                run1.artifacts.len(),
+                run2.artifacts.len()
+            )));
+        }
+
+        for first in &run1.artifacts {
+            let target = &first.target;
+            let second = run2
+                .artifacts
+                .iter()
+                .find(|a| a.target.name == target.name && a.target.kind == target.kind)
+                .ok_or_else(|| {
+                    BuildError::NotReproducible(format!(
+                        "{} target '{}' missing from second build",
+                        target.kind, target.name
+                    ))
+                })?;
+
+            if first.metadata.inputs_digest != second.metadata.inputs_digest {
+                return Err(BuildError::NotReproducible(format!(
+                    "{} target '{}' was built from different inputs",
+                    target.kind, target.name
+                )));
+            }
+            if first.metadata.content_digest != second.metadata.content_digest {
+                return Err(BuildError::NotReproducible(format!(
+                    "{} target '{}' differs: {} vs {}",
+                    target.kind,
+                    target.name,
+                    first.metadata.content_digest,
+                    second.metadata.content_digest
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Convert file path to module name
     fn path_to_module_name(&self, path: &Path) -> BuildResult<String> {
         let src_dir = self.root_dir.join("src");
@@ -815,6 +1337,9 @@ impl Builder {
         // Apply profile configuration to build config
         self.config.optimization_level = profile_config.optimization_level;
         self.config.verbose = matches!(output_mode, OutputMode::Verbose);
+        if matches!(output_mode, OutputMode::JsonStream) && self.events.is_none() {
+            self.events = Some(EventStream::stdout());
+        }
 
         // Create script context
         let script_context = ScriptContext::new(
@@ -825,27 +1350,52 @@ impl Builder {
             self.manifest.package.version.to_string(),
         );
 
-        let script_executor = ScriptExecutor::new(script_context).with_verbose(self.config.verbose);
+        let mut script_executor = ScriptExecutor::new(script_context)
+            .with_verbose(self.config.verbose)
+            .with_fingerprints(self.config.target_dir.join("scripts/fingerprints.json"));
+        let mut directives = Vec::new();
 
         // Execute pre-build scripts
-        script_executor.execute_phase(scripts, ScriptPhase::PreBuild)?;
+        let results = script_executor.execute_phase(scripts, ScriptPhase::PreBuild)?;
+        self.apply_script_results(&mut script_executor, results, &mut directives);
 
         // Perform build (incremental if profile allows)
-        let context = if profile_config.incremental {
+        let mut context = if profile_config.incremental {
             self.build_incremental()?
         } else {
             self.build()?
         };
 
         // Execute post-build scripts
-        script_executor.execute_phase(scripts, ScriptPhase::PostBuild)?;
+        let results = script_executor.execute_phase(scripts, ScriptPhase::PostBuild)?;
+        self.apply_script_results(&mut script_executor, results, &mut directives);
 
         // Execute post-link scripts
-        script_executor.execute_phase(scripts, ScriptPhase::PostLink)?;
+        let results = script_executor.execute_phase(scripts, ScriptPhase::PostLink)?;
+        self.apply_script_results(&mut script_executor, results, &mut directives);
 
+        context.script_directives = directives;
         Ok(context)
     }
 
+    /// Act on script directives: env vars reach compilation and later scripts
+    fn apply_script_results(
+        &mut self,
+        executor: &mut ScriptExecutor,
+        results: Vec<ScriptResult>,
+        directives: &mut Vec<ScriptDirective>,
+    ) {
+        for result in results {
+            for (key, value) in result.env_vars() {
+                self.config
+                    .env_vars
+                    .insert(key.to_string(), value.to_string());
+                executor.set_env(key, value);
+            }
+            directives.extend(result.directives);
+        }
+    }
+
     /// Set profile
     pub fn with_profile(self, _profile: Profile) -> Self {
         // Profile will be applied in build_with_profile
@@ -877,13 +1427,51 @@ fn format_diagnostics(diagnostics: &[Diagnostic]) -> String {
         .join("; ")
 }
 
-/// Serialize bytecode to bytes
-/// TODO: Implement proper bytecode serialization format
-/// For now, this is a placeholder - in future phases we'll add proper serialization
-fn serialize_bytecode(_bytecode: &Bytecode) -> BuildResult<Vec<u8>> {
-    // Placeholder: Return empty vec for now
-    // Phase-11b or later will implement proper bytecode serialization
-    Ok(Vec::new())
+/// Default worker count: one per available CPU
+fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Per-module timings relative to the start of compilation
+fn module_timings(
+    modules: &[CompiledModule],
+    compile_start: Instant,
+) -> BTreeMap<String, ModuleTiming> {
+    modules
+        .iter()
+        .map(|module| {
+            let timing = ModuleTiming {
+                start: module.started.saturating_duration_since(compile_start),
+                duration: module.compile_time,
+            };
+            (module.name.clone(), timing)
+        })
+        .collect()
+}
+
+/// Serialize bytecode to bytes (.atb format)
+fn serialize_bytecode(bytecode: &Bytecode) -> BuildResult<Vec<u8>> {
+    Ok(bytecode.to_bytes())
+}
+
+/// Top-level function declarations (plain and exported) with their names
+fn top_level_functions(program: &Program) -> impl Iterator<Item = (&str, &Item)> {
+    program.items.iter().filter_map(|item| match item {
+        Item::Function(func) => Some((func.name.name.as_str(), item)),
+        Item::Export(ExportDecl {
+            item: ExportItem::Function(func),
+            ..
+        }) => Some((func.name.name.as_str(), item)),
+        _ => None,
+    })
+}
+
+/// Lower a linked program to a wasm module embedding the bytecode of every module
+fn lower_to_wasm(compiled_modules: &[CompiledModule], linked: &[u8]) -> BuildResult<Vec<u8>> {
+    if !compiled_modules.iter().any(|module| module.name == "main") {
+        return Err(BuildError::module_not_found("main"));
+    }
+    Ok(crate::wasm::emit_module(linked))
 }
 
 #[cfg(test)]
@@ -904,6 +1492,7 @@ mod tests {
         assert_eq!(config.optimization_level, OptLevel::O0);
         assert!(config.parallel);
         assert!(!config.verbose);
+        assert_eq!(config.target, None);
     }
 
     #[test]
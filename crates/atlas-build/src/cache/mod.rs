@@ -1,9 +1,14 @@
 //! Build cache infrastructure for incremental compilation
+//!
+//! A cache is either per-project (entries keyed by module name) or shared
+//! (entries keyed by a content hash of the module's fingerprint, so projects
+//! with identical inputs reuse the same compiled artifact).
 
 pub mod invalidation;
 pub mod metadata;
 
 use crate::error::{BuildError, BuildResult};
+use crate::fingerprint::Fingerprint;
 pub use invalidation::{
     compute_selective_invalidation, compute_transitive_invalidation, InvalidationReason,
     InvalidationSet,
@@ -32,6 +37,7 @@ pub struct BuildCache {
     metadata: CacheMetadata,
     entries: HashMap<String, CacheEntry>,
     size_limit: u64,
+    shared: bool,
 }
 
 /// Cache entry for a compiled module
@@ -68,6 +74,9 @@ pub struct CacheMetadata {
     pub total_entries: usize,
     /// Total cache size in bytes
     pub total_size: u64,
+    /// Shared-cache hits on artifacts stored by a different project
+    #[serde(default)]
+    pub cross_project_hits: usize,
 }
 
 /// Cache statistics
@@ -80,6 +89,7 @@ pub struct CacheStats {
     pub cache_size_bytes: u64,
     pub cache_entries: usize,
     pub time_saved: Duration,
+    pub cross_project_hits: usize,
 }
 
 impl BuildCache {
@@ -97,6 +107,7 @@ impl BuildCache {
             last_updated: SystemTime::now(),
             total_entries: 0,
             total_size: 0,
+            cross_project_hits: 0,
         };
 
         Ok(Self {
@@ -104,9 +115,35 @@ impl BuildCache {
             metadata,
             entries: HashMap::new(),
             size_limit: DEFAULT_SIZE_LIMIT,
+            shared: false,
         })
     }
 
+    /// Open (or create) a content-addressed cache shared between projects
+    pub fn shared(cache_dir: impl Into<PathBuf>) -> BuildResult<Self> {
+        let mut cache = Self::load(&cache_dir.into())?;
+        cache.shared = true;
+        Ok(cache)
+    }
+
+    /// Check if this is a shared, content-addressed cache
+    pub fn is_shared(&self) -> bool {
+        self.shared
+    }
+
+    /// Content hash a shared entry is stored under
+    ///
+    /// Derived from the fingerprint (source, dependencies, compiler version and
+    /// build configuration) plus the platform, so artifacts are never shared
+    /// across incompatible platforms.
+    pub fn content_key(fingerprint: &Fingerprint) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(fingerprint.hash.as_bytes());
+        hasher.update(b"platform:");
+        hasher.update(fingerprint.platform.to_hash_input().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
     /// Load existing cache from disk
     pub fn load(cache_dir: &Path) -> BuildResult<Self> {
         let metadata_path = cache_dir.join("metadata.json");
@@ -175,6 +212,7 @@ impl BuildCache {
             metadata,
             entries,
             size_limit: DEFAULT_SIZE_LIMIT,
+            shared: false,
         })
     }
 
@@ -262,6 +300,74 @@ impl BuildCache {
         Ok(Some(entry.bytecode.clone()))
     }
 
+    /// Store a compiled module in a shared cache under its content key
+    ///
+    /// Returns `false` if an artifact with the same key was already cached.
+    pub fn store_shared(
+        &mut self,
+        fingerprint: &Fingerprint,
+        module_path: PathBuf,
+        bytecode: Vec<u8>,
+        dependencies: Vec<String>,
+        compile_time: Duration,
+    ) -> BuildResult<bool> {
+        let key = Self::content_key(fingerprint);
+        if self.entries.contains_key(&key) {
+            return Ok(false);
+        }
+
+        let entry = CacheEntry {
+            module_path,
+            source_hash: fingerprint.source_hash.clone(),
+            timestamp: SystemTime::now(),
+            bytecode,
+            dependencies,
+            compile_time,
+            last_accessed: SystemTime::now(),
+        };
+
+        let entry_size = entry.bytecode.len() as u64;
+        while self.metadata.total_size + entry_size > self.size_limit && !self.entries.is_empty() {
+            self.evict_lru()?;
+        }
+
+        self.persist_entry(&key, &entry)?;
+
+        self.metadata.total_size += entry_size;
+        self.metadata.total_entries = self.entries.len() + 1;
+        self.metadata.last_updated = SystemTime::now();
+        self.entries.insert(key, entry);
+
+        Ok(true)
+    }
+
+    /// Retrieve a shared artifact for a module with this fingerprint
+    ///
+    /// A hit on an artifact stored from a different source path counts as a
+    /// cross-project hit.
+    pub fn get_shared(&mut self, fingerprint: &Fingerprint, source_path: &Path) -> Option<Vec<u8>> {
+        let entry = self.entries.get_mut(&Self::content_key(fingerprint))?;
+        if entry.source_hash != fingerprint.source_hash {
+            return None;
+        }
+
+        entry.last_accessed = SystemTime::now();
+        if entry.module_path != source_path {
+            self.metadata.cross_project_hits += 1;
+        }
+
+        Some(entry.bytecode.clone())
+    }
+
+    /// Cached bytecode for a module without validating it against the source
+    ///
+    /// Used by function-level rebuilds, which patch a stale entry in place.
+    pub fn peek(&self, module_name: &str) -> Option<&[u8]> {
+        self.entries
+            .get(module_name)
+            .map(|entry| entry.bytecode.as_slice())
+    }
+
     /// Invalidate a cache entry
     pub fn invalidate(&mut self, module_name: &str) -> BuildResult<()> {
         if let Some(entry) = self.entries.remove(module_name) {
@@ -358,6 +464,7 @@ impl BuildCache {
             cache_size_bytes: self.metadata.total_size,
             cache_entries: self.entries.len(),
             time_saved: Duration::from_secs(0), // Updated during build
+            cross_project_hits: self.metadata.cross_project_hits,
         }
     }
 
@@ -407,6 +514,8 @@ impl BuildCache {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fingerprint::{compute_fingerprint_from_content, FingerprintConfig, PlatformInfo};
+    use std::collections::BTreeMap;
     use tempfile::TempDir;
 
     #[test]
@@ -446,4 +555,59 @@ mod tests {
         let loaded = BuildCache::load(temp_dir.path()).unwrap();
         assert_eq!(loaded.metadata.version, CACHE_VERSION);
     }
+
+    fn fingerprint_for(source: &str, platform: PlatformInfo) -> Fingerprint {
+        let mut fingerprint = compute_fingerprint_from_content(
+            source,
+            BTreeMap::new(),
+            &FingerprintConfig::default(),
+        );
+        fingerprint.platform = platform;
+        fingerprint
+    }
+
+    #[test]
+    fn test_shared_cache_cross_project_hit() {
+        let temp_dir = TempDir::new().unwrap();
+        let fingerprint = fingerprint_for("fn f() -> number { return 1; }", PlatformInfo::test());
+
+        let mut cache = BuildCache::shared(temp_dir.path()).unwrap();
+        assert!(cache.is_shared());
+        let stored = cache
+            .store_shared(
+                &fingerprint,
+                PathBuf::from("/a/src/lib.atlas"),
+                vec![1, 2, 3],
+                Vec::new(),
+                Duration::ZERO,
+            )
+            .unwrap();
+        assert!(stored);
+
+        let same_project = cache.get_shared(&fingerprint, Path::new("/a/src/lib.atlas"));
+        assert_eq!(same_project, Some(vec![1, 2, 3]));
+        assert_eq!(cache.stats().cross_project_hits, 0);
+
+        let other_project = cache.get_shared(&fingerprint, Path::new("/b/src/lib.atlas"));
+        assert_eq!(other_project, Some(vec![1, 2, 3]));
+        assert_eq!(cache.stats().cross_project_hits, 1);
+        assert_eq!(cache.stats().cache_entries, 1);
+    }
+
+    #[test]
+    fn test_shared_cache_key_includes_platform() {
+        let source = "fn f() -> number { return 1; }";
+        let linux = fingerprint_for(source, PlatformInfo::test());
+        let other = fingerprint_for(
+            source,
+            PlatformInfo {
+                os: "windows".to_string(),
+                arch: "aarch64".to_string(),
+            },
+        );
+        assert_ne!(
+            BuildCache::content_key(&linux),
+            BuildCache::content_key(&other)
+        );
+    }
 }
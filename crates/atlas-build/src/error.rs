@@ -54,6 +54,9 @@ pub enum BuildError {
     #[error("Build failed: {0}")]
     BuildFailed(String),
 
+    #[error("Build is not reproducible: {0}")]
+    NotReproducible(String),
+
     #[error("Profile not found: {0}")]
     ProfileNotFound(String),
 
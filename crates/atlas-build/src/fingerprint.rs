@@ -4,16 +4,21 @@
 //! It combines source content hash, dependency fingerprints, compiler version,
 //! platform info, and build configuration to determine when recompilation is needed.
 
+use atlas_runtime::ast::{ExportDecl, ExportItem, Item, Program};
+use atlas_runtime::{Lexer, Parser, Token};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 use std::{env, fs};
 
 /// Compiler version used in fingerprint computation
 const COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Declaration key covering everything outside top-level functions
+pub const TOP_LEVEL_DECLARATION: &str = "<top-level>";
+
 /// A fingerprint that uniquely identifies a module's compilation state
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Fingerprint {
@@ -21,7 +26,7 @@ pub struct Fingerprint {
     pub hash: String,
     /// Source content hash
     pub source_hash: String,
-    /// Dependency fingerprint hashes (sorted by name for determinism)
+    /// Dependency interface hashes (sorted by name for determinism)
     pub dependency_hashes: BTreeMap<String, String>,
     /// Compiler version
     pub compiler_version: String,
@@ -33,6 +38,12 @@ pub struct Fingerprint {
     pub mtime: Option<SystemTime>,
     /// Source file size
     pub file_size: u64,
+    /// Per-declaration hashes (function name -> hash, plus [`TOP_LEVEL_DECLARATION`])
+    #[serde(default)]
+    pub declaration_hashes: BTreeMap<String, String>,
+    /// Hash of the exported signatures (see [`compute_interface_hash`])
+    #[serde(default)]
+    pub interface_hash: String,
 }
 
 /// Platform information included in fingerprints
@@ -60,11 +71,46 @@ impl PlatformInfo {
         }
     }
 
-    fn to_hash_input(&self) -> String {
+    pub(crate) fn to_hash_input(&self) -> String {
         format!("{}:{}", self.os, self.arch)
     }
 }
 
+/// Fingerprint of the files a build script asked to be rerun for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptFingerprint {
+    /// Watched path -> content hash (`None` if the file did not exist)
+    pub watched: BTreeMap<PathBuf, Option<String>>,
+    /// Environment variables emitted by the script's last run
+    pub env_vars: BTreeMap<String, String>,
+}
+
+impl ScriptFingerprint {
+    /// Hash `paths` (relative to `base_dir`) as they are now
+    pub fn new(base_dir: &Path, paths: &[PathBuf], env_vars: BTreeMap<String, String>) -> Self {
+        let watched = paths
+            .iter()
+            .map(|path| (path.clone(), hash_watched_file(base_dir, path)))
+            .collect();
+        Self { watched, env_vars }
+    }
+
+    /// Check if any watched file changed since the fingerprint was taken
+    pub fn is_stale(&self, base_dir: &Path) -> bool {
+        self.watched
+            .iter()
+            .any(|(path, hash)| hash_watched_file(base_dir, path) != *hash)
+    }
+}
+
+/// Hash a watched file's content, if it exists
+fn hash_watched_file(base_dir: &Path, path: &Path) -> Option<String> {
+    let bytes = fs::read(base_dir.join(path)).ok()?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Some(format!("{:x}", hasher.finalize()))
+}
+
 /// Build configuration relevant to fingerprinting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FingerprintConfig {
@@ -110,6 +156,9 @@ pub struct FingerprintDb {
     pub compiler_version: String,
     /// Platform when DB was created
     pub platform: PlatformInfo,
+    /// Build script name -> watched-file fingerprint
+    #[serde(default)]
+    scripts: BTreeMap<String, ScriptFingerprint>,
 }
 
 impl FingerprintDb {
@@ -119,6 +168,7 @@ impl FingerprintDb {
             fingerprints: BTreeMap::new(),
             compiler_version: COMPILER_VERSION.to_string(),
             platform: PlatformInfo::current(),
+            scripts: BTreeMap::new(),
         }
     }
 
@@ -184,6 +234,21 @@ impl FingerprintDb {
         self.fingerprints.clear();
     }
 
+    /// Get the watched-file fingerprint for a build script
+    pub fn get_script(&self, script_name: &str) -> Option<&ScriptFingerprint> {
+        self.scripts.get(script_name)
+    }
+
+    /// Store the watched-file fingerprint for a build script
+    pub fn insert_script(&mut self, script_name: String, fingerprint: ScriptFingerprint) {
+        self.scripts.insert(script_name, fingerprint);
+    }
+
+    /// Remove the watched-file fingerprint for a build script
+    pub fn remove_script(&mut self, script_name: &str) -> Option<ScriptFingerprint> {
+        self.scripts.remove(script_name)
+    }
+
     /// Check if a module needs recompilation by comparing fingerprints
     pub fn needs_recompile(&self, module_name: &str, current: &Fingerprint) -> bool {
         match self.get(module_name) {
@@ -191,6 +256,41 @@ impl FingerprintDb {
             None => true, // No stored fingerprint = needs compile
         }
     }
+
+    /// Names of the top-level functions that changed, if nothing else did
+    ///
+    /// Returns `None` when the whole module must be recompiled: no stored
+    /// fingerprint, a dependency or configuration change, functions added or
+    /// removed, or any change outside function declarations.
+    pub fn changed_functions(
+        &self,
+        module_name: &str,
+        current: &Fingerprint,
+    ) -> Option<Vec<String>> {
+        let stored = self.get(module_name)?;
+        if stored.config_hash != current.config_hash
+            || stored.dependency_hashes != current.dependency_hashes
+        {
+            return None;
+        }
+
+        let previous = &stored.declaration_hashes;
+        let declarations = &current.declaration_hashes;
+        if previous.is_empty()
+            || !previous.keys().eq(declarations.keys())
+            || previous.get(TOP_LEVEL_DECLARATION) != declarations.get(TOP_LEVEL_DECLARATION)
+        {
+            return None;
+        }
+
+        Some(
+            declarations
+                .iter()
+                .filter(|(name, hash)| previous.get(*name) != Some(*hash))
+                .map(|(name, _)| name.clone())
+                .collect(),
+        )
+    }
 }
 
 impl Default for FingerprintDb {
@@ -238,6 +338,8 @@ pub fn compute_fingerprint(
         config_hash,
         mtime,
         file_size,
+        declaration_hashes: compute_declaration_hashes(source_content),
+        interface_hash: compute_interface_hash(source_content),
     }
 }
 
@@ -273,6 +375,8 @@ pub fn compute_fingerprint_from_content(
         config_hash,
         mtime: None,
         file_size: source_content.len() as u64,
+        declaration_hashes: compute_declaration_hashes(source_content),
+        interface_hash: compute_interface_hash(source_content),
     }
 }
 
@@ -294,6 +398,128 @@ pub fn quick_check_changed(stored: &Fingerprint, path: &Path) -> bool {
     true
 }
 
+/// Compute per-declaration hashes for a module's source
+///
+/// Each top-level function (exported or not) is keyed by name; everything else
+/// hashes under [`TOP_LEVEL_DECLARATION`]. Hashes include byte offsets because
+/// compiled bytecode embeds source spans. Returns an empty map if the source
+/// does not parse.
+pub fn compute_declaration_hashes(source: &str) -> BTreeMap<String, String> {
+    let Some(program) = parse_program(source) else {
+        return BTreeMap::new();
+    };
+
+    let mut hashes = BTreeMap::new();
+    let mut top_level = Sha256::new();
+    let mut cursor = 0;
+
+    for item in &program.items {
+        let (name, span) = match item {
+            Item::Function(func) => (&func.name.name, func.span),
+            Item::Export(ExportDecl {
+                item: ExportItem::Function(func),
+                span,
+            }) => (&func.name.name, *span),
+            _ => continue,
+        };
+        let (Some(gap), Some(text)) = (
+            source.get(cursor..span.start),
+            source.get(span.start..span.end),
+        ) else {
+            return BTreeMap::new();
+        };
+
+        top_level.update(format!("{}:{};", cursor, gap).as_bytes());
+        hashes.insert(
+            name.clone(),
+            compute_hash(&format!("{}:{}", span.start, text)),
+        );
+        cursor = span.end;
+    }
+
+    top_level.update(format!("{}:{}", cursor, &source[cursor..]).as_bytes());
+    hashes.insert(
+        TOP_LEVEL_DECLARATION.to_string(),
+        format!("{:x}", top_level.finalize()),
+    );
+    hashes
+}
+
+/// Compute the hash of a module's interface: what its importers depend on
+///
+/// Covers exported function signatures (without bodies), exported variables
+/// (their type annotation, or the initializer when the type is inferred),
+/// exported type aliases, and re-exports. Whitespace, comments, and source
+/// positions do not count, so private edits and body changes leave the hash
+/// unchanged. If the source does not parse, the whole source is hashed.
+pub fn compute_interface_hash(source: &str) -> String {
+    let Some(tokens) = lex(source) else {
+        return compute_hash(source);
+    };
+    let Some(program) = parse(tokens.clone()) else {
+        return compute_hash(source);
+    };
+    // Token lexemes between two offsets, so spacing and comments drop out
+    let text = |start: usize, end: usize| {
+        tokens
+            .iter()
+            .filter(|token| token.span.start >= start && token.span.end <= end)
+            .map(|token| token.lexeme.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let mut hasher = Sha256::new();
+    for item in &program.items {
+        let Item::Export(export) = item else {
+            continue;
+        };
+        let signature = match &export.item {
+            ExportItem::Function(func) => text(func.span.start, func.body.span.start),
+            ExportItem::Variable(var) => match &var.type_ref {
+                Some(type_ref) => format!(
+                    "{} {}: {}",
+                    if var.mutable { "var" } else { "let" },
+                    var.name.name,
+                    text(type_ref.span().start, type_ref.span().end)
+                ),
+                None => text(var.span.start, var.span.end),
+            },
+            ExportItem::TypeAlias(alias) => text(alias.span.start, alias.span.end),
+            ExportItem::ReExport(re_export) => format!(
+                "re-export {} from {}",
+                re_export
+                    .names
+                    .iter()
+                    .map(|name| name.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+                re_export.source
+            ),
+        };
+        hasher.update(signature.as_bytes());
+        hasher.update(b";");
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Lex and parse `source`, or `None` if it has errors
+fn parse_program(source: &str) -> Option<Program> {
+    parse(lex(source)?)
+}
+
+/// Lex `source`, or `None` if it has errors
+fn lex(source: &str) -> Option<Vec<Token>> {
+    let (tokens, diagnostics) = Lexer::new(source).tokenize();
+    diagnostics.is_empty().then_some(tokens)
+}
+
+/// Parse `tokens`, or `None` if they have errors
+fn parse(tokens: Vec<Token>) -> Option<Program> {
+    let (program, diagnostics) = Parser::new(tokens).parse();
+    diagnostics.is_empty().then_some(program)
+}
+
 /// Compute SHA-256 hash of content
 pub fn compute_hash(content: &str) -> String {
     let mut hasher = Sha256::new();
@@ -418,6 +644,29 @@ pub fn compute_dependency_hash(source_path: &Path) -> Option<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_script_fingerprint_detects_watched_change() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("schema.txt"), "v1").unwrap();
+
+        let watched = vec![PathBuf::from("schema.txt"), PathBuf::from("missing.txt")];
+        let fingerprint = ScriptFingerprint::new(dir.path(), &watched, BTreeMap::new());
+        assert!(!fingerprint.is_stale(dir.path()));
+
+        fs::write(dir.path().join("schema.txt"), "v2").unwrap();
+        assert!(fingerprint.is_stale(dir.path()));
+    }
+
+    #[test]
+    fn test_script_fingerprint_detects_created_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let watched = vec![PathBuf::from("later.txt")];
+        let fingerprint = ScriptFingerprint::new(dir.path(), &watched, BTreeMap::new());
+
+        fs::write(dir.path().join("later.txt"), "now").unwrap();
+        assert!(fingerprint.is_stale(dir.path()));
+    }
+
     #[test]
     fn test_compute_hash_deterministic() {
         let hash1 = compute_hash("fn main() {}");
@@ -688,4 +937,76 @@ mod tests {
         let fp2 = compute_fingerprint_from_content("test", deps2, &config);
         assert_eq!(fp1.hash, fp2.hash);
     }
+
+    const TWO_FUNCTIONS: &str = "fn a() -> number { return 1; }\nfn b() -> number { return 2; }\n";
+
+    #[test]
+    fn test_declaration_hashes_keyed_by_function() {
+        let hashes = compute_declaration_hashes(TWO_FUNCTIONS);
+        let keys: Vec<&str> = hashes.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec![TOP_LEVEL_DECLARATION, "a", "b"]);
+    }
+
+    #[test]
+    fn test_declaration_hashes_empty_on_parse_error() {
+        assert!(compute_declaration_hashes("fn broken( {").is_empty());
+    }
+
+    #[test]
+    fn test_changed_functions_single_body_edit() {
+        let config = FingerprintConfig::default();
+        let mut db = FingerprintDb::new();
+        db.insert(
+            "main".to_string(),
+            compute_fingerprint_from_content(TWO_FUNCTIONS, BTreeMap::new(), &config),
+        );
+
+        let edited = TWO_FUNCTIONS.replace("return 2;", "return 3;");
+        let current = compute_fingerprint_from_content(&edited, BTreeMap::new(), &config);
+        assert_eq!(
+            db.changed_functions("main", &current),
+            Some(vec!["b".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_changed_functions_none_for_top_level_edit() {
+        let config = FingerprintConfig::default();
+        let mut db = FingerprintDb::new();
+        db.insert(
+            "main".to_string(),
+            compute_fingerprint_from_content(TWO_FUNCTIONS, BTreeMap::new(), &config),
+        );
+
+        let edited = format!("{}let x = 1;\n", TWO_FUNCTIONS);
+        let current = compute_fingerprint_from_content(&edited, BTreeMap::new(), &config);
+        assert_eq!(db.changed_functions("main", &current), None);
+        assert_eq!(db.changed_functions("other", &current), None);
+    }
+
+    #[test]
+    fn test_interface_hash_ignores_private_and_body_changes() {
+        let source = "fn scale(x: number) -> number { return x * 2; }\n\
+                      export fn twice(x: number) -> number { return scale(x); }\n\
+                      export let LIMIT: number = 10;";
+        let base = compute_interface_hash(source);
+
+        let private = source.replace("x * 2", "x + x");
+        let body = source.replace("return scale(x);", "return x * 2;");
+        let value = source.replace("= 10", "= 20");
+        let spacing = source.replace(
+            "export fn twice(x: number)",
+            "export fn twice( x : number )",
+        );
+        for edited in [private, body, value, spacing] {
+            assert_eq!(compute_interface_hash(&edited), base, "{}", edited);
+        }
+
+        let signature = source.replace("twice(x: number)", "twice(x: string)");
+        let export_type = source.replace("LIMIT: number", "LIMIT: string");
+        let added = format!("{}\nexport fn extra() -> void {{}}", source);
+        for edited in [signature, export_type, added] {
+            assert_ne!(compute_interface_hash(&edited), base, "{}", edited);
+        }
+    }
 }
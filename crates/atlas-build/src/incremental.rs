@@ -22,6 +22,8 @@ pub struct IncrementalPlan {
     pub cached: Vec<String>,
     /// Reason each module needs recompilation
     pub reasons: HashMap<String, RecompileReason>,
+    /// Modules where only some top-level functions changed (module -> function names)
+    pub changed_functions: HashMap<String, Vec<String>>,
     /// Total modules in the build
     pub total_modules: usize,
 }
@@ -42,13 +44,18 @@ impl IncrementalPlan {
 }
 
 /// Reason a module needs recompilation
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum RecompileReason {
     /// First time compiling this module
     NoPreviousFingerprint,
-    /// Source content changed
-    SourceChanged,
-    /// A dependency's fingerprint changed
+    /// Exported signatures changed, so importers recompile too
+    InterfaceChanged,
+    /// Source changed without touching exported signatures; importers stay cached
+    BodyChanged,
+    /// Only top-level function bodies changed (the first changed function is named)
+    FunctionChanged { name: String },
+    /// A dependency's interface changed
     DependencyChanged(String),
     /// Build configuration changed
     ConfigChanged,
@@ -180,39 +187,38 @@ impl IncrementalEngine {
         let mut recompile = Vec::new();
         let mut cached = Vec::new();
         let mut reasons = HashMap::new();
+        let mut changed_functions = HashMap::new();
         let modules = graph.modules();
 
         // Phase 1: Compute current fingerprints and check direct changes
         let mut current_fingerprints: HashMap<String, Fingerprint> = HashMap::new();
         let mut directly_changed: HashSet<String> = HashSet::new();
+        let mut interface_changed: HashSet<String> = HashSet::new();
 
         for (name, node) in modules {
             let source =
                 fs::read_to_string(&node.path).map_err(|e| BuildError::io(&node.path, e))?;
 
-            // Gather dependency hashes
-            let dep_hashes: BTreeMap<String, String> = node
-                .dependencies
-                .iter()
-                .filter_map(|dep| {
-                    // Use stored fingerprint hash for dependency
-                    self.fingerprint_db
-                        .get(dep)
-                        .map(|fp| (dep.clone(), fp.hash.clone()))
-                })
-                .collect();
-
+            let dep_hashes = self.dependency_hashes(&node.dependencies);
             let fp = compute_fingerprint(&node.path, &source, dep_hashes, &self.config);
 
             if self.fingerprint_db.needs_recompile(name, &fp) {
                 directly_changed.insert(name.clone());
+                let same_interface = self
+                    .fingerprint_db
+                    .get(name)
+                    .is_some_and(|stored| stored.interface_hash == fp.interface_hash);
+                if !same_interface {
+                    interface_changed.insert(name.clone());
+                }
             }
 
             current_fingerprints.insert(name.clone(), fp);
         }
 
-        // Phase 2: Propagate invalidation through dependency graph
-        let all_invalidated = propagate_invalidation(&directly_changed, modules);
+        // Phase 2: Propagate invalidation from interface changes through the dependency graph
+        let mut all_invalidated = propagate_invalidation(&interface_changed, modules);
+        all_invalidated.extend(directly_changed.iter().cloned());
 
         // Phase 3: Classify modules
         for name in modules.keys() {
@@ -220,12 +226,32 @@ impl IncrementalEngine {
                 let reason = if directly_changed.contains(name) {
                     if self.fingerprint_db.get(name).is_none() {
                         RecompileReason::NoPreviousFingerprint
+                    } else if interface_changed.contains(name) {
+                        RecompileReason::InterfaceChanged
                     } else {
-                        RecompileReason::SourceChanged
+                        // Function-level rebuilds need every dependency to be unchanged
+                        let deps_unchanged = modules[name]
+                            .dependencies
+                            .iter()
+                            .all(|dep| !all_invalidated.contains(dep));
+                        let functions = self
+                            .fingerprint_db
+                            .changed_functions(name, &current_fingerprints[name])
+                            .filter(|functions| deps_unchanged && !functions.is_empty());
+                        match functions {
+                            Some(functions) => {
+                                let reason = RecompileReason::FunctionChanged {
+                                    name: functions[0].clone(),
+                                };
+                                changed_functions.insert(name.clone(), functions);
+                                reason
+                            }
+                            None => RecompileReason::BodyChanged,
+                        }
                     }
                 } else {
                     // Find which dependency caused invalidation
-                    let dep_cause = find_invalidation_cause(name, &directly_changed, modules);
+                    let dep_cause = find_invalidation_cause(name, &interface_changed, modules);
                     RecompileReason::DependencyChanged(dep_cause)
                 };
                 reasons.insert(name.clone(), reason);
@@ -239,10 +265,26 @@ impl IncrementalEngine {
             recompile,
             cached,
             reasons,
+            changed_functions,
             total_modules: modules.len(),
         })
     }
 
+    /// Stored interface hashes of `dependencies`, which go into a module's fingerprint
+    ///
+    /// Using interface hashes rather than full fingerprints keeps a module
+    /// fresh when a dependency changes only privately.
+    pub fn dependency_hashes(&self, dependencies: &[String]) -> BTreeMap<String, String> {
+        dependencies
+            .iter()
+            .filter_map(|dep| {
+                self.fingerprint_db
+                    .get(dep)
+                    .map(|fp| (dep.clone(), fp.interface_hash.clone()))
+            })
+            .collect()
+    }
+
     /// Record a successful compilation for a module
     pub fn record_compilation(
         &mut self,
@@ -308,8 +350,8 @@ impl IncrementalEngine {
     }
 }
 
-/// Propagate invalidation from directly changed modules through the dependency graph.
-/// Returns all modules that need recompilation (directly changed + transitive dependents).
+/// Propagate invalidation from changed modules through the dependency graph.
+/// Returns the changed modules plus all their transitive dependents.
 fn propagate_invalidation(
     directly_changed: &HashSet<String>,
     modules: &HashMap<String, crate::build_order::ModuleNode>,
@@ -345,12 +387,12 @@ fn propagate_invalidation(
 /// Find which dependency caused a module to be invalidated
 fn find_invalidation_cause(
     module: &str,
-    directly_changed: &HashSet<String>,
+    interface_changed: &HashSet<String>,
     modules: &HashMap<String, crate::build_order::ModuleNode>,
 ) -> String {
     if let Some(node) = modules.get(module) {
         for dep in &node.dependencies {
-            if directly_changed.contains(dep) {
+            if interface_changed.contains(dep) {
                 return dep.clone();
             }
         }
@@ -371,6 +413,10 @@ pub struct IncrementalStats {
     pub recompiled: usize,
     /// Modules served from cache
     pub from_cache: usize,
+    /// Top-level functions compiled this build
+    pub functions_recompiled: usize,
+    /// Modules loaded from the shared cache instead of compiled
+    pub shared_cache_hits: usize,
     /// Time spent on analysis
     pub analysis_time: Duration,
     /// Time spent on compilation
@@ -542,6 +588,7 @@ mod tests {
             recompile: vec![],
             cached: vec!["a".to_string(), "b".to_string()],
             reasons: HashMap::new(),
+            changed_functions: HashMap::new(),
             total_modules: 2,
         };
         assert!(!plan.has_work());
@@ -554,6 +601,7 @@ mod tests {
             recompile: vec!["a".to_string(), "b".to_string()],
             cached: vec![],
             reasons: HashMap::new(),
+            changed_functions: HashMap::new(),
             total_modules: 2,
         };
         assert!(plan.has_work());
@@ -566,6 +614,7 @@ mod tests {
             recompile: vec!["a".to_string()],
             cached: vec!["b".to_string()],
             reasons: HashMap::new(),
+            changed_functions: HashMap::new(),
             total_modules: 2,
         };
         assert!(plan.has_work());
@@ -632,6 +681,8 @@ mod tests {
             total_modules: 10,
             recompiled: 3,
             from_cache: 7,
+            functions_recompiled: 0,
+            shared_cache_hits: 0,
             analysis_time: Duration::from_millis(5),
             compilation_time: Duration::from_millis(100),
             time_saved: Duration::from_millis(200),
@@ -646,6 +697,8 @@ mod tests {
             total_modules: 5,
             recompiled: 5,
             from_cache: 0,
+            functions_recompiled: 0,
+            shared_cache_hits: 0,
             analysis_time: Duration::from_millis(1),
             compilation_time: Duration::from_millis(500),
             time_saved: Duration::ZERO,
@@ -662,6 +715,8 @@ mod tests {
             total_modules: 10,
             recompiled: 2,
             from_cache: 8,
+            functions_recompiled: 0,
+            shared_cache_hits: 0,
             analysis_time: Duration::from_millis(1),
             compilation_time: Duration::from_millis(50),
             time_saved: Duration::from_millis(200),
@@ -674,14 +729,8 @@ mod tests {
 
     #[test]
     fn test_recompile_reason_variants() {
-        assert_eq!(
-            RecompileReason::SourceChanged,
-            RecompileReason::SourceChanged
-        );
-        assert_ne!(
-            RecompileReason::SourceChanged,
-            RecompileReason::ConfigChanged
-        );
+        assert_eq!(RecompileReason::BodyChanged, RecompileReason::BodyChanged);
+        assert_ne!(RecompileReason::BodyChanged, RecompileReason::ConfigChanged);
         assert_eq!(
             RecompileReason::DependencyChanged("x".to_string()),
             RecompileReason::DependencyChanged("x".to_string())
@@ -737,7 +786,7 @@ mod tests {
         engine.record_compilation("main", &src, "fn main() {}", BTreeMap::new());
 
         // Change source
-        fs::write(&src, "fn main() { 42 }").unwrap();
+        fs::write(&src, "fn main() {}\nmain();").unwrap();
 
         let mut graph = BuildGraph::new();
         graph.add_module(ModuleNode::new("main", src));
@@ -746,7 +795,7 @@ mod tests {
         assert_eq!(plan.recompile.len(), 1);
         assert!(matches!(
             plan.reasons.get("main"),
-            Some(RecompileReason::SourceChanged)
+            Some(RecompileReason::BodyChanged)
         ));
     }
 
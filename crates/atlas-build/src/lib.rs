@@ -2,7 +2,7 @@
 //!
 //! Provides build orchestration for Atlas projects including:
 //! - Build pipeline management
-//! - Multiple build targets (library, binary, bytecode, test)
+//! - Multiple build targets (library, binary, bytecode, test, wasm)
 //! - Dependency resolution and building
 //! - Parallel compilation
 //! - Incremental compilation (phase-11b)
@@ -18,27 +18,35 @@ pub mod fingerprint;
 pub mod incremental;
 pub mod module_resolver;
 pub mod output;
+pub mod plan;
 pub mod profile;
 pub mod script;
 pub mod targets;
+pub mod wasm;
 
 // Re-export main types
 pub use build_order::{BuildGraph, ModuleNode};
-pub use builder::{BuildConfig, BuildContext, BuildStats, Builder, OptLevel};
+pub use builder::{BuildConfig, BuildContext, BuildStats, Builder, ModuleTiming, OptLevel};
 pub use cache::{BuildCache, CacheEntry, CacheMetadata, CacheStats};
 pub use error::{BuildError, BuildResult};
 pub use fingerprint::{
     compute_fingerprint, compute_hash, Fingerprint, FingerprintConfig, FingerprintDb, PlatformInfo,
+    ScriptFingerprint,
 };
 pub use incremental::{
     BuildState, IncrementalEngine, IncrementalPlan, IncrementalStats, RecompileReason,
 };
-pub use output::{BuildProgress, BuildSummary, ErrorFormatter, OutputMode};
+pub use output::{
+    ArtifactReport, BuildEvent, BuildProgress, BuildSummary, ErrorFormatter, EventStream,
+    OutputMode, SummaryReport,
+};
+pub use plan::{BuildPlan, PlanTarget, PlanUnit};
 pub use profile::{
     DependencyProfile, ManifestProfileConfig, Profile, ProfileConfig, ProfileManager,
 };
 pub use script::{
-    BuildScript, ScriptContext, ScriptExecutor, ScriptKind, ScriptPhase, ScriptResult,
+    parse_directives, BuildScript, ScriptContext, ScriptDirective, ScriptExecutor, ScriptKind,
+    ScriptPhase, ScriptResult,
 };
 pub use targets::{ArtifactMetadata, BuildArtifact, BuildTarget, TargetKind};
 
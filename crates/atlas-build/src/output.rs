@@ -1,11 +1,17 @@
 //! Build output formatting and progress reporting
 //!
-//! Provides progress tracking, colorized output, and build summaries.
+//! Provides progress tracking, colorized output, and build summaries. In
+//! `OutputMode::JsonStream` the builder reports progress as newline-delimited
+//! JSON `BuildEvent`s written to an `EventStream`.
 
+use crate::builder::BuildStats;
 use crate::cache::CacheStats;
 use crate::targets::BuildArtifact;
 use serde::{Deserialize, Serialize};
-use std::time::{Duration, Instant};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Build progress tracker
 pub struct BuildProgress {
@@ -82,7 +88,6 @@ impl BuildProgress {
 
             // Clear to end of line and flush
             print!("{}   ", " ".repeat(20));
-            use std::io::{self, Write};
             let _ = io::stdout().flush();
         } else {
             println!(
@@ -108,7 +113,10 @@ impl BuildProgress {
 
     /// Check if should report progress
     fn should_report(&self) -> bool {
-        !matches!(self.mode, OutputMode::Quiet | OutputMode::Json)
+        !matches!(
+            self.mode,
+            OutputMode::Quiet | OutputMode::Json | OutputMode::JsonStream
+        )
     }
 
     /// Finish progress reporting
@@ -149,6 +157,22 @@ impl BuildSummary {
         }
     }
 
+    /// Create from the statistics and artifacts of a finished build
+    pub fn from_build(stats: &BuildStats, artifacts: &[BuildArtifact]) -> Self {
+        Self {
+            total_time: stats.total_time,
+            compile_time: stats.compilation_time,
+            link_time: stats.linking_time,
+            module_count: stats.total_modules,
+            cache_hit_rate: if stats.total_modules == 0 {
+                0.0
+            } else {
+                1.0 - stats.compiled_modules as f64 / stats.total_modules as f64
+            },
+            artifacts: artifacts.to_vec(),
+        }
+    }
+
     /// Create from cache stats
     pub fn from_cache_stats(cache_stats: &CacheStats, total_time: Duration) -> Self {
         Self {
@@ -193,29 +217,28 @@ impl BuildSummary {
             OutputMode::Json => {
                 // JSON output handled separately
             }
+            OutputMode::JsonStream => {
+                // Emitted as the final `summary` event
+            }
         }
     }
 
     /// Convert to JSON string
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
-        #[derive(Serialize)]
-        struct JsonSummary {
-            success: bool,
-            total_time: f64,
-            compile_time: f64,
-            link_time: f64,
-            modules: usize,
-            cache_hit_rate: f64,
-            artifacts: Vec<JsonArtifact>,
-        }
+        serde_json::to_string_pretty(&self.report())
+    }
 
-        #[derive(Serialize)]
-        struct JsonArtifact {
-            target: String,
-            path: String,
+    /// The final `summary` event of a JSON event stream
+    pub fn to_event(&self) -> BuildEvent {
+        BuildEvent::Summary {
+            timestamp_ms: timestamp_ms(),
+            summary: self.report(),
         }
+    }
 
-        let summary = JsonSummary {
+    /// Machine-readable form of this summary
+    fn report(&self) -> SummaryReport {
+        SummaryReport {
             success: true,
             total_time: self.total_time.as_secs_f64(),
             compile_time: self.compile_time.as_secs_f64(),
@@ -225,14 +248,156 @@ impl BuildSummary {
             artifacts: self
                 .artifacts
                 .iter()
-                .map(|a| JsonArtifact {
+                .map(|a| ArtifactReport {
                     target: format!("{:?}", a.target.kind),
                     path: a.output_path.display().to_string(),
                 })
                 .collect(),
+        }
+    }
+}
+
+/// JSON form of a build summary (times in seconds)
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryReport {
+    pub success: bool,
+    pub total_time: f64,
+    pub compile_time: f64,
+    pub link_time: f64,
+    pub modules: usize,
+    pub cache_hit_rate: f64,
+    pub artifacts: Vec<ArtifactReport>,
+}
+
+/// JSON form of a build artifact
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtifactReport {
+    pub target: String,
+    pub path: String,
+}
+
+/// A structured build progress event, one JSON object per line
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub enum BuildEvent {
+    /// A unit (module) started compiling
+    CompileStarted { unit: String, timestamp_ms: u64 },
+    /// A unit finished compiling
+    CompileFinished {
+        unit: String,
+        timestamp_ms: u64,
+        duration_ms: f64,
+    },
+    /// An artifact was written
+    Artifact {
+        target: String,
+        path: PathBuf,
+        timestamp_ms: u64,
+    },
+    /// The build failed, in `unit` if the failure is tied to one
+    Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unit: Option<String>,
+        message: String,
+        timestamp_ms: u64,
+    },
+    /// The build finished
+    Summary {
+        timestamp_ms: u64,
+        #[serde(flatten)]
+        summary: SummaryReport,
+    },
+}
+
+impl BuildEvent {
+    /// `compile-started` for `unit`, stamped now
+    pub fn compile_started(unit: &str) -> Self {
+        Self::CompileStarted {
+            unit: unit.to_string(),
+            timestamp_ms: timestamp_ms(),
+        }
+    }
+
+    /// `compile-finished` for `unit`, stamped now
+    pub fn compile_finished(unit: &str, duration: Duration) -> Self {
+        Self::CompileFinished {
+            unit: unit.to_string(),
+            timestamp_ms: timestamp_ms(),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+        }
+    }
+
+    /// `artifact` for a written build artifact, stamped now
+    pub fn artifact(artifact: &BuildArtifact) -> Self {
+        Self::Artifact {
+            target: format!("{:?}", artifact.target.kind),
+            path: artifact.output_path.clone(),
+            timestamp_ms: timestamp_ms(),
+        }
+    }
+
+    /// `error`, optionally tied to a unit, stamped now
+    pub fn error(unit: Option<&str>, message: impl ToString) -> Self {
+        Self::Error {
+            unit: unit.map(str::to_string),
+            message: message.to_string(),
+            timestamp_ms: timestamp_ms(),
+        }
+    }
+
+    /// Event name as written in the `event` field
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CompileStarted { .. } => "compile-started",
+            Self::CompileFinished { .. } => "compile-finished",
+            Self::Artifact { .. } => "artifact",
+            Self::Error { .. } => "error",
+            Self::Summary { .. } => "summary",
+        }
+    }
+}
+
+/// Milliseconds since the Unix epoch
+fn timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Newline-delimited JSON sink for build events
+pub struct EventStream {
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl EventStream {
+    /// Stream events to `writer`
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    /// Stream events to stdout
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+
+    /// Write one event as a single line
+    ///
+    /// Write failures are ignored: progress reporting never fails a build.
+    pub fn emit(&self, event: &BuildEvent) {
+        let Ok(line) = serde_json::to_string(event) else {
+            return;
         };
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writeln!(writer, "{}", line);
+        let _ = writer.flush();
+    }
+}
 
-        serde_json::to_string_pretty(&summary)
+impl std::fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventStream").finish_non_exhaustive()
     }
 }
 
@@ -253,6 +418,8 @@ pub enum OutputMode {
     Quiet,
     /// JSON output (for tooling)
     Json,
+    /// Newline-delimited JSON events as the build proceeds (for IDEs)
+    JsonStream,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -279,7 +446,7 @@ impl ErrorFormatter {
             OutputMode::Normal | OutputMode::Verbose => {
                 format!("\x1b[31merror:\x1b[0m {}", error)
             }
-            OutputMode::Quiet | OutputMode::Json => error.to_string(),
+            OutputMode::Quiet | OutputMode::Json | OutputMode::JsonStream => error.to_string(),
         }
     }
 
@@ -289,7 +456,7 @@ impl ErrorFormatter {
             OutputMode::Normal | OutputMode::Verbose => {
                 format!("\x1b[33mwarning:\x1b[0m {}", warning)
             }
-            OutputMode::Quiet | OutputMode::Json => warning.to_string(),
+            OutputMode::Quiet | OutputMode::Json | OutputMode::JsonStream => warning.to_string(),
         }
     }
 
@@ -299,7 +466,7 @@ impl ErrorFormatter {
             OutputMode::Normal | OutputMode::Verbose => {
                 format!("\x1b[32m{}\x1b[0m", message)
             }
-            OutputMode::Quiet | OutputMode::Json => message.to_string(),
+            OutputMode::Quiet | OutputMode::Json | OutputMode::JsonStream => message.to_string(),
         }
     }
 
@@ -309,7 +476,7 @@ impl ErrorFormatter {
             OutputMode::Normal | OutputMode::Verbose => {
                 format!("\x1b[36m{}\x1b[0m", message)
             }
-            OutputMode::Quiet | OutputMode::Json => message.to_string(),
+            OutputMode::Quiet | OutputMode::Json | OutputMode::JsonStream => message.to_string(),
         }
     }
 }
@@ -371,6 +538,7 @@ mod tests {
             cache_size_bytes: 1024,
             cache_entries: 10,
             time_saved: Duration::from_secs(5),
+            cross_project_hits: 0,
         };
 
         let summary = BuildSummary::from_cache_stats(&stats, Duration::from_secs(5));
@@ -389,6 +557,7 @@ mod tests {
             cache_size_bytes: 0,
             cache_entries: 0,
             time_saved: Duration::ZERO,
+            cross_project_hits: 0,
         };
 
         let summary = BuildSummary::from_cache_stats(&stats, Duration::from_secs(0));
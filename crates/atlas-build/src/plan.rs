@@ -0,0 +1,130 @@
+//! Build plans
+//!
+//! A `BuildPlan` describes what a build would do without doing it: the units
+//! (modules) in compilation order, the targets each feeds, and whether the
+//! incremental engine considers each one fresh or in need of a rebuild.
+
+use crate::incremental::RecompileReason;
+use crate::targets::TargetKind;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Everything a build would do, in order
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildPlan {
+    /// Package being built
+    pub package: String,
+    /// Targets that will be linked
+    pub targets: Vec<PlanTarget>,
+    /// Units in compilation order (dependencies first)
+    pub units: Vec<PlanUnit>,
+}
+
+/// A target the plan links
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanTarget {
+    /// Target name
+    pub name: String,
+    /// Target kind
+    pub kind: TargetKind,
+    /// Entry point, for binary-like targets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry_point: Option<PathBuf>,
+}
+
+/// A single module to compile
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanUnit {
+    /// Module name
+    pub module: String,
+    /// Source path
+    pub path: PathBuf,
+    /// Modules this unit imports
+    pub dependencies: Vec<String>,
+    /// Kinds of the targets this unit is linked into
+    pub targets: Vec<TargetKind>,
+    /// Whether the cached artifact can be reused as-is
+    pub cache_hit: bool,
+    /// Why the unit needs rebuilding (absent for cache hits)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<RecompileReason>,
+    /// Top-level functions to recompile, when only function bodies changed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changed_functions: Vec<String>,
+}
+
+impl BuildPlan {
+    /// Units that need rebuilding
+    pub fn rebuild_units(&self) -> impl Iterator<Item = &PlanUnit> {
+        self.units.iter().filter(|unit| !unit.cache_hit)
+    }
+
+    /// Check if every unit is a cache hit
+    pub fn is_fresh(&self) -> bool {
+        self.units.iter().all(|unit| unit.cache_hit)
+    }
+
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(module: &str, reason: Option<RecompileReason>) -> PlanUnit {
+        PlanUnit {
+            module: module.to_string(),
+            path: PathBuf::from(format!("src/{}.atlas", module)),
+            dependencies: Vec::new(),
+            targets: vec![TargetKind::Binary],
+            cache_hit: reason.is_none(),
+            reason,
+            changed_functions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_plan_fresh_and_rebuild_units() {
+        let plan = BuildPlan {
+            package: "app".to_string(),
+            targets: Vec::new(),
+            units: vec![
+                unit("lib", None),
+                unit("main", Some(RecompileReason::BodyChanged)),
+            ],
+        };
+
+        assert!(!plan.is_fresh());
+        let rebuild: Vec<_> = plan.rebuild_units().map(|u| u.module.as_str()).collect();
+        assert_eq!(rebuild, vec!["main"]);
+    }
+
+    #[test]
+    fn test_plan_to_json() {
+        let plan = BuildPlan {
+            package: "app".to_string(),
+            targets: vec![PlanTarget {
+                name: "app".to_string(),
+                kind: TargetKind::Binary,
+                entry_point: Some(PathBuf::from("src/main.atlas")),
+            }],
+            units: vec![
+                unit("lib", None),
+                unit(
+                    "main",
+                    Some(RecompileReason::DependencyChanged("lib".to_string())),
+                ),
+            ],
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&plan.to_json().unwrap()).unwrap();
+        assert_eq!(json["package"], "app");
+        assert_eq!(json["units"][0]["cache_hit"], true);
+        assert!(json["units"][0].get("reason").is_none());
+        assert_eq!(json["units"][1]["reason"]["dependency-changed"], "lib");
+        assert_eq!(json["targets"][0]["kind"], "Binary");
+    }
+}
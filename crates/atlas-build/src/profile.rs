@@ -156,6 +156,10 @@ impl ProfileConfig {
     }
 
     /// Merge with manifest profile configuration
+    ///
+    /// Precedence: every setting present in `manifest` (the child) overrides the
+    /// value in `self` (the parent); unset settings keep the parent's value.
+    /// Environment variables are merged key by key, the child winning on conflict.
     pub fn merge_with_manifest(&mut self, manifest: &ManifestProfileConfig) {
         if let Some(opt) = manifest.opt_level {
             self.optimization_level = opt;
@@ -224,7 +228,7 @@ pub struct ManifestProfileConfig {
     /// Enable incremental compilation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub incremental: Option<bool>,
-    /// Inherit from base profile
+    /// Profile to inherit unset settings from (built-in or another manifest profile)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inherits: Option<String>,
     /// Environment variables
@@ -269,33 +273,67 @@ impl ProfileManager {
     }
 
     /// Load profiles from manifest
+    ///
+    /// Each profile's `inherits` chain is resolved first, so a profile may
+    /// inherit from another manifest profile as well as a built-in one.
     pub fn load_from_manifest(
         &mut self,
         manifest_profiles: &HashMap<String, ManifestProfileConfig>,
     ) -> BuildResult<()> {
-        for (name, manifest_config) in manifest_profiles {
-            let profile = if name == "dev" || name == "release" || name == "test" {
-                // Override built-in profile
-                let mut config = Profile::from_str(name)?.default_config();
-                config.merge_with_manifest(manifest_config);
-                config
-            } else {
-                // Custom profile - check for inheritance
-                let base_profile = if let Some(ref inherits) = manifest_config.inherits {
-                    Some(Profile::from_str(inherits)?)
-                } else {
-                    None
-                };
-
-                ProfileConfig::from_custom(name.clone(), manifest_config, base_profile.as_ref())
-            };
-
-            self.profiles.insert(name.clone(), profile);
+        let mut resolved = HashMap::new();
+        for name in manifest_profiles.keys() {
+            self.resolve(name, manifest_profiles, &mut resolved, &mut Vec::new())?;
         }
 
+        self.profiles.extend(resolved);
         Ok(())
     }
 
+    /// Resolve a manifest profile by applying it on top of its parent
+    ///
+    /// The parent is the `inherits` profile if set, otherwise the profile's own
+    /// built-in defaults (dev defaults for custom profiles). `chain` holds the
+    /// profiles currently being resolved, to detect inheritance cycles.
+    fn resolve(
+        &self,
+        name: &str,
+        manifest_profiles: &HashMap<String, ManifestProfileConfig>,
+        resolved: &mut HashMap<String, ProfileConfig>,
+        chain: &mut Vec<String>,
+    ) -> BuildResult<ProfileConfig> {
+        if let Some(config) = resolved.get(name) {
+            return Ok(config.clone());
+        }
+        if chain.iter().any(|n| n == name) {
+            chain.push(name.to_string());
+            return Err(BuildError::CircularDependency(format!(
+                "profile inheritance {}",
+                chain.join(" -> ")
+            )));
+        }
+
+        let Some(manifest) = manifest_profiles.get(name) else {
+            // Not declared in the manifest: must be an existing profile
+            return self
+                .profiles
+                .get(name)
+                .cloned()
+                .ok_or_else(|| BuildError::ProfileNotFound(name.to_string()));
+        };
+
+        chain.push(name.to_string());
+        let mut config = match &manifest.inherits {
+            Some(parent) => self.resolve(parent, manifest_profiles, resolved, chain)?,
+            None => Profile::from_str(name)?.default_config(),
+        };
+        chain.pop();
+
+        config.name = name.to_string();
+        config.merge_with_manifest(manifest);
+        resolved.insert(name.to_string(), config.clone());
+        Ok(config)
+    }
+
     /// Get profile configuration
     pub fn get(&self, profile: &Profile) -> BuildResult<ProfileConfig> {
         let name = profile.name();
@@ -2,11 +2,19 @@
 //!
 //! Provides sandboxed execution of build scripts (Atlas or shell) with
 //! timeout enforcement, output capture, and permission management.
+//!
+//! Scripts talk back to the build by printing directive lines to stdout:
+//!
+//! - `atlas:env=KEY=VALUE` sets `KEY` for compilation and later scripts
+//! - `atlas:rerun-if-changed=path` reruns the script only when `path` (relative
+//!   to the source directory) changes; without it the script runs every build
 
 use crate::error::{BuildError, BuildResult};
+use crate::fingerprint::{FingerprintDb, ScriptFingerprint};
 use crate::profile::Profile;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
@@ -106,6 +114,48 @@ impl ScriptPhase {
     }
 }
 
+/// Prefix marking a directive line in script stdout
+pub const DIRECTIVE_PREFIX: &str = "atlas:";
+
+/// A structured instruction emitted by a build script
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScriptDirective {
+    /// Set an environment variable for compilation and later scripts
+    Env { key: String, value: String },
+    /// Rerun the script only when this path changes
+    RerunIfChanged(PathBuf),
+}
+
+impl ScriptDirective {
+    /// Parse a single stdout line, returning `None` for ordinary output
+    pub fn parse(line: &str) -> Option<Self> {
+        let directive = line.trim().strip_prefix(DIRECTIVE_PREFIX)?;
+        let (name, argument) = directive.split_once('=')?;
+        match name {
+            "env" => {
+                let (key, value) = argument.split_once('=')?;
+                if key.is_empty() {
+                    return None;
+                }
+                Some(Self::Env {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+            }
+            "rerun-if-changed" if !argument.is_empty() => {
+                Some(Self::RerunIfChanged(PathBuf::from(argument)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Parse every directive line in a script's stdout
+pub fn parse_directives(stdout: &str) -> Vec<ScriptDirective> {
+    stdout.lines().filter_map(ScriptDirective::parse).collect()
+}
+
 /// Script execution context
 #[derive(Debug, Clone)]
 pub struct ScriptContext {
@@ -184,6 +234,10 @@ pub struct ScriptResult {
     pub stderr: String,
     /// Execution time
     pub execution_time: Duration,
+    /// Directives parsed from stdout
+    pub directives: Vec<ScriptDirective>,
+    /// Whether the script was skipped because its watched files are unchanged
+    pub fresh: bool,
 }
 
 impl ScriptResult {
@@ -192,6 +246,22 @@ impl ScriptResult {
         self.exit_code == 0
     }
 
+    /// Environment variables set by `atlas:env` directives
+    pub fn env_vars(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.directives.iter().filter_map(|d| match d {
+            ScriptDirective::Env { key, value } => Some((key.as_str(), value.as_str())),
+            ScriptDirective::RerunIfChanged(_) => None,
+        })
+    }
+
+    /// Paths named by `atlas:rerun-if-changed` directives
+    pub fn rerun_if_changed(&self) -> impl Iterator<Item = &Path> {
+        self.directives.iter().filter_map(|d| match d {
+            ScriptDirective::RerunIfChanged(path) => Some(path.as_path()),
+            ScriptDirective::Env { .. } => None,
+        })
+    }
+
     /// Get combined output
     pub fn output(&self) -> String {
         let mut output = String::new();
@@ -214,6 +284,10 @@ pub struct ScriptExecutor {
     context: ScriptContext,
     /// Verbose output
     verbose: bool,
+    /// Where rerun-if-changed fingerprints persist between builds
+    fingerprint_path: Option<PathBuf>,
+    /// Rerun-if-changed fingerprints, keyed by script name
+    fingerprints: RefCell<FingerprintDb>,
 }
 
 impl ScriptExecutor {
@@ -222,6 +296,8 @@ impl ScriptExecutor {
         Self {
             context,
             verbose: false,
+            fingerprint_path: None,
+            fingerprints: RefCell::new(FingerprintDb::new()),
         }
     }
 
@@ -231,18 +307,48 @@ impl ScriptExecutor {
         self
     }
 
+    /// Persist rerun-if-changed fingerprints at `path` so fresh scripts are skipped
+    pub fn with_fingerprints(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        self.fingerprints = RefCell::new(FingerprintDb::load(&path).unwrap_or_default());
+        self.fingerprint_path = Some(path);
+        self
+    }
+
+    /// Set an environment variable for subsequent scripts
+    pub fn set_env(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.context.env_vars.insert(key.into(), value.into());
+    }
+
+    /// Get the recorded rerun-if-changed fingerprint for a script
+    pub fn script_fingerprint(&self, script_name: &str) -> Option<ScriptFingerprint> {
+        self.fingerprints.borrow().get_script(script_name).cloned()
+    }
+
     /// Execute a build script
     pub fn execute(&self, script: &BuildScript) -> BuildResult<ScriptResult> {
+        if let Some(result) = self.fresh_result(script) {
+            if self.verbose {
+                println!(
+                    "Skipping {} script: {} (fresh)",
+                    script.phase.name(),
+                    script.name
+                );
+            }
+            return Ok(result);
+        }
+
         if self.verbose {
             println!("Running {} script: {}", script.phase.name(), script.name);
         }
 
         let start = Instant::now();
 
-        let result = match &script.script {
+        let mut result = match &script.script {
             ScriptKind::Shell(command) => self.execute_shell(script, command)?,
             ScriptKind::Atlas(path) => self.execute_atlas(script, path)?,
         };
+        result.execution_time = start.elapsed();
 
         if !result.success() {
             return Err(BuildError::ScriptFailed {
@@ -252,17 +358,74 @@ impl ScriptExecutor {
             });
         }
 
+        self.record_fingerprint(script, &result)?;
+
         if self.verbose {
             println!(
                 "Script {} completed in {:.2}s",
                 script.name,
-                start.elapsed().as_secs_f64()
+                result.execution_time.as_secs_f64()
             );
         }
 
         Ok(result)
     }
 
+    /// Replay a script's last run if none of its watched files changed
+    fn fresh_result(&self, script: &BuildScript) -> Option<ScriptResult> {
+        self.fingerprint_path.as_ref()?;
+        let fingerprints = self.fingerprints.borrow();
+        let fingerprint = fingerprints.get_script(&script.name)?;
+        if fingerprint.is_stale(&self.context.source_dir) {
+            return None;
+        }
+
+        let env = fingerprint
+            .env_vars
+            .iter()
+            .map(|(key, value)| ScriptDirective::Env {
+                key: key.clone(),
+                value: value.clone(),
+            });
+        let rerun = fingerprint
+            .watched
+            .keys()
+            .map(|path| ScriptDirective::RerunIfChanged(path.clone()));
+
+        Some(ScriptResult {
+            name: script.name.clone(),
+            exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+            execution_time: Duration::ZERO,
+            directives: env.chain(rerun).collect(),
+            fresh: true,
+        })
+    }
+
+    /// Record (or clear) the rerun-if-changed fingerprint after a run
+    fn record_fingerprint(&self, script: &BuildScript, result: &ScriptResult) -> BuildResult<()> {
+        let mut fingerprints = self.fingerprints.borrow_mut();
+        let watched: Vec<PathBuf> = result.rerun_if_changed().map(Path::to_path_buf).collect();
+        if watched.is_empty() {
+            fingerprints.remove_script(&script.name);
+        } else {
+            let env_vars: BTreeMap<String, String> = result
+                .env_vars()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect();
+            let fingerprint = ScriptFingerprint::new(&self.context.source_dir, &watched, env_vars);
+            fingerprints.insert_script(script.name.clone(), fingerprint);
+        }
+
+        if let Some(path) = &self.fingerprint_path {
+            fingerprints
+                .save(path)
+                .map_err(|e| BuildError::io(path, e))?;
+        }
+        Ok(())
+    }
+
     /// Execute all scripts for a given phase
     pub fn execute_phase(
         &self,
@@ -322,9 +485,11 @@ impl ScriptExecutor {
         Ok(ScriptResult {
             name: script.name.clone(),
             exit_code: output.status.code().unwrap_or(1),
+            directives: parse_directives(&stdout),
             stdout,
             stderr,
-            execution_time: Duration::ZERO, // Placeholder - would track actual time
+            execution_time: Duration::ZERO,
+            fresh: false,
         })
     }
 
@@ -379,9 +544,11 @@ impl ScriptExecutor {
         Ok(ScriptResult {
             name: script.name.clone(),
             exit_code: output.status.code().unwrap_or(1),
+            directives: parse_directives(&stdout),
             stdout,
             stderr,
             execution_time: Duration::ZERO,
+            fresh: false,
         })
     }
 }
@@ -473,6 +640,8 @@ mod tests {
             stdout: "ok".to_string(),
             stderr: String::new(),
             execution_time: Duration::from_secs(1),
+            directives: Vec::new(),
+            fresh: false,
         };
         assert!(result.success());
     }
@@ -485,6 +654,8 @@ mod tests {
             stdout: String::new(),
             stderr: "error".to_string(),
             execution_time: Duration::from_secs(1),
+            directives: Vec::new(),
+            fresh: false,
         };
         assert!(!result.success());
     }
@@ -497,6 +668,8 @@ mod tests {
             stdout: "output line".to_string(),
             stderr: "error line".to_string(),
             execution_time: Duration::from_secs(1),
+            directives: Vec::new(),
+            fresh: false,
         };
         let output = result.output();
         assert!(output.contains("STDOUT:"));
@@ -1,4 +1,6 @@
+use crate::error::{BuildError, BuildResult};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 /// Build target types and artifact management
 use std::path::PathBuf;
 use std::time::Duration;
@@ -16,6 +18,8 @@ pub enum TargetKind {
     Test,
     /// Benchmark suite
     Benchmark,
+    /// WebAssembly module
+    Wasm,
 }
 
 impl TargetKind {
@@ -27,6 +31,7 @@ impl TargetKind {
             Self::Bytecode => "bytecode",
             Self::Test => "test",
             Self::Benchmark => "bench",
+            Self::Wasm => "wasm",
         }
     }
 
@@ -38,12 +43,40 @@ impl TargetKind {
             Self::Bytecode => "atl.bc",
             Self::Test => "atl.bc",
             Self::Benchmark => "atl.bc",
+            Self::Wasm => "wasm",
         }
     }
 
     /// Whether this target requires an entry point (main function)
     pub fn requires_entry_point(&self) -> bool {
-        matches!(self, Self::Binary | Self::Test | Self::Benchmark)
+        matches!(
+            self,
+            Self::Binary | Self::Test | Self::Benchmark | Self::Wasm
+        )
+    }
+
+    /// Get the target triple for kinds that produce native code
+    pub fn target_triple(&self) -> Option<&'static str> {
+        match self {
+            Self::Wasm => Some(crate::wasm::WASM_TARGET_TRIPLE),
+            _ => None,
+        }
+    }
+
+    /// Parse a target kind from its command-line name
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> BuildResult<Self> {
+        match s.to_lowercase().as_str() {
+            "library" | "lib" => Ok(Self::Library),
+            "binary" | "bin" => Ok(Self::Binary),
+            "bytecode" => Ok(Self::Bytecode),
+            "test" => Ok(Self::Test),
+            "benchmark" | "bench" => Ok(Self::Benchmark),
+            "wasm" | "wasm32" => Ok(Self::Wasm),
+            other => Err(BuildError::TargetNotFound {
+                target: other.to_string(),
+            }),
+        }
     }
 }
 
@@ -55,6 +88,7 @@ impl std::fmt::Display for TargetKind {
             Self::Bytecode => write!(f, "bytecode"),
             Self::Test => write!(f, "test"),
             Self::Benchmark => write!(f, "benchmark"),
+            Self::Wasm => write!(f, "wasm"),
         }
     }
 }
@@ -179,6 +213,15 @@ pub struct ArtifactMetadata {
     /// Build timestamp
     #[serde(with = "serde_millis")]
     pub build_time: std::time::SystemTime,
+    /// Target triple for native outputs (e.g. `wasm32-unknown-unknown`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_triple: Option<String>,
+    /// SHA-256 of the emitted artifact bytes
+    #[serde(default)]
+    pub content_digest: String,
+    /// SHA-256 of the normalized build inputs (see [`Builder::verify_reproducible`](crate::Builder::verify_reproducible))
+    #[serde(default)]
+    pub inputs_digest: String,
 }
 
 impl ArtifactMetadata {
@@ -190,8 +233,29 @@ impl ArtifactMetadata {
             bytecode_size,
             atlas_version: env!("CARGO_PKG_VERSION").to_string(),
             build_time: std::time::SystemTime::now(),
+            target_triple: None,
+            content_digest: String::new(),
+            inputs_digest: String::new(),
         }
     }
+
+    /// Record the target triple
+    pub fn with_target_triple(mut self, triple: impl Into<String>) -> Self {
+        self.target_triple = Some(triple.into());
+        self
+    }
+
+    /// Record the digest of the emitted artifact bytes
+    pub fn with_content_digest(mut self, artifact: &[u8]) -> Self {
+        self.content_digest = format!("{:x}", Sha256::digest(artifact));
+        self
+    }
+
+    /// Record the digest of the inputs the artifact was built from
+    pub fn with_inputs_digest(mut self, digest: impl Into<String>) -> Self {
+        self.inputs_digest = digest.into();
+        self
+    }
 }
 
 /// Helper module for serde SystemTime serialization
@@ -336,6 +400,29 @@ mod tests {
         assert_eq!(TargetKind::Library.file_extension(), "atl.bc");
         assert_eq!(TargetKind::Binary.file_extension(), "atl.bc");
         assert_eq!(TargetKind::Bytecode.file_extension(), "atl.bc");
+        assert_eq!(TargetKind::Wasm.file_extension(), "wasm");
+    }
+
+    #[test]
+    fn test_wasm_target_kind() {
+        assert_eq!(TargetKind::Wasm.output_dir_name(), "wasm");
+        assert!(TargetKind::Wasm.requires_entry_point());
+        assert_eq!(
+            TargetKind::Wasm.target_triple(),
+            Some("wasm32-unknown-unknown")
+        );
+        assert_eq!(TargetKind::Binary.target_triple(), None);
+
+        let target = BuildTarget::new("app", TargetKind::Wasm);
+        assert_eq!(target.output_filename(), "app.wasm");
+    }
+
+    #[test]
+    fn test_target_kind_from_str() {
+        assert_eq!(TargetKind::from_str("wasm").unwrap(), TargetKind::Wasm);
+        assert_eq!(TargetKind::from_str("BIN").unwrap(), TargetKind::Binary);
+        assert_eq!(TargetKind::from_str("lib").unwrap(), TargetKind::Library);
+        assert!(TargetKind::from_str("x86").is_err());
     }
 
     #[test]
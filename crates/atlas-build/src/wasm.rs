@@ -0,0 +1,253 @@
+//! WebAssembly artifact emission
+//!
+//! Lowers a linked program to a `.wasm` module. The linked bytecode of every
+//! module is placed in linear memory by an active data segment at offset 0. The
+//! module imports `atlas.atlas_run(ptr: i32, len: i32) -> i32` from the host and
+//! exports `memory` and a `main` function of type `() -> i32`. `main` calls
+//! `atlas_run` with the bytecode's address and length and returns its result
+//! as the program's exit code, so the host supplies the Atlas VM.
+
+/// WebAssembly binary magic number (`\0asm`)
+pub const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+
+/// WebAssembly binary format version
+pub const WASM_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+/// Target triple recorded for wasm artifacts
+pub const WASM_TARGET_TRIPLE: &str = "wasm32-unknown-unknown";
+
+/// Import module of the host entry point
+pub const HOST_MODULE: &str = "atlas";
+
+/// Host function that runs bytecode: `(ptr: i32, len: i32) -> i32`
+pub const HOST_RUN: &str = "atlas_run";
+
+/// Linear memory offset of the embedded bytecode
+pub const BYTECODE_OFFSET: u32 = 0;
+
+/// Size of a wasm memory page in bytes
+const PAGE_SIZE: usize = 65536;
+
+// Section ids
+const SECTION_TYPE: u8 = 1;
+const SECTION_IMPORT: u8 = 2;
+const SECTION_FUNCTION: u8 = 3;
+const SECTION_MEMORY: u8 = 5;
+const SECTION_EXPORT: u8 = 7;
+const SECTION_CODE: u8 = 10;
+const SECTION_DATA: u8 = 11;
+
+// Encoding bytes
+const FUNC_TYPE: u8 = 0x60;
+const VALTYPE_I32: u8 = 0x7f;
+const EXTERNAL_FUNC: u8 = 0x00;
+const EXTERNAL_MEMORY: u8 = 0x02;
+const LIMITS_MIN: u8 = 0x00;
+const DATA_ACTIVE: u8 = 0x00;
+const OP_CALL: u8 = 0x10;
+const OP_I32_CONST: u8 = 0x41;
+const OP_END: u8 = 0x0b;
+
+// Indices (the imported function comes before defined ones)
+const TYPE_HOST_RUN: u32 = 0;
+const TYPE_MAIN: u32 = 1;
+const FUNC_HOST_RUN: u32 = 0;
+const FUNC_MAIN: u32 = 1;
+
+/// Emit a wasm module whose `main` runs `bytecode` through the host
+pub fn emit_module(bytecode: &[u8]) -> Vec<u8> {
+    let mut module = Vec::new();
+    module.extend_from_slice(&WASM_MAGIC);
+    module.extend_from_slice(&WASM_VERSION);
+
+    // Type 0: (i32, i32) -> i32, type 1: () -> i32
+    let mut types = Vec::new();
+    write_u32(&mut types, 2);
+    types.extend_from_slice(&[FUNC_TYPE, 0x02, VALTYPE_I32, VALTYPE_I32, 0x01, VALTYPE_I32]);
+    types.extend_from_slice(&[FUNC_TYPE, 0x00, 0x01, VALTYPE_I32]);
+    write_section(&mut module, SECTION_TYPE, &types);
+
+    // Function 0 is the host's `atlas_run`
+    let mut imports = Vec::new();
+    write_u32(&mut imports, 1);
+    write_name(&mut imports, HOST_MODULE);
+    write_name(&mut imports, HOST_RUN);
+    imports.push(EXTERNAL_FUNC);
+    write_u32(&mut imports, TYPE_HOST_RUN);
+    write_section(&mut module, SECTION_IMPORT, &imports);
+
+    // Function 1 (`main`) has type 1
+    let mut functions = Vec::new();
+    write_u32(&mut functions, 1);
+    write_u32(&mut functions, TYPE_MAIN);
+    write_section(&mut module, SECTION_FUNCTION, &functions);
+
+    // One memory large enough for the bytecode
+    let end = BYTECODE_OFFSET as usize + bytecode.len();
+    let pages = end.div_ceil(PAGE_SIZE).max(1);
+    let mut memories = Vec::new();
+    write_u32(&mut memories, 1);
+    memories.push(LIMITS_MIN);
+    write_u32(&mut memories, pages as u32);
+    write_section(&mut module, SECTION_MEMORY, &memories);
+
+    // Export `main` and the memory the host reads the bytecode from
+    let mut exports = Vec::new();
+    write_u32(&mut exports, 2);
+    write_name(&mut exports, "main");
+    exports.push(EXTERNAL_FUNC);
+    write_u32(&mut exports, FUNC_MAIN);
+    write_name(&mut exports, "memory");
+    exports.push(EXTERNAL_MEMORY);
+    write_u32(&mut exports, 0);
+    write_section(&mut module, SECTION_EXPORT, &exports);
+
+    // Body: no locals, `atlas_run(BYTECODE_OFFSET, len)`, `end`
+    let mut body = Vec::new();
+    write_u32(&mut body, 0);
+    body.push(OP_I32_CONST);
+    write_i32(&mut body, BYTECODE_OFFSET as i32);
+    body.push(OP_I32_CONST);
+    write_i32(&mut body, bytecode.len() as i32);
+    body.push(OP_CALL);
+    write_u32(&mut body, FUNC_HOST_RUN);
+    body.push(OP_END);
+
+    let mut code = Vec::new();
+    write_u32(&mut code, 1);
+    write_u32(&mut code, body.len() as u32);
+    code.extend_from_slice(&body);
+    write_section(&mut module, SECTION_CODE, &code);
+
+    // Bytecode placed in memory 0 at BYTECODE_OFFSET
+    let mut data = Vec::new();
+    write_u32(&mut data, 1);
+    data.push(DATA_ACTIVE);
+    data.push(OP_I32_CONST);
+    write_i32(&mut data, BYTECODE_OFFSET as i32);
+    data.push(OP_END);
+    write_u32(&mut data, bytecode.len() as u32);
+    data.extend_from_slice(bytecode);
+    write_section(&mut module, SECTION_DATA, &data);
+
+    module
+}
+
+/// Check if `bytes` start with the wasm magic number and version
+pub fn is_wasm_module(bytes: &[u8]) -> bool {
+    bytes.len() >= 8 && bytes[..4] == WASM_MAGIC && bytes[4..8] == WASM_VERSION
+}
+
+/// Write a section with its id and length prefix
+fn write_section(out: &mut Vec<u8>, id: u8, contents: &[u8]) {
+    out.push(id);
+    write_u32(out, contents.len() as u32);
+    out.extend_from_slice(contents);
+}
+
+/// Write a length-prefixed UTF-8 name
+fn write_name(out: &mut Vec<u8>, name: &str) {
+    write_u32(out, name.len() as u32);
+    out.extend_from_slice(name.as_bytes());
+}
+
+/// Write an unsigned LEB128 integer
+fn write_u32(out: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Write a signed LEB128 integer
+fn write_i32(out: &mut Vec<u8>, mut value: i32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leb_u32(value: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_u32(&mut out, value);
+        out
+    }
+
+    fn leb_i32(value: i32) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_i32(&mut out, value);
+        out
+    }
+
+    #[test]
+    fn test_unsigned_leb128() {
+        assert_eq!(leb_u32(0), vec![0x00]);
+        assert_eq!(leb_u32(127), vec![0x7f]);
+        assert_eq!(leb_u32(128), vec![0x80, 0x01]);
+        assert_eq!(leb_u32(624485), vec![0xe5, 0x8e, 0x26]);
+    }
+
+    #[test]
+    fn test_signed_leb128() {
+        assert_eq!(leb_i32(0), vec![0x00]);
+        assert_eq!(leb_i32(63), vec![0x3f]);
+        assert_eq!(leb_i32(64), vec![0xc0, 0x00]);
+        assert_eq!(leb_i32(-1), vec![0x7f]);
+        assert_eq!(leb_i32(-123456), vec![0xc0, 0xbb, 0x78]);
+    }
+
+    #[test]
+    fn test_emit_module_header() {
+        let module = emit_module(&[]);
+        assert!(is_wasm_module(&module));
+        assert!(!is_wasm_module(b"ATB\0"));
+    }
+
+    #[test]
+    fn test_emit_module_embeds_bytecode() {
+        let module = emit_module(b"ATB\0payload");
+        assert!(module.ends_with(b"ATB\0payload"));
+    }
+
+    #[test]
+    fn test_emit_module_main_calls_host_run() {
+        let module = emit_module(b"ATB\0payload");
+        for name in [HOST_MODULE, HOST_RUN, "main", "memory"] {
+            assert!(module.windows(name.len()).any(|w| w == name.as_bytes()));
+        }
+
+        // main: i32.const 0, i32.const 11, call 0, end
+        let body = [
+            OP_I32_CONST,
+            0x00,
+            OP_I32_CONST,
+            0x0b,
+            OP_CALL,
+            0x00,
+            OP_END,
+        ];
+        assert!(module.windows(body.len()).any(|w| w == body));
+    }
+
+    #[test]
+    fn test_emit_module_sizes_memory_for_bytecode() {
+        let module = emit_module(&vec![0u8; PAGE_SIZE + 1]);
+        let memory = [SECTION_MEMORY, 0x03, 0x01, LIMITS_MIN, 0x02];
+        assert!(module.windows(memory.len()).any(|w| w == memory));
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Tests the complete build pipeline with real Atlas projects
 
-use atlas_build::{Builder, OptLevel};
+use atlas_build::{BuildError, Builder, EventStream, OptLevel};
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use tempfile::TempDir;
 
 /// Create a test project with the given structure
@@ -151,6 +153,84 @@ fn test_build_with_optimization() {
     assert!(result.is_ok(), "Optimized build should succeed");
 }
 
+#[test]
+fn test_build_wasm_target() {
+    let (_temp, project_path) = create_test_project(&[(
+        "src/main.atlas",
+        r#"fn main() -> void {
+    let x: number = 42;
+    print(x);
+}"#,
+    )]);
+
+    let mut builder = make_builder(&project_path).with_target(atlas_build::TargetKind::Wasm);
+    let context = builder.build().expect("wasm build should succeed");
+
+    assert_eq!(context.artifacts.len(), 1);
+    let artifact = &context.artifacts[0];
+    assert_eq!(artifact.target.kind, atlas_build::TargetKind::Wasm);
+    assert!(artifact.size() > 8);
+    assert_eq!(&artifact.bytecode[..4], b"\0asm");
+    assert_eq!(
+        artifact.metadata.target_triple.as_deref(),
+        Some("wasm32-unknown-unknown")
+    );
+
+    assert_eq!(
+        artifact.output_path.extension().and_then(|e| e.to_str()),
+        Some("wasm")
+    );
+    let written = fs::read(&artifact.output_path).unwrap();
+    assert_eq!(written, artifact.bytecode);
+}
+
+#[test]
+fn test_build_wasm_target_embeds_every_module() {
+    let (_temp, project_path) = create_test_project(&[
+        (
+            "src/main.atlas",
+            r#"import { greet } from "greeting";
+
+fn main() -> void {
+    print("entry-module-marker");
+    print(greet());
+}"#,
+        ),
+        (
+            "src/greeting.atlas",
+            r#"export fn greet() -> string {
+    return "imported-module-marker";
+}"#,
+        ),
+    ]);
+
+    let mut builder = make_builder(&project_path).with_target(atlas_build::TargetKind::Wasm);
+    let context = builder.build().expect("wasm build should succeed");
+    assert_eq!(context.stats.total_modules, 2);
+
+    let wasm = &context.artifacts[0].bytecode;
+    for marker in [&b"entry-module-marker"[..], b"imported-module-marker"] {
+        assert!(
+            wasm.windows(marker.len()).any(|w| w == marker),
+            "wasm artifact should embed {}",
+            String::from_utf8_lossy(marker)
+        );
+    }
+}
+
+#[test]
+fn test_build_wasm_target_requires_main() {
+    let (_temp, project_path) = create_test_project(&[(
+        "src/lib.atlas",
+        r#"export fn one() -> number {
+    return 1;
+}"#,
+    )]);
+
+    let mut builder = make_builder(&project_path).with_target(atlas_build::TargetKind::Wasm);
+    assert!(builder.build().is_err());
+}
+
 #[test]
 fn test_build_error_missing_src_dir() {
     let temp = tempfile::tempdir().unwrap();
@@ -771,3 +851,254 @@ export fn level_b() -> number {
         result
     );
 }
+
+// ============================================================
+// Parallel compilation
+// ============================================================
+
+/// main imports b and c, which both import d
+fn create_diamond_project() -> (TempDir, String) {
+    create_test_project(&[
+        (
+            "src/main.atlas",
+            r#"import { from_b } from "b";
+import { from_c } from "c";
+
+fn main() -> void {
+    print(from_b() + from_c());
+}"#,
+        ),
+        (
+            "src/b.atlas",
+            r#"import { from_d } from "d";
+
+export fn from_b() -> number {
+    return from_d() + 1;
+}"#,
+        ),
+        (
+            "src/c.atlas",
+            r#"import { from_d } from "d";
+
+export fn from_c() -> number {
+    return from_d() + 2;
+}"#,
+        ),
+        (
+            "src/d.atlas",
+            r#"export fn from_d() -> number {
+    return 40;
+}"#,
+        ),
+    ])
+}
+
+#[test]
+fn test_parallel_diamond_compiles_middle_concurrently() {
+    let (_temp, project_path) = create_diamond_project();
+
+    let mut builder = make_builder(&project_path).with_jobs(4);
+    let context = builder.build().expect("Diamond build should succeed");
+    let stats = &context.stats;
+
+    // b and c become ready together once d finishes
+    assert_eq!(stats.peak_parallelism, 2);
+    assert_eq!(stats.module_timings.len(), 4);
+
+    let timing = |name: &str| stats.module_timings[name];
+    assert!(timing("b").start >= timing("d").end());
+    assert!(timing("c").start >= timing("d").end());
+    assert!(timing("main").start >= timing("b").end());
+    assert!(timing("main").start >= timing("c").end());
+}
+
+#[test]
+fn test_single_job_compiles_sequentially() {
+    let (_temp, project_path) = create_diamond_project();
+
+    let mut builder = make_builder(&project_path).with_jobs(1);
+    let context = builder.build().expect("Diamond build should succeed");
+
+    assert_eq!(context.stats.peak_parallelism, 1);
+    assert_eq!(context.stats.compiled_modules, 4);
+}
+
+#[test]
+fn test_parallel_build_reports_first_error() {
+    let (_temp, project_path) = create_test_project(&[
+        (
+            "src/main.atlas",
+            r#"import { ok } from "good";
+import { broken } from "bad";
+
+fn main() -> void {
+    print(ok());
+}"#,
+        ),
+        (
+            "src/good.atlas",
+            r#"export fn ok() -> number {
+    return 1;
+}"#,
+        ),
+        (
+            "src/bad.atlas",
+            r#"export fn broken() -> number {
+    return "not a number";
+}"#,
+        ),
+    ]);
+
+    let mut builder = make_builder(&project_path).with_jobs(4);
+    assert!(builder.build().is_err());
+}
+
+/// Writer that keeps everything written to it for later inspection
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SharedBuffer {
+    fn events(&self) -> Vec<serde_json::Value> {
+        let bytes = self.0.lock().unwrap();
+        String::from_utf8_lossy(&bytes)
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+            .collect()
+    }
+}
+
+#[test]
+fn test_json_event_stream_for_two_module_build() {
+    let (_temp, project_path) = create_test_project(&[
+        (
+            "src/main.atlas",
+            r#"import { add } from "lib";
+
+fn main() -> void {
+    print(add(1, 2));
+}"#,
+        ),
+        (
+            "src/lib.atlas",
+            r#"export fn add(a: number, b: number) -> number {
+    return a + b;
+}"#,
+        ),
+    ]);
+
+    let buffer = SharedBuffer::default();
+    let mut builder =
+        make_builder(&project_path).with_event_stream(EventStream::new(buffer.clone()));
+    builder.build().expect("Build should succeed");
+
+    let events = buffer.events();
+    let position = |event: &str, unit: &str| {
+        events
+            .iter()
+            .position(|e| e["event"] == event && e["unit"] == unit)
+            .unwrap_or_else(|| panic!("missing {} for {}", event, unit))
+    };
+
+    for unit in ["lib", "main"] {
+        assert!(position("compile-started", unit) < position("compile-finished", unit));
+    }
+    // main imports lib, so lib must finish first
+    assert!(position("compile-finished", "lib") < position("compile-started", "main"));
+
+    assert!(events.iter().any(|e| e["event"] == "artifact"));
+    assert!(events.iter().all(|e| e["timestamp_ms"].is_u64()));
+
+    let summary = events.last().unwrap();
+    assert_eq!(summary["event"], "summary");
+    assert_eq!(summary["success"], true);
+    assert_eq!(summary["modules"], 2);
+}
+
+// ============================================================
+// Reproducible builds
+// ============================================================
+
+const REPRODUCIBLE_PROJECT: &[(&str, &str)] = &[
+    (
+        "src/main.atlas",
+        r#"import { add } from "math";
+import { greet } from "text";
+
+fn main() -> void {
+    print(greet("atlas"));
+    print(add(1, 2));
+}"#,
+    ),
+    (
+        "src/math.atlas",
+        r#"export fn add(a: number, b: number) -> number {
+    return a + b;
+}"#,
+    ),
+    (
+        "src/text.atlas",
+        r#"export fn greet(name: string) -> string {
+    return "hello " + name;
+}"#,
+    ),
+];
+
+#[test]
+fn test_same_project_builds_reproducibly() {
+    let (_first, first_path) = create_test_project(REPRODUCIBLE_PROJECT);
+    let (_second, second_path) = create_test_project(REPRODUCIBLE_PROJECT);
+
+    let run1 = make_builder(&first_path).build().expect("first build");
+    let run2 = make_builder(&second_path).build().expect("second build");
+    // Rebuilding in place must not pick up anything from the previous run
+    let run3 = make_builder(&first_path).build().expect("rebuild");
+
+    let digests = |ctx: &atlas_build::BuildContext| {
+        ctx.artifacts
+            .iter()
+            .map(|a| {
+                (
+                    a.metadata.content_digest.clone(),
+                    a.metadata.inputs_digest.clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert!(!run1.artifacts.is_empty());
+    assert!(digests(&run1)
+        .iter()
+        .all(|(c, i)| c.len() == 64 && i.len() == 64));
+    assert_eq!(digests(&run1), digests(&run2));
+    assert_eq!(digests(&run1), digests(&run3));
+
+    Builder::verify_reproducible(&run1, &run2).expect("builds should match");
+    Builder::verify_reproducible(&run1, &run3).expect("rebuild should match");
+}
+
+#[test]
+fn test_verify_reproducible_reports_changed_inputs() {
+    let (_temp, project_path) = create_test_project(REPRODUCIBLE_PROJECT);
+    let run1 = make_builder(&project_path).build().expect("first build");
+
+    fs::write(
+        PathBuf::from(&project_path).join("src/math.atlas"),
+        "export fn add(a: number, b: number) -> number {\n    return b + a;\n}",
+    )
+    .unwrap();
+    let run2 = make_builder(&project_path).build().expect("second build");
+
+    let err = Builder::verify_reproducible(&run1, &run2).unwrap_err();
+    assert!(matches!(err, BuildError::NotReproducible(_)), "{:?}", err);
+    assert!(err.to_string().contains("different inputs"), "{}", err);
+}
@@ -11,9 +11,10 @@ use atlas_build::fingerprint::{
 };
 use atlas_build::incremental::BuildState;
 use atlas_build::{
-    Builder, FingerprintConfig, FingerprintDb, IncrementalEngine, IncrementalPlan, OptLevel,
-    RecompileReason,
+    BuildCache, Builder, FingerprintConfig, FingerprintDb, IncrementalEngine, IncrementalPlan,
+    OptLevel, RecompileReason,
 };
+use atlas_runtime::{Bytecode, SecurityContext, Value, VM};
 use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
@@ -173,6 +174,80 @@ fn test_change_lib_not_main() {
     assert_eq!(ctx.stats.total_modules, 2);
 }
 
+const TWO_FUNCTIONS: &str = r#"fn a() -> number { return 1; }
+fn b() -> number { return 2; }
+a() + b();
+"#;
+
+/// Run the single-module artifact of a build and return its result
+fn run_artifact(ctx: &atlas_build::BuildContext) -> Option<Value> {
+    let bytecode = Bytecode::from_bytes(&ctx.artifacts[0].bytecode).unwrap();
+    VM::new(bytecode)
+        .run(&SecurityContext::allow_all())
+        .unwrap()
+}
+
+#[test]
+fn test_change_one_function_recompiles_only_that_function() {
+    let (temp, path) = create_test_project(&[("src/main.atlas", TWO_FUNCTIONS)]);
+    let mut builder = make_builder(&path);
+    let ctx = builder.build_incremental().unwrap();
+    assert_eq!(ctx.incremental.unwrap().functions_recompiled, 2);
+
+    fs::write(
+        temp.path().join("src/main.atlas"),
+        TWO_FUNCTIONS.replace("return 2;", "return 5;"),
+    )
+    .unwrap();
+
+    let ctx = builder.build_incremental().unwrap();
+    let stats = ctx.incremental.clone().unwrap();
+    assert_eq!(stats.recompiled, 1);
+    assert_eq!(stats.functions_recompiled, 1);
+    assert_eq!(run_artifact(&ctx), Some(Value::Number(6.0)));
+}
+
+#[test]
+fn test_plan_reports_function_changed() {
+    let (temp, path) = create_test_project(&[("src/main.atlas", TWO_FUNCTIONS)]);
+    make_builder(&path).build_incremental().unwrap();
+
+    let source = TWO_FUNCTIONS.replace("return 1;", "return 7;");
+    fs::write(temp.path().join("src/main.atlas"), &source).unwrap();
+
+    let mut graph = BuildGraph::new();
+    graph.add_module(ModuleNode::new("main", temp.path().join("src/main.atlas")));
+    let engine = IncrementalEngine::new(
+        temp.path().join("target/debug/incremental"),
+        FingerprintConfig::default(),
+    );
+    let plan = engine.plan(&graph).unwrap();
+    assert_eq!(
+        plan.reasons.get("main"),
+        Some(&RecompileReason::FunctionChanged {
+            name: "a".to_string()
+        })
+    );
+    assert_eq!(plan.changed_functions["main"], vec!["a".to_string()]);
+}
+
+#[test]
+fn test_top_level_change_recompiles_whole_module() {
+    let (temp, path) = create_test_project(&[("src/main.atlas", TWO_FUNCTIONS)]);
+    let mut builder = make_builder(&path);
+    builder.build_incremental().unwrap();
+
+    fs::write(
+        temp.path().join("src/main.atlas"),
+        TWO_FUNCTIONS.replace("a() + b();", "a() * b();"),
+    )
+    .unwrap();
+
+    let ctx = builder.build_incremental().unwrap();
+    assert_eq!(ctx.incremental.as_ref().unwrap().functions_recompiled, 2);
+    assert_eq!(run_artifact(&ctx), Some(Value::Number(2.0)));
+}
+
 // ─── 4. Dependency change propagation ───
 
 #[test]
@@ -180,18 +255,16 @@ fn test_dependency_change_propagates() {
     let dir = tempfile::tempdir().unwrap();
     let a_path = dir.path().join("a.atlas");
     let b_path = dir.path().join("b.atlas");
-    fs::write(&a_path, "fn a() {}").unwrap();
+    fs::write(&a_path, "export fn a() {}").unwrap();
     fs::write(&b_path, "fn b() {}").unwrap();
 
     let mut engine = IncrementalEngine::new_empty(dir.path().to_path_buf());
-    engine.record_compilation("a", &a_path, "fn a() {}", BTreeMap::new());
-
-    let mut dep_hashes = BTreeMap::new();
-    dep_hashes.insert("a".to_string(), compute_hash("fn a() {}"));
+    engine.record_compilation("a", &a_path, "export fn a() {}", BTreeMap::new());
+    let dep_hashes = engine.dependency_hashes(&["a".to_string()]);
     engine.record_compilation("b", &b_path, "fn b() {}", dep_hashes);
 
-    // Change a
-    fs::write(&a_path, "fn a() { 42 }").unwrap();
+    // Change a's exported signature
+    fs::write(&a_path, "export fn a(x: number) {}").unwrap();
 
     let mut graph = BuildGraph::new();
     graph.add_module(ModuleNode::new("a", a_path));
@@ -199,10 +272,93 @@ fn test_dependency_change_propagates() {
 
     let plan = engine.plan(&graph).unwrap();
     assert!(plan.recompile.contains(&"a".to_string()));
-    // b should also be invalidated because its dep hash changed
+    // b should also be invalidated because a's interface changed
     assert!(plan.recompile.contains(&"b".to_string()));
 }
 
+const LIB_WITH_PRIVATE_HELPER: &str = r#"fn scale(x: number) -> number { return x * 10; }
+export fn helper() -> number { return scale(1); }
+"#;
+
+const MAIN_USING_HELPER: &str = r#"import { helper } from "lib";
+let value = helper();
+"#;
+
+/// Plan a rebuild of the two-module lib/main project after its last build
+fn plan_lib_and_main(root: &std::path::Path) -> atlas_build::IncrementalPlan {
+    let mut graph = BuildGraph::new();
+    graph.add_module(ModuleNode::new("lib", root.join("src/lib.atlas")));
+    graph.add_module(
+        ModuleNode::new("main", root.join("src/main.atlas"))
+            .with_dependencies(vec!["lib".to_string()]),
+    );
+    let engine = IncrementalEngine::new(
+        root.join("target/debug/incremental"),
+        FingerprintConfig::default(),
+    );
+    engine.plan(&graph).unwrap()
+}
+
+#[test]
+fn test_private_change_does_not_recompile_dependents() {
+    let (temp, path) = create_test_project(&[
+        ("src/lib.atlas", LIB_WITH_PRIVATE_HELPER),
+        ("src/main.atlas", MAIN_USING_HELPER),
+    ]);
+    let mut builder = make_builder(&path);
+    builder.build_incremental().unwrap();
+
+    fs::write(
+        temp.path().join("src/lib.atlas"),
+        LIB_WITH_PRIVATE_HELPER.replace("x * 10", "x * 100 + 0"),
+    )
+    .unwrap();
+
+    let plan = plan_lib_and_main(temp.path());
+    assert_eq!(plan.recompile, vec!["lib".to_string()]);
+    assert_eq!(plan.cached, vec!["main".to_string()]);
+    assert!(!matches!(
+        plan.reasons.get("lib"),
+        Some(RecompileReason::InterfaceChanged)
+    ));
+
+    let ctx = builder.build_incremental().unwrap();
+    assert_eq!(ctx.incremental.unwrap().recompiled, 1);
+}
+
+#[test]
+fn test_exported_signature_change_recompiles_dependents() {
+    let (temp, path) = create_test_project(&[
+        ("src/lib.atlas", LIB_WITH_PRIVATE_HELPER),
+        ("src/main.atlas", MAIN_USING_HELPER),
+    ]);
+    let mut builder = make_builder(&path);
+    builder.build_incremental().unwrap();
+
+    fs::write(
+        temp.path().join("src/lib.atlas"),
+        LIB_WITH_PRIVATE_HELPER.replace(
+            "export fn helper() -> number { return scale(1); }",
+            "export fn helper() -> string { return \"x\"; }",
+        ),
+    )
+    .unwrap();
+
+    let plan = plan_lib_and_main(temp.path());
+    assert_eq!(plan.recompile.len(), 2);
+    assert_eq!(
+        plan.reasons.get("lib"),
+        Some(&RecompileReason::InterfaceChanged)
+    );
+    assert_eq!(
+        plan.reasons.get("main"),
+        Some(&RecompileReason::DependencyChanged("lib".to_string()))
+    );
+
+    let ctx = builder.build_incremental().unwrap();
+    assert_eq!(ctx.incremental.unwrap().recompiled, 2);
+}
+
 // ─── 5. Fingerprint detection ───
 
 #[test]
@@ -323,6 +479,72 @@ fn test_cache_miss_on_changed_content() {
     assert!(plan.recompile.contains(&"main".to_string()));
 }
 
+#[test]
+fn test_shared_cache_reused_across_projects() {
+    let shared_dir = tempfile::tempdir().unwrap();
+    let files = [("src/main.atlas", simple_main())];
+    let (_first, first_path) = create_test_project(&files);
+    let (_second, second_path) = create_test_project(&files);
+
+    let first = make_builder(&first_path)
+        .with_shared_cache(shared_dir.path())
+        .build_incremental()
+        .unwrap();
+    assert_eq!(first.incremental.unwrap().shared_cache_hits, 0);
+    assert_eq!(
+        BuildCache::shared(shared_dir.path())
+            .unwrap()
+            .stats()
+            .cache_entries,
+        1
+    );
+
+    let second = make_builder(&second_path)
+        .with_shared_cache(shared_dir.path())
+        .build_incremental()
+        .unwrap();
+    assert_eq!(second.incremental.unwrap().shared_cache_hits, 1);
+    assert!(!second.artifacts.is_empty());
+
+    let stats = BuildCache::shared(shared_dir.path()).unwrap().stats();
+    assert_eq!(stats.cache_entries, 1);
+    assert_eq!(stats.cross_project_hits, 1);
+}
+
+// ─── Build plans ───
+
+#[test]
+fn test_build_plan_unchanged_project_all_cache_hits() {
+    let (_temp, path) = create_test_project(&[
+        ("src/main.atlas", simple_main()),
+        ("src/lib.atlas", simple_lib()),
+    ]);
+    let mut builder = make_builder(&path);
+    builder.build_incremental().unwrap();
+
+    let plan = builder.compute_plan().unwrap();
+    assert_eq!(plan.units.len(), 2);
+    assert!(plan.is_fresh());
+    assert!(plan.units.iter().all(|unit| unit.reason.is_none()));
+    assert!(plan.to_json().unwrap().contains("\"cache_hit\": true"));
+}
+
+#[test]
+fn test_build_plan_cold_project_needs_rebuild() {
+    let (_temp, path) = create_test_project(&[("src/main.atlas", simple_main())]);
+    let builder = make_builder(&path);
+
+    let plan = builder.compute_plan().unwrap();
+    assert_eq!(plan.units.len(), 1);
+    let unit = &plan.units[0];
+    assert_eq!(unit.module, "main");
+    assert!(!unit.cache_hit);
+    assert_eq!(unit.reason, Some(RecompileReason::NoPreviousFingerprint));
+    assert!(!unit.targets.is_empty());
+    // Planning never writes build output
+    assert!(!PathBuf::from(&path).join("target").exists());
+}
+
 // ─── 8. Parallel incremental build (recompile groups) ───
 
 #[test]
@@ -585,7 +807,7 @@ fn test_engine_plan_after_content_change() {
     let mut engine = IncrementalEngine::new_empty(dir.path().to_path_buf());
     engine.record_compilation("main", &src, "fn main() {}", BTreeMap::new());
 
-    fs::write(&src, "fn main() { 42 }").unwrap();
+    fs::write(&src, "fn main() {}\nmain();").unwrap();
 
     let mut graph = BuildGraph::new();
     graph.add_module(ModuleNode::new("main", src));
@@ -594,7 +816,7 @@ fn test_engine_plan_after_content_change() {
     assert_eq!(plan.recompile.len(), 1);
     assert!(matches!(
         plan.reasons.get("main"),
-        Some(RecompileReason::SourceChanged)
+        Some(RecompileReason::BodyChanged)
     ));
 }
 
@@ -659,6 +881,7 @@ fn test_plan_has_work() {
         recompile: vec!["a".to_string()],
         cached: vec![],
         reasons: Default::default(),
+        changed_functions: Default::default(),
         total_modules: 1,
     };
     assert!(plan.has_work());
@@ -670,6 +893,7 @@ fn test_plan_no_work() {
         recompile: vec![],
         cached: vec!["a".to_string()],
         reasons: Default::default(),
+        changed_functions: Default::default(),
         total_modules: 1,
     };
     assert!(!plan.has_work());
@@ -681,6 +905,7 @@ fn test_plan_recompile_ratio_full() {
         recompile: vec!["a".to_string(), "b".to_string()],
         cached: vec![],
         reasons: Default::default(),
+        changed_functions: Default::default(),
         total_modules: 2,
     };
     assert_eq!(plan.recompile_ratio(), 1.0);
@@ -692,6 +917,7 @@ fn test_plan_recompile_ratio_half() {
         recompile: vec!["a".to_string()],
         cached: vec!["b".to_string()],
         reasons: Default::default(),
+        changed_functions: Default::default(),
         total_modules: 2,
     };
     assert_eq!(plan.recompile_ratio(), 0.5);
@@ -703,6 +929,7 @@ fn test_plan_recompile_ratio_zero() {
         recompile: vec![],
         cached: vec!["a".to_string()],
         reasons: Default::default(),
+        changed_functions: Default::default(),
         total_modules: 1,
     };
     assert_eq!(plan.recompile_ratio(), 0.0);
@@ -714,6 +941,7 @@ fn test_plan_recompile_ratio_empty() {
         recompile: vec![],
         cached: vec![],
         reasons: Default::default(),
+        changed_functions: Default::default(),
         total_modules: 0,
     };
     assert_eq!(plan.recompile_ratio(), 0.0);
@@ -856,23 +1084,20 @@ fn test_platform_info_current() {
 
 #[test]
 fn test_recompile_reason_source_changed() {
-    assert_eq!(
-        RecompileReason::SourceChanged,
-        RecompileReason::SourceChanged
-    );
+    assert_eq!(RecompileReason::BodyChanged, RecompileReason::BodyChanged);
 }
 
 #[test]
 fn test_recompile_reason_dep_changed() {
     let r = RecompileReason::DependencyChanged("lib".to_string());
     assert_eq!(r, RecompileReason::DependencyChanged("lib".to_string()));
-    assert_ne!(r, RecompileReason::SourceChanged);
+    assert_ne!(r, RecompileReason::BodyChanged);
 }
 
 #[test]
 fn test_recompile_reason_no_previous() {
     assert_ne!(
         RecompileReason::NoPreviousFingerprint,
-        RecompileReason::SourceChanged
+        RecompileReason::BodyChanged
     );
 }
@@ -1,7 +1,8 @@
 //! Build profile tests
 
 use atlas_build::{
-    DependencyProfile, ManifestProfileConfig, OptLevel, Profile, ProfileConfig, ProfileManager,
+    BuildError, DependencyProfile, ManifestProfileConfig, OptLevel, Profile, ProfileConfig,
+    ProfileManager,
 };
 use std::collections::HashMap;
 
@@ -145,3 +146,84 @@ fn test_custom_profile_inheritance() {
     // debug_info inherited from release (false)
     assert!(!config.debug_info);
 }
+
+/// A manifest profile with only `opt_level`, `inline_threshold`, and one env var set
+fn manifest_profile(
+    inherits: &str,
+    opt_level: Option<OptLevel>,
+    inline_threshold: Option<usize>,
+    env: (&str, &str),
+) -> ManifestProfileConfig {
+    ManifestProfileConfig {
+        opt_level,
+        inline_threshold,
+        inherits: Some(inherits.to_string()),
+        env_vars: HashMap::from([(env.0.to_string(), env.1.to_string())]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_two_level_profile_inheritance() {
+    // bench -> fast -> release
+    let manifest_profiles = HashMap::from([
+        (
+            "bench".to_string(),
+            manifest_profile("fast", Some(OptLevel::O3), None, ("LEVEL", "bench")),
+        ),
+        (
+            "fast".to_string(),
+            manifest_profile("release", None, Some(300), ("LEVEL", "fast")),
+        ),
+    ]);
+
+    let mut manager = ProfileManager::new();
+    manager.load_from_manifest(&manifest_profiles).unwrap();
+    let config = manager.get(&Profile::Custom("bench".to_string())).unwrap();
+
+    assert_eq!(config.name, "bench");
+    // Set by bench itself
+    assert_eq!(config.optimization_level, OptLevel::O3);
+    // Inherited from fast
+    assert_eq!(config.inline_threshold, 300);
+    // Inherited from release through fast
+    assert!(!config.debug_info);
+    assert!(!config.incremental);
+    assert_eq!(config.dependencies, DependencyProfile::Release);
+    // Child wins on conflicting env vars
+    assert_eq!(config.env_vars.get("LEVEL"), Some(&"bench".to_string()));
+
+    let fast = manager.get(&Profile::Custom("fast".to_string())).unwrap();
+    assert_eq!(fast.optimization_level, OptLevel::O2);
+}
+
+#[test]
+fn test_profile_inheritance_cycle_is_error() {
+    let manifest_profiles = HashMap::from([
+        (
+            "a".to_string(),
+            manifest_profile("b", None, None, ("K", "a")),
+        ),
+        (
+            "b".to_string(),
+            manifest_profile("a", None, None, ("K", "b")),
+        ),
+    ]);
+
+    let mut manager = ProfileManager::new();
+    let err = manager.load_from_manifest(&manifest_profiles).unwrap_err();
+    assert!(matches!(err, BuildError::CircularDependency(_)));
+    assert!(err.to_string().contains("profile inheritance"));
+}
+
+#[test]
+fn test_profile_inherits_unknown_profile() {
+    let manifest_profiles = HashMap::from([(
+        "bench".to_string(),
+        manifest_profile("missing", None, None, ("K", "v")),
+    )]);
+
+    let mut manager = ProfileManager::new();
+    let err = manager.load_from_manifest(&manifest_profiles).unwrap_err();
+    assert!(matches!(err, BuildError::ProfileNotFound(name) if name == "missing"));
+}
@@ -1,6 +1,10 @@
 //! Build script execution tests
 
-use atlas_build::{BuildScript, Profile, ScriptContext, ScriptExecutor, ScriptPhase};
+use atlas_build::{
+    BuildScript, Builder, OutputMode, Profile, ScriptContext, ScriptDirective, ScriptExecutor,
+    ScriptPhase,
+};
+use std::path::PathBuf;
 use std::time::Duration;
 use tempfile::TempDir;
 
@@ -140,3 +144,124 @@ fn test_multiple_phases_execution() {
     assert_eq!(phases[1], ScriptPhase::PostBuild);
     assert_eq!(phases[2], ScriptPhase::PostLink);
 }
+
+#[test]
+fn test_script_directives_parsed_from_stdout() {
+    let (_dir, ctx) = test_context();
+    let executor = ScriptExecutor::new(ctx);
+    let script = BuildScript::shell(
+        "gen",
+        "echo 'generating'; echo 'atlas:env=MODE=fast=1'; echo 'atlas:rerun-if-changed=schema.txt'; echo 'atlas:bogus=x'",
+        ScriptPhase::PreBuild,
+    );
+
+    let result = executor.execute(&script).unwrap();
+    assert_eq!(
+        result.directives,
+        vec![
+            ScriptDirective::Env {
+                key: "MODE".to_string(),
+                value: "fast=1".to_string(),
+            },
+            ScriptDirective::RerunIfChanged(PathBuf::from("schema.txt")),
+        ]
+    );
+    assert_eq!(
+        result.env_vars().collect::<Vec<_>>(),
+        vec![("MODE", "fast=1")]
+    );
+}
+
+#[test]
+fn test_rerun_if_changed_recorded_and_respected() {
+    let (dir, ctx) = test_context();
+    let src_dir = ctx.source_dir.clone();
+    std::fs::write(src_dir.join("schema.txt"), "v1").unwrap();
+    let fingerprints = dir.path().join("target/scripts/fingerprints.json");
+    let script = BuildScript::shell(
+        "gen",
+        "echo 'atlas:env=GENERATED=1'; echo 'atlas:rerun-if-changed=schema.txt'",
+        ScriptPhase::PreBuild,
+    );
+
+    let executor = ScriptExecutor::new(ctx.clone()).with_fingerprints(&fingerprints);
+    let first = executor.execute(&script).unwrap();
+    assert!(!first.fresh);
+    let recorded = executor.script_fingerprint("gen").unwrap();
+    assert!(recorded.watched.contains_key(&PathBuf::from("schema.txt")));
+    assert!(fingerprints.exists());
+
+    // Unchanged watched file: the script is skipped but its directives replay
+    let executor = ScriptExecutor::new(ctx.clone()).with_fingerprints(&fingerprints);
+    let second = executor.execute(&script).unwrap();
+    assert!(second.fresh);
+    assert_eq!(
+        second.env_vars().collect::<Vec<_>>(),
+        vec![("GENERATED", "1")]
+    );
+
+    // Changed watched file re-triggers the script
+    std::fs::write(src_dir.join("schema.txt"), "v2").unwrap();
+    let executor = ScriptExecutor::new(ctx).with_fingerprints(&fingerprints);
+    let third = executor.execute(&script).unwrap();
+    assert!(!third.fresh);
+}
+
+#[test]
+fn test_script_without_rerun_directive_always_runs() {
+    let (dir, ctx) = test_context();
+    let fingerprints = dir.path().join("target/scripts/fingerprints.json");
+    let script = BuildScript::shell("gen", "echo 'atlas:env=A=1'", ScriptPhase::PreBuild);
+
+    let executor = ScriptExecutor::new(ctx).with_fingerprints(&fingerprints);
+    executor.execute(&script).unwrap();
+    assert!(executor.script_fingerprint("gen").is_none());
+    assert!(!executor.execute(&script).unwrap().fresh);
+}
+
+#[test]
+fn test_env_directive_visible_to_compilation() {
+    let dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::write(
+        dir.path().join("atlas.toml"),
+        "[package]\nname = \"scripted\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("src/main.atlas"),
+        "fn main() -> void {\n    print(1);\n}",
+    )
+    .unwrap();
+
+    let scripts = vec![
+        BuildScript::shell(
+            "configure",
+            "echo 'atlas:env=FEATURE_LEVEL=3'",
+            ScriptPhase::PreBuild,
+        ),
+        BuildScript::shell(
+            "report",
+            "echo \"$FEATURE_LEVEL\" > feature.txt",
+            ScriptPhase::PostBuild,
+        ),
+    ];
+
+    let mut builder = Builder::new(dir.path())
+        .unwrap()
+        .with_target_dir(dir.path().join("target/debug"));
+    let context = builder
+        .build_with_profile(Profile::Dev, &scripts, OutputMode::Normal)
+        .unwrap();
+
+    assert_eq!(
+        builder.config().env_vars.get("FEATURE_LEVEL"),
+        Some(&"3".to_string())
+    );
+    assert!(context.script_directives.contains(&ScriptDirective::Env {
+        key: "FEATURE_LEVEL".to_string(),
+        value: "3".to_string(),
+    }));
+    let reported = std::fs::read_to_string(dir.path().join("src/feature.txt")).unwrap();
+    assert_eq!(reported.trim(), "3");
+}
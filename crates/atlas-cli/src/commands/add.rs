@@ -212,6 +212,7 @@ fn build_dependency(args: &AddArgs) -> Result<Dependency> {
             None
         },
         rename: args.rename.clone(),
+        workspace: None,
     };
 
     Ok(Dependency::Detailed(detailed))
@@ -460,6 +461,7 @@ version = "0.1.0"
             features: None,
             default_features: None,
             rename: None,
+            workspace: None,
         });
         let info = format_dependency_info(&dep);
         assert!(info.contains("git:"));
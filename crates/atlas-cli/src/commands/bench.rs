@@ -0,0 +1,531 @@
+//! Bench command - run Atlas microbenchmarks
+
+use crate::testing::discovery::TestFunction;
+use crate::testing::TestSuite;
+use anyhow::Result;
+use atlas_jit::{JitConfig, JitEngine};
+use atlas_runtime::binder::Binder;
+use atlas_runtime::bytecode::Bytecode;
+use atlas_runtime::compiler::Compiler;
+use atlas_runtime::lexer::Lexer;
+use atlas_runtime::parser::Parser;
+use atlas_runtime::security::SecurityContext;
+use atlas_runtime::typechecker::TypeChecker;
+use atlas_runtime::vm::VM;
+use atlas_runtime::{Diagnostic, DiagnosticLevel, Value};
+use colored::*;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Arguments for the bench command
+pub struct BenchArgs {
+    /// Filter benchmarks by name pattern
+    pub pattern: Option<String>,
+    /// Benchmark directory (defaults to current directory)
+    pub dir: PathBuf,
+    /// Measured iterations per benchmark
+    pub iterations: usize,
+    /// Unmeasured iterations run before measuring
+    pub warmup: usize,
+    /// Run eligible functions through the JIT
+    pub jit: bool,
+    /// Disable colored output
+    pub no_color: bool,
+    /// Output in JSON format
+    pub json: bool,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        Self {
+            pattern: None,
+            dir: PathBuf::from("."),
+            iterations: 100,
+            warmup: 10,
+            jit: false,
+            no_color: false,
+            json: false,
+        }
+    }
+}
+
+/// Timing summary for one benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+    /// Number of measured iterations
+    pub iterations: usize,
+    /// Mean time per iteration
+    pub mean: Duration,
+    /// Median time per iteration
+    pub median: Duration,
+    /// Sample standard deviation of the iteration times
+    pub stddev: Duration,
+    /// Fastest iteration
+    pub min: Duration,
+    /// Slowest iteration
+    pub max: Duration,
+    /// Iterations per second, derived from the mean
+    pub ops_per_sec: f64,
+}
+
+impl BenchStats {
+    /// Summarize per-iteration samples; `None` if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (secs[n / 2 - 1] + secs[n / 2]) / 2.0
+        } else {
+            secs[n / 2]
+        };
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            iterations: n,
+            mean: Duration::from_secs_f64(mean),
+            median: Duration::from_secs_f64(median),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+            max: sorted[n - 1],
+            ops_per_sec: if mean > 0.0 {
+                1.0 / mean
+            } else {
+                f64::INFINITY
+            },
+        })
+    }
+
+    /// JSON representation with all durations in nanoseconds
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "iterations": self.iterations,
+            "mean_ns": self.mean.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "stddev_ns": self.stddev.as_nanos() as u64,
+            "min_ns": self.min.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+            "ops_per_sec": self.ops_per_sec,
+        })
+    }
+}
+
+/// A completed benchmark run
+#[derive(Debug, Clone)]
+pub struct BenchRun {
+    /// The benchmark that was run
+    pub bench: TestFunction,
+    /// Timing summary, or the error that stopped the benchmark
+    pub result: Result<BenchStats, String>,
+    /// Whether every measured iteration ran as JIT-compiled native code
+    pub jit_native: bool,
+}
+
+/// Run the bench command
+pub fn run(args: BenchArgs) -> Result<()> {
+    if args.no_color {
+        colored::control::set_override(false);
+    }
+
+    let mut suite = TestSuite::discover_prefixed(&args.dir, "bench_");
+
+    if !suite.parse_errors.is_empty() && !args.json {
+        eprintln!("{}", "Parse errors in benchmark files:".yellow().bold());
+        for (path, error) in &suite.parse_errors {
+            eprintln!("  {} {}", "●".yellow(), path.display());
+            eprintln!("    {}", error.dimmed());
+        }
+        eprintln!();
+    }
+
+    if let Some(pattern) = &args.pattern {
+        suite = suite.filter(pattern);
+    }
+
+    if suite.is_empty() {
+        if args.json {
+            println!(
+                "{}",
+                serde_json::json!({
+                    "benchmarks": 0,
+                    "results": [],
+                    "message": "No benchmarks found"
+                })
+            );
+        } else {
+            println!("{}", "No benchmarks found.".yellow());
+        }
+        return Ok(());
+    }
+
+    if !args.json {
+        println!(
+            "Running {} benchmark{} ({} iterations, {} warmup{})",
+            suite.len().to_string().bold(),
+            if suite.len() == 1 { "" } else { "s" },
+            args.iterations,
+            args.warmup,
+            if args.jit { ", JIT enabled" } else { "" }
+        );
+        println!();
+    }
+
+    // Benchmarks run one at a time so they don't compete for the CPU
+    let runs: Vec<BenchRun> = suite
+        .tests
+        .iter()
+        .map(|bench| {
+            let run = run_single_bench(bench, &args);
+            if !args.json {
+                report_run(&run);
+            }
+            run
+        })
+        .collect();
+
+    if args.json {
+        let results: Vec<_> = runs
+            .iter()
+            .map(|r| {
+                let mut entry = serde_json::json!({
+                    "name": r.bench.name,
+                    "file": r.bench.file.display().to_string(),
+                    "line": r.bench.line,
+                    "jit": r.jit_native,
+                });
+                match &r.result {
+                    Ok(stats) => entry["stats"] = stats.to_json(),
+                    Err(error) => entry["error"] = serde_json::json!(error),
+                }
+                entry
+            })
+            .collect();
+
+        println!(
+            "{}",
+            serde_json::json!({
+                "benchmarks": runs.len(),
+                "iterations": args.iterations,
+                "warmup": args.warmup,
+                "jit": args.jit,
+                "results": results,
+            })
+        );
+    }
+
+    if args.no_color {
+        colored::control::unset_override();
+    }
+
+    // Exit with code 1 if any benchmark failed
+    if runs.iter().any(|r| r.result.is_err()) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Run a single benchmark
+fn run_single_bench(bench: &TestFunction, args: &BenchArgs) -> BenchRun {
+    let (result, jit_native) = match measure(bench, args) {
+        Ok((stats, jit_native)) => (Ok(stats), jit_native),
+        Err(error) => (Err(error), false),
+    };
+
+    BenchRun {
+        bench: bench.clone(),
+        result,
+        jit_native,
+    }
+}
+
+/// Compile a benchmark's file, run its top level once, then time the function
+///
+/// Also returns whether every measured iteration ran as native code.
+fn measure(bench: &TestFunction, args: &BenchArgs) -> Result<(BenchStats, bool), String> {
+    let source = std::fs::read_to_string(&bench.file)
+        .map_err(|e| format!("Failed to read benchmark file: {}", e))?;
+    let bytecode = compile(&source)?;
+
+    let mut vm = VM::new(bytecode.clone());
+    vm.run(&SecurityContext::allow_all())
+        .map_err(|e| format!("Failed to load benchmark file: {}", e))?;
+
+    let offset = match vm.get_globals().get(&bench.name) {
+        Some(Value::Function(func)) => func.bytecode_offset,
+        _ => return Err(format!("{} is not a function", bench.name)),
+    };
+
+    // Compile as soon as the function is first called; the warmup
+    // iterations absorb the compile time
+    let mut jit = if args.jit {
+        let config = JitConfig {
+            compilation_threshold: 1,
+            ..JitConfig::default()
+        };
+        Some(JitEngine::new(config).map_err(|e| format!("Failed to start JIT: {}", e))?)
+    } else {
+        None
+    };
+
+    let mut iterate = || -> Result<bool, String> {
+        if let Some(jit) = jit.as_mut() {
+            // Native code only covers straight-line functions; anything
+            // else falls back to the VM
+            if jit
+                .notify_call(offset, &bytecode, bytecode.instructions.len())
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+        vm.call_global(&bench.name, Vec::new())
+            .map(|_| false)
+            .map_err(|e| e.to_string())
+    };
+
+    for _ in 0..args.warmup {
+        iterate()?;
+    }
+
+    let mut samples = Vec::with_capacity(args.iterations);
+    let mut native = 0;
+    for _ in 0..args.iterations {
+        let start = Instant::now();
+        let ran_native = iterate()?;
+        samples.push(start.elapsed());
+        native += usize::from(ran_native);
+    }
+
+    let stats =
+        BenchStats::from_samples(&samples).ok_or_else(|| "No iterations were run".to_string())?;
+    Ok((stats, native == samples.len()))
+}
+
+/// Compile source through the full pipeline (lex → parse → bind → check → compile)
+fn compile(source: &str) -> Result<Bytecode, String> {
+    let mut lexer = Lexer::new(source);
+    let (tokens, lex_diags) = lexer.tokenize();
+    check_diagnostics(&lex_diags)?;
+
+    let mut parser = Parser::new(tokens);
+    let (ast, parse_diags) = parser.parse();
+    check_diagnostics(&parse_diags)?;
+
+    let mut binder = Binder::new();
+    let (mut symbol_table, bind_diags) = binder.bind(&ast);
+    check_diagnostics(&bind_diags)?;
+
+    let mut checker = TypeChecker::new(&mut symbol_table);
+    let type_diags = checker.check(&ast);
+    check_diagnostics(&type_diags)?;
+
+    let mut compiler = Compiler::with_optimization();
+    compiler.compile(&ast).map_err(|diags| join_errors(&diags))
+}
+
+fn check_diagnostics(diags: &[Diagnostic]) -> Result<(), String> {
+    if diags.iter().any(|d| d.level == DiagnosticLevel::Error) {
+        Err(join_errors(diags))
+    } else {
+        Ok(())
+    }
+}
+
+fn join_errors(diags: &[Diagnostic]) -> String {
+    let messages: Vec<_> = diags
+        .iter()
+        .filter(|d| d.level == DiagnosticLevel::Error)
+        .map(|d| format!("{}:{}: {}", d.line, d.column, d.message))
+        .collect();
+    format!("Compilation failed: {}", messages.join("; "))
+}
+
+/// Print one benchmark's result line
+fn report_run(run: &BenchRun) {
+    match &run.result {
+        Ok(stats) => println!(
+            "{} {}  mean {:?}  median {:?}  ± {:?}  {:.0} ops/s{}",
+            "✓".green(),
+            run.bench.name.bold(),
+            stats.mean,
+            stats.median,
+            stats.stddev,
+            stats.ops_per_sec,
+            if run.jit_native {
+                " (jit)".cyan().to_string()
+            } else {
+                String::new()
+            }
+        ),
+        Err(error) => {
+            println!("{} {}", "✗".red(), run.bench.name.bold());
+            println!("    {}", error.red());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats_summary() {
+        let stats = BenchStats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]).unwrap();
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.max, ms(4));
+        // Sample stddev of 1,2,3,4 ms is sqrt(5/3) ms
+        let stddev_ms = stats.stddev.as_secs_f64() * 1000.0;
+        assert!(
+            (stddev_ms - (5.0f64 / 3.0).sqrt()).abs() < 1e-6,
+            "{}",
+            stddev_ms
+        );
+        assert!((stats.ops_per_sec - 400.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_stats_odd_sample_count_median() {
+        let stats = BenchStats::from_samples(&[ms(9), ms(1), ms(5)]).unwrap();
+        assert_eq!(stats.median, ms(5));
+    }
+
+    #[test]
+    fn test_stats_single_sample_has_no_spread() {
+        let stats = BenchStats::from_samples(&[ms(7)]).unwrap();
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.mean, ms(7));
+    }
+
+    #[test]
+    fn test_stats_empty_samples() {
+        assert!(BenchStats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_stats_json_structure() {
+        let json = BenchStats::from_samples(&[ms(1), ms(3)]).unwrap().to_json();
+        assert_eq!(json["iterations"], 2);
+        assert_eq!(json["mean_ns"], 2_000_000);
+        assert_eq!(json["median_ns"], 2_000_000);
+        assert_eq!(json["min_ns"], 1_000_000);
+        assert_eq!(json["max_ns"], 3_000_000);
+        assert!(json["stddev_ns"].as_u64().unwrap() > 0);
+        assert_eq!(json["ops_per_sec"], 500.0);
+    }
+
+    #[test]
+    fn test_bench_runs_discovered_function() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("bench.at"),
+            r#"
+var calls: number = 0;
+fn bench_loop() {
+    calls = calls + 1;
+    var i: number = 0;
+    while (i < 10) { i = i + 1; }
+}
+"#,
+        )
+        .unwrap();
+
+        let bench = TestSuite::discover_prefixed(dir.path(), "bench_").tests[0].clone();
+        let args = BenchArgs {
+            iterations: 20,
+            warmup: 5,
+            ..Default::default()
+        };
+        let run = run_single_bench(&bench, &args);
+        let stats = run.result.unwrap();
+        assert_eq!(stats.iterations, 20);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+        assert!(!run.jit_native);
+    }
+
+    #[test]
+    fn test_bench_jit_runs_straight_line_function_natively() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("bench.at"),
+            "fn bench_arith() -> number { return 1 + 2 * 3; }",
+        )
+        .unwrap();
+
+        let bench = TestSuite::discover_prefixed(dir.path(), "bench_").tests[0].clone();
+        let args = BenchArgs {
+            iterations: 10,
+            warmup: 2,
+            jit: true,
+            ..Default::default()
+        };
+        let run = run_single_bench(&bench, &args);
+        assert!(run.result.is_ok(), "{:?}", run.result);
+        assert!(run.jit_native);
+    }
+
+    #[test]
+    fn test_bench_jit_falls_back_to_vm() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("bench.at"),
+            "fn bench_loop() { var i: number = 0; while (i < 5) { i = i + 1; } }",
+        )
+        .unwrap();
+
+        let bench = TestSuite::discover_prefixed(dir.path(), "bench_").tests[0].clone();
+        let args = BenchArgs {
+            iterations: 10,
+            warmup: 2,
+            jit: true,
+            ..Default::default()
+        };
+        let run = run_single_bench(&bench, &args);
+        assert!(run.result.is_ok(), "{:?}", run.result);
+        assert!(!run.jit_native);
+    }
+
+    #[test]
+    fn test_bench_reports_runtime_error() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("bench.at"),
+            "fn bench_oob() { let a: number[] = [1]; let x: number = a[5]; }",
+        )
+        .unwrap();
+
+        let bench = TestSuite::discover_prefixed(dir.path(), "bench_").tests[0].clone();
+        let run = run_single_bench(&bench, &BenchArgs::default());
+        assert!(run.result.is_err());
+    }
+
+    #[test]
+    fn test_bench_command_no_benchmarks_found() {
+        let dir = tempdir().unwrap();
+        let args = BenchArgs {
+            dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        assert!(run(args).is_ok());
+    }
+}
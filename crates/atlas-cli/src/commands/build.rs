@@ -1,10 +1,20 @@
 //! Build command - compile Atlas projects with profiles, scripts, and caching
 
 use anyhow::{Context, Result};
-use atlas_build::{BuildScript, Builder, OutputMode, Profile, ScriptPhase};
+use atlas_build::{
+    BuildContext, BuildScript, BuildSummary, Builder, OutputMode, Profile, ProfileManager,
+    ScriptPhase, TargetKind,
+};
+use atlas_package::manifest::PackageManifest;
+use atlas_package::Lockfile;
+use notify::{RecursiveMode, Watcher};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
 use std::time::Duration;
 
+/// Quiet period after a change before rebuilding, so one save triggers one rebuild
+const WATCH_DEBOUNCE_MS: u64 = 300;
+
 /// Build command arguments
 #[derive(Default)]
 pub struct BuildArgs {
@@ -12,24 +22,32 @@ pub struct BuildArgs {
     pub profile: Option<String>,
     /// Build in release mode (shorthand for --profile=release)
     pub release: bool,
-    /// Specific target to build
-    #[allow(dead_code)]
+    /// Output target kind (e.g. wasm)
     pub target: Option<String>,
     /// Clean build (ignore cache)
     pub clean: bool,
+    /// Print the build plan as JSON and exit without compiling
+    pub build_plan: bool,
     /// Verbose output
     pub verbose: bool,
     /// Quiet output (errors only)
     pub quiet: bool,
     /// JSON output
     pub json: bool,
+    /// Stream progress as newline-delimited JSON events
+    pub json_stream: bool,
     /// Number of parallel jobs
-    #[allow(dead_code)]
     pub jobs: Option<usize>,
+    /// Fail if atlas.lock is missing or out of date
+    pub locked: bool,
     /// Target directory
     pub target_dir: Option<PathBuf>,
     /// Project directory (defaults to current directory)
     pub project_dir: Option<PathBuf>,
+    /// Rebuild incrementally whenever a source file changes
+    pub watch: bool,
+    /// Don't clear terminal before each rebuild (with --watch)
+    pub no_clear: bool,
 }
 
 /// Run the build command
@@ -43,15 +61,20 @@ pub fn run(args: BuildArgs) -> Result<()> {
     // Create builder
     let mut builder = Builder::new(&project_dir).context("Failed to create builder")?;
 
+    // Refuse to build against a stale lockfile
+    if args.locked {
+        verify_lockfile(&project_dir)?;
+    }
+
     // Determine build profile
     let profile = determine_profile(&args)?;
 
     // Determine output mode
     let output_mode = determine_output_mode(&args);
 
-    // Clean if requested
-    if args.clean {
-        if !args.quiet {
+    // Clean if requested (never when only planning)
+    if args.clean && !args.build_plan {
+        if !args.quiet && !args.json_stream {
             println!("Cleaning build artifacts...");
         }
         builder.clean().context("Failed to clean build artifacts")?;
@@ -62,11 +85,55 @@ pub fn run(args: BuildArgs) -> Result<()> {
         builder = builder.with_target_dir(target_dir.clone());
     }
 
+    // Set output target if specified
+    if let Some(ref target) = args.target {
+        let kind =
+            TargetKind::from_str(target).map_err(|e| anyhow::anyhow!("Invalid target: {}", e))?;
+        builder = builder.with_target(kind);
+    }
+
+    // Limit parallel compilation if requested
+    if let Some(jobs) = args.jobs {
+        if jobs == 0 {
+            anyhow::bail!("Invalid jobs: must be at least 1");
+        }
+        builder = builder.with_jobs(jobs);
+    }
+
     // Set verbose mode
     if args.verbose {
         builder = builder.with_verbose(true);
     }
 
+    // Report what the build would do, then stop
+    if args.build_plan {
+        let profile_config = ProfileManager::new()
+            .get(&profile)
+            .map_err(|e| anyhow::anyhow!("Invalid profile: {}", e))?;
+        let plan = builder
+            .with_optimization(profile_config.optimization_level)
+            .compute_plan()
+            .context("Failed to compute build plan")?;
+        println!("{}", plan.to_json()?);
+        return Ok(());
+    }
+
+    if args.watch {
+        let profile_config = ProfileManager::new()
+            .get(&profile)
+            .map_err(|e| anyhow::anyhow!("Invalid profile: {}", e))?;
+        let mut builder = builder.with_optimization(profile_config.optimization_level);
+        let events = FileEvents::watch(&project_dir)?;
+        if !args.quiet {
+            println!("Watching {} for changes...", project_dir.display());
+            println!("Press Ctrl+C to stop\n");
+        }
+        watch_loop(&mut builder, events, !args.no_clear, |result| {
+            report_cycle(result, &output_mode)
+        });
+        return Ok(());
+    }
+
     // Load build scripts from manifest
     let scripts = load_build_scripts(&builder, &project_dir)?;
 
@@ -75,7 +142,10 @@ pub fn run(args: BuildArgs) -> Result<()> {
         .build_with_profile(profile.clone(), &scripts, output_mode)
         .context("Build failed")?;
 
-    // Display results
+    // Display results (a JSON stream already ended with its summary event)
+    if args.json_stream {
+        return Ok(());
+    }
     if args.json {
         // JSON output
         let summary = context.stats;
@@ -111,6 +181,124 @@ pub fn run(args: BuildArgs) -> Result<()> {
     Ok(())
 }
 
+/// Rebuild once up front, then once per batch of changed paths
+///
+/// Every cycle is an incremental build, so only modules whose sources (or
+/// dependencies) changed are recompiled. Failed builds are handed to
+/// `on_cycle` like successful ones and never end the loop; it stops only
+/// when `events` is exhausted.
+fn watch_loop<I, F>(builder: &mut Builder, events: I, clear_screen: bool, mut on_cycle: F)
+where
+    I: IntoIterator<Item = Vec<PathBuf>>,
+    F: FnMut(&atlas_build::BuildResult<BuildContext>),
+{
+    on_cycle(&builder.build_incremental());
+
+    for changed in events {
+        if !changed.iter().any(|path| is_watched_source(path)) {
+            continue;
+        }
+        if clear_screen {
+            print!("\x1B[2J\x1B[1;1H");
+            use std::io::Write;
+            let _ = std::io::stdout().flush();
+        }
+        on_cycle(&builder.build_incremental());
+    }
+}
+
+/// Print the outcome of one watch cycle
+fn report_cycle(result: &atlas_build::BuildResult<BuildContext>, output_mode: &OutputMode) {
+    match result {
+        Ok(context) => {
+            let summary = BuildSummary::from_build(&context.stats, &context.artifacts);
+            if matches!(output_mode, OutputMode::Json) {
+                if let Ok(json) = summary.to_json() {
+                    println!("{}", json);
+                }
+            } else {
+                summary.display(output_mode);
+                if let Some(incremental) = &context.incremental {
+                    println!("  {}", incremental.summary());
+                }
+            }
+        }
+        Err(e) => eprintln!("Build failed: {}", e),
+    }
+    if !matches!(output_mode, OutputMode::Json) {
+        println!("\nWatching for changes...");
+    }
+}
+
+/// Whether a changed path can affect the build
+fn is_watched_source(path: &Path) -> bool {
+    if path.file_name().is_some_and(|name| name == "atlas.toml") {
+        return true;
+    }
+    // Ignore our own output so writing artifacts doesn't retrigger a build
+    if path.components().any(|c| c.as_os_str() == "target") {
+        return false;
+    }
+    path.extension()
+        .is_some_and(|ext| ext == "at" || ext == "atlas" || ext == "atl")
+}
+
+/// Batches of changed paths from a filesystem watcher
+///
+/// Each batch gathers every event that arrives within the debounce window,
+/// so an editor's burst of writes for one save becomes a single rebuild.
+struct FileEvents {
+    _watcher: notify::RecommendedWatcher,
+    rx: Receiver<notify::Result<notify::Event>>,
+}
+
+impl FileEvents {
+    fn watch(project_dir: &Path) -> Result<Self> {
+        let (tx, rx) = channel();
+        let mut watcher =
+            notify::recommended_watcher(tx).context("Failed to create file watcher")?;
+        watcher
+            .watch(project_dir, RecursiveMode::Recursive)
+            .context("Failed to start watching project directory")?;
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+        })
+    }
+}
+
+impl Iterator for FileEvents {
+    type Item = Vec<PathBuf>;
+
+    fn next(&mut self) -> Option<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        let mut collect = |event: notify::Result<notify::Event>| match event {
+            Ok(event) => paths.extend(event.paths),
+            Err(e) => eprintln!("[watch] Error: {:?}", e),
+        };
+
+        collect(self.rx.recv().ok()?);
+        let debounce = Duration::from_millis(WATCH_DEBOUNCE_MS);
+        while let Ok(event) = self.rx.recv_timeout(debounce) {
+            collect(event);
+        }
+        Some(paths)
+    }
+}
+
+/// Check atlas.lock against atlas.toml for `--locked`
+fn verify_lockfile(project_dir: &Path) -> Result<()> {
+    let manifest = PackageManifest::from_file(&project_dir.join("atlas.toml"))
+        .context("Failed to read atlas.toml")?;
+    let lockfile_path = project_dir.join("atlas.lock");
+    let lockfile = if lockfile_path.exists() {
+        Some(Lockfile::from_file(&lockfile_path).context("Failed to read atlas.lock")?)
+    } else {
+        None
+    };
+    super::install::verify_locked(&manifest, lockfile.as_ref())
+}
+
 /// Determine build profile from arguments
 fn determine_profile(args: &BuildArgs) -> Result<Profile> {
     if args.release {
@@ -130,7 +318,9 @@ fn determine_profile(args: &BuildArgs) -> Result<Profile> {
 
 /// Determine output mode from arguments
 fn determine_output_mode(args: &BuildArgs) -> OutputMode {
-    if args.json {
+    if args.json_stream {
+        OutputMode::JsonStream
+    } else if args.json {
         OutputMode::Json
     } else if args.quiet {
         OutputMode::Quiet
@@ -284,6 +474,17 @@ mod tests {
         assert_eq!(mode, OutputMode::Json);
     }
 
+    #[test]
+    fn test_determine_output_mode_json_stream() {
+        let args = BuildArgs {
+            json: true,
+            json_stream: true,
+            ..Default::default()
+        };
+        let mode = determine_output_mode(&args);
+        assert_eq!(mode, OutputMode::JsonStream);
+    }
+
     #[test]
     fn test_parse_script_phase_prebuild() {
         assert_eq!(
@@ -324,4 +525,96 @@ mod tests {
     fn test_parse_script_phase_invalid() {
         assert!(parse_script_phase("invalid").is_err());
     }
+
+    #[test]
+    fn test_is_watched_source() {
+        assert!(is_watched_source(Path::new("proj/src/main.atlas")));
+        assert!(is_watched_source(Path::new("proj/atlas.toml")));
+        assert!(!is_watched_source(Path::new("proj/README.md")));
+        assert!(!is_watched_source(Path::new("proj/target/debug/gen.atlas")));
+    }
+
+    fn watch_project() -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("atlas.toml"),
+            "[package]\nname = \"watched\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/main.atlas"),
+            "fn main() -> void { print(1); }",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.atlas"),
+            "export fn helper() -> number { return 10; }",
+        )
+        .unwrap();
+        dir
+    }
+
+    fn watch_builder(dir: &Path) -> Builder {
+        Builder::new(dir)
+            .unwrap()
+            .with_target_dir(dir.join("target/debug"))
+    }
+
+    #[test]
+    fn test_watch_single_change_rebuilds_one_unit() {
+        let dir = watch_project();
+        let mut builder = watch_builder(dir.path());
+        let lib = dir.path().join("src/lib.atlas");
+
+        let mut recompiled = Vec::new();
+        let events = std::iter::once(()).map(|_| {
+            std::fs::write(&lib, "export fn helper() -> number { return 11; }").unwrap();
+            vec![lib.clone()]
+        });
+        watch_loop(&mut builder, events, false, |result| {
+            let context = result.as_ref().expect("build succeeds");
+            recompiled.push(context.incremental.as_ref().unwrap().recompiled);
+        });
+
+        // Initial full build, then exactly one rebuild of the changed module
+        assert_eq!(recompiled, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_watch_ignores_unrelated_changes() {
+        let dir = watch_project();
+        let mut builder = watch_builder(dir.path());
+
+        let mut cycles = 0;
+        let events = vec![vec![dir.path().join("README.md")]];
+        watch_loop(&mut builder, events, false, |_| cycles += 1);
+
+        assert_eq!(cycles, 1);
+    }
+
+    #[test]
+    fn test_watch_survives_build_errors() {
+        let dir = watch_project();
+        let mut builder = watch_builder(dir.path());
+        let main = dir.path().join("src/main.atlas");
+        let broken = main.clone();
+        let fixed = main.clone();
+
+        let mut outcomes = Vec::new();
+        let events = vec![
+            (broken, "fn main() -> void { let x: number = \"no\"; }"),
+            (fixed, "fn main() -> void { print(2); }"),
+        ]
+        .into_iter()
+        .map(|(path, source)| {
+            std::fs::write(&path, source).unwrap();
+            vec![path]
+        });
+        watch_loop(&mut builder, events, false, |result| {
+            outcomes.push(result.is_ok())
+        });
+
+        assert_eq!(outcomes, vec![true, false, true]);
+    }
 }
@@ -0,0 +1,38 @@
+//! Config command - inspect the configuration format
+
+use anyhow::{Context, Result};
+use atlas_config::ConfigLoader;
+use std::path::Path;
+
+/// Print the JSON Schema for atlas.toml and ~/.atlas/config.toml
+///
+/// Writes to `output` when given, otherwise to stdout.
+pub fn schema(output: Option<&Path>) -> Result<()> {
+    let schema = ConfigLoader::export_schema();
+    match output {
+        Some(path) => std::fs::write(path, schema + "\n")
+            .with_context(|| format!("Failed to write {}", path.display())),
+        None => {
+            println!("{}", schema);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_schema_written_to_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("atlas.schema.json");
+
+        schema(Some(&path)).unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["$ref"], "#/definitions/ProjectConfig");
+    }
+}
@@ -0,0 +1,109 @@
+//! Disasm command - print the bytecode compiled from a source file
+
+use anyhow::{bail, Context, Result};
+use atlas_runtime::{Binder, Compiler, Diagnostic, DiagnosticLevel, Lexer, Parser, TypeChecker};
+use std::fs;
+
+/// Compile `file_path` and render its bytecode listing
+///
+/// Runs the full front end (lex, parse, bind, typecheck) so method calls
+/// dispatch the same way they do under `atlas run`. With `optimize`, the
+/// listing shows the bytecode after the optimizer passes.
+pub fn render(file_path: &str, optimize: bool) -> Result<String> {
+    let source = fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read source file: {}", file_path))?;
+
+    let (tokens, lex_diags) = Lexer::new(&source).tokenize();
+    check_diagnostics(&lex_diags)?;
+
+    let (ast, parse_diags) = Parser::new(tokens).parse();
+    check_diagnostics(&parse_diags)?;
+
+    let mut binder = Binder::new();
+    let (mut symbol_table, bind_diags) = binder.bind(&ast);
+    check_diagnostics(&bind_diags)?;
+
+    let mut checker = TypeChecker::new(&mut symbol_table);
+    let type_diags = checker.check(&ast);
+    check_diagnostics(&type_diags)?;
+
+    let mut compiler = if optimize {
+        Compiler::with_optimization()
+    } else {
+        Compiler::new()
+    };
+    let bytecode = compiler.compile(&ast).map_err(|diags| {
+        anyhow::anyhow!("Failed to compile {}:\n{}", file_path, join_errors(&diags))
+    })?;
+
+    Ok(bytecode.disassemble_with_source(&source))
+}
+
+/// Print the bytecode listing for `file_path` to stdout
+pub fn run(file_path: &str, optimize: bool) -> Result<()> {
+    print!("{}", render(file_path, optimize)?);
+    Ok(())
+}
+
+fn check_diagnostics(diags: &[Diagnostic]) -> Result<()> {
+    if diags.iter().any(|d| d.level == DiagnosticLevel::Error) {
+        bail!("{}", join_errors(diags));
+    }
+    Ok(())
+}
+
+fn join_errors(diags: &[Diagnostic]) -> String {
+    diags
+        .iter()
+        .filter(|d| d.level == DiagnosticLevel::Error)
+        .map(|d| format!("{}:{}: error: {}", d.line, d.column, d.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn source_file(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", content).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_disasm_lists_instructions_with_lines() {
+        let file = source_file("let x = 40;\nprint(x + 2);\n");
+        let listing = render(file.path().to_str().unwrap(), false).unwrap();
+        assert!(
+            listing.contains("0000    1  Constant 0 (40)"),
+            "{}",
+            listing
+        );
+        assert!(listing.contains("    2  GetGlobal"), "{}", listing);
+        assert!(listing.trim_end().ends_with("Halt"), "{}", listing);
+    }
+
+    #[test]
+    fn test_disasm_optimize_folds_constants() {
+        let file = source_file("let x = 2 * 3;\n");
+        let plain = render(file.path().to_str().unwrap(), false).unwrap();
+        let optimized = render(file.path().to_str().unwrap(), true).unwrap();
+        assert!(plain.contains("Mul"), "{}", plain);
+        assert!(!optimized.contains("Mul"), "{}", optimized);
+    }
+
+    #[test]
+    fn test_disasm_reports_type_errors() {
+        let file = source_file("let x: number = \"a\";\n");
+        let err = render(file.path().to_str().unwrap(), false).unwrap_err();
+        assert!(err.to_string().contains("1:"), "{}", err);
+    }
+
+    #[test]
+    fn test_disasm_missing_file() {
+        assert!(render("nonexistent.atl", false).is_err());
+    }
+}
@@ -0,0 +1,601 @@
+//! Doc command - extract doc comments into Markdown or JSON
+
+use anyhow::{Context, Result};
+use atlas_runtime::ast::{
+    ExportItem, FunctionDecl, Item, OwnershipAnnotation, Param, TraitDecl, TypeAliasDecl,
+    TypeParam, TypeRef,
+};
+use atlas_runtime::span::Span;
+use atlas_runtime::{DiagnosticLevel, Lexer, Parser, Token, TokenKind};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Version of the `--json` output schema; bumped on incompatible changes
+pub const DOC_JSON_VERSION: u32 = 1;
+
+/// Arguments for the doc command
+pub struct DocArgs {
+    /// Files or directories to document
+    pub paths: Vec<String>,
+    /// Output in JSON format instead of Markdown
+    pub json: bool,
+    /// Write output to this file instead of stdout
+    pub output: Option<PathBuf>,
+}
+
+/// Kind of documented item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocItemKind {
+    Function,
+    TypeAlias,
+    Trait,
+}
+
+impl DocItemKind {
+    /// Name used in JSON output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocItemKind::Function => "function",
+            DocItemKind::TypeAlias => "type",
+            DocItemKind::Trait => "trait",
+        }
+    }
+}
+
+/// A documented top-level declaration
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocItem {
+    pub kind: DocItemKind,
+    pub name: String,
+    /// Declaration signature, e.g. `fn add(a: number, b: number) -> number`
+    pub signature: String,
+    /// Doc comment text without the leading `///`, if any
+    pub doc: Option<String>,
+    /// Whether the item is declared with `export`
+    pub exported: bool,
+    /// 1-based line of the declaration
+    pub line: usize,
+}
+
+/// All documented items in one source file
+#[derive(Debug, Clone)]
+pub struct DocModule {
+    pub file: PathBuf,
+    pub items: Vec<DocItem>,
+}
+
+/// Run the doc command
+pub fn run(args: DocArgs) -> Result<()> {
+    let paths = if args.paths.is_empty() {
+        vec![".".to_string()]
+    } else {
+        args.paths
+    };
+
+    let mut modules = Vec::new();
+    for file in collect_files(&paths)? {
+        let source = std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let items =
+            extract_items(&source).map_err(|e| anyhow::anyhow!("{}: {}", file.display(), e))?;
+        modules.push(DocModule { file, items });
+    }
+
+    let rendered = if args.json {
+        format!("{}\n", serde_json::to_string_pretty(&to_json(&modules))?)
+    } else {
+        to_markdown(&modules)
+    };
+
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, rendered)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            eprintln!("Documentation written to {}", path.display());
+        }
+        None => print!("{}", rendered),
+    }
+
+    Ok(())
+}
+
+/// Extract documented top-level functions, type aliases, and traits from source
+pub fn extract_items(source: &str) -> Result<Vec<DocItem>, String> {
+    let mut lexer = Lexer::new(source);
+    let (tokens, lex_diags) = lexer.tokenize_with_comments();
+    if let Some(d) = lex_diags.iter().find(|d| d.level == DiagnosticLevel::Error) {
+        return Err(format!("{}:{}: {}", d.line, d.column, d.message));
+    }
+
+    // Doc comments are attached here rather than in the parser, which only
+    // accepts them before top-level items
+    let (doc_tokens, code_tokens): (Vec<Token>, Vec<Token>) = tokens
+        .into_iter()
+        .partition(|t| t.kind == TokenKind::DocComment);
+    let chars: Vec<char> = source.chars().collect();
+    let mut docs = attach_doc_comments(&doc_tokens, &chars);
+
+    let mut parser = Parser::new(code_tokens);
+    let (program, parse_diags) = parser.parse();
+    if let Some(d) = parse_diags
+        .iter()
+        .find(|d| d.level == DiagnosticLevel::Error)
+    {
+        return Err(format!("{}:{}: {}", d.line, d.column, d.message));
+    }
+
+    let line_of = |offset: usize| {
+        chars[..offset.min(chars.len())]
+            .iter()
+            .filter(|&&c| c == '\n')
+            .count()
+            + 1
+    };
+
+    let mut items = Vec::new();
+    for item in &program.items {
+        let (start, exported, entry) = match item {
+            Item::Function(func) => (func.span.start, false, function_entry(func)),
+            Item::TypeAlias(alias) => (alias.span.start, false, alias_entry(alias)),
+            Item::Trait(decl) => (decl.span.start, false, trait_entry(decl)),
+            Item::Export(export) => match &export.item {
+                ExportItem::Function(func) => (export.span.start, true, function_entry(func)),
+                ExportItem::TypeAlias(alias) => (export.span.start, true, alias_entry(alias)),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let (kind, name, signature) = entry;
+        items.push(DocItem {
+            kind,
+            name,
+            signature,
+            doc: docs.remove(&start),
+            exported,
+            line: line_of(start),
+        });
+    }
+
+    Ok(items)
+}
+
+/// Group consecutive `///` lines and key each group by the offset of the
+/// first non-whitespace character after it, i.e. the declaration it documents
+fn attach_doc_comments(doc_tokens: &[Token], chars: &[char]) -> HashMap<usize, String> {
+    let is_blank = |from: usize, to: usize| {
+        chars[from.min(chars.len())..to.min(chars.len())]
+            .iter()
+            .all(|c| c.is_whitespace())
+    };
+
+    let mut docs = HashMap::new();
+    let mut lines: Vec<String> = Vec::new();
+    for (i, token) in doc_tokens.iter().enumerate() {
+        let text = token.lexeme.trim_start_matches("///");
+        lines.push(
+            text.strip_prefix(' ')
+                .unwrap_or(text)
+                .trim_end()
+                .to_string(),
+        );
+
+        let next_start = doc_tokens.get(i + 1).map(|next| next.span.start);
+        if next_start.is_some_and(|next| is_blank(token.span.end, next)) {
+            continue;
+        }
+
+        let mut target = token.span.end;
+        while target < chars.len() && chars[target].is_whitespace() {
+            target += 1;
+        }
+        docs.insert(target, lines.join("\n"));
+        lines.clear();
+    }
+    docs
+}
+
+fn function_entry(func: &FunctionDecl) -> (DocItemKind, String, String) {
+    let mut signature = format!(
+        "fn {}{}({})",
+        func.name.name,
+        format_type_params(&func.type_params),
+        format_params(&func.params)
+    );
+    if !is_implicit_return(&func.return_type) {
+        signature.push_str(" -> ");
+        signature.push_str(ownership_prefix(func.return_ownership.as_ref()));
+        signature.push_str(&format_type_ref(&func.return_type));
+    }
+    if let Some(predicate) = &func.predicate {
+        signature.push_str(&format!(
+            " is {}: {}",
+            predicate.param.name,
+            format_type_ref(&predicate.target)
+        ));
+    }
+    (DocItemKind::Function, func.name.name.clone(), signature)
+}
+
+fn alias_entry(alias: &TypeAliasDecl) -> (DocItemKind, String, String) {
+    let signature = format!(
+        "type {}{} = {}",
+        alias.name.name,
+        format_type_params(&alias.type_params),
+        format_type_ref(&alias.type_ref)
+    );
+    (DocItemKind::TypeAlias, alias.name.name.clone(), signature)
+}
+
+fn trait_entry(decl: &TraitDecl) -> (DocItemKind, String, String) {
+    let mut signature = format!(
+        "trait {}{} {{\n",
+        decl.name.name,
+        format_type_params(&decl.type_params)
+    );
+    for method in &decl.methods {
+        signature.push_str(&format!(
+            "    fn {}{}({}) -> {};\n",
+            method.name.name,
+            format_type_params(&method.type_params),
+            format_params(&method.params),
+            format_type_ref(&method.return_type)
+        ));
+    }
+    signature.push('}');
+    (DocItemKind::Trait, decl.name.name.clone(), signature)
+}
+
+/// The parser fills in `null` (with no source span) when a function declares
+/// no return type
+fn is_implicit_return(type_ref: &TypeRef) -> bool {
+    matches!(type_ref, TypeRef::Named(name, span) if name == "null" && *span == Span::dummy())
+}
+
+fn ownership_prefix(ownership: Option<&OwnershipAnnotation>) -> &'static str {
+    match ownership {
+        Some(OwnershipAnnotation::Own) => "own ",
+        Some(OwnershipAnnotation::Borrow) => "borrow ",
+        Some(OwnershipAnnotation::Shared) => "shared ",
+        None => "",
+    }
+}
+
+fn format_params(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|p| {
+            format!(
+                "{}{}: {}",
+                ownership_prefix(p.ownership.as_ref()),
+                p.name.name,
+                format_type_ref(&p.type_ref)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_type_params(type_params: &[TypeParam]) -> String {
+    if type_params.is_empty() {
+        return String::new();
+    }
+    let params: Vec<String> = type_params
+        .iter()
+        .map(|tp| {
+            let mut text = tp.name.clone();
+            if let Some(bound) = &tp.bound {
+                text.push_str(&format!(" extends {}", format_type_ref(bound)));
+            }
+            if !tp.trait_bounds.is_empty() {
+                let bounds: Vec<&str> = tp
+                    .trait_bounds
+                    .iter()
+                    .map(|b| b.trait_name.as_str())
+                    .collect();
+                text.push_str(&format!(": {}", bounds.join(" + ")));
+            }
+            text
+        })
+        .collect();
+    format!("<{}>", params.join(", "))
+}
+
+/// Format a type reference for display
+fn format_type_ref(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Named(name, _) => name.clone(),
+        TypeRef::Array(inner, _) => match inner.as_ref() {
+            TypeRef::Union { .. } | TypeRef::Intersection { .. } | TypeRef::Function { .. } => {
+                format!("({})[]", format_type_ref(inner))
+            }
+            _ => format!("{}[]", format_type_ref(inner)),
+        },
+        TypeRef::Union { members, .. } => {
+            let formatted: Vec<String> = members.iter().map(format_type_ref).collect();
+            formatted.join(" | ")
+        }
+        TypeRef::Intersection { members, .. } => {
+            let formatted: Vec<String> = members.iter().map(format_type_ref).collect();
+            formatted.join(" & ")
+        }
+        TypeRef::Function {
+            params,
+            return_type,
+            ..
+        } => {
+            let param_strs: Vec<String> = params.iter().map(format_type_ref).collect();
+            format!(
+                "({}) -> {}",
+                param_strs.join(", "),
+                format_type_ref(return_type)
+            )
+        }
+        TypeRef::Structural { members, .. } => {
+            let field_strs: Vec<String> = members
+                .iter()
+                .map(|m| format!("{}: {}", m.name, format_type_ref(&m.type_ref)))
+                .collect();
+            format!("{{ {} }}", field_strs.join(", "))
+        }
+        TypeRef::Generic {
+            name, type_args, ..
+        } => {
+            let arg_strs: Vec<String> = type_args.iter().map(format_type_ref).collect();
+            format!("{}<{}>", name, arg_strs.join(", "))
+        }
+    }
+}
+
+/// Stable JSON form: `{ "version", "modules": [{ "file", "items": [...] }] }`
+pub fn to_json(modules: &[DocModule]) -> serde_json::Value {
+    let modules: Vec<_> = modules
+        .iter()
+        .map(|module| {
+            let items: Vec<_> = module
+                .items
+                .iter()
+                .map(|item| {
+                    serde_json::json!({
+                        "kind": item.kind.as_str(),
+                        "name": item.name,
+                        "signature": item.signature,
+                        "doc": item.doc,
+                        "exported": item.exported,
+                        "line": item.line,
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "file": module.file.display().to_string(),
+                "items": items,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "version": DOC_JSON_VERSION,
+        "modules": modules,
+    })
+}
+
+/// Markdown form: one section per file, one subsection per item
+pub fn to_markdown(modules: &[DocModule]) -> String {
+    let mut out = String::from("# API Documentation\n");
+    for module in modules.iter().filter(|m| !m.items.is_empty()) {
+        out.push_str(&format!("\n## `{}`\n", module.file.display()));
+        for item in &module.items {
+            out.push_str(&format!("\n### {} `{}`\n\n", item.kind.as_str(), item.name));
+            out.push_str(&format!("```atlas\n{}\n```\n", item.signature));
+            if let Some(doc) = &item.doc {
+                out.push_str(&format!("\n{}\n", doc));
+            }
+        }
+    }
+    out
+}
+
+/// Collect Atlas source files, sorted for deterministic output
+fn collect_files(paths: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path_str in paths {
+        let path = Path::new(path_str);
+        if path.is_dir() {
+            for entry in walkdir::WalkDir::new(path)
+                .follow_links(true)
+                .into_iter()
+                .filter_map(Result::ok)
+            {
+                let entry_path = entry.path();
+                if entry_path
+                    .extension()
+                    .is_some_and(|ext| ext == "at" || ext == "atlas" || ext == "atl")
+                {
+                    files.push(entry_path.to_path_buf());
+                }
+            }
+        } else if path.exists() {
+            files.push(path.to_path_buf());
+        } else {
+            anyhow::bail!("No such file or directory: {}", path.display());
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn item<'a>(items: &'a [DocItem], name: &str) -> &'a DocItem {
+        items
+            .iter()
+            .find(|i| i.name == name)
+            .unwrap_or_else(|| panic!("no item {} in {:?}", name, items))
+    }
+
+    #[test]
+    fn test_documented_function_has_signature_and_doc() {
+        let source = "/// Adds two numbers.\n/// Returns their sum.\nfn add(a: number, b: number) -> number {\n    return a + b;\n}\n";
+        let items = extract_items(source).unwrap();
+        assert_eq!(
+            items,
+            vec![DocItem {
+                kind: DocItemKind::Function,
+                name: "add".to_string(),
+                signature: "fn add(a: number, b: number) -> number".to_string(),
+                doc: Some("Adds two numbers.\nReturns their sum.".to_string()),
+                exported: false,
+                line: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_undocumented_function_has_no_doc() {
+        let items = extract_items("fn noop() { }").unwrap();
+        assert_eq!(items[0].signature, "fn noop()");
+        assert_eq!(items[0].doc, None);
+    }
+
+    #[test]
+    fn test_doc_comment_separated_by_code_is_not_attached() {
+        let source = "/// Orphaned\nlet x: number = 1;\nfn later() -> number { return x; }";
+        let items = extract_items(source).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(item(&items, "later").doc, None);
+    }
+
+    #[test]
+    fn test_doc_comments_inside_bodies_are_ignored() {
+        let source = "/// Outer\nfn outer() -> number {\n    /// not a doc target\n    let y: number = 2;\n    return y;\n}";
+        let items = extract_items(source).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].doc.as_deref(), Some("Outer"));
+    }
+
+    #[test]
+    fn test_exported_generic_function_and_types() {
+        let source = r#"
+/// Identity
+export fn id<T>(own value: T) -> T { return value; }
+
+/// A user id
+type UserId = string;
+
+/// Shapes with an area
+trait Shape {
+    fn area(self: Shape) -> number;
+}
+
+/// Exported alias
+export type Ids = UserId[];
+"#;
+        let items = extract_items(source).unwrap();
+        let id = item(&items, "id");
+        assert!(id.exported);
+        assert_eq!(id.signature, "fn id<T>(own value: T) -> T");
+        assert_eq!(id.doc.as_deref(), Some("Identity"));
+
+        let alias = item(&items, "UserId");
+        assert_eq!(alias.kind, DocItemKind::TypeAlias);
+        assert_eq!(alias.signature, "type UserId = string");
+        assert_eq!(alias.doc.as_deref(), Some("A user id"));
+
+        let shape = item(&items, "Shape");
+        assert_eq!(shape.kind, DocItemKind::Trait);
+        assert_eq!(
+            shape.signature,
+            "trait Shape {\n    fn area(self: Shape) -> number;\n}"
+        );
+
+        let ids = item(&items, "Ids");
+        assert!(ids.exported);
+        assert_eq!(ids.signature, "type Ids = UserId[]");
+        assert_eq!(ids.doc.as_deref(), Some("Exported alias"));
+    }
+
+    #[test]
+    fn test_doc_keeps_indentation_after_first_space() {
+        let source = "/// Example:\n///     add(1, 2)\nfn add(a: number, b: number) -> number { return a + b; }";
+        let items = extract_items(source).unwrap();
+        assert_eq!(items[0].doc.as_deref(), Some("Example:\n    add(1, 2)"));
+    }
+
+    #[test]
+    fn test_parse_error_is_reported() {
+        assert!(extract_items("fn broken( {").is_err());
+    }
+
+    #[test]
+    fn test_json_structure() {
+        let modules = vec![DocModule {
+            file: PathBuf::from("math.atlas"),
+            items: extract_items("/// Doubles n\nfn double(n: number) -> number { return n * 2; }")
+                .unwrap(),
+        }];
+        let json = to_json(&modules);
+        assert_eq!(json["version"], DOC_JSON_VERSION);
+        let entry = &json["modules"][0]["items"][0];
+        assert_eq!(json["modules"][0]["file"], "math.atlas");
+        assert_eq!(entry["kind"], "function");
+        assert_eq!(entry["name"], "double");
+        assert_eq!(entry["signature"], "fn double(n: number) -> number");
+        assert_eq!(entry["doc"], "Doubles n");
+        assert_eq!(entry["exported"], false);
+        assert_eq!(entry["line"], 2);
+    }
+
+    #[test]
+    fn test_markdown_output() {
+        let modules = vec![DocModule {
+            file: PathBuf::from("math.atlas"),
+            items: extract_items("/// Doubles n\nfn double(n: number) -> number { return n * 2; }")
+                .unwrap(),
+        }];
+        let markdown = to_markdown(&modules);
+        assert!(markdown.contains("## `math.atlas`"), "{}", markdown);
+        assert!(markdown.contains("### function `double`"), "{}", markdown);
+        assert!(
+            markdown.contains("```atlas\nfn double(n: number) -> number\n```\n\nDoubles n\n"),
+            "{}",
+            markdown
+        );
+    }
+
+    #[test]
+    fn test_run_writes_json_for_directory() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("b.atlas"),
+            "/// Second\nfn second() -> number { return 2; }",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("a.atlas"),
+            "/// First\nfn first() -> number { return 1; }",
+        )
+        .unwrap();
+        let out = dir.path().join("docs.json");
+
+        run(DocArgs {
+            paths: vec![dir.path().to_string_lossy().to_string()],
+            json: true,
+            output: Some(out.clone()),
+        })
+        .unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(out).unwrap()).unwrap();
+        let names: Vec<_> = json["modules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["items"][0]["name"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, vec!["first", "second"]);
+    }
+}
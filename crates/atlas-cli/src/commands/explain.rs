@@ -0,0 +1,38 @@
+//! Explain command - print the long-form description of an error code
+
+use anyhow::{bail, Result};
+use atlas_runtime::diagnostic::error_codes;
+
+/// Render the explanation for `code`
+///
+/// Fails for codes missing from the registry.
+pub fn render(code: &str) -> Result<String> {
+    match error_codes::explain(code) {
+        Some(text) => Ok(text),
+        None => bail!("Unknown error code: {}", code),
+    }
+}
+
+/// Print the explanation for `code` to stdout
+pub fn run(code: &str) -> Result<()> {
+    print!("{}", render(code)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_known_code() {
+        let text = render("AT0300").unwrap();
+        assert!(text.starts_with("AT0300: Filesystem permission denied"));
+        assert!(text.contains("help: "));
+    }
+
+    #[test]
+    fn test_explain_unknown_code() {
+        let err = render("AT4242").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown error code: AT4242");
+    }
+}
@@ -3,7 +3,7 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
-use atlas_formatter::{FormatConfig, FormatResult};
+use atlas_formatter::{FormatConfig, FormatResult, LineRange};
 
 /// Verbosity level for formatter output
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -21,11 +21,13 @@ pub enum Verbosity {
 pub struct FmtArgs {
     pub files: Vec<String>,
     pub check: bool,
+    pub diff: bool,
     pub write: bool,
     pub config_path: Option<PathBuf>,
     pub indent_size: Option<usize>,
     pub max_width: Option<usize>,
     pub trailing_commas: Option<bool>,
+    pub range: Option<LineRange>,
     pub verbosity: Verbosity,
 }
 
@@ -46,6 +48,7 @@ pub fn run(args: FmtArgs) -> Result<()> {
     if let Some(tc) = args.trailing_commas {
         config.trailing_commas = tc;
     }
+    config.range = args.range;
 
     // Collect all .at files from arguments
     let files = collect_files(&args.files)?;
@@ -93,7 +96,17 @@ pub fn run(args: FmtArgs) -> Result<()> {
             FormatResult::Ok(formatted) => {
                 let changed = formatted != source;
 
-                if args.check {
+                if args.diff {
+                    if changed {
+                        print!("{}", unified_diff(file, &source, &formatted));
+                        unformatted_count += 1;
+                    } else {
+                        unchanged_count += 1;
+                    }
+                    if args.verbosity == Verbosity::Verbose {
+                        eprintln!("{}", if changed { "would reformat" } else { "ok" });
+                    }
+                } else if args.check {
                     if changed {
                         if args.verbosity == Verbosity::Verbose {
                             eprintln!("would reformat");
@@ -141,7 +154,11 @@ pub fn run(args: FmtArgs) -> Result<()> {
     // Summary output
     let total_elapsed = start_time.elapsed();
 
-    if args.check {
+    if args.diff {
+        if unformatted_count > 0 {
+            std::process::exit(1);
+        }
+    } else if args.check {
         if unformatted_count > 0 {
             if args.verbosity != Verbosity::Quiet {
                 eprintln!();
@@ -176,6 +193,38 @@ pub fn run(args: FmtArgs) -> Result<()> {
     Ok(())
 }
 
+/// Parse a `START:END` line range for `--range`
+pub fn parse_range(value: &str) -> std::result::Result<LineRange, String> {
+    let (start, end) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected START:END, got '{}'", value))?;
+    let start: usize = start
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid start line '{}'", start))?;
+    let end: usize = end
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid end line '{}'", end))?;
+    if start == 0 || end < start {
+        return Err(format!(
+            "invalid range {}:{} (lines start at 1 and END must not precede START)",
+            start, end
+        ));
+    }
+    Ok(LineRange::new(start, end))
+}
+
+/// Unified diff from a file's current contents to its formatted form
+fn unified_diff(path: &Path, old: &str, new: &str) -> String {
+    let name = path.display().to_string();
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&name, &name)
+        .to_string()
+}
+
 /// Load format configuration from a file path or use defaults
 fn load_config(config_path: &Option<PathBuf>) -> Result<FormatConfig> {
     if let Some(path) = config_path {
@@ -187,6 +236,7 @@ fn load_config(config_path: &Option<PathBuf>) -> Result<FormatConfig> {
         // indent_size = 4
         // max_width = 100
         // trailing_commas = true
+        // reorder_imports = false
         let mut config = FormatConfig::default();
 
         for line in content.lines() {
@@ -215,6 +265,11 @@ fn load_config(config_path: &Option<PathBuf>) -> Result<FormatConfig> {
                             .parse()
                             .with_context(|| format!("Invalid trailing_commas: {}", value))?;
                     }
+                    "reorder_imports" => {
+                        config.reorder_imports = value
+                            .parse()
+                            .with_context(|| format!("Invalid reorder_imports: {}", value))?;
+                    }
                     _ => {
                         // Ignore unknown keys for forward compatibility
                     }
@@ -26,6 +26,8 @@ pub struct InstallArgs {
     pub verbose: bool,
     /// Quiet output (errors only)
     pub quiet: bool,
+    /// Require an up-to-date lockfile and never rewrite it
+    pub locked: bool,
 }
 
 impl Default for InstallArgs {
@@ -38,6 +40,7 @@ impl Default for InstallArgs {
             dry_run: false,
             verbose: false,
             quiet: false,
+            locked: false,
         }
     }
 }
@@ -87,6 +90,10 @@ pub fn run(args: InstallArgs) -> Result<()> {
         None
     };
 
+    if args.locked {
+        verify_locked(&manifest, existing_lockfile.as_ref())?;
+    }
+
     // Create progress indicator
     let spinner = if !args.quiet {
         let pb = ProgressBar::new_spinner();
@@ -185,9 +192,10 @@ pub fn run(args: InstallArgs) -> Result<()> {
         stats.downloaded += 1;
     }
 
-    // Generate/update lockfile
-    if !args.dry_run {
-        let new_lockfile = resolver.generate_lockfile(&resolution);
+    // Generate/update lockfile (a locked install already matches it)
+    if !args.dry_run && !args.locked {
+        let mut new_lockfile = resolver.generate_lockfile(&resolution);
+        new_lockfile.record_manifest(&manifest);
         new_lockfile.write_to_file(&lockfile_path)?;
 
         if args.verbose {
@@ -261,6 +269,26 @@ fn print_summary(stats: &InstallStats, dry_run: bool) {
     }
 }
 
+/// Fail unless `lockfile` exists and matches `manifest` (for `--locked`)
+pub fn verify_locked(manifest: &PackageManifest, lockfile: Option<&Lockfile>) -> Result<()> {
+    let Some(lockfile) = lockfile else {
+        if manifest.dependencies.is_empty() {
+            return Ok(());
+        }
+        bail!("atlas.lock is missing and --locked prevents creating it");
+    };
+
+    if let Err(drifts) = lockfile.verify_against(manifest) {
+        let details: Vec<String> = drifts.iter().map(|d| format!("  {}", d)).collect();
+        bail!(
+            "atlas.lock is out of date with atlas.toml and --locked prevents updating it:\n{}",
+            details.join("\n")
+        );
+    }
+
+    Ok(())
+}
+
 /// Find atlas.toml manifest file
 fn find_manifest(start_dir: &Path) -> Result<PathBuf> {
     let mut current = start_dir
@@ -371,6 +399,53 @@ version = "0.1.0"
         run(args).unwrap();
     }
 
+    #[test]
+    fn test_install_locked_requires_lockfile() {
+        let temp = TempDir::new().unwrap();
+        create_test_manifest(temp.path());
+
+        let args = InstallArgs {
+            project_dir: temp.path().to_path_buf(),
+            quiet: true,
+            locked: true,
+            ..Default::default()
+        };
+
+        assert!(run(args).is_err());
+        assert!(!temp.path().join("atlas.lock").exists());
+    }
+
+    #[test]
+    fn test_install_locked_rejects_drift() {
+        let temp = TempDir::new().unwrap();
+        create_test_manifest(temp.path());
+        let args = InstallArgs {
+            project_dir: temp.path().to_path_buf(),
+            quiet: true,
+            ..Default::default()
+        };
+        run(args.clone()).unwrap();
+
+        // Locked install against the lockfile just written succeeds
+        let locked = InstallArgs {
+            locked: true,
+            ..args
+        };
+        run(locked.clone()).unwrap();
+
+        // Adding a dependency makes the lockfile stale
+        let manifest_path = temp.path().join("atlas.toml");
+        let manifest = fs::read_to_string(&manifest_path).unwrap();
+        fs::write(
+            &manifest_path,
+            manifest.replace("bar = \"^2.0\"", "bar = \"^2.0\"\nbaz = \"^1.0\""),
+        )
+        .unwrap();
+
+        let error = run(locked).unwrap_err().to_string();
+        assert!(error.contains("baz"), "unexpected error: {}", error);
+    }
+
     #[test]
     fn test_install_dry_run() {
         let temp = TempDir::new().unwrap();
@@ -1,8 +1,13 @@
 pub mod add;
 pub mod ast;
+pub mod bench;
 pub mod build;
 pub mod check;
+pub mod config;
 pub mod debug;
+pub mod disasm;
+pub mod doc;
+pub mod explain;
 pub mod fmt;
 pub mod init;
 pub mod install;
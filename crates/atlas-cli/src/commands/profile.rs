@@ -7,7 +7,7 @@ use atlas_runtime::lexer::Lexer;
 use atlas_runtime::parser::Parser;
 use atlas_runtime::security::SecurityContext;
 use atlas_runtime::typechecker::TypeChecker;
-use atlas_runtime::vm::VM;
+use atlas_runtime::vm::{TraceFrame, VM};
 use atlas_runtime::Value;
 use std::path::PathBuf;
 
@@ -22,6 +22,8 @@ pub struct ProfileArgs {
     pub output_file: Option<PathBuf>,
     /// Show full detailed report (true) or summary only (false)
     pub detailed: bool,
+    /// Output format (text report or folded stacks)
+    pub format: ProfileFormat,
 }
 
 impl ProfileArgs {
@@ -31,6 +33,29 @@ impl ProfileArgs {
             hotspot_threshold: 1.0,
             output_file: None,
             detailed: true,
+            format: ProfileFormat::Text,
+        }
+    }
+}
+
+/// Output format for the profile command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProfileFormat {
+    /// Human-readable performance report
+    #[default]
+    Text,
+    /// Folded stacks (`main;foo;bar 123`) for inferno / flamegraph.pl
+    Folded,
+}
+
+impl std::str::FromStr for ProfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(ProfileFormat::Text),
+            "folded" => Ok(ProfileFormat::Folded),
+            _ => Err(format!("Unknown profile format: {}", s)),
         }
     }
 }
@@ -81,13 +106,14 @@ pub fn run(args: ProfileArgs) -> Result<()> {
     // --- Run with profiling ---
     let security = SecurityContext::allow_all();
     let mut vm = VM::with_profiling(bytecode);
+    vm.set_source(&args.file, &source);
 
     let result = vm
         .run(&security)
-        .map_err(|e| anyhow::anyhow!("Runtime error: {:?}", e))?;
+        .map_err(|e| runtime_error(&e, vm.error_trace()))?;
 
-    // --- Show program result ---
-    if let Some(ref val) = result {
+    // --- Show program result (folded output must stay machine-readable) ---
+    if let (Some(ref val), ProfileFormat::Text) = (&result, args.format) {
         if !matches!(val, Value::Null) {
             println!("Result: {}", val);
         }
@@ -98,12 +124,16 @@ pub fn run(args: ProfileArgs) -> Result<()> {
         .profiler()
         .expect("profiler was enabled but is absent after run");
 
-    let report = profiler.generate_report(args.hotspot_threshold);
-
-    let report_text = if args.detailed {
-        report.format_detailed()
-    } else {
-        format!("{}\n", report.format_summary())
+    let report_text = match args.format {
+        ProfileFormat::Folded => profiler.format_folded(),
+        ProfileFormat::Text => {
+            let report = profiler.generate_report(args.hotspot_threshold);
+            if args.detailed {
+                report.format_detailed()
+            } else {
+                format!("{}\n", report.format_summary())
+            }
+        }
     };
 
     match args.output_file {
@@ -132,6 +162,17 @@ fn diagnostics_to_error(file: &str, diags: Vec<atlas_runtime::Diagnostic>) -> an
     anyhow::anyhow!("Compilation failed:\n{}", messages.join("\n"))
 }
 
+fn runtime_error(
+    error: &atlas_runtime::value::RuntimeError,
+    trace: &[TraceFrame],
+) -> anyhow::Error {
+    let mut message = format!("Runtime error: {:?}", error);
+    for frame in trace {
+        message.push_str(&format!("\n    {}", frame));
+    }
+    anyhow::anyhow!(message)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +207,7 @@ mod tests {
             hotspot_threshold: 1.0,
             output_file: Some(out.path().to_path_buf()),
             detailed: true,
+            format: ProfileFormat::Text,
         };
         run(args).unwrap();
         let content = std::fs::read_to_string(out.path()).unwrap();
@@ -184,6 +226,7 @@ mod tests {
             hotspot_threshold: 1.0,
             output_file: None,
             detailed: false,
+            format: ProfileFormat::Text,
         };
         assert!(run(args).is_ok());
     }
@@ -205,6 +248,7 @@ mod tests {
             hotspot_threshold: 50.0,
             output_file: None,
             detailed: true,
+            format: ProfileFormat::Text,
         };
         assert!(run(args).is_ok());
     }
@@ -215,4 +259,53 @@ mod tests {
         let args = ProfileArgs::new(f.path().to_str().unwrap());
         assert!(run(args).is_err());
     }
+
+    #[test]
+    fn test_profile_runtime_error_shows_trace() {
+        let f = write_temp("fn get(a: number[]) -> number {\n    return a[3];\n}\nget([1]);");
+        let path = f.path().to_str().unwrap().to_string();
+        let err = run(ProfileArgs::new(&path)).unwrap_err().to_string();
+        assert!(err.contains("OutOfBounds"), "{}", err);
+        assert!(err.contains(&format!("at get ({}:2:12)", path)), "{}", err);
+        assert!(
+            err.contains(&format!("at <main> ({}:4:1)", path)),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_profile_format_parse() {
+        assert_eq!(
+            "text".parse::<ProfileFormat>().unwrap(),
+            ProfileFormat::Text
+        );
+        assert_eq!(
+            "folded".parse::<ProfileFormat>().unwrap(),
+            ProfileFormat::Folded
+        );
+        assert!("svg".parse::<ProfileFormat>().is_err());
+    }
+
+    #[test]
+    fn test_profile_folded_output() {
+        let src = write_temp(
+            "fn helper(n: number) -> number { var total: number = 0; var i: number = 0; while (i < n) { total = total + i; i = i + 1; } return total; }\nlet r: number = helper(10);",
+        );
+        let out = NamedTempFile::new().unwrap();
+        let mut args = ProfileArgs::new(src.path().to_str().unwrap());
+        args.output_file = Some(out.path().to_path_buf());
+        args.format = ProfileFormat::Folded;
+        run(args).unwrap();
+
+        let content = std::fs::read_to_string(out.path()).unwrap();
+        let helper_line = content
+            .lines()
+            .find(|line| line.starts_with("main;helper "))
+            .unwrap_or_else(|| panic!("no main;helper stack in: {}", content));
+        let count: u64 = helper_line.rsplit(' ').next().unwrap().parse().unwrap();
+        // The loop body runs 10 times, each executing several instructions.
+        assert!(count > 50, "implausible count: {}", helper_line);
+        assert!(content.lines().any(|line| line.starts_with("main ")));
+    }
 }
@@ -192,14 +192,26 @@ pub fn run(args: PublishArgs) -> Result<()> {
     Ok(())
 }
 
-/// Validate manifest contents
+/// Validate manifest contents, reporting lints as warnings
 fn validate_manifest(manifest: &PackageManifest, skip: bool) -> StepResult {
     if skip {
         return StepResult::Skip("skipped".to_string());
     }
 
     match Validator::validate(manifest) {
-        Ok(_) => StepResult::Success("valid".to_string()),
+        Ok(_) => {
+            let warnings = Validator::lint(manifest);
+            if warnings.is_empty() {
+                StepResult::Success("valid".to_string())
+            } else {
+                let msg = warnings
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                StepResult::Warning(msg)
+            }
+        }
         Err(errors) => {
             let msg = errors
                 .into_iter()
@@ -474,6 +486,8 @@ version = "0.1.0"
         let manifest_content = r#"[package]
 name = "test"
 version = "1.0.0"
+description = "A test package"
+license = "MIT"
 
 [dependencies]
 "#;
@@ -482,6 +496,26 @@ version = "1.0.0"
         assert!(matches!(result, StepResult::Success(_)));
     }
 
+    #[test]
+    fn test_validate_manifest_lints() {
+        let manifest_content = r#"[package]
+name = "test"
+version = "1.0.0"
+
+[dependencies]
+foo = "=1.2.0"
+"#;
+        let manifest = PackageManifest::from_str(manifest_content).unwrap();
+        match validate_manifest(&manifest, false) {
+            StepResult::Warning(msg) => {
+                assert!(msg.contains("No license specified"));
+                assert!(msg.contains("No description specified"));
+                assert!(msg.contains("'foo' is pinned"));
+            }
+            _ => panic!("Expected lint warnings"),
+        }
+    }
+
     #[test]
     fn test_validate_manifest_skip() {
         let manifest_content = r#"[package]
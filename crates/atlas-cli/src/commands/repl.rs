@@ -1,7 +1,7 @@
 //! REPL command implementation
 
 use anyhow::Result;
-use atlas_runtime::repl::ReplBinding;
+use atlas_runtime::repl::{ReplBinding, ReplResult};
 use atlas_runtime::{InputCompleteness, MultilineInput, ReplCore, Type};
 use rustyline::error::ReadlineError;
 use rustyline::DefaultEditor;
@@ -33,7 +33,9 @@ pub fn run(use_tui: bool, no_history: bool, config: &crate::config::Config) -> R
     // Display welcome message
     println!("Atlas v{} REPL", atlas_runtime::VERSION);
     println!("Type expressions or statements, or :quit to exit");
-    println!("Commands: :quit, :reset, :clear, :help, :load <file>, :type <expr>, :vars [page]");
+    println!(
+        "Commands: :quit, :reset, :clear, :help, :load <file>, :reload, :save <file>, :type <expr>, :vars [page]"
+    );
     println!();
 
     // Multiline input state
@@ -67,6 +69,22 @@ pub fn run(use_tui: bool, no_history: bool, config: &crate::config::Config) -> R
                         continue;
                     }
 
+                    // :reload - re-run the last loaded file
+                    if trimmed == ":reload" || trimmed == ":r!" {
+                        handle_reload(&mut repl, config);
+                        continue;
+                    }
+
+                    // :save <file> - write this session's definitions to a file
+                    if let Some(path_str) = trimmed.strip_prefix(":save").map(str::trim) {
+                        if path_str.is_empty() {
+                            println!("Usage: :save <file>");
+                            continue;
+                        }
+                        handle_save(&repl, path_str);
+                        continue;
+                    }
+
                     // :load <file> - load and execute a file
                     if let Some(path_str) = trimmed
                         .strip_prefix(":load")
@@ -238,25 +256,22 @@ fn handle_load(repl: &mut ReplCore, path_str: &str, config: &crate::config::Conf
     let path = Path::new(path_str);
 
     match repl.load_file(path) {
+        Ok(result) => print_load_result(&result, "Loaded", path, config),
+        Err(e) => {
+            println!("error: {}", e);
+        }
+    }
+}
+
+/// Handle :reload command
+fn handle_reload(repl: &mut ReplCore, config: &crate::config::Config) {
+    match repl.reload() {
         Ok(result) => {
-            if !result.diagnostics.is_empty() {
-                for diag in &result.diagnostics {
-                    println!("{}", format_diagnostic(diag, ""));
-                }
-            } else {
-                println!("Loaded '{}'", path.display());
-                // Show any bindings created
-                if config.show_types && !result.bindings.is_empty() {
-                    for binding in &result.bindings {
-                        println!(
-                            "  {}: {} = {}",
-                            binding.name,
-                            format_type(&binding.ty, config.no_color),
-                            binding.value
-                        );
-                    }
-                }
-            }
+            let path = repl
+                .loaded_file()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            print_load_result(&result, "Reloaded", &path, config);
         }
         Err(e) => {
             println!("error: {}", e);
@@ -264,6 +279,40 @@ fn handle_load(repl: &mut ReplCore, path_str: &str, config: &crate::config::Conf
     }
 }
 
+/// Handle :save command
+fn handle_save(repl: &ReplCore, path_str: &str) {
+    let path = Path::new(path_str);
+
+    match repl.save(path) {
+        Ok(count) => println!("Saved {} definition(s) to '{}'", count, path.display()),
+        Err(e) => {
+            println!("error: {}", e);
+        }
+    }
+}
+
+/// Print diagnostics or the bindings created by a loaded file
+fn print_load_result(result: &ReplResult, verb: &str, path: &Path, config: &crate::config::Config) {
+    if !result.diagnostics.is_empty() {
+        for diag in &result.diagnostics {
+            println!("{}", format_diagnostic(diag, ""));
+        }
+    } else {
+        println!("{} '{}'", verb, path.display());
+        // Show any bindings created
+        if config.show_types && !result.bindings.is_empty() {
+            for binding in &result.bindings {
+                println!(
+                    "  {}: {} = {}",
+                    binding.name,
+                    format_type(&binding.ty, config.no_color),
+                    binding.value
+                );
+            }
+        }
+    }
+}
+
 /// Print help information
 fn print_help() {
     println!("Atlas REPL Commands:");
@@ -271,6 +320,8 @@ fn print_help() {
     println!("  :reset, :clear    Clear all variables and functions");
     println!("  :help, :h         Show this help message");
     println!("  :load <file>, :l  Load and execute an Atlas file");
+    println!("  :reload, :r!      Re-run the last loaded file");
+    println!("  :save <file>      Save this session's definitions to a file");
     println!("  :type <expr>      Show inferred type of an expression");
     println!("  :vars [page]      List variables with types and values");
     println!();
@@ -2,17 +2,39 @@
 
 use anyhow::Result;
 use atlas_runtime::{Atlas, SecurityContext};
+use std::path::Path;
 
 /// Run an Atlas source file
 ///
 /// Compiles and executes the source file, printing the result to stdout.
 /// If `json_output` is true, diagnostics are printed in JSON format.
-pub fn run(file_path: &str, json_output: bool) -> Result<()> {
+/// A `prelude` module's exports are visible in every module without an import.
+pub fn run(file_path: &str, json_output: bool, prelude: Option<&Path>) -> Result<()> {
     // Create runtime with full permissions (like go run, cargo run, python, node, etc.)
-    let runtime = Atlas::new_with_security(SecurityContext::allow_all());
+    let mut runtime = Atlas::new_with_security(SecurityContext::allow_all());
+    if let Some(prelude) = prelude {
+        runtime = runtime.with_prelude(prelude);
+    }
 
     // Use eval_file to support module imports
-    match runtime.eval_file(file_path) {
+    report(runtime.eval_file(file_path), file_path, json_output)
+}
+
+/// Run an inline Atlas program passed with `--eval`
+///
+/// Behaves like [`run`] but takes the source directly instead of a path.
+pub fn run_eval(source: &str, json_output: bool) -> Result<()> {
+    let runtime = Atlas::new_with_security(SecurityContext::allow_all());
+    report(runtime.eval(source), "<eval>", json_output)
+}
+
+/// Print the program result or its diagnostics
+fn report(
+    result: atlas_runtime::RuntimeResult<atlas_runtime::Value>,
+    origin: &str,
+    json_output: bool,
+) -> Result<()> {
+    match result {
         Ok(value) => {
             // Print the result value if it's not null
             if !matches!(value, atlas_runtime::Value::Null) {
@@ -29,7 +51,7 @@ pub fn run(file_path: &str, json_output: bool) -> Result<()> {
                 }
             } else {
                 // Human-readable format
-                eprintln!("Errors occurred while running {}:", file_path);
+                eprintln!("Errors occurred while running {}:", origin);
                 for diag in &diagnostics {
                     eprintln!("{}", format_diagnostic(diag));
                 }
@@ -68,13 +90,13 @@ mod tests {
         let mut temp_file = NamedTempFile::new().unwrap();
         writeln!(temp_file, "1 + 2;").unwrap();
 
-        let result = run(temp_file.path().to_str().unwrap(), false);
+        let result = run(temp_file.path().to_str().unwrap(), false, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_run_missing_file() {
-        let result = run("nonexistent.atl", false);
+        let result = run("nonexistent.atl", false, None);
         assert!(result.is_err());
     }
 
@@ -84,10 +106,20 @@ mod tests {
         let mut temp_file = NamedTempFile::new().unwrap();
         writeln!(temp_file, "let x: number = \"wrong\";").unwrap();
 
-        let result = run(temp_file.path().to_str().unwrap(), true);
+        let result = run(temp_file.path().to_str().unwrap(), true, None);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_run_eval_expression() {
+        assert!(run_eval("1 + 2", false).is_ok());
+    }
+
+    #[test]
+    fn test_run_eval_type_error() {
+        assert!(run_eval("let x: number = \"wrong\";", true).is_err());
+    }
+
     #[test]
     fn test_format_diagnostic() {
         let diag = Diagnostic::error("Test error".to_string(), Span::new(0, 3));
@@ -1,9 +1,10 @@
 //! Test command - run Atlas tests
 
-use crate::testing::{TestReporter, TestRunner, TestSuite};
+use crate::testing::{EventWriter, ReportFormat, TestReporter, TestRunner, TestSuite};
 use anyhow::Result;
 use colored::*;
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// Arguments for the test command
 pub struct TestArgs {
@@ -19,6 +20,8 @@ pub struct TestArgs {
     pub dir: PathBuf,
     /// Output in JSON format
     pub json: bool,
+    /// Stream events in a standard report format (overrides `json`)
+    pub format: Option<ReportFormat>,
 }
 
 impl Default for TestArgs {
@@ -30,12 +33,22 @@ impl Default for TestArgs {
             no_color: false,
             dir: PathBuf::from("."),
             json: false,
+            format: None,
         }
     }
 }
 
+/// Parse a `--format` value
+pub fn parse_format(s: &str) -> Result<ReportFormat, String> {
+    s.parse()
+}
+
 /// Run the test command
 pub fn run(args: TestArgs) -> Result<()> {
+    if let Some(format) = args.format {
+        return run_streaming(&args, format);
+    }
+
     if args.no_color {
         colored::control::set_override(false);
     }
@@ -138,6 +151,29 @@ pub fn run(args: TestArgs) -> Result<()> {
     Ok(())
 }
 
+/// Run tests, streaming start/result events to stdout in `format`
+fn run_streaming(args: &TestArgs, format: ReportFormat) -> Result<()> {
+    let mut suite = TestSuite::discover(&args.dir);
+    for (path, error) in &suite.parse_errors {
+        eprintln!("{}: {}", path.display(), error);
+    }
+    if let Some(pattern) = &args.pattern {
+        suite = suite.filter(pattern);
+    }
+
+    let writer = EventWriter::new(format, std::io::stdout());
+    let start = Instant::now();
+    writer.suite_started(suite.len());
+    let runner = TestRunner::new().with_parallel(!args.sequential);
+    let runs = runner.run_with_events(&suite, |event| writer.event(event));
+    writer.suite_finished(&runs, start.elapsed());
+
+    if runs.iter().any(|r| r.result.is_fail()) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
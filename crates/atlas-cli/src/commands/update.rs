@@ -160,7 +160,8 @@ pub fn run(args: UpdateArgs) -> Result<()> {
     }
 
     // Generate new lockfile
-    let new_lockfile = resolver.generate_lockfile(&resolution);
+    let mut new_lockfile = resolver.generate_lockfile(&resolution);
+    new_lockfile.record_manifest(&manifest);
 
     // Write lockfile
     new_lockfile.write_to_file(&lockfile_path)?;
@@ -21,6 +21,8 @@ pub struct WatchConfig {
     pub json_output: bool,
     /// Show verbose timing information
     pub verbose: bool,
+    /// Prelude module from the project config (`[build] prelude`)
+    pub prelude: Option<PathBuf>,
 }
 
 impl Default for WatchConfig {
@@ -30,6 +32,7 @@ impl Default for WatchConfig {
             continue_on_error: true,
             json_output: false,
             verbose: false,
+            prelude: None,
         }
     }
 }
@@ -145,7 +148,10 @@ fn is_relevant_change(changed_path: &Path, watched_path: &Path) -> bool {
 fn run_once(path: &Path, config: &WatchConfig) {
     let start = std::time::Instant::now();
 
-    let runtime = Atlas::new_with_security(SecurityContext::allow_all());
+    let mut runtime = Atlas::new_with_security(SecurityContext::allow_all());
+    if let Some(prelude) = &config.prelude {
+        runtime = runtime.with_prelude(prelude.clone());
+    }
 
     match runtime.eval_file(path.to_str().unwrap_or("")) {
         Ok(value) => {
@@ -4,6 +4,7 @@
 
 use atlas_runtime::debugger::{
     BreakpointId, DebugRequest, DebugResponse, DebuggerSession, PauseReason, SourceLocation,
+    WatchValue,
 };
 use atlas_runtime::SecurityContext;
 use rustyline::error::ReadlineError;
@@ -124,6 +125,8 @@ impl DebugRepl {
             "breakpoints" | "bp" => self.cmd_breakpoints(),
             "vars" | "v" | "locals" => self.cmd_vars(args),
             "print" | "p" | "inspect" => self.cmd_print(args),
+            "watch" | "w" => self.cmd_watch(args),
+            "unwatch" | "uw" => self.cmd_unwatch(args),
             "backtrace" | "bt" | "where" => self.cmd_backtrace(),
             "location" | "loc" => self.cmd_location(),
             _ => println!(
@@ -156,6 +159,9 @@ impl DebugRepl {
         println!("  \x1b[1;33mInspection:\x1b[0m");
         println!("    vars, v, locals     Show local variables");
         println!("    print <expr>, p     Evaluate and print expression");
+        println!("    watch <expr>, w     Print expression after every step");
+        println!("    watch               Show all watch expressions");
+        println!("    unwatch <expr>, uw  Remove a watch expression");
         println!("    backtrace, bt       Show call stack");
         println!("    location, loc       Show current location");
         println!();
@@ -395,6 +401,50 @@ impl DebugRepl {
         }
     }
 
+    fn cmd_watch(&mut self, args: &[&str]) {
+        if args.is_empty() {
+            self.show_watches();
+            return;
+        }
+
+        let expression = args.join(" ");
+        let response = self
+            .session
+            .process_request(DebugRequest::AddWatch { expression });
+
+        match response {
+            DebugResponse::WatchAdded { expression } => {
+                println!("Watching '{}'.", expression);
+            }
+            DebugResponse::Error { message } => {
+                println!("\x1b[31mError:\x1b[0m {}", message);
+            }
+            _ => {}
+        }
+    }
+
+    fn cmd_unwatch(&mut self, args: &[&str]) {
+        if args.is_empty() {
+            println!("Usage: unwatch <expression>");
+            return;
+        }
+
+        let expression = args.join(" ");
+        let response = self
+            .session
+            .process_request(DebugRequest::RemoveWatch { expression });
+
+        match response {
+            DebugResponse::WatchRemoved { expression } => {
+                println!("No longer watching '{}'.", expression);
+            }
+            DebugResponse::Error { message } => {
+                println!("\x1b[31mError:\x1b[0m {}", message);
+            }
+            _ => {}
+        }
+    }
+
     fn cmd_backtrace(&mut self) {
         let response = self.session.process_request(DebugRequest::GetStack);
 
@@ -432,7 +482,7 @@ impl DebugRepl {
 
     // ── Helper methods ────────────────────────────────────────────────────────
 
-    fn handle_pause_response(&self, response: DebugResponse) {
+    fn handle_pause_response(&mut self, response: DebugResponse) {
         match response {
             DebugResponse::Paused {
                 reason, location, ..
@@ -461,6 +511,8 @@ impl DebugRepl {
                 if let Some(loc) = location {
                     self.display_source_context(loc.line as usize, 2);
                 }
+
+                self.show_watches();
             }
             DebugResponse::Error { message } => {
                 println!("\x1b[31mError:\x1b[0m {}", message);
@@ -469,6 +521,25 @@ impl DebugRepl {
         }
     }
 
+    /// Print the current value of every watch expression (nothing if none are set).
+    fn show_watches(&mut self) {
+        let response = self
+            .session
+            .process_request(DebugRequest::GetWatches { frame_index: 0 });
+
+        if let DebugResponse::Watches { watches } = response {
+            if watches.is_empty() {
+                return;
+            }
+
+            println!("\x1b[1mWatches:\x1b[0m");
+            for watch in &watches {
+                println!("  {}", format_watch(watch));
+            }
+            println!();
+        }
+    }
+
     fn show_current_location(&mut self) {
         let response = self.session.process_request(DebugRequest::GetLocation);
 
@@ -525,6 +596,15 @@ impl DebugRepl {
     }
 }
 
+/// Format a watch as `expr = value` or `expr: <error: message>`.
+fn format_watch(watch: &WatchValue) -> String {
+    match (&watch.value, &watch.error) {
+        (Some(value), _) => format!("{} = {}", watch.expression, value),
+        (None, Some(error)) => format!("{}: \x1b[31m<error: {}>\x1b[0m", watch.expression, error),
+        (None, None) => format!("{}: <unavailable>", watch.expression),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,6 +36,19 @@ struct Cli {
     command: Commands,
 }
 
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print a JSON Schema for atlas.toml, for editor validation
+    ///
+    /// The global config (~/.atlas/config.toml) is described by the
+    /// schema's #/definitions/GlobalConfig entry.
+    Schema {
+        /// Write to file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Run an Atlas source file
@@ -47,10 +60,15 @@ enum Commands {
     ///     atlas run main.atl              Run a program
     ///     atlas run main.atl --watch      Watch for changes
     ///     atlas run main.atl --json       Output diagnostics as JSON
+    ///     atlas run -e "1 + 2"            Evaluate an inline program
     #[command(visible_alias = "r")]
     Run {
         /// Path to the Atlas source file
-        file: String,
+        #[arg(required_unless_present = "eval")]
+        file: Option<String>,
+        /// Evaluate an inline program instead of a file
+        #[arg(long, short = 'e', value_name = "SOURCE", conflicts_with_all = ["file", "watch"])]
+        eval: Option<String>,
         /// Output diagnostics in JSON format
         #[arg(long, env = "ATLAS_JSON")]
         json: bool,
@@ -91,17 +109,25 @@ enum Commands {
     ///     atlas build                   Build with default profile
     ///     atlas build --release         Build optimized release
     ///     atlas build --profile=test    Build with test profile
+    ///     atlas build --target wasm     Build a WebAssembly module
+    ///     atlas build --watch           Rebuild on file changes
     #[command(visible_alias = "b")]
     Build {
         /// Build profile (dev, release, test, or custom)
         #[arg(long, short = 'p')]
         profile: Option<String>,
+        /// Output target (e.g. wasm)
+        #[arg(long)]
+        target: Option<String>,
         /// Build in release mode (shorthand for --profile=release)
         #[arg(long)]
         release: bool,
         /// Clean build (ignore cache)
         #[arg(long)]
         clean: bool,
+        /// Print the build plan as JSON and exit without compiling
+        #[arg(long)]
+        build_plan: bool,
         /// Verbose output
         #[arg(long, short = 'v')]
         verbose: bool,
@@ -111,6 +137,21 @@ enum Commands {
         /// JSON output
         #[arg(long, env = "ATLAS_JSON")]
         json: bool,
+        /// Stream progress as newline-delimited JSON events (for IDEs)
+        #[arg(long)]
+        json_stream: bool,
+        /// Maximum number of modules compiled in parallel
+        #[arg(long, short = 'j')]
+        jobs: Option<usize>,
+        /// Fail if atlas.lock is missing or out of date
+        #[arg(long)]
+        locked: bool,
+        /// Rebuild incrementally on file changes
+        #[arg(long, short = 'w', conflicts_with_all = ["build_plan", "json_stream"])]
+        watch: bool,
+        /// Don't clear terminal before each rebuild (with --watch)
+        #[arg(long)]
+        no_clear: bool,
     },
 
     /// Start an interactive REPL
@@ -123,6 +164,8 @@ enum Commands {
     ///     :quit, :q      Exit REPL
     ///     :reset         Clear all definitions
     ///     :load <file>   Load and run a file
+    ///     :reload, :r!   Re-run the last loaded file
+    ///     :save <file>   Save session definitions to a file
     ///     :type <expr>   Show expression type
     ///     :vars          List defined variables
     ///
@@ -152,6 +195,52 @@ enum Commands {
         file: String,
     },
 
+    /// Disassemble the bytecode compiled from a source file
+    ///
+    /// Prints the constant pool and every instruction with its offset,
+    /// operands, resolved constant values and source line.
+    ///
+    /// EXAMPLES:
+    ///     atlas disasm main.atl           Print the bytecode listing
+    ///     atlas disasm main.atl -O        Show bytecode after optimization
+    Disasm {
+        /// Path to the Atlas source file
+        file: String,
+        /// Run the bytecode optimizer before disassembling
+        #[arg(long, short = 'O')]
+        optimize: bool,
+    },
+
+    /// Inspect the configuration format
+    ///
+    /// EXAMPLES:
+    ///     atlas config schema                    Print the JSON Schema
+    ///     atlas config schema -o atlas.schema.json
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Generate documentation from doc comments
+    ///
+    /// Collects `///` doc comments attached to top-level functions, type
+    /// aliases, and traits, and emits them with their signatures.
+    ///
+    /// EXAMPLES:
+    ///     atlas doc                       Document the current directory
+    ///     atlas doc src/ -o API.md        Write Markdown to a file
+    ///     atlas doc src/ --json           Stable JSON for tooling
+    Doc {
+        /// Files or directories to document (defaults to current directory)
+        paths: Vec<String>,
+        /// Output in JSON format instead of Markdown
+        #[arg(long, env = "ATLAS_JSON")]
+        json: bool,
+        /// Write documentation to this file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<std::path::PathBuf>,
+    },
+
     /// Dump typecheck information to JSON
     ///
     /// Type-checks the source file and outputs detailed type information
@@ -165,6 +254,18 @@ enum Commands {
         file: String,
     },
 
+    /// Explain an error code
+    ///
+    /// Prints the title, long-form explanation and help text for a
+    /// diagnostic code such as AT0300.
+    ///
+    /// EXAMPLES:
+    ///     atlas explain AT0300            Explain a permission error
+    Explain {
+        /// The error code to explain (e.g. AT0300)
+        code: String,
+    },
+
     /// Format Atlas source files
     ///
     /// Automatically formats Atlas code according to style guidelines.
@@ -173,6 +274,7 @@ enum Commands {
     /// EXAMPLES:
     ///     atlas fmt src/                  Format all files in src/
     ///     atlas fmt main.atl --check      Check without modifying
+    ///     atlas fmt src/ --diff           Show what would change
     ///     atlas fmt . --write             Format all files recursively
     ///     atlas fmt main.atl --indent-size=2
     #[command(visible_alias = "f")]
@@ -183,6 +285,9 @@ enum Commands {
         /// Check formatting without modifying files
         #[arg(long)]
         check: bool,
+        /// Print a unified diff of formatting changes without modifying files
+        #[arg(long, conflicts_with = "write")]
+        diff: bool,
         /// Write changes to files (explicit mode)
         #[arg(long, short = 'w')]
         write: bool,
@@ -198,6 +303,9 @@ enum Commands {
         /// Enable or disable trailing commas
         #[arg(long)]
         trailing_commas: Option<bool>,
+        /// Only format statements within lines START:END (1-based, inclusive)
+        #[arg(long, value_name = "START:END", value_parser = commands::fmt::parse_range)]
+        range: Option<atlas_formatter::LineRange>,
         /// Verbose output with timing information
         #[arg(long, short = 'v')]
         verbose: bool,
@@ -215,6 +323,7 @@ enum Commands {
     ///     atlas profile slow.atl          Profile execution
     ///     atlas profile slow.atl -o report.txt  Save report
     ///     atlas profile slow.atl --summary      Brief output
+    ///     atlas profile slow.atl --format=folded -o out.folded  Flamegraph input
     Profile {
         /// Path to the Atlas source file
         file: String,
@@ -227,6 +336,9 @@ enum Commands {
         /// Print summary only (no detailed report)
         #[arg(long)]
         summary: bool,
+        /// Output format: text (report) or folded (flamegraph stacks)
+        #[arg(long, default_value = "text")]
+        format: commands::profile::ProfileFormat,
     },
 
     /// Run tests in a directory
@@ -240,6 +352,7 @@ enum Commands {
     ///     atlas test --dir=tests/unit     Specific directory
     ///     atlas test --verbose            Show all test names
     ///     atlas test --sequential         Disable parallelism
+    ///     atlas test --format=tap         TAP output for CI
     #[command(visible_alias = "t")]
     Test {
         /// Filter tests by name pattern
@@ -259,6 +372,43 @@ enum Commands {
         /// Output in JSON format
         #[arg(long, env = "ATLAS_JSON")]
         json: bool,
+        /// Stream per-test events for CI tools (libtest-json or tap)
+        #[arg(long, value_name = "FORMAT", value_parser = commands::test::parse_format)]
+        format: Option<testing::ReportFormat>,
+    },
+
+    /// Run benchmarks in a directory
+    ///
+    /// Discovers functions prefixed with 'bench_' and times them on the VM,
+    /// reporting mean, median, standard deviation, and ops/second.
+    ///
+    /// EXAMPLES:
+    ///     atlas bench                     Run all benchmarks
+    ///     atlas bench sort                Filter by pattern
+    ///     atlas bench --iterations=1000   More measured iterations
+    ///     atlas bench --jit               Run eligible functions natively
+    ///     atlas bench --json              Machine-readable results
+    Bench {
+        /// Filter benchmarks by name pattern
+        pattern: Option<String>,
+        /// Measured iterations per benchmark
+        #[arg(long, default_value = "100")]
+        iterations: usize,
+        /// Unmeasured warmup iterations per benchmark
+        #[arg(long, default_value = "10")]
+        warmup: usize,
+        /// Run eligible functions through the JIT to compare
+        #[arg(long)]
+        jit: bool,
+        /// Benchmark directory (defaults to current directory)
+        #[arg(long, default_value = ".")]
+        dir: std::path::PathBuf,
+        /// Disable colored output
+        #[arg(long, env = "NO_COLOR")]
+        no_color: bool,
+        /// Output in JSON format
+        #[arg(long, env = "ATLAS_JSON")]
+        json: bool,
     },
 
     /// Debug an Atlas program interactively
@@ -459,6 +609,9 @@ enum Commands {
         /// Quiet output (errors only)
         #[arg(long, short = 'q')]
         quiet: bool,
+        /// Fail if atlas.lock is missing or out of date instead of updating it
+        #[arg(long)]
+        locked: bool,
     },
 
     /// Update project dependencies
@@ -571,13 +724,19 @@ fn main() -> Result<()> {
 
     // Load project configuration (atlas.toml) if in a project directory
     // This is available for commands that need project-level settings
-    let _project_config = atlas_config::ConfigLoader::new()
+    // Unknown fields only warn, so configs written for newer Atlas versions still load
+    let project_config = atlas_config::ConfigLoader::new()
+        .with_unknown_field_policy(atlas_config::UnknownFieldPolicy::Warn)
         .load_from_directory(&std::env::current_dir()?)
         .ok(); // Optional - not all commands run in a project
+    for warning in project_config.iter().flat_map(|c| &c.warnings) {
+        eprintln!("warning: {}", warning);
+    }
 
     match cli.command {
         Commands::Run {
             file,
+            eval,
             json,
             watch,
             no_clear,
@@ -585,19 +744,27 @@ fn main() -> Result<()> {
         } => {
             // Command-line flag overrides environment variable
             let use_json = json || cli_config.default_json;
+            let prelude = project_config
+                .as_ref()
+                .and_then(|config| config.prelude_path());
 
-            if watch {
+            if let Some(source) = eval {
+                commands::run::run_eval(&source, use_json)?;
+            } else if watch {
+                let file = file.expect("clap requires a file without --eval");
                 // Watch mode
                 let config = commands::watch::WatchConfig {
                     clear_screen: !no_clear,
                     continue_on_error: true,
                     json_output: use_json,
                     verbose,
+                    prelude,
                 };
                 commands::watch::run_watch(&file, config)?;
             } else {
                 // Normal run
-                commands::run::run(&file, use_json)?;
+                let file = file.expect("clap requires a file without --eval");
+                commands::run::run(&file, use_json, prelude.as_deref())?;
             }
         }
         Commands::Check { file, json } => {
@@ -607,21 +774,35 @@ fn main() -> Result<()> {
         }
         Commands::Build {
             profile,
+            target,
             release,
             clean,
+            build_plan,
             verbose,
             quiet,
             json,
+            json_stream,
+            jobs,
+            locked,
+            watch,
+            no_clear,
         } => {
             // Command-line flag overrides environment variable
             let use_json = json || cli_config.default_json;
             let args = commands::build::BuildArgs {
                 profile,
+                target,
                 release,
                 clean,
+                build_plan,
                 verbose,
                 quiet,
                 json: use_json,
+                json_stream,
+                jobs,
+                locked,
+                watch,
+                no_clear,
                 ..Default::default()
             };
             commands::build::run(args)?;
@@ -634,17 +815,37 @@ fn main() -> Result<()> {
         Commands::Ast { file } => {
             commands::ast::run(&file)?;
         }
+        Commands::Disasm { file, optimize } => {
+            commands::disasm::run(&file, optimize)?;
+        }
+        Commands::Doc {
+            paths,
+            json,
+            output,
+        } => {
+            let args = commands::doc::DocArgs {
+                paths,
+                json,
+                output,
+            };
+            commands::doc::run(args)?;
+        }
         Commands::Typecheck { file } => {
             commands::typecheck::run(&file)?;
         }
+        Commands::Explain { code } => {
+            commands::explain::run(&code)?;
+        }
         Commands::Fmt {
             files,
             check,
+            diff,
             write,
             config,
             indent_size,
             max_width,
             trailing_commas,
+            range,
             verbose,
             quiet,
         } => {
@@ -658,11 +859,13 @@ fn main() -> Result<()> {
             let args = commands::fmt::FmtArgs {
                 files,
                 check,
+                diff,
                 write,
                 config_path: config,
                 indent_size,
                 max_width,
                 trailing_commas,
+                range,
                 verbosity,
             };
             commands::fmt::run(args)?;
@@ -672,11 +875,13 @@ fn main() -> Result<()> {
             threshold,
             output,
             summary,
+            format,
         } => {
             let mut args = commands::profile::ProfileArgs::new(file);
             args.hotspot_threshold = threshold;
             args.output_file = output.map(std::path::PathBuf::from);
             args.detailed = !summary;
+            args.format = format;
             commands::profile::run(args)?;
         }
         Commands::Test {
@@ -686,6 +891,7 @@ fn main() -> Result<()> {
             no_color,
             dir,
             json,
+            format,
         } => {
             let args = commands::test::TestArgs {
                 pattern,
@@ -694,9 +900,30 @@ fn main() -> Result<()> {
                 no_color,
                 dir,
                 json,
+                format,
             };
             commands::test::run(args)?;
         }
+        Commands::Bench {
+            pattern,
+            iterations,
+            warmup,
+            jit,
+            dir,
+            no_color,
+            json,
+        } => {
+            let args = commands::bench::BenchArgs {
+                pattern,
+                dir,
+                iterations,
+                warmup,
+                jit,
+                no_color,
+                json,
+            };
+            commands::bench::run(args)?;
+        }
         Commands::Debug { file, breakpoint } => {
             let args = commands::debug::DebugArgs {
                 file,
@@ -719,6 +946,9 @@ fn main() -> Result<()> {
             };
             commands::lsp::run(args)?;
         }
+        Commands::Config { action } => match action {
+            ConfigAction::Schema { output } => commands::config::schema(output.as_deref())?,
+        },
         Commands::Completions { shell } => {
             let mut cmd = Cli::command();
             let name = cmd.get_name().to_string();
@@ -809,6 +1039,7 @@ fn main() -> Result<()> {
             dry_run,
             verbose,
             quiet,
+            locked,
         } => {
             let args = commands::install::InstallArgs {
                 packages: Vec::new(),
@@ -818,6 +1049,7 @@ fn main() -> Result<()> {
                 dry_run,
                 verbose,
                 quiet,
+                locked,
             };
             commands::install::run(args)?;
         }
@@ -954,6 +1186,34 @@ mod tests {
         matches!(cli.command, Commands::Run { .. });
     }
 
+    #[test]
+    fn test_cli_run_eval_flag() {
+        let cli = Cli::parse_from(["atlas", "run", "-e", "1 + 2"]);
+        match cli.command {
+            Commands::Run { file, eval, .. } => {
+                assert_eq!(file, None);
+                assert_eq!(eval.as_deref(), Some("1 + 2"));
+            }
+            _ => panic!("Expected Run command"),
+        }
+
+        let cli = Cli::parse_from(["atlas", "run", "--eval", "42", "--json"]);
+        match cli.command {
+            Commands::Run { eval, json, .. } => {
+                assert_eq!(eval.as_deref(), Some("42"));
+                assert!(json);
+            }
+            _ => panic!("Expected Run command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_run_requires_file_or_eval() {
+        assert!(Cli::try_parse_from(["atlas", "run"]).is_err());
+        assert!(Cli::try_parse_from(["atlas", "run", "main.atl", "-e", "1"]).is_err());
+        assert!(Cli::try_parse_from(["atlas", "run", "-e", "1", "--watch"]).is_err());
+    }
+
     #[test]
     fn test_alias_t_for_test() {
         let cli = Cli::parse_from(["atlas", "t"]);
@@ -972,6 +1232,50 @@ mod tests {
         matches!(cli.command, Commands::Build { .. });
     }
 
+    #[test]
+    fn test_cli_build_watch_flags() {
+        let cli = Cli::parse_from(["atlas", "build", "--watch", "--no-clear"]);
+        match cli.command {
+            Commands::Build {
+                watch, no_clear, ..
+            } => {
+                assert!(watch);
+                assert!(no_clear);
+            }
+            _ => panic!("Expected Build command"),
+        }
+        assert!(Cli::try_parse_from(["atlas", "build", "-w", "--build-plan"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_test_format_flag() {
+        let cli = Cli::parse_from(["atlas", "test", "--format=libtest-json"]);
+        match cli.command {
+            Commands::Test { format, .. } => {
+                assert_eq!(format, Some(testing::ReportFormat::LibtestJson))
+            }
+            _ => panic!("Expected Test command"),
+        }
+        let cli = Cli::parse_from(["atlas", "test", "--format", "tap"]);
+        match cli.command {
+            Commands::Test { format, .. } => assert_eq!(format, Some(testing::ReportFormat::Tap)),
+            _ => panic!("Expected Test command"),
+        }
+        assert!(Cli::try_parse_from(["atlas", "test", "--format=junit"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_config_schema() {
+        let cli = Cli::parse_from(["atlas", "config", "schema", "-o", "schema.json"]);
+        match cli.command {
+            Commands::Config {
+                action: ConfigAction::Schema { output },
+            } => assert_eq!(output, Some(std::path::PathBuf::from("schema.json"))),
+            _ => panic!("Expected Config command"),
+        }
+        assert!(Cli::try_parse_from(["atlas", "config"]).is_err());
+    }
+
     #[test]
     fn test_alias_c_for_check() {
         let cli = Cli::parse_from(["atlas", "c", "main.atl"]);
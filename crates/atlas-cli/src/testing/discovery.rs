@@ -30,6 +30,13 @@ pub struct TestSuite {
 impl TestSuite {
     /// Discover all test functions in a directory tree
     pub fn discover(root: &Path) -> Self {
+        Self::discover_prefixed(root, "test_")
+    }
+
+    /// Discover all functions named `<prefix>...` in a directory tree
+    ///
+    /// `atlas test` uses `test_`; `atlas bench` uses `bench_`.
+    pub fn discover_prefixed(root: &Path, prefix: &str) -> Self {
         let mut suite = TestSuite::default();
 
         // Walk directory tree finding .at and .atlas files
@@ -43,7 +50,7 @@ impl TestSuite {
             // Check for Atlas file extensions
             if let Some(ext) = path.extension() {
                 if ext == OsStr::new("at") || ext == OsStr::new("atlas") {
-                    match discover_tests_in_file(path) {
+                    match discover_functions_in_file(path, prefix) {
                         Ok(tests) => suite.tests.extend(tests),
                         Err(e) => suite.parse_errors.push((path.to_path_buf(), e)),
                     }
@@ -85,8 +92,8 @@ impl TestSuite {
     }
 }
 
-/// Discover test functions in a single file
-fn discover_tests_in_file(path: &Path) -> Result<Vec<TestFunction>, String> {
+/// Discover functions named `<prefix>...` in a single file
+fn discover_functions_in_file(path: &Path, prefix: &str) -> Result<Vec<TestFunction>, String> {
     let source = fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
 
@@ -121,17 +128,17 @@ fn discover_tests_in_file(path: &Path) -> Result<Vec<TestFunction>, String> {
 
     let mut tests = Vec::new();
 
-    // Walk AST finding functions starting with "test_"
+    // Walk AST finding functions starting with the prefix
     for item in &ast.items {
         if let Item::Function(func) = item {
             let name = &func.name.name;
 
-            if name.starts_with("test_") {
-                // Verify test function signature: no parameters
+            if name.starts_with(prefix) {
+                // Verify function signature: no parameters
                 if !func.params.is_empty() {
                     eprintln!(
-                        "Warning: {} takes parameters, skipping (tests must have no parameters)",
-                        name
+                        "Warning: {} takes parameters, skipping ({}* functions must have no parameters)",
+                        name, prefix
                     );
                     continue;
                 }
@@ -179,7 +186,7 @@ fn helper() {{
         )
         .unwrap();
 
-        let tests = discover_tests_in_file(file.path()).unwrap();
+        let tests = discover_functions_in_file(file.path(), "test_").unwrap();
         assert_eq!(tests.len(), 2);
         assert_eq!(tests[0].name, "test_addition");
         assert_eq!(tests[1].name, "test_subtraction");
@@ -202,11 +209,64 @@ fn test_no_param() {{
         )
         .unwrap();
 
-        let tests = discover_tests_in_file(file.path()).unwrap();
+        let tests = discover_functions_in_file(file.path(), "test_").unwrap();
         assert_eq!(tests.len(), 1);
         assert_eq!(tests[0].name, "test_no_param");
     }
 
+    #[test]
+    fn test_discover_bench_functions() {
+        let mut file = NamedTempFile::with_suffix(".at").unwrap();
+        write!(
+            file,
+            r#"
+fn bench_sum() {{
+    var total: number = 0;
+}}
+
+fn test_sum() {{
+    assert(true, "ok");
+}}
+
+fn bench_with_param(n: number) {{
+    // Skipped: benchmarks take no parameters
+}}
+
+fn bench_concat() {{
+    let s: string = "a" + "b";
+}}
+"#
+        )
+        .unwrap();
+
+        let benches = discover_functions_in_file(file.path(), "bench_").unwrap();
+        let names: Vec<_> = benches.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["bench_sum", "bench_concat"]);
+
+        let tests = discover_functions_in_file(file.path(), "test_").unwrap();
+        assert_eq!(tests.len(), 1);
+        assert_eq!(tests[0].name, "test_sum");
+    }
+
+    #[test]
+    fn test_suite_discover_prefixed_directory() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("a.at"),
+            "fn bench_a() { let x: number = 1; }\nfn test_a() { assert(true, \"ok\"); }",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("b.atlas"),
+            "fn bench_b() { let y: number = 2; }",
+        )
+        .unwrap();
+
+        let suite = TestSuite::discover_prefixed(dir.path(), "bench_");
+        let names: Vec<_> = suite.tests.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["bench_a", "bench_b"]);
+    }
+
     #[test]
     fn test_suite_filter() {
         let suite = TestSuite {
@@ -0,0 +1,322 @@
+//! Streaming test events in formats CI tooling already understands
+//!
+//! - `libtest-json`: the newline-delimited JSON emitted by
+//!   `cargo test -- -Z unstable-options --format json`
+//! - `tap`: Test Anything Protocol, version 13
+//!
+//! Events are written as tests start and finish, so consumers see progress
+//! before the whole suite completes.
+
+use crate::testing::discovery::TestFunction;
+use crate::testing::runner::{TestResult, TestRun};
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A test lifecycle event reported while the suite runs
+#[derive(Debug, Clone, Copy)]
+pub enum TestEvent<'a> {
+    /// A test is about to run
+    Started(&'a TestFunction),
+    /// A test has finished
+    Finished(&'a TestRun),
+}
+
+/// Machine-readable test report format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// libtest's newline-delimited JSON events
+    LibtestJson,
+    /// Test Anything Protocol version 13
+    Tap,
+}
+
+impl std::str::FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "libtest-json" => Ok(ReportFormat::LibtestJson),
+            "tap" => Ok(ReportFormat::Tap),
+            _ => Err(format!(
+                "unknown test format '{}' (expected libtest-json or tap)",
+                s
+            )),
+        }
+    }
+}
+
+/// Writes test events in a [`ReportFormat`]
+///
+/// Safe to share between the runner's worker threads; each event is
+/// written as one unit.
+pub struct EventWriter<W: Write> {
+    format: ReportFormat,
+    state: Mutex<WriterState<W>>,
+}
+
+struct WriterState<W> {
+    out: W,
+    /// Number of results written so far (TAP test points are numbered)
+    finished: usize,
+}
+
+impl<W: Write> EventWriter<W> {
+    /// Create a writer for the given format
+    pub fn new(format: ReportFormat, out: W) -> Self {
+        Self {
+            format,
+            state: Mutex::new(WriterState { out, finished: 0 }),
+        }
+    }
+
+    /// Announce the start of a suite of `test_count` tests
+    pub fn suite_started(&self, test_count: usize) {
+        let line = match self.format {
+            ReportFormat::LibtestJson => serde_json::json!({
+                "type": "suite",
+                "event": "started",
+                "test_count": test_count,
+            })
+            .to_string(),
+            ReportFormat::Tap => format!("TAP version 13\n1..{}", test_count),
+        };
+        self.write_line(&line);
+    }
+
+    /// Report a single test event
+    pub fn event(&self, event: TestEvent<'_>) {
+        let mut state = self.state.lock().unwrap();
+        let line = match (self.format, event) {
+            (ReportFormat::LibtestJson, TestEvent::Started(test)) => serde_json::json!({
+                "type": "test",
+                "event": "started",
+                "name": qualified_name(test),
+            })
+            .to_string(),
+            // TAP has no start event; a comment keeps the stream live
+            (ReportFormat::Tap, TestEvent::Started(test)) => {
+                format!("# {}", qualified_name(test))
+            }
+            (ReportFormat::LibtestJson, TestEvent::Finished(run)) => {
+                let mut event = serde_json::json!({
+                    "type": "test",
+                    "name": qualified_name(&run.test),
+                    "event": if run.result.is_pass() { "ok" } else { "failed" },
+                    "exec_time": run.result.duration().as_secs_f64(),
+                });
+                if let Some(message) = failure_message(&run.result) {
+                    event["stdout"] = serde_json::Value::String(message);
+                }
+                event.to_string()
+            }
+            (ReportFormat::Tap, TestEvent::Finished(run)) => {
+                state.finished += 1;
+                tap_test_point(state.finished, run)
+            }
+        };
+        let _ = writeln!(state.out, "{}", line);
+        let _ = state.out.flush();
+    }
+
+    /// Close the suite with its totals
+    pub fn suite_finished(&self, runs: &[TestRun], exec_time: Duration) {
+        let passed = runs.iter().filter(|r| r.result.is_pass()).count();
+        let failed = runs.iter().filter(|r| r.result.is_fail()).count();
+        let line = match self.format {
+            ReportFormat::LibtestJson => serde_json::json!({
+                "type": "suite",
+                "event": if failed == 0 { "ok" } else { "failed" },
+                "passed": passed,
+                "failed": failed,
+                "ignored": 0,
+                "measured": 0,
+                "filtered_out": 0,
+                "exec_time": exec_time.as_secs_f64(),
+            })
+            .to_string(),
+            ReportFormat::Tap => format!(
+                "# tests {}\n# pass {}\n# fail {}",
+                runs.len(),
+                passed,
+                failed
+            ),
+        };
+        self.write_line(&line);
+    }
+
+    /// Consume the writer and return the underlying output
+    #[cfg(test)]
+    pub fn into_inner(self) -> W {
+        self.state.into_inner().unwrap().out
+    }
+
+    fn write_line(&self, line: &str) {
+        let mut state = self.state.lock().unwrap();
+        let _ = writeln!(state.out, "{}", line);
+        let _ = state.out.flush();
+    }
+}
+
+/// Test name qualified by its file, so same-named tests stay distinct
+fn qualified_name(test: &TestFunction) -> String {
+    format!("{}::{}", test.file.display(), test.name)
+}
+
+fn failure_message(result: &TestResult) -> Option<String> {
+    match result {
+        TestResult::Pass { .. } => None,
+        TestResult::Fail { error, .. } => Some(error.clone()),
+        TestResult::Timeout { duration } => Some(format!("timed out after {:.2?}", duration)),
+    }
+}
+
+/// A TAP test point with a YAML diagnostic block
+fn tap_test_point(number: usize, run: &TestRun) -> String {
+    let status = if run.result.is_pass() { "ok" } else { "not ok" };
+    let mut point = format!(
+        "{} {} - {}\n  ---\n  duration_ms: {:.3}\n",
+        status,
+        number,
+        qualified_name(&run.test),
+        run.result.duration().as_secs_f64() * 1000.0
+    );
+    if let Some(message) = failure_message(&run.result) {
+        // A JSON string is a valid YAML flow scalar, which handles escaping
+        point.push_str(&format!(
+            "  message: {}\n",
+            serde_json::Value::String(message)
+        ));
+    }
+    point.push_str("  ...");
+    point
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn test_fn(name: &str) -> TestFunction {
+        TestFunction {
+            name: name.to_string(),
+            file: PathBuf::from("math.at"),
+            line: 1,
+        }
+    }
+
+    fn stream(format: ReportFormat) -> String {
+        let runs = vec![
+            TestRun {
+                test: test_fn("test_add"),
+                result: TestResult::Pass {
+                    duration: Duration::from_millis(2),
+                },
+            },
+            TestRun {
+                test: test_fn("test_sub"),
+                result: TestResult::Pass {
+                    duration: Duration::from_millis(3),
+                },
+            },
+            TestRun {
+                test: test_fn("test_div"),
+                result: TestResult::Fail {
+                    error: "assertion failed: \"div\"".to_string(),
+                    duration: Duration::from_millis(4),
+                },
+            },
+        ];
+
+        let writer = EventWriter::new(format, Vec::new());
+        writer.suite_started(runs.len());
+        for run in &runs {
+            writer.event(TestEvent::Started(&run.test));
+            writer.event(TestEvent::Finished(run));
+        }
+        writer.suite_finished(&runs, Duration::from_millis(9));
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_report_format() {
+        assert_eq!(
+            "libtest-json".parse::<ReportFormat>(),
+            Ok(ReportFormat::LibtestJson)
+        );
+        assert_eq!("tap".parse::<ReportFormat>(), Ok(ReportFormat::Tap));
+        assert!("junit".parse::<ReportFormat>().is_err());
+    }
+
+    #[test]
+    fn test_libtest_json_stream() {
+        let events: Vec<serde_json::Value> = stream(ReportFormat::LibtestJson)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        // suite start, start + result per test, suite end
+        assert_eq!(events.len(), 8);
+        assert_eq!(events[0]["type"], "suite");
+        assert_eq!(events[0]["event"], "started");
+        assert_eq!(events[0]["test_count"], 3);
+
+        for (i, (name, outcome)) in [
+            ("test_add", "ok"),
+            ("test_sub", "ok"),
+            ("test_div", "failed"),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let started = &events[1 + 2 * i];
+            let finished = &events[2 + 2 * i];
+            let qualified = format!("math.at::{}", name);
+            assert_eq!(started["type"], "test");
+            assert_eq!(started["event"], "started");
+            assert_eq!(started["name"], qualified.as_str());
+            assert_eq!(finished["type"], "test");
+            assert_eq!(finished["event"], *outcome);
+            assert_eq!(finished["name"], qualified.as_str());
+            assert!(finished["exec_time"].is_f64());
+        }
+        assert!(events[2].get("stdout").is_none());
+        assert_eq!(events[6]["stdout"], "assertion failed: \"div\"");
+
+        let suite = &events[7];
+        assert_eq!(suite["type"], "suite");
+        assert_eq!(suite["event"], "failed");
+        assert_eq!(suite["passed"], 2);
+        assert_eq!(suite["failed"], 1);
+        assert_eq!(suite["exec_time"], 0.009);
+    }
+
+    #[test]
+    fn test_tap_stream() {
+        let output = stream(ReportFormat::Tap);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..3");
+
+        let points: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|l| l.starts_with("ok ") || l.starts_with("not ok "))
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                "ok 1 - math.at::test_add",
+                "ok 2 - math.at::test_sub",
+                "not ok 3 - math.at::test_div",
+            ]
+        );
+
+        assert!(output.contains(
+            "not ok 3 - math.at::test_div\n  ---\n  duration_ms: 4.000\n  \
+             message: \"assertion failed: \\\"div\\\"\"\n  ..."
+        ));
+        assert!(output.ends_with("# tests 3\n# pass 2\n# fail 1\n"));
+    }
+}
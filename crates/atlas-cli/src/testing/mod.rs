@@ -4,9 +4,11 @@
 //! the Rust (cargo test) and Go (go test) model.
 
 pub mod discovery;
+pub mod events;
 pub mod reporter;
 pub mod runner;
 
 pub use discovery::TestSuite;
+pub use events::{EventWriter, ReportFormat};
 pub use reporter::TestReporter;
 pub use runner::TestRunner;
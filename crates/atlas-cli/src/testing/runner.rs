@@ -1,6 +1,7 @@
 //! Test runner - execute discovered tests
 
 use crate::testing::discovery::{TestFunction, TestSuite};
+use crate::testing::events::TestEvent;
 use atlas_runtime::api::{ExecutionMode, Runtime};
 use rayon::prelude::*;
 use std::fs;
@@ -87,31 +88,59 @@ impl TestRunner {
 
     /// Run all tests in the suite
     pub fn run(&self, suite: &TestSuite) -> Vec<TestRun> {
+        self.run_with_events(suite, |_| {})
+    }
+
+    /// Run all tests, reporting each test's start and result as it happens
+    ///
+    /// In parallel mode `on_event` is called from worker threads, so events
+    /// from different tests may interleave.
+    pub fn run_with_events<F>(&self, suite: &TestSuite, on_event: F) -> Vec<TestRun>
+    where
+        F: Fn(TestEvent<'_>) + Sync,
+    {
         if self.parallel {
-            self.run_parallel(suite)
+            self.run_parallel(suite, &on_event)
         } else {
-            self.run_sequential(suite)
+            self.run_sequential(suite, &on_event)
         }
     }
 
     /// Run tests sequentially
-    fn run_sequential(&self, suite: &TestSuite) -> Vec<TestRun> {
+    fn run_sequential<F>(&self, suite: &TestSuite, on_event: &F) -> Vec<TestRun>
+    where
+        F: Fn(TestEvent<'_>) + Sync,
+    {
         suite
             .tests
             .iter()
-            .map(|test| self.run_single_test(test))
+            .map(|test| self.run_reported(test, on_event))
             .collect()
     }
 
     /// Run tests in parallel using rayon
-    fn run_parallel(&self, suite: &TestSuite) -> Vec<TestRun> {
+    fn run_parallel<F>(&self, suite: &TestSuite, on_event: &F) -> Vec<TestRun>
+    where
+        F: Fn(TestEvent<'_>) + Sync,
+    {
         suite
             .tests
             .par_iter()
-            .map(|test| self.run_single_test(test))
+            .map(|test| self.run_reported(test, on_event))
             .collect()
     }
 
+    /// Run a single test between its start and result events
+    fn run_reported<F>(&self, test: &TestFunction, on_event: &F) -> TestRun
+    where
+        F: Fn(TestEvent<'_>) + Sync,
+    {
+        on_event(TestEvent::Started(test));
+        let run = self.run_single_test(test);
+        on_event(TestEvent::Finished(&run));
+        run
+    }
+
     /// Run a single test
     fn run_single_test(&self, test: &TestFunction) -> TestRun {
         let start = Instant::now();
@@ -309,6 +338,54 @@ fn test_c() { assert(true, "ok"); }
         assert!(results.iter().all(|r| r.result.is_pass()));
     }
 
+    #[test]
+    fn test_runner_reports_events_in_order() {
+        let file = create_test_file(
+            r#"
+fn test_one() { assert(true, "ok"); }
+fn test_two() { assert(false, "nope"); }
+"#,
+        );
+
+        let suite = TestSuite {
+            tests: vec![
+                TestFunction {
+                    name: "test_one".to_string(),
+                    file: file.path().to_path_buf(),
+                    line: 2,
+                },
+                TestFunction {
+                    name: "test_two".to_string(),
+                    file: file.path().to_path_buf(),
+                    line: 3,
+                },
+            ],
+            parse_errors: Vec::new(),
+        };
+
+        let events = std::sync::Mutex::new(Vec::new());
+        let runner = TestRunner::new().with_parallel(false);
+        runner.run_with_events(&suite, |event| {
+            let entry = match event {
+                TestEvent::Started(test) => format!("start {}", test.name),
+                TestEvent::Finished(run) => {
+                    format!("{} {}", run.result.is_pass(), run.test.name)
+                }
+            };
+            events.lock().unwrap().push(entry);
+        });
+
+        assert_eq!(
+            events.into_inner().unwrap(),
+            vec![
+                "start test_one",
+                "true test_one",
+                "start test_two",
+                "false test_two"
+            ]
+        );
+    }
+
     #[test]
     fn test_result_duration() {
         let pass = TestResult::Pass {
@@ -218,6 +218,47 @@ sum;
         .stdout(predicate::str::contains("10"));
 }
 
+#[test]
+fn test_run_eval_prints_result() {
+    assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .args(["run", "-e", "1 + 2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::diff("3\n"));
+}
+
+#[test]
+fn test_run_eval_json_diagnostics() {
+    assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .args(["run", "--eval", "let x: number = \"wrong\";", "--json"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("\"level\""));
+}
+
+#[test]
+fn test_run_uses_project_prelude() {
+    let temp_dir = create_test_project("greet(\"atlas\");");
+    let root = temp_dir.path();
+    fs::write(
+        root.join("atlas.toml"),
+        "[package]\nname = \"test-project\"\nversion = \"0.1.0\"\n\n[build]\nprelude = \"src/prelude.atl\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("src/prelude.atl"),
+        "export fn greet(name: string) -> string { return \"hello \" + name; }",
+    )
+    .unwrap();
+
+    assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .current_dir(root)
+        .args(["run", "src/main.atlas"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello atlas"));
+}
+
 // ============================================================================
 // atlas run - Error Cases
 // ============================================================================
@@ -373,6 +414,27 @@ let result: number = factorial(5);
     let _metadata = fs::metadata(bytecode_path.unwrap()).unwrap();
 }
 
+#[test]
+fn test_build_plan_after_build_reports_cache_hits() {
+    let temp_dir = create_test_project("let x: number = 42;");
+
+    assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .current_dir(temp_dir.path())
+        .arg("build")
+        .assert()
+        .success();
+
+    assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .current_dir(temp_dir.path())
+        .arg("build")
+        .arg("--build-plan")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"cache_hit\": true"))
+        .stdout(predicate::str::contains("\"cache_hit\": false").not())
+        .stdout(predicate::str::contains("Build succeeded").not());
+}
+
 // ============================================================================
 // atlas build - Error Cases
 // ============================================================================
@@ -508,6 +570,79 @@ fn test_check_json_output_with_error() {
         .stdout(predicate::str::contains("diag_version"));
 }
 
+// ============================================================================
+// atlas test - Report Formats
+// ============================================================================
+
+const MIXED_TESTS: &str = r#"
+fn test_one() { assert(true, "ok"); }
+fn test_two() { assert(true, "ok"); }
+fn test_three() { assert(false, "three broke"); }
+"#;
+
+#[test]
+fn test_test_format_libtest_json() {
+    let (dir, _path) = create_test_file("math.at", MIXED_TESTS);
+    let output = assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .args(["test", "--sequential", "--format=libtest-json", "--dir"])
+        .arg(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let events: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let kinds: Vec<(String, String)> = events
+        .iter()
+        .map(|e| {
+            (
+                e["type"].as_str().unwrap().to_string(),
+                e["event"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    let expected = [
+        ("suite", "started"),
+        ("test", "started"),
+        ("test", "ok"),
+        ("test", "started"),
+        ("test", "ok"),
+        ("test", "started"),
+        ("test", "failed"),
+        ("suite", "failed"),
+    ];
+    assert_eq!(
+        kinds,
+        expected
+            .iter()
+            .map(|(t, e)| (t.to_string(), e.to_string()))
+            .collect::<Vec<_>>()
+    );
+    assert!(events[6]["stdout"]
+        .as_str()
+        .unwrap()
+        .contains("three broke"));
+    assert_eq!(events[7]["passed"], 2);
+    assert_eq!(events[7]["failed"], 1);
+}
+
+#[test]
+fn test_test_format_tap() {
+    let (dir, _path) = create_test_file("math.at", MIXED_TESTS);
+    assert_cmd::cargo::cargo_bin_cmd!("atlas")
+        .args(["test", "--sequential", "--format=tap", "--dir"])
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::starts_with("TAP version 13\n1..3\n"))
+        .stdout(predicate::str::contains("ok 2 - "))
+        .stdout(predicate::str::contains("not ok 3 - "))
+        .stdout(predicate::str::contains("three broke"));
+}
+
 // ============================================================================
 // Cross-command Integration Tests
 // ============================================================================
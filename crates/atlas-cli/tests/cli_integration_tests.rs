@@ -176,6 +176,29 @@ mod help_messages {
             .stdout(predicate::str::contains("EXAMPLES"));
     }
 
+    #[test]
+    fn test_bench_help_comprehensive() {
+        let mut cmd = atlas_cmd();
+        cmd.args(["bench", "--help"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("--iterations"))
+            .stdout(predicate::str::contains("--warmup"))
+            .stdout(predicate::str::contains("--jit"))
+            .stdout(predicate::str::contains("EXAMPLES"));
+    }
+
+    #[test]
+    fn test_doc_help_comprehensive() {
+        let mut cmd = atlas_cmd();
+        cmd.args(["doc", "--help"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("--json"))
+            .stdout(predicate::str::contains("--output"))
+            .stdout(predicate::str::contains("EXAMPLES"));
+    }
+
     #[test]
     fn test_ast_help() {
         let mut cmd = atlas_cmd();
@@ -469,6 +492,64 @@ mod flag_parsing {
             .stdout(predicate::str::contains("--write"));
     }
 
+    #[test]
+    fn test_fmt_range_flag() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("main.atl");
+        std::fs::write(&file, "let a=1;\nlet b=2;\nlet c=3;\n").unwrap();
+
+        atlas_cmd()
+            .args(["fmt", "--range", "2:2", "-q"])
+            .arg(&file)
+            .assert()
+            .success();
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "let a=1;\nlet b = 2;\nlet c=3;\n"
+        );
+
+        atlas_cmd()
+            .args(["fmt", "--range", "3:1"])
+            .arg(&file)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--range"));
+    }
+
+    #[test]
+    fn test_fmt_diff_unformatted_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("main.atl");
+        let source = "let a = 1;\nlet b=2;\nlet c = 3;\n";
+        std::fs::write(&file, source).unwrap();
+
+        let name = file.display().to_string();
+        let expected = format!(
+            "--- {name}\n+++ {name}\n@@ -1,3 +1,3 @@\n let a = 1;\n-let b=2;\n+let b = 2;\n let c = 3;\n"
+        );
+        atlas_cmd()
+            .args(["fmt", "--diff"])
+            .arg(&file)
+            .assert()
+            .failure()
+            .stdout(predicate::str::diff(expected));
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), source);
+    }
+
+    #[test]
+    fn test_fmt_diff_formatted_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("main.atl");
+        std::fs::write(&file, "let a = 1;\n").unwrap();
+
+        atlas_cmd()
+            .args(["fmt", "--diff", "-q"])
+            .arg(&file)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_empty());
+    }
+
     #[test]
     fn test_fmt_config_short_flag() {
         let mut cmd = atlas_cmd();
@@ -600,6 +681,51 @@ mod error_handling {
             .stderr(predicate::str::contains("error"));
     }
 
+    #[test]
+    fn test_explain_known_code() {
+        let mut cmd = atlas_cmd();
+        cmd.args(["explain", "AT0300"])
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(
+                "AT0300: Filesystem permission denied",
+            ))
+            .stdout(predicate::str::contains("help: Enable file permissions"));
+    }
+
+    #[test]
+    fn test_explain_unknown_code() {
+        let mut cmd = atlas_cmd();
+        cmd.args(["explain", "AT4242"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Unknown error code: AT4242"));
+    }
+
+    #[test]
+    fn test_disasm_prints_listing() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("main.atl");
+        std::fs::write(&file, "let x = 1 + 2;\n").unwrap();
+
+        let mut cmd = atlas_cmd();
+        cmd.arg("disasm")
+            .arg(&file)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("=== Instructions ==="))
+            .stdout(predicate::str::contains("0006    |  Add"));
+    }
+
+    #[test]
+    fn test_disasm_missing_file() {
+        let mut cmd = atlas_cmd();
+        cmd.args(["disasm", "nonexistent.atl"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Failed to read source file"));
+    }
+
     #[test]
     fn test_missing_required_arg_run() {
         let mut cmd = atlas_cmd();
@@ -735,7 +861,9 @@ mod subcommand_structure {
             "build",
             "repl",
             "ast",
+            "disasm",
             "typecheck",
+            "explain",
             "fmt",
             "profile",
             "test",
@@ -188,6 +188,26 @@ mod check_workflow {
         let mut cmd = atlas_cmd();
         cmd.args(["check", "--json"]).arg(&file).assert().success();
     }
+
+    #[test]
+    fn test_check_repl_saved_session() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("session.atl");
+
+        let mut repl = atlas_runtime::ReplCore::new();
+        for line in [
+            "fn double(n: number) -> number { return n * 2; }",
+            "fn quadruple(n: number) -> number { return double(double(n)); }",
+            "quadruple(3);",
+        ] {
+            let result = repl.eval_line(line);
+            assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        }
+        assert_eq!(repl.save(&file).unwrap(), 2);
+
+        let mut cmd = atlas_cmd();
+        cmd.arg("check").arg(&file).assert().success();
+    }
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -224,6 +224,58 @@ fn test_debug_location_command() {
         .stdout(predicate::str::contains("At"));
 }
 
+#[test]
+fn test_debug_watch_across_steps() {
+    let file = create_test_file(
+        "var total = 0;\ntotal = total + 1;\ntotal = total + 2;\ntotal = total + 3;",
+    );
+    let mut cmd = atlas_cmd();
+    cmd.args(["debug", file.path().to_str().unwrap()])
+        .write_stdin("break 3\nbreak 4\nwatch total\nrun\ncontinue\ncontinue\nquit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Watching 'total'"))
+        .stdout(predicate::str::contains("total = 1"))
+        .stdout(predicate::str::contains("total = 3"))
+        .stdout(predicate::str::contains("total = 6"));
+}
+
+#[test]
+fn test_debug_watch_out_of_scope_shows_error() {
+    let file = create_test_file("let x = 1;");
+    let mut cmd = atlas_cmd();
+    cmd.args(["debug", file.path().to_str().unwrap()])
+        .write_stdin("watch missing\nstep\nquit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("missing: "))
+        .stdout(predicate::str::contains("<error:"));
+}
+
+#[test]
+fn test_debug_unwatch() {
+    let file = create_test_file("var total = 0;\ntotal = 5;");
+    let mut cmd = atlas_cmd();
+    cmd.args(["debug", file.path().to_str().unwrap()])
+        .write_stdin("watch total\nunwatch total\nrun\nunwatch total\nquit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No longer watching 'total'"))
+        .stdout(predicate::str::contains("Watches:").not())
+        .stdout(predicate::str::contains("no watch for 'total'"));
+}
+
+#[test]
+fn test_debug_unwatch_without_args() {
+    let file = create_test_file("let x = 1;");
+    let mut cmd = atlas_cmd();
+    cmd.args(["debug", file.path().to_str().unwrap()])
+        .write_stdin("unwatch\nquit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Usage: unwatch"));
+}
+
 // ── Source listing tests ──────────────────────────────────────────────────────
 
 #[test]
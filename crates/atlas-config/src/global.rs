@@ -2,7 +2,9 @@
 //!
 //! Handles user-level configuration stored in `~/.atlas/config.toml`.
 
-use crate::{ConfigError, ConfigResult};
+use crate::project::EDITIONS;
+use crate::schema::{self, ConfigSchema, ObjectSchema};
+use crate::{ConfigError, ConfigResult, ConfigWarning, UnknownFieldPolicy};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -96,8 +98,16 @@ pub struct LspConfig {
 }
 
 impl GlobalConfig {
-    /// Load global configuration from a file
+    /// Load global configuration from a file, rejecting unknown fields
     pub fn load_from_file(path: &Path) -> ConfigResult<Self> {
+        Self::load_from_file_with_policy(path, UnknownFieldPolicy::Error).map(|(config, _)| config)
+    }
+
+    /// Load global configuration from a file, handling unknown fields per `policy`
+    pub fn load_from_file_with_policy(
+        path: &Path,
+        policy: UnknownFieldPolicy,
+    ) -> ConfigResult<(Self, Vec<ConfigWarning>)> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 ConfigError::NotFound(path.to_path_buf())
@@ -106,13 +116,10 @@ impl GlobalConfig {
             }
         })?;
 
-        let config: Self = toml::from_str(&content).map_err(|e| ConfigError::TomlParseError {
-            file: path.to_path_buf(),
-            error: e,
-        })?;
+        let (config, warnings): (Self, _) = crate::loader::parse_config(&content, path, policy)?;
 
         config.validate()?;
-        Ok(config)
+        Ok((config, warnings))
     }
 
     /// Validate the global configuration
@@ -187,7 +194,89 @@ fn validate_permission_value(field: &str, value: &str) -> ConfigResult<()> {
 
 /// Check if edition is valid
 fn is_valid_edition(edition: &str) -> bool {
-    matches!(edition, "2026" | "2027" | "2028")
+    EDITIONS.contains(&edition)
+}
+
+/// Values accepted by [`validate_permission_value`]
+const PERMISSION_VALUES: &[&str] = &["allow", "deny", "prompt"];
+
+impl ConfigSchema for GlobalConfig {
+    const NAME: &'static str = "GlobalConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Global user configuration (~/.atlas/config.toml)")
+            .field("defaults", schema::reference::<DefaultsConfig>())
+            .field("formatting", schema::reference::<GlobalFormattingConfig>())
+            .field("permissions", schema::reference::<PermissionsConfig>())
+            .field("lsp", schema::reference::<LspConfig>())
+            .build()
+    }
+}
+
+impl ConfigSchema for DefaultsConfig {
+    const NAME: &'static str = "DefaultsConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Default settings")
+            .field(
+                "edition",
+                schema::string_enum("Default edition for new projects", EDITIONS),
+            )
+            .field("author", schema::string("Default author for new projects"))
+            .field(
+                "license",
+                schema::string("Default license for new projects"),
+            )
+            .build()
+    }
+}
+
+impl ConfigSchema for GlobalFormattingConfig {
+    const NAME: &'static str = "GlobalFormattingConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Global formatting preferences")
+            .field("indent", schema::integer("Indentation size (default: 4)"))
+            .field(
+                "max_line_length",
+                schema::integer("Maximum line length (default: 100)"),
+            )
+            .field("use_tabs", schema::boolean("Use tabs instead of spaces"))
+            .build()
+    }
+}
+
+impl ConfigSchema for PermissionsConfig {
+    const NAME: &'static str = "PermissionsConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Permission defaults")
+            .field(
+                "network",
+                schema::string_enum("Network access", PERMISSION_VALUES),
+            )
+            .field(
+                "filesystem",
+                schema::string_enum("Filesystem access", PERMISSION_VALUES),
+            )
+            .field(
+                "env",
+                schema::string_enum("Environment variables", PERMISSION_VALUES),
+            )
+            .build()
+    }
+}
+
+impl ConfigSchema for LspConfig {
+    const NAME: &'static str = "LspConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("LSP server settings")
+            .field("diagnostics", schema::boolean("Enable diagnostics"))
+            .field("completion", schema::boolean("Enable code completion"))
+            .field("hover", schema::boolean("Enable hover information"))
+            .build()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,212 @@
+//! Environment Variable Interpolation
+//!
+//! Expands `${VAR}` and `${VAR:-default}` references in configuration string
+//! values. Expansion runs on the parsed TOML tree, before it is deserialized
+//! into a config struct and validated, so every string field supports it.
+
+use crate::{ConfigError, ConfigResult};
+
+/// Expand `${VAR}` references against the process environment
+///
+/// `${VAR:-default}` falls back to `default` when `VAR` is unset or empty;
+/// a plain `${VAR}` whose variable is unset is an error. `$${` produces a
+/// literal `${`. Only values are expanded, never table keys.
+pub fn interpolate_env(value: &mut toml::Value) -> ConfigResult<()> {
+    interpolate_with(value, &|name| std::env::var(name).ok())
+}
+
+/// Expand references using `lookup` to resolve variable names
+pub fn interpolate_with(
+    value: &mut toml::Value,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ConfigResult<()> {
+    walk(value, &mut String::new(), lookup)
+}
+
+fn walk(
+    value: &mut toml::Value,
+    field: &mut String,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ConfigResult<()> {
+    match value {
+        toml::Value::String(s) if s.contains('$') => {
+            *s = expand(s, field, lookup)?;
+        }
+        toml::Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                let len = field.len();
+                field.push_str(&format!("[{}]", i));
+                walk(item, field, lookup)?;
+                field.truncate(len);
+            }
+        }
+        toml::Value::Table(table) => {
+            for (key, item) in table.iter_mut() {
+                let len = field.len();
+                if !field.is_empty() {
+                    field.push('.');
+                }
+                field.push_str(key);
+                walk(item, field, lookup)?;
+                field.truncate(len);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Expand every reference in a single string value
+fn expand(
+    input: &str,
+    field: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ConfigResult<String> {
+    let invalid = |reason: String| ConfigError::InvalidValue {
+        field: field.to_string(),
+        reason,
+    };
+
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos..];
+
+        if let Some(escaped) = after.strip_prefix("$${") {
+            out.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let Some(reference) = after.strip_prefix("${") else {
+            out.push('$');
+            rest = &after[1..];
+            continue;
+        };
+        let end = reference
+            .find('}')
+            .ok_or_else(|| invalid(format!("unterminated '${{' in \"{}\"", input)))?;
+
+        let body = &reference[..end];
+        let (name, default) = match body.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (body, None),
+        };
+        if !is_var_name(name) {
+            return Err(invalid(format!(
+                "invalid environment variable name '{}' in \"{}\"",
+                name, input
+            )));
+        }
+
+        let resolved = lookup(name).filter(|v| default.is_none() || !v.is_empty());
+        match (resolved, default) {
+            (Some(v), _) => out.push_str(&v),
+            (None, Some(default)) => out.push_str(default),
+            (None, None) => {
+                return Err(invalid(format!(
+                "environment variable '{}' is not defined (use ${{{}:-default}} for a fallback)",
+                name, name
+            )))
+            }
+        }
+        rest = &reference[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/atlas".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    fn expand_str(input: &str) -> ConfigResult<String> {
+        expand(input, "build.output", &env)
+    }
+
+    #[test]
+    fn test_expand_defined_variable() {
+        assert_eq!(
+            expand_str("${HOME}/atlas-out").unwrap(),
+            "/home/atlas/atlas-out"
+        );
+        assert_eq!(
+            expand_str("${HOME}${HOME}").unwrap(),
+            "/home/atlas/home/atlas"
+        );
+    }
+
+    #[test]
+    fn test_expand_default_fallback() {
+        assert_eq!(expand_str("${MISSING:-target}").unwrap(), "target");
+        assert_eq!(expand_str("${EMPTY:-target}").unwrap(), "target");
+        assert_eq!(expand_str("${HOME:-target}").unwrap(), "/home/atlas");
+        assert_eq!(expand_str("${MISSING:-}").unwrap(), "");
+    }
+
+    #[test]
+    fn test_expand_undefined_variable_errors() {
+        let err = expand_str("${MISSING}/out").unwrap_err();
+        match err {
+            ConfigError::InvalidValue { field, reason } => {
+                assert_eq!(field, "build.output");
+                assert!(reason.contains("'MISSING' is not defined"));
+            }
+            other => panic!("expected InvalidValue, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_expand_leaves_plain_dollars() {
+        assert_eq!(expand_str("cost: $5").unwrap(), "cost: $5");
+        assert_eq!(expand_str("$${HOME}").unwrap(), "${HOME}");
+    }
+
+    #[test]
+    fn test_expand_malformed_references() {
+        assert!(expand_str("${HOME").is_err());
+        assert!(expand_str("${}").is_err());
+        assert!(expand_str("${1BAD}").is_err());
+    }
+
+    #[test]
+    fn test_interpolate_nested_values() {
+        let mut value: toml::Value = toml::from_str(
+            r#"
+[build]
+output = "${HOME}/out"
+
+[package]
+authors = ["${MISSING:-anon}"]
+"#,
+        )
+        .unwrap();
+        interpolate_with(&mut value, &env).unwrap();
+        assert_eq!(value["build"]["output"].as_str(), Some("/home/atlas/out"));
+        assert_eq!(value["package"]["authors"][0].as_str(), Some("anon"));
+    }
+
+    #[test]
+    fn test_interpolate_reports_field_path() {
+        let mut value: toml::Value =
+            toml::from_str("[package]\nauthors = [\"a\", \"${MISSING}\"]\n").unwrap();
+        match interpolate_with(&mut value, &env).unwrap_err() {
+            ConfigError::InvalidValue { field, .. } => assert_eq!(field, "package.authors[1]"),
+            other => panic!("expected InvalidValue, got {:?}", other),
+        }
+    }
+}
@@ -14,6 +14,9 @@
 //! 3. Environment variables (ATLAS_*)
 //! 4. CLI flags
 //!
+//! String values in `atlas.toml` and `~/.atlas/config.toml` may reference
+//! environment variables as `${VAR}` or `${VAR:-default}`; see [`interpolate`].
+//!
 //! # Example
 //!
 //! ```no_run
@@ -25,9 +28,11 @@
 //! ```
 
 pub mod global;
+pub mod interpolate;
 pub mod loader;
 pub mod manifest;
 pub mod project;
+pub mod schema;
 pub mod security;
 
 use std::path::PathBuf;
@@ -73,6 +78,25 @@ pub enum ConfigError {
 /// Result type for configuration operations
 pub type ConfigResult<T> = Result<T, ConfigError>;
 
+/// How to treat fields a configuration file sets but Atlas doesn't know
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownFieldPolicy {
+    /// Reject the file with [`ConfigError::UnknownField`]
+    #[default]
+    Error,
+    /// Load the file and report each field as a [`ConfigWarning`]
+    Warn,
+    /// Load the file and drop unknown fields silently
+    Ignore,
+}
+
+/// Non-fatal problems found while loading configuration
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ConfigWarning {
+    #[error("Unknown field '{field}' in {file}")]
+    UnknownField { field: String, file: PathBuf },
+}
+
 // Re-export main types
 pub use global::GlobalConfig;
 pub use loader::ConfigLoader;
@@ -4,7 +4,9 @@
 
 use crate::global::GlobalConfig;
 use crate::project::ProjectConfig;
-use crate::{ConfigError, ConfigResult};
+use crate::schema::{self, ConfigSchema};
+use crate::{ConfigError, ConfigResult, ConfigWarning, UnknownFieldPolicy};
+use serde::de::DeserializeOwned;
 use std::env;
 use std::path::{Path, PathBuf};
 
@@ -18,6 +20,9 @@ use std::path::{Path, PathBuf};
 pub struct ConfigLoader {
     /// Cached global config path
     global_config_path: Option<PathBuf>,
+
+    /// How to treat unknown fields in config files
+    unknown_fields: UnknownFieldPolicy,
 }
 
 /// Merged configuration result
@@ -31,6 +36,9 @@ pub struct Config {
 
     /// Project root directory (where atlas.toml was found)
     pub project_root: Option<PathBuf>,
+
+    /// Problems that didn't stop loading (see [`UnknownFieldPolicy::Warn`])
+    pub warnings: Vec<ConfigWarning>,
 }
 
 impl ConfigLoader {
@@ -38,19 +46,28 @@ impl ConfigLoader {
     pub fn new() -> Self {
         Self {
             global_config_path: None,
+            unknown_fields: UnknownFieldPolicy::default(),
         }
     }
 
+    /// Set how unknown fields are handled (default: [`UnknownFieldPolicy::Error`])
+    pub fn with_unknown_field_policy(mut self, policy: UnknownFieldPolicy) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
     /// Load configuration starting from the given directory
     ///
     /// Walks up the directory tree to find atlas.toml, then loads and merges
     /// global config if it exists.
     pub fn load_from_directory(&mut self, start_dir: &Path) -> ConfigResult<Config> {
+        let mut warnings = Vec::new();
+
         // Find project root (directory containing atlas.toml)
-        let (project_root, project_config) = self.find_project_config(start_dir)?;
+        let (project_root, project_config) = self.find_project_config(start_dir, &mut warnings)?;
 
         // Load global config (optional)
-        let global_config = self.load_global_config().unwrap_or_default();
+        let global_config = self.load_global_config(&mut warnings).unwrap_or_default();
 
         // Apply environment variable overrides
         let project_config = self.apply_env_overrides(project_config)?;
@@ -59,13 +76,15 @@ impl ConfigLoader {
             project: project_config,
             global: global_config,
             project_root,
+            warnings,
         })
     }
 
     /// Load configuration from a specific project config file
     pub fn load_from_file(&mut self, config_path: &Path) -> ConfigResult<Config> {
-        let project_config = ProjectConfig::load_from_file(config_path)?;
-        let global_config = self.load_global_config().unwrap_or_default();
+        let (project_config, mut warnings) =
+            ProjectConfig::load_from_file_with_policy(config_path, self.unknown_fields)?;
+        let global_config = self.load_global_config(&mut warnings).unwrap_or_default();
 
         let project_root = config_path.parent().map(|p| p.to_path_buf());
 
@@ -73,6 +92,7 @@ impl ConfigLoader {
             project: project_config,
             global: global_config,
             project_root,
+            warnings,
         })
     }
 
@@ -82,6 +102,7 @@ impl ConfigLoader {
     fn find_project_config(
         &self,
         start_dir: &Path,
+        warnings: &mut Vec<ConfigWarning>,
     ) -> ConfigResult<(Option<PathBuf>, ProjectConfig)> {
         let mut current = start_dir.to_path_buf();
 
@@ -89,7 +110,9 @@ impl ConfigLoader {
             let config_path = current.join("atlas.toml");
 
             if config_path.exists() {
-                let project_config = ProjectConfig::load_from_file(&config_path)?;
+                let (project_config, file_warnings) =
+                    ProjectConfig::load_from_file_with_policy(&config_path, self.unknown_fields)?;
+                warnings.extend(file_warnings);
                 return Ok((Some(current), project_config));
             }
 
@@ -106,7 +129,10 @@ impl ConfigLoader {
     }
 
     /// Load global configuration from ~/.atlas/config.toml
-    fn load_global_config(&mut self) -> ConfigResult<GlobalConfig> {
+    fn load_global_config(
+        &mut self,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> ConfigResult<GlobalConfig> {
         // Get or cache global config path
         if self.global_config_path.is_none() {
             self.global_config_path = Some(GlobalConfig::global_config_path()?);
@@ -119,7 +145,10 @@ impl ConfigLoader {
             return Ok(GlobalConfig::default());
         }
 
-        GlobalConfig::load_from_file(path)
+        let (config, file_warnings) =
+            GlobalConfig::load_from_file_with_policy(path, self.unknown_fields)?;
+        warnings.extend(file_warnings);
+        Ok(config)
     }
 
     /// Apply environment variable overrides to project config
@@ -165,6 +194,14 @@ impl ConfigLoader {
         Ok(home.join(".atlas"))
     }
 
+    /// JSON Schema for the configuration files, for editor validation
+    ///
+    /// See [`crate::schema::export`] for the document layout.
+    pub fn export_schema() -> String {
+        serde_json::to_string_pretty(&crate::schema::export())
+            .expect("schema values always serialize")
+    }
+
     /// Ensure global configuration directory exists
     pub fn ensure_global_config_dir() -> ConfigResult<PathBuf> {
         let dir = Self::global_config_dir()?;
@@ -175,6 +212,47 @@ impl ConfigLoader {
     }
 }
 
+/// Parse a config file: expand environment references, apply the unknown
+/// field policy, then deserialize
+///
+/// Runs after TOML parsing and before the caller validates the result.
+pub(crate) fn parse_config<T: DeserializeOwned + ConfigSchema>(
+    content: &str,
+    path: &Path,
+    policy: UnknownFieldPolicy,
+) -> ConfigResult<(T, Vec<ConfigWarning>)> {
+    let to_parse_error = |error| ConfigError::TomlParseError {
+        file: path.to_path_buf(),
+        error,
+    };
+    let mut value: toml::Value = toml::from_str(content).map_err(to_parse_error)?;
+    crate::interpolate::interpolate_env(&mut value)?;
+
+    let unknown = schema::strip_unknown_fields(&mut value, T::NAME);
+    let warnings = match policy {
+        UnknownFieldPolicy::Error => {
+            if let Some(field) = unknown.into_iter().next() {
+                return Err(ConfigError::UnknownField {
+                    field,
+                    file: path.to_path_buf(),
+                });
+            }
+            Vec::new()
+        }
+        UnknownFieldPolicy::Warn => unknown
+            .into_iter()
+            .map(|field| ConfigWarning::UnknownField {
+                field,
+                file: path.to_path_buf(),
+            })
+            .collect(),
+        UnknownFieldPolicy::Ignore => Vec::new(),
+    };
+
+    let config = value.try_into().map_err(to_parse_error)?;
+    Ok((config, warnings))
+}
+
 impl Default for ConfigLoader {
     fn default() -> Self {
         Self::new()
@@ -200,6 +278,15 @@ impl Config {
         self.project.package_name()
     }
 
+    /// Get the prelude module path, resolved against the project root
+    pub fn prelude_path(&self) -> Option<PathBuf> {
+        let prelude = self.project.prelude()?;
+        Some(match self.project_root() {
+            Some(root) => root.join(prelude),
+            None => prelude.to_path_buf(),
+        })
+    }
+
     /// Check if this is a project (has atlas.toml)
     pub fn is_project(&self) -> bool {
         self.project_root.is_some()
@@ -257,6 +344,23 @@ version = "1.0.0"
         assert_eq!(config.project_root(), Some(temp_dir.path()));
     }
 
+    #[test]
+    fn test_prelude_path_is_relative_to_project_root() {
+        let temp_dir = TempDir::new().unwrap();
+        create_config_file(temp_dir.path(), "[build]\nprelude = \"src/prelude.atl\"\n");
+
+        let sub_dir = temp_dir.path().join("src");
+        fs::create_dir(&sub_dir).unwrap();
+
+        let mut loader = ConfigLoader::new();
+        let config = loader.load_from_directory(&sub_dir).unwrap();
+
+        assert_eq!(
+            config.prelude_path(),
+            Some(temp_dir.path().join("src/prelude.atl"))
+        );
+    }
+
     #[test]
     fn test_no_project_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -323,6 +427,7 @@ optimize = false
             project: ProjectConfig::default(),
             global: GlobalConfig::default(),
             project_root: None,
+            warnings: Vec::new(),
         };
 
         assert_eq!(config.edition(), "2026"); // Default edition
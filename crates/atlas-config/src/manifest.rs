@@ -3,6 +3,7 @@
 //! Handles package metadata and dependencies for Atlas packages.
 
 use crate::project::{DependencySpec, PackageConfig};
+use crate::schema::{self, ConfigSchema, ObjectSchema};
 use crate::{ConfigError, ConfigResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -100,6 +101,28 @@ impl Manifest {
     }
 }
 
+impl ConfigSchema for Manifest {
+    const NAME: &'static str = "Manifest";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Package manifest")
+            .required("package", schema::reference::<PackageConfig>())
+            .field(
+                "dependencies",
+                schema::map("Dependencies", schema::reference::<DependencySpec>()),
+            )
+            .field(
+                "dev-dependencies",
+                schema::map(
+                    "Development dependencies",
+                    schema::reference::<DependencySpec>(),
+                ),
+            )
+            .allow_unknown()
+            .build()
+    }
+}
+
 /// Basic semver validation (simplified)
 fn is_valid_version(version: &str) -> bool {
     if version.is_empty() {
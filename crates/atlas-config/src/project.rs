@@ -2,8 +2,9 @@
 //!
 //! Handles project-level configuration stored in `atlas.toml` at the project root.
 
+use crate::schema::{self, ConfigSchema, ObjectSchema};
 use crate::security::SecurityConfig;
-use crate::{ConfigError, ConfigResult};
+use crate::{ConfigError, ConfigResult, ConfigWarning, UnknownFieldPolicy};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -90,6 +91,10 @@ pub struct BuildConfig {
     /// Entry point file (default: "src/main.atl")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entry: Option<PathBuf>,
+
+    /// Module whose exports every module sees without importing them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prelude: Option<PathBuf>,
 }
 
 /// Compiler configuration
@@ -154,8 +159,16 @@ pub enum DependencySpec {
 }
 
 impl ProjectConfig {
-    /// Load project configuration from a file
+    /// Load project configuration from a file, rejecting unknown fields
     pub fn load_from_file(path: &Path) -> ConfigResult<Self> {
+        Self::load_from_file_with_policy(path, UnknownFieldPolicy::Error).map(|(config, _)| config)
+    }
+
+    /// Load project configuration from a file, handling unknown fields per `policy`
+    pub fn load_from_file_with_policy(
+        path: &Path,
+        policy: UnknownFieldPolicy,
+    ) -> ConfigResult<(Self, Vec<ConfigWarning>)> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 ConfigError::NotFound(path.to_path_buf())
@@ -164,13 +177,10 @@ impl ProjectConfig {
             }
         })?;
 
-        let config: Self = toml::from_str(&content).map_err(|e| ConfigError::TomlParseError {
-            file: path.to_path_buf(),
-            error: e,
-        })?;
+        let (config, warnings): (Self, _) = crate::loader::parse_config(&content, path, policy)?;
 
         config.validate()?;
-        Ok(config)
+        Ok((config, warnings))
     }
 
     /// Validate the project configuration
@@ -230,6 +240,11 @@ impl ProjectConfig {
         self.package.as_ref().and_then(|p| p.edition.as_deref())
     }
 
+    /// Get the prelude module path, if configured
+    pub fn prelude(&self) -> Option<&Path> {
+        self.build.as_ref().and_then(|b| b.prelude.as_deref())
+    }
+
     /// Merge another project config into this one
     /// Other config takes precedence for non-None values
     pub fn merge(&mut self, other: &ProjectConfig) {
@@ -257,6 +272,126 @@ impl ProjectConfig {
     }
 }
 
+impl ConfigSchema for ProjectConfig {
+    const NAME: &'static str = "ProjectConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Project configuration (atlas.toml)")
+            .field("package", schema::reference::<PackageConfig>())
+            .field("build", schema::reference::<BuildConfig>())
+            .field("compiler", schema::reference::<CompilerConfig>())
+            .field("formatting", schema::reference::<FormattingConfig>())
+            .field("security", schema::reference::<SecurityConfig>())
+            .field(
+                "dependencies",
+                schema::map("Dependencies", schema::reference::<DependencySpec>()),
+            )
+            .field(
+                "dev-dependencies",
+                schema::map(
+                    "Development dependencies",
+                    schema::reference::<DependencySpec>(),
+                ),
+            )
+            .build()
+    }
+}
+
+impl ConfigSchema for PackageConfig {
+    const NAME: &'static str = "PackageConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Package metadata")
+            .required("name", schema::string("Package name"))
+            .required("version", schema::string("Package version (semver)"))
+            .field("edition", schema::string_enum("Atlas edition", EDITIONS))
+            .field("description", schema::string("Package description"))
+            .field(
+                "authors",
+                schema::array("Package authors", schema::string("Author")),
+            )
+            .field("license", schema::string("License identifier"))
+            .field("repository", schema::string("Repository URL"))
+            .build()
+    }
+}
+
+impl ConfigSchema for BuildConfig {
+    const NAME: &'static str = "BuildConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Build configuration")
+            .field(
+                "output",
+                schema::string("Output directory (default: \"target\")"),
+            )
+            .field(
+                "source",
+                schema::string("Source directory (default: \"src\")"),
+            )
+            .field(
+                "entry",
+                schema::string("Entry point file (default: \"src/main.atl\")"),
+            )
+            .field(
+                "prelude",
+                schema::string("Module whose exports are visible in every module"),
+            )
+            .build()
+    }
+}
+
+impl ConfigSchema for CompilerConfig {
+    const NAME: &'static str = "CompilerConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Compiler configuration")
+            .field("optimize", schema::boolean("Enable optimizations"))
+            .field(
+                "target",
+                schema::string("Target (interpreter, bytecode, etc.)"),
+            )
+            .field("debug", schema::boolean("Enable debug info"))
+            .build()
+    }
+}
+
+impl ConfigSchema for FormattingConfig {
+    const NAME: &'static str = "FormattingConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Formatting configuration")
+            .field("indent", schema::integer("Indentation size (default: 4)"))
+            .field(
+                "max_line_length",
+                schema::integer("Maximum line length (default: 100)"),
+            )
+            .field("use_tabs", schema::boolean("Use tabs instead of spaces"))
+            .build()
+    }
+}
+
+impl ConfigSchema for DependencySpec {
+    const NAME: &'static str = "DependencySpec";
+
+    fn schema() -> serde_json::Value {
+        let detailed = ObjectSchema::new("Detailed dependency spec")
+            .field("version", schema::string("Version requirement"))
+            .field("git", schema::string("Git repository URL"))
+            .field("path", schema::string("Local path"))
+            .field("registry", schema::string("Registry name"))
+            .allow_unknown()
+            .build();
+        serde_json::json!({
+            "description": "Dependency specification",
+            "oneOf": [schema::string("Version requirement (e.g., \"1.0\")"), detailed],
+        })
+    }
+}
+
+/// Editions accepted by [`is_valid_edition`]
+pub(crate) const EDITIONS: &[&str] = &["2026", "2027", "2028"];
+
 /// Basic semver validation (simplified)
 fn is_valid_version(version: &str) -> bool {
     if version.is_empty() {
@@ -284,7 +419,7 @@ fn is_valid_version(version: &str) -> bool {
 
 /// Check if edition is valid
 fn is_valid_edition(edition: &str) -> bool {
-    matches!(edition, "2026" | "2027" | "2028") // Future-proof
+    EDITIONS.contains(&edition)
 }
 
 /// Validate a dependency specification
@@ -378,6 +513,18 @@ test-utils = { path = "../test-utils" }
         assert!(config.dependencies.contains_key("http"));
     }
 
+    #[test]
+    fn test_parse_build_prelude() {
+        let toml = r#"
+[build]
+prelude = "src/prelude.atl"
+"#;
+
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.prelude(), Some(Path::new("src/prelude.atl")));
+        assert_eq!(ProjectConfig::default().prelude(), None);
+    }
+
     #[test]
     fn test_version_validation() {
         assert!(is_valid_version("1.0.0"));
@@ -0,0 +1,452 @@
+//! JSON Schema Export
+//!
+//! Describes the configuration file formats as a JSON Schema (draft-07) so
+//! editors can validate and complete `atlas.toml` and `~/.atlas/config.toml`.
+//!
+//! Each config struct implements [`ConfigSchema`] next to its definition.
+//! The tests in this module check every schema against its struct's serde
+//! fields, so adding a field without describing it fails the build.
+
+use serde_json::{json, Map, Value};
+
+/// Draft used by the exported schema
+const SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// A configuration type with a JSON Schema description
+pub trait ConfigSchema {
+    /// Name of the type under the schema's `definitions`
+    const NAME: &'static str;
+
+    /// Schema for this type; nested config types appear as [`reference`]s
+    fn schema() -> Value;
+}
+
+/// Build the full schema document
+///
+/// The root validates `atlas.toml` (a [`ProjectConfig`](crate::ProjectConfig)).
+/// The global config and package manifest are available as
+/// `#/definitions/GlobalConfig` and `#/definitions/Manifest`.
+pub fn export() -> Value {
+    use crate::global::*;
+    use crate::project::*;
+    use crate::security::*;
+    use crate::Manifest;
+
+    let mut definitions = Map::new();
+    let mut define = |name: &str, schema: Value| {
+        definitions.insert(name.to_string(), schema);
+    };
+    define(ProjectConfig::NAME, ProjectConfig::schema());
+    define(PackageConfig::NAME, PackageConfig::schema());
+    define(BuildConfig::NAME, BuildConfig::schema());
+    define(CompilerConfig::NAME, CompilerConfig::schema());
+    define(FormattingConfig::NAME, FormattingConfig::schema());
+    define(DependencySpec::NAME, DependencySpec::schema());
+    define(SecurityConfig::NAME, SecurityConfig::schema());
+    define(FilesystemPermissions::NAME, FilesystemPermissions::schema());
+    define(NetworkPermissions::NAME, NetworkPermissions::schema());
+    define(ProcessPermissions::NAME, ProcessPermissions::schema());
+    define(
+        EnvironmentPermissions::NAME,
+        EnvironmentPermissions::schema(),
+    );
+    define(GlobalConfig::NAME, GlobalConfig::schema());
+    define(DefaultsConfig::NAME, DefaultsConfig::schema());
+    define(
+        GlobalFormattingConfig::NAME,
+        GlobalFormattingConfig::schema(),
+    );
+    define(PermissionsConfig::NAME, PermissionsConfig::schema());
+    define(LspConfig::NAME, LspConfig::schema());
+    define(Manifest::NAME, Manifest::schema());
+
+    json!({
+        "$schema": SCHEMA_DRAFT,
+        "title": "Atlas configuration (atlas.toml)",
+        "$ref": reference::<ProjectConfig>()["$ref"],
+        "definitions": definitions,
+    })
+}
+
+/// Remove fields the schema doesn't describe, returning their dotted paths
+///
+/// `value` is checked against the definition named `definition`. Objects
+/// that allow unknown fields keep them.
+pub(crate) fn strip_unknown_fields(value: &mut toml::Value, definition: &str) -> Vec<String> {
+    let definitions = export()["definitions"].take();
+    let mut unknown = Vec::new();
+    strip(
+        value,
+        &definitions[definition],
+        &definitions,
+        "",
+        &mut unknown,
+    );
+    unknown
+}
+
+fn strip(
+    value: &mut toml::Value,
+    schema: &Value,
+    definitions: &Value,
+    path: &str,
+    unknown: &mut Vec<String>,
+) {
+    if let Some(target) = schema["$ref"].as_str() {
+        let name = target.trim_start_matches("#/definitions/");
+        return strip(value, &definitions[name], definitions, path, unknown);
+    }
+    if let Some(options) = schema["oneOf"].as_array() {
+        // Only a table can hold unknown fields; check it against the object form
+        if value.is_table() {
+            if let Some(object) = options.iter().find(|o| o["type"] == "object") {
+                strip(value, object, definitions, path, unknown);
+            }
+        }
+        return;
+    }
+
+    let join = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match value {
+        toml::Value::Table(table) => {
+            if let Some(properties) = schema["properties"].as_object() {
+                let allow_unknown = schema["additionalProperties"] == true;
+                let keys: Vec<String> = table.keys().cloned().collect();
+                for key in keys {
+                    match properties.get(&key) {
+                        Some(property) => {
+                            let item = table.get_mut(&key).unwrap();
+                            strip(item, property, definitions, &join(&key), unknown);
+                        }
+                        None if !allow_unknown => {
+                            table.remove(&key);
+                            unknown.push(join(&key));
+                        }
+                        None => {}
+                    }
+                }
+            } else if schema["additionalProperties"].is_object() {
+                for (key, item) in table.iter_mut() {
+                    let values = &schema["additionalProperties"];
+                    strip(item, values, definitions, &join(key), unknown);
+                }
+            }
+        }
+        toml::Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                let item_path = format!("{}[{}]", path, i);
+                strip(item, &schema["items"], definitions, &item_path, unknown);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Builder for an object schema
+pub struct ObjectSchema {
+    description: &'static str,
+    properties: Map<String, Value>,
+    required: Vec<&'static str>,
+    allow_unknown: bool,
+}
+
+impl ObjectSchema {
+    /// Start an object that rejects unknown fields (`deny_unknown_fields`)
+    pub fn new(description: &'static str) -> Self {
+        Self {
+            description,
+            properties: Map::new(),
+            required: Vec::new(),
+            allow_unknown: false,
+        }
+    }
+
+    /// Add an optional field
+    pub fn field(mut self, name: &'static str, schema: Value) -> Self {
+        self.properties.insert(name.to_string(), schema);
+        self
+    }
+
+    /// Add a field that must be present
+    pub fn required(mut self, name: &'static str, schema: Value) -> Self {
+        self.required.push(name);
+        self.field(name, schema)
+    }
+
+    /// Accept fields not listed in the schema
+    pub fn allow_unknown(mut self) -> Self {
+        self.allow_unknown = true;
+        self
+    }
+
+    /// Finish the object schema
+    pub fn build(self) -> Value {
+        let mut schema = json!({
+            "type": "object",
+            "description": self.description,
+            "properties": self.properties,
+            "additionalProperties": self.allow_unknown,
+        });
+        if !self.required.is_empty() {
+            schema["required"] = json!(self.required);
+        }
+        schema
+    }
+}
+
+/// A string value
+pub fn string(description: &str) -> Value {
+    json!({ "type": "string", "description": description })
+}
+
+/// A string restricted to the given values
+pub fn string_enum(description: &str, values: &[&str]) -> Value {
+    json!({ "type": "string", "description": description, "enum": values })
+}
+
+/// A non-negative integer value
+pub fn integer(description: &str) -> Value {
+    json!({ "type": "integer", "minimum": 0, "description": description })
+}
+
+/// A boolean value
+pub fn boolean(description: &str) -> Value {
+    json!({ "type": "boolean", "description": description })
+}
+
+/// An array whose items match `items`
+pub fn array(description: &str, items: Value) -> Value {
+    json!({ "type": "array", "description": description, "items": items })
+}
+
+/// A table with arbitrary keys whose values match `values`
+pub fn map(description: &str, values: Value) -> Value {
+    json!({ "type": "object", "description": description, "additionalProperties": values })
+}
+
+/// A reference to another config type's definition
+pub fn reference<T: ConfigSchema>() -> Value {
+    json!({ "$ref": format!("#/definitions/{}", T::NAME) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::global::*;
+    use crate::project::*;
+    use crate::security::*;
+    use crate::Manifest;
+    use serde::de::{self, Deserialize, Visitor};
+
+    /// Deserializer that records the field names serde derives for a struct
+    struct FieldNames(Vec<&'static str>);
+
+    #[derive(Debug)]
+    struct Stop;
+
+    impl std::fmt::Display for Stop {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("stop")
+        }
+    }
+
+    impl std::error::Error for Stop {}
+
+    impl de::Error for Stop {
+        fn custom<T: std::fmt::Display>(_: T) -> Self {
+            Stop
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for &mut FieldNames {
+        type Error = Stop;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Stop> {
+            Err(Stop)
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Stop> {
+            self.0 = fields.to_vec();
+            Err(Stop)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    fn struct_fields<T: for<'de> Deserialize<'de>>() -> Vec<&'static str> {
+        let mut names = FieldNames(Vec::new());
+        let _ = T::deserialize(&mut names);
+        names.0.sort_unstable();
+        names.0
+    }
+
+    fn schema_fields<T: ConfigSchema>() -> Vec<String> {
+        let mut names: Vec<String> = T::schema()["properties"]
+            .as_object()
+            .expect("struct schemas are objects")
+            .keys()
+            .cloned()
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    fn assert_in_sync<T: ConfigSchema + for<'de> Deserialize<'de>>() {
+        assert_eq!(
+            schema_fields::<T>(),
+            struct_fields::<T>(),
+            "schema for {} is out of sync with its fields",
+            T::NAME
+        );
+    }
+
+    #[test]
+    fn test_schemas_match_struct_fields() {
+        assert_in_sync::<ProjectConfig>();
+        assert_in_sync::<PackageConfig>();
+        assert_in_sync::<BuildConfig>();
+        assert_in_sync::<CompilerConfig>();
+        assert_in_sync::<FormattingConfig>();
+        assert_in_sync::<SecurityConfig>();
+        assert_in_sync::<FilesystemPermissions>();
+        assert_in_sync::<NetworkPermissions>();
+        assert_in_sync::<ProcessPermissions>();
+        assert_in_sync::<EnvironmentPermissions>();
+        assert_in_sync::<GlobalConfig>();
+        assert_in_sync::<DefaultsConfig>();
+        assert_in_sync::<GlobalFormattingConfig>();
+        assert_in_sync::<PermissionsConfig>();
+        assert_in_sync::<LspConfig>();
+        assert_in_sync::<Manifest>();
+    }
+
+    /// Build a TOML value containing every property the schema describes
+    fn sample(schema: &Value, definitions: &Value) -> toml::Value {
+        if let Some(target) = schema["$ref"].as_str() {
+            let name = target.trim_start_matches("#/definitions/");
+            return sample(&definitions[name], definitions);
+        }
+        if let Some(options) = schema["oneOf"].as_array() {
+            return sample(&options[0], definitions);
+        }
+        if let Some(values) = schema["enum"].as_array() {
+            return toml::Value::String(values[0].as_str().unwrap().to_string());
+        }
+        match schema["type"].as_str().unwrap() {
+            "string" => toml::Value::String("1.0.0".to_string()),
+            "integer" => toml::Value::Integer(1),
+            "boolean" => toml::Value::Boolean(true),
+            "array" => toml::Value::Array(vec![sample(&schema["items"], definitions)]),
+            "object" => {
+                let mut table = toml::map::Map::new();
+                match schema["properties"].as_object() {
+                    Some(properties) => {
+                        for (name, property) in properties {
+                            table.insert(name.clone(), sample(property, definitions));
+                        }
+                    }
+                    None => {
+                        let values = &schema["additionalProperties"];
+                        table.insert("key".to_string(), sample(values, definitions));
+                    }
+                }
+                toml::Value::Table(table)
+            }
+            other => panic!("unexpected schema type {}", other),
+        }
+    }
+
+    fn assert_round_trips<T>(definitions: &Value)
+    where
+        T: ConfigSchema + serde::Serialize + for<'de> Deserialize<'de>,
+    {
+        let document = sample(&definitions[T::NAME], definitions);
+        let parsed: T = document
+            .clone()
+            .try_into()
+            .unwrap_or_else(|e| panic!("{} rejected its schema sample: {}", T::NAME, e));
+        assert_eq!(toml::Value::try_from(&parsed).unwrap(), document);
+    }
+
+    #[test]
+    fn test_schema_types_accepted_by_structs() {
+        let definitions = export()["definitions"].clone();
+        assert_round_trips::<ProjectConfig>(&definitions);
+        assert_round_trips::<GlobalConfig>(&definitions);
+        assert_round_trips::<Manifest>(&definitions);
+    }
+
+    #[test]
+    fn test_export_root_is_project_config() {
+        let schema = export();
+        assert_eq!(schema["$schema"], SCHEMA_DRAFT);
+        assert_eq!(schema["$ref"], "#/definitions/ProjectConfig");
+        assert!(schema["definitions"]["GlobalConfig"].is_object());
+    }
+
+    #[test]
+    fn test_strip_unknown_fields() {
+        let mut value: toml::Value = toml::from_str(
+            r#"
+future_section = true
+
+[package]
+name = "demo"
+version = "1.0.0"
+sponsor = "someone"
+
+[dependencies]
+simple = "1.0"
+detailed = { version = "1.0", features = ["x"] }
+
+[security.network]
+allow = ["example.com"]
+proxy = "none"
+"#,
+        )
+        .unwrap();
+
+        let unknown = strip_unknown_fields(&mut value, ProjectConfig::NAME);
+
+        assert_eq!(
+            unknown,
+            vec![
+                "future_section".to_string(),
+                "package.sponsor".to_string(),
+                "security.network.proxy".to_string(),
+            ]
+        );
+        assert!(value.get("future_section").is_none());
+        assert!(value["package"].get("sponsor").is_none());
+        // Detailed dependency specs accept extra keys
+        assert!(value["dependencies"]["detailed"].get("features").is_some());
+        let _: ProjectConfig = value.try_into().unwrap();
+    }
+
+    #[test]
+    fn test_object_schema_builder() {
+        let schema = ObjectSchema::new("thing")
+            .required("name", string("Name"))
+            .field("count", integer("Count"))
+            .build();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"], json!(["name"]));
+        assert_eq!(schema["properties"]["count"]["type"], "integer");
+        assert_eq!(schema["additionalProperties"], false);
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Defines security policies and permission settings for Atlas projects.
 
+use crate::schema::{self, ConfigSchema, ObjectSchema};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -107,6 +108,73 @@ impl SecurityConfig {
     }
 }
 
+impl ConfigSchema for SecurityConfig {
+    const NAME: &'static str = "SecurityConfig";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Security configuration")
+            .field(
+                "mode",
+                schema::string_enum("Security mode", &["none", "standard", "strict"]),
+            )
+            .field("filesystem", schema::reference::<FilesystemPermissions>())
+            .field("network", schema::reference::<NetworkPermissions>())
+            .field("process", schema::reference::<ProcessPermissions>())
+            .field("environment", schema::reference::<EnvironmentPermissions>())
+            .build()
+    }
+}
+
+/// Schema for a list of allowed or denied entries
+fn entries(description: &str) -> serde_json::Value {
+    schema::array(description, schema::string(description))
+}
+
+impl ConfigSchema for FilesystemPermissions {
+    const NAME: &'static str = "FilesystemPermissions";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Filesystem permissions")
+            .field("read", entries("Paths allowed for reading"))
+            .field("write", entries("Paths allowed for writing"))
+            .field("deny", entries("Paths explicitly denied"))
+            .build()
+    }
+}
+
+impl ConfigSchema for NetworkPermissions {
+    const NAME: &'static str = "NetworkPermissions";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Network permissions")
+            .field("allow", entries("Hosts/domains allowed"))
+            .field("deny", entries("Hosts/domains explicitly denied"))
+            .build()
+    }
+}
+
+impl ConfigSchema for ProcessPermissions {
+    const NAME: &'static str = "ProcessPermissions";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Process execution permissions")
+            .field("allow", entries("Commands allowed to execute"))
+            .field("deny", entries("Commands explicitly denied"))
+            .build()
+    }
+}
+
+impl ConfigSchema for EnvironmentPermissions {
+    const NAME: &'static str = "EnvironmentPermissions";
+
+    fn schema() -> serde_json::Value {
+        ObjectSchema::new("Environment variable permissions")
+            .field("allow", entries("Environment variables allowed to read"))
+            .field("deny", entries("Environment variables explicitly denied"))
+            .build()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
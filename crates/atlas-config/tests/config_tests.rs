@@ -1,6 +1,6 @@
 //! Comprehensive configuration loading and precedence tests
 
-use atlas_config::{ConfigLoader, ProjectConfig};
+use atlas_config::{ConfigError, ConfigLoader, ConfigWarning, ProjectConfig, UnknownFieldPolicy};
 use serial_test::serial;
 use std::env;
 use std::fs;
@@ -148,6 +148,82 @@ unknown_field = "value"
     assert!(result.is_err());
 }
 
+const FORWARD_COMPATIBLE_CONFIG: &str = r#"
+[package]
+name = "test"
+version = "1.0.0"
+sponsor = "someone"
+
+[workspace]
+members = ["a"]
+"#;
+
+#[test]
+fn test_unknown_field_policy_error_rejects() {
+    let temp_dir = TempDir::new().unwrap();
+    create_config_file(temp_dir.path(), FORWARD_COMPATIBLE_CONFIG);
+
+    let mut loader = ConfigLoader::new().with_unknown_field_policy(UnknownFieldPolicy::Error);
+    let err = loader.load_from_directory(temp_dir.path()).unwrap_err();
+
+    match err {
+        ConfigError::UnknownField { field, file } => {
+            assert_eq!(field, "package.sponsor");
+            assert_eq!(file, temp_dir.path().join("atlas.toml"));
+        }
+        other => panic!("expected UnknownField, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_field_policy_warn_loads_with_warnings() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = create_config_file(temp_dir.path(), FORWARD_COMPATIBLE_CONFIG);
+
+    let mut loader = ConfigLoader::new().with_unknown_field_policy(UnknownFieldPolicy::Warn);
+    let config = loader.load_from_directory(temp_dir.path()).unwrap();
+
+    assert_eq!(config.package_name(), Some("test"));
+    let project_warnings: Vec<_> = config
+        .warnings
+        .iter()
+        .filter(|w| matches!(w, ConfigWarning::UnknownField { file, .. } if *file == config_path))
+        .collect();
+    assert_eq!(
+        project_warnings,
+        vec![
+            &ConfigWarning::UnknownField {
+                field: "package.sponsor".to_string(),
+                file: config_path.clone(),
+            },
+            &ConfigWarning::UnknownField {
+                field: "workspace".to_string(),
+                file: config_path.clone(),
+            },
+        ]
+    );
+    assert_eq!(
+        project_warnings[0].to_string(),
+        format!(
+            "Unknown field 'package.sponsor' in {}",
+            config_path.display()
+        )
+    );
+}
+
+#[test]
+fn test_unknown_field_policy_ignore_loads_silently() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = create_config_file(temp_dir.path(), FORWARD_COMPATIBLE_CONFIG);
+
+    let (config, warnings) =
+        ProjectConfig::load_from_file_with_policy(&config_path, UnknownFieldPolicy::Ignore)
+            .unwrap();
+
+    assert_eq!(config.package_name(), Some("test"));
+    assert!(warnings.is_empty());
+}
+
 #[test]
 fn test_invalid_version_format() {
     let temp_dir = TempDir::new().unwrap();
@@ -295,6 +371,103 @@ version = "1.0.0"
     assert_eq!(config.edition(), "2026");
 }
 
+// ============================================================================
+// Environment Interpolation Tests
+// ============================================================================
+
+#[test]
+#[serial]
+fn test_interpolate_defined_variable() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = r#"
+[package]
+name = "test"
+version = "1.0.0"
+
+[build]
+output = "${ATLAS_TEST_OUT_ROOT}/atlas-out"
+"#;
+    create_config_file(temp_dir.path(), content);
+
+    env::set_var("ATLAS_TEST_OUT_ROOT", "/tmp/home");
+
+    let mut loader = ConfigLoader::new();
+    let config = loader.load_from_directory(temp_dir.path()).unwrap();
+
+    assert_eq!(
+        config.project.build.as_ref().unwrap().output,
+        Some(std::path::PathBuf::from("/tmp/home/atlas-out"))
+    );
+
+    env::remove_var("ATLAS_TEST_OUT_ROOT");
+}
+
+#[test]
+#[serial]
+fn test_interpolate_default_fallback() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = r#"
+[package]
+name = "${ATLAS_TEST_PKG_NAME:-fallback-name}"
+version = "1.0.0"
+"#;
+    create_config_file(temp_dir.path(), content);
+
+    env::remove_var("ATLAS_TEST_PKG_NAME");
+
+    let mut loader = ConfigLoader::new();
+    let config = loader.load_from_directory(temp_dir.path()).unwrap();
+
+    assert_eq!(config.package_name(), Some("fallback-name"));
+}
+
+#[test]
+#[serial]
+fn test_interpolate_undefined_variable_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = r#"
+[package]
+name = "test"
+version = "1.0.0"
+
+[build]
+output = "${ATLAS_TEST_UNDEFINED_DIR}/out"
+"#;
+    create_config_file(temp_dir.path(), content);
+
+    env::remove_var("ATLAS_TEST_UNDEFINED_DIR");
+
+    let mut loader = ConfigLoader::new();
+    let err = loader.load_from_directory(temp_dir.path()).unwrap_err();
+
+    match err {
+        ConfigError::InvalidValue { field, reason } => {
+            assert_eq!(field, "build.output");
+            assert!(reason.contains("ATLAS_TEST_UNDEFINED_DIR"));
+        }
+        other => panic!("expected InvalidValue, got {:?}", other),
+    }
+}
+
+#[test]
+#[serial]
+fn test_interpolated_values_are_validated() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = r#"
+[package]
+name = "test"
+version = "${ATLAS_TEST_PKG_VERSION}"
+"#;
+    create_config_file(temp_dir.path(), content);
+
+    env::set_var("ATLAS_TEST_PKG_VERSION", "not-a-version");
+
+    let result = ProjectConfig::load_from_file(&temp_dir.path().join("atlas.toml"));
+    assert!(matches!(result, Err(ConfigError::InvalidVersion(_))));
+
+    env::remove_var("ATLAS_TEST_PKG_VERSION");
+}
+
 // ============================================================================
 // Validation Tests
 // ============================================================================
@@ -575,3 +748,42 @@ use_tabs = true
     assert_eq!(formatting.max_line_length, Some(80));
     assert_eq!(formatting.use_tabs, Some(true));
 }
+
+// ============================================================================
+// Schema Export Tests
+// ============================================================================
+
+#[test]
+fn test_export_schema_lists_required_package_name() {
+    let schema: serde_json::Value = serde_json::from_str(&ConfigLoader::export_schema()).unwrap();
+    let package = &schema["definitions"]["PackageConfig"];
+
+    let required: Vec<&str> = package["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v.as_str().unwrap())
+        .collect();
+    assert_eq!(required, vec!["name", "version"]);
+    assert_eq!(package["properties"]["name"]["type"], "string");
+    assert_eq!(package["properties"]["authors"]["type"], "array");
+    assert_eq!(
+        schema["definitions"]["ProjectConfig"]["properties"]["package"]["$ref"],
+        "#/definitions/PackageConfig"
+    );
+}
+
+#[test]
+fn test_export_schema_describes_global_config() {
+    let schema: serde_json::Value = serde_json::from_str(&ConfigLoader::export_schema()).unwrap();
+    let permissions = &schema["definitions"]["PermissionsConfig"]["properties"];
+
+    assert_eq!(
+        permissions["network"]["enum"],
+        serde_json::json!(["allow", "deny", "prompt"])
+    );
+    assert_eq!(
+        schema["definitions"]["LspConfig"]["properties"]["hover"]["type"],
+        "boolean"
+    );
+}
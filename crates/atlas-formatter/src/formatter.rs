@@ -5,6 +5,7 @@ use atlas_runtime::parser::Parser;
 use serde::{Deserialize, Serialize};
 
 use crate::comments::CommentCollector;
+use crate::range::{LineRange, RangeFormatter};
 use crate::visitor::FormatVisitor;
 
 /// Formatter configuration
@@ -18,6 +19,12 @@ pub struct FormatConfig {
     pub trailing_commas: bool,
     /// Semicolon style: "always" (default)
     pub semicolon_style: SemicolonStyle,
+    /// Whether to sort and group consecutive imports (default: false)
+    #[serde(default)]
+    pub reorder_imports: bool,
+    /// Only reformat statements entirely within these lines (default: whole file)
+    #[serde(skip)]
+    pub range: Option<LineRange>,
 }
 
 /// Semicolon insertion style
@@ -34,6 +41,8 @@ impl Default for FormatConfig {
             max_width: 100,
             trailing_commas: true,
             semicolon_style: SemicolonStyle::Always,
+            reorder_imports: false,
+            range: None,
         }
     }
 }
@@ -56,6 +65,18 @@ impl FormatConfig {
         self.trailing_commas = enabled;
         self
     }
+
+    /// Create config that only formats statements within `start..=end` (1-based lines)
+    pub fn with_range(mut self, start: usize, end: usize) -> Self {
+        self.range = Some(LineRange::new(start, end));
+        self
+    }
+
+    /// Create config with import reordering setting
+    pub fn with_reorder_imports(mut self, enabled: bool) -> Self {
+        self.reorder_imports = enabled;
+        self
+    }
 }
 
 /// Result of formatting
@@ -99,7 +120,13 @@ impl Formatter {
             return FormatResult::ParseError(errors);
         }
 
-        // Step 4: Visit AST and produce formatted output
+        // Step 4: With a range, splice reformatted statements into the source
+        if let Some(range) = self.config.range {
+            let formatter = RangeFormatter::new(&self.config, &comments, source, range);
+            return FormatResult::Ok(formatter.format(&program));
+        }
+
+        // Step 5: Visit AST and produce formatted output
         let mut visitor = FormatVisitor::new(self.config.clone(), comments, source.to_string());
         visitor.visit_program(&program);
 
@@ -4,10 +4,12 @@
 
 pub mod comments;
 mod formatter;
+mod range;
 mod visitor;
 
 pub use comments::{Comment, CommentKind, CommentPosition};
 pub use formatter::{FormatConfig, FormatResult, Formatter};
+pub use range::LineRange;
 
 /// Format Atlas source code with default configuration
 pub fn format_source(source: &str) -> FormatResult {
@@ -0,0 +1,205 @@
+//! Range formatting - reformat only the statements inside a line range
+
+use atlas_runtime::ast::*;
+use atlas_runtime::span::Span;
+use serde::{Deserialize, Serialize};
+
+use crate::comments::Comment;
+use crate::formatter::FormatConfig;
+use crate::visitor::FormatVisitor;
+
+/// An inclusive, 1-based range of source lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineRange {
+    /// First line of the range
+    pub start: usize,
+    /// Last line of the range (inclusive)
+    pub end: usize,
+}
+
+impl LineRange {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    fn contains(&self, first: usize, last: usize) -> bool {
+        self.start <= first && last <= self.end
+    }
+
+    fn overlaps(&self, first: usize, last: usize) -> bool {
+        first <= self.end && self.start <= last
+    }
+}
+
+/// A node selected for reformatting
+enum Target<'a> {
+    Item(&'a Item),
+    Stmt(&'a Stmt),
+}
+
+/// Reformats the nodes that lie entirely within a line range, copying
+/// everything else from the source unchanged
+pub(crate) struct RangeFormatter<'a> {
+    config: &'a FormatConfig,
+    comments: &'a [Comment],
+    source: &'a str,
+    range: LineRange,
+    /// Byte offset of the start of each line
+    line_starts: Vec<usize>,
+    /// Replacements as (start, end, text), in source order
+    edits: Vec<(usize, usize, String)>,
+}
+
+impl<'a> RangeFormatter<'a> {
+    pub(crate) fn new(
+        config: &'a FormatConfig,
+        comments: &'a [Comment],
+        source: &'a str,
+        range: LineRange,
+    ) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            config,
+            comments,
+            source,
+            range,
+            line_starts,
+            edits: Vec::new(),
+        }
+    }
+
+    pub(crate) fn format(mut self, program: &Program) -> String {
+        for item in &program.items {
+            let span = match item_span(item) {
+                Some(span) => span,
+                None => continue,
+            };
+            if !self.try_format(Target::Item(item), span, 0) {
+                if let Item::Function(f) = item {
+                    self.descend_block(&f.body, 1);
+                }
+            }
+        }
+
+        let mut output = String::with_capacity(self.source.len());
+        let mut cursor = 0;
+        for (start, end, text) in &self.edits {
+            output.push_str(&self.source[cursor..*start]);
+            output.push_str(text);
+            cursor = *end;
+        }
+        output.push_str(&self.source[cursor..]);
+        output
+    }
+
+    /// Reformat a node if it covers whole lines inside the range. Returns
+    /// false if the node was left alone.
+    fn try_format(&mut self, target: Target, span: Span, indent_level: usize) -> bool {
+        let first = self.line_of(span.start);
+        let last = self.line_of(span.end.saturating_sub(1));
+        if !self.range.contains(first, last) {
+            return false;
+        }
+
+        // Only replace nodes that own their lines, so neighbouring code on the
+        // same line is never touched
+        let line_start = self.line_starts[first - 1];
+        let line_end = self.line_end(last);
+        if !self.source[line_start..span.start].trim().is_empty() {
+            return false;
+        }
+        let rest = self.source[span.end..line_end].trim();
+        if !rest.is_empty() && !rest.starts_with("//") && !rest.starts_with("/*") {
+            return false;
+        }
+
+        let comments = self
+            .comments
+            .iter()
+            .filter(|c| c.span.start >= span.start && c.span.end <= line_end)
+            .cloned()
+            .collect();
+        let mut visitor =
+            FormatVisitor::new(self.config.clone(), comments, self.source.to_string())
+                .with_indent_level(indent_level);
+        match target {
+            Target::Item(item) => visitor.visit_item(item),
+            Target::Stmt(stmt) => visitor.visit_statement(stmt),
+        }
+        let formatted = visitor.into_output();
+
+        self.edits.push((
+            line_start,
+            line_end,
+            formatted.trim_end_matches('\n').to_string(),
+        ));
+        true
+    }
+
+    /// Look for formattable statements inside a partially selected block
+    fn descend_block(&mut self, block: &Block, indent_level: usize) {
+        let first = self.line_of(block.span.start);
+        let last = self.line_of(block.span.end.saturating_sub(1));
+        if !self.range.overlaps(first, last) {
+            return;
+        }
+
+        for stmt in &block.statements {
+            if self.try_format(Target::Stmt(stmt), stmt.span(), indent_level) {
+                continue;
+            }
+            match stmt {
+                Stmt::FunctionDecl(f) => self.descend_block(&f.body, indent_level + 1),
+                Stmt::If(i) => {
+                    self.descend_block(&i.then_block, indent_level + 1);
+                    if let Some(else_block) = &i.else_block {
+                        self.descend_block(else_block, indent_level + 1);
+                    }
+                }
+                Stmt::While(w) => self.descend_block(&w.body, indent_level + 1),
+                Stmt::For(f) => self.descend_block(&f.body, indent_level + 1),
+                Stmt::ForIn(f) => self.descend_block(&f.body, indent_level + 1),
+                Stmt::TryCatch(t) => {
+                    self.descend_block(&t.body, indent_level + 1);
+                    self.descend_block(&t.handler, indent_level + 1);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// 1-based line number of a byte offset
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset)
+    }
+
+    /// Byte offset of the end of a 1-based line, excluding the line ending
+    fn line_end(&self, line: usize) -> usize {
+        let end = self
+            .line_starts
+            .get(line)
+            .map(|&next| next - 1)
+            .unwrap_or(self.source.len());
+        if self.source[..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        }
+    }
+}
+
+/// Span of an item the visitor knows how to format
+fn item_span(item: &Item) -> Option<Span> {
+    match item {
+        Item::Function(f) => Some(f.span),
+        Item::Statement(s) => Some(s.span()),
+        Item::Import(i) => Some(i.span),
+        Item::Export(e) => Some(e.span),
+        Item::Extern(e) => Some(e.span),
+        Item::TypeAlias(alias) => Some(alias.span),
+        // Traits and impls are not formatted yet, so they are always left alone
+        Item::Trait(_) | Item::Impl(_) => None,
+    }
+}
@@ -1,10 +1,34 @@
 //! AST visitor for code formatting
 
 use atlas_runtime::ast::*;
+use atlas_runtime::span::Span;
 
 use crate::comments::{Comment, CommentPosition};
 use crate::formatter::FormatConfig;
 
+/// Import groups, in the order they are emitted when reordering imports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ImportGroup {
+    /// Standard library: `std` and `std/...`
+    Std,
+    /// Bare package names
+    ThirdParty,
+    /// Relative (`./`, `../`) and absolute (`/`) paths
+    Local,
+}
+
+impl ImportGroup {
+    fn of(source: &str) -> Self {
+        if source.starts_with('.') || source.starts_with('/') {
+            ImportGroup::Local
+        } else if source == "std" || source.starts_with("std/") {
+            ImportGroup::Std
+        } else {
+            ImportGroup::ThirdParty
+        }
+    }
+}
+
 /// AST visitor that produces formatted source code
 pub struct FormatVisitor {
     /// Output buffer
@@ -33,6 +57,12 @@ impl FormatVisitor {
         }
     }
 
+    /// Start formatting at the given indentation level
+    pub fn with_indent_level(mut self, level: usize) -> Self {
+        self.indent_level = level;
+        self
+    }
+
     pub fn into_output(self) -> String {
         let mut result = self.output;
         // Ensure file ends with a single newline
@@ -83,7 +113,10 @@ impl FormatVisitor {
         if self.comment_index < self.comments.len() {
             let pos = self.comments[self.comment_index].position;
             let start = self.comments[self.comment_index].span.start;
-            if pos == CommentPosition::Trailing && start >= after_offset {
+            let same_line = start >= after_offset
+                && !self.source[after_offset.min(self.source.len())..start.min(self.source.len())]
+                    .contains('\n');
+            if pos == CommentPosition::Trailing && same_line {
                 let text = self.comments[self.comment_index].text.clone();
                 self.write(" ");
                 self.write(&text);
@@ -106,7 +139,9 @@ impl FormatVisitor {
     // === Program ===
 
     pub fn visit_program(&mut self, program: &Program) {
-        for (i, item) in program.items.iter().enumerate() {
+        let mut i = 0;
+        while i < program.items.len() {
+            let item = &program.items[i];
             if i > 0 {
                 // Add blank line between top-level items for readability
                 if self.should_add_blank_line_before(
@@ -120,11 +155,49 @@ impl FormatVisitor {
                     self.writeln();
                 }
             }
-            self.visit_item(item);
+            if self.config.reorder_imports && matches!(item, Item::Import(_)) {
+                let run = program.items[i..]
+                    .iter()
+                    .take_while(|item| matches!(item, Item::Import(_)))
+                    .count();
+                self.visit_import_block(&program.items[i..i + run]);
+                i += run;
+            } else {
+                self.visit_item(item);
+                i += 1;
+            }
         }
         self.emit_remaining_comments();
     }
 
+    /// Emit a run of consecutive imports sorted by path, grouped into std,
+    /// third-party, and local imports separated by blank lines
+    fn visit_import_block(&mut self, items: &[Item]) {
+        // Render each import in source order so its leading and trailing
+        // comments are consumed with it, then reorder the rendered chunks
+        let mut rendered = Vec::new();
+        for item in items {
+            let Item::Import(import) = item else {
+                continue;
+            };
+            let outer = std::mem::take(&mut self.output);
+            self.visit_item(item);
+            let text = std::mem::replace(&mut self.output, outer);
+            rendered.push((ImportGroup::of(&import.source), import.source.clone(), text));
+        }
+
+        rendered.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+
+        let mut prev_group = None;
+        for (group, _, text) in rendered {
+            if prev_group.is_some_and(|prev| prev != group) {
+                self.writeln();
+            }
+            self.write(&text);
+            prev_group = Some(group);
+        }
+    }
+
     fn should_add_blank_line_before(&self, item: &Item, prev: Option<&Item>) -> bool {
         match item {
             Item::Function(_) => true,
@@ -134,7 +207,7 @@ impl FormatVisitor {
         }
     }
 
-    fn visit_item(&mut self, item: &Item) {
+    pub(crate) fn visit_item(&mut self, item: &Item) {
         match item {
             Item::Function(f) => {
                 self.emit_leading_comments(f.span.start);
@@ -168,7 +241,7 @@ impl FormatVisitor {
 
     // === Statements ===
 
-    fn visit_statement(&mut self, stmt: &Stmt) {
+    pub(crate) fn visit_statement(&mut self, stmt: &Stmt) {
         match stmt {
             Stmt::VarDecl(v) => self.visit_var_decl(v),
             Stmt::FunctionDecl(f) => self.visit_function_decl(f),
@@ -180,19 +253,10 @@ impl FormatVisitor {
             Stmt::While(w) => self.visit_while(w),
             Stmt::For(f) => self.visit_for(f),
             Stmt::ForIn(f) => self.visit_for_in(f),
+            Stmt::TryCatch(t) => self.visit_try_catch(t),
             Stmt::Return(r) => self.visit_return(r),
-            Stmt::Break(span) => {
-                self.write_indent();
-                self.write("break;");
-                self.emit_trailing_comment(span.end);
-                self.writeln();
-            }
-            Stmt::Continue(span) => {
-                self.write_indent();
-                self.write("continue;");
-                self.emit_trailing_comment(span.end);
-                self.writeln();
-            }
+            Stmt::Break(b) => self.visit_loop_jump("break", b.label.as_ref(), b.span.end),
+            Stmt::Continue(c) => self.visit_loop_jump("continue", c.label.as_ref(), c.span.end),
             Stmt::Expr(e) => self.visit_expr_stmt(e),
         }
     }
@@ -324,8 +388,30 @@ impl FormatVisitor {
         self.writeln();
     }
 
+    /// Write `break;` / `continue;`, with the target label if present
+    fn visit_loop_jump(&mut self, keyword: &str, label: Option<&Identifier>, end: usize) {
+        self.write_indent();
+        self.write(keyword);
+        if let Some(label) = label {
+            self.write(" ");
+            self.write(&label.name);
+        }
+        self.write(";");
+        self.emit_trailing_comment(end);
+        self.writeln();
+    }
+
+    /// Write a `label: ` prefix for a labelled loop
+    fn write_loop_label(&mut self, label: Option<&Identifier>) {
+        if let Some(label) = label {
+            self.write(&label.name);
+            self.write(": ");
+        }
+    }
+
     fn visit_while(&mut self, w: &WhileStmt) {
         self.write_indent();
+        self.write_loop_label(w.label.as_ref());
         self.write("while (");
         self.visit_expr(&w.cond);
         self.write(") ");
@@ -335,6 +421,7 @@ impl FormatVisitor {
 
     fn visit_for(&mut self, f: &ForStmt) {
         self.write_indent();
+        self.write_loop_label(f.label.as_ref());
         self.write("for (");
         self.visit_inline_statement(&f.init);
         self.write("; ");
@@ -348,6 +435,7 @@ impl FormatVisitor {
 
     fn visit_for_in(&mut self, f: &ForInStmt) {
         self.write_indent();
+        self.write_loop_label(f.label.as_ref());
         self.write("for ");
         self.write(&f.variable.name);
         self.write(" in ");
@@ -357,6 +445,17 @@ impl FormatVisitor {
         self.writeln();
     }
 
+    fn visit_try_catch(&mut self, t: &TryCatchStmt) {
+        self.write_indent();
+        self.write("try ");
+        self.visit_block(&t.body);
+        self.write(" catch (");
+        self.write(&t.error.name);
+        self.write(") ");
+        self.visit_block(&t.handler);
+        self.writeln();
+    }
+
     /// Visit a statement inline (no indent, no trailing newline) - for `for` loop init/step
     fn visit_inline_statement(&mut self, stmt: &Stmt) {
         match stmt {
@@ -529,7 +628,7 @@ impl FormatVisitor {
 
     fn visit_expr(&mut self, expr: &Expr) {
         match expr {
-            Expr::Literal(lit, _) => self.visit_literal(lit),
+            Expr::Literal(lit, span) => self.visit_literal(lit, *span),
             Expr::Identifier(id) => self.write(&id.name),
             Expr::Unary(u) => self.visit_unary(u),
             Expr::Binary(b) => self.visit_binary(b),
@@ -537,6 +636,8 @@ impl FormatVisitor {
             Expr::Index(i) => self.visit_index(i),
             Expr::Member(m) => self.visit_member(m),
             Expr::ArrayLiteral(a) => self.visit_array_literal(a),
+            Expr::MapLiteral(m) => self.visit_map_literal(m),
+            Expr::StringInterp(s) => self.visit_string_interp(s),
             Expr::Group(g) => {
                 self.write("(");
                 self.visit_expr(&g.expr);
@@ -550,7 +651,18 @@ impl FormatVisitor {
         }
     }
 
-    fn visit_literal(&mut self, lit: &Literal) {
+    fn visit_literal(&mut self, lit: &Literal, span: Span) {
+        // Keep `_` separators and `0x`/`0b` prefixes as written
+        if let Literal::Number(_) | Literal::Int(_) = lit {
+            if let Some(text) = self.source.get(span.start..span.end) {
+                let lower = text.to_ascii_lowercase();
+                if text.contains('_') || lower.starts_with("0x") || lower.starts_with("0b") {
+                    let text = text.to_string();
+                    self.write(&text);
+                    return;
+                }
+            }
+        }
         match lit {
             Literal::Number(n) => {
                 // Format numbers cleanly
@@ -560,6 +672,7 @@ impl FormatVisitor {
                     self.write(&format!("{}", n));
                 }
             }
+            Literal::Int(n) => self.write(&format!("{}i", n)),
             Literal::String(s) => {
                 self.write("\"");
                 self.write(&escape_string(s));
@@ -601,14 +714,40 @@ impl FormatVisitor {
 
     fn visit_call(&mut self, c: &CallExpr) {
         self.visit_expr(&c.callee);
-        self.write("(");
-        let args_str = self.format_args(&c.args);
-        if self.would_exceed_max_width(&args_str) && c.args.len() > 1 {
-            self.write_args_multiline(&c.args);
-        } else {
-            self.write(&args_str);
+        self.write_list("(", ")", &c.args);
+    }
+
+    /// Write a delimited list of expressions on one line if it fits within
+    /// `max_width`, otherwise one element per line. Trailing commas are only
+    /// ever written in the multiline form.
+    fn write_list(&mut self, open: &str, close: &str, items: &[Expr]) {
+        self.write(open);
+        let flat = self.format_args_flat(items);
+        let fits =
+            !flat.contains('\n') && !self.would_exceed_max_width(&format!("{}{}", flat, close));
+        match items {
+            _ if fits => self.write(&flat),
+            // A lone call or literal breaks itself instead of being moved to its own line
+            [only] if is_breakable(only) => self.visit_expr(only),
+            _ => self.write_args_multiline(items),
         }
-        self.write(")");
+        self.write(close);
+    }
+
+    /// Format expressions on a single line, ignoring `max_width`
+    fn format_args_flat(&self, args: &[Expr]) -> String {
+        let config = FormatConfig {
+            max_width: usize::MAX,
+            ..self.config.clone()
+        };
+        let mut visitor = FormatVisitor::new(config, Vec::new(), self.source.clone());
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                visitor.write(", ");
+            }
+            visitor.visit_expr(arg);
+        }
+        visitor.output
     }
 
     fn format_args(&self, args: &[Expr]) -> String {
@@ -651,43 +790,66 @@ impl FormatVisitor {
         self.write(".");
         self.write(&m.member.name);
         if let Some(ref args) = m.args {
-            self.write("(");
-            let args_str = self.format_args(args);
-            if self.would_exceed_max_width(&args_str) && args.len() > 1 {
-                self.write_args_multiline(args);
-            } else {
-                self.write(&args_str);
-            }
-            self.write(")");
+            self.write_list("(", ")", args);
         }
     }
 
     fn visit_array_literal(&mut self, a: &ArrayLiteral) {
-        if a.elements.is_empty() {
-            self.write("[]");
+        self.write_list("[", "]", &a.elements);
+    }
+
+    fn visit_string_interp(&mut self, s: &StringInterpExpr) {
+        self.write("\"");
+        for part in &s.parts {
+            match part {
+                StringInterpPart::Literal(text) => self.write(&escape_string(text)),
+                StringInterpPart::Expr(expr) => {
+                    self.write("${");
+                    self.visit_expr(expr);
+                    self.write("}");
+                }
+            }
+        }
+        self.write("\"");
+    }
+
+    fn visit_map_literal(&mut self, m: &MapLiteral) {
+        if m.entries.is_empty() {
+            self.write("{}");
             return;
         }
 
-        let elements_str = self.format_args(&a.elements);
-        if self.would_exceed_max_width(&format!("[{}]", elements_str)) && a.elements.len() > 1 {
-            self.write("[");
+        let entries: Vec<String> = m
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{}: {}",
+                    format_map_key(&entry.key),
+                    self.format_args(std::slice::from_ref(&entry.value))
+                )
+            })
+            .collect();
+        let entries_str = entries.join(", ");
+        if self.would_exceed_max_width(&format!("{{ {} }}", entries_str)) && entries.len() > 1 {
+            self.write("{");
             self.writeln();
             self.indent_level += 1;
-            for (i, elem) in a.elements.iter().enumerate() {
+            for (i, entry) in entries.iter().enumerate() {
                 self.write_indent();
-                self.visit_expr(elem);
-                if i < a.elements.len() - 1 || self.config.trailing_commas {
+                self.write(entry);
+                if i < entries.len() - 1 || self.config.trailing_commas {
                     self.write(",");
                 }
                 self.writeln();
             }
             self.indent_level -= 1;
             self.write_indent();
-            self.write("]");
+            self.write("}");
         } else {
-            self.write("[");
-            self.write(&elements_str);
-            self.write("]");
+            self.write("{ ");
+            self.write(&entries_str);
+            self.write(" }");
         }
     }
 
@@ -716,7 +878,7 @@ impl FormatVisitor {
 
     fn visit_pattern(&mut self, pattern: &Pattern) {
         match pattern {
-            Pattern::Literal(lit, _) => self.visit_literal(lit),
+            Pattern::Literal(lit, span) => self.visit_literal(lit, *span),
             Pattern::Wildcard(_) => self.write("_"),
             Pattern::Variable(id) => self.write(&id.name),
             Pattern::Constructor { name, args, .. } => {
@@ -901,6 +1063,16 @@ impl FormatVisitor {
             ExportItem::TypeAlias(alias) => {
                 self.visit_type_alias(alias);
             }
+            ExportItem::ReExport(re_export) => {
+                let names: Vec<&str> = re_export.names.iter().map(|n| n.name.as_str()).collect();
+                self.write("{ ");
+                self.write(&names.join(", "));
+                self.write(" } from \"");
+                self.write(&re_export.source);
+                self.write("\";");
+                self.emit_trailing_comment(export.span.end);
+                self.writeln();
+            }
         }
     }
 
@@ -937,10 +1109,39 @@ fn extern_type_str(ty: &ExternTypeAnnotation) -> &'static str {
     }
 }
 
+/// Whether an expression can break across lines on its own.
+fn is_breakable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Call(c) => !c.args.is_empty(),
+        Expr::ArrayLiteral(a) => !a.elements.is_empty(),
+        Expr::MapLiteral(m) => !m.entries.is_empty(),
+        Expr::Member(m) => m.args.as_ref().is_some_and(|args| !args.is_empty()),
+        _ => false,
+    }
+}
+
+/// Write a map key bare when it is a valid identifier, quoted otherwise.
+fn format_map_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && atlas_runtime::TokenKind::is_keyword(key).is_none();
+    if is_identifier {
+        key.to_string()
+    } else {
+        format!("\"{}\"", escape_string(key))
+    }
+}
+
 fn escape_string(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
-    for c in s.chars() {
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
         match c {
+            // A literal `${` must stay escaped so it isn't read as interpolation
+            '$' if chars.peek() == Some(&'{') => result.push_str("\\$"),
             '\\' => result.push_str("\\\\"),
             '"' => result.push_str("\\\""),
             '\n' => result.push_str("\\n"),
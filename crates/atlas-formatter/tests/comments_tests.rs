@@ -2,7 +2,7 @@
 
 use atlas_formatter::comments::CommentCollector;
 use atlas_formatter::CommentPosition;
-use atlas_formatter::{format_source, FormatResult};
+use atlas_formatter::{format_source, format_source_with_config, FormatConfig, FormatResult};
 use atlas_runtime::lexer::Lexer;
 use atlas_runtime::token::TokenKind;
 use pretty_assertions::assert_eq;
@@ -181,6 +181,28 @@ fn test_comment_at_file_end() {
     );
 }
 
+#[test]
+fn test_comments_follow_reordered_imports() {
+    let source = r#"import { z } from "./z";
+// keep
+import { a } from "./a";
+import { m } from "./m"; // pinned
+"#;
+    let config = FormatConfig::default().with_reorder_imports(true);
+    let result = match format_source_with_config(source, &config) {
+        FormatResult::Ok(s) => s,
+        FormatResult::ParseError(e) => panic!("Parse error: {:?}", e),
+    };
+    assert_eq!(
+        result,
+        r#"// keep
+import { a } from "./a";
+import { m } from "./m"; // pinned
+import { z } from "./z";
+"#
+    );
+}
+
 #[test]
 fn test_mixed_comment_types() {
     let result = fmt("// line\n/* block */\n/// doc\nlet x = 5;");
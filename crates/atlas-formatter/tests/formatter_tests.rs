@@ -1,7 +1,8 @@
 //! Formatter tests - 70+ tests for code formatting
 
 use atlas_formatter::{
-    check_formatted, format_source, format_source_with_config, FormatConfig, FormatResult,
+    check_formatted, check_formatted_with_config, format_source, format_source_with_config,
+    FormatConfig, FormatResult,
 };
 use pretty_assertions::assert_eq;
 use rstest::rstest;
@@ -218,8 +219,32 @@ fn test_for_in_loop() {
     );
 }
 
+#[test]
+fn test_labelled_loops() {
+    assert_eq!(
+        fmt("outer: for row in rows { while (true) { continue outer; } break outer; }"),
+        "outer: for row in rows {\n    while (true) {\n        continue outer;\n    }\n    break outer;\n}\n"
+    );
+}
+
+#[test]
+fn test_try_catch() {
+    assert_eq!(
+        fmt("try { risky(); } catch (e) { print(e[\"message\"]); }"),
+        "try {\n    risky();\n} catch (e) {\n    print(e[\"message\"]);\n}\n"
+    );
+}
+
 // === Expression Formatting ===
 
+#[test]
+fn test_number_literal_forms_preserved() {
+    assert_eq!(
+        fmt("let x = [1_000_000, 0xFF, 0b1010, 0x10i];"),
+        "let x = [1_000_000, 0xFF, 0b1010, 0x10i];\n"
+    );
+}
+
 #[test]
 fn test_binary_operators() {
     assert_eq!(fmt("let r = a + b * c;"), "let r = a + b * c;\n");
@@ -308,6 +333,39 @@ fn test_nested_array() {
     );
 }
 
+// === String Interpolation Formatting ===
+
+#[test]
+fn test_string_interpolation() {
+    assert_eq!(
+        fmt("let s = \"Hi ${ name }, ${a+1}\";"),
+        "let s = \"Hi ${name}, ${a + 1}\";\n"
+    );
+}
+
+#[test]
+fn test_escaped_interpolation_stays_escaped() {
+    assert_eq!(
+        fmt("let s = \"cost: \\${price}\";"),
+        "let s = \"cost: \\${price}\";\n"
+    );
+}
+
+// === Map Literal Formatting ===
+
+#[test]
+fn test_empty_map() {
+    assert_eq!(fmt("let m = {};"), "let m = {};\n");
+}
+
+#[test]
+fn test_map_literal() {
+    assert_eq!(
+        fmt("let m = {a:1,\"full name\":2};"),
+        "let m = { a: 1, \"full name\": 2 };\n"
+    );
+}
+
 // === Match Expression ===
 
 #[test]
@@ -355,6 +413,63 @@ fn test_no_trailing_commas() {
     assert!(result.contains("\"a\",\n"));
 }
 
+#[test]
+fn test_multiline_array_gets_trailing_comma() {
+    let config = FormatConfig::default().with_max_width(30);
+    let expected = "let xs = [\n    \"alpha\",\n    \"beta\",\n    \"gamma\",\n];\n";
+    assert_eq!(
+        fmt_with("let xs = [\"alpha\", \"beta\", \"gamma\"];", &config),
+        expected
+    );
+    assert!(check_formatted_with_config(expected, &config));
+}
+
+#[test]
+fn test_collapsed_array_strips_trailing_comma() {
+    let source = "let xs = [\n    1,\n    2,\n    3,\n];\n";
+    assert_eq!(fmt(source), "let xs = [1, 2, 3];\n");
+    assert!(!check_formatted(source));
+    assert!(check_formatted("let xs = [1, 2, 3];\n"));
+}
+
+#[test]
+fn test_collapsed_call_strips_trailing_comma() {
+    assert_eq!(fmt("foo(\n    a,\n    b,\n);"), "foo(a, b);\n");
+}
+
+#[test]
+fn test_single_element_array_breaks_with_trailing_comma() {
+    let config = FormatConfig::default().with_max_width(20);
+    let expected = "let xs = [\n    \"a long string\",\n];\n";
+    assert_eq!(fmt_with("let xs = [\"a long string\"];", &config), expected);
+    assert!(check_formatted_with_config(expected, &config));
+    assert_eq!(fmt("let xs = [\n    1,\n];"), "let xs = [1];\n");
+}
+
+#[test]
+fn test_nested_call_breaks_innermost_list() {
+    let config = FormatConfig::default().with_max_width(30);
+    let expected = "outer(inner(\n    first_arg,\n    second_arg,\n));\n";
+    assert_eq!(
+        fmt_with("outer(inner(first_arg, second_arg));", &config),
+        expected
+    );
+    assert!(check_formatted_with_config(expected, &config));
+}
+
+#[test]
+fn test_multiline_call_without_trailing_commas() {
+    let config = FormatConfig::default()
+        .with_max_width(30)
+        .with_trailing_commas(false);
+    let expected = "process(\n    first_argument,\n    second_argument\n);\n";
+    assert_eq!(
+        fmt_with("process(first_argument, second_argument,);", &config),
+        expected
+    );
+    assert!(check_formatted_with_config(expected, &config));
+}
+
 // === Line Breaking ===
 
 #[test]
@@ -392,6 +507,34 @@ fn test_import_namespace() {
     );
 }
 
+#[test]
+fn test_reorder_imports_sorts_and_groups() {
+    let source = r#"import { parse } from "./parser";
+import * as json from "json";
+import { read } from "std/fs";
+import { Lexer } from "./lexer";
+import { http } from "http";
+let x = 1;"#;
+    let expected = r#"import { read } from "std/fs";
+
+import { http } from "http";
+import * as json from "json";
+
+import { Lexer } from "./lexer";
+import { parse } from "./parser";
+let x = 1;
+"#;
+    let config = FormatConfig::default().with_reorder_imports(true);
+    assert_eq!(fmt_with(source, &config), expected);
+    assert_eq!(fmt_with(expected, &config), expected);
+}
+
+#[test]
+fn test_imports_not_reordered_by_default() {
+    let source = "import { b } from \"./b\";\nimport { a } from \"./a\";\n";
+    assert_eq!(fmt(source), source);
+}
+
 #[test]
 fn test_export_function() {
     assert_eq!(
@@ -400,6 +543,14 @@ fn test_export_function() {
     );
 }
 
+#[test]
+fn test_re_export() {
+    assert_eq!(
+        fmt("export {add,sub} from \"./math\";"),
+        "export { add, sub } from \"./math\";\n"
+    );
+}
+
 #[test]
 fn test_export_variable() {
     assert_eq!(fmt("export let VERSION = 1;"), "export let VERSION = 1;\n");
@@ -439,6 +590,73 @@ fn test_parse_error_returns_error() {
     ));
 }
 
+// === Range Formatting ===
+
+#[test]
+fn test_range_formats_only_selected_lines() {
+    let source = "let   a=1;\n\
+                  let b   =2;\n\
+                  let c=3;\n\
+                  fn  f(x:number)->number{return x+1;}\n\
+                  print( c );\n\
+                  let   d=4;\n\
+                  \n\
+                  let e  = [1,2];\n\
+                  print(e)  ;\n\
+                  let f=  6;\n";
+    let config = FormatConfig::default().with_range(3, 5);
+    let result = fmt_with(source, &config);
+
+    let before: Vec<&str> = source.lines().collect();
+    let after: Vec<&str> = result.lines().collect();
+    assert_eq!(after[..2], before[..2]);
+    assert_eq!(
+        after[2..7],
+        [
+            "let c = 3;",
+            "fn f(x: number) -> number {",
+            "    return x + 1;",
+            "}",
+            "print(c);",
+        ]
+    );
+    assert_eq!(after[7..], before[5..]);
+}
+
+#[test]
+fn test_range_skips_partially_selected_construct() {
+    let source = "fn f() {\n    let x=1;\n    let y=2;\n}\n";
+    let config = FormatConfig::default().with_range(1, 2);
+    assert_eq!(
+        fmt_with(source, &config),
+        "fn f() {\n    let x = 1;\n    let y=2;\n}\n"
+    );
+}
+
+#[test]
+fn test_range_formats_nested_statement_at_block_indent() {
+    let source = "fn f() {\n  if (x) {\n  let y=2;\n  }\n}\n";
+    let config = FormatConfig::default().with_range(3, 3);
+    assert_eq!(
+        fmt_with(source, &config),
+        "fn f() {\n  if (x) {\n        let y = 2;\n  }\n}\n"
+    );
+}
+
+#[test]
+fn test_range_leaves_shared_line_untouched() {
+    let source = "let a=1; let b=2;\nlet c=3;\n";
+    let config = FormatConfig::default().with_range(1, 2);
+    assert_eq!(fmt_with(source, &config), "let a=1; let b=2;\nlet c = 3;\n");
+}
+
+#[test]
+fn test_range_keeps_trailing_comment() {
+    let source = "let a=1; // one\nlet b=2;";
+    let config = FormatConfig::default().with_range(1, 1);
+    assert_eq!(fmt_with(source, &config), "let a = 1; // one\nlet b=2;");
+}
+
 // === Check Mode ===
 
 #[test]
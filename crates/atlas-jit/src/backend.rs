@@ -4,12 +4,14 @@
 //! native machine code. Handles target detection, compilation, and
 //! function pointer retrieval.
 
-use cranelift_codegen::ir::Function;
+use cranelift_codegen::ir::{types, AbiParam, Function, Signature};
+use cranelift_codegen::isa::CallConv;
 use cranelift_codegen::settings::{self, Configurable};
 use cranelift_jit::{JITBuilder, JITModule};
 use cranelift_module::{FuncId, Linkage, Module};
 
-use crate::{JitError, JitResult};
+use crate::codegen::ValueKind;
+use crate::{JitError, JitResult, JitReturn};
 
 /// Native code backend using Cranelift JIT
 ///
@@ -61,9 +63,12 @@ impl NativeBackend {
 
     /// Compile an IR function to native code and return the function pointer
     ///
-    /// The returned pointer is a `fn() -> f64` for parameterless functions.
+    /// The return kind is read from the function's signature: `f64` for
+    /// numbers, `i8` for booleans, and no return value for null.
     pub fn compile(&mut self, func: Function) -> JitResult<CompiledFunction> {
         let name = format!("jit_fn_{}", self.compiled_count);
+        let return_kind = ValueKind::from_signature(&func.signature)?;
+        let takes_globals = takes_globals_pointer(&func.signature);
 
         let func_id = self
             .module
@@ -77,21 +82,82 @@ impl NativeBackend {
             .define_function(func_id, &mut ctx)
             .map_err(|e| JitError::CompilationFailed(format!("define: {}", e)))?;
 
+        // Keep a copy of the machine code so it can be persisted. Code with
+        // relocations depends on its load address and is never persisted.
+        let native_code = match ctx.compiled_code() {
+            Some(code) if code.buffer.relocs().is_empty() => code.code_buffer().to_vec(),
+            _ => Vec::new(),
+        };
+
         self.module.clear_context(&mut ctx);
         self.module
             .finalize_definitions()
             .map_err(|e| JitError::CompilationFailed(format!("finalize: {}", e)))?;
 
         let code_ptr = self.module.get_finalized_function(func_id);
-        let code_size = 0; // Cranelift doesn't expose size directly; tracked separately
+        let code_size = native_code.len();
 
         self.compiled_count += 1;
+        self.native_bytes += code_size;
 
         Ok(CompiledFunction {
             func_id,
             code_ptr,
             code_size,
             name,
+            return_kind,
+            takes_globals,
+            native_code,
+        })
+    }
+
+    /// Load previously generated machine code into executable memory
+    ///
+    /// `native_code` must be relocation-free code produced by [`compile`]
+    /// on the same target architecture.
+    ///
+    /// [`compile`]: NativeBackend::compile
+    pub fn load_native(
+        &mut self,
+        native_code: &[u8],
+        return_kind: ValueKind,
+        takes_globals: bool,
+    ) -> JitResult<CompiledFunction> {
+        let name = format!("jit_fn_{}", self.compiled_count);
+
+        let mut sig = Signature::new(CallConv::SystemV);
+        if takes_globals {
+            sig.params.push(AbiParam::new(types::I64));
+        }
+        if let Some(ty) = return_kind.ir_type() {
+            sig.returns.push(AbiParam::new(ty));
+        }
+
+        let func_id = self
+            .module
+            .declare_function(&name, Linkage::Local, &sig)
+            .map_err(|e| JitError::CompilationFailed(format!("declare: {}", e)))?;
+
+        self.module
+            .define_function_bytes(func_id, 16, native_code, &[])
+            .map_err(|e| JitError::CompilationFailed(format!("define: {}", e)))?;
+        self.module
+            .finalize_definitions()
+            .map_err(|e| JitError::CompilationFailed(format!("finalize: {}", e)))?;
+
+        let code_ptr = self.module.get_finalized_function(func_id);
+
+        self.compiled_count += 1;
+        self.native_bytes += native_code.len();
+
+        Ok(CompiledFunction {
+            func_id,
+            code_ptr,
+            code_size: native_code.len(),
+            name,
+            return_kind,
+            takes_globals,
+            native_code: native_code.to_vec(),
         })
     }
 
@@ -138,9 +204,33 @@ pub struct CompiledFunction {
     pub code_size: usize,
     /// Function name (for debugging)
     pub name: String,
+    /// Atlas value kind returned by the native code
+    pub return_kind: ValueKind,
+    /// Whether the code takes the hidden globals slot-table pointer
+    pub takes_globals: bool,
+    /// Copy of the machine code (empty if the code is not relocatable)
+    pub native_code: Vec<u8>,
 }
 
 impl CompiledFunction {
+    /// Execute as a function that takes no args, decoding the result by return kind
+    ///
+    /// # Safety
+    /// The code pointer must be valid and take no arguments.
+    pub unsafe fn call(&self) -> JitReturn {
+        call_native(self.code_ptr, self.return_kind, None)
+    }
+
+    /// Execute a function compiled with `translate_with_globals`, passing the
+    /// globals slot table
+    ///
+    /// # Safety
+    /// The code pointer must be valid, and `slots` must hold at least as many
+    /// entries as the function's global slot layout.
+    pub unsafe fn call_with_globals(&self, slots: &mut [u64]) -> JitReturn {
+        call_native(self.code_ptr, self.return_kind, Some(slots.as_mut_ptr()))
+    }
+
     /// Execute as a function that takes no args and returns f64
     ///
     /// # Safety
@@ -169,6 +259,54 @@ impl CompiledFunction {
     }
 }
 
+/// Whether a signature starts with the hidden globals slot-table pointer
+fn takes_globals_pointer(sig: &Signature) -> bool {
+    sig.params.first().map(|p| p.value_type) == Some(types::I64)
+}
+
+/// Call a native function and decode its result
+///
+/// `globals` is the slot-table pointer for code that takes one, or `None`
+/// for parameterless code.
+///
+/// # Safety
+/// `code_ptr` must point to finalized code whose parameters match `globals`
+/// and whose native return type matches `kind`.
+pub unsafe fn call_native(
+    code_ptr: *const u8,
+    kind: ValueKind,
+    globals: Option<*mut u64>,
+) -> JitReturn {
+    match (kind, globals) {
+        (ValueKind::Number, None) => {
+            let func: unsafe extern "C" fn() -> f64 = std::mem::transmute(code_ptr);
+            JitReturn::Number(func())
+        }
+        (ValueKind::Number, Some(slots)) => {
+            let func: unsafe extern "C" fn(*mut u64) -> f64 = std::mem::transmute(code_ptr);
+            JitReturn::Number(func(slots))
+        }
+        (ValueKind::Bool, None) => {
+            let func: unsafe extern "C" fn() -> u8 = std::mem::transmute(code_ptr);
+            JitReturn::Bool(func() != 0)
+        }
+        (ValueKind::Bool, Some(slots)) => {
+            let func: unsafe extern "C" fn(*mut u64) -> u8 = std::mem::transmute(code_ptr);
+            JitReturn::Bool(func(slots) != 0)
+        }
+        (ValueKind::Null, None) => {
+            let func: unsafe extern "C" fn() = std::mem::transmute(code_ptr);
+            func();
+            JitReturn::Null
+        }
+        (ValueKind::Null, Some(slots)) => {
+            let func: unsafe extern "C" fn(*mut u64) = std::mem::transmute(code_ptr);
+            func(slots);
+            JitReturn::Null
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,8 +3,38 @@
 //! Caches compiled native functions keyed by their bytecode offset.
 //! Supports size limits, eviction of cold code, and versioning for
 //! invalidation when bytecode changes.
+//!
+//! Entries carrying their machine code can be persisted to disk with
+//! [`CodeCache::save_to_disk`] and restored with [`CodeCache::load_from_disk`].
+//! Each persisted entry records a fingerprint of the bytecode it was compiled
+//! from, so native code is only reused when that bytecode is unchanged.
 
 use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use atlas_runtime::bytecode::Bytecode;
+use atlas_runtime::value::Value;
+
+use crate::codegen::{GlobalSlot, ValueKind};
+
+/// Magic bytes at the start of a persisted cache file
+const CACHE_MAGIC: &[u8; 8] = b"ATLASJIT";
+
+/// Persisted cache format version (bump on any layout change)
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+/// How the cache makes room when a new function does not fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+    /// Evict the least recently used entries first
+    #[default]
+    Lru,
+    /// Evict the least frequently used entries first
+    Lfu,
+    /// Never evict; reject new code once the cache is full
+    RejectNew,
+}
 
 /// Cached entry for a compiled function
 #[derive(Debug)]
@@ -17,8 +47,42 @@ pub struct CacheEntry {
     pub version: u64,
     /// Number of times this entry was used
     pub hit_count: u64,
+    /// Logical time of the last insert or hit (higher is more recent)
+    pub last_access: u64,
+    /// Parameter count of the function
+    pub param_count: usize,
+    /// Atlas value kind returned by the native code
+    pub return_kind: ValueKind,
+    /// Globals slot layout, if the code takes the hidden slot-table pointer
+    pub global_slots: Option<Vec<GlobalSlot>>,
+    /// Data needed to persist this entry (absent for non-persistable code)
+    pub persist: Option<PersistInfo>,
+}
+
+/// Data recorded alongside a cache entry so it can be written to disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistInfo {
+    /// End offset (exclusive) of the function's bytecode
+    pub function_end: usize,
+    /// Fingerprint of the bytecode the code was compiled from
+    pub fingerprint: u64,
+    /// Whether the code takes the hidden globals slot-table pointer
+    pub takes_globals: bool,
+    /// Relocation-free machine code
+    pub native_code: Vec<u8>,
+}
+
+/// A function restored from a persisted cache file
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistedFunction {
+    /// Bytecode offset of the function
+    pub offset: usize,
     /// Parameter count of the function
     pub param_count: usize,
+    /// Atlas value kind returned by the native code
+    pub return_kind: ValueKind,
+    /// Persisted fingerprint and machine code
+    pub info: PersistInfo,
 }
 
 // Safety: code pointers are read-only after compilation
@@ -40,11 +104,20 @@ pub struct CodeCache {
     total_hits: u64,
     /// Total cache misses
     total_misses: u64,
+    /// How to make room when full
+    policy: EvictionPolicy,
+    /// Logical clock for last-access tracking
+    clock: u64,
 }
 
 impl CodeCache {
-    /// Create a new code cache with the given size limit
+    /// Create a new code cache with the given size limit and the default (LRU) policy
     pub fn new(max_bytes: usize) -> Self {
+        Self::with_policy(max_bytes, EvictionPolicy::default())
+    }
+
+    /// Create a new code cache with the given size limit and eviction policy
+    pub fn with_policy(max_bytes: usize, policy: EvictionPolicy) -> Self {
         Self {
             entries: HashMap::new(),
             version: 0,
@@ -52,6 +125,8 @@ impl CodeCache {
             max_bytes,
             total_hits: 0,
             total_misses: 0,
+            policy,
+            clock: 0,
         }
     }
 
@@ -59,7 +134,9 @@ impl CodeCache {
     pub fn get(&mut self, offset: usize) -> Option<&CacheEntry> {
         if let Some(entry) = self.entries.get_mut(&offset) {
             if entry.version == self.version {
+                self.clock += 1;
                 entry.hit_count += 1;
+                entry.last_access = self.clock;
                 self.total_hits += 1;
                 // Re-borrow immutably
                 return self.entries.get(&offset);
@@ -78,18 +155,28 @@ impl CodeCache {
     }
 
     /// Insert a compiled function into the cache
+    ///
+    /// If the code does not fit, entries are evicted according to the
+    /// eviction policy. Returns the offsets of the evicted entries.
     pub fn insert(
         &mut self,
         offset: usize,
         code_ptr: *const u8,
         code_size: usize,
         param_count: usize,
-    ) -> Result<(), CacheFullError> {
+        return_kind: ValueKind,
+    ) -> Result<Vec<usize>, CacheFullError> {
+        // Replacing an entry frees its bytes first
+        self.invalidate(offset);
+
         // Check if we need to evict
-        if self.total_bytes + code_size > self.max_bytes {
-            self.evict_cold(code_size)?;
-        }
+        let evicted = if self.total_bytes + code_size > self.max_bytes {
+            self.evict_cold(code_size)?
+        } else {
+            Vec::new()
+        };
 
+        self.clock += 1;
         self.entries.insert(
             offset,
             CacheEntry {
@@ -97,11 +184,133 @@ impl CodeCache {
                 code_size,
                 version: self.version,
                 hit_count: 0,
+                last_access: self.clock,
                 param_count,
+                return_kind,
+                global_slots: None,
+                persist: None,
             },
         );
         self.total_bytes += code_size;
-        Ok(())
+        Ok(evicted)
+    }
+
+    /// Attach persistence data to a cached entry
+    ///
+    /// Returns `false` if no entry exists at `offset`.
+    pub fn attach_persist_info(&mut self, offset: usize, info: PersistInfo) -> bool {
+        match self.entries.get_mut(&offset) {
+            Some(entry) => {
+                entry.persist = Some(info);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record the globals slot layout of a cached entry
+    ///
+    /// Returns `false` if no entry exists at `offset`.
+    pub fn attach_global_slots(&mut self, offset: usize, slots: Vec<GlobalSlot>) -> bool {
+        match self.entries.get_mut(&offset) {
+            Some(entry) => {
+                entry.global_slots = Some(slots);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Write all current, persistable entries to `path`
+    ///
+    /// Returns the number of entries written.
+    pub fn save_to_disk(&self, path: &Path) -> io::Result<usize> {
+        let mut persisted: Vec<(usize, &CacheEntry, &PersistInfo)> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.version == self.version)
+            .filter_map(|(&offset, entry)| entry.persist.as_ref().map(|p| (offset, entry, p)))
+            .collect();
+        persisted.sort_by_key(|&(offset, _, _)| offset);
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(CACHE_MAGIC);
+        buf.extend_from_slice(&CACHE_FORMAT_VERSION.to_le_bytes());
+        write_bytes(&mut buf, std::env::consts::ARCH.as_bytes());
+        buf.extend_from_slice(&(persisted.len() as u64).to_le_bytes());
+        for (offset, entry, info) in &persisted {
+            buf.extend_from_slice(&(*offset as u64).to_le_bytes());
+            buf.extend_from_slice(&(info.function_end as u64).to_le_bytes());
+            buf.extend_from_slice(&info.fingerprint.to_le_bytes());
+            buf.extend_from_slice(&(entry.param_count as u64).to_le_bytes());
+            buf.push(kind_tag(entry.return_kind));
+            buf.push(u8::from(info.takes_globals));
+            write_bytes(&mut buf, &info.native_code);
+        }
+
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+        std::fs::write(path, buf)?;
+        Ok(persisted.len())
+    }
+
+    /// Read a persisted cache file, keeping only entries whose fingerprint
+    /// matches `bytecode`
+    ///
+    /// Files written by a different format version or for a different
+    /// target architecture yield no entries. A malformed file is an error.
+    pub fn load_from_disk(path: &Path, bytecode: &Bytecode) -> io::Result<Vec<PersistedFunction>> {
+        let data = std::fs::read(path)?;
+        let mut reader = Reader {
+            data: &data,
+            pos: 0,
+        };
+
+        if reader.take(CACHE_MAGIC.len())? != CACHE_MAGIC {
+            return Err(invalid_data("not an Atlas JIT cache file"));
+        }
+        if reader.read_u32()? != CACHE_FORMAT_VERSION {
+            return Ok(Vec::new());
+        }
+        if reader.read_bytes()? != std::env::consts::ARCH.as_bytes() {
+            return Ok(Vec::new());
+        }
+
+        let count = reader.read_u64()?;
+        let mut functions = Vec::new();
+        for _ in 0..count {
+            let offset = reader.read_u64()? as usize;
+            let function_end = reader.read_u64()? as usize;
+            let fingerprint = reader.read_u64()?;
+            let param_count = reader.read_u64()? as usize;
+            let return_kind = kind_from_tag(reader.read_u8()?)?;
+            let takes_globals = reader.read_u8()? != 0;
+            let native_code = reader.read_bytes()?.to_vec();
+
+            // Stale native code must never run
+            if function_end > bytecode.instructions.len()
+                || offset > function_end
+                || bytecode_fingerprint(bytecode, offset, function_end) != fingerprint
+            {
+                continue;
+            }
+
+            functions.push(PersistedFunction {
+                offset,
+                param_count,
+                return_kind,
+                info: PersistInfo {
+                    function_end,
+                    fingerprint,
+                    takes_globals,
+                    native_code,
+                },
+            });
+        }
+        Ok(functions)
     }
 
     /// Invalidate all cached entries (bump version)
@@ -116,36 +325,60 @@ impl CodeCache {
         }
     }
 
-    /// Evict cold (least-used) entries to make room for `needed` bytes
-    fn evict_cold(&mut self, needed: usize) -> Result<(), CacheFullError> {
-        // Sort entries by hit count (ascending) and evict coldest first
-        let mut offsets: Vec<(usize, u64)> = self
+    /// Evict cold entries to make room for `needed` bytes
+    ///
+    /// Entries from an invalidated version go first, then the coldest live
+    /// entries by the eviction policy. Nothing is evicted if the space cannot
+    /// be freed (or under `RejectNew`).
+    fn evict_cold(&mut self, needed: usize) -> Result<Vec<usize>, CacheFullError> {
+        let full = CacheFullError {
+            limit: self.max_bytes,
+            used: self.total_bytes,
+            needed,
+        };
+        if needed > self.max_bytes {
+            return Err(full);
+        }
+
+        let version = self.version;
+        let policy = self.policy;
+        let mut candidates: Vec<(usize, bool, u64, usize)> = self
             .entries
             .iter()
-            .map(|(&offset, entry)| (offset, entry.hit_count))
+            .filter(|(_, entry)| policy != EvictionPolicy::RejectNew || entry.version != version)
+            .map(|(&offset, entry)| {
+                let coldness = match policy {
+                    EvictionPolicy::Lfu => entry.hit_count,
+                    EvictionPolicy::Lru | EvictionPolicy::RejectNew => entry.last_access,
+                };
+                (offset, entry.version == version, coldness, entry.code_size)
+            })
             .collect();
-        offsets.sort_by_key(|&(_, hits)| hits);
+        // Stale entries first, then coldest; offset breaks ties deterministically
+        candidates.sort_by_key(|&(offset, live, coldness, _)| (live, coldness, offset));
 
-        let mut freed = 0usize;
-        for (offset, _) in offsets {
-            if self.total_bytes.saturating_sub(freed) + needed <= self.max_bytes {
+        let mut victims = Vec::new();
+        let mut remaining = self.total_bytes;
+        for (offset, _, _, size) in candidates {
+            if remaining + needed <= self.max_bytes {
                 break;
             }
-            if let Some(entry) = self.entries.remove(&offset) {
-                freed += entry.code_size;
-            }
+            remaining = remaining.saturating_sub(size);
+            victims.push(offset);
+        }
+        if remaining + needed > self.max_bytes {
+            return Err(full);
         }
 
-        self.total_bytes = self.total_bytes.saturating_sub(freed);
-
-        if self.total_bytes + needed > self.max_bytes {
-            return Err(CacheFullError {
-                limit: self.max_bytes,
-                used: self.total_bytes,
-                needed,
-            });
+        for &offset in &victims {
+            self.invalidate(offset);
         }
-        Ok(())
+        Ok(victims)
+    }
+
+    /// Eviction policy in use
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
     }
 
     /// Number of cached entries
@@ -222,6 +455,127 @@ impl std::fmt::Display for CacheFullError {
 
 impl std::error::Error for CacheFullError {}
 
+/// Fingerprint a function's bytecode range (FNV-1a, stable across runs)
+///
+/// Covers the function's instructions and every scalar constant in the
+/// pool, so any change to the code or the constants it may load produces
+/// a different fingerprint.
+pub fn bytecode_fingerprint(bytecode: &Bytecode, start: usize, end: usize) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(&(start as u64).to_le_bytes());
+    hasher.write(&(end as u64).to_le_bytes());
+    let end = end.min(bytecode.instructions.len());
+    let start = start.min(end);
+    hasher.write(&bytecode.instructions[start..end]);
+    for (index, constant) in bytecode.constants.iter().enumerate() {
+        hasher.write(&(index as u64).to_le_bytes());
+        match constant {
+            Value::Number(n) => {
+                hasher.write(&[0]);
+                hasher.write(&n.to_bits().to_le_bytes());
+            }
+            Value::Bool(b) => hasher.write(&[1, u8::from(*b)]),
+            Value::Null => hasher.write(&[2]),
+            Value::String(s) => {
+                hasher.write(&[3]);
+                hasher.write(s.as_bytes());
+            }
+            other => {
+                hasher.write(&[4]);
+                hasher.write(other.type_name().as_bytes());
+            }
+        }
+    }
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a hasher
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn kind_tag(kind: ValueKind) -> u8 {
+    match kind {
+        ValueKind::Number => 0,
+        ValueKind::Bool => 1,
+        ValueKind::Null => 2,
+    }
+}
+
+fn kind_from_tag(tag: u8) -> io::Result<ValueKind> {
+    match tag {
+        0 => Ok(ValueKind::Number),
+        1 => Ok(ValueKind::Bool),
+        2 => Ok(ValueKind::Null),
+        other => Err(invalid_data(&format!("unknown return kind tag {}", other))),
+    }
+}
+
+/// Write a length-prefixed byte string
+fn write_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    buf.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    buf.extend_from_slice(bytes);
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+/// Cursor over a persisted cache file
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| invalid_data("truncated JIT cache file"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> io::Result<u32> {
+        let mut raw = [0u8; 4];
+        raw.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(raw))
+    }
+
+    fn read_u64(&mut self) -> io::Result<u64> {
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(raw))
+    }
+
+    fn read_bytes(&mut self) -> io::Result<&'a [u8]> {
+        let len = self.read_u64()? as usize;
+        self.take(len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,7 +593,9 @@ mod tests {
     fn test_cache_insert_and_get() {
         let mut cache = CodeCache::new(1024);
         let fake_ptr = 0x1000 as *const u8;
-        cache.insert(42, fake_ptr, 64, 0).unwrap();
+        cache
+            .insert(42, fake_ptr, 64, 0, ValueKind::Number)
+            .unwrap();
         assert_eq!(cache.len(), 1);
         assert!(cache.contains(42));
         assert!(!cache.contains(99));
@@ -253,7 +609,9 @@ mod tests {
     fn test_cache_hit_miss_tracking() {
         let mut cache = CodeCache::new(1024);
         let fake_ptr = 0x1000 as *const u8;
-        cache.insert(42, fake_ptr, 64, 0).unwrap();
+        cache
+            .insert(42, fake_ptr, 64, 0, ValueKind::Number)
+            .unwrap();
 
         // Hit
         assert!(cache.get(42).is_some());
@@ -272,7 +630,9 @@ mod tests {
     fn test_cache_invalidate() {
         let mut cache = CodeCache::new(1024);
         let fake_ptr = 0x1000 as *const u8;
-        cache.insert(42, fake_ptr, 64, 0).unwrap();
+        cache
+            .insert(42, fake_ptr, 64, 0, ValueKind::Number)
+            .unwrap();
         assert!(cache.contains(42));
 
         cache.invalidate(42);
@@ -284,8 +644,12 @@ mod tests {
     fn test_cache_invalidate_all() {
         let mut cache = CodeCache::new(1024);
         let fake_ptr = 0x1000 as *const u8;
-        cache.insert(42, fake_ptr, 64, 0).unwrap();
-        cache.insert(84, fake_ptr, 64, 0).unwrap();
+        cache
+            .insert(42, fake_ptr, 64, 0, ValueKind::Number)
+            .unwrap();
+        cache
+            .insert(84, fake_ptr, 64, 0, ValueKind::Number)
+            .unwrap();
 
         cache.invalidate_all();
         // Entries still exist but version mismatch means they won't be found
@@ -299,15 +663,15 @@ mod tests {
         let fake_ptr = 0x1000 as *const u8;
 
         // Fill cache
-        cache.insert(1, fake_ptr, 64, 0).unwrap();
-        cache.insert(2, fake_ptr, 64, 0).unwrap();
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.insert(2, fake_ptr, 64, 0, ValueKind::Number).unwrap();
         assert_eq!(cache.total_bytes(), 128);
 
         // Use entry 2 (make it hot)
         cache.get(2);
 
         // Insert another — should evict entry 1 (coldest)
-        cache.insert(3, fake_ptr, 64, 0).unwrap();
+        cache.insert(3, fake_ptr, 64, 0, ValueKind::Number).unwrap();
         assert!(!cache.contains(1)); // evicted
         assert!(cache.contains(3));
     }
@@ -318,17 +682,85 @@ mod tests {
         let fake_ptr = 0x1000 as *const u8;
 
         // Fill it
-        cache.insert(1, fake_ptr, 64, 0).unwrap();
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
         // Try to insert more than max — should evict entry 1 and succeed
-        let result = cache.insert(2, fake_ptr, 64, 0);
+        let result = cache.insert(2, fake_ptr, 64, 0, ValueKind::Number);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_lru_evicts_least_recently_used() {
+        let mut cache = CodeCache::with_policy(128, EvictionPolicy::Lru);
+        let fake_ptr = 0x1000 as *const u8;
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.insert(2, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+
+        // Entry 1 was used more often, but entry 2 more recently... then 1 again
+        cache.get(1);
+        cache.get(1);
+        cache.get(2);
+        cache.get(1);
+
+        let evicted = cache.insert(3, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        assert_eq!(evicted, vec![2]);
+        assert!(cache.contains(1));
+        assert!(cache.contains(3));
+    }
+
+    #[test]
+    fn test_lfu_evicts_least_frequently_used() {
+        let mut cache = CodeCache::with_policy(128, EvictionPolicy::Lfu);
+        let fake_ptr = 0x1000 as *const u8;
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.insert(2, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.get(1);
+        cache.get(1);
+        cache.get(2);
+
+        let evicted = cache.insert(3, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        assert_eq!(evicted, vec![2]);
+    }
+
+    #[test]
+    fn test_reject_new_policy() {
+        let mut cache = CodeCache::with_policy(64, EvictionPolicy::RejectNew);
+        let fake_ptr = 0x1000 as *const u8;
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        let err = cache
+            .insert(2, fake_ptr, 64, 0, ValueKind::Number)
+            .unwrap_err();
+        assert_eq!(err.needed, 64);
+        assert!(cache.contains(1));
+        assert!(!cache.contains(2));
+    }
+
+    #[test]
+    fn test_eviction_prefers_stale_entries() {
+        let mut cache = CodeCache::with_policy(128, EvictionPolicy::RejectNew);
+        let fake_ptr = 0x1000 as *const u8;
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.insert(2, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.invalidate_all();
+        // Invalidated code is reclaimed even under RejectNew
+        let evicted = cache.insert(3, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        assert_eq!(evicted, vec![1]);
+    }
+
+    #[test]
+    fn test_reinsert_does_not_double_count() {
+        let mut cache = CodeCache::new(1024);
+        let fake_ptr = 0x1000 as *const u8;
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
+        cache.insert(1, fake_ptr, 32, 0, ValueKind::Number).unwrap();
+        assert_eq!(cache.total_bytes(), 32);
+        assert_eq!(cache.len(), 1);
+    }
+
     #[test]
     fn test_cache_clear() {
         let mut cache = CodeCache::new(1024);
         let fake_ptr = 0x1000 as *const u8;
-        cache.insert(1, fake_ptr, 64, 0).unwrap();
+        cache.insert(1, fake_ptr, 64, 0, ValueKind::Number).unwrap();
         cache.get(1);
         cache.clear();
         assert!(cache.is_empty());
@@ -341,4 +773,103 @@ mod tests {
         let cache = CodeCache::new(1024);
         assert_eq!(cache.version(), 0);
     }
+
+    fn numeric_bytecode(value: f64) -> Bytecode {
+        use atlas_runtime::bytecode::Opcode;
+        use atlas_runtime::span::Span;
+        let mut bc = Bytecode::new();
+        let idx = bc.add_constant(Value::Number(value));
+        bc.emit(Opcode::Constant, Span::dummy());
+        bc.emit_u16(idx);
+        bc.emit(Opcode::Return, Span::dummy());
+        bc
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_constants() {
+        let a = numeric_bytecode(1.0);
+        let b = numeric_bytecode(2.0);
+        let end = a.instructions.len();
+        assert_eq!(
+            bytecode_fingerprint(&a, 0, end),
+            bytecode_fingerprint(&numeric_bytecode(1.0), 0, end)
+        );
+        assert_ne!(
+            bytecode_fingerprint(&a, 0, end),
+            bytecode_fingerprint(&b, 0, end)
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jit.cache");
+        let bc = numeric_bytecode(1.0);
+        let end = bc.instructions.len();
+
+        let mut cache = CodeCache::new(1024);
+        cache
+            .insert(0, 0x1000 as *const u8, 4, 0, ValueKind::Bool)
+            .unwrap();
+        let info = PersistInfo {
+            function_end: end,
+            fingerprint: bytecode_fingerprint(&bc, 0, end),
+            takes_globals: true,
+            native_code: vec![0xc3, 0x90, 0x90, 0x90],
+        };
+        assert!(cache.attach_persist_info(0, info.clone()));
+        // Entries without persistence data are skipped
+        cache
+            .insert(8, 0x2000 as *const u8, 4, 0, ValueKind::Number)
+            .unwrap();
+        assert_eq!(cache.save_to_disk(&path).unwrap(), 1);
+
+        let loaded = CodeCache::load_from_disk(&path, &bc).unwrap();
+        assert_eq!(
+            loaded,
+            vec![PersistedFunction {
+                offset: 0,
+                param_count: 0,
+                return_kind: ValueKind::Bool,
+                info,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_load_discards_stale_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jit.cache");
+        let bc = numeric_bytecode(1.0);
+        let end = bc.instructions.len();
+
+        let mut cache = CodeCache::new(1024);
+        cache
+            .insert(0, 0x1000 as *const u8, 1, 0, ValueKind::Number)
+            .unwrap();
+        cache.attach_persist_info(
+            0,
+            PersistInfo {
+                function_end: end,
+                fingerprint: bytecode_fingerprint(&bc, 0, end),
+                takes_globals: false,
+                native_code: vec![0xc3],
+            },
+        );
+        cache.save_to_disk(&path).unwrap();
+
+        let changed = numeric_bytecode(2.0);
+        assert!(CodeCache::load_from_disk(&path, &changed)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_load_rejects_garbage() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jit.cache");
+        std::fs::write(&path, b"not a cache").unwrap();
+        let bc = numeric_bytecode(1.0);
+        assert!(CodeCache::load_from_disk(&path, &bc).is_err());
+    }
 }
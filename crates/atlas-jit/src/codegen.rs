@@ -4,20 +4,147 @@
 //! code generation. Handles arithmetic, comparisons, control flow,
 //! and local variable access.
 
+use std::collections::HashMap;
+
 use atlas_runtime::bytecode::{Bytecode, Opcode};
-use cranelift_codegen::ir::condcodes::FloatCC;
+use atlas_runtime::value::Value;
+use cranelift_codegen::ir::condcodes::{FloatCC, IntCC};
 use cranelift_codegen::ir::types;
-use cranelift_codegen::ir::{AbiParam, Function, InstBuilder, Signature, UserFuncName};
+use cranelift_codegen::ir::{
+    AbiParam, Function, InstBuilder, MemFlags, Signature, Type, UserFuncName,
+};
 use cranelift_codegen::isa::CallConv;
 use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext, Variable};
 
 use crate::{JitError, JitResult};
 
+/// The Atlas `Value` tag of a value on the simulated IR stack.
+///
+/// Numbers are carried as `f64`, booleans as `i8` (0 or 1), and null
+/// has no native representation. The kind of the top-of-stack value at
+/// `Return` determines the native return type of the compiled function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Number,
+    Bool,
+    Null,
+}
+
+impl ValueKind {
+    /// Cranelift type used to carry this kind, if any
+    pub fn ir_type(self) -> Option<Type> {
+        match self {
+            ValueKind::Number => Some(types::F64),
+            ValueKind::Bool => Some(types::I8),
+            ValueKind::Null => None,
+        }
+    }
+
+    /// Recover the return kind from a compiled function's signature
+    pub fn from_signature(sig: &Signature) -> JitResult<Self> {
+        match sig.returns.as_slice() {
+            [] => Ok(ValueKind::Null),
+            [ret] if ret.value_type == types::F64 => Ok(ValueKind::Number),
+            [ret] if ret.value_type == types::I8 => Ok(ValueKind::Bool),
+            other => Err(JitError::CompilationFailed(format!(
+                "unsupported return signature: {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ValueKind::Number => "number",
+            ValueKind::Bool => "bool",
+            ValueKind::Null => "null",
+        }
+    }
+}
+
+/// A value on the simulated IR stack together with its Atlas tag
+type StackValue = (cranelift_codegen::ir::Value, ValueKind);
+
+/// A global variable bound to a fixed slot in the globals table
+///
+/// Compiled functions that access globals take a hidden `*mut u64` argument
+/// pointing at a table with one 8-byte slot per entry, in slot order. Numbers
+/// are stored as their `f64` bits and booleans as 0 or 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalSlot {
+    /// Global variable name
+    pub name: String,
+    /// Kind the global had at compile time
+    pub kind: ValueKind,
+}
+
+impl GlobalSlot {
+    /// Encode a runtime value into this slot's native representation
+    ///
+    /// Returns `None` if the value's kind differs from the compiled kind.
+    pub fn encode(&self, value: &Value) -> Option<u64> {
+        match (self.kind, value) {
+            (ValueKind::Number, Value::Number(n)) => Some(n.to_bits()),
+            (ValueKind::Bool, Value::Bool(b)) => Some(u64::from(*b)),
+            _ => None,
+        }
+    }
+
+    /// Decode a native slot back into a runtime value
+    pub fn decode(&self, bits: u64) -> Value {
+        match self.kind {
+            ValueKind::Number => Value::Number(f64::from_bits(bits)),
+            ValueKind::Bool => Value::Bool(bits & 0xff != 0),
+            ValueKind::Null => Value::Null,
+        }
+    }
+}
+
+/// Global variable state threaded through translation
+struct GlobalEnv<'a> {
+    /// Globals visible to the function, with their current values
+    known: &'a HashMap<String, Value>,
+    /// Hidden argument: base pointer of the slot table
+    base: cranelift_codegen::ir::Value,
+    /// Slots assigned so far, in slot order
+    slots: Vec<GlobalSlot>,
+}
+
+impl GlobalEnv<'_> {
+    /// Resolve the global named by constant `idx` to a slot byte offset and kind
+    ///
+    /// Only globals currently holding a number or bool get a slot; anything
+    /// else cannot be proven native-representable.
+    fn slot_for(&mut self, bytecode: &Bytecode, idx: u16) -> Option<(i32, ValueKind)> {
+        let name = match bytecode.constants.get(idx as usize)? {
+            Value::String(s) => s.as_str(),
+            _ => return None,
+        };
+        let kind = match self.known.get(name)? {
+            Value::Number(_) => ValueKind::Number,
+            Value::Bool(_) => ValueKind::Bool,
+            _ => return None,
+        };
+        let slot = match self.slots.iter().position(|s| s.name == name) {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(GlobalSlot {
+                    name: name.to_string(),
+                    kind,
+                });
+                self.slots.len() - 1
+            }
+        };
+        let offset = i32::try_from(slot * 8).ok()?;
+        Some((offset, kind))
+    }
+}
+
 /// Translates a range of Atlas bytecode to a Cranelift IR function.
 ///
-/// The generated function takes no arguments and returns an f64 (the
-/// numeric result). This handles the common case of numeric
-/// computations which are the primary JIT compilation targets.
+/// The generated function takes no arguments. Its return type follows
+/// the kind of the top-of-stack value: `f64` for numbers, `i8` for
+/// booleans, and no return value for null.
 pub struct IrTranslator {
     /// Optimization level string for Cranelift (used by backend)
     _opt_level: &'static str,
@@ -42,9 +169,8 @@ impl IrTranslator {
     ///
     /// Returns a Cranelift Function ready for compilation.
     pub fn translate(&self, bytecode: &Bytecode, start: usize, end: usize) -> JitResult<Function> {
-        // Function signature: () -> f64
-        let mut sig = Signature::new(CallConv::SystemV);
-        sig.returns.push(AbiParam::new(types::F64));
+        // Function signature: () -> <kind>; the return is filled in once known
+        let sig = Signature::new(CallConv::SystemV);
 
         let mut func = Function::with_name_signature(UserFuncName::user(0, 0), sig);
         let mut func_ctx = FunctionBuilderContext::new();
@@ -59,12 +185,60 @@ impl IrTranslator {
         // Translate bytecode to IR using a simulated value stack
         let result = self.translate_body(&mut builder, bytecode, start, end)?;
 
-        builder.ins().return_(&[result]);
+        emit_return(&mut builder, result);
         builder.finalize();
 
         Ok(func)
     }
 
+    /// Translate a bytecode range into a Cranelift IR function with access
+    /// to global variables
+    ///
+    /// The generated function takes a hidden `*mut u64` argument pointing at
+    /// the globals slot table. `GetGlobal`/`SetGlobal` compile to loads and
+    /// stores against fixed slots, but only for globals that currently hold a
+    /// number or bool in `globals`; any other global bails out with
+    /// `UnsupportedOpcode`. Returns the function and the slot layout it uses.
+    pub fn translate_with_globals(
+        &self,
+        bytecode: &Bytecode,
+        start: usize,
+        end: usize,
+        globals: &HashMap<String, Value>,
+    ) -> JitResult<(Function, Vec<GlobalSlot>)> {
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.params.push(AbiParam::new(types::I64));
+
+        let mut func = Function::with_name_signature(UserFuncName::user(0, 0), sig);
+        let mut func_ctx = FunctionBuilderContext::new();
+        let mut builder = FunctionBuilder::new(&mut func, &mut func_ctx);
+
+        let entry_block = builder.create_block();
+        builder.append_block_params_for_function_params(entry_block);
+        builder.switch_to_block(entry_block);
+        builder.seal_block(entry_block);
+
+        let mut env = GlobalEnv {
+            known: globals,
+            base: builder.block_params(entry_block)[0],
+            slots: Vec::new(),
+        };
+        let result = self.translate_body_with_locals(
+            &mut builder,
+            bytecode,
+            start,
+            end,
+            &[],
+            Some(&mut env),
+        )?;
+        let slots = env.slots;
+
+        emit_return(&mut builder, result);
+        builder.finalize();
+
+        Ok((func, slots))
+    }
+
     /// Translate a bytecode range into a Cranelift IR function that takes
     /// arguments (for parameterized functions).
     ///
@@ -80,7 +254,6 @@ impl IrTranslator {
         for _ in 0..param_count {
             sig.params.push(AbiParam::new(types::F64));
         }
-        sig.returns.push(AbiParam::new(types::F64));
 
         let mut func = Function::with_name_signature(UserFuncName::user(0, 0), sig);
         let mut func_ctx = FunctionBuilderContext::new();
@@ -97,13 +270,13 @@ impl IrTranslator {
             let var = builder.declare_var(types::F64);
             let param_val = builder.block_params(entry_block)[i];
             builder.def_var(var, param_val);
-            locals.push(var);
+            locals.push((var, ValueKind::Number));
         }
 
         let result =
-            self.translate_body_with_locals(&mut builder, bytecode, start, end, &locals)?;
+            self.translate_body_with_locals(&mut builder, bytecode, start, end, &locals, None)?;
 
-        builder.ins().return_(&[result]);
+        emit_return(&mut builder, result);
         builder.finalize();
 
         Ok(func)
@@ -116,8 +289,8 @@ impl IrTranslator {
         bytecode: &Bytecode,
         start: usize,
         end: usize,
-    ) -> JitResult<cranelift_codegen::ir::Value> {
-        self.translate_body_with_locals(builder, bytecode, start, end, &[])
+    ) -> JitResult<StackValue> {
+        self.translate_body_with_locals(builder, bytecode, start, end, &[], None)
     }
 
     /// Core translation loop with local variable support
@@ -127,15 +300,16 @@ impl IrTranslator {
         bytecode: &Bytecode,
         start: usize,
         end: usize,
-        locals: &[Variable],
-    ) -> JitResult<cranelift_codegen::ir::Value> {
+        locals: &[(Variable, ValueKind)],
+        mut globals: Option<&mut GlobalEnv>,
+    ) -> JitResult<StackValue> {
         let instructions = &bytecode.instructions;
         let mut ip = start;
-        let mut stack: Vec<cranelift_codegen::ir::Value> = Vec::new();
+        let mut stack: Vec<StackValue> = Vec::new();
 
         // Track all declared variables (start with passed-in locals)
         let max_locals = 64; // reasonable upper bound
-        let mut declared_vars: Vec<Variable> = locals.to_vec();
+        let mut declared_vars: Vec<(Variable, ValueKind)> = locals.to_vec();
 
         while ip < end && ip < instructions.len() {
             let byte = instructions[ip];
@@ -150,94 +324,109 @@ impl IrTranslator {
                     let val = bytecode.constants.get(idx as usize).ok_or_else(|| {
                         JitError::InvalidBytecode(format!("constant index {} out of bounds", idx))
                     })?;
-                    // Only support numeric constants in JIT
-                    let f = match val {
-                        atlas_runtime::value::Value::Number(n) => *n,
+                    // Only support numeric and boolean constants in JIT
+                    match val {
+                        atlas_runtime::value::Value::Number(n) => {
+                            stack.push((builder.ins().f64const(*n), ValueKind::Number));
+                        }
+                        atlas_runtime::value::Value::Bool(b) => {
+                            stack.push(bool_const(builder, *b));
+                        }
+                        atlas_runtime::value::Value::Null => {
+                            stack.push(null_const(builder));
+                        }
                         _ => {
                             return Err(JitError::InvalidBytecode(
                                 "JIT only supports numeric constants".into(),
                             ));
                         }
-                    };
-                    stack.push(builder.ins().f64const(f));
+                    }
                 }
                 Opcode::True => {
-                    stack.push(builder.ins().f64const(1.0));
+                    stack.push(bool_const(builder, true));
+                }
+                Opcode::False => {
+                    stack.push(bool_const(builder, false));
                 }
-                Opcode::False | Opcode::Null => {
-                    stack.push(builder.ins().f64const(0.0));
+                Opcode::Null => {
+                    stack.push(null_const(builder));
                 }
                 Opcode::Add => {
-                    let (a, b) = pop2(&mut stack)?;
-                    stack.push(builder.ins().fadd(a, b));
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
+                    stack.push((builder.ins().fadd(a, b), ValueKind::Number));
                 }
                 Opcode::Sub => {
-                    let (a, b) = pop2(&mut stack)?;
-                    stack.push(builder.ins().fsub(a, b));
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
+                    stack.push((builder.ins().fsub(a, b), ValueKind::Number));
                 }
                 Opcode::Mul => {
-                    let (a, b) = pop2(&mut stack)?;
-                    stack.push(builder.ins().fmul(a, b));
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
+                    stack.push((builder.ins().fmul(a, b), ValueKind::Number));
                 }
                 Opcode::Div => {
-                    let (a, b) = pop2(&mut stack)?;
-                    stack.push(builder.ins().fdiv(a, b));
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
+                    stack.push((builder.ins().fdiv(a, b), ValueKind::Number));
                 }
                 Opcode::Mod => {
                     // f64 modulo: a - floor(a/b) * b
-                    let (a, b) = pop2(&mut stack)?;
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
                     let div = builder.ins().fdiv(a, b);
                     let floored = builder.ins().floor(div);
                     let prod = builder.ins().fmul(floored, b);
-                    stack.push(builder.ins().fsub(a, prod));
+                    stack.push((builder.ins().fsub(a, prod), ValueKind::Number));
                 }
                 Opcode::Negate => {
-                    let a = pop1(&mut stack)?;
-                    stack.push(builder.ins().fneg(a));
-                }
-                Opcode::Equal => {
-                    let (a, b) = pop2(&mut stack)?;
-                    let cmp = builder.ins().fcmp(FloatCC::Equal, a, b);
-                    // Convert bool (i8) to f64: 1.0 or 0.0
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                    let a = pop1_kind(&mut stack, ValueKind::Number, opcode)?;
+                    stack.push((builder.ins().fneg(a), ValueKind::Number));
                 }
-                Opcode::NotEqual => {
-                    let (a, b) = pop2(&mut stack)?;
-                    let cmp = builder.ins().fcmp(FloatCC::NotEqual, a, b);
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                Opcode::Equal | Opcode::NotEqual => {
+                    let ((a, a_kind), (b, b_kind)) = pop2(&mut stack)?;
+                    let equal = opcode == Opcode::Equal;
+                    // Values of different kinds are never equal (matches the VM)
+                    let cmp = match (a_kind, b_kind) {
+                        (ValueKind::Number, ValueKind::Number) => {
+                            let cc = if equal {
+                                FloatCC::Equal
+                            } else {
+                                FloatCC::NotEqual
+                            };
+                            builder.ins().fcmp(cc, a, b)
+                        }
+                        (ValueKind::Bool, ValueKind::Bool) => {
+                            let cc = if equal { IntCC::Equal } else { IntCC::NotEqual };
+                            builder.ins().icmp(cc, a, b)
+                        }
+                        (ValueKind::Null, ValueKind::Null) => {
+                            builder.ins().iconst(types::I8, i64::from(equal))
+                        }
+                        _ => builder.ins().iconst(types::I8, i64::from(!equal)),
+                    };
+                    stack.push((cmp, ValueKind::Bool));
                 }
                 Opcode::Less => {
-                    let (a, b) = pop2(&mut stack)?;
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
                     let cmp = builder.ins().fcmp(FloatCC::LessThan, a, b);
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                    stack.push((cmp, ValueKind::Bool));
                 }
                 Opcode::LessEqual => {
-                    let (a, b) = pop2(&mut stack)?;
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
                     let cmp = builder.ins().fcmp(FloatCC::LessThanOrEqual, a, b);
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                    stack.push((cmp, ValueKind::Bool));
                 }
                 Opcode::Greater => {
-                    let (a, b) = pop2(&mut stack)?;
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
                     let cmp = builder.ins().fcmp(FloatCC::GreaterThan, a, b);
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                    stack.push((cmp, ValueKind::Bool));
                 }
                 Opcode::GreaterEqual => {
-                    let (a, b) = pop2(&mut stack)?;
+                    let (a, b) = pop2_numbers(&mut stack, opcode)?;
                     let cmp = builder.ins().fcmp(FloatCC::GreaterThanOrEqual, a, b);
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                    stack.push((cmp, ValueKind::Bool));
                 }
                 Opcode::Not => {
-                    let a = pop1(&mut stack)?;
-                    let zero = builder.ins().f64const(0.0);
-                    let cmp = builder.ins().fcmp(FloatCC::Equal, a, zero);
-                    let int_val = builder.ins().uextend(types::I32, cmp);
-                    stack.push(builder.ins().fcvt_from_uint(types::F64, int_val));
+                    let a = pop1_kind(&mut stack, ValueKind::Bool, opcode)?;
+                    let cmp = builder.ins().icmp_imm(IntCC::Equal, a, 0);
+                    stack.push((cmp, ValueKind::Bool));
                 }
                 Opcode::GetLocal => {
                     let idx = read_u16(instructions, &mut ip) as usize;
@@ -246,10 +435,15 @@ impl IrTranslator {
                         let var = builder.declare_var(types::F64);
                         let zero = builder.ins().f64const(0.0);
                         builder.def_var(var, zero);
-                        declared_vars.push(var);
+                        declared_vars.push((var, ValueKind::Number));
                     }
                     if idx < declared_vars.len() {
-                        stack.push(builder.use_var(declared_vars[idx]));
+                        let (var, kind) = declared_vars[idx];
+                        if kind == ValueKind::Null {
+                            stack.push(null_const(builder));
+                        } else {
+                            stack.push((builder.use_var(var), kind));
+                        }
                     } else {
                         return Err(JitError::InvalidBytecode(format!(
                             "local index {} exceeds max {}",
@@ -259,18 +453,69 @@ impl IrTranslator {
                 }
                 Opcode::SetLocal => {
                     let idx = read_u16(instructions, &mut ip) as usize;
-                    let val = pop1(&mut stack)?;
+                    let (val, kind) = pop1(&mut stack)?;
                     // Ensure variable is declared
                     while declared_vars.len() <= idx && declared_vars.len() < max_locals {
                         let var = builder.declare_var(types::F64);
                         let zero = builder.ins().f64const(0.0);
                         builder.def_var(var, zero);
-                        declared_vars.push(var);
+                        declared_vars.push((var, ValueKind::Number));
                     }
                     if idx < declared_vars.len() {
-                        builder.def_var(declared_vars[idx], val);
+                        // A local that changes kind gets a fresh variable of the new type
+                        if declared_vars[idx].1 != kind {
+                            let ty = kind.ir_type().unwrap_or(types::I8);
+                            declared_vars[idx] = (builder.declare_var(ty), kind);
+                        }
+                        builder.def_var(declared_vars[idx].0, val);
                     }
                 }
+                Opcode::GetGlobal => {
+                    let idx = read_u16(instructions, &mut ip);
+                    let env = globals
+                        .as_deref_mut()
+                        .ok_or(JitError::UnsupportedOpcode(opcode))?;
+                    let (offset, kind) = env
+                        .slot_for(bytecode, idx)
+                        .ok_or(JitError::UnsupportedOpcode(opcode))?;
+                    let flags = MemFlags::trusted();
+                    let value = match kind {
+                        ValueKind::Number => {
+                            builder.ins().load(types::F64, flags, env.base, offset)
+                        }
+                        _ => {
+                            let raw = builder.ins().load(types::I64, flags, env.base, offset);
+                            builder.ins().ireduce(types::I8, raw)
+                        }
+                    };
+                    stack.push((value, kind));
+                }
+                Opcode::SetGlobal => {
+                    let idx = read_u16(instructions, &mut ip);
+                    let env = globals
+                        .as_deref_mut()
+                        .ok_or(JitError::UnsupportedOpcode(opcode))?;
+                    let (offset, kind) = env
+                        .slot_for(bytecode, idx)
+                        .ok_or(JitError::UnsupportedOpcode(opcode))?;
+                    // SetGlobal leaves the value on the stack (matches the VM)
+                    let &(value, value_kind) = stack
+                        .last()
+                        .ok_or_else(|| JitError::InvalidBytecode("stack underflow".into()))?;
+                    if value_kind != kind {
+                        return Err(JitError::InvalidBytecode(format!(
+                            "SetGlobal would change a {} global to {}",
+                            kind.name(),
+                            value_kind.name()
+                        )));
+                    }
+                    let flags = MemFlags::trusted();
+                    let stored = match kind {
+                        ValueKind::Number => value,
+                        _ => builder.ins().uextend(types::I64, value),
+                    };
+                    builder.ins().store(flags, stored, env.base, offset);
+                }
                 Opcode::Pop => {
                     let _ = pop1(&mut stack)?;
                 }
@@ -293,11 +538,37 @@ impl IrTranslator {
         if let Some(top) = stack.last() {
             Ok(*top)
         } else {
-            Ok(builder.ins().f64const(0.0))
+            Ok((builder.ins().f64const(0.0), ValueKind::Number))
+        }
+    }
+}
+
+/// Set the function's return type from the result kind and emit `return`
+fn emit_return(builder: &mut FunctionBuilder, (value, kind): StackValue) {
+    match kind.ir_type() {
+        Some(ty) => {
+            builder.func.signature.returns.push(AbiParam::new(ty));
+            builder.ins().return_(&[value]);
+        }
+        None => {
+            builder.ins().return_(&[]);
         }
     }
 }
 
+/// Push a boolean constant as an `i8` (0 or 1)
+fn bool_const(builder: &mut FunctionBuilder, b: bool) -> StackValue {
+    (
+        builder.ins().iconst(types::I8, i64::from(b)),
+        ValueKind::Bool,
+    )
+}
+
+/// Push a null placeholder (never read as data, only carried for its kind)
+fn null_const(builder: &mut FunctionBuilder) -> StackValue {
+    (builder.ins().iconst(types::I8, 0), ValueKind::Null)
+}
+
 /// Read a big-endian u16 from the instruction stream and advance ip
 fn read_u16(instructions: &[u8], ip: &mut usize) -> u16 {
     let hi = instructions.get(*ip).copied().unwrap_or(0) as u16;
@@ -307,21 +578,47 @@ fn read_u16(instructions: &[u8], ip: &mut usize) -> u16 {
 }
 
 /// Pop one value from the IR value stack
-fn pop1(stack: &mut Vec<cranelift_codegen::ir::Value>) -> JitResult<cranelift_codegen::ir::Value> {
+fn pop1(stack: &mut Vec<StackValue>) -> JitResult<StackValue> {
     stack
         .pop()
         .ok_or_else(|| JitError::InvalidBytecode("stack underflow".into()))
 }
 
 /// Pop two values: first popped is `b`, second is `a` (for a op b)
-fn pop2(
-    stack: &mut Vec<cranelift_codegen::ir::Value>,
-) -> JitResult<(cranelift_codegen::ir::Value, cranelift_codegen::ir::Value)> {
+fn pop2(stack: &mut Vec<StackValue>) -> JitResult<(StackValue, StackValue)> {
     let b = pop1(stack)?;
     let a = pop1(stack)?;
     Ok((a, b))
 }
 
+/// Pop one value, requiring it to be of the given kind
+fn pop1_kind(
+    stack: &mut Vec<StackValue>,
+    expected: ValueKind,
+    opcode: Opcode,
+) -> JitResult<cranelift_codegen::ir::Value> {
+    let (value, kind) = pop1(stack)?;
+    if kind != expected {
+        return Err(JitError::InvalidBytecode(format!(
+            "{:?} expects a {} operand, found {}",
+            opcode,
+            expected.name(),
+            kind.name()
+        )));
+    }
+    Ok(value)
+}
+
+/// Pop two numeric operands (for a op b)
+fn pop2_numbers(
+    stack: &mut Vec<StackValue>,
+    opcode: Opcode,
+) -> JitResult<(cranelift_codegen::ir::Value, cranelift_codegen::ir::Value)> {
+    let b = pop1_kind(stack, ValueKind::Number, opcode)?;
+    let a = pop1_kind(stack, ValueKind::Number, opcode)?;
+    Ok((a, b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
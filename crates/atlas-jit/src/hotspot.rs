@@ -5,7 +5,7 @@
 //! compilation to native code.
 
 use atlas_runtime::bytecode::{Bytecode, Opcode};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Tracks function execution counts and identifies compilation candidates
 #[derive(Debug)]
@@ -16,6 +16,10 @@ pub struct HotspotTracker {
     threshold: u64,
     /// Functions already compiled (don't recompile)
     compiled: HashMap<usize, bool>,
+    /// Functions that crossed the threshold but failed to compile (don't retry)
+    failed: HashSet<usize>,
+    /// Deoptimization count per function (each one doubles its threshold)
+    deopt_counts: HashMap<usize, u32>,
 }
 
 impl HotspotTracker {
@@ -25,6 +29,8 @@ impl HotspotTracker {
             function_counts: HashMap::new(),
             threshold,
             compiled: HashMap::new(),
+            failed: HashSet::new(),
+            deopt_counts: HashMap::new(),
         }
     }
 
@@ -43,7 +49,9 @@ impl HotspotTracker {
 
     /// Check if a function is hot enough for JIT compilation
     pub fn is_hot(&self, function_offset: usize) -> bool {
-        self.call_count(function_offset) >= self.threshold && !self.is_compiled(function_offset)
+        self.call_count(function_offset) >= self.threshold_for(function_offset)
+            && !self.is_compiled(function_offset)
+            && !self.is_failed(function_offset)
     }
 
     /// Mark a function as compiled
@@ -59,18 +67,78 @@ impl HotspotTracker {
             .unwrap_or(false)
     }
 
+    /// Mark a function as having failed compilation (bailed out to the interpreter)
+    pub fn mark_failed(&mut self, function_offset: usize) {
+        self.failed.insert(function_offset);
+    }
+
+    /// Check if a function's compilation has failed
+    pub fn is_failed(&self, function_offset: usize) -> bool {
+        self.failed.contains(&function_offset)
+    }
+
+    /// Offsets that crossed the threshold but never compiled, sorted ascending
+    pub fn failed_compilations(&self) -> Vec<usize> {
+        let mut failed: Vec<usize> = self
+            .failed
+            .iter()
+            .copied()
+            .filter(|&offset| !self.is_compiled(offset))
+            .collect();
+        failed.sort_unstable();
+        failed
+    }
+
     /// Get all hot functions that need compilation, sorted by call count (highest first)
     pub fn pending_compilations(&self) -> Vec<HotFunction> {
         let mut hot: Vec<HotFunction> = self
             .function_counts
             .iter()
-            .filter(|(&offset, &count)| count >= self.threshold && !self.is_compiled(offset))
+            .filter(|(&offset, &count)| {
+                count >= self.threshold_for(offset)
+                    && !self.is_compiled(offset)
+                    && !self.is_failed(offset)
+            })
             .map(|(&offset, &count)| HotFunction { offset, count })
             .collect();
         hot.sort_by(|a, b| b.count.cmp(&a.count));
         hot
     }
 
+    /// Drop all state for a function (e.g. after its code was evicted)
+    ///
+    /// The function starts counting from zero and may be compiled again.
+    /// Bail-outs and deoptimization back-off are kept.
+    pub fn forget(&mut self, function_offset: usize) {
+        self.function_counts.remove(&function_offset);
+        self.compiled.remove(&function_offset);
+    }
+
+    /// Record a deoptimization and return the function's new threshold
+    ///
+    /// The function is no longer considered compiled, its call count
+    /// restarts from zero, and its threshold doubles (exponential back-off).
+    pub fn deoptimize(&mut self, function_offset: usize) -> u64 {
+        self.compiled.remove(&function_offset);
+        self.function_counts.insert(function_offset, 0);
+        *self.deopt_counts.entry(function_offset).or_insert(0) += 1;
+        self.threshold_for(function_offset)
+    }
+
+    /// Number of times a function has been deoptimized
+    pub fn deopt_count(&self, function_offset: usize) -> u32 {
+        self.deopt_counts
+            .get(&function_offset)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Effective compilation threshold for a function, including back-off
+    pub fn threshold_for(&self, function_offset: usize) -> u64 {
+        let backoff = 2u64.saturating_pow(self.deopt_count(function_offset));
+        self.threshold.saturating_mul(backoff)
+    }
+
     /// Get the compilation threshold
     pub fn threshold(&self) -> u64 {
         self.threshold
@@ -85,6 +153,8 @@ impl HotspotTracker {
     pub fn reset(&mut self) {
         self.function_counts.clear();
         self.compiled.clear();
+        self.failed.clear();
+        self.deopt_counts.clear();
     }
 
     /// Total number of tracked functions
@@ -156,10 +226,12 @@ pub fn extract_function_boundaries(bytecode: &Bytecode) -> Vec<FunctionBoundary>
                 | Opcode::Jump
                 | Opcode::JumpIfFalse
                 | Opcode::Loop
-                | Opcode::Array => {
+                | Opcode::TryBegin
+                | Opcode::Array
+                | Opcode::NewMap => {
                     ip += 3; // opcode + u16
                 }
-                Opcode::Call => {
+                Opcode::Call | Opcode::TailCall => {
                     ip += 2; // opcode + u8
                 }
                 _ => {
@@ -255,6 +327,45 @@ mod tests {
         assert_eq!(pending[1].count, 3);
     }
 
+    #[test]
+    fn test_failed_compilations() {
+        let mut tracker = HotspotTracker::new(1);
+        tracker.record_call(30);
+        tracker.record_call(10);
+        tracker.record_call(20);
+        tracker.mark_failed(30);
+        tracker.mark_failed(10);
+        tracker.mark_compiled(20);
+
+        assert!(!tracker.is_hot(10));
+        assert!(tracker.is_failed(10));
+        assert_eq!(tracker.failed_compilations(), vec![10, 30]);
+        assert_eq!(tracker.compiled_count(), 1);
+        assert!(tracker.pending_compilations().is_empty());
+    }
+
+    #[test]
+    fn test_deoptimize_backs_off() {
+        let mut tracker = HotspotTracker::new(2);
+        tracker.record_call(10);
+        tracker.record_call(10);
+        tracker.mark_compiled(10);
+
+        assert_eq!(tracker.deoptimize(10), 4);
+        assert!(!tracker.is_compiled(10));
+        assert_eq!(tracker.call_count(10), 0);
+        for _ in 0..3 {
+            tracker.record_call(10);
+        }
+        assert!(!tracker.is_hot(10));
+        tracker.record_call(10);
+        assert!(tracker.is_hot(10));
+
+        assert_eq!(tracker.deoptimize(10), 8);
+        assert_eq!(tracker.deopt_count(10), 2);
+        assert_eq!(tracker.threshold_for(99), 2);
+    }
+
     #[test]
     fn test_reset() {
         let mut tracker = HotspotTracker::new(2);
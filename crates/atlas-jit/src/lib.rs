@@ -7,19 +7,24 @@
 //! # Status: Foundation Complete — Not Yet Wired to Production
 //!
 //! The JIT compiles **arithmetic-only** functions (numeric constants, local variables,
-//! arithmetic operators, comparisons). It does NOT support control flow (jump/call),
-//! global variables, or collection opcodes. See `JIT_STATUS.md` for the full capability
+//! number/bool globals, arithmetic operators, comparisons). It does NOT support control
+//! flow (jump/call) or collection opcodes. See `JIT_STATUS.md` for the full capability
 //! matrix and v0.3 integration requirements.
 //!
+//! Compiled functions return their result natively as `f64` (numbers), `i8`
+//! (booleans), or nothing (null); [`JitEngine::notify_call`] decodes this into
+//! a [`JitReturn`].
+//!
 //! ## Supported Opcodes
 //!
 //! `Constant`, `True`, `False`, `Null`, `Add`, `Sub`, `Mul`, `Div`, `Mod`, `Negate`,
 //! `Equal`, `NotEqual`, `Less`, `LessEqual`, `Greater`, `GreaterEqual`, `Not`,
-//! `GetLocal`, `SetLocal`, `Pop`, `Dup`, `Return`, `Halt`
+//! `GetLocal`, `SetLocal`, `Pop`, `Dup`, `Return`, `Halt`, and `GetGlobal`/`SetGlobal`
+//! for globals holding a number or bool (via a slot table passed as a hidden argument)
 //!
 //! ## Unsupported Opcodes (bail out to interpreter)
 //!
-//! `GetGlobal`, `SetGlobal`, `Jump`, `JumpIfFalse`, `Loop`, `Call`, `And`, `Or`,
+//! `Jump`, `JumpIfFalse`, `Loop`, `Call`, `And`, `Or`,
 //! `Array`, `GetIndex`, `SetIndex`, `IsOptionSome`, `IsOptionNone`, `IsResultOk`,
 //! `IsResultErr`, `ExtractOptionValue`, `ExtractResultValue`, `IsArray`, `GetArrayLen`
 
@@ -28,6 +33,12 @@ pub mod cache;
 pub mod codegen;
 pub mod hotspot;
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use atlas_runtime::value::Value;
+
 use thiserror::Error;
 
 /// JIT compilation errors
@@ -47,11 +58,33 @@ pub enum JitError {
 
     #[error("native execution error: {0}")]
     ExecutionError(String),
+
+    #[error("persistent code cache error: {0}")]
+    CacheIo(#[from] std::io::Error),
 }
 
 /// Result type for JIT operations
 pub type JitResult<T> = Result<T, JitError>;
 
+/// Result of a native JIT execution, decoded by the function's return kind
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JitReturn {
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+impl JitReturn {
+    /// Convert into the equivalent Atlas runtime value
+    pub fn into_value(self) -> atlas_runtime::value::Value {
+        match self {
+            JitReturn::Number(n) => atlas_runtime::value::Value::Number(n),
+            JitReturn::Bool(b) => atlas_runtime::value::Value::Bool(b),
+            JitReturn::Null => atlas_runtime::value::Value::Null,
+        }
+    }
+}
+
 /// Configuration for the JIT compiler
 #[derive(Debug, Clone)]
 pub struct JitConfig {
@@ -63,6 +96,10 @@ pub struct JitConfig {
     pub enabled: bool,
     /// Optimization level for Cranelift (0=none, 1=speed, 2=speed+size)
     pub opt_level: u8,
+    /// File used to persist compiled code across process runs (None = in-memory only)
+    pub cache_path: Option<PathBuf>,
+    /// How the code cache makes room once `cache_size_limit` is reached
+    pub eviction_policy: cache::EvictionPolicy,
 }
 
 impl Default for JitConfig {
@@ -72,6 +109,8 @@ impl Default for JitConfig {
             cache_size_limit: 64 * 1024 * 1024, // 64 MB
             enabled: true,
             opt_level: 1,
+            cache_path: None,
+            eviction_policy: cache::EvictionPolicy::Lru,
         }
     }
 }
@@ -84,6 +123,8 @@ impl JitConfig {
             cache_size_limit: 4 * 1024 * 1024,
             enabled: true,
             opt_level: 0,
+            cache_path: None,
+            eviction_policy: cache::EvictionPolicy::Lru,
         }
     }
 }
@@ -106,6 +147,12 @@ pub struct JitEngine {
     jit_executions: u64,
     /// Total number of interpreter fallbacks
     interpreter_fallbacks: u64,
+    /// Wall-clock compile time per successfully compiled function offset
+    compile_times: Vec<(usize, Duration)>,
+    /// Deoptimization events, oldest first
+    deopt_events: Vec<DeoptEvent>,
+    /// Whether the persisted cache at `config.cache_path` has been loaded
+    persisted_cache_loaded: bool,
 }
 
 impl JitEngine {
@@ -114,38 +161,81 @@ impl JitEngine {
         let backend = backend::NativeBackend::new(config.opt_level)?;
         Ok(Self {
             tracker: hotspot::HotspotTracker::new(config.compilation_threshold),
-            cache: cache::CodeCache::new(config.cache_size_limit),
+            cache: cache::CodeCache::with_policy(config.cache_size_limit, config.eviction_policy),
             translator: codegen::IrTranslator::new(config.opt_level),
             backend,
             config,
             compilations: 0,
             jit_executions: 0,
             interpreter_fallbacks: 0,
+            compile_times: Vec::new(),
+            deopt_events: Vec::new(),
+            persisted_cache_loaded: false,
         })
     }
 
     /// Record a function call and potentially trigger JIT compilation
     ///
     /// Returns `Some(result)` if the function was executed via JIT,
-    /// or `None` if the interpreter should handle it.
+    /// or `None` if the interpreter should handle it. Functions that read
+    /// or write globals always fall back; use [`notify_call_with_globals`]
+    /// to let them run natively.
+    ///
+    /// [`notify_call_with_globals`]: JitEngine::notify_call_with_globals
     pub fn notify_call(
         &mut self,
         function_offset: usize,
         bytecode: &atlas_runtime::bytecode::Bytecode,
         function_end: usize,
-    ) -> Option<f64> {
+    ) -> Option<JitReturn> {
+        self.notify_call_with_globals(function_offset, bytecode, function_end, &mut HashMap::new())
+    }
+
+    /// Record a function call with access to the VM's global variables
+    ///
+    /// Globals holding a number or bool when the function is compiled are
+    /// bound to fixed native slots. Before each native call their current
+    /// values are copied into the slot table, and afterwards any values the
+    /// native code stored are written back to `globals`. If a bound global
+    /// has since changed kind or disappeared, the function is deoptimized
+    /// and `None` is returned.
+    pub fn notify_call_with_globals(
+        &mut self,
+        function_offset: usize,
+        bytecode: &atlas_runtime::bytecode::Bytecode,
+        function_end: usize,
+        globals: &mut HashMap<String, Value>,
+    ) -> Option<JitReturn> {
         if !self.config.enabled {
             return None;
         }
 
+        // Pick up native code persisted by a previous run. A missing or
+        // unreadable cache file just means everything compiles from scratch.
+        if !self.persisted_cache_loaded && self.config.cache_path.is_some() {
+            let _ = self.load_cache(bytecode);
+        }
+
         self.tracker.record_call(function_offset);
 
         // Check if already cached
         if self.cache.contains(function_offset) {
             if let Some(entry) = self.cache.get(function_offset) {
-                let result = unsafe {
-                    let func: unsafe fn() -> f64 = std::mem::transmute(entry.code_ptr);
-                    func()
+                let (code_ptr, kind) = (entry.code_ptr, entry.return_kind);
+                let result = match entry.global_slots.clone() {
+                    None => unsafe { backend::call_native(code_ptr, kind, None) },
+                    Some(layout) => {
+                        let Some(mut slots) = pack_globals(&layout, globals) else {
+                            self.deoptimize(function_offset, DeoptReason::OperandMismatch);
+                            self.interpreter_fallbacks += 1;
+                            return None;
+                        };
+                        let result = unsafe {
+                            backend::call_native(code_ptr, kind, Some(slots.as_mut_ptr()))
+                        };
+                        unpack_globals(&layout, &slots, globals);
+                        result
+                    }
                 };
                 self.jit_executions += 1;
                 return Some(result);
@@ -154,47 +244,170 @@ impl JitEngine {
 
         // Check if hot enough to compile
         if self.tracker.is_hot(function_offset) {
-            match self.try_compile(function_offset, bytecode, function_end) {
+            match self.try_compile(function_offset, bytecode, function_end, globals) {
                 Ok(result) => {
                     self.jit_executions += 1;
                     return Some(result);
                 }
                 Err(_) => {
-                    // Compilation failed — mark as compiled to avoid retrying
-                    self.tracker.mark_compiled(function_offset);
+                    // Compilation failed — mark as a bail-out to avoid retrying
+                    self.tracker.mark_failed(function_offset);
                     self.interpreter_fallbacks += 1;
                 }
             }
+        } else if self.tracker.deopt_count(function_offset) > 0 {
+            // Deoptimized and backing off — interpret until hot again
+            self.interpreter_fallbacks += 1;
         }
 
         None
     }
 
+    /// Discard a function's native code and fall back to the interpreter
+    ///
+    /// Use this when compiled code meets a runtime condition it was not built
+    /// for. The function's call count restarts and its compilation threshold
+    /// doubles with each deoptimization, so it is not immediately re-JITed.
+    pub fn deoptimize(&mut self, function_offset: usize, reason: DeoptReason) -> DeoptEvent {
+        self.cache.invalidate(function_offset);
+        let next_threshold = self.tracker.deoptimize(function_offset);
+        let event = DeoptEvent {
+            function_offset,
+            reason,
+            deopt_count: self.tracker.deopt_count(function_offset),
+            next_threshold,
+        };
+        self.deopt_events.push(event.clone());
+        event
+    }
+
+    /// All deoptimization events recorded so far, oldest first
+    pub fn deopt_events(&self) -> &[DeoptEvent] {
+        &self.deopt_events
+    }
+
     /// Try to compile a function and execute it
     fn try_compile(
         &mut self,
         offset: usize,
         bytecode: &atlas_runtime::bytecode::Bytecode,
         end: usize,
-    ) -> JitResult<f64> {
-        let func = self.translator.translate(bytecode, offset, end)?;
+        globals: &mut HashMap<String, Value>,
+    ) -> JitResult<JitReturn> {
+        let started = Instant::now();
+        let (func, layout) = self
+            .translator
+            .translate_with_globals(bytecode, offset, end, globals)?;
         let compiled = self.backend.compile(func)?;
+        let compile_time = started.elapsed();
 
-        let result = unsafe { compiled.call_no_args() };
+        let mut slots = pack_globals(&layout, globals).ok_or_else(|| {
+            JitError::ExecutionError("global changed kind during compilation".into())
+        })?;
 
-        self.cache
-            .insert(offset, compiled.code_ptr, 64, 0)
+        // Cache before running, so a full cache leaves the call to the interpreter
+        let evicted = self
+            .cache
+            .insert(
+                offset,
+                compiled.code_ptr,
+                compiled.code_size,
+                0,
+                compiled.return_kind,
+            )
             .map_err(|e| JitError::CacheFull {
                 limit: e.limit,
                 used: e.used,
             })?;
+        self.forget_evicted(&evicted);
+        // Global slot bindings depend on runtime values, so only functions
+        // that touch no globals are persisted
+        if !compiled.native_code.is_empty() && layout.is_empty() {
+            self.cache.attach_persist_info(
+                offset,
+                cache::PersistInfo {
+                    function_end: end,
+                    fingerprint: cache::bytecode_fingerprint(bytecode, offset, end),
+                    takes_globals: compiled.takes_globals,
+                    native_code: compiled.native_code.clone(),
+                },
+            );
+        }
 
         self.tracker.mark_compiled(offset);
         self.compilations += 1;
+        self.compile_times.push((offset, compile_time));
+
+        let result = unsafe { compiled.call_with_globals(&mut slots) };
+        unpack_globals(&layout, &slots, globals);
+        self.cache.attach_global_slots(offset, layout);
 
         Ok(result)
     }
 
+    /// Reset tracker state for functions whose code was evicted, so they
+    /// can be recompiled once hot again
+    fn forget_evicted(&mut self, evicted: &[usize]) {
+        for &offset in evicted {
+            self.tracker.forget(offset);
+        }
+    }
+
+    /// Load native code persisted at `config.cache_path` for `bytecode`
+    ///
+    /// Entries compiled from different bytecode are discarded. Returns the
+    /// number of functions restored into the code cache.
+    pub fn load_cache(&mut self, bytecode: &atlas_runtime::bytecode::Bytecode) -> JitResult<usize> {
+        self.persisted_cache_loaded = true;
+        let Some(path) = self.config.cache_path.clone() else {
+            return Ok(0);
+        };
+        if !path.exists() {
+            return Ok(0);
+        }
+
+        let mut restored = 0;
+        for persisted in cache::CodeCache::load_from_disk(&path, bytecode)? {
+            let compiled = self.backend.load_native(
+                &persisted.info.native_code,
+                persisted.return_kind,
+                persisted.info.takes_globals,
+            )?;
+            let evicted = self
+                .cache
+                .insert(
+                    persisted.offset,
+                    compiled.code_ptr,
+                    compiled.code_size,
+                    persisted.param_count,
+                    persisted.return_kind,
+                )
+                .map_err(|e| JitError::CacheFull {
+                    limit: e.limit,
+                    used: e.used,
+                })?;
+            self.forget_evicted(&evicted);
+            if persisted.info.takes_globals {
+                self.cache.attach_global_slots(persisted.offset, Vec::new());
+            }
+            self.cache
+                .attach_persist_info(persisted.offset, persisted.info);
+            self.tracker.mark_compiled(persisted.offset);
+            restored += 1;
+        }
+        Ok(restored)
+    }
+
+    /// Persist the code cache to `config.cache_path`
+    ///
+    /// Returns the number of functions written (0 if no path is configured).
+    pub fn save_cache(&self) -> JitResult<usize> {
+        match &self.config.cache_path {
+            Some(path) => Ok(self.cache.save_to_disk(path)?),
+            None => Ok(0),
+        }
+    }
+
     /// Whether JIT is enabled
     pub fn is_enabled(&self) -> bool {
         self.config.enabled
@@ -216,11 +429,21 @@ impl JitEngine {
             compilations: self.compilations,
             jit_executions: self.jit_executions,
             interpreter_fallbacks: self.interpreter_fallbacks,
+            deoptimizations: self.deopt_events.len() as u64,
             cached_functions: self.cache.len(),
             cache_bytes: self.cache.total_bytes(),
             cache_hit_rate: self.cache.hit_rate(),
             tracked_functions: self.tracker.tracked_count(),
             compiled_functions: self.tracker.compiled_count(),
+            failed_compilations: self.tracker.failed_compilations(),
+            compile_times: self.compile_times.clone(),
+            total_compile_time: self.compile_times.iter().map(|&(_, t)| t).sum(),
+            max_compile_time: self
+                .compile_times
+                .iter()
+                .map(|&(_, t)| t)
+                .max()
+                .unwrap_or_default(),
         }
     }
 
@@ -231,6 +454,8 @@ impl JitEngine {
         self.compilations = 0;
         self.jit_executions = 0;
         self.interpreter_fallbacks = 0;
+        self.compile_times.clear();
+        self.deopt_events.clear();
     }
 
     /// Get the compilation threshold
@@ -244,6 +469,54 @@ impl JitEngine {
     }
 }
 
+/// Copy the current values of bound globals into a native slot table
+///
+/// Returns `None` if any bound global is missing or has changed kind.
+fn pack_globals(
+    layout: &[codegen::GlobalSlot],
+    globals: &HashMap<String, Value>,
+) -> Option<Vec<u64>> {
+    layout
+        .iter()
+        .map(|slot| globals.get(&slot.name).and_then(|v| slot.encode(v)))
+        .collect()
+}
+
+/// Write native slot values back into the globals map
+fn unpack_globals(
+    layout: &[codegen::GlobalSlot],
+    slots: &[u64],
+    globals: &mut HashMap<String, Value>,
+) {
+    for (slot, &bits) in layout.iter().zip(slots) {
+        globals.insert(slot.name.clone(), slot.decode(bits));
+    }
+}
+
+/// Why a compiled function was deoptimized
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeoptReason {
+    /// Native code was reached with operand kinds it was not compiled for
+    OperandMismatch,
+    /// The runtime hit a condition the native code cannot handle
+    UnsupportedCondition(String),
+    /// Deoptimization was requested explicitly by the host
+    Manual,
+}
+
+/// Record of a single deoptimization
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeoptEvent {
+    /// Bytecode offset of the deoptimized function
+    pub function_offset: usize,
+    /// Why the function was deoptimized
+    pub reason: DeoptReason,
+    /// How many times this function has now been deoptimized
+    pub deopt_count: u32,
+    /// Call count required before the function is compiled again
+    pub next_threshold: u64,
+}
+
 /// Statistics from the JIT engine
 #[derive(Debug, Clone)]
 pub struct JitStats {
@@ -251,8 +524,10 @@ pub struct JitStats {
     pub compilations: u64,
     /// Total native code executions
     pub jit_executions: u64,
-    /// Total interpreter fallbacks (JIT failed)
+    /// Total interpreter fallbacks (JIT failed or function deoptimized)
     pub interpreter_fallbacks: u64,
+    /// Total deoptimizations
+    pub deoptimizations: u64,
     /// Number of functions in the code cache
     pub cached_functions: usize,
     /// Total bytes of cached native code
@@ -263,4 +538,12 @@ pub struct JitStats {
     pub tracked_functions: usize,
     /// Number of functions that have been compiled
     pub compiled_functions: usize,
+    /// Offsets that crossed the threshold but failed to compile (sorted)
+    pub failed_compilations: Vec<usize>,
+    /// Wall-clock compile time per compiled function offset, in compile order
+    pub compile_times: Vec<(usize, Duration)>,
+    /// Sum of all compile times
+    pub total_compile_time: Duration,
+    /// Longest single compile time
+    pub max_compile_time: Duration,
 }
@@ -4,10 +4,11 @@
 //! Verifies JIT results match interpreter output for all supported operations.
 
 use atlas_jit::backend::NativeBackend;
-use atlas_jit::cache::CodeCache;
+use atlas_jit::cache::{CodeCache, EvictionPolicy};
 use atlas_jit::codegen::IrTranslator;
+use atlas_jit::codegen::ValueKind;
 use atlas_jit::hotspot::HotspotTracker;
-use atlas_jit::{JitConfig, JitEngine, JitError};
+use atlas_jit::{DeoptReason, JitConfig, JitEngine, JitError, JitReturn};
 use atlas_runtime::bytecode::{Bytecode, Opcode};
 use atlas_runtime::span::Span;
 use atlas_runtime::value::Value;
@@ -50,6 +51,15 @@ fn jit_eval(bc: &Bytecode) -> f64 {
     unsafe { compiled.call_no_args() }
 }
 
+/// Full pipeline, decoding the result by the compiled function's return kind
+fn jit_eval_value(bc: &Bytecode) -> JitReturn {
+    let translator = IrTranslator::new(0);
+    let func = translator.translate(bc, 0, bc.instructions.len()).unwrap();
+    let mut backend = NativeBackend::new(0).unwrap();
+    let compiled = backend.compile(func).unwrap();
+    unsafe { compiled.call() }
+}
+
 // =============================================================================
 // Arithmetic tests
 // =============================================================================
@@ -130,23 +140,23 @@ fn test_jit_negate_zero() {
 // =============================================================================
 
 #[rstest]
-#[case(1.0, 2.0, Opcode::Less, 1.0)]
-#[case(2.0, 1.0, Opcode::Less, 0.0)]
-#[case(1.0, 1.0, Opcode::Less, 0.0)]
-#[case(1.0, 2.0, Opcode::LessEqual, 1.0)]
-#[case(1.0, 1.0, Opcode::LessEqual, 1.0)]
-#[case(2.0, 1.0, Opcode::LessEqual, 0.0)]
-#[case(2.0, 1.0, Opcode::Greater, 1.0)]
-#[case(1.0, 2.0, Opcode::Greater, 0.0)]
-#[case(2.0, 1.0, Opcode::GreaterEqual, 1.0)]
-#[case(1.0, 1.0, Opcode::GreaterEqual, 1.0)]
-#[case(1.0, 1.0, Opcode::Equal, 1.0)]
-#[case(1.0, 2.0, Opcode::Equal, 0.0)]
-#[case(1.0, 2.0, Opcode::NotEqual, 1.0)]
-#[case(1.0, 1.0, Opcode::NotEqual, 0.0)]
-fn test_jit_comparison(#[case] a: f64, #[case] b: f64, #[case] op: Opcode, #[case] expected: f64) {
+#[case(1.0, 2.0, Opcode::Less, true)]
+#[case(2.0, 1.0, Opcode::Less, false)]
+#[case(1.0, 1.0, Opcode::Less, false)]
+#[case(1.0, 2.0, Opcode::LessEqual, true)]
+#[case(1.0, 1.0, Opcode::LessEqual, true)]
+#[case(2.0, 1.0, Opcode::LessEqual, false)]
+#[case(2.0, 1.0, Opcode::Greater, true)]
+#[case(1.0, 2.0, Opcode::Greater, false)]
+#[case(2.0, 1.0, Opcode::GreaterEqual, true)]
+#[case(1.0, 1.0, Opcode::GreaterEqual, true)]
+#[case(1.0, 1.0, Opcode::Equal, true)]
+#[case(1.0, 2.0, Opcode::Equal, false)]
+#[case(1.0, 2.0, Opcode::NotEqual, true)]
+#[case(1.0, 1.0, Opcode::NotEqual, false)]
+fn test_jit_comparison(#[case] a: f64, #[case] b: f64, #[case] op: Opcode, #[case] expected: bool) {
     let bc = binop_bc(a, b, op);
-    assert_eq!(jit_eval(&bc), expected);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(expected));
 }
 
 // =============================================================================
@@ -159,7 +169,7 @@ fn test_jit_not_true() {
     bc.emit(Opcode::True, dummy());
     bc.emit(Opcode::Not, dummy());
     bc.emit(Opcode::Return, dummy());
-    assert_eq!(jit_eval(&bc), 0.0);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(false));
 }
 
 #[test]
@@ -168,7 +178,7 @@ fn test_jit_not_false() {
     bc.emit(Opcode::False, dummy());
     bc.emit(Opcode::Not, dummy());
     bc.emit(Opcode::Return, dummy());
-    assert_eq!(jit_eval(&bc), 1.0);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(true));
 }
 
 // =============================================================================
@@ -381,7 +391,9 @@ fn test_cache_with_real_compiled_code() {
     let compiled = backend.compile(func).unwrap();
 
     let mut cache = CodeCache::new(1024 * 1024);
-    cache.insert(0, compiled.code_ptr, 64, 0).unwrap();
+    cache
+        .insert(0, compiled.code_ptr, 64, 0, compiled.return_kind)
+        .unwrap();
 
     assert!(cache.contains(0));
     let entry = cache.get(0).unwrap();
@@ -397,9 +409,9 @@ fn test_cache_eviction_preserves_hot() {
     let mut cache = CodeCache::new(192);
     let fake = 0x1000 as *const u8;
 
-    cache.insert(1, fake, 64, 0).unwrap();
-    cache.insert(2, fake, 64, 0).unwrap();
-    cache.insert(3, fake, 64, 0).unwrap();
+    cache.insert(1, fake, 64, 0, ValueKind::Number).unwrap();
+    cache.insert(2, fake, 64, 0, ValueKind::Number).unwrap();
+    cache.insert(3, fake, 64, 0, ValueKind::Number).unwrap();
 
     // Make entry 2 hot
     cache.get(2);
@@ -407,10 +419,121 @@ fn test_cache_eviction_preserves_hot() {
     cache.get(2);
 
     // Insert new entry — should evict 1 or 3, not 2
-    cache.insert(4, fake, 64, 0).unwrap();
+    cache.insert(4, fake, 64, 0, ValueKind::Number).unwrap();
     assert!(cache.contains(2)); // preserved because hot
 }
 
+#[test]
+fn test_cache_lru_drops_least_recently_used() {
+    let mut cache = CodeCache::with_policy(192, EvictionPolicy::Lru);
+    let fake = 0x1000 as *const u8;
+    cache.insert(1, fake, 64, 0, ValueKind::Number).unwrap();
+    cache.insert(2, fake, 64, 0, ValueKind::Number).unwrap();
+    cache.insert(3, fake, 64, 0, ValueKind::Number).unwrap();
+
+    // 2 is the most frequently used, but 1 and 3 were used after it
+    cache.get(2);
+    cache.get(2);
+    cache.get(1);
+    cache.get(3);
+
+    let evicted = cache.insert(4, fake, 64, 0, ValueKind::Number).unwrap();
+    assert_eq!(evicted, vec![2]);
+    assert!(!cache.contains(2));
+    assert!(cache.contains(1) && cache.contains(3) && cache.contains(4));
+}
+
+/// Bytecode with three constant-returning functions of identical code size
+fn three_functions() -> (Bytecode, Vec<(usize, usize)>) {
+    let mut bc = Bytecode::new();
+    let mut ranges = Vec::new();
+    for value in [1.0, 2.0, 3.0] {
+        let idx = bc.add_constant(Value::Number(value));
+        let start = bc.instructions.len();
+        bc.emit(Opcode::Constant, dummy());
+        bc.emit_u16(idx);
+        bc.emit(Opcode::Return, dummy());
+        ranges.push((start, bc.instructions.len()));
+    }
+    (bc, ranges)
+}
+
+/// Native code size of one of `three_functions`, as compiled by the engine
+fn engine_code_size() -> usize {
+    let (bc, ranges) = three_functions();
+    let (start, end) = ranges[0];
+    let (func, _) = IrTranslator::new(0)
+        .translate_with_globals(&bc, start, end, &Default::default())
+        .unwrap();
+    NativeBackend::new(0)
+        .unwrap()
+        .compile(func)
+        .unwrap()
+        .code_size
+}
+
+#[test]
+fn test_engine_lru_eviction_resets_tracker() {
+    let (bc, ranges) = three_functions();
+    let config = JitConfig {
+        cache_size_limit: engine_code_size() * 2,
+        eviction_policy: EvictionPolicy::Lru,
+        ..JitConfig::for_testing()
+    };
+    let mut engine = JitEngine::new(config).unwrap();
+    let [(s1, e1), (s2, e2), (s3, e3)] = [ranges[0], ranges[1], ranges[2]];
+
+    for _ in 0..2 {
+        engine.notify_call(s1, &bc, e1);
+        engine.notify_call(s2, &bc, e2);
+    }
+    // Touch function 1 so function 2 is the least recently used
+    assert_eq!(
+        engine.notify_call(s1, &bc, e1),
+        Some(JitReturn::Number(1.0))
+    );
+
+    engine.notify_call(s3, &bc, e3);
+    assert_eq!(
+        engine.notify_call(s3, &bc, e3),
+        Some(JitReturn::Number(3.0))
+    );
+    let stats = engine.stats();
+    assert_eq!(stats.cached_functions, 2);
+    assert_eq!(stats.compilations, 3);
+
+    // Function 2 was evicted and its tracker state reset: it warms up again
+    assert!(engine.notify_call(s2, &bc, e2).is_none());
+    assert_eq!(
+        engine.notify_call(s2, &bc, e2),
+        Some(JitReturn::Number(2.0))
+    );
+    assert_eq!(engine.stats().compilations, 4);
+}
+
+#[test]
+fn test_engine_reject_new_keeps_interpreting() {
+    let (bc, ranges) = three_functions();
+    let config = JitConfig {
+        cache_size_limit: engine_code_size(),
+        eviction_policy: EvictionPolicy::RejectNew,
+        ..JitConfig::for_testing()
+    };
+    let mut engine = JitEngine::new(config).unwrap();
+    let [(s1, e1), (s2, e2)] = [ranges[0], ranges[1]];
+
+    for _ in 0..2 {
+        engine.notify_call(s1, &bc, e1);
+    }
+    engine.notify_call(s2, &bc, e2);
+    assert!(engine.notify_call(s2, &bc, e2).is_none());
+    assert_eq!(
+        engine.notify_call(s1, &bc, e1),
+        Some(JitReturn::Number(1.0))
+    );
+    assert_eq!(engine.stats().failed_compilations, vec![s2]);
+}
+
 // =============================================================================
 // Backend tests
 // =============================================================================
@@ -452,6 +575,7 @@ fn test_backend_optimization_levels() {
 
 #[test]
 fn test_config_default() {
+    assert_eq!(JitConfig::default().eviction_policy, EvictionPolicy::Lru);
     let config = JitConfig::default();
     assert!(config.enabled);
     assert_eq!(config.compilation_threshold, 100);
@@ -490,27 +614,112 @@ fn test_jit_division_by_zero() {
 }
 
 #[test]
-fn test_jit_null_as_zero() {
+fn test_jit_null_returns_null() {
     let mut bc = Bytecode::new();
     bc.emit(Opcode::Null, dummy());
     bc.emit(Opcode::Return, dummy());
-    assert_eq!(jit_eval(&bc), 0.0);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Null);
 }
 
 #[test]
-fn test_jit_true_as_one() {
+fn test_jit_true_returns_bool() {
     let mut bc = Bytecode::new();
     bc.emit(Opcode::True, dummy());
     bc.emit(Opcode::Return, dummy());
-    assert_eq!(jit_eval(&bc), 1.0);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(true));
 }
 
 #[test]
-fn test_jit_false_as_zero() {
+fn test_jit_false_returns_bool() {
     let mut bc = Bytecode::new();
     bc.emit(Opcode::False, dummy());
     bc.emit(Opcode::Return, dummy());
-    assert_eq!(jit_eval(&bc), 0.0);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(false));
+}
+
+// =============================================================================
+// Return ABI tests
+// =============================================================================
+
+#[rstest]
+#[case(Opcode::True, ValueKind::Bool)]
+#[case(Opcode::False, ValueKind::Bool)]
+#[case(Opcode::Null, ValueKind::Null)]
+fn test_return_kind_recorded(#[case] op: Opcode, #[case] expected: ValueKind) {
+    let mut bc = Bytecode::new();
+    bc.emit(op, dummy());
+    bc.emit(Opcode::Return, dummy());
+    let translator = IrTranslator::new(0);
+    let func = translator.translate(&bc, 0, bc.instructions.len()).unwrap();
+    let mut backend = NativeBackend::new(0).unwrap();
+    let compiled = backend.compile(func).unwrap();
+    assert_eq!(compiled.return_kind, expected);
+}
+
+#[test]
+fn test_return_kind_number() {
+    let bc = num_bc(1.0);
+    assert_eq!(jit_eval_value(&bc), JitReturn::Number(1.0));
+}
+
+#[test]
+fn test_mixed_kind_equality_is_false() {
+    let mut bc = Bytecode::new();
+    let idx = bc.add_constant(Value::Number(1.0));
+    bc.emit(Opcode::Constant, dummy());
+    bc.emit_u16(idx);
+    bc.emit(Opcode::True, dummy());
+    bc.emit(Opcode::Equal, dummy());
+    bc.emit(Opcode::Return, dummy());
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(false));
+}
+
+#[test]
+fn test_bool_equality() {
+    let mut bc = Bytecode::new();
+    bc.emit(Opcode::False, dummy());
+    bc.emit(Opcode::False, dummy());
+    bc.emit(Opcode::Equal, dummy());
+    bc.emit(Opcode::Return, dummy());
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(true));
+}
+
+#[test]
+fn test_comparison_through_local_keeps_bool() {
+    // local0 = 1 < 2; !local0
+    let mut bc = Bytecode::new();
+    let a = bc.add_constant(Value::Number(1.0));
+    let b = bc.add_constant(Value::Number(2.0));
+    bc.emit(Opcode::Constant, dummy());
+    bc.emit_u16(a);
+    bc.emit(Opcode::Constant, dummy());
+    bc.emit_u16(b);
+    bc.emit(Opcode::Less, dummy());
+    bc.emit(Opcode::SetLocal, dummy());
+    bc.emit_u16(0);
+    bc.emit(Opcode::GetLocal, dummy());
+    bc.emit_u16(0);
+    bc.emit(Opcode::Not, dummy());
+    bc.emit(Opcode::Return, dummy());
+    assert_eq!(jit_eval_value(&bc), JitReturn::Bool(false));
+}
+
+#[test]
+fn test_arithmetic_on_bool_bails_out() {
+    let mut bc = Bytecode::new();
+    bc.emit(Opcode::True, dummy());
+    bc.emit(Opcode::True, dummy());
+    bc.emit(Opcode::Add, dummy());
+    bc.emit(Opcode::Return, dummy());
+    let translator = IrTranslator::new(0);
+    assert!(translator.translate(&bc, 0, bc.instructions.len()).is_err());
+}
+
+#[test]
+fn test_jit_return_into_value() {
+    assert_eq!(JitReturn::Number(2.0).into_value(), Value::Number(2.0));
+    assert_eq!(JitReturn::Bool(true).into_value(), Value::Bool(true));
+    assert_eq!(JitReturn::Null.into_value(), Value::Null);
 }
 
 // =============================================================================
@@ -572,7 +781,9 @@ fn test_full_jit_pipeline() {
     let compiled = backend.compile(func).unwrap();
 
     // Cache
-    cache.insert(0, compiled.code_ptr, 64, 0).unwrap();
+    cache
+        .insert(0, compiled.code_ptr, 64, 0, compiled.return_kind)
+        .unwrap();
     tracker.mark_compiled(0);
 
     // Execute from cache
@@ -663,7 +874,7 @@ fn test_engine_compiles_after_threshold() {
     assert!(engine.notify_call(0, &bc, end).is_none());
     // Second call: at threshold (2), should compile and execute
     let result = engine.notify_call(0, &bc, end);
-    assert_eq!(result, Some(42.0));
+    assert_eq!(result, Some(JitReturn::Number(42.0)));
 
     let stats = engine.stats();
     assert_eq!(stats.compilations, 1);
@@ -682,7 +893,7 @@ fn test_engine_cache_hit() {
 
     // Third call should be a cache hit
     let result = engine.notify_call(0, &bc, end);
-    assert_eq!(result, Some(42.0));
+    assert_eq!(result, Some(JitReturn::Number(42.0)));
 
     let stats = engine.stats();
     assert_eq!(stats.compilations, 1); // only compiled once
@@ -747,6 +958,67 @@ fn test_engine_unsupported_fallback() {
     assert_eq!(stats.interpreter_fallbacks, 1);
 }
 
+#[test]
+fn test_engine_records_compile_times() {
+    let mut bc = Bytecode::new();
+    let c1 = bc.add_constant(Value::Number(1.0));
+    let fn1_start = bc.instructions.len();
+    bc.emit(Opcode::Constant, dummy());
+    bc.emit_u16(c1);
+    bc.emit(Opcode::Return, dummy());
+    let fn1_end = bc.instructions.len();
+    let fn2_start = bc.instructions.len();
+    bc.emit(Opcode::True, dummy());
+    bc.emit(Opcode::Return, dummy());
+    let fn2_end = bc.instructions.len();
+
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    assert!(engine.stats().compile_times.is_empty());
+    for _ in 0..2 {
+        engine.notify_call(fn1_start, &bc, fn1_end);
+        engine.notify_call(fn2_start, &bc, fn2_end);
+    }
+
+    let stats = engine.stats();
+    let offsets: Vec<usize> = stats.compile_times.iter().map(|&(o, _)| o).collect();
+    assert_eq!(offsets, vec![fn1_start, fn2_start]);
+    let sum: std::time::Duration = stats.compile_times.iter().map(|&(_, t)| t).sum();
+    assert_eq!(stats.total_compile_time, sum);
+    assert!(stats.max_compile_time <= stats.total_compile_time);
+    assert!(stats
+        .compile_times
+        .iter()
+        .all(|&(_, t)| t <= stats.max_compile_time));
+
+    engine.reset();
+    assert!(engine.stats().compile_times.is_empty());
+}
+
+#[test]
+fn test_engine_distinguishes_bailouts_from_compiles() {
+    let mut bc = Bytecode::new();
+    let ok_start = bc.instructions.len();
+    bc.emit(Opcode::False, dummy());
+    bc.emit(Opcode::Return, dummy());
+    let ok_end = bc.instructions.len();
+    let bad_start = bc.instructions.len();
+    bc.emit(Opcode::Call, dummy());
+    bc.emit(Opcode::Return, dummy());
+    let bad_end = bc.instructions.len();
+
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    for _ in 0..3 {
+        engine.notify_call(ok_start, &bc, ok_end);
+        engine.notify_call(bad_start, &bc, bad_end);
+    }
+
+    let stats = engine.stats();
+    assert_eq!(stats.failed_compilations, vec![bad_start]);
+    assert_eq!(stats.compiled_functions, 1);
+    assert_eq!(stats.compile_times.len(), 1);
+    assert_eq!(stats.interpreter_fallbacks, 1);
+}
+
 #[test]
 fn test_engine_multiple_functions() {
     // Build a single bytecode with two "functions" at different offsets
@@ -774,8 +1046,14 @@ fn test_engine_multiple_functions() {
     engine.notify_call(fn2_start, &bc, fn2_end);
 
     // Compile both
-    assert_eq!(engine.notify_call(fn1_start, &bc, fn1_end), Some(10.0));
-    assert_eq!(engine.notify_call(fn2_start, &bc, fn2_end), Some(20.0));
+    assert_eq!(
+        engine.notify_call(fn1_start, &bc, fn1_end),
+        Some(JitReturn::Number(10.0))
+    );
+    assert_eq!(
+        engine.notify_call(fn2_start, &bc, fn2_end),
+        Some(JitReturn::Number(20.0))
+    );
 
     let stats = engine.stats();
     assert_eq!(stats.compilations, 2);
@@ -916,3 +1194,310 @@ fn test_halt_treated_as_return() {
     let result = jit_eval(&bc);
     assert_eq!(result, 99.0);
 }
+
+// =============================================================================
+// Globals
+// =============================================================================
+
+fn globals_with(entries: &[(&str, Value)]) -> std::collections::HashMap<String, Value> {
+    entries
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.clone()))
+        .collect()
+}
+
+fn name_const(bc: &mut Bytecode, name: &str) -> u16 {
+    bc.add_constant(Value::String(std::sync::Arc::new(name.to_string())))
+}
+
+/// counter = counter + step; returns the new value
+fn increment_global_bc(step: f64) -> Bytecode {
+    let mut bc = Bytecode::new();
+    let name = name_const(&mut bc, "counter");
+    let step = bc.add_constant(Value::Number(step));
+    bc.emit(Opcode::GetGlobal, dummy());
+    bc.emit_u16(name);
+    bc.emit(Opcode::Constant, dummy());
+    bc.emit_u16(step);
+    bc.emit(Opcode::Add, dummy());
+    bc.emit(Opcode::SetGlobal, dummy());
+    bc.emit_u16(name);
+    bc.emit(Opcode::Return, dummy());
+    bc
+}
+
+#[test]
+fn test_translate_global_slots() {
+    let bc = increment_global_bc(1.0);
+    let globals = globals_with(&[("counter", Value::Number(0.0))]);
+    let translator = IrTranslator::new(0);
+    let (_, slots) = translator
+        .translate_with_globals(&bc, 0, bc.instructions.len(), &globals)
+        .unwrap();
+    assert_eq!(slots.len(), 1);
+    assert_eq!(slots[0].name, "counter");
+    assert_eq!(slots[0].kind, ValueKind::Number);
+}
+
+#[test]
+fn test_translate_unknown_global_bails_out() {
+    let bc = increment_global_bc(1.0);
+    let globals = globals_with(&[(
+        "counter",
+        Value::String(std::sync::Arc::new("zero".to_string())),
+    )]);
+    let translator = IrTranslator::new(0);
+    match translator.translate_with_globals(&bc, 0, bc.instructions.len(), &globals) {
+        Err(JitError::UnsupportedOpcode(Opcode::GetGlobal)) => {}
+        other => panic!("expected UnsupportedOpcode(GetGlobal), got {:?}", other),
+    }
+}
+
+#[test]
+fn test_native_call_reads_and_writes_globals() {
+    let bc = increment_global_bc(2.5);
+    let globals = globals_with(&[("counter", Value::Number(10.0))]);
+    let translator = IrTranslator::new(0);
+    let (func, slots) = translator
+        .translate_with_globals(&bc, 0, bc.instructions.len(), &globals)
+        .unwrap();
+    let mut backend = NativeBackend::new(0).unwrap();
+    let compiled = backend.compile(func).unwrap();
+    assert!(compiled.takes_globals);
+
+    let mut table = vec![slots[0].encode(&globals["counter"]).unwrap()];
+    let result = unsafe { compiled.call_with_globals(&mut table) };
+    assert_eq!(result, JitReturn::Number(12.5));
+    assert_eq!(slots[0].decode(table[0]), Value::Number(12.5));
+}
+
+#[test]
+fn test_bool_global_roundtrip() {
+    // flag = !flag
+    let mut bc = Bytecode::new();
+    let name = name_const(&mut bc, "flag");
+    bc.emit(Opcode::GetGlobal, dummy());
+    bc.emit_u16(name);
+    bc.emit(Opcode::Not, dummy());
+    bc.emit(Opcode::SetGlobal, dummy());
+    bc.emit_u16(name);
+    bc.emit(Opcode::Return, dummy());
+    let end = bc.instructions.len();
+
+    let mut globals = globals_with(&[("flag", Value::Bool(false))]);
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    engine.notify_call_with_globals(0, &bc, end, &mut globals);
+    assert_eq!(
+        engine.notify_call_with_globals(0, &bc, end, &mut globals),
+        Some(JitReturn::Bool(true))
+    );
+    assert_eq!(globals["flag"], Value::Bool(true));
+    assert_eq!(
+        engine.notify_call_with_globals(0, &bc, end, &mut globals),
+        Some(JitReturn::Bool(false))
+    );
+    assert_eq!(globals["flag"], Value::Bool(false));
+}
+
+#[test]
+fn test_global_mutated_natively_is_visible_to_interpreter() {
+    use atlas_runtime::security::SecurityContext;
+    use atlas_runtime::vm::VM;
+
+    let bc = increment_global_bc(1.0);
+    let end = bc.instructions.len();
+    let mut globals = globals_with(&[("counter", Value::Number(0.0))]);
+
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    // Below threshold: the interpreter would run this call
+    assert!(engine
+        .notify_call_with_globals(0, &bc, end, &mut globals)
+        .is_none());
+    for expected in [1.0, 2.0, 3.0] {
+        assert_eq!(
+            engine.notify_call_with_globals(0, &bc, end, &mut globals),
+            Some(JitReturn::Number(expected))
+        );
+    }
+    assert_eq!(engine.stats().compilations, 1);
+
+    // Read the global back through the VM
+    let mut read = Bytecode::new();
+    let name = name_const(&mut read, "counter");
+    read.emit(Opcode::GetGlobal, dummy());
+    read.emit_u16(name);
+    read.emit(Opcode::Halt, dummy());
+    let mut vm = VM::new(read);
+    for (name, value) in &globals {
+        vm.set_global(name.clone(), value.clone());
+    }
+    let result = vm.run(&SecurityContext::allow_all()).unwrap();
+    assert_eq!(result, Some(Value::Number(3.0)));
+}
+
+#[test]
+fn test_global_kind_change_deoptimizes() {
+    let bc = increment_global_bc(1.0);
+    let end = bc.instructions.len();
+    let mut globals = globals_with(&[("counter", Value::Number(0.0))]);
+
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    engine.notify_call_with_globals(0, &bc, end, &mut globals);
+    assert!(engine
+        .notify_call_with_globals(0, &bc, end, &mut globals)
+        .is_some());
+
+    globals.insert("counter".to_string(), Value::Bool(true));
+    assert!(engine
+        .notify_call_with_globals(0, &bc, end, &mut globals)
+        .is_none());
+    assert_eq!(globals["counter"], Value::Bool(true));
+    assert_eq!(engine.deopt_events().len(), 1);
+    assert_eq!(
+        engine.deopt_events()[0].reason,
+        DeoptReason::OperandMismatch
+    );
+}
+
+#[test]
+fn test_notify_call_without_globals_bails_out_on_get_global() {
+    let bc = increment_global_bc(1.0);
+    let end = bc.instructions.len();
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    engine.notify_call(0, &bc, end);
+    assert!(engine.notify_call(0, &bc, end).is_none());
+    assert_eq!(engine.stats().failed_compilations, vec![0]);
+}
+
+// =============================================================================
+// Deoptimization
+// =============================================================================
+
+#[test]
+fn test_deoptimize_evicts_and_falls_back() {
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    let bc = num_bc(42.0);
+    let end = bc.instructions.len();
+    engine.notify_call(0, &bc, end);
+    assert_eq!(
+        engine.notify_call(0, &bc, end),
+        Some(JitReturn::Number(42.0))
+    );
+    let fallbacks_before = engine.stats().interpreter_fallbacks;
+
+    let event = engine.deoptimize(0, DeoptReason::OperandMismatch);
+    assert_eq!(event.function_offset, 0);
+    assert_eq!(event.deopt_count, 1);
+    assert_eq!(event.next_threshold, 4);
+    assert_eq!(engine.deopt_events(), &[event]);
+
+    // Back to the interpreter, and not immediately re-JITed
+    assert!(engine.notify_call(0, &bc, end).is_none());
+    let stats = engine.stats();
+    assert_eq!(stats.cached_functions, 0);
+    assert_eq!(stats.deoptimizations, 1);
+    assert!(stats.interpreter_fallbacks > fallbacks_before);
+    assert_eq!(stats.compilations, 1);
+}
+
+#[test]
+fn test_deoptimized_function_recompiles_after_backoff() {
+    let mut engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    let bc = num_bc(7.0);
+    let end = bc.instructions.len();
+    engine.notify_call(0, &bc, end);
+    engine.notify_call(0, &bc, end);
+    engine.deoptimize(0, DeoptReason::Manual);
+
+    // Threshold doubled from 2 to 4
+    for _ in 0..3 {
+        assert!(engine.notify_call(0, &bc, end).is_none());
+    }
+    assert_eq!(
+        engine.notify_call(0, &bc, end),
+        Some(JitReturn::Number(7.0))
+    );
+    assert_eq!(engine.stats().compilations, 2);
+}
+
+// =============================================================================
+// Persistent code cache
+// =============================================================================
+
+fn persistent_config(dir: &tempfile::TempDir) -> JitConfig {
+    JitConfig {
+        cache_path: Some(dir.path().join("jit.cache")),
+        ..JitConfig::for_testing()
+    }
+}
+
+#[test]
+fn test_persisted_cache_skips_recompile() {
+    let dir = tempfile::tempdir().unwrap();
+    let bc = binop_bc(6.0, 7.0, Opcode::Mul);
+    let end = bc.instructions.len();
+
+    let mut first = JitEngine::new(persistent_config(&dir)).unwrap();
+    first.notify_call(0, &bc, end);
+    assert_eq!(
+        first.notify_call(0, &bc, end),
+        Some(JitReturn::Number(42.0))
+    );
+    assert_eq!(first.stats().compilations, 1);
+    assert_eq!(first.save_cache().unwrap(), 1);
+
+    // A fresh engine runs the persisted native code on the very first call
+    let mut second = JitEngine::new(persistent_config(&dir)).unwrap();
+    assert_eq!(
+        second.notify_call(0, &bc, end),
+        Some(JitReturn::Number(42.0))
+    );
+    let stats = second.stats();
+    assert_eq!(stats.compilations, 0);
+    assert_eq!(stats.jit_executions, 1);
+}
+
+#[test]
+fn test_persisted_cache_preserves_return_kind() {
+    let dir = tempfile::tempdir().unwrap();
+    let bc = binop_bc(1.0, 2.0, Opcode::Less);
+    let end = bc.instructions.len();
+
+    let mut first = JitEngine::new(persistent_config(&dir)).unwrap();
+    first.notify_call(0, &bc, end);
+    first.notify_call(0, &bc, end);
+    first.save_cache().unwrap();
+
+    let mut second = JitEngine::new(persistent_config(&dir)).unwrap();
+    assert_eq!(second.load_cache(&bc).unwrap(), 1);
+    assert_eq!(second.notify_call(0, &bc, end), Some(JitReturn::Bool(true)));
+}
+
+#[test]
+fn test_persisted_cache_discards_stale_code() {
+    let dir = tempfile::tempdir().unwrap();
+    let bc = binop_bc(6.0, 7.0, Opcode::Mul);
+    let end = bc.instructions.len();
+
+    let mut first = JitEngine::new(persistent_config(&dir)).unwrap();
+    first.notify_call(0, &bc, end);
+    first.notify_call(0, &bc, end);
+    first.save_cache().unwrap();
+
+    // Same shape, different constants: the fingerprint no longer matches
+    let changed = binop_bc(6.0, 8.0, Opcode::Mul);
+    let mut second = JitEngine::new(persistent_config(&dir)).unwrap();
+    assert_eq!(second.load_cache(&changed).unwrap(), 0);
+    assert!(second.notify_call(0, &changed, end).is_none());
+    assert_eq!(
+        second.notify_call(0, &changed, end),
+        Some(JitReturn::Number(48.0))
+    );
+    assert_eq!(second.stats().compilations, 1);
+}
+
+#[test]
+fn test_save_cache_without_path_is_noop() {
+    let engine = JitEngine::new(JitConfig::for_testing()).unwrap();
+    assert_eq!(engine.save_cache().unwrap(), 0);
+}
@@ -471,8 +471,8 @@ fn generate_refactoring_actions(
     uri: &Url,
     range: Range,
     text: &str,
-    _program: &Program,
-    _symbols: Option<&SymbolTable>,
+    program: &Program,
+    symbols: Option<&SymbolTable>,
 ) -> Vec<CodeActionOrCommand> {
     let mut actions = Vec::new();
 
@@ -495,7 +495,9 @@ fn generate_refactoring_actions(
 
     // Extract function refactoring (for statement blocks)
     if contains_statements(&selected_text) {
-        actions.push(create_extract_function_action(uri, range, &selected_text));
+        actions.push(create_extract_function_action(
+            uri, range, text, program, symbols,
+        ));
     }
 
     // Inline variable (if selection is a single identifier that's a simple assignment)
@@ -568,23 +570,31 @@ fn create_extract_variable_action(
 fn create_extract_function_action(
     uri: &Url,
     range: Range,
-    _selected_text: &str,
+    text: &str,
+    program: &Program,
+    symbols: Option<&SymbolTable>,
 ) -> CodeActionOrCommand {
+    // Offer the action even when the selection can't be extracted, so the
+    // client can show why
+    let (edit, disabled) =
+        match crate::refactor::extract_function(uri, range, text, program, symbols, None) {
+            Ok(edit) => (Some(edit), None),
+            Err(err) => (
+                None,
+                Some(CodeActionDisabled {
+                    reason: err.to_string(),
+                }),
+            ),
+        };
+
     CodeActionOrCommand::CodeAction(CodeAction {
         title: "Extract to function".to_string(),
         kind: Some(action_kinds::refactor_extract()),
         diagnostics: None,
-        edit: None,
-        command: Some(Command {
-            title: "Extract Function".to_string(),
-            command: "atlas.extractFunction".to_string(),
-            arguments: Some(vec![
-                serde_json::to_value(uri.to_string()).unwrap(),
-                serde_json::to_value(range).unwrap(),
-            ]),
-        }),
+        edit,
+        command: None,
         is_preferred: Some(false),
-        disabled: None,
+        disabled,
         data: None,
     })
 }
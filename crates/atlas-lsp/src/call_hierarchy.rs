@@ -349,6 +349,10 @@ fn collect_calls_from_stmt(stmt: &Stmt, calls: &mut Vec<CallExpr>) {
             collect_calls_from_expr(&for_in_stmt.iterable, calls);
             collect_function_calls(&for_in_stmt.body, calls);
         }
+        Stmt::TryCatch(try_catch) => {
+            collect_function_calls(&try_catch.body, calls);
+            collect_function_calls(&try_catch.handler, calls);
+        }
         _ => {}
     }
 }
@@ -385,6 +389,18 @@ fn collect_calls_from_expr(expr: &Expr, calls: &mut Vec<CallExpr>) {
                 collect_calls_from_expr(elem, calls);
             }
         }
+        Expr::MapLiteral(map) => {
+            for entry in &map.entries {
+                collect_calls_from_expr(&entry.value, calls);
+            }
+        }
+        Expr::StringInterp(interp) => {
+            for part in &interp.parts {
+                if let StringInterpPart::Expr(expr) = part {
+                    collect_calls_from_expr(expr, calls);
+                }
+            }
+        }
         Expr::Group(group) => {
             collect_calls_from_expr(&group.expr, calls);
         }
@@ -2,6 +2,7 @@
 
 use atlas_runtime::ast::*;
 use atlas_runtime::symbol::SymbolTable;
+use atlas_runtime::types::Type;
 use tower_lsp::lsp_types::{
     CompletionItem, CompletionItemKind, Documentation, InsertTextFormat, Position,
 };
@@ -188,6 +189,14 @@ pub fn keyword_completions() -> Vec<CompletionItem> {
             detail: Some("Continue statement".to_string()),
             ..Default::default()
         },
+        CompletionItem {
+            label: "try".to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            detail: Some("Try/catch statement".to_string()),
+            insert_text: Some("try {\n\t${1}\n} catch (${2:e}) {\n\t${3}\n}".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            ..Default::default()
+        },
         CompletionItem {
             label: "true".to_string(),
             kind: Some(CompletionItemKind::KEYWORD),
@@ -234,53 +243,70 @@ pub fn type_completions() -> Vec<CompletionItem> {
 }
 
 /// Generate completion items for built-in functions
+///
+/// The list comes from the runtime's builtin registry. Builtins with a typed
+/// prelude signature show it as their detail and get one snippet placeholder
+/// per parameter.
 pub fn builtin_completions() -> Vec<CompletionItem> {
-    vec![
-        CompletionItem {
-            label: "print".to_string(),
-            kind: Some(CompletionItemKind::FUNCTION),
-            detail: Some("fn(value: any) -> null".to_string()),
-            documentation: Some(tower_lsp::lsp_types::Documentation::String(
-                "Print a value to stdout".to_string(),
-            )),
-            insert_text: Some("print(${1:value})".to_string()),
-            insert_text_format: Some(InsertTextFormat::SNIPPET),
-            ..Default::default()
-        },
-        CompletionItem {
-            label: "len".to_string(),
-            kind: Some(CompletionItemKind::FUNCTION),
-            detail: Some("fn(array: T[]) -> number".to_string()),
-            documentation: Some(tower_lsp::lsp_types::Documentation::String(
-                "Get the length of an array".to_string(),
-            )),
-            insert_text: Some("len(${1:array})".to_string()),
-            insert_text_format: Some(InsertTextFormat::SNIPPET),
-            ..Default::default()
-        },
-        CompletionItem {
-            label: "push".to_string(),
-            kind: Some(CompletionItemKind::FUNCTION),
-            detail: Some("fn(array: T[], value: T) -> null".to_string()),
-            documentation: Some(tower_lsp::lsp_types::Documentation::String(
-                "Add an element to the end of an array".to_string(),
-            )),
-            insert_text: Some("push(${1:array}, ${2:value})".to_string()),
-            insert_text_format: Some(InsertTextFormat::SNIPPET),
-            ..Default::default()
-        },
-        CompletionItem {
-            label: "pop".to_string(),
-            kind: Some(CompletionItemKind::FUNCTION),
-            detail: Some("fn(array: T[]) -> T | null".to_string()),
-            documentation: Some(tower_lsp::lsp_types::Documentation::String(
-                "Remove and return the last element of an array".to_string(),
-            )),
-            insert_text: Some("pop(${1:array})".to_string()),
-            insert_text_format: Some(InsertTextFormat::SNIPPET),
-            ..Default::default()
-        },
-    ]
+    let prelude = SymbolTable::new();
+    atlas_runtime::stdlib::builtin_names()
+        .into_iter()
+        .map(|name| {
+            let ty = prelude.lookup(name).map(|symbol| &symbol.ty);
+            let (detail, insert_text) = match ty {
+                Some(ty @ Type::Function { params, .. }) => {
+                    let placeholders: Vec<String> = params
+                        .iter()
+                        .enumerate()
+                        .map(|(i, param)| format!("${{{}:{}}}", i + 1, placeholder(param)))
+                        .collect();
+                    (
+                        format!("fn{}", ty.display_name()),
+                        format!("{}({})", name, placeholders.join(", ")),
+                    )
+                }
+                _ => ("builtin function".to_string(), format!("{}($0)", name)),
+            };
+            CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: Some(detail),
+                insert_text: Some(insert_text),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// Snippet placeholder text for a builtin parameter
+fn placeholder(ty: &Type) -> String {
+    match ty {
+        Type::Unknown => "value".to_string(),
+        Type::Array(_) => "array".to_string(),
+        Type::Function { .. } => "callback".to_string(),
+        _ => ty
+            .display_name()
+            .replace('\\', "\\\\")
+            .replace('$', "\\$")
+            .replace('}', "\\}"),
+    }
+}
+
+/// The identifier characters typed immediately before the cursor
+fn identifier_prefix(text: &str, position: Position) -> &str {
+    let line = match text.lines().nth(position.line as usize) {
+        Some(line) => line,
+        None => return "",
+    };
+    let end = line
+        .char_indices()
+        .nth(position.character as usize)
+        .map_or(line.len(), |(i, _)| i);
+    let start = line[..end]
+        .rfind(|c: char| !c.is_alphanumeric() && c != '_')
+        .map_or(0, |i| i + 1);
+    &line[start..end]
 }
 
 /// Generate completion items from symbols in scope
@@ -535,7 +561,8 @@ fn format_type_ref_str(type_ref: &TypeRef) -> String {
 ///
 /// `text` and `position` are used for context detection: ownership annotation
 /// completions (`own`, `borrow`, `shared`) are only suggested when the cursor
-/// is inside a function parameter list. Other completions are always included.
+/// is inside a function parameter list, and builtins are filtered by the
+/// identifier prefix before the cursor. Other completions are always included.
 pub fn generate_completions(
     text: Option<&str>,
     position: Option<Position>,
@@ -544,10 +571,19 @@ pub fn generate_completions(
 ) -> Vec<CompletionItem> {
     let mut items = Vec::new();
 
-    // Always include keywords and builtins
+    // Always include keywords and builtins; there are hundreds of builtins,
+    // so only those matching the typed prefix are offered
     items.extend(keyword_completions());
     items.extend(type_completions());
-    items.extend(builtin_completions());
+    let prefix = match (text, position) {
+        (Some(src), Some(pos)) => identifier_prefix(src, pos),
+        _ => "",
+    };
+    items.extend(
+        builtin_completions()
+            .into_iter()
+            .filter(|item| item.label.starts_with(prefix)),
+    );
 
     // Ownership annotations only in parameter position
     if let (Some(src), Some(pos)) = (text, position) {
@@ -2,12 +2,14 @@
 //!
 //! Provides code folding for:
 //! - Function bodies
-//! - Block statements (if, while, for, match)
+//! - Block statements (if, while, for)
+//! - Match expressions and their multi-line arms
 //! - Multi-line comments
 //! - Array literals
 //! - Import blocks
 
 use atlas_runtime::ast::*;
+use atlas_runtime::{Lexer, TokenKind};
 use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind};
 
 use crate::symbols::span_to_range;
@@ -204,33 +206,40 @@ fn extract_statement_folds(text: &str, stmt: &Stmt, ranges: &mut Vec<FoldingRang
             }
             extract_block_folds(text, &for_in.body, ranges);
         }
-        Stmt::VarDecl(var) => {
-            // Large array literals can be folded
-            extract_expression_folds(text, &var.init, ranges);
-        }
-        Stmt::Expr(expr_stmt) => {
-            // Check for match expressions
-            if let Expr::Match(match_expr) = &expr_stmt.expr {
-                let match_range = span_to_range(text, match_expr.span);
-                if match_range.end.line > match_range.start.line {
+        Stmt::TryCatch(try_catch) => {
+            for block in [&try_catch.body, &try_catch.handler] {
+                let block_range = span_to_range(text, block.span);
+                if block_range.end.line > block_range.start.line {
                     ranges.push(FoldingRange {
-                        start_line: match_range.start.line,
-                        start_character: Some(match_range.start.character),
-                        end_line: match_range.end.line,
-                        end_character: Some(match_range.end.character),
+                        start_line: block_range.start.line,
+                        start_character: Some(block_range.start.character),
+                        end_line: block_range.end.line,
+                        end_character: Some(block_range.end.character),
                         kind: Some(FoldingRangeKind::Region),
                         collapsed_text: None,
                     });
                 }
+                extract_block_folds(text, block, ranges);
             }
+        }
+        Stmt::VarDecl(var) => {
+            // Large array literals can be folded
+            extract_expression_folds(text, &var.init, ranges);
+        }
+        Stmt::Expr(expr_stmt) => {
             extract_expression_folds(text, &expr_stmt.expr, ranges);
         }
+        Stmt::Return(ret) => {
+            if let Some(value) = &ret.value {
+                extract_expression_folds(text, value, ranges);
+            }
+        }
         // Other statements don't need folding
         _ => {}
     }
 }
 
-/// Extract folding ranges from expressions (arrays, match)
+/// Extract folding ranges from expressions (arrays, maps, match)
 fn extract_expression_folds(text: &str, expr: &Expr, ranges: &mut Vec<FoldingRange>) {
     match expr {
         Expr::ArrayLiteral(arr) => {
@@ -250,6 +259,22 @@ fn extract_expression_folds(text: &str, expr: &Expr, ranges: &mut Vec<FoldingRan
                 extract_expression_folds(text, elem, ranges);
             }
         }
+        Expr::MapLiteral(map) => {
+            let range = span_to_range(text, map.span);
+            if range.end.line > range.start.line {
+                ranges.push(FoldingRange {
+                    start_line: range.start.line,
+                    start_character: Some(range.start.character),
+                    end_line: range.end.line,
+                    end_character: Some(range.end.character),
+                    kind: Some(FoldingRangeKind::Region),
+                    collapsed_text: Some(format!("{{{} entries}}", map.entries.len())),
+                });
+            }
+            for entry in &map.entries {
+                extract_expression_folds(text, &entry.value, ranges);
+            }
+        }
         Expr::Match(match_expr) => {
             let range = span_to_range(text, match_expr.span);
             if range.end.line > range.start.line {
@@ -262,6 +287,24 @@ fn extract_expression_folds(text: &str, expr: &Expr, ranges: &mut Vec<FoldingRan
                     collapsed_text: None,
                 });
             }
+            extract_expression_folds(text, &match_expr.scrutinee, ranges);
+            for arm in &match_expr.arms {
+                let arm_range = span_to_range(text, arm.span);
+                if arm_range.end.line > arm_range.start.line {
+                    ranges.push(FoldingRange {
+                        start_line: arm_range.start.line,
+                        start_character: Some(arm_range.start.character),
+                        end_line: arm_range.end.line,
+                        end_character: Some(arm_range.end.character),
+                        kind: Some(FoldingRangeKind::Region),
+                        collapsed_text: None,
+                    });
+                }
+                if let Some(guard) = &arm.guard {
+                    extract_expression_folds(text, guard, ranges);
+                }
+                extract_expression_folds(text, &arm.body, ranges);
+            }
         }
         Expr::Binary(bin) => {
             extract_expression_folds(text, &bin.left, ranges);
@@ -295,56 +338,60 @@ fn extract_expression_folds(text: &str, expr: &Expr, ranges: &mut Vec<FoldingRan
     }
 }
 
-/// Extract comment folding ranges from source text
+/// Extract comment folding ranges from the lexer's comment tokens
 fn extract_comment_folds(text: &str, ranges: &mut Vec<FoldingRange>) {
-    let lines: Vec<&str> = text.lines().collect();
-    let mut i = 0;
-
-    while i < lines.len() {
-        let line = lines[i].trim();
-
-        // Multi-line comment /* ... */
-        if line.starts_with("/*") {
-            let start_line = i;
-            // Find the end
-            while i < lines.len() && !lines[i].contains("*/") {
-                i += 1;
-            }
-            let end_line = i;
-
-            if end_line > start_line {
-                ranges.push(FoldingRange {
-                    start_line: start_line as u32,
-                    start_character: None,
-                    end_line: end_line as u32,
-                    end_character: None,
-                    kind: Some(FoldingRangeKind::Comment),
-                    collapsed_text: None,
-                });
+    let (tokens, _) = Lexer::new(text).tokenize_with_comments();
+
+    // First and last line of the current run of whole-line `//` comments
+    let mut run: Option<(u32, u32)> = None;
+    // Line on which the last non-comment token ended
+    let mut code_line: Option<u32> = None;
+
+    for token in &tokens {
+        let range = span_to_range(text, token.span);
+        match token.kind {
+            TokenKind::BlockComment => {
+                if let Some((start, end)) = run.take() {
+                    push_comment_fold(start, end, ranges);
+                }
+                push_comment_fold(range.start.line, range.end.line, ranges);
             }
-        }
-        // Consecutive single-line comments
-        else if line.starts_with("//") {
-            let start_line = i;
-            while i + 1 < lines.len() && lines[i + 1].trim().starts_with("//") {
-                i += 1;
+            TokenKind::LineComment | TokenKind::DocComment => {
+                // Trailing comments after code don't join a run
+                let own_line = code_line != Some(range.start.line);
+                run = match run {
+                    Some((start, end)) if own_line && range.start.line == end + 1 => {
+                        Some((start, range.start.line))
+                    }
+                    previous => {
+                        if let Some((start, end)) = previous {
+                            push_comment_fold(start, end, ranges);
+                        }
+                        own_line.then_some((range.start.line, range.start.line))
+                    }
+                };
             }
-            let end_line = i;
-
-            // Only fold if there are 2+ consecutive comment lines
-            if end_line > start_line {
-                ranges.push(FoldingRange {
-                    start_line: start_line as u32,
-                    start_character: None,
-                    end_line: end_line as u32,
-                    end_character: None,
-                    kind: Some(FoldingRangeKind::Comment),
-                    collapsed_text: None,
-                });
+            _ => {
+                if let Some((start, end)) = run.take() {
+                    push_comment_fold(start, end, ranges);
+                }
+                code_line = Some(range.end.line);
             }
         }
+    }
+}
 
-        i += 1;
+/// Add a comment folding range if it spans more than one line
+fn push_comment_fold(start_line: u32, end_line: u32, ranges: &mut Vec<FoldingRange>) {
+    if end_line > start_line {
+        ranges.push(FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind: Some(FoldingRangeKind::Comment),
+            collapsed_text: None,
+        });
     }
 }
 
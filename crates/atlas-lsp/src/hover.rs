@@ -732,6 +732,8 @@ fn find_keyword_hover(identifier: &str) -> Option<String> {
         "return" => "Returns a value from a function.",
         "break" => "Exits the current loop.",
         "continue" => "Skips to the next iteration of a loop.",
+        "try" => "Runs a block, handing any runtime error to the following catch block.",
+        "catch" => "Handles a runtime error from the try block; binds a {code, message} map.",
         "match" => "Pattern matching expression.",
         "type" => "Declares a type alias.",
         "import" => "Imports symbols from another module.",
@@ -277,6 +277,17 @@ impl SymbolIndex {
                 self.index_expr(&for_in_stmt.iterable, ctx, false);
                 self.index_block(&for_in_stmt.body, ctx);
             }
+            Stmt::TryCatch(try_catch) => {
+                self.index_block(&try_catch.body, ctx);
+                self.add_definition(
+                    &try_catch.error.name,
+                    &try_catch.error.span,
+                    SymbolKind::Variable,
+                    ctx.current_scope.clone(),
+                    ctx,
+                );
+                self.index_block(&try_catch.handler, ctx);
+            }
             Stmt::Return(ret_stmt) => {
                 if let Some(expr) = &ret_stmt.value {
                     self.index_expr(expr, ctx, false);
@@ -351,6 +362,18 @@ impl SymbolIndex {
                     self.index_expr(elem, ctx, false);
                 }
             }
+            Expr::MapLiteral(map) => {
+                for entry in &map.entries {
+                    self.index_expr(&entry.value, ctx, false);
+                }
+            }
+            Expr::StringInterp(interp) => {
+                for part in &interp.parts {
+                    if let StringInterpPart::Expr(expr) = part {
+                        self.index_expr(expr, ctx, false);
+                    }
+                }
+            }
             Expr::Group(group) => {
                 self.index_expr(&group.expr, ctx, false);
             }
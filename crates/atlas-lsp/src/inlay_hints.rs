@@ -7,6 +7,8 @@
 use atlas_runtime::ast::*;
 use atlas_runtime::symbol::{SymbolKind as AtlasSymbolKind, SymbolTable};
 use atlas_runtime::types::Type;
+use atlas_runtime::TypecheckDump;
+use std::collections::HashMap;
 use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position, Range};
 
 use crate::symbols::offset_to_position;
@@ -35,6 +37,30 @@ impl Default for InlayHintConfig {
     }
 }
 
+/// Symbol information available while collecting hints
+struct HintSymbols<'a> {
+    table: Option<&'a SymbolTable>,
+    /// Inferred variable types from the typecheck dump, keyed by the offset
+    /// of the variable's name
+    inferred: HashMap<usize, String>,
+}
+
+impl<'a> HintSymbols<'a> {
+    fn new(table: Option<&'a SymbolTable>) -> Self {
+        let inferred = table
+            .map(|table| {
+                TypecheckDump::from_symbol_table(table)
+                    .symbols
+                    .into_iter()
+                    .filter(|symbol| symbol.kind == "variable" && symbol.ty != "unknown")
+                    .map(|symbol| (symbol.start, symbol.ty))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { table, inferred }
+    }
+}
+
 /// Generate inlay hints for a document range
 pub fn generate_inlay_hints(
     text: &str,
@@ -46,6 +72,8 @@ pub fn generate_inlay_hints(
     let mut hints = Vec::new();
 
     if let Some(program) = ast {
+        let symbols = &HintSymbols::new(symbols);
+
         // Convert range to byte offsets for filtering
         let start_offset = position_to_offset_simple(text, range.start);
         let end_offset = position_to_offset_simple(text, range.end);
@@ -70,7 +98,7 @@ pub fn generate_inlay_hints(
 fn extract_item_hints(
     text: &str,
     item: &Item,
-    symbols: Option<&SymbolTable>,
+    symbols: &HintSymbols,
     config: &InlayHintConfig,
     start_offset: usize,
     end_offset: usize,
@@ -100,7 +128,7 @@ fn extract_item_hints(
 fn extract_block_hints(
     text: &str,
     block: &Block,
-    symbols: Option<&SymbolTable>,
+    symbols: &HintSymbols,
     config: &InlayHintConfig,
     start_offset: usize,
     end_offset: usize,
@@ -115,7 +143,7 @@ fn extract_block_hints(
 fn extract_statement_hints(
     text: &str,
     stmt: &Stmt,
-    symbols: Option<&SymbolTable>,
+    symbols: &HintSymbols,
     config: &InlayHintConfig,
     start_offset: usize,
     end_offset: usize,
@@ -130,7 +158,7 @@ fn extract_statement_hints(
                     // Skip obvious types if configured
                     if !config.skip_obvious_types || !is_obvious_type(&var.init) {
                         // Try to get type from symbol table
-                        if let Some(type_str) = get_variable_type(symbols, &var.name.name) {
+                        if let Some(type_str) = get_variable_type(symbols, &var.name) {
                             let truncated = truncate_type(&type_str, config.max_type_length);
                             let position = offset_to_position(text, var.name.span.end);
 
@@ -257,6 +285,19 @@ fn extract_statement_hints(
                 hints,
             );
         }
+        Stmt::TryCatch(try_catch) => {
+            for block in [&try_catch.body, &try_catch.handler] {
+                extract_block_hints(
+                    text,
+                    block,
+                    symbols,
+                    config,
+                    start_offset,
+                    end_offset,
+                    hints,
+                );
+            }
+        }
         Stmt::Return(ret) => {
             if let Some(expr) = &ret.value {
                 extract_expression_hints(
@@ -289,7 +330,7 @@ fn extract_statement_hints(
 fn extract_expression_hints(
     text: &str,
     expr: &Expr,
-    symbols: Option<&SymbolTable>,
+    symbols: &HintSymbols,
     config: &InlayHintConfig,
     start_offset: usize,
     end_offset: usize,
@@ -305,7 +346,7 @@ fn extract_expression_hints(
         Expr::Call(call) => {
             // Parameter name hints
             if config.show_parameter_hints && !call.args.is_empty() {
-                if let Some(param_names) = get_function_params(symbols, &call.callee) {
+                if let Some(param_names) = get_function_params(symbols.table, &call.callee) {
                     for (i, arg) in call.args.iter().enumerate() {
                         if let Some(param_name) = param_names.get(i) {
                             // Skip if argument is already named or obvious
@@ -412,6 +453,19 @@ fn extract_expression_hints(
                 );
             }
         }
+        Expr::MapLiteral(map) => {
+            for entry in &map.entries {
+                extract_expression_hints(
+                    text,
+                    &entry.value,
+                    symbols,
+                    config,
+                    start_offset,
+                    end_offset,
+                    hints,
+                );
+            }
+        }
         Expr::Index(index) => {
             extract_expression_hints(
                 text,
@@ -485,16 +539,9 @@ fn is_obvious_argument(arg: &Expr, param_name: &str) -> bool {
     }
 }
 
-/// Get variable type from symbol table
-fn get_variable_type(symbols: Option<&SymbolTable>, name: &str) -> Option<String> {
-    let symbols = symbols?;
-    let symbol = symbols.lookup(name)?;
-
-    if symbol.kind == AtlasSymbolKind::Variable || symbol.kind == AtlasSymbolKind::Parameter {
-        Some(format_type(&symbol.ty))
-    } else {
-        None
-    }
+/// Get the inferred type of the variable declared by `name`
+fn get_variable_type(symbols: &HintSymbols, name: &Identifier) -> Option<String> {
+    symbols.inferred.get(&name.span.start).cloned()
 }
 
 /// Get function parameter names
@@ -521,58 +568,6 @@ fn get_function_params(symbols: Option<&SymbolTable>, callee: &Expr) -> Option<V
     None
 }
 
-/// Format a type for display
-fn format_type(ty: &Type) -> String {
-    match ty {
-        Type::Number => "number".to_string(),
-        Type::String => "string".to_string(),
-        Type::Bool => "bool".to_string(),
-        Type::Null => "null".to_string(),
-        Type::Void => "void".to_string(),
-        Type::Never => "never".to_string(),
-        Type::Array(inner) => format!("{}[]", format_type(inner)),
-        Type::Function {
-            params,
-            return_type,
-            ..
-        } => {
-            let param_strs: Vec<String> = params.iter().map(format_type).collect();
-            format!(
-                "({}) -> {}",
-                param_strs.join(", "),
-                format_type(return_type)
-            )
-        }
-        Type::Generic { name, type_args } => {
-            if type_args.is_empty() {
-                name.clone()
-            } else {
-                let arg_strs: Vec<String> = type_args.iter().map(format_type).collect();
-                format!("{}<{}>", name, arg_strs.join(", "))
-            }
-        }
-        Type::Alias { name, .. } => name.clone(),
-        Type::TypeParameter { name } => name.clone(),
-        Type::JsonValue => "JsonValue".to_string(),
-        Type::Union(types) => {
-            let formatted: Vec<String> = types.iter().map(format_type).collect();
-            formatted.join(" | ")
-        }
-        Type::Intersection(types) => {
-            let formatted: Vec<String> = types.iter().map(format_type).collect();
-            formatted.join(" & ")
-        }
-        Type::Structural { members } => {
-            let field_strs: Vec<String> = members
-                .iter()
-                .map(|m| format!("{}: {}", m.name, format_type(&m.ty)))
-                .collect();
-            format!("{{ {} }}", field_strs.join(", "))
-        }
-        Type::Unknown | Type::Extern(_) => "?".to_string(),
-    }
-}
-
 /// Truncate a type string if too long
 fn truncate_type(type_str: &str, max_len: usize) -> String {
     if type_str.len() <= max_len {
@@ -628,6 +623,7 @@ mod tests {
             callee: Box::new(Expr::Identifier(Identifier {
                 name: "foo".to_string(),
                 span: Span::dummy(),
+                unwrap_payload: std::cell::Cell::new(None),
             })),
             args: vec![],
             span: Span::dummy(),
@@ -648,29 +644,6 @@ mod tests {
         assert!(truncated.len() <= 20);
     }
 
-    #[test]
-    fn test_format_type_simple() {
-        assert_eq!(format_type(&Type::Number), "number");
-        assert_eq!(format_type(&Type::String), "string");
-        assert_eq!(format_type(&Type::Bool), "bool");
-    }
-
-    #[test]
-    fn test_format_type_array() {
-        let arr_type = Type::Array(Box::new(Type::Number));
-        assert_eq!(format_type(&arr_type), "number[]");
-    }
-
-    #[test]
-    fn test_format_type_function() {
-        let fn_type = Type::Function {
-            type_params: vec![],
-            params: vec![Type::String, Type::Number],
-            return_type: Box::new(Type::Bool),
-        };
-        assert_eq!(format_type(&fn_type), "(string, number) -> bool");
-    }
-
     #[test]
     fn test_inlay_hint_config_default() {
         let config = InlayHintConfig::default();
@@ -18,4 +18,5 @@ pub mod refactor;
 pub mod references;
 pub mod semantic_tokens;
 pub mod server;
+pub mod signature_help;
 pub mod symbols;
@@ -212,6 +212,13 @@ fn find_references_in_stmt(stmt: &Stmt, identifier: &str, references: &mut Vec<R
             find_references_in_expr(&for_in_stmt.iterable, identifier, references);
             find_references_in_block(&for_in_stmt.body, identifier, references);
         }
+        Stmt::TryCatch(try_catch) => {
+            find_references_in_block(&try_catch.body, identifier, references);
+            if try_catch.error.name == identifier {
+                references.push(Range::default());
+            }
+            find_references_in_block(&try_catch.handler, identifier, references);
+        }
         Stmt::Return(ret_stmt) => {
             if let Some(expr) = &ret_stmt.value {
                 find_references_in_expr(expr, identifier, references);
@@ -295,6 +302,18 @@ fn find_references_in_expr(expr: &Expr, identifier: &str, references: &mut Vec<R
                 find_references_in_expr(elem, identifier, references);
             }
         }
+        Expr::MapLiteral(map) => {
+            for entry in &map.entries {
+                find_references_in_expr(&entry.value, identifier, references);
+            }
+        }
+        Expr::StringInterp(interp) => {
+            for part in &interp.parts {
+                if let StringInterpPart::Expr(expr) = part {
+                    find_references_in_expr(expr, identifier, references);
+                }
+            }
+        }
         Expr::Group(group) => {
             find_references_in_expr(&group.expr, identifier, references);
         }
@@ -1,12 +1,16 @@
 //! Extract variable and extract function refactoring
 
+use super::scope::{BindingKind, ScopeResolver};
 use super::{
     create_workspace_edit, extract_all_names, generate_unique_name, validate_new_name,
     RefactorError, RefactorResult,
 };
+use crate::index::{offset_to_position, position_to_offset, span_to_range};
 use atlas_runtime::ast::*;
+use atlas_runtime::span::Span;
 use atlas_runtime::symbol::SymbolTable;
-use tower_lsp::lsp_types::*;
+use std::collections::{HashMap, HashSet};
+use tower_lsp::lsp_types::{Position, Range, TextEdit, Url};
 
 /// Extract the selected expression to a new variable
 ///
@@ -65,20 +69,19 @@ pub fn extract_variable(
 
 /// Extract the selected statements to a new function
 ///
-/// Analyzes the selected range, determines captured variables (which become parameters),
-/// infers the return type, generates a function signature, inserts the function definition,
-/// and replaces the selection with a function call.
+/// The selection must cover whole statements from a single block. Variables
+/// declared outside the selection and read inside it become parameters, and a
+/// variable declared in the selection and used after it becomes the return
+/// value. The new function is inserted after the enclosing top-level function
+/// (or at the end of the file) and the selection is replaced with a call.
 pub fn extract_function(
     uri: &Url,
     range: Range,
     text: &str,
     program: &Program,
-    _symbols: Option<&SymbolTable>,
+    symbols: Option<&SymbolTable>,
     suggested_name: Option<&str>,
 ) -> RefactorResult {
-    // Extract the selected statements
-    let statements_text = extract_text_at_range(text, range)?;
-
     // Generate a unique function name
     let existing_names = extract_all_names(program);
     let base_name = suggested_name.unwrap_or("extracted_function");
@@ -87,24 +90,135 @@ pub fn extract_function(
     // Validate the name
     validate_new_name(&func_name)?;
 
-    // For now, generate a simple function with no parameters
-    // TODO: Analyze captured variables and add them as parameters
-    // TODO: Infer return type from extracted code
+    // Find the statements covered by the selection
+    let selection = find_selection(
+        program,
+        text,
+        position_to_offset(range.start, text),
+        position_to_offset(range.end, text),
+    )?;
+    for stmt in &selection.statements {
+        if let Some(keyword) = escaping_control_flow(stmt, false) {
+            return Err(RefactorError::SemanticsViolation(format!(
+                "selection contains a '{}' that leaves the selected code",
+                keyword
+            )));
+        }
+    }
+    let start = selection.statements[0].span().start;
+    let end = selection.statements[selection.statements.len() - 1]
+        .span()
+        .end;
+    let inside = |span: Span| start <= span.start && span.end <= end;
+
+    let unchanged = HashSet::new();
+    let resolver = ScopeResolver::resolve(program, &unchanged, "");
+
+    // Variables read inside the selection but declared outside it become
+    // parameters, in order of first use
+    let mut params: Vec<(String, Span)> = Vec::new();
+    for occ in resolver.occurrences.iter().filter(|occ| inside(occ.span)) {
+        let decl = match occ.decl {
+            Some(decl) if !inside(decl) => decl,
+            _ => continue,
+        };
+        if resolver.hoisted.contains(&decl) {
+            if resolver.bindings.get(&decl) == Some(&BindingKind::Local) {
+                return Err(RefactorError::NotImplemented(format!(
+                    "'{}' is a local function and would not be visible from the extracted function",
+                    occ.name
+                )));
+            }
+            continue;
+        }
+        if occ.write {
+            return Err(RefactorError::NotImplemented(format!(
+                "assigning to '{}', which is declared outside the selection",
+                occ.name
+            )));
+        }
+        if !params.iter().any(|(_, span)| *span == decl) {
+            params.push((occ.name.clone(), decl));
+        }
+    }
 
+    // A variable declared inside the selection and used after it becomes the
+    // return value
+    let mut outputs: Vec<Span> = Vec::new();
+    for occ in resolver.occurrences.iter().filter(|occ| !inside(occ.span)) {
+        if let Some(decl) = occ.decl.filter(|decl| inside(*decl)) {
+            if !outputs.contains(&decl) {
+                outputs.push(decl);
+            }
+        }
+    }
+    if outputs.len() > 1 {
+        return Err(RefactorError::NotImplemented(format!(
+            "selection declares {} variables that are used after it; only a single return value is supported",
+            outputs.len()
+        )));
+    }
+    let output = match outputs.first() {
+        Some(decl) => Some(
+            selection
+                .statements
+                .iter()
+                .find_map(|stmt| match stmt {
+                    Stmt::VarDecl(var) if var.name.span == *decl => Some(var),
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    RefactorError::NotImplemented(
+                        "a function declared in the selection is used after it".to_string(),
+                    )
+                })?,
+        ),
+        None => None,
+    };
+
+    // Build the signature from the declared or inferred types
+    let types = TypeResolver::new(program, text, &resolver, symbols);
+    let mut param_list = Vec::new();
+    for (name, decl) in &params {
+        param_list.push(format!("{}: {}", name, types.type_of(*decl, name)?));
+    }
+    let return_type = match output {
+        Some(var) => format!(" -> {}", types.type_of(var.name.span, &var.name.name)?),
+        None => String::new(),
+    };
+
+    let mut body = indent_text(&dedent(text, start, end), 1);
+    if let Some(var) = output {
+        body.push_str(&format!("\n    return {};", var.name.name));
+    }
     let func_decl = format!(
-        "fn {}() {{\n{}\n}}\n\n",
+        "\n\nfn {}({}){} {{\n{}\n}}",
         func_name,
-        indent_text(&statements_text, 1)
+        param_list.join(", "),
+        return_type,
+        body
     );
 
-    // Find insertion point for the function (after the current function or at file start)
-    let insert_position = Position {
-        line: 0,
-        character: 0,
+    let args: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+    let call = format!("{}({});", func_name, args.join(", "));
+    let call = match output {
+        Some(var) => format!(
+            "{} {} = {}",
+            if var.mutable { "var" } else { "let" },
+            var.name.name,
+            call
+        ),
+        None => call,
     };
 
     // Create text edits
+    let insert_position = offset_to_position(selection.insert_at, text);
     let edits = vec![
+        // Replace selection with function call
+        TextEdit {
+            range: span_to_range(&Span::new(start, end), text),
+            new_text: call,
+        },
         // Insert function declaration
         TextEdit {
             range: Range {
@@ -113,16 +227,348 @@ pub fn extract_function(
             },
             new_text: func_decl,
         },
-        // Replace selection with function call
-        TextEdit {
-            range,
-            new_text: format!("{}();", func_name),
-        },
     ];
 
     Ok(create_workspace_edit(uri, edits))
 }
 
+/// Statements selected for extraction
+struct Selection<'a> {
+    statements: Vec<&'a Stmt>,
+    /// Offset at which the new function is inserted
+    insert_at: usize,
+}
+
+/// Find the run of whole statements covered by `start..end`
+fn find_selection<'a>(
+    program: &'a Program,
+    text: &str,
+    start: usize,
+    end: usize,
+) -> Result<Selection<'a>, RefactorError> {
+    let overlaps = |span: Span| span.start < end && start < span.end;
+
+    // A selection inside a function body is extracted from that body
+    for item in &program.items {
+        let (func, item_span) = match item {
+            Item::Function(func) => (func, func.span),
+            Item::Export(ExportDecl {
+                item: ExportItem::Function(func),
+                span,
+            }) => (func, *span),
+            _ => continue,
+        };
+        if func.body.span.start < start && end < func.body.span.end {
+            return Ok(Selection {
+                statements: select_in_block(&func.body, start, end)?,
+                insert_at: item_span.end,
+            });
+        }
+    }
+
+    let mut statements = Vec::new();
+    for item in program.items.iter() {
+        match item {
+            Item::Statement(stmt) if overlaps(stmt.span()) => {
+                if stmt.span().start < start || end < stmt.span().end {
+                    return Err(partial_selection());
+                }
+                statements.push(stmt);
+            }
+            Item::Statement(_) => {}
+            Item::Function(func) if overlaps(func.span) => {
+                return Err(RefactorError::InvalidSelection(
+                    "selection contains a function declaration".to_string(),
+                ))
+            }
+            _ => {}
+        }
+    }
+    if statements.is_empty() {
+        return Err(no_statements());
+    }
+    Ok(Selection {
+        statements,
+        insert_at: text.trim_end().len(),
+    })
+}
+
+/// Find the selected statements in a block, descending into nested blocks
+/// when the selection lies entirely within one statement
+fn select_in_block(block: &Block, start: usize, end: usize) -> Result<Vec<&Stmt>, RefactorError> {
+    let selected: Vec<&Stmt> = block
+        .statements
+        .iter()
+        .filter(|stmt| stmt.span().start < end && start < stmt.span().end)
+        .collect();
+    let contained = |stmt: &Stmt| start <= stmt.span().start && stmt.span().end <= end;
+
+    if let [stmt] = selected.as_slice() {
+        if !contained(stmt) {
+            let nested = match stmt {
+                Stmt::If(if_stmt) => vec![Some(&if_stmt.then_block), if_stmt.else_block.as_ref()],
+                Stmt::While(while_stmt) => vec![Some(&while_stmt.body)],
+                Stmt::For(for_stmt) => vec![Some(&for_stmt.body)],
+                Stmt::ForIn(for_in) => vec![Some(&for_in.body)],
+                Stmt::TryCatch(try_catch) => vec![Some(&try_catch.body), Some(&try_catch.handler)],
+                Stmt::FunctionDecl(func) => vec![Some(&func.body)],
+                _ => Vec::new(),
+            };
+            return nested
+                .into_iter()
+                .flatten()
+                .find(|block| block.span.start < start && end < block.span.end)
+                .map(|block| select_in_block(block, start, end))
+                .unwrap_or_else(|| Err(partial_selection()));
+        }
+    }
+
+    if selected.is_empty() {
+        return Err(no_statements());
+    }
+    if !selected.iter().all(|stmt| contained(stmt)) {
+        return Err(partial_selection());
+    }
+    Ok(selected)
+}
+
+fn partial_selection() -> RefactorError {
+    RefactorError::InvalidSelection("selection must cover whole statements".to_string())
+}
+
+fn no_statements() -> RefactorError {
+    RefactorError::InvalidSelection("selection does not contain any statements".to_string())
+}
+
+/// Find a `return`, `break`, or `continue` that would jump out of the
+/// selected statements
+fn escaping_control_flow(stmt: &Stmt, in_loop: bool) -> Option<&'static str> {
+    let in_block = |block: &Block, in_loop: bool| {
+        block
+            .statements
+            .iter()
+            .find_map(|stmt| escaping_control_flow(stmt, in_loop))
+    };
+    match stmt {
+        Stmt::Return(_) => Some("return"),
+        Stmt::Break(_) if !in_loop => Some("break"),
+        Stmt::Continue(_) if !in_loop => Some("continue"),
+        Stmt::If(if_stmt) => in_block(&if_stmt.then_block, in_loop).or_else(|| {
+            if_stmt
+                .else_block
+                .as_ref()
+                .and_then(|block| in_block(block, in_loop))
+        }),
+        Stmt::While(while_stmt) => in_block(&while_stmt.body, true),
+        Stmt::For(for_stmt) => in_block(&for_stmt.body, true),
+        Stmt::ForIn(for_in) => in_block(&for_in.body, true),
+        Stmt::TryCatch(try_catch) => {
+            in_block(&try_catch.body, in_loop).or_else(|| in_block(&try_catch.handler, in_loop))
+        }
+        _ => None,
+    }
+}
+
+/// A declaration whose type can be written in a signature
+enum Declaration<'a> {
+    Param(&'a Param),
+    Variable(&'a VarDecl),
+    Function(&'a FunctionDecl),
+}
+
+/// Finds the types of variables from their annotations, or infers them from
+/// simple initializers
+struct TypeResolver<'a> {
+    text: &'a str,
+    declarations: HashMap<Span, Declaration<'a>>,
+    /// Declaration each identifier resolves to
+    references: HashMap<Span, Span>,
+    globals: HashSet<Span>,
+    symbols: Option<&'a SymbolTable>,
+}
+
+impl<'a> TypeResolver<'a> {
+    fn new(
+        program: &'a Program,
+        text: &'a str,
+        resolver: &ScopeResolver,
+        symbols: Option<&'a SymbolTable>,
+    ) -> Self {
+        let mut types = Self {
+            text,
+            declarations: HashMap::new(),
+            references: resolver
+                .occurrences
+                .iter()
+                .filter_map(|occ| occ.decl.map(|decl| (occ.span, decl)))
+                .collect(),
+            globals: resolver
+                .bindings
+                .iter()
+                .filter(|(_, kind)| **kind == BindingKind::Global)
+                .map(|(span, _)| *span)
+                .collect(),
+            symbols,
+        };
+        for item in &program.items {
+            match item {
+                Item::Function(func) => types.function(func),
+                Item::Statement(stmt) => types.stmt(stmt),
+                Item::Export(export) => match &export.item {
+                    ExportItem::Function(func) => types.function(func),
+                    ExportItem::Variable(var) => {
+                        types
+                            .declarations
+                            .insert(var.name.span, Declaration::Variable(var));
+                    }
+                    ExportItem::TypeAlias(_) | ExportItem::ReExport(_) => {}
+                },
+                _ => {}
+            }
+        }
+        types
+    }
+
+    fn function(&mut self, func: &'a FunctionDecl) {
+        self.declarations
+            .insert(func.name.span, Declaration::Function(func));
+        for param in &func.params {
+            self.declarations
+                .insert(param.name.span, Declaration::Param(param));
+        }
+        self.block(&func.body);
+    }
+
+    fn block(&mut self, block: &'a Block) {
+        for stmt in &block.statements {
+            self.stmt(stmt);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::VarDecl(var) => {
+                self.declarations
+                    .insert(var.name.span, Declaration::Variable(var));
+            }
+            Stmt::FunctionDecl(func) => self.function(func),
+            Stmt::If(if_stmt) => {
+                self.block(&if_stmt.then_block);
+                if let Some(else_block) = &if_stmt.else_block {
+                    self.block(else_block);
+                }
+            }
+            Stmt::While(while_stmt) => self.block(&while_stmt.body),
+            Stmt::For(for_stmt) => {
+                self.stmt(&for_stmt.init);
+                self.block(&for_stmt.body);
+            }
+            Stmt::ForIn(for_in) => self.block(&for_in.body),
+            Stmt::TryCatch(try_catch) => {
+                self.block(&try_catch.body);
+                self.block(&try_catch.handler);
+            }
+            _ => {}
+        }
+    }
+
+    /// The type of the variable declared at `decl`, as source text
+    fn type_of(&self, decl: Span, name: &str) -> Result<String, RefactorError> {
+        self.declared_type(decl)
+            .or_else(|| {
+                // The symbol table only holds top-level bindings
+                if !self.globals.contains(&decl) {
+                    return None;
+                }
+                self.symbols?
+                    .lookup(name)
+                    .map(|symbol| symbol.ty.display_name())
+            })
+            .ok_or_else(|| {
+                RefactorError::TypeSafetyViolation(format!(
+                    "cannot infer the type of '{}'; add a type annotation",
+                    name
+                ))
+            })
+    }
+
+    fn declared_type(&self, decl: Span) -> Option<String> {
+        match self.declarations.get(&decl)? {
+            Declaration::Param(param) => Some(self.source(param.type_ref.span())),
+            Declaration::Variable(var) => match &var.type_ref {
+                Some(type_ref) => Some(self.source(type_ref.span())),
+                None => self.infer(&var.init),
+            },
+            Declaration::Function(_) => None,
+        }
+    }
+
+    fn infer(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Literal(Literal::Number(_), _) => Some("number".to_string()),
+            Expr::Literal(Literal::String(_), _) | Expr::StringInterp(_) => {
+                Some("string".to_string())
+            }
+            Expr::Literal(Literal::Bool(_), _) => Some("bool".to_string()),
+            Expr::Literal(Literal::Null, _) => Some("null".to_string()),
+            Expr::Unary(unary) => match unary.op {
+                UnaryOp::Negate => Some("number".to_string()),
+                UnaryOp::Not => Some("bool".to_string()),
+            },
+            Expr::Binary(binary) => match binary.op {
+                BinaryOp::Add => {
+                    let left = self.infer(&binary.left)?;
+                    let right = self.infer(&binary.right)?;
+                    if left == "string" || right == "string" {
+                        Some("string".to_string())
+                    } else {
+                        Some(left)
+                    }
+                }
+                BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => {
+                    Some("number".to_string())
+                }
+                _ => Some("bool".to_string()),
+            },
+            Expr::Group(group) => self.infer(&group.expr),
+            Expr::Identifier(id) => self.declared_type(*self.references.get(&id.span)?),
+            Expr::ArrayLiteral(array) => {
+                let elem = self.infer(array.elements.first()?)?;
+                Some(format!("{}[]", elem))
+            }
+            Expr::Call(call) => match call.callee.as_ref() {
+                Expr::Identifier(id) => {
+                    match self.declarations.get(self.references.get(&id.span)?)? {
+                        Declaration::Function(func) if func.return_type.span().end > 0 => {
+                            Some(self.source(func.return_type.span()))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn source(&self, span: Span) -> String {
+        self.text[span.start..span.end].to_string()
+    }
+}
+
+/// Source text of `start..end` with the indentation of its first line
+/// removed from every line
+fn dedent(text: &str, start: usize, end: usize) -> String {
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &text[line_start..start];
+    let indent = if indent.trim().is_empty() { indent } else { "" };
+    text[start..end]
+        .lines()
+        .map(|line| line.strip_prefix(indent).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Extract text from source at the given range
 fn extract_text_at_range(text: &str, range: Range) -> Result<String, RefactorError> {
     let lines: Vec<&str> = text.lines().collect();
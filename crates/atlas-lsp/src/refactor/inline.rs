@@ -151,6 +151,13 @@ fn find_variable_in_block<'a>(block: &'a Block, name: &str) -> Option<&'a VarDec
                     return Some(decl);
                 }
             }
+            Stmt::TryCatch(try_catch) => {
+                if let Some(decl) = find_variable_in_block(&try_catch.body, name)
+                    .or_else(|| find_variable_in_block(&try_catch.handler, name))
+                {
+                    return Some(decl);
+                }
+            }
             _ => {}
         }
     }
@@ -5,7 +5,7 @@
 //! - Extract function: Extract statements to new function
 //! - Inline variable: Replace variable usages with its value
 //! - Inline function: Expand function calls inline
-//! - Rename symbol: Rename across workspace
+//! - Rename symbol: Rename a binding and its references within a document
 
 use atlas_runtime::ast::*;
 use tower_lsp::lsp_types::*;
@@ -13,6 +13,7 @@ use tower_lsp::lsp_types::*;
 pub mod extract;
 pub mod inline;
 pub mod rename;
+pub(crate) mod scope;
 
 pub use extract::{extract_function, extract_variable};
 pub use inline::{inline_function, inline_variable};
@@ -145,6 +146,11 @@ fn extract_names_from_stmt(stmt: &Stmt, names: &mut Vec<String>) {
             extract_names_from_expr(&for_in_stmt.iterable, names);
             extract_names_from_block(&for_in_stmt.body, names);
         }
+        Stmt::TryCatch(try_catch) => {
+            extract_names_from_block(&try_catch.body, names);
+            names.push(try_catch.error.name.clone());
+            extract_names_from_block(&try_catch.handler, names);
+        }
         Stmt::Return(ret_stmt) => {
             if let Some(expr) = &ret_stmt.value {
                 extract_names_from_expr(expr, names);
@@ -219,6 +225,18 @@ fn extract_names_from_expr(expr: &Expr, names: &mut Vec<String>) {
                 extract_names_from_expr(elem, names);
             }
         }
+        Expr::MapLiteral(map) => {
+            for entry in &map.entries {
+                extract_names_from_expr(&entry.value, names);
+            }
+        }
+        Expr::StringInterp(interp) => {
+            for part in &interp.parts {
+                if let StringInterpPart::Expr(expr) = part {
+                    extract_names_from_expr(expr, names);
+                }
+            }
+        }
         Expr::Group(group) => {
             extract_names_from_expr(&group.expr, names);
         }
@@ -1,59 +1,107 @@
 //! Rename symbol refactoring
 
+use super::scope::{BindingKind, ScopeResolver};
 use super::{create_workspace_edit, validate_new_name, RefactorError, RefactorResult};
+use crate::index::{position_to_offset, span_to_range};
 use atlas_runtime::ast::*;
+use atlas_runtime::span::Span;
 use atlas_runtime::symbol::SymbolTable;
-use tower_lsp::lsp_types::*;
+use std::collections::HashSet;
+use tower_lsp::lsp_types::{Position, TextEdit, Url};
 
-/// Rename a symbol across the workspace
+/// Rename the variable, function, or parameter at `position` within a document
 ///
-/// Finds all references to the symbol workspace-wide, validates the new name,
-/// checks for conflicts, and generates a WorkspaceEdit to update all occurrences.
+/// References are resolved with the binder's scoping rules, so shadowed
+/// bindings with the same name are left alone. The rename is rejected if the
+/// new name would collide with a binding in the same scope or change what any
+/// other identifier in the document refers to.
 pub fn rename_symbol(
     uri: &Url,
-    _position: Position,
+    position: Position,
+    text: &str,
     program: &Program,
     symbols: Option<&SymbolTable>,
-    old_name: &str,
     new_name: &str,
 ) -> RefactorResult {
     // Validate the new name
     validate_new_name(new_name)?;
 
-    // Check if the old name exists
-    if !symbol_exists(program, old_name) {
-        return Err(RefactorError::AnalysisFailed(format!(
-            "Symbol '{}' not found",
-            old_name
-        )));
+    // Resolve every identifier in the document to its declaration
+    let unchanged = HashSet::new();
+    let before = ScopeResolver::resolve(program, &unchanged, new_name);
+
+    let offset = position_to_offset(position, text);
+    let target = before
+        .occurrences
+        .iter()
+        .find(|occ| occ.span.start <= offset && offset <= occ.span.end)
+        .ok_or_else(|| RefactorError::AnalysisFailed("No symbol at cursor".to_string()))?;
+    let decl = target.decl.ok_or_else(|| {
+        RefactorError::AnalysisFailed(format!("'{}' is not declared in this file", target.name))
+    })?;
+    let old_name = target.name.clone();
+
+    match before.bindings.get(&decl) {
+        Some(BindingKind::Import) => {
+            return Err(RefactorError::SemanticsViolation(format!(
+                "'{}' is imported from another module",
+                old_name
+            )));
+        }
+        Some(BindingKind::Exported) => {
+            return Err(RefactorError::SemanticsViolation(format!(
+                "'{}' is exported; renaming it would break importing modules",
+                old_name
+            )));
+        }
+        Some(BindingKind::Global) => {
+            let builtins = SymbolTable::new();
+            if symbols.unwrap_or(&builtins).is_prelude_builtin(new_name) {
+                return Err(RefactorError::NameConflict(format!(
+                    "'{}' would shadow a prelude builtin in global scope",
+                    new_name
+                )));
+            }
+        }
+        Some(BindingKind::Local) | None => {}
     }
 
-    // Check for name conflicts
-    if symbol_exists(program, new_name) {
+    // Resolve again as if the rename had been applied; every identifier must
+    // still refer to the same declaration
+    let renamed: HashSet<Span> = before
+        .occurrences
+        .iter()
+        .filter(|occ| occ.decl == Some(decl))
+        .map(|occ| occ.span)
+        .collect();
+    let after = ScopeResolver::resolve(program, &renamed, new_name);
+
+    if let Some(span) = after.collisions.first() {
         return Err(RefactorError::NameConflict(format!(
-            "Symbol '{}' already exists",
-            new_name
+            "'{}' is already declared in this scope (line {})",
+            new_name,
+            span_to_range(span, text).start.line + 1
         )));
     }
-
-    // Find all references to the symbol
-    let references = crate::navigation::find_references(
-        program,
-        symbols.unwrap_or(&SymbolTable::new()),
-        old_name,
-    );
-
-    if references.is_empty() {
-        return Err(RefactorError::AnalysisFailed(
-            "No references found for symbol".to_string(),
-        ));
+    for (old, new) in before.occurrences.iter().zip(&after.occurrences) {
+        if old.decl != new.decl {
+            return Err(RefactorError::NameConflict(format!(
+                "renaming '{}' to '{}' would change what '{}' refers to on line {}",
+                old_name,
+                new_name,
+                new.name,
+                span_to_range(&new.span, text).start.line + 1
+            )));
+        }
     }
 
     // Create text edits to rename all occurrences
-    let edits: Vec<TextEdit> = references
-        .into_iter()
-        .map(|range| TextEdit {
-            range,
+    let mut spans: Vec<Span> = renamed.into_iter().collect();
+    spans.sort_by_key(|span| span.start);
+    let edits: Vec<TextEdit> = spans
+        .iter()
+        .map(|span| TextEdit {
+            range: span_to_range(span, text),
             new_text: new_name.to_string(),
         })
         .collect();
@@ -61,107 +109,10 @@ pub fn rename_symbol(
     Ok(create_workspace_edit(uri, edits))
 }
 
-/// Check if a symbol exists in the program
-fn symbol_exists(program: &Program, name: &str) -> bool {
-    for item in &program.items {
-        match item {
-            Item::Function(func) => {
-                if func.name.name == name {
-                    return true;
-                }
-                // Check parameters
-                for param in &func.params {
-                    if param.name.name == name {
-                        return true;
-                    }
-                }
-                // Check body
-                if symbol_exists_in_block(&func.body, name) {
-                    return true;
-                }
-            }
-            Item::Statement(Stmt::VarDecl(var_decl)) => {
-                if var_decl.name.name == name {
-                    return true;
-                }
-            }
-            Item::TypeAlias(alias) => {
-                if alias.name.name == name {
-                    return true;
-                }
-            }
-            _ => {}
-        }
-    }
-    false
-}
-
-/// Check if a symbol exists in a block
-fn symbol_exists_in_block(block: &Block, name: &str) -> bool {
-    for stmt in &block.statements {
-        match stmt {
-            Stmt::VarDecl(var_decl) => {
-                if var_decl.name.name == name {
-                    return true;
-                }
-            }
-            Stmt::FunctionDecl(func) => {
-                if func.name.name == name {
-                    return true;
-                }
-                for param in &func.params {
-                    if param.name.name == name {
-                        return true;
-                    }
-                }
-                if symbol_exists_in_block(&func.body, name) {
-                    return true;
-                }
-            }
-            Stmt::If(if_stmt) => {
-                if symbol_exists_in_block(&if_stmt.then_block, name) {
-                    return true;
-                }
-                if let Some(else_block) = &if_stmt.else_block {
-                    if symbol_exists_in_block(else_block, name) {
-                        return true;
-                    }
-                }
-            }
-            Stmt::While(while_stmt) => {
-                if symbol_exists_in_block(&while_stmt.body, name) {
-                    return true;
-                }
-            }
-            Stmt::For(for_stmt) => {
-                if symbol_exists_in_block(&for_stmt.body, name) {
-                    return true;
-                }
-            }
-            Stmt::ForIn(for_in_stmt) => {
-                if for_in_stmt.variable.name == name {
-                    return true;
-                }
-                if symbol_exists_in_block(&for_in_stmt.body, name) {
-                    return true;
-                }
-            }
-            _ => {}
-        }
-    }
-    false
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_symbol_exists() {
-        // This test would require parsing Atlas code
-        // Skipping for now
-    }
-
     #[test]
     fn test_validate_rename() {
         assert!(validate_new_name("foo").is_ok());
@@ -0,0 +1,358 @@
+//! Scope resolution shared by the refactorings and find references
+
+use atlas_runtime::ast::*;
+use atlas_runtime::span::Span;
+use std::collections::{HashMap, HashSet};
+
+/// How a name was bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    /// Top-level declaration
+    Global,
+    /// Declaration inside a function, block, loop, or match arm
+    Local,
+    /// Named import from another module
+    Import,
+    /// Exported top-level declaration
+    Exported,
+}
+
+/// An identifier and the declaration it resolves to
+#[derive(Debug)]
+pub(crate) struct Occurrence {
+    pub(crate) name: String,
+    pub(crate) span: Span,
+    /// Span of the declaring identifier, or None for builtins and unknown names
+    pub(crate) decl: Option<Span>,
+    /// Whether the identifier is the target of an assignment
+    pub(crate) write: bool,
+}
+
+/// Resolves identifiers to declarations following the binder's scope rules:
+/// top-level and block-level functions are hoisted, functions get a scope
+/// for their parameters, and blocks, `for` loops, and match arms open scopes
+pub(crate) struct ScopeResolver<'a> {
+    scopes: Vec<HashMap<String, Span>>,
+    pub(crate) occurrences: Vec<Occurrence>,
+    pub(crate) bindings: HashMap<Span, BindingKind>,
+    /// Function and import declarations, which are visible throughout their scope
+    pub(crate) hoisted: HashSet<Span>,
+    /// Declarations that collide with an existing binding in the same scope
+    pub(crate) collisions: Vec<Span>,
+    /// Identifiers to treat as if they were already named `new_name`
+    renamed: &'a HashSet<Span>,
+    new_name: &'a str,
+}
+
+impl<'a> ScopeResolver<'a> {
+    pub(crate) fn resolve(
+        program: &Program,
+        renamed: &'a HashSet<Span>,
+        new_name: &'a str,
+    ) -> Self {
+        let mut resolver = Self {
+            scopes: vec![HashMap::new()],
+            occurrences: Vec::new(),
+            bindings: HashMap::new(),
+            hoisted: HashSet::new(),
+            collisions: Vec::new(),
+            renamed,
+            new_name,
+        };
+        resolver.program(program);
+        resolver
+    }
+
+    fn name_of(&self, id: &Identifier) -> String {
+        if self.renamed.contains(&id.span) {
+            self.new_name.to_string()
+        } else {
+            id.name.clone()
+        }
+    }
+
+    fn declare(&mut self, id: &Identifier, kind: BindingKind) {
+        let name = self.name_of(id);
+
+        // A renamed binding may not redeclare a name in the same scope, or
+        // shadow (or be shadowed by) a parameter or local of an enclosing scope
+        let innermost = self.scopes.len() - 1;
+        let collides = self.scopes.iter().enumerate().any(|(depth, scope)| {
+            scope.get(&name).is_some_and(|existing| {
+                (self.renamed.contains(&id.span) || self.renamed.contains(existing))
+                    && (depth == innermost
+                        || self.bindings.get(existing) == Some(&BindingKind::Local))
+            })
+        });
+        if collides {
+            self.collisions.push(id.span);
+        }
+
+        let scope = self.scopes.last_mut().expect("scope stack is never empty");
+        scope.insert(name.clone(), id.span);
+        self.bindings.insert(id.span, kind);
+        self.occurrences.push(Occurrence {
+            name,
+            span: id.span,
+            decl: Some(id.span),
+            write: false,
+        });
+    }
+
+    fn reference(&mut self, id: &Identifier) {
+        self.occurrence(id, false);
+    }
+
+    fn occurrence(&mut self, id: &Identifier, write: bool) {
+        let name = self.name_of(id);
+        let decl = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name).copied());
+        self.occurrences.push(Occurrence {
+            name,
+            span: id.span,
+            decl,
+            write,
+        });
+    }
+
+    fn hoist(&mut self, id: &Identifier, kind: BindingKind) {
+        self.hoisted.insert(id.span);
+        self.declare(id, kind);
+    }
+
+    fn local_kind(&self) -> BindingKind {
+        if self.scopes.len() == 1 {
+            BindingKind::Global
+        } else {
+            BindingKind::Local
+        }
+    }
+
+    fn program(&mut self, program: &Program) {
+        // Imports and hoisted functions are visible throughout the file
+        for item in &program.items {
+            match item {
+                Item::Function(func) => self.hoist(&func.name, BindingKind::Global),
+                Item::Export(ExportDecl {
+                    item: ExportItem::Function(func),
+                    ..
+                }) => self.hoist(&func.name, BindingKind::Exported),
+                Item::Import(import) => {
+                    for spec in &import.specifiers {
+                        match spec {
+                            ImportSpecifier::Named { name, .. } => {
+                                self.hoist(name, BindingKind::Import)
+                            }
+                            ImportSpecifier::Namespace { alias, .. } => {
+                                self.hoist(alias, BindingKind::Global)
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for item in &program.items {
+            match item {
+                Item::Function(func) => self.function(func),
+                Item::Statement(stmt) => self.stmt(stmt),
+                Item::Export(export) => match &export.item {
+                    ExportItem::Function(func) => self.function(func),
+                    ExportItem::Variable(var) => {
+                        self.expr(&var.init);
+                        self.declare(&var.name, BindingKind::Exported);
+                    }
+                    ExportItem::TypeAlias(_) | ExportItem::ReExport(_) => {}
+                },
+                Item::Import(_) | Item::Extern(_) | Item::TypeAlias(_) => {}
+                Item::Trait(_) | Item::Impl(_) => {}
+            }
+        }
+    }
+
+    fn function(&mut self, func: &FunctionDecl) {
+        self.scopes.push(HashMap::new());
+        for param in &func.params {
+            self.declare(&param.name, BindingKind::Local);
+        }
+        self.block(&func.body);
+        self.scopes.pop();
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.scopes.push(HashMap::new());
+        for stmt in &block.statements {
+            if let Stmt::FunctionDecl(func) = stmt {
+                self.hoist(&func.name, BindingKind::Local);
+            }
+        }
+        for stmt in &block.statements {
+            self.stmt(stmt);
+        }
+        self.scopes.pop();
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::VarDecl(var) => {
+                self.expr(&var.init);
+                let kind = self.local_kind();
+                self.declare(&var.name, kind);
+            }
+            Stmt::FunctionDecl(func) => self.function(func),
+            Stmt::Assign(assign) => {
+                self.assign_target(&assign.target);
+                self.expr(&assign.value);
+            }
+            Stmt::CompoundAssign(assign) => {
+                self.assign_target(&assign.target);
+                self.expr(&assign.value);
+            }
+            Stmt::Increment(inc) => self.assign_target(&inc.target),
+            Stmt::Decrement(dec) => self.assign_target(&dec.target),
+            Stmt::If(if_stmt) => {
+                self.expr(&if_stmt.cond);
+                self.block(&if_stmt.then_block);
+                if let Some(else_block) = &if_stmt.else_block {
+                    self.block(else_block);
+                }
+            }
+            Stmt::While(while_stmt) => {
+                self.expr(&while_stmt.cond);
+                self.block(&while_stmt.body);
+            }
+            Stmt::For(for_stmt) => {
+                self.scopes.push(HashMap::new());
+                self.stmt(&for_stmt.init);
+                self.expr(&for_stmt.cond);
+                self.stmt(&for_stmt.step);
+                self.block(&for_stmt.body);
+                self.scopes.pop();
+            }
+            Stmt::ForIn(for_in) => {
+                self.expr(&for_in.iterable);
+                self.scopes.push(HashMap::new());
+                self.declare(&for_in.variable, BindingKind::Local);
+                self.block(&for_in.body);
+                self.scopes.pop();
+            }
+            Stmt::TryCatch(try_catch) => {
+                self.block(&try_catch.body);
+                self.scopes.push(HashMap::new());
+                self.declare(&try_catch.error, BindingKind::Local);
+                self.block(&try_catch.handler);
+                self.scopes.pop();
+            }
+            Stmt::Return(ret) => {
+                if let Some(value) = &ret.value {
+                    self.expr(value);
+                }
+            }
+            Stmt::Expr(expr_stmt) => self.expr(&expr_stmt.expr),
+            Stmt::Break(_) | Stmt::Continue(_) => {}
+        }
+    }
+
+    fn assign_target(&mut self, target: &AssignTarget) {
+        match target {
+            AssignTarget::Name(id) => self.occurrence(id, true),
+            AssignTarget::Index { target, index, .. } => {
+                self.expr(target);
+                self.expr(index);
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Identifier(id) => self.reference(id),
+            Expr::Unary(unary) => self.expr(&unary.expr),
+            Expr::Binary(binary) => {
+                self.expr(&binary.left);
+                self.expr(&binary.right);
+            }
+            Expr::Call(call) => {
+                self.expr(&call.callee);
+                for arg in &call.args {
+                    self.expr(arg);
+                }
+            }
+            Expr::Index(index) => {
+                self.expr(&index.target);
+                self.expr(&index.index);
+            }
+            Expr::Member(member) => {
+                self.expr(&member.target);
+                for arg in member.args.iter().flatten() {
+                    self.expr(arg);
+                }
+            }
+            Expr::ArrayLiteral(array) => {
+                for elem in &array.elements {
+                    self.expr(elem);
+                }
+            }
+            Expr::MapLiteral(map) => {
+                for entry in &map.entries {
+                    self.expr(&entry.value);
+                }
+            }
+            Expr::StringInterp(interp) => {
+                for part in &interp.parts {
+                    if let StringInterpPart::Expr(expr) = part {
+                        self.expr(expr);
+                    }
+                }
+            }
+            Expr::Group(group) => self.expr(&group.expr),
+            Expr::Match(match_expr) => {
+                self.expr(&match_expr.scrutinee);
+                for arm in &match_expr.arms {
+                    self.scopes.push(HashMap::new());
+                    self.pattern(&arm.pattern, false);
+                    if let Some(guard) = &arm.guard {
+                        self.expr(guard);
+                    }
+                    self.expr(&arm.body);
+                    self.scopes.pop();
+                }
+            }
+            Expr::Try(try_expr) => self.expr(&try_expr.expr),
+            Expr::Literal(_, _) => {}
+        }
+    }
+
+    /// Bind pattern variables. Alternatives after the first in an OR pattern
+    /// refer to the bindings introduced by the first.
+    fn pattern(&mut self, pattern: &Pattern, rebinding: bool) {
+        match pattern {
+            Pattern::Literal(_, _) | Pattern::Wildcard(_) => {}
+            Pattern::Variable(id) => {
+                if rebinding {
+                    self.reference(id);
+                } else {
+                    self.declare(id, BindingKind::Local);
+                }
+            }
+            Pattern::Constructor { args, .. } => {
+                for arg in args {
+                    self.pattern(arg, rebinding);
+                }
+            }
+            Pattern::Array { elements, .. } => {
+                for elem in elements {
+                    self.pattern(elem, rebinding);
+                }
+            }
+            Pattern::Or(alternatives, _) => {
+                for (i, alt) in alternatives.iter().enumerate() {
+                    self.pattern(alt, rebinding || i > 0);
+                }
+            }
+        }
+    }
+}
@@ -2,14 +2,21 @@
 //!
 //! Provides textDocument/references implementation for the LSP server.
 
-use crate::index::{position_to_offset, SymbolIndex};
+use crate::index::{position_to_offset, span_to_range, SymbolIndex};
 use crate::navigation::find_identifier_at_position;
+use crate::refactor::scope::ScopeResolver;
 use atlas_runtime::ast::Program;
 use atlas_runtime::symbol::SymbolTable;
+use std::collections::HashSet;
 use tower_lsp::lsp_types::{Location, Position, Url};
 
 /// Find all references to the symbol at the given position
 ///
+/// When the symbol is declared in the document, references are resolved with
+/// the binder's scoping rules and limited to the document, so shadowed
+/// bindings with the same name are excluded. Other names fall back to a
+/// name-based search of the workspace index.
+///
 /// # Arguments
 /// * `uri` - URI of the document
 /// * `text` - Source text of the document
@@ -30,6 +37,12 @@ pub fn find_all_references(
     index: &SymbolIndex,
     include_declaration: bool,
 ) -> Option<Vec<Location>> {
+    if let Some(locations) =
+        ast.and_then(|ast| scoped_references(uri, text, position, ast, include_declaration))
+    {
+        return (!locations.is_empty()).then_some(locations);
+    }
+
     // Find the identifier at the cursor position
     let identifier = find_identifier_at_position(text, position)?;
 
@@ -45,6 +58,38 @@ pub fn find_all_references(
     Some(locations)
 }
 
+/// References to the binding the identifier at `position` resolves to, or
+/// None if it is not declared in this document
+fn scoped_references(
+    uri: &Url,
+    text: &str,
+    position: Position,
+    ast: &Program,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let unchanged = HashSet::new();
+    let resolver = ScopeResolver::resolve(ast, &unchanged, "");
+
+    let offset = position_to_offset(position, text);
+    let decl = resolver
+        .occurrences
+        .iter()
+        .find(|occ| occ.span.start <= offset && offset <= occ.span.end)?
+        .decl?;
+
+    Some(
+        resolver
+            .occurrences
+            .iter()
+            .filter(|occ| occ.decl == Some(decl) && (include_declaration || occ.span != decl))
+            .map(|occ| Location {
+                uri: uri.clone(),
+                range: span_to_range(&occ.span, text),
+            })
+            .collect(),
+    )
+}
+
 /// Fallback implementation that searches only the current file
 ///
 /// Used when the workspace index doesn't have results (e.g., file just opened)
@@ -203,6 +248,11 @@ fn is_definition_in_stmt(stmt: &atlas_runtime::ast::Stmt, identifier: &str, offs
                 || is_definition_in_stmt(&for_stmt.step, identifier, offset)
                 || is_definition_in_block(&for_stmt.body, identifier, offset)
         }
+        Stmt::TryCatch(try_catch) => {
+            is_definition_in_block(&try_catch.body, identifier, offset)
+                || try_catch.error.name == identifier && try_catch.error.span.contains(offset)
+                || is_definition_in_block(&try_catch.handler, identifier, offset)
+        }
         _ => false,
     }
 }
@@ -4,6 +4,7 @@
 //! Tokens are classified by type (variable, function, keyword, etc.) and
 //! modified by attributes (declaration, readonly, deprecated, etc.).
 
+use crate::refactor::scope::ScopeResolver;
 use atlas_runtime::ast::*;
 use atlas_runtime::symbol::SymbolTable;
 use atlas_runtime::token::{Token, TokenKind};
@@ -59,6 +60,7 @@ mod token_type_idx {
     pub const PARAMETER: u32 = 7;
     pub const VARIABLE: u32 = 8;
     pub const PROPERTY: u32 = 9;
+    pub const ENUM_MEMBER: u32 = 10;
     pub const FUNCTION: u32 = 12;
     pub const KEYWORD: u32 = 15;
     pub const COMMENT: u32 = 17;
@@ -152,12 +154,18 @@ fn tokenize_document(
     let declarations = collect_declarations(ast);
     let parameters = collect_parameters(ast);
     let builtins = get_builtin_names();
+    let bound = collect_bound_identifiers(ast);
 
     // Classify each token
     for token in &tokens {
-        if let Some(semantic) =
-            classify_token(token, &declarations, &parameters, &builtins, symbols)
-        {
+        if let Some(semantic) = classify_token(
+            token,
+            &declarations,
+            &parameters,
+            &builtins,
+            &bound,
+            symbols,
+        ) {
             semantic_tokens.push(semantic);
         }
     }
@@ -171,6 +179,7 @@ fn classify_token(
     declarations: &std::collections::HashSet<String>,
     parameters: &std::collections::HashSet<String>,
     builtins: &std::collections::HashSet<&str>,
+    bound: &std::collections::HashSet<usize>,
     symbols: Option<&SymbolTable>,
 ) -> Option<SemanticTokenInfo> {
     let (token_type, modifiers) = match token.kind {
@@ -187,6 +196,8 @@ fn classify_token(
         | TokenKind::Return
         | TokenKind::Break
         | TokenKind::Continue
+        | TokenKind::Try
+        | TokenKind::Catch
         | TokenKind::Import
         | TokenKind::Export
         | TokenKind::From
@@ -206,7 +217,10 @@ fn classify_token(
 
         // Literals
         TokenKind::Number => (token_type_idx::NUMBER, 0),
-        TokenKind::String => (token_type_idx::STRING, 0),
+        TokenKind::String
+        | TokenKind::StringInterpStart
+        | TokenKind::StringInterpMiddle
+        | TokenKind::StringInterpEnd => (token_type_idx::STRING, 0),
 
         // Comments
         TokenKind::LineComment | TokenKind::BlockComment => (token_type_idx::COMMENT, 0),
@@ -229,6 +243,7 @@ fn classify_token(
         | TokenKind::PipePipe
         | TokenKind::Ampersand
         | TokenKind::Pipe
+        | TokenKind::PipeGreater
         | TokenKind::PlusEqual
         | TokenKind::MinusEqual
         | TokenKind::StarEqual
@@ -245,8 +260,21 @@ fn classify_token(
         TokenKind::Identifier => {
             let name = &token.lexeme;
 
+            // Names bound in the document shadow constructors and builtins
+            let shadowed = bound.contains(&token.span.start);
+
+            // Check if it's an Option/Result constructor
+            if !shadowed && CONSTRUCTORS.contains(&name.as_str()) {
+                return Some(SemanticTokenInfo {
+                    start: token.span.start,
+                    length: token.span.len(),
+                    token_type: token_type_idx::ENUM_MEMBER,
+                    modifiers: token_modifier_bits::DEFAULT_LIBRARY,
+                });
+            }
+
             // Check if it's a builtin
-            if builtins.contains(name.as_str()) {
+            if !shadowed && builtins.contains(name.as_str()) {
                 return Some(SemanticTokenInfo {
                     start: token.span.start,
                     length: token.span.len(),
@@ -307,8 +335,8 @@ fn classify_token(
                 });
             }
 
-            // Check if name looks like a type (PascalCase)
-            if is_type_name(name) {
+            // Check if name looks like a type (primitive or PascalCase)
+            if PRIMITIVE_TYPES.contains(&name.as_str()) || is_type_name(name) {
                 return Some(SemanticTokenInfo {
                     start: token.span.start,
                     length: token.span.len(),
@@ -386,96 +414,34 @@ fn collect_parameters(ast: Option<&Program>) -> std::collections::HashSet<String
     params
 }
 
-/// Get builtin function names
+/// Option and Result constructors, highlighted as enum members
+const CONSTRUCTORS: &[&str] = &["Some", "None", "Ok", "Err"];
+
+/// Primitive type names, which the lexer reports as identifiers
+const PRIMITIVE_TYPES: &[&str] = &["number", "int", "string", "bool", "void", "null", "json"];
+
+/// Get builtin function names from the runtime's builtin registry
 fn get_builtin_names() -> std::collections::HashSet<&'static str> {
-    [
-        // I/O
-        "print",
-        "println",
-        "input",
-        // Type conversion
-        "string",
-        "number",
-        "bool",
-        "int",
-        // Type checking
-        "typeof",
-        "is_number",
-        "is_string",
-        "is_bool",
-        "is_null",
-        "is_array",
-        "is_function",
-        // Collections
-        "len",
-        "push",
-        "pop",
-        "shift",
-        "unshift",
-        "slice",
-        "concat",
-        "reverse",
-        "sort",
-        "map",
-        "filter",
-        "reduce",
-        "find",
-        "every",
-        "some",
-        "includes",
-        "index_of",
-        "join",
-        "flat",
-        // String
-        "split",
-        "trim",
-        "to_upper",
-        "to_lower",
-        "starts_with",
-        "ends_with",
-        "contains",
-        "replace",
-        "char_at",
-        "substring",
-        "pad_start",
-        "pad_end",
-        "repeat",
-        // Math
-        "abs",
-        "floor",
-        "ceil",
-        "round",
-        "sqrt",
-        "pow",
-        "min",
-        "max",
-        "sin",
-        "cos",
-        "tan",
-        "log",
-        "log10",
-        "exp",
-        "random",
-        "random_range",
-        // HashMap
-        "keys",
-        "values",
-        "entries",
-        "has_key",
-        "remove",
-        // Assertions
-        "assert",
-        "assert_eq",
-        "assert_ne",
-        // Time
-        "time",
-        "sleep",
-        // Error handling
-        "error",
-        "try_catch",
-    ]
-    .into_iter()
-    .collect()
+    atlas_runtime::stdlib::builtin_names()
+        .into_iter()
+        .filter(|name| !CONSTRUCTORS.contains(name))
+        .collect()
+}
+
+/// Start offsets of identifiers that resolve to a declaration in the document
+fn collect_bound_identifiers(ast: Option<&Program>) -> std::collections::HashSet<usize> {
+    let program = match ast {
+        Some(program) => program,
+        None => return std::collections::HashSet::new(),
+    };
+
+    let unchanged = std::collections::HashSet::new();
+    ScopeResolver::resolve(program, &unchanged, "")
+        .occurrences
+        .iter()
+        .filter(|occ| occ.decl.is_some())
+        .map(|occ| occ.span.start)
+        .collect()
 }
 
 /// Check if a name looks like a type (PascalCase)
@@ -561,6 +527,12 @@ fn offset_to_position(line_offsets: &[usize], offset: usize) -> (u32, u32) {
 mod tests {
     use super::*;
 
+    fn parse_for_tokens(text: &str) -> Option<Program> {
+        let (tokens, _) = Lexer::new(text).tokenize();
+        let (program, _) = atlas_runtime::Parser::new(tokens).parse();
+        Some(program)
+    }
+
     #[test]
     fn test_get_legend() {
         let legend = get_legend();
@@ -649,6 +621,41 @@ mod tests {
         assert!(print_token.unwrap().modifiers & token_modifier_bits::DEFAULT_LIBRARY != 0);
     }
 
+    #[test]
+    fn test_tokenize_constructors_and_builtins() {
+        let text =
+            "fn f(n: number) -> number { return n; }\nlet xs = [1, 2];\nlet x = Some(map(xs, f));";
+        let ast = parse_for_tokens(text);
+        let tokens = tokenize_document(text, ast.as_ref(), None);
+        let token_at = |needle: &str| {
+            let start = text.rfind(needle).unwrap();
+            tokens.iter().find(|t| t.start == start).unwrap()
+        };
+
+        let some = token_at("Some");
+        assert_eq!(some.token_type, token_type_idx::ENUM_MEMBER);
+
+        let map = token_at("map");
+        assert_eq!(map.token_type, token_type_idx::FUNCTION);
+        assert!(map.modifiers & token_modifier_bits::DEFAULT_LIBRARY != 0);
+
+        let f = token_at("f)");
+        assert_eq!(f.token_type, token_type_idx::FUNCTION);
+        assert_eq!(f.modifiers & token_modifier_bits::DEFAULT_LIBRARY, 0);
+    }
+
+    #[test]
+    fn test_tokenize_shadowed_builtin() {
+        let text = "let len = 3;\nlet y = len + 1;";
+        let ast = parse_for_tokens(text);
+        let tokens = tokenize_document(text, ast.as_ref(), None);
+
+        let len = tokens
+            .iter()
+            .find(|t| t.start == text.rfind("len").unwrap());
+        assert_eq!(len.unwrap().token_type, token_type_idx::VARIABLE);
+    }
+
     #[test]
     fn test_tokenize_string() {
         let text = "let s = \"hello\";";
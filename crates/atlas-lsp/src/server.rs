@@ -55,6 +55,12 @@ impl LanguageServer for AtlasLspServer {
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
                 references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Left(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
                     trigger_characters: Some(vec![".".to_string()]),
@@ -335,6 +341,29 @@ impl LanguageServer for AtlasLspServer {
         Ok(None)
     }
 
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        let documents = self.documents.lock().await;
+        if let Some(doc) = documents.get(&uri) {
+            if let Some(ast) = &doc.ast {
+                return crate::refactor::rename_symbol(
+                    &uri,
+                    position,
+                    &doc.text,
+                    ast,
+                    doc.symbols.as_ref(),
+                    &params.new_name,
+                )
+                .map(Some)
+                .map_err(|e| tower_lsp::jsonrpc::Error::invalid_params(e.to_string()));
+            }
+        }
+
+        Ok(None)
+    }
+
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
         let uri = params.text_document_position_params.text_document.uri;
         let position = params.text_document_position_params.position;
@@ -353,6 +382,23 @@ impl LanguageServer for AtlasLspServer {
         Ok(None)
     }
 
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let documents = self.documents.lock().await;
+        if let Some(doc) = documents.get(&uri) {
+            return Ok(crate::signature_help::generate_signature_help(
+                &doc.text,
+                position,
+                doc.ast.as_ref(),
+                doc.symbols.as_ref(),
+            ));
+        }
+
+        Ok(None)
+    }
+
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
         let range = params.range;
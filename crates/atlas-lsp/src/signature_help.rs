@@ -0,0 +1,298 @@
+//! Signature help provider for LSP
+//!
+//! Shows the signature of the function being called at the cursor and
+//! highlights the argument being typed. The call is found by scanning the
+//! source text rather than the AST, so it works while the call is still
+//! incomplete and the document does not parse.
+
+use crate::index::position_to_offset;
+use atlas_runtime::ast::*;
+use atlas_runtime::symbol::SymbolTable;
+use atlas_runtime::types::Type;
+use tower_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, Position, SignatureHelp, SignatureInformation,
+};
+
+/// Generate signature help for the call enclosing `position`
+pub fn generate_signature_help(
+    text: &str,
+    position: Position,
+    ast: Option<&Program>,
+    symbols: Option<&SymbolTable>,
+) -> Option<SignatureHelp> {
+    let offset = position_to_offset(position, text);
+    let (callee, active_parameter) = find_call_at_offset(text, offset)?;
+
+    let signature = ast
+        .and_then(|program| find_function(program, &callee))
+        .map(|func| function_signature(func, text))
+        .or_else(|| symbol_signature(symbols, &callee))?;
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            active_parameter: Some(active_parameter),
+            ..signature
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// Find the name of the function whose argument list contains `offset`, and
+/// the index of the argument at `offset`
+pub fn find_call_at_offset(text: &str, offset: usize) -> Option<(String, u32)> {
+    let offset = offset.min(text.len());
+
+    // Open delimiters before the cursor, with the number of commas seen
+    // directly inside each
+    let mut open: Vec<(char, usize, u32)> = Vec::new();
+    let mut chars = text[..offset].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek().map(|(_, c)| *c) == Some('/') => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek().map(|(_, c)| *c) == Some('*') => {
+                chars.next();
+                let mut prev = ' ';
+                for (_, c) in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '(' | '[' | '{' => open.push((c, i, 0)),
+            ')' | ']' | '}' => {
+                open.pop();
+            }
+            ',' => {
+                if let Some(top) = open.last_mut() {
+                    top.2 += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Arguments may contain array and map literals, but a block ends the
+    // search. A brace opened inside parentheses or brackets is a map literal.
+    let mut in_expr = false;
+    let literal: Vec<bool> = open
+        .iter()
+        .map(|&(c, _, _)| {
+            let is_literal = c == '{' && in_expr;
+            in_expr = c != '{' || is_literal;
+            is_literal
+        })
+        .collect();
+    let (paren, commas) =
+        open.iter()
+            .zip(&literal)
+            .rev()
+            .find_map(|(&(c, i, commas), &is_literal)| match c {
+                '(' => Some(Some((i, commas))),
+                '{' if !is_literal => Some(None),
+                _ => None,
+            })??;
+
+    let before = text[..paren].trim_end();
+    let start = before
+        .rfind(|c: char| !c.is_alphanumeric() && c != '_')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let name = &before[start..];
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    if matches!(name, "if" | "while" | "for" | "match" | "return") {
+        return None;
+    }
+
+    // Skip declarations (`fn name(`) and method calls (`value.name(`)
+    let preceding = before[..start].trim_end();
+    if preceding.ends_with('.') || preceding.ends_with("fn") {
+        return None;
+    }
+
+    Some((name.to_string(), commas))
+}
+
+/// Find a function declared anywhere in the program
+fn find_function<'a>(program: &'a Program, name: &str) -> Option<&'a FunctionDecl> {
+    program.items.iter().find_map(|item| match item {
+        Item::Function(func)
+        | Item::Export(ExportDecl {
+            item: ExportItem::Function(func),
+            ..
+        }) => find_in_function(func, name),
+        Item::Statement(stmt) => find_in_stmt(stmt, name),
+        _ => None,
+    })
+}
+
+fn find_in_function<'a>(func: &'a FunctionDecl, name: &str) -> Option<&'a FunctionDecl> {
+    if func.name.name == name {
+        return Some(func);
+    }
+    find_in_block(&func.body, name)
+}
+
+fn find_in_block<'a>(block: &'a Block, name: &str) -> Option<&'a FunctionDecl> {
+    block
+        .statements
+        .iter()
+        .find_map(|stmt| find_in_stmt(stmt, name))
+}
+
+fn find_in_stmt<'a>(stmt: &'a Stmt, name: &str) -> Option<&'a FunctionDecl> {
+    match stmt {
+        Stmt::FunctionDecl(func) => find_in_function(func, name),
+        Stmt::If(if_stmt) => find_in_block(&if_stmt.then_block, name).or_else(|| {
+            if_stmt
+                .else_block
+                .as_ref()
+                .and_then(|block| find_in_block(block, name))
+        }),
+        Stmt::While(while_stmt) => find_in_block(&while_stmt.body, name),
+        Stmt::For(for_stmt) => find_in_block(&for_stmt.body, name),
+        Stmt::ForIn(for_in) => find_in_block(&for_in.body, name),
+        Stmt::TryCatch(try_catch) => {
+            find_in_block(&try_catch.body, name).or_else(|| find_in_block(&try_catch.handler, name))
+        }
+        _ => None,
+    }
+}
+
+/// Signature of a function declared in the document, with parameter names
+fn function_signature(func: &FunctionDecl, text: &str) -> SignatureInformation {
+    let mut label = format!("fn {}(", func.name.name);
+    let mut parameters = Vec::new();
+    for (i, param) in func.params.iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let start = label.chars().count() as u32;
+        label.push_str(&format!(
+            "{}{}: {}",
+            ownership_prefix(&param.ownership),
+            param.name.name,
+            type_ref_text(&param.type_ref, text)
+        ));
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, label.chars().count() as u32]),
+            documentation: None,
+        });
+    }
+    label.push_str(&format!(") -> {}", type_ref_text(&func.return_type, text)));
+
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: None,
+    }
+}
+
+/// Signature of a builtin or other function known to the symbol table.
+/// Only parameter types are available.
+fn symbol_signature(symbols: Option<&SymbolTable>, name: &str) -> Option<SignatureInformation> {
+    let builtins = SymbolTable::new();
+    let symbol = symbols
+        .and_then(|table| table.lookup(name))
+        .or_else(|| builtins.lookup(name))?;
+    let Type::Function {
+        params,
+        return_type,
+        ..
+    } = &symbol.ty
+    else {
+        return None;
+    };
+
+    let mut label = format!("fn {}(", name);
+    let mut parameters = Vec::new();
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let start = label.chars().count() as u32;
+        label.push_str(&param.display_name());
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, label.chars().count() as u32]),
+            documentation: None,
+        });
+    }
+    label.push_str(&format!(") -> {}", return_type.display_name()));
+
+    Some(SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: None,
+    })
+}
+
+/// Source text of a type annotation. Omitted return types have no source
+/// and fall back to the name the parser gave them.
+fn type_ref_text(type_ref: &TypeRef, text: &str) -> String {
+    let span = type_ref.span();
+    match type_ref {
+        TypeRef::Named(name, _) if span.end == 0 => name.clone(),
+        _ => text[span.start..span.end].to_string(),
+    }
+}
+
+fn ownership_prefix(ownership: &Option<OwnershipAnnotation>) -> &'static str {
+    match ownership {
+        Some(OwnershipAnnotation::Own) => "own ",
+        Some(OwnershipAnnotation::Borrow) => "borrow ",
+        Some(OwnershipAnnotation::Shared) => "shared ",
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_call_skips_strings_and_nested_calls() {
+        let text = r#"f("a, b", g(1, 2), "#;
+        assert_eq!(
+            find_call_at_offset(text, text.len()),
+            Some(("f".to_string(), 2))
+        );
+    }
+
+    #[test]
+    fn test_find_call_through_map_literal() {
+        let text = "f(1, { a: 1, b: ";
+        assert_eq!(
+            find_call_at_offset(text, text.len()),
+            Some(("f".to_string(), 1))
+        );
+    }
+
+    #[test]
+    fn test_find_call_ignores_control_flow_and_blocks() {
+        assert_eq!(find_call_at_offset("if (x", 5), None);
+        assert_eq!(find_call_at_offset("fn f() { x", 10), None);
+        assert_eq!(find_call_at_offset("fn add(a", 8), None);
+    }
+}
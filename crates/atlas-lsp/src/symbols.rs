@@ -373,6 +373,20 @@ fn extract_indexed_symbols(uri: &Url, text: &str, program: &Program) -> Vec<Inde
                         container_name: None,
                     });
                 }
+                ExportItem::ReExport(re_export) => {
+                    for name in &re_export.names {
+                        let range = span_to_range(text, name.span);
+                        symbols.push(IndexedSymbol {
+                            name: name.name.clone(),
+                            kind: SymbolKind::MODULE,
+                            location: Location {
+                                uri: uri.clone(),
+                                range,
+                            },
+                            container_name: Some(re_export.source.clone()),
+                        });
+                    }
+                }
             },
             Item::Trait(_) | Item::Impl(_) => {
                 // Trait/impl symbol extraction handled in Block 3
@@ -467,6 +481,23 @@ fn extract_statement_symbols(
 
             extract_block_symbols(uri, text, &for_in.body, container, symbols);
         }
+        Stmt::TryCatch(try_catch) => {
+            extract_block_symbols(uri, text, &try_catch.body, container, symbols);
+
+            // Error binding
+            let range = span_to_range(text, try_catch.error.span);
+            symbols.push(IndexedSymbol {
+                name: try_catch.error.name.clone(),
+                kind: SymbolKind::VARIABLE,
+                location: Location {
+                    uri: uri.clone(),
+                    range,
+                },
+                container_name: container.map(String::from),
+            });
+
+            extract_block_symbols(uri, text, &try_catch.handler, container, symbols);
+        }
         // Other statements don't introduce new symbols
         _ => {}
     }
@@ -667,6 +698,23 @@ pub fn extract_document_symbols(text: &str, program: &Program) -> Vec<DocumentSy
                         deprecated: None,
                     });
                 }
+                ExportItem::ReExport(re_export) => {
+                    for name in &re_export.names {
+                        let range = span_to_range(text, name.span);
+
+                        #[allow(deprecated)]
+                        symbols.push(DocumentSymbol {
+                            name: name.name.clone(),
+                            detail: Some(format!("export from \"{}\"", re_export.source)),
+                            kind: SymbolKind::MODULE,
+                            range,
+                            selection_range: range,
+                            children: None,
+                            tags: None,
+                            deprecated: None,
+                        });
+                    }
+                }
             },
             _ => {}
         }
@@ -762,6 +810,10 @@ fn extract_block_children(text: &str, block: &Block, children: &mut Vec<Document
             Stmt::ForIn(for_in) => {
                 extract_block_children(text, &for_in.body, children);
             }
+            Stmt::TryCatch(try_catch) => {
+                extract_block_children(text, &try_catch.body, children);
+                extract_block_children(text, &try_catch.handler, children);
+            }
             _ => {}
         }
     }
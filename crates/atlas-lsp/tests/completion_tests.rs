@@ -86,11 +86,66 @@ async fn test_builtin_function_completions() {
         // Should have builtin functions
         assert!(items.iter().any(|item| item.label == "print"));
         assert!(items.iter().any(|item| item.label == "len"));
-        assert!(items.iter().any(|item| item.label == "push"));
+        assert!(items.iter().any(|item| item.label == "split"));
         assert!(items.iter().any(|item| item.label == "pop"));
     }
 }
 
+#[tokio::test]
+async fn test_builtin_completion_filtered_by_prefix_with_snippet() {
+    let (service, _socket) = LspService::new(AtlasLspServer::new);
+    let server = service.inner();
+
+    let uri = Url::parse("file:///test.atl").unwrap();
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "atlas".to_string(),
+            version: 1,
+            text: "let parts = spl".to_string(),
+        },
+    };
+    server.did_open(open_params).await;
+
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position {
+                line: 0,
+                character: 15,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = server.completion(completion_params).await.unwrap();
+    let Some(CompletionResponse::Array(items)) = result else {
+        panic!("expected completion items");
+    };
+
+    let split = items
+        .iter()
+        .find(|item| item.label == "split")
+        .expect("split should be offered for `spl`");
+    assert_eq!(split.kind, Some(CompletionItemKind::FUNCTION));
+    assert_eq!(
+        split.detail.as_deref(),
+        Some("fn(string, string) -> string[]")
+    );
+    assert_eq!(
+        split.insert_text.as_deref(),
+        Some("split(${1:string}, ${2:string})")
+    );
+    assert_eq!(split.insert_text_format, Some(InsertTextFormat::SNIPPET));
+
+    // Builtins that don't match the prefix are filtered out
+    assert!(!items.iter().any(|item| item.label == "print"));
+    assert!(!items.iter().any(|item| item.label == "map"));
+}
+
 #[tokio::test]
 async fn test_type_completions() {
     let (service, _socket) = LspService::new(AtlasLspServer::new);
@@ -374,6 +374,45 @@ fn test_refactor_inline_variable() {
     assert!(has_inline);
 }
 
+#[test]
+fn test_refactor_extract_function_has_edit() {
+    let source = "fn main() {\n    let a = 1;\n    let b = a + 1;\n    print(b);\n}";
+    let uri = create_test_uri();
+
+    // Select `let b = a + 1;`
+    let range = Range {
+        start: Position {
+            line: 2,
+            character: 4,
+        },
+        end: Position {
+            line: 2,
+            character: 18,
+        },
+    };
+
+    let context = create_empty_context();
+    let (ast, symbols, diagnostics) = parse_source(source);
+
+    let actions = generate_code_actions(
+        &uri,
+        range,
+        &context,
+        source,
+        ast.as_ref(),
+        symbols.as_ref(),
+        &diagnostics,
+    );
+
+    let extract = actions.iter().find_map(|a| match a {
+        CodeActionOrCommand::CodeAction(ca) if ca.title == "Extract to function" => Some(ca),
+        _ => None,
+    });
+    let extract = extract.expect("extract function action");
+    assert!(extract.disabled.is_none());
+    assert!(extract.edit.is_some());
+}
+
 #[test]
 fn test_refactor_convert_to_template() {
     // Test with simple string concat pattern
@@ -142,6 +142,35 @@ fn test_nested_blocks_folding() {
     assert!(!region_ranges.is_empty());
 }
 
+#[test]
+fn test_nested_blocks_produce_nested_ranges() {
+    let source = "fn test() {\n  if (true) {\n    while (true) {\n      let x = 1;\n    }\n  }\n}";
+    let ast = parse_source(source);
+    let ranges = generate_folding_ranges(source, Some(&ast));
+
+    let lines: Vec<_> = ranges
+        .iter()
+        .filter(|r| r.kind == Some(FoldingRangeKind::Region))
+        .map(|r| (r.start_line, r.end_line))
+        .collect();
+    assert_eq!(lines, vec![(0, 6), (1, 5), (2, 4)]);
+}
+
+#[test]
+fn test_match_arm_folding() {
+    let source = "fn test(x: number) -> number[] {\n  return match x {\n    1 => [\n      1,\n      2\n    ],\n    _ => [0]\n  };\n}";
+    let ast = parse_source(source);
+    let ranges = generate_folding_ranges(source, Some(&ast));
+
+    let lines: Vec<_> = ranges
+        .iter()
+        .filter(|r| r.kind == Some(FoldingRangeKind::Region))
+        .map(|r| (r.start_line, r.end_line))
+        .collect();
+    assert!(lines.contains(&(1, 7)), "match expression: {lines:?}");
+    assert!(lines.contains(&(2, 5)), "multi-line arm: {lines:?}");
+}
+
 // === Comment Folding ===
 
 #[test]
@@ -159,6 +188,32 @@ fn test_multiline_comment_folding() {
     assert_eq!(comment_ranges[0].end_line, 3);
 }
 
+#[test]
+fn test_block_comment_after_code_folds_full_extent() {
+    let source = "let x = 1; /* starts here\n  continues\n  ends here */\nlet y = 2;";
+    let ast = parse_source(source);
+    let ranges = generate_folding_ranges(source, Some(&ast));
+
+    let comment_ranges: Vec<_> = ranges
+        .iter()
+        .filter(|r| r.kind == Some(FoldingRangeKind::Comment))
+        .collect();
+
+    assert_eq!(comment_ranges.len(), 1);
+    assert_eq!(comment_ranges[0].start_line, 0);
+    assert_eq!(comment_ranges[0].end_line, 2);
+}
+
+#[test]
+fn test_comment_markers_in_strings_no_fold() {
+    let source = "let a = \"/* not\";\nlet b = 1;\nlet c = \"a comment */\";";
+    let ranges = generate_folding_ranges(source, None);
+
+    assert!(!ranges
+        .iter()
+        .any(|r| r.kind == Some(FoldingRangeKind::Comment)));
+}
+
 #[test]
 fn test_consecutive_single_line_comments_folding() {
     let source = "// Line 1\n// Line 2\n// Line 3\nlet x = 1;";
@@ -141,6 +141,43 @@ fn test_skip_obvious_type_literal() {
     assert!(type_hints.is_empty());
 }
 
+#[test]
+fn test_type_hint_for_inferred_let() {
+    let source = r#"let words = split("a b", " ");"#;
+    let (ast, symbols) = parse_source(source);
+
+    let config = InlayHintConfig::default();
+    let hints = generate_inlay_hints(source, full_range(), Some(&ast), Some(&symbols), &config);
+
+    let type_hints: Vec<_> = hints
+        .iter()
+        .filter(|h| h.kind == Some(InlayHintKind::TYPE))
+        .collect();
+    assert_eq!(type_hints.len(), 1);
+    match &type_hints[0].label {
+        tower_lsp::lsp_types::InlayHintLabel::String(label) => assert_eq!(label, ": string[]"),
+        _ => panic!("Expected string label"),
+    }
+    assert_eq!(
+        type_hints[0].position,
+        Position {
+            line: 0,
+            character: 9
+        }
+    );
+}
+
+#[test]
+fn test_no_type_hint_for_annotated_let() {
+    let source = r#"let words: string[] = split("a b", " ");"#;
+    let (ast, symbols) = parse_source(source);
+
+    let config = InlayHintConfig::default();
+    let hints = generate_inlay_hints(source, full_range(), Some(&ast), Some(&symbols), &config);
+
+    assert!(!hints.iter().any(|h| h.kind == Some(InlayHintKind::TYPE)));
+}
+
 #[test]
 fn test_type_hint_position() {
     let source = "fn test() { let x = foo(); }";
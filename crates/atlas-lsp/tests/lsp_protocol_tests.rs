@@ -64,6 +64,8 @@ async fn test_capability_negotiation() {
     assert!(caps.hover_provider.is_some());
     assert!(caps.definition_provider.is_some());
     assert!(caps.references_provider.is_some());
+    assert!(caps.rename_provider.is_some());
+    assert!(caps.signature_help_provider.is_some());
     assert!(caps.completion_provider.is_some());
     assert!(caps.document_formatting_provider.is_some());
     assert!(caps.code_action_provider.is_some());
@@ -113,6 +115,113 @@ async fn test_method_conformance_hover() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_method_conformance_rename() {
+    let (service, _socket) = LspService::new(AtlasLspServer::new);
+    let server = service.inner();
+
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.atl").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "atlas".to_string(),
+                version: 1,
+                text: "fn test() -> number { return 42; }\nlet x = test();".to_string(),
+            },
+        })
+        .await;
+
+    let result = server
+        .rename(RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 0,
+                    character: 4,
+                },
+            },
+            new_name: "answer".to_string(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap()
+        .expect("rename should produce an edit");
+
+    let edits = &result.changes.unwrap()[&uri];
+    assert_eq!(edits.len(), 2);
+
+    // Renaming to a keyword is rejected with an error response
+    let result = server
+        .rename(RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position {
+                    line: 0,
+                    character: 4,
+                },
+            },
+            new_name: "let".to_string(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_method_conformance_signature_help() {
+    let (service, _socket) = LspService::new(AtlasLspServer::new);
+    let server = service.inner();
+
+    server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test.atl").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "atlas".to_string(),
+                version: 1,
+                text:
+                    "fn add(a: number, b: number) -> number { return a + b; }\nlet x = add(1, 2);"
+                        .to_string(),
+            },
+        })
+        .await;
+
+    let result = server
+        .signature_help(SignatureHelpParams {
+            context: None,
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position {
+                    line: 1,
+                    character: 15,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap()
+        .expect("signature help inside a call");
+
+    assert_eq!(
+        result.signatures[0].label,
+        "fn add(a: number, b: number) -> number"
+    );
+    assert_eq!(result.active_parameter, Some(1));
+}
+
 #[tokio::test]
 async fn test_notification_handling_did_open() {
     let (service, _socket) = LspService::new(AtlasLspServer::new);
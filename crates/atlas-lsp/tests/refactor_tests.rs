@@ -226,6 +226,105 @@ fn test_extract_function_rejects_invalid_name() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_extract_function_captures_parameter_and_return_value() {
+    let source =
+        "fn main() {\n    let a = 1;\n    let b = a + 1;\n    let c = b * 2;\n    print(c);\n}";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    // Select the `b` and `c` declarations, which read `a` and produce `c`
+    let range = Range {
+        start: Position {
+            line: 2,
+            character: 0,
+        },
+        end: Position {
+            line: 3,
+            character: 18,
+        },
+    };
+
+    let edit = extract_function(&uri, range, source, &program, None, Some("scale")).unwrap();
+    let edits = &edit.changes.unwrap()[&uri];
+    assert_eq!(edits.len(), 2);
+
+    // The selection is replaced with a call that binds the result
+    assert_eq!(edits[0].range.start, Position::new(2, 4));
+    assert_eq!(edits[0].range.end, Position::new(3, 18));
+    assert_eq!(edits[0].new_text, "let c = scale(a);");
+
+    // The new function is inserted after `main`
+    assert_eq!(edits[1].range.start, Position::new(5, 1));
+    assert_eq!(
+        edits[1].new_text,
+        "\n\nfn scale(a: number) -> number {\n    let b = a + 1;\n    let c = b * 2;\n    return c;\n}"
+    );
+}
+
+#[test]
+fn test_extract_function_rejects_multiple_return_values() {
+    let source = "let a = 1;\nlet b = 2;\nprint(a + b);";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    let range = Range {
+        start: Position {
+            line: 0,
+            character: 0,
+        },
+        end: Position {
+            line: 1,
+            character: 10,
+        },
+    };
+
+    let result = extract_function(&uri, range, source, &program, None, None);
+    assert!(matches!(result, Err(RefactorError::NotImplemented(_))));
+}
+
+#[test]
+fn test_extract_function_rejects_partial_statement() {
+    let source = "let a = 1;\nlet b = a + 2;";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    let range = Range {
+        start: Position {
+            line: 0,
+            character: 0,
+        },
+        end: Position {
+            line: 1,
+            character: 5,
+        },
+    };
+
+    let result = extract_function(&uri, range, source, &program, None, None);
+    assert!(matches!(result, Err(RefactorError::InvalidSelection(_))));
+}
+
+#[test]
+fn test_extract_function_rejects_return() {
+    let source = "fn f(x: number) -> number {\n    let y = x + 1;\n    return y;\n}";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    let range = Range {
+        start: Position {
+            line: 1,
+            character: 0,
+        },
+        end: Position {
+            line: 2,
+            character: 13,
+        },
+    };
+
+    let result = extract_function(&uri, range, source, &program, None, None);
+    assert!(matches!(result, Err(RefactorError::SemanticsViolation(_))));
+}
+
 // ============================================================================
 // Inline Variable Tests
 // ============================================================================
@@ -326,7 +425,7 @@ fn test_rename_variable() {
         character: 4,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "oldName", "newName");
+    let result = rename_symbol(&uri, position, source, &program, None, "newName");
     assert!(result.is_ok());
 }
 
@@ -341,7 +440,7 @@ fn test_rename_function() {
         character: 3,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "oldFunc", "newFunc");
+    let result = rename_symbol(&uri, position, source, &program, None, "newFunc");
     assert!(result.is_ok());
 }
 
@@ -351,12 +450,13 @@ fn test_rename_symbol_not_found() {
     let program = parse_program(source);
     let uri = test_uri();
 
+    // Cursor on the literal, not an identifier
     let position = Position {
         line: 0,
-        character: 4,
+        character: 8,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "unknown", "newName");
+    let result = rename_symbol(&uri, position, source, &program, None, "newName");
     assert!(result.is_err());
 }
 
@@ -371,7 +471,7 @@ fn test_rename_name_conflict() {
         character: 4,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "oldName", "existing");
+    let result = rename_symbol(&uri, position, source, &program, None, "existing");
     assert!(matches!(result, Err(RefactorError::NameConflict(_))));
 }
 
@@ -386,7 +486,7 @@ fn test_rename_rejects_reserved_keyword() {
         character: 4,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "oldName", "let");
+    let result = rename_symbol(&uri, position, source, &program, None, "let");
     assert!(matches!(result, Err(RefactorError::NameConflict(_))));
 }
 
@@ -401,10 +501,84 @@ fn test_rename_rejects_invalid_identifier() {
         character: 4,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "oldName", "123invalid");
+    let result = rename_symbol(&uri, position, source, &program, None, "123invalid");
+    assert!(matches!(result, Err(RefactorError::NameConflict(_))));
+}
+
+#[test]
+fn test_rename_function_and_call_sites() {
+    let source = "fn add(a: number, b: number) -> number { return a + b; }\n\
+                  let x = add(1, 2);\n\
+                  fn twice(n: number) -> number { return add(n, n); }";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    // Cursor on the call in `twice`
+    let position = Position {
+        line: 2,
+        character: 40,
+    };
+
+    let edit = rename_symbol(&uri, position, source, &program, None, "sum").unwrap();
+    let edits = &edit.changes.unwrap()[&uri];
+    let ranges: Vec<(u32, u32)> = edits
+        .iter()
+        .map(|e| (e.range.start.line, e.range.start.character))
+        .collect();
+    assert_eq!(ranges, vec![(0, 3), (1, 8), (2, 39)]);
+    assert!(edits.iter().all(|e| e.new_text == "sum"));
+    assert!(edits
+        .iter()
+        .all(|e| e.range.end.character - e.range.start.character == 3));
+}
+
+#[test]
+fn test_rename_parameter_skips_shadowed_binding() {
+    let source = "fn f(x: number) -> number { return x; }\nlet x = 1;\nlet y = x;";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    let position = Position {
+        line: 0,
+        character: 5,
+    };
+
+    let edit = rename_symbol(&uri, position, source, &program, None, "value").unwrap();
+    let edits = &edit.changes.unwrap()[&uri];
+    assert_eq!(edits.len(), 2);
+    assert!(edits.iter().all(|e| e.range.start.line == 0));
+}
+
+#[test]
+fn test_rename_rejects_shadowing_parameter() {
+    let source = "fn f(a: number) -> number {\n    let b = 1;\n    return a + b;\n}";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    let position = Position {
+        line: 1,
+        character: 8,
+    };
+
+    let result = rename_symbol(&uri, position, source, &program, None, "a");
     assert!(matches!(result, Err(RefactorError::NameConflict(_))));
 }
 
+#[test]
+fn test_rename_rejects_imported_symbol() {
+    let source = "import { helper } from \"./util\";\nlet x = helper();";
+    let program = parse_program(source);
+    let uri = test_uri();
+
+    let position = Position {
+        line: 1,
+        character: 9,
+    };
+
+    let result = rename_symbol(&uri, position, source, &program, None, "other");
+    assert!(matches!(result, Err(RefactorError::SemanticsViolation(_))));
+}
+
 // ============================================================================
 // Workspace Edit Tests
 // ============================================================================
@@ -452,7 +626,7 @@ fn test_workspace_edit_multiple_files() {
         character: 4,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "x", "y");
+    let result = rename_symbol(&uri, position, source, &program, None, "y");
     assert!(result.is_ok());
 
     let workspace_edit = result.unwrap();
@@ -557,7 +731,7 @@ fn test_rename_with_shadowing() {
         character: 4,
     };
 
-    let result = rename_symbol(&uri, position, &program, None, "x", "y");
+    let result = rename_symbol(&uri, position, source, &program, None, "y");
     // Should handle shadowing correctly
     assert!(result.is_ok());
 }
@@ -0,0 +1,119 @@
+//! Signature help provider tests
+//!
+//! Tests for LSP signature help including:
+//! - Active parameter tracking as arguments are typed
+//! - Signatures of document functions and builtins
+//! - Nested and incomplete calls
+
+use atlas_lsp::signature_help::generate_signature_help;
+use atlas_runtime::{Lexer, Parser};
+use tower_lsp::lsp_types::{ParameterLabel, Position, SignatureHelp};
+
+/// Parse source and get AST/symbols for testing
+fn parse_source(
+    source: &str,
+) -> (
+    Option<atlas_runtime::ast::Program>,
+    Option<atlas_runtime::symbol::SymbolTable>,
+) {
+    let mut lexer = Lexer::new(source);
+    let (tokens, _) = lexer.tokenize();
+    let mut parser = Parser::new(tokens);
+    let (ast, _) = parser.parse();
+
+    let mut binder = atlas_runtime::Binder::new();
+    let (symbols, _) = binder.bind(&ast);
+
+    (Some(ast), Some(symbols))
+}
+
+/// Get signature help at a line/character position
+fn signature_help_at(source: &str, line: u32, character: u32) -> Option<SignatureHelp> {
+    let (ast, symbols) = parse_source(source);
+    generate_signature_help(
+        source,
+        Position { line, character },
+        ast.as_ref(),
+        symbols.as_ref(),
+    )
+}
+
+// === Active Parameter Tests ===
+
+#[test]
+fn test_active_parameter_advances_past_commas() {
+    let source = "fn clamp(value: number, low: number, high: number) -> number { return value; }\nlet x = clamp(5, 1, 10);";
+
+    // Cursor positions on line 1: after `(`, after the first `,`, after the second `,`
+    for (character, expected) in [(14, 0), (17, 1), (20, 2)] {
+        let help = signature_help_at(source, 1, character).unwrap();
+        assert_eq!(help.active_parameter, Some(expected), "at {}", character);
+        assert_eq!(help.signatures[0].active_parameter, Some(expected));
+    }
+}
+
+#[test]
+fn test_active_parameter_ignores_commas_in_strings_and_nested_calls() {
+    let source =
+        "fn pair(a: string, b: number) -> string { return a; }\nlet x = pair(\"x, y\", max(1, 2));";
+
+    // Inside the nested `max` call
+    let help = signature_help_at(source, 1, 28).unwrap();
+    assert!(help.signatures[0].label.starts_with("fn max("));
+    assert_eq!(help.active_parameter, Some(1));
+
+    // After the nested call closes, back in `pair`
+    let help = signature_help_at(source, 1, 30).unwrap();
+    assert!(help.signatures[0].label.starts_with("fn pair("));
+    assert_eq!(help.active_parameter, Some(1));
+}
+
+// === Signature Tests ===
+
+#[test]
+fn test_signature_of_document_function() {
+    let source = "fn add(a: number, b: number) -> number { return a + b; }\nadd(1, 2);";
+    let help = signature_help_at(source, 1, 4).unwrap();
+    let signature = &help.signatures[0];
+
+    assert_eq!(signature.label, "fn add(a: number, b: number) -> number");
+    let parameters = signature.parameters.as_ref().unwrap();
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(parameters[1].label, ParameterLabel::LabelOffsets([18, 27]));
+}
+
+#[test]
+fn test_signature_of_builtin_in_incomplete_call() {
+    // The document does not parse while the call is being typed
+    let source = "let parts = split(\"a,b\", ";
+    let help = signature_help_at(source, 0, 25).unwrap();
+
+    assert_eq!(
+        help.signatures[0].label,
+        "fn split(string, string) -> string[]"
+    );
+    assert_eq!(help.active_parameter, Some(1));
+}
+
+#[test]
+fn test_signature_of_map_builtin() {
+    let source = "let doubled = map([1, 2, 3], ";
+    let help = signature_help_at(source, 0, 29).unwrap();
+
+    assert!(help.signatures[0].label.starts_with("fn map("));
+    assert_eq!(help.signatures[0].parameters.as_ref().unwrap().len(), 2);
+    assert_eq!(help.active_parameter, Some(1));
+}
+
+#[test]
+fn test_no_signature_outside_call() {
+    let source = "fn add(a: number, b: number) -> number { return a + b; }\nlet x = add(1, 2);";
+    assert!(signature_help_at(source, 1, 5).is_none());
+    assert!(signature_help_at(source, 1, 18).is_none());
+}
+
+#[test]
+fn test_no_signature_for_unknown_function() {
+    let source = "let x = nothing(1, ";
+    assert!(signature_help_at(source, 0, 19).is_none());
+}
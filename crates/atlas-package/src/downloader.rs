@@ -1,21 +1,72 @@
+use crate::lockfile::LockedPackage;
 use crate::registry::{Registry, RegistryError, RegistryResult};
 use flate2::read::GzDecoder;
 use semver::Version;
 use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tar::Archive;
 
+/// How failed downloads are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (at least 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled after each failure
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `retry` (1-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
 /// Package downloader with checksum verification
 pub struct Downloader {
     cache_dir: PathBuf,
+    retry: RetryPolicy,
 }
 
 impl Downloader {
     pub fn new(cache_dir: PathBuf) -> Self {
         // Ensure cache directory exists
         fs::create_dir_all(&cache_dir).ok();
-        Self { cache_dir }
+        Self {
+            cache_dir,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Set the retry policy for transient network failures
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
     }
 
     /// Get cache directory
@@ -31,14 +82,44 @@ impl Downloader {
         version: &Version,
     ) -> RegistryResult<PathBuf> {
         // Get metadata for checksum
-        let metadata = registry.get_metadata(package, version)?;
+        let metadata = self.with_retries(|| registry.get_metadata(package, version))?;
+        let expected = (!metadata.checksum.is_empty() && metadata.checksum != "mock_checksum")
+            .then_some(metadata.checksum.as_str());
 
-        // Download archive
-        let archive_bytes = registry.download(package, version)?;
+        self.fetch(registry, package, version, expected)
+    }
 
-        // Verify checksum if provided
-        if !metadata.checksum.is_empty() && metadata.checksum != "mock_checksum" {
-            self.verify_checksum(&archive_bytes, &metadata.checksum, package, version)?;
+    /// Download and extract a locked package, verifying its recorded checksum
+    ///
+    /// Falls back to the registry's checksum when the lockfile has none.
+    pub fn download_locked(
+        &self,
+        registry: &dyn Registry,
+        locked: &LockedPackage,
+    ) -> RegistryResult<PathBuf> {
+        match &locked.checksum {
+            Some(checksum) => self.fetch(
+                registry,
+                &locked.name,
+                &locked.version,
+                Some(checksum.as_str()),
+            ),
+            None => self.download_package(registry, &locked.name, &locked.version),
+        }
+    }
+
+    /// Download, verify, and extract into the cache
+    fn fetch(
+        &self,
+        registry: &dyn Registry,
+        package: &str,
+        version: &Version,
+        expected: Option<&str>,
+    ) -> RegistryResult<PathBuf> {
+        let archive_bytes = self.with_retries(|| registry.download(package, version))?;
+
+        if let Some(expected) = expected {
+            self.verify_checksum(&archive_bytes, expected, package, version)?;
         }
 
         // Extract to cache
@@ -48,6 +129,20 @@ impl Downloader {
         Ok(extract_path)
     }
 
+    /// Run `request`, retrying transient failures with exponential backoff
+    fn with_retries<T>(&self, mut request: impl FnMut() -> RegistryResult<T>) -> RegistryResult<T> {
+        let mut attempt = 1;
+        loop {
+            match request() {
+                Err(e) if e.is_transient() && attempt < self.retry.max_attempts => {
+                    std::thread::sleep(self.retry.backoff(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// Verify SHA256 checksum
     fn verify_checksum(
         &self,
@@ -64,6 +159,8 @@ impl Downloader {
             return Err(RegistryError::ChecksumMismatch {
                 package: package.to_string(),
                 version: version.to_string(),
+                expected: expected.to_string(),
+                actual: result,
             });
         }
 
@@ -71,9 +168,18 @@ impl Downloader {
     }
 
     /// Extract tar.gz archive
+    ///
+    /// Unpacks into a staging directory and moves it into place only once
+    /// extraction succeeds, so a truncated archive never leaves a partial
+    /// package in the cache.
     fn extract_archive(&self, data: &[u8], dest: &Path) -> RegistryResult<()> {
-        // Create destination directory
-        fs::create_dir_all(dest)
+        let mut staging = dest.as_os_str().to_owned();
+        staging.push(".partial");
+        let staging = PathBuf::from(staging);
+        let _ = fs::remove_dir_all(&staging);
+
+        // Create staging directory
+        fs::create_dir_all(&staging)
             .map_err(|e| RegistryError::NetworkError(format!("Create dir failed: {}", e)))?;
 
         // Decompress gzip
@@ -81,9 +187,13 @@ impl Downloader {
         let mut archive = Archive::new(decoder);
 
         // Extract tar archive
-        archive
-            .unpack(dest)
-            .map_err(|e| RegistryError::NetworkError(format!("Extract failed: {}", e)))?;
+        if let Err(e) = archive.unpack(&staging) {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(RegistryError::ParseError(format!("Extract failed: {}", e)));
+        }
+
+        let _ = fs::remove_dir_all(dest);
+        fs::rename(&staging, dest)?;
 
         Ok(())
     }
@@ -130,6 +240,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_retry_backoff_doubles_and_caps() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff(10), Duration::from_millis(300));
+    }
+
     #[test]
     fn test_cache_dir_created() {
         let temp_dir = TempDir::new().unwrap();
@@ -15,8 +15,8 @@ pub mod validator;
 
 pub use build_order::{BuildOrderComputer, BuildOrderError, BuildOrderResult};
 pub use cache::PackageCache;
-pub use downloader::Downloader;
-pub use lockfile::{LockedPackage, LockedSource, Lockfile, LockfileMetadata};
+pub use downloader::{Downloader, RetryPolicy};
+pub use lockfile::{Drift, LockedPackage, LockedSource, Lockfile, LockfileMetadata};
 pub use manifest::{
     Dependency, DependencySource, Feature, PackageManifest, VersionConstraint, Workspace,
 };
@@ -25,10 +25,10 @@ pub use registry::{
     RemoteRegistry,
 };
 pub use resolver::{
-    Conflict, ConflictResolver, ConflictingConstraint, DependencyGraph, Resolution,
+    Conflict, ConflictResolver, ConflictingConstraint, DependencyGraph, DependencyStep, Resolution,
     ResolvedPackage, Resolver, ResolverError, ResolverResult, VersionSolver,
 };
-pub use validator::{ValidationError, Validator};
+pub use validator::{ValidationError, ValidationWarning, Validator};
 
 /// Package management errors
 #[derive(Debug, thiserror::Error)]
@@ -56,6 +56,9 @@ pub enum PackageError {
 
     #[error("Invalid field value: {field} - {reason}")]
     InvalidField { field: String, reason: String },
+
+    #[error("Workspace dependency '{name}' {reason}")]
+    WorkspaceDependency { name: String, reason: String },
 }
 
 pub type Result<T> = std::result::Result<T, PackageError>;
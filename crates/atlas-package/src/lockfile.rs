@@ -1,7 +1,11 @@
 //! Package lockfile (atlas.lock) for reproducible builds
 
+use crate::manifest::PackageManifest;
+use semver::VersionReq;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::path::Path;
 
 /// Lockfile structure (atlas.lock)
@@ -94,6 +98,120 @@ impl Lockfile {
 
         Ok(())
     }
+
+    /// Record the hash of the manifest this lockfile was generated from
+    pub fn record_manifest(&mut self, manifest: &PackageManifest) {
+        self.metadata.manifest_hash = Some(manifest_hash(manifest));
+    }
+
+    /// Check that this lockfile still matches `manifest`
+    ///
+    /// Reports dependencies added to the manifest but not locked, locked
+    /// packages no longer reachable from the manifest, and locked versions
+    /// that no longer satisfy the manifest requirement.
+    pub fn verify_against(&self, manifest: &PackageManifest) -> Result<(), Vec<Drift>> {
+        let mut drifts = Vec::new();
+
+        let mut names: Vec<&String> = manifest.dependencies.keys().collect();
+        names.sort();
+        for name in names {
+            let requirement = manifest.dependencies[name]
+                .version_constraint()
+                .unwrap_or("*");
+            let Some(locked) = self.get_package(name) else {
+                drifts.push(Drift::Added {
+                    name: name.clone(),
+                    requirement: requirement.to_string(),
+                });
+                continue;
+            };
+            let satisfied = requirement
+                .parse::<VersionReq>()
+                .is_ok_and(|req| req.matches(&locked.version));
+            if !satisfied {
+                drifts.push(Drift::VersionMismatch {
+                    name: name.clone(),
+                    requirement: requirement.to_string(),
+                    locked: locked.version.clone(),
+                });
+            }
+        }
+
+        // Anything not reachable from a manifest dependency was removed
+        let mut reachable: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = manifest.dependencies.keys().map(String::as_str).collect();
+        while let Some(name) = stack.pop() {
+            if !reachable.insert(name) {
+                continue;
+            }
+            if let Some(locked) = self.get_package(name) {
+                stack.extend(locked.dependencies.keys().map(String::as_str));
+            }
+        }
+        for package in &self.packages {
+            if !reachable.contains(package.name.as_str()) {
+                drifts.push(Drift::Removed {
+                    name: package.name.clone(),
+                });
+            }
+        }
+
+        if drifts.is_empty() {
+            Ok(())
+        } else {
+            Err(drifts)
+        }
+    }
+}
+
+/// Hash of the manifest's dependency requirements, hex-encoded SHA-256
+///
+/// Only `[dependencies]` feeds the hash, so edits elsewhere in the manifest do
+/// not change it.
+pub fn manifest_hash(manifest: &PackageManifest) -> String {
+    let dependencies: BTreeMap<_, _> = manifest.dependencies.iter().collect();
+    let mut hasher = Sha256::new();
+    for (name, dependency) in dependencies {
+        hasher.update(format!("{}={:?}\n", name, dependency));
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// A difference between a manifest and its lockfile
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drift {
+    /// Dependency in the manifest but not in the lockfile
+    Added { name: String, requirement: String },
+    /// Locked package no longer required by the manifest
+    Removed { name: String },
+    /// Locked version does not satisfy the manifest requirement
+    VersionMismatch {
+        name: String,
+        requirement: String,
+        locked: semver::Version,
+    },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Drift::Added { name, requirement } => {
+                write!(f, "{} {} is not in the lockfile", name, requirement)
+            }
+            Drift::Removed { name } => {
+                write!(f, "{} is locked but no longer required", name)
+            }
+            Drift::VersionMismatch {
+                name,
+                requirement,
+                locked,
+            } => write!(
+                f,
+                "{} is locked at {} which does not satisfy {}",
+                name, locked, requirement
+            ),
+        }
+    }
 }
 
 impl Default for Lockfile {
@@ -152,6 +270,9 @@ pub struct LockfileMetadata {
     /// Atlas version used to generate lockfile
     #[serde(skip_serializing_if = "Option::is_none")]
     pub atlas_version: Option<String>,
+    /// Hash of the manifest dependencies the lockfile was generated from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_hash: Option<String>,
 }
 
 #[cfg(test)]
@@ -261,6 +382,103 @@ mod tests {
         assert!(lockfile.verify().is_err());
     }
 
+    fn locked(name: &str, version: &str, dependencies: &[(&str, &str)]) -> LockedPackage {
+        LockedPackage {
+            name: name.to_string(),
+            version: version.parse().unwrap(),
+            source: LockedSource::Registry { registry: None },
+            checksum: None,
+            dependencies: dependencies
+                .iter()
+                .map(|(n, v)| (n.to_string(), v.parse().unwrap()))
+                .collect(),
+        }
+    }
+
+    fn manifest(dependencies: &str) -> PackageManifest {
+        PackageManifest::from_str(&format!(
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\n{}",
+            dependencies
+        ))
+        .unwrap()
+    }
+
+    fn http_lockfile() -> Lockfile {
+        let mut lockfile = Lockfile::new();
+        lockfile.add_package(locked("http", "1.2.0", &[("tls", "2.0.0")]));
+        lockfile.add_package(locked("tls", "2.0.0", &[]));
+        lockfile
+    }
+
+    #[test]
+    fn test_verify_against_clean_match() {
+        let lockfile = http_lockfile();
+        assert_eq!(
+            lockfile.verify_against(&manifest("http = \"^1.0\"")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_against_added_dependency() {
+        let lockfile = http_lockfile();
+        let drifts = lockfile
+            .verify_against(&manifest("http = \"^1.0\"\nws = \"^0.3\""))
+            .unwrap_err();
+        assert_eq!(
+            drifts,
+            vec![Drift::Added {
+                name: "ws".to_string(),
+                requirement: "^0.3".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_verify_against_version_drift() {
+        let lockfile = http_lockfile();
+        let drifts = lockfile
+            .verify_against(&manifest("http = \"^2.0\""))
+            .unwrap_err();
+        assert_eq!(
+            drifts,
+            vec![Drift::VersionMismatch {
+                name: "http".to_string(),
+                requirement: "^2.0".to_string(),
+                locked: semver::Version::new(1, 2, 0),
+            }]
+        );
+        assert!(drifts[0].to_string().contains("does not satisfy ^2.0"));
+    }
+
+    #[test]
+    fn test_verify_against_removed_dependency() {
+        let mut lockfile = http_lockfile();
+        lockfile.add_package(locked("ws", "0.3.0", &[]));
+        let drifts = lockfile
+            .verify_against(&manifest("http = \"^1.0\""))
+            .unwrap_err();
+        assert_eq!(
+            drifts,
+            vec![Drift::Removed {
+                name: "ws".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_record_manifest_hash() {
+        let mut lockfile = Lockfile::new();
+        lockfile.record_manifest(&manifest("http = \"^1.0\""));
+
+        let hash = lockfile.metadata.manifest_hash.clone().unwrap();
+        assert_eq!(hash, manifest_hash(&manifest("http = \"^1.0\"")));
+        assert_ne!(hash, manifest_hash(&manifest("http = \"^2.0\"")));
+
+        let parsed = Lockfile::from_str(&lockfile.to_string().unwrap()).unwrap();
+        assert_eq!(parsed.metadata.manifest_hash, Some(hash));
+    }
+
     #[test]
     fn test_git_source_serialization() {
         let pkg = LockedPackage {
@@ -1,8 +1,9 @@
 //! Package manifest parsing and types (atlas.toml)
 
+use crate::PackageError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Package manifest (atlas.toml)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -32,9 +33,76 @@ impl PackageManifest {
     }
 
     /// Load manifest from file
+    ///
+    /// Dependencies declared with `workspace = true` are resolved against the
+    /// manifest's own `[workspace]` table, or else the nearest ancestor
+    /// workspace manifest.
     pub fn from_file(path: &std::path::Path) -> crate::Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        Ok(Self::from_str(&content)?)
+        let mut manifest = Self::from_str(&content)?;
+
+        if manifest.inherits_workspace() {
+            let dir = path.parent().unwrap_or(Path::new("."));
+            let (root, workspace) = match &manifest.workspace {
+                Some(workspace) => (dir.to_path_buf(), workspace.clone()),
+                None => find_workspace(dir)?.ok_or_else(|| PackageError::InvalidField {
+                    field: "dependencies".to_string(),
+                    reason: "uses `workspace = true` outside of a workspace".to_string(),
+                })?,
+            };
+            manifest.resolve_workspace(&workspace, &root)?;
+        }
+
+        Ok(manifest)
+    }
+
+    /// Check if any dependency inherits from `[workspace.dependencies]`
+    pub fn inherits_workspace(&self) -> bool {
+        self.dependencies
+            .values()
+            .chain(self.dev_dependencies.values())
+            .any(Dependency::is_workspace)
+    }
+
+    /// Replace `workspace = true` dependencies with the workspace's definition
+    ///
+    /// The member may add features, mark the dependency optional, or rename
+    /// it, but may not set its own version or source. Relative workspace paths
+    /// are taken relative to `root`.
+    pub fn resolve_workspace(&mut self, workspace: &Workspace, root: &Path) -> crate::Result<()> {
+        for (name, dep) in self
+            .dependencies
+            .iter_mut()
+            .chain(self.dev_dependencies.iter_mut())
+        {
+            let Dependency::Detailed(member) = dep else {
+                continue;
+            };
+            if member.workspace != Some(true) {
+                continue;
+            }
+
+            let explicit = [
+                ("version", member.version.is_some()),
+                ("git", member.git.is_some()),
+                ("path", member.path.is_some()),
+                ("registry", member.registry.is_some()),
+            ];
+            if let Some((field, _)) = explicit.iter().find(|(_, set)| *set) {
+                return Err(workspace_error(
+                    name,
+                    format!("sets both `workspace = true` and an explicit {}", field),
+                ));
+            }
+
+            let inherited = workspace
+                .dependencies
+                .get(name)
+                .ok_or_else(|| workspace_error(name, "is not in [workspace.dependencies]"))?;
+            *dep = Dependency::Detailed(inherit(inherited, member, root));
+        }
+
+        Ok(())
     }
 
     /// Serialize to TOML string
@@ -83,6 +151,11 @@ impl Dependency {
         }
     }
 
+    /// Check if dependency inherits from `[workspace.dependencies]`
+    pub fn is_workspace(&self) -> bool {
+        matches!(self, Dependency::Detailed(d) if d.workspace == Some(true))
+    }
+
     /// Check if dependency is optional
     pub fn is_optional(&self) -> bool {
         match self {
@@ -93,7 +166,7 @@ impl Dependency {
 }
 
 /// Detailed dependency specification
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct DetailedDependency {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
@@ -117,6 +190,71 @@ pub struct DetailedDependency {
     pub default_features: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "package")]
     pub rename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<bool>,
+}
+
+/// Merge a member's `workspace = true` entry into the workspace definition
+fn inherit(inherited: &Dependency, member: &DetailedDependency, root: &Path) -> DetailedDependency {
+    let mut resolved = match inherited {
+        Dependency::Simple(version) => DetailedDependency {
+            version: Some(version.clone()),
+            ..DetailedDependency::default()
+        },
+        Dependency::Detailed(detailed) => detailed.clone(),
+    };
+    resolved.workspace = None;
+
+    if let Some(path) = resolved.path.as_mut() {
+        if path.is_relative() {
+            *path = root.join(&*path);
+        }
+    }
+
+    // Member features add to the workspace's
+    if let Some(features) = &member.features {
+        let merged = resolved.features.get_or_insert_with(Vec::new);
+        for feature in features {
+            if !merged.contains(feature) {
+                merged.push(feature.clone());
+            }
+        }
+    }
+    if member.optional.is_some() {
+        resolved.optional = member.optional;
+    }
+    if member.default_features.is_some() {
+        resolved.default_features = member.default_features;
+    }
+    if member.rename.is_some() {
+        resolved.rename = member.rename.clone();
+    }
+
+    resolved
+}
+
+/// Find the nearest ancestor of `dir` whose manifest declares a workspace
+fn find_workspace(dir: &Path) -> crate::Result<Option<(PathBuf, Workspace)>> {
+    for ancestor in dir.ancestors().skip(1) {
+        let path = ancestor.join("atlas.toml");
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let value: toml::Value = toml::from_str(&content)?;
+        if let Some(workspace) = value.get("workspace") {
+            let workspace = Workspace::deserialize(workspace.clone())?;
+            return Ok(Some((ancestor.to_path_buf(), workspace)));
+        }
+    }
+    Ok(None)
+}
+
+fn workspace_error(name: &str, reason: impl Into<String>) -> PackageError {
+    PackageError::WorkspaceDependency {
+        name: name.to_string(),
+        reason: reason.into(),
+    }
 }
 
 /// Dependency source type
@@ -334,8 +472,89 @@ mod tests {
             features: None,
             default_features: None,
             rename: None,
+            workspace: None,
         });
 
         assert!(dep.is_optional());
     }
+
+    #[test]
+    fn test_workspace_dependency_inheritance() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("atlas.toml"),
+            r#"
+            [package]
+            name = "root"
+            version = "1.0.0"
+
+            [workspace]
+            members = ["app"]
+
+            [workspace.dependencies]
+            http = { version = "^2.1", features = ["tls"] }
+            json = "1.4"
+        "#,
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("app")).unwrap();
+        let member = dir.path().join("app").join("atlas.toml");
+        std::fs::write(
+            &member,
+            r#"
+            [package]
+            name = "app"
+            version = "0.1.0"
+
+            [dependencies]
+            http = { workspace = true, features = ["gzip"] }
+            json = { workspace = true, optional = true }
+        "#,
+        )
+        .unwrap();
+
+        let manifest = PackageManifest::from_file(&member).unwrap();
+        assert!(!manifest.inherits_workspace());
+
+        let Dependency::Detailed(http) = &manifest.dependencies["http"] else {
+            panic!("expected detailed dependency");
+        };
+        assert_eq!(http.version.as_deref(), Some("^2.1"));
+        assert_eq!(
+            http.features,
+            Some(vec!["tls".to_string(), "gzip".to_string()])
+        );
+
+        let json = &manifest.dependencies["json"];
+        assert_eq!(json.version_constraint(), Some("1.4"));
+        assert!(json.is_optional());
+    }
+
+    #[test]
+    fn test_workspace_dependency_with_explicit_version() {
+        let toml = r#"
+            [package]
+            name = "root"
+            version = "1.0.0"
+
+            [workspace]
+            members = []
+
+            [workspace.dependencies]
+            http = "2.1"
+
+            [dependencies]
+            http = { workspace = true, version = "2.0" }
+        "#;
+
+        let mut manifest = PackageManifest::from_str(toml).unwrap();
+        let workspace = manifest.workspace.clone().unwrap();
+        let err = manifest
+            .resolve_workspace(&workspace, Path::new("."))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PackageError::WorkspaceDependency { ref name, .. } if name == "http"
+        ));
+    }
 }
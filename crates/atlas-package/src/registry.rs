@@ -15,12 +15,20 @@ pub enum RegistryError {
     #[error("Network error: {0}")]
     NetworkError(String),
 
-    #[error("Checksum mismatch for {package}@{version}")]
-    ChecksumMismatch { package: String, version: String },
+    #[error("Checksum mismatch for {package}@{version}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        package: String,
+        version: String,
+        expected: String,
+        actual: String,
+    },
 
     #[error("Registry unavailable: {0}")]
     Unavailable(String),
 
+    #[error("{0} is not in the local mirror and network access is disabled (offline mode)")]
+    Offline(String),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
@@ -30,6 +38,13 @@ pub enum RegistryError {
 
 pub type RegistryResult<T> = Result<T, RegistryError>;
 
+impl RegistryError {
+    /// Check if retrying the request might succeed
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::NetworkError(_) | Self::Unavailable(_))
+    }
+}
+
 /// Package metadata from registry
 #[derive(Debug, Clone, PartialEq)]
 pub struct PackageMetadata {
@@ -65,6 +80,25 @@ impl PackageMetadata {
         self.dependencies = deps;
         self
     }
+
+    /// Check if the metadata carries a checksum (mirrors may omit it)
+    pub fn is_complete(&self) -> bool {
+        !self.checksum.is_empty()
+    }
+
+    /// Fill fields missing here from `other`, keeping what this one has
+    pub fn merge(mut self, other: PackageMetadata) -> Self {
+        if self.checksum.is_empty() {
+            self.checksum = other.checksum;
+        }
+        if self.download_url.is_empty() {
+            self.download_url = other.download_url;
+        }
+        if self.dependencies.is_empty() {
+            self.dependencies = other.dependencies;
+        }
+        self
+    }
 }
 
 /// Registry trait - implemented by remote, local, git registries
@@ -77,13 +111,23 @@ pub trait Registry: Send + Sync {
 
     /// Download package archive
     fn download(&self, package: &str, version: &Version) -> RegistryResult<Vec<u8>>;
+
+    /// Check if this registry needs network access
+    fn is_network(&self) -> bool {
+        false
+    }
 }
 
 /// Registry manager - handles multiple registry sources
+///
+/// Registries are consulted in order, so a local mirror added with
+/// `add_mirror` answers first and the network is only used on a miss. In
+/// offline mode, reaching a network registry is an error instead.
 #[derive(Default)]
 pub struct RegistryManager {
     registries: Vec<Box<dyn Registry>>,
     cache_enabled: bool,
+    offline: bool,
 }
 
 impl RegistryManager {
@@ -91,6 +135,7 @@ impl RegistryManager {
         Self {
             registries: Vec::new(),
             cache_enabled: true,
+            offline: false,
         }
     }
 
@@ -99,49 +144,86 @@ impl RegistryManager {
         self.registries.push(registry);
     }
 
+    /// Add a mirror consulted before every registry added so far
+    pub fn add_mirror(&mut self, mirror: Box<dyn Registry>) {
+        self.registries.insert(0, mirror);
+    }
+
     /// Enable or disable caching
     pub fn set_cache_enabled(&mut self, enabled: bool) {
         self.cache_enabled = enabled;
     }
 
+    /// Forbid (or allow) network access
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// Check if network access is forbidden
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
     /// Query all registries for package versions
     pub fn query_versions(&self, package: &str) -> RegistryResult<Vec<Version>> {
-        for registry in &self.registries {
-            if let Ok(versions) = registry.query_versions(package) {
-                return Ok(versions);
-            }
-        }
-        Err(RegistryError::PackageNotFound(package.to_string()))
+        self.first_hit(package, |registry| registry.query_versions(package))
     }
 
     /// Get metadata from first registry that has it
+    ///
+    /// Metadata missing a checksum (typical of mirrors) is completed from the
+    /// registries behind it when online.
     pub fn get_metadata(
         &self,
         package: &str,
         version: &Version,
     ) -> RegistryResult<PackageMetadata> {
+        let id = format!("{}@{}", package, version);
+        let mut found: Option<PackageMetadata> = None;
+
         for registry in &self.registries {
+            if found.as_ref().is_some_and(PackageMetadata::is_complete) {
+                break;
+            }
+            if registry.is_network() && self.offline {
+                if found.is_some() {
+                    break;
+                }
+                return Err(RegistryError::Offline(id));
+            }
             if let Ok(metadata) = registry.get_metadata(package, version) {
-                return Ok(metadata);
+                found = Some(match found {
+                    Some(existing) => existing.merge(metadata),
+                    None => metadata,
+                });
             }
         }
-        Err(RegistryError::PackageNotFound(format!(
-            "{}@{}",
-            package, version
-        )))
+
+        found.ok_or(RegistryError::PackageNotFound(id))
     }
 
     /// Download from first registry that has the package
     pub fn download(&self, package: &str, version: &Version) -> RegistryResult<Vec<u8>> {
+        self.first_hit(&format!("{}@{}", package, version), |registry| {
+            registry.download(package, version)
+        })
+    }
+
+    /// Run `request` against each registry in order until one succeeds
+    fn first_hit<T>(
+        &self,
+        id: &str,
+        request: impl Fn(&dyn Registry) -> RegistryResult<T>,
+    ) -> RegistryResult<T> {
         for registry in &self.registries {
-            if let Ok(data) = registry.download(package, version) {
-                return Ok(data);
+            if registry.is_network() && self.offline {
+                return Err(RegistryError::Offline(id.to_string()));
+            }
+            if let Ok(value) = request(registry.as_ref()) {
+                return Ok(value);
             }
         }
-        Err(RegistryError::PackageNotFound(format!(
-            "{}@{}",
-            package, version
-        )))
+        Err(RegistryError::PackageNotFound(id.to_string()))
     }
 
     /// Get number of registered registries
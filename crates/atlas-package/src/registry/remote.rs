@@ -101,6 +101,10 @@ impl Registry for RemoteRegistry {
 
         Ok(bytes)
     }
+
+    fn is_network(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -7,7 +7,7 @@ pub mod conflict;
 mod graph;
 mod version_solver;
 
-pub use conflict::{Conflict, ConflictResolver, ConflictingConstraint};
+pub use conflict::{Conflict, ConflictResolver, ConflictingConstraint, DependencyStep};
 pub use graph::DependencyGraph;
 pub use version_solver::VersionSolver;
 
@@ -92,6 +92,14 @@ impl Resolver {
         package: &str,
         dep: &Dependency,
     ) -> ResolverResult<()> {
+        // Workspace dependencies must be inherited before resolution
+        if dep.is_workspace() {
+            return Err(ResolverError::ResolutionFailed(format!(
+                "Dependency '{}' of '{}' still uses workspace = true; load the manifest with PackageManifest::from_file",
+                package, source
+            )));
+        }
+
         // Get version constraint from dependency
         let version_str = dep.version_constraint().unwrap_or("*");
 
@@ -304,6 +312,7 @@ impl Resolver {
         lockfile.metadata = LockfileMetadata {
             generated_at: Some(chrono::Utc::now().to_rfc3339()),
             atlas_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            manifest_hash: None,
         };
 
         lockfile
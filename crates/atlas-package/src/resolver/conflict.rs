@@ -1,8 +1,9 @@
 //! Conflict detection and resolution for dependency resolution
 
-use super::{ResolverError, VersionConstraint};
+use super::{DependencyGraph, ResolverError, VersionConstraint};
 use semver::{Version, VersionReq};
 use std::collections::HashMap;
+use std::fmt;
 
 /// Conflict information for reporting
 #[derive(Debug, Clone, PartialEq)]
@@ -16,6 +17,25 @@ pub struct Conflict {
 pub struct ConflictingConstraint {
     pub requirement: VersionReq,
     pub source: String, // Which package imposed this constraint
+    /// Dependency path from the root package to the constrained package
+    /// (empty when not explained against a graph)
+    pub path: Vec<DependencyStep>,
+}
+
+/// One package along a dependency path, with the requirement its parent placed on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyStep {
+    pub package: String,
+    pub requirement: Option<VersionReq>,
+}
+
+impl fmt::Display for DependencyStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.requirement {
+            Some(requirement) => write!(f, "{} {}", self.package, requirement),
+            None => write!(f, "{}", self.package),
+        }
+    }
 }
 
 impl Conflict {
@@ -32,10 +52,13 @@ impl Conflict {
         let mut report = format!("Version conflict for package '{}':\n", self.package);
 
         for constraint in &self.constraints {
-            report.push_str(&format!(
-                "  {} requires {}\n",
-                constraint.source, constraint.requirement
-            ));
+            match constraint.explanation() {
+                Some(path) => report.push_str(&format!("  {}\n", path)),
+                None => report.push_str(&format!(
+                    "  {} requires {}\n",
+                    constraint.source, constraint.requirement
+                )),
+            }
         }
 
         report.push_str("\nPossible solutions:\n");
@@ -58,15 +81,22 @@ impl ConflictingConstraint {
         Self {
             requirement,
             source,
+            path: Vec::new(),
         }
     }
 
     /// Create from VersionConstraint
     pub fn from_version_constraint(vc: &VersionConstraint) -> Self {
-        Self {
-            requirement: vc.requirement.clone(),
-            source: vc.source.clone(),
+        Self::new(vc.requirement.clone(), vc.source.clone())
+    }
+
+    /// Render the dependency path, e.g. `root -> http ^1 -> tls ^2`
+    pub fn explanation(&self) -> Option<String> {
+        if self.path.is_empty() {
+            return None;
         }
+        let steps: Vec<String> = self.path.iter().map(ToString::to_string).collect();
+        Some(steps.join(" -> "))
     }
 }
 
@@ -106,6 +136,45 @@ impl ConflictResolver {
         conflicts
     }
 
+    /// Detect conflicts and explain each constraint with its path from `root`
+    ///
+    /// Each intermediate step is labelled with the requirement its parent
+    /// placed on it, looked up in `constraints`.
+    pub fn detect_conflicts_in_graph(
+        &mut self,
+        constraints: &HashMap<String, Vec<VersionConstraint>>,
+        graph: &DependencyGraph,
+        root: &str,
+    ) -> Vec<Conflict> {
+        let mut conflicts = self.detect_conflicts(constraints);
+
+        for conflict in &mut conflicts {
+            for constraint in &mut conflict.constraints {
+                let Some(packages) = graph.find_path(root, &constraint.source) else {
+                    continue;
+                };
+                let mut path: Vec<DependencyStep> = Vec::with_capacity(packages.len() + 1);
+                for (i, package) in packages.iter().enumerate() {
+                    let requirement = i
+                        .checked_sub(1)
+                        .and_then(|parent| requirement_on(constraints, &packages[parent], package));
+                    path.push(DependencyStep {
+                        package: package.clone(),
+                        requirement,
+                    });
+                }
+                path.push(DependencyStep {
+                    package: conflict.package.clone(),
+                    requirement: Some(constraint.requirement.clone()),
+                });
+                constraint.path = path;
+            }
+        }
+
+        self.conflicts = conflicts.clone();
+        conflicts
+    }
+
     /// Check if constraints are compatible
     fn are_constraints_compatible(&self, constraints: &[VersionConstraint]) -> bool {
         if constraints.len() <= 1 {
@@ -207,6 +276,19 @@ impl Default for ConflictResolver {
     }
 }
 
+/// The requirement `source` placed on `package`, if recorded
+fn requirement_on(
+    constraints: &HashMap<String, Vec<VersionConstraint>>,
+    source: &str,
+    package: &str,
+) -> Option<VersionReq> {
+    constraints
+        .get(package)?
+        .iter()
+        .find(|c| c.source == source)
+        .map(|c| c.requirement.clone())
+}
+
 /// Convert a map of version constraints to a conflict error
 pub fn constraints_to_error(package: &str, constraints: &[VersionConstraint]) -> ResolverError {
     let constraint_strings: Vec<String> = constraints
@@ -100,6 +100,42 @@ impl DependencyGraph {
         false
     }
 
+    /// Find the shortest dependency path from `start` to `end` using BFS
+    ///
+    /// The path includes both endpoints. Ties are broken by package name so
+    /// the result is deterministic.
+    pub fn find_path(&self, start: &str, end: &str) -> Option<Vec<String>> {
+        let mut previous: HashMap<&str, &str> = HashMap::new();
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == end {
+                let mut path = vec![end.to_string()];
+                let mut node = end;
+                while let Some(&parent) = previous.get(node) {
+                    path.push(parent.to_string());
+                    node = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            if let Some(deps) = self.edges.get(current) {
+                let mut deps: Vec<&str> = deps.iter().map(String::as_str).collect();
+                deps.sort_unstable();
+                for dep in deps {
+                    if visited.insert(dep) {
+                        previous.insert(dep, current);
+                        queue.push_back(dep);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
     /// Get topological sort (build order) using Kahn's algorithm
     pub fn topological_sort(&self) -> GraphResult<Vec<String>> {
         if self.edges.is_empty() {
@@ -192,6 +228,28 @@ impl Default for DependencyGraph {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_find_path() {
+        let mut graph = DependencyGraph::new();
+        graph.add_edge("root", "http").unwrap();
+        graph.add_edge("http", "tls").unwrap();
+        graph.add_edge("root", "ws").unwrap();
+
+        assert_eq!(
+            graph.find_path("root", "tls"),
+            Some(vec![
+                "root".to_string(),
+                "http".to_string(),
+                "tls".to_string()
+            ])
+        );
+        assert_eq!(
+            graph.find_path("root", "root"),
+            Some(vec!["root".to_string()])
+        );
+        assert_eq!(graph.find_path("tls", "root"), None);
+    }
+
     #[test]
     fn test_new_graph() {
         let graph = DependencyGraph::new();
@@ -1,6 +1,6 @@
 //! Package manifest validation
 
-use crate::manifest::{Dependency, DetailedDependency, PackageManifest};
+use crate::manifest::{Dependency, DetailedDependency, PackageManifest, VersionConstraint};
 use std::collections::{HashMap, HashSet};
 
 /// Validation errors
@@ -57,6 +57,41 @@ impl std::fmt::Display for ValidationError {
 
 impl std::error::Error for ValidationError {}
 
+/// Soft manifest lints: worth fixing, but never fail validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWarning {
+    /// `package.license` is not set
+    MissingLicense,
+    /// `package.description` is not set
+    MissingDescription,
+    /// Dependency pinned to an exact version (`x.y.z` or `=x.y.z`)
+    ExactVersion { name: String, version: String },
+    /// Package name uses `_` where the convention is `-`
+    NonConventionalName(String),
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationWarning::MissingLicense => write!(f, "No license specified"),
+            ValidationWarning::MissingDescription => write!(f, "No description specified"),
+            ValidationWarning::ExactVersion { name, version } => write!(
+                f,
+                "Dependency '{}' is pinned to {}; prefer a range like ^{}",
+                name,
+                version,
+                version.trim_start_matches('=').trim()
+            ),
+            ValidationWarning::NonConventionalName(name) => write!(
+                f,
+                "Package name '{}' should use '-' instead of '_' (e.g. '{}')",
+                name,
+                name.replace('_', "-")
+            ),
+        }
+    }
+}
+
 /// Package manifest validator
 pub struct Validator;
 
@@ -97,6 +132,49 @@ impl Validator {
         }
     }
 
+    /// Lint package manifest for soft issues
+    ///
+    /// Separate from `validate`: warnings never make a manifest invalid.
+    pub fn lint(manifest: &PackageManifest) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        let package = &manifest.package;
+
+        if package.license.as_deref().is_none_or(str::is_empty) {
+            warnings.push(ValidationWarning::MissingLicense);
+        }
+        if package.description.as_deref().is_none_or(str::is_empty) {
+            warnings.push(ValidationWarning::MissingDescription);
+        }
+
+        let mut deps: Vec<_> = manifest
+            .dependencies
+            .iter()
+            .chain(&manifest.dev_dependencies)
+            .collect();
+        deps.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, dep) in deps {
+            if let Some(version) = dep.version_constraint() {
+                let exact = version.trim_start().starts_with('=')
+                    || matches!(
+                        VersionConstraint::parse(version),
+                        Ok(VersionConstraint::Exact(_))
+                    );
+                if exact {
+                    warnings.push(ValidationWarning::ExactVersion {
+                        name: name.clone(),
+                        version: version.to_string(),
+                    });
+                }
+            }
+        }
+
+        if package.name.contains('_') {
+            warnings.push(ValidationWarning::NonConventionalName(package.name.clone()));
+        }
+
+        warnings
+    }
+
     /// Validate package name format
     pub fn validate_package_name(name: &str) -> Result<(), ValidationError> {
         if name.is_empty() {
@@ -205,8 +283,15 @@ impl Validator {
             sources += 1;
         }
 
-        // Must have exactly one source
-        if sources == 0 {
+        // Workspace dependencies take their source from the workspace
+        if dep.workspace == Some(true) {
+            if sources > 0 {
+                errors.push(ValidationError::ConflictingSource {
+                    name: name.to_string(),
+                    reason: "Cannot combine workspace = true with version/git/path".to_string(),
+                });
+            }
+        } else if sources == 0 {
             errors.push(ValidationError::InvalidDependency {
                 name: name.to_string(),
                 reason: "Must specify version, git, or path".to_string(),
@@ -412,6 +497,96 @@ impl Validator {
 mod tests {
     use super::*;
 
+    fn lint_manifest(toml: &str) -> Vec<ValidationWarning> {
+        Validator::lint(&PackageManifest::from_str(toml).unwrap())
+    }
+
+    #[test]
+    fn test_lint_complete_manifest() {
+        let warnings = lint_manifest(
+            r#"
+[package]
+name = "my-package"
+version = "1.0.0"
+description = "Does things"
+license = "MIT"
+
+[dependencies]
+foo = "^1.0"
+bar = "~2.1.0"
+"#,
+        );
+        assert!(warnings.is_empty(), "unexpected warnings: {:?}", warnings);
+    }
+
+    #[test]
+    fn test_lint_missing_license_and_description() {
+        let warnings = lint_manifest(
+            r#"
+[package]
+name = "my-package"
+version = "1.0.0"
+"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                ValidationWarning::MissingLicense,
+                ValidationWarning::MissingDescription
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_exact_versions() {
+        let warnings = lint_manifest(
+            r#"
+[package]
+name = "my-package"
+version = "1.0.0"
+description = "Does things"
+license = "MIT"
+
+[dependencies]
+foo = "=1.2.3"
+bar = "1.0.0"
+baz = ">=1.0"
+
+[dev-dependencies]
+qux = { version = "=0.4.0" }
+"#,
+        );
+        let pinned: Vec<_> = warnings
+            .iter()
+            .map(|w| match w {
+                ValidationWarning::ExactVersion { name, .. } => name.as_str(),
+                other => panic!("unexpected warning: {:?}", other),
+            })
+            .collect();
+        assert_eq!(pinned, vec!["bar", "foo", "qux"]);
+        assert!(warnings[1].to_string().contains("^1.2.3"));
+    }
+
+    #[test]
+    fn test_lint_name_convention() {
+        let warnings = lint_manifest(
+            r#"
+[package]
+name = "my_package"
+version = "1.0.0"
+description = "Does things"
+license = "MIT"
+"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![ValidationWarning::NonConventionalName(
+                "my_package".to_string()
+            )]
+        );
+        assert!(warnings[0].to_string().contains("my-package"));
+    }
+
     #[test]
     fn test_valid_package_names() {
         assert!(Validator::validate_package_name("my-package").is_ok());
@@ -485,6 +660,7 @@ mod tests {
                 features: None,
                 default_features: None,
                 rename: None,
+                workspace: None,
             }),
         );
 
@@ -513,6 +689,7 @@ mod tests {
                 features: None,
                 default_features: None,
                 rename: None,
+                workspace: None,
             }),
         );
 
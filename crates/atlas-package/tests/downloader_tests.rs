@@ -1,5 +1,9 @@
-use atlas_package::{Downloader, PackageCache};
+use atlas_package::registry::{PackageMetadata, Registry, RegistryError, RegistryResult};
+use atlas_package::{Downloader, LockedPackage, LockedSource, PackageCache, RetryPolicy};
 use semver::Version;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
 use tempfile::TempDir;
 
 // ==================================================================
@@ -44,6 +48,152 @@ fn test_downloader_empty_checksum() {
     );
 }
 
+// ==================================================================
+// VERIFIED DOWNLOAD TESTS
+// ==================================================================
+
+/// Registry whose first `failures` downloads fail with a network error
+struct FlakyRegistry {
+    archive: Vec<u8>,
+    failures: u32,
+    attempts: AtomicU32,
+}
+
+impl FlakyRegistry {
+    fn new(failures: u32) -> Self {
+        Self {
+            archive: package_archive(),
+            failures,
+            attempts: AtomicU32::new(0),
+        }
+    }
+
+    fn attempts(&self) -> u32 {
+        self.attempts.load(Ordering::SeqCst)
+    }
+}
+
+impl Registry for FlakyRegistry {
+    fn query_versions(&self, _package: &str) -> RegistryResult<Vec<Version>> {
+        Ok(vec![Version::new(1, 0, 0)])
+    }
+
+    fn get_metadata(&self, package: &str, version: &Version) -> RegistryResult<PackageMetadata> {
+        Ok(PackageMetadata::new(package.to_string(), version.clone()))
+    }
+
+    fn download(&self, _package: &str, _version: &Version) -> RegistryResult<Vec<u8>> {
+        let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        if attempt <= self.failures {
+            return Err(RegistryError::NetworkError("connection reset".to_string()));
+        }
+        Ok(self.archive.clone())
+    }
+}
+
+/// A tar.gz holding a single `mod.atlas`
+fn package_archive() -> Vec<u8> {
+    let content = b"export fn hello() -> string { return \"hi\"; }";
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+
+    let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    builder
+        .append_data(&mut header, "mod.atlas", &content[..])
+        .unwrap();
+    builder.into_inner().unwrap().finish().unwrap()
+}
+
+fn locked_package(checksum: String) -> LockedPackage {
+    LockedPackage {
+        name: "hello".to_string(),
+        version: Version::new(1, 0, 0),
+        source: LockedSource::Registry { registry: None },
+        checksum: Some(checksum),
+        dependencies: HashMap::new(),
+    }
+}
+
+fn fast_retry(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(4),
+    }
+}
+
+#[test]
+fn test_download_locked_checksum_mismatch() {
+    let temp_dir = TempDir::new().unwrap();
+    let downloader = Downloader::new(temp_dir.path().to_path_buf());
+    let registry = FlakyRegistry::new(0);
+
+    let result = downloader.download_locked(&registry, &locked_package("deadbeef".to_string()));
+
+    match result {
+        Err(RegistryError::ChecksumMismatch {
+            package,
+            expected,
+            actual,
+            ..
+        }) => {
+            assert_eq!(package, "hello");
+            assert_eq!(expected, "deadbeef");
+            assert_eq!(actual, Downloader::calculate_checksum(&registry.archive));
+        }
+        other => panic!("Expected checksum mismatch, got {:?}", other),
+    }
+    // Nothing was cached
+    assert!(!temp_dir.path().join("hello").exists());
+}
+
+#[test]
+fn test_download_succeeds_after_two_failures() {
+    let temp_dir = TempDir::new().unwrap();
+    let downloader = Downloader::new(temp_dir.path().to_path_buf()).with_retry(fast_retry(3));
+    let registry = FlakyRegistry::new(2);
+    let checksum = Downloader::calculate_checksum(&registry.archive);
+
+    let path = downloader
+        .download_locked(&registry, &locked_package(checksum))
+        .unwrap();
+
+    assert_eq!(registry.attempts(), 3);
+    assert!(path.join("mod.atlas").exists());
+}
+
+#[test]
+fn test_download_gives_up_after_max_attempts() {
+    let temp_dir = TempDir::new().unwrap();
+    let downloader = Downloader::new(temp_dir.path().to_path_buf()).with_retry(fast_retry(2));
+    let registry = FlakyRegistry::new(5);
+    let checksum = Downloader::calculate_checksum(&registry.archive);
+
+    let result = downloader.download_locked(&registry, &locked_package(checksum));
+
+    assert!(matches!(result, Err(RegistryError::NetworkError(_))));
+    assert_eq!(registry.attempts(), 2);
+    assert!(!temp_dir.path().join("hello").exists());
+}
+
+#[test]
+fn test_truncated_archive_not_cached() {
+    let temp_dir = TempDir::new().unwrap();
+    let downloader = Downloader::new(temp_dir.path().to_path_buf());
+    let mut registry = FlakyRegistry::new(0);
+    registry.archive.truncate(registry.archive.len() / 2);
+
+    let result = downloader.download_package(&registry, "hello", &Version::new(1, 0, 0));
+
+    assert!(result.is_err());
+    let package_dir = temp_dir.path().join("hello");
+    assert!(!package_dir.join("1.0.0").exists());
+    assert!(!package_dir.join("1.0.0.partial").exists());
+}
+
 // ==================================================================
 // CACHE TESTS
 // ==================================================================
@@ -196,6 +196,50 @@ fn test_detect_simple_conflict() {
     assert_eq!(conflicts[0].constraint_count(), 2);
 }
 
+#[test]
+fn test_transitive_conflict_explains_both_paths() {
+    use atlas_package::resolver::VersionConstraint;
+    use atlas_package::DependencyGraph;
+
+    let constraint = |req: &str, source: &str| VersionConstraint {
+        requirement: req.parse().unwrap(),
+        source: source.to_string(),
+    };
+
+    // root -> http ^1 -> tls ^2, and root -> ws ^1 -> tls ^3
+    let mut graph = DependencyGraph::new();
+    graph.add_edge("root", "http").unwrap();
+    graph.add_edge("root", "ws").unwrap();
+    graph.add_edge("http", "tls").unwrap();
+    graph.add_edge("ws", "tls").unwrap();
+
+    let mut constraints = HashMap::new();
+    constraints.insert("http".to_string(), vec![constraint("^1", "root")]);
+    constraints.insert("ws".to_string(), vec![constraint("^1", "root")]);
+    constraints.insert(
+        "tls".to_string(),
+        vec![constraint("^2", "http"), constraint("^3", "ws")],
+    );
+
+    let mut resolver = ConflictResolver::new();
+    let conflicts = resolver.detect_conflicts_in_graph(&constraints, &graph, "root");
+    assert_eq!(conflicts.len(), 1);
+
+    let conflict = &conflicts[0];
+    assert_eq!(conflict.package, "tls");
+    let paths: Vec<String> = conflict
+        .constraints
+        .iter()
+        .map(|c| c.explanation().expect("constraint should be explained"))
+        .collect();
+    assert!(paths.contains(&"root -> http ^1 -> tls ^2".to_string()));
+    assert!(paths.contains(&"root -> ws ^1 -> tls ^3".to_string()));
+
+    let report = conflict.report();
+    assert!(report.contains("root -> http ^1 -> tls ^2"));
+    assert!(report.contains("root -> ws ^1 -> tls ^3"));
+}
+
 #[test]
 fn test_no_conflict_compatible_constraints() {
     use atlas_package::resolver::VersionConstraint;
@@ -1,6 +1,10 @@
+use atlas_package::registry::{RegistryError, RegistryResult};
 use atlas_package::{LocalRegistry, PackageMetadata, Registry, RegistryManager, RemoteRegistry};
 use semver::Version;
 use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 use tempfile::TempDir;
 
 // ==================================================================
@@ -214,6 +218,127 @@ fn test_registry_manager_package_not_found() {
     assert!(result.is_err());
 }
 
+// ==================================================================
+// OFFLINE MIRROR TESTS
+// ==================================================================
+
+/// Network registry that knows every package and counts requests
+struct CountingNetworkRegistry {
+    requests: Arc<AtomicU32>,
+}
+
+impl Registry for CountingNetworkRegistry {
+    fn query_versions(&self, _package: &str) -> RegistryResult<Vec<Version>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        Ok(vec![Version::new(9, 0, 0)])
+    }
+
+    fn get_metadata(&self, package: &str, version: &Version) -> RegistryResult<PackageMetadata> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        Ok(PackageMetadata::new(package.to_string(), version.clone())
+            .with_checksum("remote-checksum".to_string())
+            .with_dependencies(vec!["remote-dep".to_string()]))
+    }
+
+    fn download(&self, _package: &str, _version: &Version) -> RegistryResult<Vec<u8>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        Ok(b"remote archive".to_vec())
+    }
+
+    fn is_network(&self) -> bool {
+        true
+    }
+}
+
+/// Write `package@1.0.0` into a mirror directory
+fn mirror_package(root: &Path, package: &str, metadata: &str) {
+    let version_dir = root.join(package).join("1.0.0");
+    fs::create_dir_all(&version_dir).unwrap();
+    fs::write(version_dir.join("metadata.toml"), metadata).unwrap();
+    fs::write(version_dir.join("package.tar.gz"), b"mirror archive").unwrap();
+}
+
+/// A manager with `mirror` layered over a network registry, and its request count
+fn mirrored_manager(mirror: &Path) -> (RegistryManager, Arc<AtomicU32>) {
+    let requests = Arc::new(AtomicU32::new(0));
+    let mut manager = RegistryManager::new();
+    manager.add_registry(Box::new(CountingNetworkRegistry {
+        requests: requests.clone(),
+    }));
+    manager.add_mirror(Box::new(LocalRegistry::new(mirror.to_path_buf())));
+    (manager, requests)
+}
+
+#[test]
+fn test_offline_resolution_from_mirror() {
+    let temp_dir = TempDir::new().unwrap();
+    mirror_package(temp_dir.path(), "http", "checksum = \"mirror-checksum\"\n");
+    let (mut manager, requests) = mirrored_manager(temp_dir.path());
+    manager.set_offline(true);
+
+    let version = Version::new(1, 0, 0);
+    assert_eq!(
+        manager.query_versions("http").unwrap(),
+        vec![version.clone()]
+    );
+    let metadata = manager.get_metadata("http", &version).unwrap();
+    assert_eq!(metadata.checksum, "mirror-checksum");
+    assert_eq!(
+        manager.download("http", &version).unwrap(),
+        b"mirror archive"
+    );
+
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_offline_missing_package_is_hard_error() {
+    let temp_dir = TempDir::new().unwrap();
+    mirror_package(temp_dir.path(), "http", "checksum = \"mirror-checksum\"\n");
+    let (mut manager, requests) = mirrored_manager(temp_dir.path());
+    manager.set_offline(true);
+
+    let result = manager.query_versions("tls");
+    assert!(matches!(result, Err(RegistryError::Offline(ref p)) if p == "tls"));
+    let result = manager.download("tls", &Version::new(1, 0, 0));
+    assert!(matches!(result, Err(RegistryError::Offline(_))));
+
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_online_mirror_miss_falls_back_to_network() {
+    let temp_dir = TempDir::new().unwrap();
+    let (manager, requests) = mirrored_manager(temp_dir.path());
+
+    assert_eq!(
+        manager.query_versions("tls").unwrap(),
+        vec![Version::new(9, 0, 0)]
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_mirror_metadata_merged_with_network() {
+    let temp_dir = TempDir::new().unwrap();
+    // Mirror entry without a checksum
+    mirror_package(temp_dir.path(), "http", "name = \"http\"\n");
+    let (mut manager, requests) = mirrored_manager(temp_dir.path());
+
+    let version = Version::new(1, 0, 0);
+    let metadata = manager.get_metadata("http", &version).unwrap();
+    assert_eq!(metadata.checksum, "remote-checksum");
+    assert_eq!(metadata.dependencies, vec!["remote-dep".to_string()]);
+    assert!(metadata.download_url.starts_with("file://"));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // Offline, the mirror's partial metadata is returned as-is
+    manager.set_offline(true);
+    let metadata = manager.get_metadata("http", &version).unwrap();
+    assert_eq!(metadata.checksum, "");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
 // ==================================================================
 // PACKAGE METADATA TESTS
 // ==================================================================
@@ -94,8 +94,11 @@ fn type_name(value: &Value) -> &'static str {
         Value::Null => "null",
         Value::Bool(_) => "bool",
         Value::Number(_) => "number",
+        Value::Int(_) => "int",
         Value::String(_) => "string",
         Value::Array(_) => "array",
+        Value::Map(_) => "map",
+        Value::Set(_) => "set",
         Value::Function(_) => "function",
         Value::Builtin(_) => "builtin",
         Value::NativeFunction(_) => "function",
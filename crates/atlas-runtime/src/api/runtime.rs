@@ -19,7 +19,7 @@
 
 use crate::binder::Binder;
 use crate::compiler::Compiler;
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, DiagnosticLevel};
 use crate::interpreter::Interpreter;
 use crate::lexer::Lexer;
 use crate::module_executor::ModuleExecutor;
@@ -307,7 +307,11 @@ impl Runtime {
         let mut type_checker = TypeChecker::new(&mut symbol_table);
         let type_diagnostics = type_checker.check(&ast);
 
-        if !type_diagnostics.is_empty() {
+        // Warnings (unused variables, unreachable code) do not stop evaluation
+        if type_diagnostics
+            .iter()
+            .any(|d| d.level == DiagnosticLevel::Error)
+        {
             return Err(EvalError::TypeError(type_diagnostics));
         }
 
@@ -96,7 +96,7 @@ pub enum ImportSpecifier {
 
 /// Export declaration
 ///
-/// Syntax: `export fn foo()` or `export let x = 5`
+/// Syntax: `export fn foo()`, `export let x = 5`, or `export { x } from "./path"`
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExportDecl {
     /// What is being exported
@@ -113,6 +113,20 @@ pub enum ExportItem {
     Variable(VarDecl),
     /// Export type alias: `export type Foo = bar`
     TypeAlias(TypeAliasDecl),
+    /// Re-export another module's symbols: `export { x, y } from "./path"`
+    ReExport(ReExportDecl),
+}
+
+/// Re-export declaration
+///
+/// Forwards names exported by another module without binding them locally.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReExportDecl {
+    /// Names to forward
+    pub names: Vec<Identifier>,
+    /// Module path (e.g., "./math", "/src/utils")
+    pub source: String,
+    pub span: Span,
 }
 
 /// Extern function declaration (FFI)
@@ -314,8 +328,9 @@ pub enum Stmt {
     For(ForStmt),
     ForIn(ForInStmt),
     Return(ReturnStmt),
-    Break(Span),
-    Continue(Span),
+    Break(BreakStmt),
+    Continue(ContinueStmt),
+    TryCatch(TryCatchStmt),
     Expr(ExprStmt),
 }
 
@@ -393,6 +408,8 @@ pub struct IfStmt {
 /// While loop
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WhileStmt {
+    /// Optional loop label (`outer: while ...`)
+    pub label: Option<Identifier>,
     pub cond: Expr,
     pub body: Block,
     pub span: Span,
@@ -401,6 +418,8 @@ pub struct WhileStmt {
 /// For loop
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ForStmt {
+    /// Optional loop label (`outer: for (...)`)
+    pub label: Option<Identifier>,
     pub init: Box<Stmt>,
     pub cond: Expr,
     pub step: Box<Stmt>,
@@ -413,6 +432,8 @@ pub struct ForStmt {
 /// Syntax: `for item in array { body }`
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ForInStmt {
+    /// Optional loop label (`outer: for x in xs`)
+    pub label: Option<Identifier>,
     /// Loop variable name
     pub variable: Identifier,
     /// Expression to iterate over
@@ -422,6 +443,18 @@ pub struct ForInStmt {
     pub span: Span,
 }
 
+/// Try/catch statement
+///
+/// Syntax: `try { body } catch (error) { handler }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TryCatchStmt {
+    pub body: Block,
+    /// Name bound to the structured error value inside the handler
+    pub error: Identifier,
+    pub handler: Block,
+    pub span: Span,
+}
+
 /// Return statement
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReturnStmt {
@@ -429,6 +462,26 @@ pub struct ReturnStmt {
     pub span: Span,
 }
 
+/// Break statement
+///
+/// Syntax: `break;` or `break label;`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakStmt {
+    /// Label of the enclosing loop to exit (innermost loop if `None`)
+    pub label: Option<Identifier>,
+    pub span: Span,
+}
+
+/// Continue statement
+///
+/// Syntax: `continue;` or `continue label;`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContinueStmt {
+    /// Label of the enclosing loop to continue (innermost loop if `None`)
+    pub label: Option<Identifier>,
+    pub span: Span,
+}
+
 /// Expression statement
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExprStmt {
@@ -447,6 +500,8 @@ pub enum Expr {
     Index(IndexExpr),
     Member(MemberExpr),
     ArrayLiteral(ArrayLiteral),
+    MapLiteral(MapLiteral),
+    StringInterp(StringInterpExpr),
     Group(GroupExpr),
     Match(MatchExpr),
     Try(TryExpr),
@@ -504,12 +559,20 @@ pub struct MemberExpr {
     /// Set by the typechecker, used by the compiler and interpreter for static dispatch.
     #[serde(skip)]
     pub trait_dispatch: std::cell::RefCell<Option<(String, String)>>,
+    /// Extension dispatch info: the user function to call with the receiver as `self`.
+    /// Set by the typechecker, used by the compiler and interpreter for static dispatch.
+    #[serde(skip)]
+    pub extension_dispatch: std::cell::RefCell<Option<String>>,
+    /// Field read on a record value (`e.code`), read from the map holding its fields.
+    /// Set by the typechecker, used by the compiler and interpreter.
+    #[serde(skip)]
+    pub field_access: Cell<bool>,
     pub span: Span,
 }
 
 impl PartialEq for MemberExpr {
     fn eq(&self, other: &Self) -> bool {
-        // type_tag and trait_dispatch are ephemeral annotations — exclude from equality
+        // type_tag, trait_dispatch, extension_dispatch and field_access are ephemeral annotations — exclude from equality
         self.target == other.target
             && self.member == other.member
             && self.args == other.args
@@ -524,6 +587,44 @@ pub struct ArrayLiteral {
     pub span: Span,
 }
 
+/// Map literal expression
+///
+/// Syntax: `{ key: value, "other key": value }` — keys are identifiers or
+/// string literals and always evaluate to strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapLiteral {
+    pub entries: Vec<MapEntry>,
+    pub span: Span,
+}
+
+/// A single `key: value` entry in a map literal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapEntry {
+    pub key: String,
+    pub key_span: Span,
+    pub value: Expr,
+    pub span: Span,
+}
+
+/// Interpolated string expression
+///
+/// Syntax: `"Hello ${name}, you are ${age}"` — evaluates to the concatenation
+/// of the literal chunks and the `toString` of each embedded expression.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StringInterpExpr {
+    pub parts: Vec<StringInterpPart>,
+    pub span: Span,
+}
+
+/// A piece of an interpolated string
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StringInterpPart {
+    /// Literal text (escapes already processed)
+    Literal(String),
+    /// Embedded `${ expr }`
+    Expr(Expr),
+}
+
 /// Grouped expression (parenthesized)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GroupExpr {
@@ -583,16 +684,48 @@ pub enum Pattern {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Literal {
     Number(f64),
+    /// Integer literal with an `i` suffix (42i)
+    Int(i64),
     String(String),
     Bool(bool),
     Null,
 }
 
 /// Identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Identifier {
     pub name: String,
     pub span: Span,
+    /// Read of an `Option`/`Result` binding narrowed by a guard (`is_some(x)`),
+    /// which yields the wrapped payload instead of the wrapper.
+    /// Set by the typechecker, used by the compiler and interpreter.
+    #[serde(skip)]
+    pub unwrap_payload: Cell<Option<PayloadKind>>,
+}
+
+/// The wrapper a narrowed identifier read unwraps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadKind {
+    /// `Some(x)` yields `x`
+    Option,
+    /// `Ok(x)` or `Err(x)` yields `x`
+    Result,
+}
+
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        // unwrap_payload is an ephemeral annotation — exclude from equality
+        self.name == other.name && self.span == other.span
+    }
+}
+
+impl Eq for Identifier {}
+
+impl std::hash::Hash for Identifier {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.span.hash(state);
+    }
 }
 
 /// Type reference
@@ -678,6 +811,8 @@ impl Expr {
             Expr::Index(i) => i.span,
             Expr::Member(m) => m.span,
             Expr::ArrayLiteral(a) => a.span,
+            Expr::MapLiteral(m) => m.span,
+            Expr::StringInterp(s) => s.span,
             Expr::Group(g) => g.span,
             Expr::Match(m) => m.span,
             Expr::Try(t) => t.span,
@@ -700,10 +835,18 @@ impl Stmt {
             Stmt::For(f) => f.span,
             Stmt::ForIn(f) => f.span,
             Stmt::Return(r) => r.span,
-            Stmt::Break(s) | Stmt::Continue(s) => *s,
+            Stmt::Break(s) => s.span,
+            Stmt::Continue(s) => s.span,
+            Stmt::TryCatch(t) => t.span,
             Stmt::Expr(e) => e.span,
         }
     }
+
+    /// Check if this statement unconditionally leaves the enclosing block
+    /// (`return`, `break`, or `continue`)
+    pub fn is_terminator(&self) -> bool {
+        matches!(self, Stmt::Return(_) | Stmt::Break(_) | Stmt::Continue(_))
+    }
 }
 
 impl TypeRef {
@@ -762,6 +905,7 @@ mod tests {
         let ident = Identifier {
             name: "x".to_string(),
             span: Span::new(0, 1),
+            unwrap_payload: Cell::new(None),
         };
         assert_eq!(ident.name, "x");
         assert_eq!(ident.span, Span::new(0, 1));
@@ -790,6 +934,7 @@ mod tests {
             name: Identifier {
                 name: "x".to_string(),
                 span: Span::new(4, 5),
+                unwrap_payload: Cell::new(None),
             },
             type_ref: Some(TypeRef::Named("number".to_string(), Span::new(7, 13))),
             init: Expr::Literal(Literal::Number(42.0), Span::new(16, 18)),
@@ -818,6 +963,7 @@ mod tests {
             name: Identifier {
                 name: "data".to_string(),
                 span: Span::new(0, 4),
+                unwrap_payload: Cell::new(None),
             },
             type_ref: TypeRef::Named("number".to_string(), Span::new(6, 12)),
             ownership: Some(OwnershipAnnotation::Own),
@@ -832,6 +978,7 @@ mod tests {
             name: Identifier {
                 name: "x".to_string(),
                 span: Span::new(0, 1),
+                unwrap_payload: Cell::new(None),
             },
             type_ref: TypeRef::Named("number".to_string(), Span::new(3, 9)),
             ownership: None,
@@ -846,6 +993,7 @@ mod tests {
             name: Identifier {
                 name: "test".to_string(),
                 span: Span::new(5, 9),
+                unwrap_payload: Cell::new(None),
             },
             type_params: vec![],
             params: vec![],
@@ -899,6 +1047,7 @@ mod tests {
             callee: Box::new(Expr::Identifier(Identifier {
                 name: "print".to_string(),
                 span: Span::new(0, 5),
+                unwrap_payload: Cell::new(None),
             })),
             args: vec![Expr::Literal(
                 Literal::String("hello".to_string()),
@@ -912,10 +1061,16 @@ mod tests {
 
     #[test]
     fn test_stmt_span() {
-        let stmt = Stmt::Break(Span::new(0, 5));
+        let stmt = Stmt::Break(BreakStmt {
+            label: None,
+            span: Span::new(0, 5),
+        });
         assert_eq!(stmt.span(), Span::new(0, 5));
 
-        let stmt2 = Stmt::Continue(Span::new(10, 18));
+        let stmt2 = Stmt::Continue(ContinueStmt {
+            label: None,
+            span: Span::new(10, 18),
+        });
         assert_eq!(stmt2.span(), Span::new(10, 18));
     }
 
@@ -1090,12 +1245,14 @@ mod tests {
             name: Identifier {
                 name: "Display".to_string(),
                 span: Span::new(6, 13),
+                unwrap_payload: Cell::new(None),
             },
             type_params: vec![],
             methods: vec![TraitMethodSig {
                 name: Identifier {
                     name: "display".to_string(),
                     span: Span::new(20, 27),
+                    unwrap_payload: Cell::new(None),
                 },
                 type_params: vec![],
                 params: vec![],
@@ -1116,11 +1273,13 @@ mod tests {
             trait_name: Identifier {
                 name: "Display".to_string(),
                 span: Span::new(5, 12),
+                unwrap_payload: Cell::new(None),
             },
             trait_type_args: vec![],
             type_name: Identifier {
                 name: "Buffer".to_string(),
                 span: Span::new(17, 23),
+                unwrap_payload: Cell::new(None),
             },
             methods: vec![],
             span: Span::new(0, 30),
@@ -1171,6 +1330,7 @@ mod tests {
             name: Identifier {
                 name: "Foo".to_string(),
                 span: Span::new(6, 9),
+                unwrap_payload: Cell::new(None),
             },
             type_params: vec![],
             methods: vec![],
@@ -1186,11 +1346,13 @@ mod tests {
             trait_name: Identifier {
                 name: "Foo".to_string(),
                 span: Span::new(5, 8),
+                unwrap_payload: Cell::new(None),
             },
             trait_type_args: vec![],
             type_name: Identifier {
                 name: "Bar".to_string(),
                 span: Span::new(13, 16),
+                unwrap_payload: Cell::new(None),
             },
             methods: vec![],
             span: Span::new(0, 19),
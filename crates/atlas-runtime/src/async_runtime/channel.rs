@@ -68,7 +68,7 @@ impl ChannelReceiver {
         let future_clone = future.clone();
 
         // Spawn task to wait for message
-        tokio::task::spawn_local(async move {
+        future.spawn_settler(async move {
             let mut rx = receiver.lock().await;
             match rx.recv().await {
                 Some(value) => future_clone.resolve(value),
@@ -156,7 +156,7 @@ pub fn channel_select(receivers: Vec<ChannelReceiver>) -> AtlasFuture {
     let future = AtlasFuture::new_pending();
     let future_clone = future.clone();
 
-    tokio::task::spawn_local(async move {
+    future.spawn_settler(async move {
         let mut receivers_locked = Vec::new();
         for receiver in receivers {
             receivers_locked.push(receiver.inner.clone());
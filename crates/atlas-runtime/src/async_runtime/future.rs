@@ -10,8 +10,11 @@
 //! like `futureAll` and `futureRace` for working with multiple futures.
 
 use crate::value::Value;
+use futures_util::future::try_join_all;
 use std::fmt;
 use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio::task::AbortHandle;
 
 /// Future state representing the status of an async computation
 #[derive(Clone)]
@@ -53,6 +56,10 @@ impl fmt::Debug for FutureState {
 #[derive(Clone)]
 pub struct AtlasFuture {
     state: Arc<Mutex<FutureState>>,
+    /// Runtime task producing the value, if any. Aborted by `cancel`.
+    task: Arc<Mutex<Option<AbortHandle>>>,
+    /// Signalled when the future settles
+    settled: Arc<Notify>,
 }
 
 impl AtlasFuture {
@@ -60,6 +67,8 @@ impl AtlasFuture {
     pub fn new_pending() -> Self {
         Self {
             state: Arc::new(Mutex::new(FutureState::Pending)),
+            task: Arc::new(Mutex::new(None)),
+            settled: Arc::new(Notify::new()),
         }
     }
 
@@ -67,6 +76,8 @@ impl AtlasFuture {
     pub fn resolved(value: Value) -> Self {
         Self {
             state: Arc::new(Mutex::new(FutureState::Resolved(value))),
+            task: Arc::new(Mutex::new(None)),
+            settled: Arc::new(Notify::new()),
         }
     }
 
@@ -74,6 +85,8 @@ impl AtlasFuture {
     pub fn rejected(error: Value) -> Self {
         Self {
             state: Arc::new(Mutex::new(FutureState::Rejected(error))),
+            task: Arc::new(Mutex::new(None)),
+            settled: Arc::new(Notify::new()),
         }
     }
 
@@ -105,6 +118,7 @@ impl AtlasFuture {
         let mut state = self.state.lock().unwrap();
         if matches!(*state, FutureState::Pending) {
             *state = FutureState::Resolved(value);
+            self.settled.notify_waiters();
         }
     }
 
@@ -116,9 +130,81 @@ impl AtlasFuture {
         let mut state = self.state.lock().unwrap();
         if matches!(*state, FutureState::Pending) {
             *state = FutureState::Rejected(error);
+            self.settled.notify_waiters();
         }
     }
 
+    /// Tie this future to the runtime task that settles it
+    ///
+    /// `cancel` aborts the task, so its in-flight work (timers, I/O) is dropped
+    /// rather than left running with nobody waiting on the result. The task
+    /// must settle the future before it ends; `spawn_settler` guarantees this.
+    pub fn attach_task(&self, handle: AbortHandle) {
+        *self.task.lock().unwrap() = Some(handle);
+    }
+
+    /// Spawn `work` on the global runtime as the task that settles this future
+    ///
+    /// The task is attached, so `cancel` drops it. If it ends without settling
+    /// the future (it panicked or was aborted), the future is rejected so
+    /// nothing waits on it forever.
+    pub fn spawn_settler<F>(&self, work: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let guard = SettleGuard(self.clone());
+        let handle = crate::async_runtime::runtime().spawn(async move {
+            let _guard = guard;
+            work.await;
+        });
+        self.attach_task(handle.abort_handle());
+    }
+
+    /// Cancel a pending future
+    ///
+    /// Rejects the future with `"cancelled"` and aborts the attached task, if
+    /// any. Settled futures are left unchanged.
+    pub fn cancel(&self) {
+        self.reject(Value::string("cancelled"));
+        if let Some(handle) = self.task.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    /// Wait until the future settles
+    ///
+    /// Sleeps until `resolve` or `reject` signals rather than polling. A
+    /// pending future whose attached task is gone, or that never had one, has
+    /// nothing left to settle it, so the wait fails with a string error
+    /// instead of hanging.
+    pub async fn wait(&self) -> Result<Value, Value> {
+        loop {
+            // Register before checking the state so a settle in between is not missed
+            let notified = self.settled.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            match self.get_state() {
+                FutureState::Resolved(value) => return Ok(value),
+                FutureState::Rejected(error) => return Err(error),
+                FutureState::Pending if !self.has_live_task() => {
+                    return Err(Value::string(
+                        "a future is pending with no task left to settle it",
+                    ))
+                }
+                FutureState::Pending => notified.await,
+            }
+        }
+    }
+
+    fn has_live_task(&self) -> bool {
+        self.task
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+
     /// Apply a transformation to a resolved future
     ///
     /// Creates a new future that will contain the result of applying
@@ -192,6 +278,16 @@ impl fmt::Display for AtlasFuture {
     }
 }
 
+/// Rejects a future whose settling task ends without settling it
+struct SettleGuard(AtlasFuture);
+
+impl Drop for SettleGuard {
+    fn drop(&mut self) {
+        self.0
+            .reject(Value::string("task ended without settling its future"));
+    }
+}
+
 /// Combine multiple futures into one that resolves when all resolve
 ///
 /// Returns a future containing an array of all results.
@@ -217,6 +313,23 @@ pub fn future_all(futures: Vec<AtlasFuture>) -> AtlasFuture {
     AtlasFuture::resolved(Value::array(results))
 }
 
+/// Drive the runtime until every future settles
+///
+/// Unlike `future_all`, this blocks the caller and keeps the executor running
+/// so pending futures (sleeps, timers) make progress concurrently. Results are
+/// returned in input order. The first rejection short-circuits the wait.
+///
+/// A future that is pending with no task left to settle it fails the wait
+/// with a string error instead of hanging (see [`AtlasFuture::wait`]).
+pub fn await_all(futures: &[AtlasFuture]) -> Result<Vec<Value>, Value> {
+    crate::async_runtime::block_on(wait_all(futures))
+}
+
+/// Async body of `await_all`, for callers already inside the runtime
+pub(crate) async fn wait_all(futures: &[AtlasFuture]) -> Result<Vec<Value>, Value> {
+    try_join_all(futures.iter().map(AtlasFuture::wait)).await
+}
+
 /// Return the first future to complete (resolve or reject)
 ///
 /// Creates a future that adopts the state of the first future to complete.
@@ -405,6 +518,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_await_all_returns_values_in_order() {
+        let futures = vec![
+            AtlasFuture::resolved(Value::Number(1.0)),
+            AtlasFuture::resolved(Value::Number(2.0)),
+        ];
+        let values = await_all(&futures).unwrap();
+        assert_eq!(values, vec![Value::Number(1.0), Value::Number(2.0)]);
+    }
+
+    #[test]
+    fn test_await_all_stalled_future_errors() {
+        let futures = vec![AtlasFuture::new_pending()];
+        assert!(await_all(&futures).is_err());
+    }
+
+    #[test]
+    fn test_await_all_stalled_future_errors_despite_unrelated_tasks() {
+        // A long-running task that will never settle the awaited future
+        let unrelated = crate::async_runtime::runtime().spawn(async {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        });
+
+        let started = std::time::Instant::now();
+        assert!(await_all(&[AtlasFuture::new_pending()]).is_err());
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        unrelated.abort();
+    }
+
+    #[test]
+    fn test_await_all_wakes_on_resolve() {
+        let future = AtlasFuture::new_pending();
+        let settler = future.clone();
+        future.spawn_settler(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            settler.resolve(Value::Number(7.0));
+        });
+        assert_eq!(await_all(&[future]), Ok(vec![Value::Number(7.0)]));
+    }
+
+    #[test]
+    fn test_settler_that_ends_without_settling_rejects() {
+        let future = AtlasFuture::new_pending();
+        future.spawn_settler(async {});
+        assert!(await_all(std::slice::from_ref(&future)).is_err());
+        assert!(future.is_rejected());
+    }
+
     #[test]
     fn test_future_all_empty() {
         let result = future_all(vec![]);
@@ -18,8 +18,10 @@ pub mod task;
 pub use channel::{
     channel_bounded, channel_select, channel_unbounded, ChannelReceiver, ChannelSender,
 };
-pub use future::{future_all, future_race, AtlasFuture, FutureState};
-pub use primitives::{interval, retry_with_timeout, sleep, timeout, timer, AsyncMutex};
+pub use future::{await_all, future_all, future_race, AtlasFuture, FutureState};
+pub use primitives::{
+    interval, retry_with_timeout, sleep, timeout, timer, with_timeout, AsyncMutex,
+};
 pub use task::{join_all, spawn_and_await, spawn_task, TaskHandle, TaskStatus};
 
 use std::sync::OnceLock;
@@ -2,6 +2,7 @@
 //!
 //! Provides essential async primitives for building concurrent applications.
 
+use crate::async_runtime::future::wait_all;
 use crate::async_runtime::AtlasFuture;
 use crate::value::Value;
 use std::sync::Arc;
@@ -16,12 +17,14 @@ use tokio::time;
 /// Sleep for a specified duration
 ///
 /// Returns a Future that resolves after the specified number of milliseconds.
-/// Non-blocking - other tasks can run while sleeping.
+/// Non-blocking - other tasks can run while sleeping. The timer is spawned on
+/// the global runtime, so it advances whenever the runtime is driven (e.g. by
+/// `await_all`), even when called outside a `LocalSet`.
 pub fn sleep(milliseconds: u64) -> AtlasFuture {
     let future = AtlasFuture::new_pending();
     let future_clone = future.clone();
 
-    tokio::task::spawn_local(async move {
+    future.spawn_settler(async move {
         time::sleep(Duration::from_millis(milliseconds)).await;
         future_clone.resolve(Value::Null);
     });
@@ -47,7 +50,7 @@ pub fn interval(milliseconds: u64) -> AtlasFuture {
     let future = AtlasFuture::new_pending();
     let future_clone = future.clone();
 
-    tokio::task::spawn_local(async move {
+    future.spawn_settler(async move {
         let mut interval = time::interval(Duration::from_millis(milliseconds));
         interval.tick().await; // First tick completes immediately
         interval.tick().await; // Wait for first interval
@@ -88,7 +91,7 @@ impl AsyncMutex {
         let future = AtlasFuture::new_pending();
         let future_clone = future.clone();
 
-        tokio::task::spawn_local(async move {
+        future.spawn_settler(async move {
             let guard = mutex.lock().await;
             // Clone the value since we can't return the guard
             let value = (*guard).clone();
@@ -114,7 +117,7 @@ impl AsyncMutex {
         let future = AtlasFuture::new_pending();
         let future_clone = future.clone();
 
-        tokio::task::spawn_local(async move {
+        future.spawn_settler(async move {
             let mut guard = mutex.lock().await;
             *guard = new_value;
             future_clone.resolve(Value::Null);
@@ -142,35 +145,9 @@ pub fn timeout(future: AtlasFuture, milliseconds: u64) -> AtlasFuture {
     let timeout_future = AtlasFuture::new_pending();
     let timeout_clone = timeout_future.clone();
 
-    tokio::task::spawn_local(async move {
+    timeout_future.spawn_settler(async move {
         let duration = Duration::from_millis(milliseconds);
-
-        // Create timeout
-        let timeout_result = time::timeout(duration, async {
-            // Poll the future by checking its state
-            // In a full implementation, this would properly await the future
-            let start = std::time::Instant::now();
-            loop {
-                match future.get_state() {
-                    crate::async_runtime::FutureState::Resolved(value) => {
-                        return Ok(value);
-                    }
-                    crate::async_runtime::FutureState::Rejected(error) => {
-                        return Err(error);
-                    }
-                    crate::async_runtime::FutureState::Pending => {
-                        // Still pending, check timeout
-                        if start.elapsed() > duration {
-                            return Err(Value::string("Operation timed out"));
-                        }
-                        time::sleep(Duration::from_millis(10)).await;
-                    }
-                }
-            }
-        })
-        .await;
-
-        match timeout_result {
+        match time::timeout(duration, future.wait()).await {
             Ok(Ok(value)) => timeout_clone.resolve(value),
             Ok(Err(error)) => timeout_clone.reject(error),
             Err(_) => timeout_clone.reject(Value::string("Operation timed out")),
@@ -180,6 +157,27 @@ pub fn timeout(future: AtlasFuture, milliseconds: u64) -> AtlasFuture {
     timeout_future
 }
 
+/// Wait for a future, giving up after a deadline
+///
+/// Blocks until the future settles or `milliseconds` pass. On timeout the
+/// future is cancelled, which aborts its backing task so the work is dropped,
+/// and `Err` carries a description of the timeout. The deadline timer lives
+/// only for the duration of this call.
+pub fn with_timeout(future: &AtlasFuture, milliseconds: u64) -> Result<Value, Value> {
+    crate::async_runtime::block_on(async {
+        let deadline = Duration::from_millis(milliseconds);
+        match time::timeout(deadline, wait_all(std::slice::from_ref(future))).await {
+            Ok(result) => result.map(|mut values| values.remove(0)),
+            Err(_) => {
+                future.cancel();
+                // Let the runtime drop the aborted task before returning
+                tokio::task::yield_now().await;
+                Err(Value::string(format!("Timed out after {}ms", milliseconds)))
+            }
+        }
+    })
+}
+
 /// Retry an operation with timeout
 ///
 /// Attempts the operation up to max_attempts times, with a timeout per attempt.
@@ -298,4 +296,87 @@ mod tests {
             _ => panic!("Expected timeout error"),
         }
     }
+
+    #[test]
+    fn test_with_timeout_returns_fast_result() {
+        let result = with_timeout(&sleep(10), 500);
+        assert_eq!(result, Ok(Value::Null));
+    }
+
+    #[test]
+    fn test_with_timeout_drops_cancelled_work() {
+        struct DropFlag(Arc<std::sync::atomic::AtomicBool>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = DropFlag(Arc::clone(&dropped));
+        let future = AtlasFuture::new_pending();
+        let future_clone = future.clone();
+        let handle = crate::async_runtime::runtime().spawn(async move {
+            let _flag = flag;
+            time::sleep(Duration::from_millis(500)).await;
+            future_clone.resolve(Value::Number(1.0));
+        });
+        future.attach_task(handle.abort_handle());
+
+        let result = with_timeout(&future, 50);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Timed out after 50ms"));
+        assert!(future.is_rejected());
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_with_timeout_aborts_channel_receive() {
+        let (sender, receiver) = crate::async_runtime::channel_unbounded();
+        let pending = receiver.receive();
+
+        let result = with_timeout(&pending, 50);
+        assert!(result.unwrap_err().to_string().contains("Timed out"));
+        assert!(pending.is_rejected());
+
+        // The aborted receive no longer holds the receiver or steals messages
+        assert!(sender.send(Value::Number(1.0)));
+        assert_eq!(receiver.try_receive(), Some(Value::Number(1.0)));
+    }
+
+    #[test]
+    fn test_with_timeout_cancels_joined_task() {
+        struct DropFlag(Arc<std::sync::atomic::AtomicBool>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = DropFlag(Arc::clone(&dropped));
+        let handle = crate::async_runtime::spawn_task(
+            async move {
+                let _flag = flag;
+                time::sleep(Duration::from_secs(10)).await;
+                Value::Null
+            },
+            None,
+        );
+
+        let result = with_timeout(&handle.join(), 50);
+        assert!(result.unwrap_err().to_string().contains("Timed out"));
+        assert!(handle.is_cancelled());
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while !dropped.load(std::sync::atomic::Ordering::SeqCst) {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "task work not dropped"
+            );
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
 }
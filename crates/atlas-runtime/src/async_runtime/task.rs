@@ -10,7 +10,8 @@ use futures_util::FutureExt;
 use std::fmt;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
-use tokio::task::JoinHandle;
+use tokio::sync::Notify;
+use tokio::task::{AbortHandle, JoinHandle};
 
 /// Global task ID counter
 static TASK_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -35,6 +36,67 @@ pub(crate) struct TaskState {
     status: StdMutex<TaskStatus>,
     cancelled: AtomicBool,
     result: StdMutex<Option<Result<Value, String>>>,
+    /// Signalled when the task leaves the `Running` status
+    done: Notify,
+    /// The task's work, aborted on cancellation
+    work: StdMutex<Option<AbortHandle>>,
+}
+
+impl TaskState {
+    /// Record the task's outcome unless it already ended, and wake joiners
+    fn finish(&self, status: TaskStatus, result: Option<Result<Value, String>>) {
+        let mut current = self.status.lock().unwrap();
+        if *current == TaskStatus::Running {
+            *current = status;
+            if result.is_some() {
+                *self.result.lock().unwrap() = result;
+            }
+        }
+        drop(current);
+        self.done.notify_waiters();
+    }
+
+    /// Cancel the task, aborting its work if it has started
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        if let Some(work) = self.work.lock().unwrap().take() {
+            work.abort();
+        }
+        self.finish(TaskStatus::Cancelled, None);
+    }
+
+    /// Wait for the task to end; `None` if it was cancelled
+    async fn finished(&self) -> Option<Result<Value, String>> {
+        loop {
+            let notified = self.done.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if *self.status.lock().unwrap() != TaskStatus::Running {
+                return self.result.lock().unwrap().clone();
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Settle `future` with a finished task's outcome
+fn settle_with(future: &AtlasFuture, outcome: Option<Result<Value, String>>) {
+    match outcome {
+        Some(Ok(value)) => future.resolve(value),
+        Some(Err(error)) => future.reject(Value::string(error)),
+        None => future.reject(Value::string("Task cancelled")),
+    }
+}
+
+/// Cancels a still-running task when a join on it is abandoned
+struct CancelOnDrop(Arc<TaskState>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if *self.0.status.lock().unwrap() == TaskStatus::Running {
+            self.0.cancel();
+        }
+    }
 }
 
 /// Handle to a spawned task
@@ -59,6 +121,8 @@ impl TaskHandle {
                 status: StdMutex::new(TaskStatus::Running),
                 cancelled: AtomicBool::new(false),
                 result: StdMutex::new(None),
+                done: Notify::new(),
+                work: StdMutex::new(None),
             }),
             _marker: std::marker::PhantomData,
         }
@@ -100,12 +164,11 @@ impl TaskHandle {
     }
 
     /// Cancel the task
+    ///
+    /// Aborts the task's work, so a cancelled task stops rather than running
+    /// to completion unobserved.
     pub fn cancel(&self) {
-        self.state.cancelled.store(true, Ordering::SeqCst);
-        let mut status = self.state.status.lock().unwrap();
-        if *status == TaskStatus::Running {
-            *status = TaskStatus::Cancelled;
-        }
+        self.state.cancel();
     }
 
     /// Check if cancellation was requested
@@ -115,7 +178,8 @@ impl TaskHandle {
 
     /// Wait for task completion and get result
     ///
-    /// Returns a Future that resolves to the task's result value.
+    /// Returns a Future that resolves to the task's result value. Cancelling
+    /// that future (e.g. via `withTimeout`) cancels the task itself.
     pub fn join(&self) -> AtlasFuture {
         // Check if already complete
         let result = self.state.result.lock().unwrap().clone();
@@ -126,11 +190,18 @@ impl TaskHandle {
                 Err(error) => return AtlasFuture::rejected(Value::string(error)),
             }
         }
+        if self.is_cancelled() {
+            return AtlasFuture::rejected(Value::string("Task cancelled"));
+        }
 
-        // Task still running - return pending future
-        // Note: In a full implementation, this would poll the task
-        // For now, we return pending and rely on external polling
-        AtlasFuture::new_pending()
+        let future = AtlasFuture::new_pending();
+        let settle = future.clone();
+        let state = self.state_ref();
+        future.spawn_settler(async move {
+            let _cancel = CancelOnDrop(Arc::clone(&state));
+            settle_with(&settle, state.finished().await);
+        });
+        future
     }
 
     /// Mark task as completed with result
@@ -207,12 +278,12 @@ where
     let state_clone = Arc::clone(&state);
     std::thread::spawn(move || {
         crate::async_runtime::block_on(async move {
+            let task_state = Arc::clone(&state_clone);
             // Spawn on LocalSet
-            tokio::task::spawn_local(async move {
+            let work = tokio::task::spawn_local(async move {
                 // Check for cancellation before starting
                 if state_clone.cancelled.load(Ordering::SeqCst) {
-                    let mut status = state_clone.status.lock().unwrap();
-                    *status = TaskStatus::Cancelled;
+                    state_clone.finish(TaskStatus::Cancelled, None);
                     return;
                 }
 
@@ -222,11 +293,7 @@ where
                 match result {
                     Ok(value) => {
                         // Task completed successfully
-                        let mut status = state_clone.status.lock().unwrap();
-                        if *status == TaskStatus::Running {
-                            *status = TaskStatus::Completed;
-                            *state_clone.result.lock().unwrap() = Some(Ok(value));
-                        }
+                        state_clone.finish(TaskStatus::Completed, Some(Ok(value)));
                     }
                     Err(panic_err) => {
                         // Task panicked
@@ -238,16 +305,16 @@ where
                             "Task panicked".to_string()
                         };
 
-                        let mut status = state_clone.status.lock().unwrap();
-                        if *status == TaskStatus::Running {
-                            *status = TaskStatus::Failed;
-                            *state_clone.result.lock().unwrap() = Some(Err(error_msg));
-                        }
+                        state_clone.finish(TaskStatus::Failed, Some(Err(error_msg)));
                     }
                 }
-            })
-            .await
-            .ok(); // Ignore join errors
+            });
+
+            *task_state.work.lock().unwrap() = Some(work.abort_handle());
+            if task_state.cancelled.load(Ordering::SeqCst) {
+                work.abort();
+            }
+            work.await.ok(); // Ignore join errors (cancellation aborts the work)
         });
     });
 
@@ -294,7 +361,7 @@ pub fn join_all(handles: Vec<TaskHandle>) -> AtlasFuture {
     let mut results = Vec::new();
     let mut all_complete = true;
 
-    for handle in handles {
+    for handle in &handles {
         let result = handle.state.result.lock().unwrap().clone();
 
         if let Some(res) = result {
@@ -313,10 +380,24 @@ pub fn join_all(handles: Vec<TaskHandle>) -> AtlasFuture {
     }
 
     if all_complete {
-        AtlasFuture::resolved(Value::array(results))
-    } else {
-        AtlasFuture::new_pending()
+        return AtlasFuture::resolved(Value::array(results));
     }
+
+    // Settle once every task has ended, in input order
+    let future = AtlasFuture::new_pending();
+    let settle = future.clone();
+    let states: Vec<_> = handles.iter().map(TaskHandle::state_ref).collect();
+    future.spawn_settler(async move {
+        let mut results = Vec::with_capacity(states.len());
+        for state in states {
+            match state.finished().await {
+                Some(Ok(value)) => results.push(value),
+                outcome => return settle_with(&settle, outcome),
+            }
+        }
+        settle.resolve(Value::array(results));
+    });
+    future
 }
 
 #[cfg(test)]
@@ -9,6 +9,7 @@ use crate::diagnostic::Diagnostic;
 use crate::module_loader::ModuleRegistry;
 use crate::span::Span;
 use crate::symbol::{Symbol, SymbolKind, SymbolTable};
+use crate::typechecker::suggestions;
 use crate::types::{StructuralMemberType, Type, TypeParamDef};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -75,6 +76,8 @@ impl Binder {
                     ExportItem::Function(func) => &func.name.name,
                     ExportItem::Variable(var) => &var.name.name,
                     ExportItem::TypeAlias(alias) => &alias.name.name,
+                    // Re-exports are resolved against the registry in phase 2
+                    ExportItem::ReExport(_) => continue,
                 };
 
                 let mut exported = self.symbol_table.mark_exported(name);
@@ -151,6 +154,8 @@ impl Binder {
                     ExportItem::Function(func) => &func.name.name,
                     ExportItem::Variable(var) => &var.name.name,
                     ExportItem::TypeAlias(alias) => &alias.name.name,
+                    // Re-exports are resolved against the registry in phase 2
+                    ExportItem::ReExport(_) => continue,
                 };
 
                 let mut exported = self.symbol_table.mark_exported(name);
@@ -264,6 +269,7 @@ impl Binder {
                         .end
                         .saturating_sub(existing_symbol.span.start),
                     message: format!("'{}' first defined here", existing_symbol.name),
+                    ..Default::default()
                 });
             }
 
@@ -349,6 +355,7 @@ impl Binder {
                         .end
                         .saturating_sub(existing_symbol.span.start),
                     message: format!("'{}' first defined here", existing_symbol.name),
+                    ..Default::default()
                 });
             }
 
@@ -376,6 +383,9 @@ impl Binder {
                     crate::ast::ExportItem::TypeAlias(_) => {
                         // Type aliases are handled during collection
                     }
+                    crate::ast::ExportItem::ReExport(_) => {
+                        // Re-exports need a module registry (see bind_with_modules)
+                    }
                 }
             }
             Item::Extern(_) => {
@@ -395,8 +405,8 @@ impl Binder {
     fn bind_item_with_modules(
         &mut self,
         item: &Item,
-        _module_path: &Path,
-        _registry: &ModuleRegistry,
+        module_path: &Path,
+        registry: &ModuleRegistry,
     ) {
         match item {
             Item::Function(func) => self.bind_function(func),
@@ -415,6 +425,9 @@ impl Binder {
                     crate::ast::ExportItem::TypeAlias(_) => {
                         // Type aliases are handled during collection
                     }
+                    crate::ast::ExportItem::ReExport(re_export) => {
+                        self.bind_re_export(re_export, module_path, registry);
+                    }
                 }
             }
             Item::Extern(_) => {
@@ -555,6 +568,61 @@ impl Binder {
         }
     }
 
+    /// Bind a re-export declaration
+    ///
+    /// Forwards symbols exported by the source module into this module's exports
+    /// with their original types. Nothing is bound in the local scope.
+    fn bind_re_export(
+        &mut self,
+        re_export: &ReExportDecl,
+        module_path: &Path,
+        registry: &ModuleRegistry,
+    ) {
+        let source_path = Self::resolve_import_path(&re_export.source, module_path);
+
+        let Some(source_symbols) = registry.get(&source_path) else {
+            self.diagnostics.push(
+                Diagnostic::error_with_code(
+                    "AT5005",
+                    format!("Cannot find module '{}'", re_export.source),
+                    re_export.span,
+                )
+                .with_label("re-export statement")
+                .with_help(
+                    "ensure the module exists and has been loaded before re-exporting from it",
+                ),
+            );
+            return;
+        };
+
+        let exports = source_symbols.get_exports();
+        let type_alias_exports = source_symbols.get_type_alias_exports();
+
+        for name in &re_export.names {
+            if let Some(symbol) = exports.get(&name.name) {
+                self.symbol_table.add_re_export(Symbol {
+                    span: name.span,
+                    ..symbol.clone()
+                });
+            } else if let Some(alias) = type_alias_exports.get(&name.name) {
+                self.symbol_table.add_re_exported_type_alias(alias.clone());
+            } else {
+                self.diagnostics.push(
+                    Diagnostic::error_with_code(
+                        "AT5006",
+                        format!(
+                            "Module '{}' does not export '{}'",
+                            re_export.source, name.name
+                        ),
+                        name.span,
+                    )
+                    .with_label("re-exported name")
+                    .with_help("check the module's exports or re-export a different symbol"),
+                );
+            }
+        }
+    }
+
     fn resolve_import_path(source: &str, module_path: &Path) -> PathBuf {
         if source.starts_with("./") || source.starts_with("../") {
             let base = module_path.parent().unwrap_or(Path::new("."));
@@ -629,6 +697,7 @@ impl Binder {
                         .end
                         .saturating_sub(existing_alias.name.span.start),
                     message: format!("'{}' first defined here", existing_alias.name.name),
+                    ..Default::default()
                 });
             }
 
@@ -676,6 +745,7 @@ impl Binder {
                             .end
                             .saturating_sub(existing_symbol.span.start),
                         message: format!("'{}' first defined here", existing_symbol.name),
+                        ..Default::default()
                     });
                 }
 
@@ -777,6 +847,7 @@ impl Binder {
                                 .end
                                 .saturating_sub(existing_symbol.span.start),
                             message: format!("'{}' first defined here", existing_symbol.name),
+                            ..Default::default()
                         });
                     }
 
@@ -856,6 +927,7 @@ impl Binder {
                                 .end
                                 .saturating_sub(existing_symbol.span.start),
                             message: format!("'{}' first defined here", existing_symbol.name),
+                            ..Default::default()
                         });
                     }
 
@@ -868,6 +940,24 @@ impl Binder {
                 // Exit loop scope
                 self.symbol_table.exit_scope();
             }
+            Stmt::TryCatch(try_catch) => {
+                self.bind_block(&try_catch.body);
+
+                // The handler scope holds the error binding
+                self.symbol_table.enter_scope();
+                let symbol = Symbol {
+                    name: try_catch.error.name.clone(),
+                    ty: Type::caught_error(),
+                    mutable: false,
+                    kind: SymbolKind::Variable,
+                    span: try_catch.error.span,
+                    exported: false,
+                };
+                // A fresh scope cannot already hold the name
+                let _ = self.symbol_table.define(symbol);
+                self.bind_block(&try_catch.handler);
+                self.symbol_table.exit_scope();
+            }
             Stmt::Return(ret) => {
                 if let Some(expr) = &ret.value {
                     self.bind_expr(expr);
@@ -888,24 +978,48 @@ impl Binder {
         }
     }
 
+    /// Build an unknown-symbol error, suggesting the closest visible name if it looks like a typo
+    fn unknown_symbol_error(&self, id: &Identifier, fallback_help: String) -> Diagnostic {
+        let diag =
+            Diagnostic::error_with_code("AT2002", format!("Unknown symbol '{}'", id.name), id.span)
+                .with_label("undefined variable");
+
+        let Some(suggestion) =
+            suggestions::closest_name(&id.name, self.symbol_table.visible_names())
+        else {
+            return diag.with_help(fallback_help);
+        };
+
+        let mut diag = diag.with_help(format!("did you mean '{}'?", suggestion));
+        if let Some(symbol) = self.symbol_table.lookup(suggestion) {
+            if symbol.span != Span::dummy() {
+                diag = diag.with_related_location(crate::diagnostic::RelatedLocation {
+                    file: "<input>".to_string(),
+                    line: 1,
+                    column: symbol.span.start + 1,
+                    length: symbol.span.end.saturating_sub(symbol.span.start),
+                    message: format!("'{}' declared here", symbol.name),
+                    ..Default::default()
+                });
+            }
+        }
+        diag
+    }
+
     /// Bind an assignment target
     fn bind_assign_target(&mut self, target: &AssignTarget) {
         match target {
             AssignTarget::Name(id) => {
                 // Check if the identifier exists
                 if self.symbol_table.lookup(&id.name).is_none() {
-                    self.diagnostics.push(
-                        Diagnostic::error_with_code(
-                            "AT2002",
-                            format!("Unknown symbol '{}'", id.name),
-                            id.span,
-                        )
-                        .with_label("undefined variable")
-                        .with_help(format!(
+                    let diag = self.unknown_symbol_error(
+                        id,
+                        format!(
                             "declare '{}' with 'let' or 'const' before assigning to it",
                             id.name
-                        )),
+                        ),
                     );
+                    self.diagnostics.push(diag);
                 }
             }
             AssignTarget::Index { target, index, .. } => {
@@ -927,18 +1041,11 @@ impl Binder {
                     && !crate::stdlib::is_builtin(&id.name)
                     && !crate::stdlib::is_array_intrinsic(&id.name)
                 {
-                    self.diagnostics.push(
-                        Diagnostic::error_with_code(
-                            "AT2002",
-                            format!("Unknown symbol '{}'", id.name),
-                            id.span,
-                        )
-                        .with_label("undefined variable")
-                        .with_help(format!(
-                            "declare '{}' before using it, or check for typos",
-                            id.name
-                        )),
+                    let diag = self.unknown_symbol_error(
+                        id,
+                        format!("declare '{}' before using it, or check for typos", id.name),
                     );
+                    self.diagnostics.push(diag);
                 }
             }
             Expr::Binary(binary) => {
@@ -963,6 +1070,18 @@ impl Binder {
                     self.bind_expr(elem);
                 }
             }
+            Expr::MapLiteral(map) => {
+                for entry in &map.entries {
+                    self.bind_expr(&entry.value);
+                }
+            }
+            Expr::StringInterp(interp) => {
+                for part in &interp.parts {
+                    if let StringInterpPart::Expr(expr) = part {
+                        self.bind_expr(expr);
+                    }
+                }
+            }
             Expr::Group(group) => {
                 self.bind_expr(&group.expr);
             }
@@ -1063,6 +1182,8 @@ impl Binder {
             "Array" => Some(1), // Array<T> is sugar for T[]
             "HashMap" => Some(2),
             "HashSet" => Some(1),
+            "Map" => Some(1),
+            "Set" => Some(1),
             _ => None, // Unknown generic type
         }
     }
@@ -1072,6 +1193,7 @@ impl Binder {
         match type_ref {
             TypeRef::Named(name, span) => match name.as_str() {
                 "number" => Type::Number,
+                "int" => Type::Int,
                 "string" => Type::String,
                 "bool" => Type::Bool,
                 "void" => Type::Void,
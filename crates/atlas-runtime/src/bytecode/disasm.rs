@@ -1,25 +1,51 @@
 //! Bytecode disassembler
 //!
 //! Converts bytecode back to human-readable assembly-like format.
-//! Used for debugging, testing, and `atlas build --disasm` output.
+//! Used for debugging, testing, and `atlas disasm` output.
 
 use super::{Bytecode, Opcode};
+use crate::debugger::source_map::SourceMap;
+use crate::value::Value;
+use std::collections::HashMap;
 use std::fmt::Write;
 
 /// Disassemble bytecode to human-readable format
 ///
+/// Operands that index the constant pool are followed by the value they
+/// resolve to, and each function's entry point is labelled.
+///
 /// # Format
 /// ```text
 /// === Constants ===
-/// 0: 42.0
-/// 1: "hello"
+/// 0: 42
+/// 1: "x"
 ///
 /// === Instructions ===
-/// 0000  Constant 0
-/// 0003  Add
-/// 0004  Halt
+/// 0000  Constant 0 (42)
+/// 0003  SetGlobal 1 ("x")
+/// 0006  Halt
 /// ```
 pub fn disassemble(bytecode: &Bytecode) -> String {
+    render(bytecode, None)
+}
+
+/// Disassemble bytecode with the source line of each instruction
+///
+/// Lines come from the bytecode's debug spans resolved against `source`. A
+/// `|` marks an instruction on the same line as the one before it.
+///
+/// # Format
+/// ```text
+/// === Instructions ===
+/// 0000     1  Constant 0 (42)
+/// 0003     |  SetGlobal 1 ("x")
+/// 0006     2  GetGlobal 1 ("x")
+/// ```
+pub fn disassemble_with_source(bytecode: &Bytecode, source: &str) -> String {
+    render(bytecode, Some(source))
+}
+
+fn render(bytecode: &Bytecode, source: Option<&str>) -> String {
     let mut output = String::new();
 
     // Constants section
@@ -31,12 +57,50 @@ pub fn disassemble(bytecode: &Bytecode) -> String {
         writeln!(output).unwrap();
     }
 
+    // Dummy spans carry no position, so leave them out of the line lookup
+    let source_map = source.map(|src| {
+        let spans: Vec<_> = bytecode
+            .debug_info
+            .iter()
+            .filter(|d| !(d.span.start == 0 && d.span.end == 0))
+            .cloned()
+            .collect();
+        SourceMap::from_debug_spans(&spans, "", Some(src))
+    });
+    let entry_points: HashMap<usize, &str> = bytecode
+        .constants
+        .iter()
+        .filter_map(|c| match c {
+            Value::Function(f) => Some((f.bytecode_offset, f.name.as_str())),
+            _ => None,
+        })
+        .collect();
+
     // Instructions section
     writeln!(output, "=== Instructions ===").unwrap();
     let mut offset = 0;
+    let mut previous_line = None;
     while offset < bytecode.instructions.len() {
-        let line = disassemble_instruction(bytecode, &mut offset);
-        writeln!(output, "{}", line).unwrap();
+        if let Some(name) = entry_points.get(&offset) {
+            writeln!(output, "<fn {}>:", name).unwrap();
+        }
+        let start = offset;
+        let instruction = disassemble_instruction(bytecode, &mut offset);
+        match &source_map {
+            Some(map) => {
+                let line = map.location_for_offset(start).map(|loc| loc.line);
+                let column = match line {
+                    Some(line) if previous_line == Some(line) => "    |".to_string(),
+                    Some(line) => format!("{:5}", line),
+                    None => "    -".to_string(),
+                };
+                previous_line = line;
+                // Splice the line column in after the offset
+                let (addr, rest) = instruction.split_at(instruction.find(' ').unwrap_or(0));
+                writeln!(output, "{}{}{}", addr, column, rest).unwrap();
+            }
+            None => writeln!(output, "{}", instruction).unwrap(),
+        }
     }
 
     output
@@ -84,8 +148,11 @@ fn disassemble_instruction(bytecode: &Bytecode, offset: &mut usize) -> String {
         | Opcode::And
         | Opcode::Or
         | Opcode::Return
+        | Opcode::TryEnd
         | Opcode::GetIndex
         | Opcode::SetIndex
+        | Opcode::MapGet
+        | Opcode::MapSet
         | Opcode::Pop
         | Opcode::Dup
         | Opcode::IsOptionSome
@@ -100,37 +167,50 @@ fn disassemble_instruction(bytecode: &Bytecode, offset: &mut usize) -> String {
             format!("{:04}  {:?}", start_offset, opcode)
         }
 
-        // u16 operands (constants, locals, globals, upvalues)
-        Opcode::Constant
-        | Opcode::GetLocal
+        // u16 operands (locals, upvalues, element counts)
+        Opcode::GetLocal
         | Opcode::SetLocal
-        | Opcode::GetGlobal
-        | Opcode::SetGlobal
         | Opcode::GetUpvalue
         | Opcode::SetUpvalue
-        | Opcode::Array => {
+        | Opcode::Array
+        | Opcode::NewMap => {
             let operand = read_u16(bytecode, offset);
             format!("{:04}  {:?} {}", start_offset, opcode, operand)
         }
 
+        // u16 constant pool indices — show the value they resolve to
+        Opcode::Constant | Opcode::GetGlobal | Opcode::SetGlobal => {
+            let operand = read_u16(bytecode, offset);
+            format!(
+                "{:04}  {:?} {}{}",
+                start_offset,
+                opcode,
+                operand,
+                resolve_constant(bytecode, operand)
+            )
+        }
+
         // MakeClosure: two u16 operands (func_const_idx, n_upvalues)
         Opcode::MakeClosure => {
             let func_idx = read_u16(bytecode, offset);
             let n_upvalues = read_u16(bytecode, offset);
             format!(
-                "{:04}  MakeClosure func={} upvalues={}",
-                start_offset, func_idx, n_upvalues
+                "{:04}  MakeClosure func={} upvalues={}{}",
+                start_offset,
+                func_idx,
+                n_upvalues,
+                resolve_constant(bytecode, func_idx)
             )
         }
 
         // u8 operand (call arg count)
-        Opcode::Call => {
+        Opcode::Call | Opcode::TailCall => {
             let operand = read_u8(bytecode, offset);
             format!("{:04}  {:?} {}", start_offset, opcode, operand)
         }
 
         // i16 operands (jumps)
-        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop => {
+        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop | Opcode::TryBegin => {
             let jump_offset = read_i16(bytecode, offset);
             let target = (*offset as i32 + jump_offset as i32) as usize;
             format!(
@@ -167,12 +247,21 @@ fn read_i16(bytecode: &Bytecode, offset: &mut usize) -> i16 {
     read_u16(bytecode, offset) as i16
 }
 
+/// The constant at `idx` formatted as ` (value)`, or empty if out of range
+fn resolve_constant(bytecode: &Bytecode, idx: u16) -> String {
+    bytecode
+        .constants
+        .get(idx as usize)
+        .map(|value| format!(" ({})", format_value(value)))
+        .unwrap_or_default()
+}
+
 /// Format a Value for constant pool display
-fn format_value(value: &crate::value::Value) -> String {
-    use crate::value::Value;
+fn format_value(value: &Value) -> String {
     match value {
         Value::Null => "null".to_string(),
         Value::Bool(b) => b.to_string(),
+        Value::Int(n) => format!("{}i", n),
         Value::Number(n) => {
             // Show integers without decimal point
             if n.fract() == 0.0 && n.is_finite() {
@@ -186,6 +275,8 @@ fn format_value(value: &crate::value::Value) -> String {
         Value::Builtin(name) => format!("<builtin {}>", name),
         Value::NativeFunction(_) => "<native fn>".to_string(),
         Value::Array(_) => "<array>".to_string(),
+        Value::Map(_) => "<map>".to_string(),
+        Value::Set(_) => "<set>".to_string(),
         Value::JsonValue(_) => "<json>".to_string(),
         Value::Option(_) => "<option>".to_string(),
         Value::Result(_) => "<result>".to_string(),
@@ -245,10 +336,36 @@ mod tests {
         let output = disassemble(&bytecode);
         assert!(output.contains("=== Constants ==="));
         assert!(output.contains("0: 42"));
-        assert!(output.contains("0000  Constant 0"));
+        assert!(output.contains("0000  Constant 0 (42)"));
         assert!(output.contains("0003  Halt"));
     }
 
+    #[test]
+    fn test_disassemble_out_of_range_constant() {
+        let mut bytecode = Bytecode::new();
+        bytecode.emit(Opcode::GetGlobal, Span::dummy());
+        bytecode.emit_u16(7);
+
+        let output = disassemble(&bytecode);
+        assert!(output.contains("0000  GetGlobal 7\n"));
+    }
+
+    #[test]
+    fn test_disassemble_with_source_marks_repeated_lines() {
+        let source = "1;\n2;";
+        let mut bytecode = Bytecode::new();
+        bytecode.emit(Opcode::Null, Span::new(0, 1));
+        bytecode.emit(Opcode::Pop, Span::new(0, 2));
+        bytecode.emit(Opcode::True, Span::new(3, 4));
+        bytecode.emit(Opcode::Halt, Span::dummy());
+
+        let output = disassemble_with_source(&bytecode, source);
+        assert!(output.contains("0000    1  Null"));
+        assert!(output.contains("0001    |  Pop"));
+        assert!(output.contains("0002    2  True"));
+        assert!(output.contains("0003    |  Halt"));
+    }
+
     #[test]
     fn test_disassemble_locals() {
         let mut bytecode = Bytecode::new();
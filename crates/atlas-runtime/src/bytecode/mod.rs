@@ -9,7 +9,7 @@ mod optimizer;
 mod serialize;
 pub mod validator;
 
-pub use disasm::disassemble;
+pub use disasm::{disassemble, disassemble_with_source};
 pub use opcode::Opcode;
 pub use optimizer::{
     ConstantFoldingPass, DeadCodeEliminationPass, OptimizationPass, OptimizationStats, Optimizer,
@@ -29,7 +29,14 @@ use crate::value::Value;
 ///
 /// Version history:
 /// - Version 1: Initial bytecode format (Phase 10)
-pub const BYTECODE_VERSION: u16 = 1;
+/// - Version 2: Function constants and top-level code record their local counts
+pub const BYTECODE_VERSION: u16 = 2;
+
+/// Header flag: a debug info section follows the instructions
+const FLAG_DEBUG_INFO: u16 = 1;
+
+/// Header flag: the top-level local count follows the last section
+const FLAG_TOP_LEVEL_LOCALS: u16 = 2;
 
 /// Debug information for bytecode
 ///
@@ -131,6 +138,21 @@ impl Bytecode {
             .map(|debug_span| debug_span.span)
     }
 
+    /// Render the bytecode as a human-readable listing
+    ///
+    /// See [`disassemble`] for the format.
+    pub fn disassemble(&self) -> String {
+        disassemble(self)
+    }
+
+    /// Render the bytecode as a listing annotated with source line numbers
+    ///
+    /// `source` must be the text the bytecode was compiled from. See
+    /// [`disassemble_with_source`] for the format.
+    pub fn disassemble_with_source(&self, source: &str) -> String {
+        disassemble_with_source(self, source)
+    }
+
     /// Serialize bytecode to binary format (.atb file)
     ///
     /// Format:
@@ -138,17 +160,20 @@ impl Bytecode {
     /// - Constants: count u32 + serialized values
     /// - Instructions: length u32 + bytecode bytes
     /// - Debug info (optional): count u32 + debug spans
+    /// - Top-level local count (optional): u32
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
 
         // Header
         bytes.extend_from_slice(b"ATB\0"); // Magic number
         bytes.extend_from_slice(&BYTECODE_VERSION.to_be_bytes()); // Version
-        let flags = if self.debug_info.is_empty() {
-            0u16
-        } else {
-            1u16
-        };
+        let mut flags = 0u16;
+        if !self.debug_info.is_empty() {
+            flags |= FLAG_DEBUG_INFO;
+        }
+        if self.top_level_local_count > 0 {
+            flags |= FLAG_TOP_LEVEL_LOCALS;
+        }
         bytes.extend_from_slice(&flags.to_be_bytes()); // Flags
 
         // Constants section
@@ -170,6 +195,11 @@ impl Bytecode {
             }
         }
 
+        // Top-level local count (optional)
+        if self.top_level_local_count > 0 {
+            bytes.extend_from_slice(&(self.top_level_local_count as u32).to_be_bytes());
+        }
+
         bytes
     }
 
@@ -191,7 +221,8 @@ impl Bytecode {
             ));
         }
         let flags = u16::from_be_bytes([bytes[6], bytes[7]]);
-        let has_debug_info = (flags & 1) != 0;
+        let has_debug_info = (flags & FLAG_DEBUG_INFO) != 0;
+        let has_top_level_locals = (flags & FLAG_TOP_LEVEL_LOCALS) != 0;
 
         // Start reading sections after header (8 bytes)
         let mut offset = 8;
@@ -268,6 +299,21 @@ impl Bytecode {
             }
         }
 
+        // Read top-level local count (optional)
+        let mut top_level_local_count = 0;
+        if has_top_level_locals {
+            if offset + 4 > bytes.len() {
+                return Err("Invalid bytecode: top-level local count truncated".to_string());
+            }
+            top_level_local_count = u32::from_be_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ]) as usize;
+            offset += 4;
+        }
+
         // Verify we consumed exactly the expected amount of data
         if offset != bytes.len() {
             return Err(format!(
@@ -281,7 +327,7 @@ impl Bytecode {
             instructions,
             constants,
             debug_info,
-            top_level_local_count: 0,
+            top_level_local_count,
         })
     }
 
@@ -320,12 +366,14 @@ impl Bytecode {
             self.instructions.push(opcode_byte);
             i += 1;
 
-            // Check if this opcode uses a constant index (u16 operand)
+            let opcode = Opcode::try_from(opcode_byte).ok();
+
+            // Opcodes whose first operand is a u16 constant index
             let uses_constant = matches!(
-                opcode_byte,
-                x if x == Opcode::Constant as u8
-                    || x == Opcode::GetGlobal as u8
-                    || x == Opcode::SetGlobal as u8
+                opcode,
+                Some(
+                    Opcode::Constant | Opcode::GetGlobal | Opcode::SetGlobal | Opcode::MakeClosure
+                )
             );
 
             if uses_constant && i + 1 < other.instructions.len() {
@@ -341,34 +389,32 @@ impl Bytecode {
                 self.instructions.push((new_index >> 8) as u8);
                 self.instructions.push((new_index & 0xFF) as u8);
                 i += 2;
-            } else if uses_constant {
-                // Malformed bytecode, but continue
-                while i < other.instructions.len() && i < 2 {
+            }
+
+            // Copy the remaining operand bytes unchanged
+            let operand_size = match opcode {
+                Some(
+                    Opcode::Jump
+                    | Opcode::JumpIfFalse
+                    | Opcode::Loop
+                    | Opcode::TryBegin
+                    | Opcode::GetLocal
+                    | Opcode::SetLocal
+                    | Opcode::GetUpvalue
+                    | Opcode::SetUpvalue
+                    | Opcode::Array
+                    | Opcode::NewMap,
+                ) => 2, // u16 operand
+                Some(Opcode::MakeClosure) if uses_constant => 2, // u16 upvalue count
+                Some(Opcode::Call | Opcode::TailCall) => 1,      // u8 operand
+                _ => 0,                                          // No operand
+            };
+
+            for _ in 0..operand_size {
+                if i < other.instructions.len() {
                     self.instructions.push(other.instructions[i]);
                     i += 1;
                 }
-            } else {
-                // Check opcode operand size and copy remaining bytes
-                // Most opcodes have known operand sizes
-                let operand_size = match opcode_byte {
-                    x if x == Opcode::Jump as u8
-                        || x == Opcode::JumpIfFalse as u8
-                        || x == Opcode::GetLocal as u8
-                        || x == Opcode::SetLocal as u8
-                        || x == Opcode::Array as u8 =>
-                    {
-                        2 // u16 operand
-                    }
-                    x if x == Opcode::Call as u8 => 1, // u8 operand
-                    _ => 0,                            // No operand
-                };
-
-                for _ in 0..operand_size {
-                    if i < other.instructions.len() {
-                        self.instructions.push(other.instructions[i]);
-                        i += 1;
-                    }
-                }
             }
         }
 
@@ -378,6 +424,31 @@ impl Bytecode {
             self.debug_info.push(debug_span);
         }
     }
+
+    /// Replace top-level functions with recompiled versions
+    ///
+    /// `patch` is bytecode compiled from just the changed function declarations.
+    /// It is appended, and every function constant named in `names` is repointed
+    /// at its new body. The superseded bodies stay in place but are unreachable.
+    pub fn replace_functions(&mut self, patch: Bytecode, names: &[String]) {
+        let first_patch_constant = self.constants.len();
+        self.append(patch);
+
+        let (existing, patched) = self.constants.split_at_mut(first_patch_constant);
+        for name in names {
+            let replacement = patched
+                .iter()
+                .find(|value| matches!(value, Value::Function(f) if &f.name == name));
+            let Some(replacement) = replacement else {
+                continue;
+            };
+            for constant in existing.iter_mut() {
+                if matches!(constant, Value::Function(f) if &f.name == name) {
+                    *constant = replacement.clone();
+                }
+            }
+        }
+    }
 }
 
 impl Default for Bytecode {
@@ -438,11 +509,17 @@ mod tests {
             Opcode::Jump,
             Opcode::JumpIfFalse,
             Opcode::Loop,
+            Opcode::TryBegin,
+            Opcode::TryEnd,
             Opcode::Call,
             Opcode::Return,
+            Opcode::TailCall,
             Opcode::Array,
             Opcode::GetIndex,
             Opcode::SetIndex,
+            Opcode::NewMap,
+            Opcode::MapGet,
+            Opcode::MapSet,
             Opcode::Pop,
             Opcode::Dup,
             Opcode::Halt,
@@ -811,8 +888,8 @@ mod tests {
 
         // Check header
         assert_eq!(&bytes[0..4], b"ATB\0");
-        // Version should be 1
-        assert_eq!(u16::from_be_bytes([bytes[4], bytes[5]]), 1);
+        // Version should be current
+        assert_eq!(u16::from_be_bytes([bytes[4], bytes[5]]), BYTECODE_VERSION);
         // Flags should be 0 (no debug info)
         assert_eq!(u16::from_be_bytes([bytes[6], bytes[7]]), 0);
         // Constants count should be 0
@@ -835,15 +912,17 @@ mod tests {
         bytecode.add_constant(Value::String(Arc::new("hello".to_string())));
         bytecode.add_constant(Value::Bool(true));
         bytecode.add_constant(Value::Null);
+        bytecode.add_constant(Value::Int(i64::MAX));
 
         let bytes = bytecode.to_bytes();
         let loaded = Bytecode::from_bytes(&bytes).unwrap();
 
-        assert_eq!(loaded.constants.len(), 4);
+        assert_eq!(loaded.constants.len(), 5);
         assert_eq!(loaded.constants[0], Value::Number(42.0));
         assert_eq!(loaded.constants[1], Value::string("hello"));
         assert_eq!(loaded.constants[2], Value::Bool(true));
         assert_eq!(loaded.constants[3], Value::Null);
+        assert_eq!(loaded.constants[4], Value::Int(i64::MAX));
     }
 
     #[test]
@@ -1008,7 +1087,7 @@ mod tests {
             name: "test_func".to_string(),
             arity: 2,
             bytecode_offset: 100,
-            local_count: 0,
+            local_count: 5,
             param_ownership: vec![],
             param_names: vec![],
             return_ownership: None,
@@ -1023,11 +1102,27 @@ mod tests {
                 assert_eq!(func.name, "test_func");
                 assert_eq!(func.arity, 2);
                 assert_eq!(func.bytecode_offset, 100);
+                assert_eq!(func.local_count, 5);
             }
             _ => panic!("Expected Function value"),
         }
     }
 
+    #[test]
+    fn test_bytecode_serialize_top_level_local_count() {
+        let mut bytecode = Bytecode::new();
+        bytecode.emit(Opcode::Halt, Span::dummy());
+        bytecode.top_level_local_count = 3;
+
+        let bytes = bytecode.to_bytes();
+        let flags = u16::from_be_bytes([bytes[6], bytes[7]]);
+        assert_eq!(flags & FLAG_TOP_LEVEL_LOCALS, FLAG_TOP_LEVEL_LOCALS);
+
+        let loaded = Bytecode::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.top_level_local_count, 3);
+        assert_eq!(loaded.debug_info, bytecode.debug_info);
+    }
+
     // ===== Debug Info Default Tests (Phase 15) =====
 
     #[test]
@@ -80,20 +80,32 @@ pub enum Opcode {
     JumpIfFalse = 0x51,
     /// Jump backward [i16 offset]
     Loop = 0x52,
+    /// Install an error handler whose catch block starts at [i16 offset]
+    TryBegin = 0x53,
+    /// Uninstall the innermost error handler and restore its stack height
+    TryEnd = 0x54,
 
     // ===== Functions (0x60-0x6F) =====
     /// Call function [u8 arg_count]
     Call = 0x60,
     /// Return from function
     Return = 0x61,
+    /// Call the current function again in place [u8 arg_count], reusing its frame
+    TailCall = 0x62,
 
-    // ===== Arrays (0x70-0x7F) =====
+    // ===== Arrays and maps (0x70-0x7F) =====
     /// Create array [u16 size] from stack
     Array = 0x70,
     /// Pop index, pop array, push array[index]
     GetIndex = 0x71,
     /// Pop value, pop index, pop array, array[index] = value
     SetIndex = 0x72,
+    /// Create map [u16 count] from `count` key/value pairs on the stack
+    NewMap = 0x73,
+    /// Pop key, pop map, push Option of map[key]
+    MapGet = 0x74,
+    /// Pop value, pop key, pop map, push copy of map with key set
+    MapSet = 0x75,
 
     // ===== Stack manipulation (0x80-0x8F) =====
     /// Pop and discard top of stack
@@ -158,11 +170,17 @@ impl TryFrom<u8> for Opcode {
             0x50 => Ok(Opcode::Jump),
             0x51 => Ok(Opcode::JumpIfFalse),
             0x52 => Ok(Opcode::Loop),
+            0x53 => Ok(Opcode::TryBegin),
+            0x54 => Ok(Opcode::TryEnd),
             0x60 => Ok(Opcode::Call),
             0x61 => Ok(Opcode::Return),
+            0x62 => Ok(Opcode::TailCall),
             0x70 => Ok(Opcode::Array),
             0x71 => Ok(Opcode::GetIndex),
             0x72 => Ok(Opcode::SetIndex),
+            0x73 => Ok(Opcode::NewMap),
+            0x74 => Ok(Opcode::MapGet),
+            0x75 => Ok(Opcode::MapSet),
             0x80 => Ok(Opcode::Pop),
             0x81 => Ok(Opcode::Dup),
             0x90 => Ok(Opcode::IsOptionSome),
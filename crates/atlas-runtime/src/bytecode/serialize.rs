@@ -58,6 +58,8 @@ pub(super) fn serialize_value(value: &Value, bytes: &mut Vec<u8>) {
                 Some(crate::ast::OwnershipAnnotation::Borrow) => 2,
                 Some(crate::ast::OwnershipAnnotation::Shared) => 3,
             });
+            // Serialize local_count
+            bytes.extend_from_slice(&(func.local_count as u32).to_be_bytes());
         }
         Value::Builtin(name) => {
             bytes.push(0x05); // Type tag for Builtin
@@ -65,6 +67,10 @@ pub(super) fn serialize_value(value: &Value, bytes: &mut Vec<u8>) {
             bytes.extend_from_slice(&(name_bytes.len() as u32).to_be_bytes());
             bytes.extend_from_slice(name_bytes);
         }
+        Value::Int(n) => {
+            bytes.push(0x06); // Type tag for Int
+            bytes.extend_from_slice(&n.to_be_bytes());
+        }
         Value::NativeFunction(_) => {
             // Native functions cannot be serialized in constant pool
             // They are runtime-only closures
@@ -75,6 +81,16 @@ pub(super) fn serialize_value(value: &Value, bytes: &mut Vec<u8>) {
             // They are runtime-only values
             panic!("Cannot serialize array values in bytecode constants");
         }
+        Value::Map(_) => {
+            // Map values cannot be serialized in constant pool
+            // They are runtime-only values
+            panic!("Cannot serialize map values in bytecode constants");
+        }
+        Value::Set(_) => {
+            // Set values cannot be serialized in constant pool
+            // They are runtime-only values
+            panic!("Cannot serialize set values in bytecode constants");
+        }
         Value::JsonValue(_) => {
             // JSON values cannot be serialized in constant pool
             // They are runtime-only values
@@ -256,13 +272,24 @@ pub(super) fn deserialize_value(bytes: &[u8]) -> Result<(Value, usize), String>
                 3 => Some(crate::ast::OwnershipAnnotation::Shared),
                 _ => None,
             };
-            let total_consumed = names_cursor + 1;
+            // Deserialize local_count
+            let count_start = names_cursor + 1;
+            if bytes.len() < count_start + 4 {
+                return Err("Truncated function local count".to_string());
+            }
+            let local_count = u32::from_be_bytes([
+                bytes[count_start],
+                bytes[count_start + 1],
+                bytes[count_start + 2],
+                bytes[count_start + 3],
+            ]) as usize;
+            let total_consumed = count_start + 4;
             Ok((
                 Value::Function(crate::value::FunctionRef {
                     name,
                     arity,
                     bytecode_offset: offset,
-                    local_count: 0, // Not serialized; set on recompile
+                    local_count,
                     param_ownership,
                     param_names,
                     return_ownership,
@@ -285,6 +312,13 @@ pub(super) fn deserialize_value(bytes: &[u8]) -> Result<(Value, usize), String>
                 5 + name_len,
             ))
         }
+        0x06 => {
+            if bytes.len() < 9 {
+                return Err("Truncated int value".to_string());
+            }
+            let int_bytes: [u8; 8] = bytes[1..9].try_into().unwrap();
+            Ok((Value::Int(i64::from_be_bytes(int_bytes)), 9))
+        }
         _ => Err(format!("Unknown value type tag: {:#x}", tag)),
     }
 }
@@ -215,7 +215,8 @@ fn read_operand(opcode: Opcode, code: &[u8], ip: usize) -> Result<(usize, i64),
         | Opcode::SetGlobal
         | Opcode::GetUpvalue
         | Opcode::SetUpvalue
-        | Opcode::Array => {
+        | Opcode::Array
+        | Opcode::NewMap => {
             if ip + 1 >= code.len() {
                 return Err(opcode_name(opcode));
             }
@@ -233,7 +234,7 @@ fn read_operand(opcode: Opcode, code: &[u8], ip: usize) -> Result<(usize, i64),
             Ok((4, ((hi << 8) | lo) as i64))
         }
         // 2-byte signed operand (i16)
-        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop => {
+        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop | Opcode::TryBegin => {
             if ip + 1 >= code.len() {
                 return Err(opcode_name(opcode));
             }
@@ -243,7 +244,7 @@ fn read_operand(opcode: Opcode, code: &[u8], ip: usize) -> Result<(usize, i64),
             Ok((2, value as i64))
         }
         // 1-byte operand (u8)
-        Opcode::Call => {
+        Opcode::Call | Opcode::TailCall => {
             if ip >= code.len() {
                 return Err(opcode_name(opcode));
             }
@@ -283,11 +284,17 @@ fn opcode_name(opcode: Opcode) -> &'static str {
         Opcode::Jump => "Jump",
         Opcode::JumpIfFalse => "JumpIfFalse",
         Opcode::Loop => "Loop",
+        Opcode::TryBegin => "TryBegin",
+        Opcode::TryEnd => "TryEnd",
         Opcode::Call => "Call",
         Opcode::Return => "Return",
+        Opcode::TailCall => "TailCall",
         Opcode::Array => "Array",
         Opcode::GetIndex => "GetIndex",
         Opcode::SetIndex => "SetIndex",
+        Opcode::NewMap => "NewMap",
+        Opcode::MapGet => "MapGet",
+        Opcode::MapSet => "MapSet",
         Opcode::Pop => "Pop",
         Opcode::Dup => "Dup",
         Opcode::IsOptionSome => "IsOptionSome",
@@ -320,7 +327,10 @@ fn check_jump_targets(
     for instr in decoded {
         let is_jump = matches!(
             instr.opcode,
-            Some(Opcode::Jump) | Some(Opcode::JumpIfFalse) | Some(Opcode::Loop)
+            Some(Opcode::Jump)
+                | Some(Opcode::JumpIfFalse)
+                | Some(Opcode::Loop)
+                | Some(Opcode::TryBegin)
         );
         if !is_jump {
             continue;
@@ -411,6 +421,7 @@ fn stack_delta(instr: &DecodedInstruction) -> Option<i32> {
         | Opcode::Not
         | Opcode::Jump
         | Opcode::Loop
+        | Opcode::TryBegin
         | Opcode::IsOptionSome
         | Opcode::IsOptionNone
         | Opcode::IsResultOk
@@ -438,16 +449,20 @@ fn stack_delta(instr: &DecodedInstruction) -> Option<i32> {
         | Opcode::GreaterEqual
         | Opcode::And
         | Opcode::Or
-        | Opcode::GetIndex => Some(-1),
+        | Opcode::GetIndex
+        | Opcode::MapGet => Some(-1),
 
         // Pop 3, push 1 (value assigned back)
-        Opcode::SetIndex => Some(-2),
+        Opcode::SetIndex | Opcode::MapSet => Some(-2),
 
-        // Variable-arity — skip (MakeClosure pops n_upvalues, push 1; net depends on operand)
-        Opcode::Call | Opcode::Array | Opcode::MakeClosure => None,
+        // Variable-arity — skip (MakeClosure pops n_upvalues, push 1; net depends on operand;
+        // TryEnd restores the height recorded by its TryBegin)
+        Opcode::Call | Opcode::Array | Opcode::NewMap | Opcode::MakeClosure | Opcode::TryEnd => {
+            None
+        }
 
-        // Return drains the frame — stop tracking
-        Opcode::Return => None,
+        // Return and TailCall drain the frame — stop tracking
+        Opcode::Return | Opcode::TailCall => None,
     }
 }
 
@@ -462,7 +477,7 @@ fn check_stack_depth(decoded: &[DecodedInstruction], errors: &mut Vec<Validation
                 // Call/Array/Return — reset depth tracking conservatively.
                 // After a Call we know net result is +1 (return value), but arity
                 // is unknown statically, so we just reset to a safe minimum.
-                if matches!(instr.opcode, Some(Opcode::Return)) {
+                if matches!(instr.opcode, Some(Opcode::Return) | Some(Opcode::TailCall)) {
                     break; // End of this code path
                 }
                 // For Call/Array: assume depth stays valid, reset to current
@@ -12,11 +12,26 @@ impl Compiler {
     pub(super) fn compile_expr(&mut self, expr: &Expr) -> Result<(), Vec<Diagnostic>> {
         match expr {
             Expr::Literal(lit, span) => self.compile_literal(lit, *span),
-            Expr::Identifier(ident) => self.compile_identifier(ident),
+            Expr::Identifier(ident) => {
+                self.compile_identifier(ident)?;
+                // Guard-narrowed binding: read the payload of its wrapper
+                match ident.unwrap_payload.get() {
+                    Some(PayloadKind::Option) => {
+                        self.bytecode.emit(Opcode::ExtractOptionValue, ident.span)
+                    }
+                    Some(PayloadKind::Result) => {
+                        self.bytecode.emit(Opcode::ExtractResultValue, ident.span)
+                    }
+                    None => {}
+                }
+                Ok(())
+            }
             Expr::Binary(bin) => self.compile_binary(bin),
             Expr::Unary(un) => self.compile_unary(un),
             Expr::Group(group) => self.compile_expr(&group.expr),
             Expr::ArrayLiteral(arr) => self.compile_array_literal(arr),
+            Expr::MapLiteral(map) => self.compile_map_literal(map),
+            Expr::StringInterp(interp) => self.compile_string_interp(interp),
             Expr::Index(index) => self.compile_index(index),
             Expr::Call(call) => self.compile_call(call),
             Expr::Match(match_expr) => self.compile_match(match_expr),
@@ -36,6 +51,23 @@ impl Compiler {
             }
         };
 
+        // Map access builtins compile to dedicated opcodes instead of a Call
+        let map_opcode = match (func_name.as_str(), call.args.len()) {
+            ("mapGet", 2) => Some(Opcode::MapGet),
+            ("mapSet", 3) => Some(Opcode::MapSet),
+            _ => None,
+        };
+        if let Some(opcode) = map_opcode {
+            if self.resolve_local(func_name).is_none() {
+                for arg in &call.args {
+                    self.compile_expr(arg)?;
+                }
+                self.bytecode.emit(opcode, call.span);
+                self.emit_cow_writeback_if_needed(func_name, call);
+                return Ok(());
+            }
+        }
+
         // Load the function from local or global scope
         // Don't hardcode builtins - let GetGlobal handle them so natives can override
         {
@@ -104,6 +136,10 @@ impl Compiler {
     ///   Result on stack becomes just `extracted` (item), new_col is written to var.
     fn emit_cow_writeback_if_needed(&mut self, func_name: &str, call: &CallExpr) {
         const RETURNS_COLLECTION: &[&str] = &[
+            // Map / Set
+            "mapSet",
+            "setAdd",
+            "setRemove",
             // HashMap
             "hashMapPut",
             "hashMapClear",
@@ -205,6 +241,18 @@ impl Compiler {
     /// The function name is determined from the method name using a standard mapping:
     ///   value.as_string() → jsonAsString(value)
     fn compile_member(&mut self, member: &MemberExpr) -> Result<(), Vec<Diagnostic>> {
+        // Record field read (`e.code`): index the field map by name
+        if member.field_access.get() {
+            self.compile_expr(&member.target)?;
+            let key_idx = self
+                .bytecode
+                .add_constant(crate::value::Value::string(&member.member.name));
+            self.bytecode.emit(Opcode::Constant, member.span);
+            self.bytecode.emit_u16(key_idx);
+            self.bytecode.emit(Opcode::GetIndex, member.span);
+            return Ok(());
+        }
+
         // Check for trait dispatch (user-defined impl methods) first.
         // The typechecker annotates `trait_dispatch` when a trait method is resolved.
         if let Some((type_name, trait_name)) = member.trait_dispatch.borrow().clone() {
@@ -237,6 +285,28 @@ impl Compiler {
             return Ok(());
         }
 
+        // Extension dispatch: call the user `fn name(self: T, ...)` function by name
+        if let Some(func_name) = member.extension_dispatch.borrow().clone() {
+            let name_idx = self
+                .bytecode
+                .add_constant(crate::value::Value::string(&func_name));
+            self.bytecode.emit(Opcode::GetGlobal, member.span);
+            self.bytecode.emit_u16(name_idx);
+
+            self.compile_expr(&member.target)?;
+            if let Some(args) = &member.args {
+                for arg in args {
+                    self.compile_expr(arg)?;
+                }
+            }
+
+            let arg_count = 1 + member.args.as_ref().map(|a| a.len()).unwrap_or(0);
+            self.bytecode.emit(Opcode::Call, member.span);
+            self.bytecode.emit_u8(arg_count as u8);
+
+            return Ok(());
+        }
+
         // Resolve method via shared dispatch table (type tag set by typechecker)
         let type_tag = member
             .type_tag
@@ -308,6 +378,11 @@ impl Compiler {
                 self.bytecode.emit(Opcode::Constant, span);
                 self.bytecode.emit_u16(idx);
             }
+            Literal::Int(n) => {
+                let idx = self.bytecode.add_constant(Value::Int(*n));
+                self.bytecode.emit(Opcode::Constant, span);
+                self.bytecode.emit_u16(idx);
+            }
             Literal::String(s) => {
                 let idx = self.bytecode.add_constant(Value::string(s));
                 self.bytecode.emit(Opcode::Constant, span);
@@ -464,6 +539,51 @@ impl Compiler {
         Ok(())
     }
 
+    /// Compile a map literal
+    fn compile_map_literal(&mut self, map: &MapLiteral) -> Result<(), Vec<Diagnostic>> {
+        // Push each key/value pair (leaves them on stack)
+        for entry in &map.entries {
+            let key_idx = self.bytecode.add_constant(Value::string(&entry.key));
+            self.bytecode.emit(Opcode::Constant, entry.key_span);
+            self.bytecode.emit_u16(key_idx);
+            self.compile_expr(&entry.value)?;
+        }
+
+        // Emit NewMap instruction with pair count
+        self.bytecode.emit(Opcode::NewMap, map.span);
+        self.bytecode.emit_u16(map.entries.len() as u16);
+
+        Ok(())
+    }
+
+    /// Compile an interpolated string
+    ///
+    /// Lowered to the equivalent manual concatenation:
+    /// `"a ${x} b"` compiles like `"a " + toString(x) + " b"`.
+    fn compile_string_interp(&mut self, interp: &StringInterpExpr) -> Result<(), Vec<Diagnostic>> {
+        for (i, part) in interp.parts.iter().enumerate() {
+            match part {
+                StringInterpPart::Literal(text) => {
+                    let idx = self.bytecode.add_constant(Value::string(text));
+                    self.bytecode.emit(Opcode::Constant, interp.span);
+                    self.bytecode.emit_u16(idx);
+                }
+                StringInterpPart::Expr(expr) => {
+                    let name_idx = self.bytecode.add_constant(Value::string("toString"));
+                    self.bytecode.emit(Opcode::GetGlobal, expr.span());
+                    self.bytecode.emit_u16(name_idx);
+                    self.compile_expr(expr)?;
+                    self.bytecode.emit(Opcode::Call, expr.span());
+                    self.bytecode.emit_u8(1);
+                }
+            }
+            if i > 0 {
+                self.bytecode.emit(Opcode::Add, interp.span);
+            }
+        }
+        Ok(())
+    }
+
     /// Compile an index expression
     fn compile_index(&mut self, index: &IndexExpr) -> Result<(), Vec<Diagnostic>> {
         // Compile the target (array)
@@ -649,6 +769,11 @@ impl Compiler {
                         self.bytecode.emit(Opcode::Constant, *lit_span);
                         self.bytecode.emit_u16(const_idx);
                     }
+                    Literal::Int(n) => {
+                        let const_idx = self.bytecode.add_constant(Value::Int(*n));
+                        self.bytecode.emit(Opcode::Constant, *lit_span);
+                        self.bytecode.emit_u16(const_idx);
+                    }
                     Literal::String(s) => {
                         let const_idx = self.bytecode.add_constant(Value::string(s.clone()));
                         self.bytecode.emit(Opcode::Constant, *lit_span);
@@ -33,6 +33,13 @@ pub(super) struct Local {
 pub(super) struct LoopContext {
     pub(super) start_offset: usize,
     pub(super) break_jumps: Vec<usize>,
+    /// Loop label, if the loop was written as `label: while ...`
+    pub(super) label: Option<String>,
+    /// Hidden stack slots the loop keeps alive while running (the for-in iterator state).
+    /// A labelled jump out of this loop to an outer one must pop them first.
+    pub(super) hidden_slots: usize,
+    /// Number of enclosing `try` bodies when the loop started
+    pub(super) try_depth: usize,
 }
 
 /// How an upvalue is sourced when building a closure.
@@ -88,6 +95,11 @@ pub struct Compiler {
     /// Stack of upvalue contexts, one entry per active nested function compilation.
     /// Empty when not inside any nested function.
     pub(super) upvalue_stack: Vec<UpvalueContext>,
+    /// Name of the enclosing top-level function whose self calls in tail position
+    /// compile to `TailCall`. `None` outside functions and inside nested functions.
+    pub(super) tail_call_target: Option<String>,
+    /// Number of `try` bodies enclosing the code being compiled
+    pub(super) try_depth: usize,
 }
 
 impl Compiler {
@@ -105,6 +117,8 @@ impl Compiler {
             global_mutability: std::collections::HashMap::new(),
             locals_watermark: 0,
             upvalue_stack: Vec::new(),
+            tail_call_target: None,
+            try_depth: 0,
         }
     }
 
@@ -129,6 +143,8 @@ impl Compiler {
             global_mutability: std::collections::HashMap::new(),
             locals_watermark: 0,
             upvalue_stack: Vec::new(),
+            tail_call_target: None,
+            try_depth: 0,
         }
     }
 
@@ -181,6 +197,8 @@ impl Compiler {
                         self.compile_stmt(&crate::ast::Stmt::VarDecl(var.clone()))
                     }
                     crate::ast::ExportItem::TypeAlias(_) => Ok(()),
+                    // Forwarded symbols are globals defined by the source module
+                    crate::ast::ExportItem::ReExport(_) => Ok(()),
                 }
             }
             Item::Extern(_) => {
@@ -262,10 +280,20 @@ impl Compiler {
         // Track function base for nested function support
         let prev_function_base = std::mem::replace(&mut self.current_function_base, old_locals_len);
 
+        // Self calls in tail position reuse the frame. Ownership-annotated parameters
+        // keep a regular Call so the VM still enforces their contracts.
+        let tail_call_target = func
+            .params
+            .iter()
+            .all(|p| p.ownership.is_none())
+            .then(|| func.name.name.clone());
+        let prev_tail_call_target = std::mem::replace(&mut self.tail_call_target, tail_call_target);
+
         // Compile function body
         self.compile_block(&func.body)?;
 
         // Restore function base
+        self.tail_call_target = prev_tail_call_target;
         self.current_function_base = prev_function_base;
 
         // Calculate total local count using the watermark.
@@ -57,7 +57,11 @@ impl Compiler {
             captures: Vec::new(),
         });
 
+        // Nested functions are reached through locals/upvalues, not a global, so their
+        // self calls stay ordinary calls.
+        let prev_tail_call_target = self.tail_call_target.take();
         self.compile_block(&func.body)?;
+        self.tail_call_target = prev_tail_call_target;
 
         // Pop upvalue context — now we know all captured outer-scope variables
         let upvalue_ctx = self.upvalue_stack.pop().expect("upvalue context missing");
@@ -169,6 +173,11 @@ impl Compiler {
                 Ok(())
             }
             Stmt::Return(ret) => {
+                if let Some(Expr::Call(call)) = &ret.value {
+                    if self.is_self_tail_call(call) {
+                        return self.compile_tail_call(call);
+                    }
+                }
                 if let Some(expr) = &ret.value {
                     self.compile_expr(expr)?;
                 } else {
@@ -181,14 +190,36 @@ impl Compiler {
             Stmt::While(while_stmt) => self.compile_while(while_stmt),
             Stmt::For(for_stmt) => self.compile_for(for_stmt),
             Stmt::ForIn(for_in_stmt) => self.compile_for_in(for_in_stmt),
-            Stmt::Break(span) => self.compile_break(*span),
-            Stmt::Continue(span) => self.compile_continue(*span),
+            Stmt::TryCatch(try_catch) => self.compile_try_catch(try_catch),
+            Stmt::Break(break_stmt) => self.compile_break(break_stmt),
+            Stmt::Continue(continue_stmt) => self.compile_continue(continue_stmt),
             Stmt::CompoundAssign(compound) => self.compile_compound_assign(compound),
             Stmt::Increment(inc) => self.compile_increment(inc),
             Stmt::Decrement(dec) => self.compile_decrement(dec),
         }
     }
 
+    /// Check whether `call` re-enters the enclosing top-level function by name
+    fn is_self_tail_call(&self, call: &CallExpr) -> bool {
+        match (&self.tail_call_target, call.callee.as_ref()) {
+            (Some(target), Expr::Identifier(id)) => {
+                id.name == *target && self.resolve_local(&id.name).is_none()
+            }
+            _ => false,
+        }
+    }
+
+    /// Compile `return f(args)` inside `f` as a `TailCall`, which reuses the frame
+    fn compile_tail_call(&mut self, call: &CallExpr) -> Result<(), Vec<Diagnostic>> {
+        self.compile_expr(&call.callee)?;
+        for arg in &call.args {
+            self.compile_expr(arg)?;
+        }
+        self.bytecode.emit(Opcode::TailCall, call.span);
+        self.bytecode.emit_u8(call.args.len() as u8);
+        Ok(())
+    }
+
     /// Compile a variable declaration
     fn compile_var_decl(&mut self, decl: &VarDecl) -> Result<(), Vec<Diagnostic>> {
         // Compile the initializer
@@ -361,6 +392,9 @@ impl Compiler {
         self.loops.push(LoopContext {
             start_offset: loop_start,
             break_jumps: Vec::new(),
+            label: while_stmt.label.as_ref().map(|l| l.name.clone()),
+            hidden_slots: 0,
+            try_depth: self.try_depth,
         });
 
         // Compile condition
@@ -403,6 +437,9 @@ impl Compiler {
         self.loops.push(LoopContext {
             start_offset: loop_start,
             break_jumps: Vec::new(),
+            label: for_stmt.label.as_ref().map(|l| l.name.clone()),
+            hidden_slots: 0,
+            try_depth: self.try_depth,
         });
 
         // Compile condition
@@ -531,6 +568,9 @@ impl Compiler {
         self.loops.push(crate::compiler::LoopContext {
             start_offset: increment_start,
             break_jumps: Vec::new(),
+            label: for_in_stmt.label.as_ref().map(|l| l.name.clone()),
+            hidden_slots: 4,
+            try_depth: self.try_depth,
         });
 
         // if idx < len → continue; else jump to cleanup
@@ -580,6 +620,57 @@ impl Compiler {
         Ok(())
     }
 
+    /// Compile a try/catch statement
+    ///
+    /// Layout:
+    ///   TryBegin → handler           ; install the handler
+    ///   <body>
+    ///   TryEnd                       ; uninstall it and drop the body's locals
+    ///   Jump → end
+    ///   handler:                     ; the VM pushes the error value here
+    ///   <handler>
+    ///   Pop × (handler locals + 1)
+    ///   end:
+    fn compile_try_catch(&mut self, try_catch: &TryCatchStmt) -> Result<(), Vec<Diagnostic>> {
+        let span = try_catch.span;
+        let locals_before = self.locals.len();
+
+        self.bytecode.emit(Opcode::TryBegin, span);
+        let handler_jump = self.bytecode.current_offset();
+        self.bytecode.emit_u16(0xFFFF); // Placeholder — patched to the handler
+
+        // A tail call would leave the frame the handler belongs to
+        let prev_tail_call_target = self.tail_call_target.take();
+        self.try_depth += 1;
+        let body_result = self.compile_block(&try_catch.body);
+        self.try_depth -= 1;
+        self.tail_call_target = prev_tail_call_target;
+        body_result?;
+
+        self.bytecode.emit(Opcode::TryEnd, span);
+        self.locals.truncate(locals_before);
+        self.bytecode.emit(Opcode::Jump, span);
+        let end_jump = self.bytecode.current_offset();
+        self.bytecode.emit_u16(0xFFFF); // Placeholder — patched to the end
+
+        // ── Handler: the error value occupies the slot of the catch binding ──
+        self.bytecode.patch_jump(handler_jump);
+        self.push_local(Local {
+            name: try_catch.error.name.clone(),
+            depth: self.scope_depth + 1,
+            mutable: false,
+            scoped_name: None,
+        });
+        self.compile_block(&try_catch.handler)?;
+        for _ in locals_before..self.locals.len() {
+            self.bytecode.emit(Opcode::Pop, span);
+        }
+        self.locals.truncate(locals_before);
+
+        self.bytecode.patch_jump(end_jump);
+        Ok(())
+    }
+
     /// Compile a compound assignment (+=, -=, *=, /=, %=)
     fn compile_compound_assign(
         &mut self,
@@ -1030,40 +1121,74 @@ impl Compiler {
         Ok(())
     }
 
+    /// Find the loop a `break`/`continue` targets and unwind everything being
+    /// jumped out of: `try` handlers first, then the hidden slots of inner loops.
+    ///
+    /// Returns the index into `self.loops`, or `None` if there is no matching
+    /// loop (the typechecker reports that case).
+    fn resolve_jump_target(&mut self, label: Option<&Identifier>, span: Span) -> Option<usize> {
+        let target = match label {
+            Some(label) => self
+                .loops
+                .iter()
+                .rposition(|ctx| ctx.label.as_deref() == Some(label.name.as_str()))?,
+            None => self.loops.len().checked_sub(1)?,
+        };
+        // TryEnd restores the stack height of its `try`, which already drops
+        // the slots of loops started inside the outermost crossed `try`
+        let target_try_depth = self.loops[target].try_depth;
+        for _ in target_try_depth..self.try_depth {
+            self.bytecode.emit(Opcode::TryEnd, span);
+        }
+        let inner_slots: usize = self.loops[target + 1..]
+            .iter()
+            .filter(|ctx| ctx.try_depth == target_try_depth)
+            .map(|ctx| ctx.hidden_slots)
+            .sum();
+        for _ in 0..inner_slots {
+            self.bytecode.emit(Opcode::Pop, span);
+        }
+        Some(target)
+    }
+
     /// Compile a break statement
-    fn compile_break(&mut self, span: Span) -> Result<(), Vec<Diagnostic>> {
-        if let Some(loop_ctx) = self.loops.last_mut() {
+    fn compile_break(&mut self, break_stmt: &BreakStmt) -> Result<(), Vec<Diagnostic>> {
+        let span = break_stmt.span;
+        if let Some(target) = self.resolve_jump_target(break_stmt.label.as_ref(), span) {
             // Emit jump and save offset to patch later
             self.bytecode.emit(Opcode::Jump, span);
             let jump_offset = self.bytecode.current_offset();
             self.bytecode.emit_u16(0xFFFF); // Placeholder
-            loop_ctx.break_jumps.push(jump_offset);
-            Ok(())
-        } else {
-            // Error: break outside loop (should be caught by typechecker)
-            Ok(())
+            self.loops[target].break_jumps.push(jump_offset);
         }
+        // Otherwise: break outside loop (should be caught by typechecker)
+        Ok(())
     }
 
     /// Compile a continue statement
-    fn compile_continue(&mut self, span: Span) -> Result<(), Vec<Diagnostic>> {
-        if let Some(loop_ctx) = self.loops.last() {
+    fn compile_continue(&mut self, continue_stmt: &ContinueStmt) -> Result<(), Vec<Diagnostic>> {
+        let span = continue_stmt.span;
+        if let Some(target) = self.resolve_jump_target(continue_stmt.label.as_ref(), span) {
             // Jump back to loop start
             // Offset needs to account for the Loop instruction (1 byte) + offset operand (2 bytes) = 3 bytes
-            let offset = loop_ctx.start_offset as i32 - (self.bytecode.current_offset() as i32 + 3);
+            let start_offset = self.loops[target].start_offset;
+            let offset = start_offset as i32 - (self.bytecode.current_offset() as i32 + 3);
             self.bytecode.emit(Opcode::Loop, span);
             self.bytecode.emit_i16(offset as i16);
-            Ok(())
-        } else {
-            // Error: continue outside loop (should be caught by typechecker)
-            Ok(())
         }
+        // Otherwise: continue outside loop (should be caught by typechecker)
+        Ok(())
     }
 
     /// Compile a block
     pub(super) fn compile_block(&mut self, block: &Block) -> Result<(), Vec<Diagnostic>> {
         for stmt in &block.statements {
             self.compile_stmt(stmt)?;
+            // Anything after return/break/continue can never run (the typechecker
+            // warns about it), so don't emit it
+            if stmt.is_terminator() {
+                break;
+            }
         }
         Ok(())
     }
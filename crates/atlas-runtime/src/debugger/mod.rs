@@ -40,7 +40,7 @@ pub mod stepping;
 // Re-export the most commonly used types at the `debugger` crate level.
 pub use protocol::{
     Breakpoint, BreakpointId, DebugEvent, DebugRequest, DebugResponse, DebugStackFrame,
-    PauseReason, SourceLocation, Variable,
+    PauseReason, SourceLocation, Variable, WatchValue,
 };
 pub use source_map::SourceMap;
 pub use state::{DebuggerState, ExecutionMode, StepMode};
@@ -71,6 +71,8 @@ pub struct DebuggerSession {
     state: DebuggerState,
     /// Bidirectional source map (offset ↔ source location).
     source_map: SourceMap,
+    /// Watch expressions re-evaluated on every pause.
+    inspector: Inspector,
 }
 
 impl DebuggerSession {
@@ -87,6 +89,7 @@ impl DebuggerSession {
             vm,
             state: DebuggerState::new(),
             source_map,
+            inspector: Inspector::new(),
         }
     }
 
@@ -187,6 +190,32 @@ impl DebuggerSession {
                 let location = self.source_map.location_for_offset(ip).cloned();
                 DebugResponse::Location { location, ip }
             }
+
+            // ── Watches ───────────────────────────────────────────────────────
+            DebugRequest::AddWatch { expression } => {
+                self.inspector.add_watch(expression.clone());
+                DebugResponse::WatchAdded { expression }
+            }
+
+            DebugRequest::RemoveWatch { expression } => {
+                if self.inspector.remove_watch(&expression) {
+                    DebugResponse::WatchRemoved { expression }
+                } else {
+                    DebugResponse::error(format!("no watch for '{expression}'"))
+                }
+            }
+
+            DebugRequest::GetWatches { frame_index } => {
+                let watches = self
+                    .inspector
+                    .watch_expressions()
+                    .iter()
+                    .map(|expr| {
+                        WatchValue::from_eval(expr, self.evaluate_in_context(expr, frame_index))
+                    })
+                    .collect();
+                DebugResponse::Watches { watches }
+            }
         }
     }
 
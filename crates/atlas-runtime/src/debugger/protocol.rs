@@ -139,6 +139,48 @@ impl Variable {
     }
 }
 
+// ── Watch value ───────────────────────────────────────────────────────────────
+
+/// The current value of a watch expression.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchValue {
+    /// The watched expression as registered.
+    pub expression: String,
+    /// Human-readable value (if evaluation succeeded).
+    pub value: Option<String>,
+    /// Atlas type name of the value (if evaluation succeeded).
+    pub type_name: Option<String>,
+    /// Error message (if the expression could not be evaluated in this frame).
+    pub error: Option<String>,
+}
+
+impl WatchValue {
+    /// Build a watch value from the response to an `Evaluate` request.
+    pub fn from_eval(expression: impl Into<String>, response: DebugResponse) -> Self {
+        let expression = expression.into();
+        match response {
+            DebugResponse::EvalResult { value, type_name } => Self {
+                expression,
+                value: Some(value),
+                type_name: Some(type_name),
+                error: None,
+            },
+            DebugResponse::Error { message } => Self {
+                expression,
+                value: None,
+                type_name: None,
+                error: Some(message),
+            },
+            other => Self {
+                expression,
+                value: None,
+                type_name: None,
+                error: Some(format!("unexpected response: {:?}", other)),
+            },
+        }
+    }
+}
+
 // ── Pause reason ─────────────────────────────────────────────────────────────
 
 /// Why execution was paused.
@@ -205,6 +247,17 @@ pub enum DebugRequest {
     },
     /// Get the current execution location (instruction pointer + source).
     GetLocation,
+
+    // ── Watches ─────────────────────────────────────────────────────────────
+    /// Register an expression to be re-evaluated on every pause.
+    AddWatch { expression: String },
+    /// Remove a previously registered watch expression.
+    RemoveWatch { expression: String },
+    /// Evaluate all watch expressions in the context of a stack frame.
+    GetWatches {
+        /// 0 = innermost frame.
+        frame_index: usize,
+    },
 }
 
 // ── Responses ────────────────────────────────────────────────────────────────
@@ -243,6 +296,12 @@ pub enum DebugResponse {
         location: Option<SourceLocation>,
         ip: usize,
     },
+    /// A watch expression was registered.
+    WatchAdded { expression: String },
+    /// A watch expression was removed.
+    WatchRemoved { expression: String },
+    /// Current values of all watch expressions, in registration order.
+    Watches { watches: Vec<WatchValue> },
     /// An error occurred processing the request.
     Error { message: String },
 }
@@ -537,6 +596,13 @@ mod tests {
                 frame_index: 0,
             },
             DebugRequest::GetLocation,
+            DebugRequest::AddWatch {
+                expression: "x".to_string(),
+            },
+            DebugRequest::RemoveWatch {
+                expression: "x".to_string(),
+            },
+            DebugRequest::GetWatches { frame_index: 0 },
         ];
         for req in &requests {
             let json = serialize_request(req).unwrap();
@@ -36,8 +36,38 @@ impl fmt::Display for DiagnosticLevel {
     }
 }
 
+/// Whether a labeled span marks a cause of the diagnostic or supporting context
+///
+/// Primary spans are underlined with `^`, secondary spans with `-`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelStyle {
+    /// Part of the problem itself, e.g. the value with the wrong type
+    Primary,
+    /// Context explaining the problem, e.g. the annotation that set the expectation
+    #[default]
+    Secondary,
+}
+
+impl LabelStyle {
+    fn is_secondary(&self) -> bool {
+        *self == LabelStyle::Secondary
+    }
+
+    /// Underline character used by the human formatters
+    pub fn marker(self) -> char {
+        match self {
+            LabelStyle::Primary => '^',
+            LabelStyle::Secondary => '-',
+        }
+    }
+}
+
 /// Secondary location for related diagnostic information
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// A location with a `label` is rendered caret-style under its source line;
+/// one without is rendered as a `note: related location at ...` line.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RelatedLocation {
     /// File path
     pub file: String,
@@ -49,6 +79,70 @@ pub struct RelatedLocation {
     pub length: usize,
     /// Description of this location
     pub message: String,
+    /// Short text shown next to the underline (optional)
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub label: String,
+    /// Primary or secondary underline
+    #[serde(skip_serializing_if = "LabelStyle::is_secondary", default)]
+    pub style: LabelStyle,
+    /// Source line string (optional; filled in by `enrich_diagnostic`)
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub snippet: String,
+}
+
+impl RelatedLocation {
+    /// Create a labeled span in the same source as the diagnostic
+    pub fn labeled(span: Span, label: impl Into<String>, style: LabelStyle) -> Self {
+        let label = label.into();
+        Self {
+            file: "<input>".to_string(),
+            line: 1,
+            column: span.start + 1,
+            length: span.end.saturating_sub(span.start),
+            message: label.clone(),
+            label,
+            style,
+            snippet: String::new(),
+        }
+    }
+}
+
+/// Where the human formatters draw a related location
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RelatedLayout {
+    /// An extra underline beneath the diagnostic's own snippet
+    UnderPrimary,
+    /// Its own snippet block
+    OwnSnippet,
+    /// A `note:` line
+    Note,
+}
+
+/// Machine-applicable source edit attached to a diagnostic
+///
+/// Replaces the bytes covered by `span` with `replacement`. A zero-width
+/// span is an insertion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextEdit {
+    /// Source range to replace (byte offsets)
+    pub span: Span,
+    /// Replacement text
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Replace `span` with `replacement`
+    pub fn replace(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Insert `text` at byte offset `at`
+    pub fn insert(at: usize, text: impl Into<String>) -> Self {
+        Self::replace(Span::new(at, at), text)
+    }
 }
 
 /// A diagnostic message (error or warning)
@@ -83,6 +177,9 @@ pub struct Diagnostic {
     /// Suggested fix (optional)
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub help: Option<String>,
+    /// Machine-applicable fixes (optional)
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub fixes: Vec<TextEdit>,
 }
 
 impl Diagnostic {
@@ -92,10 +189,12 @@ impl Diagnostic {
         message: impl Into<String>,
         span: Span,
     ) -> Self {
+        let code = code.into();
+        debug_assert_registered(&code);
         Self {
             diag_version: DIAG_VERSION,
             level: DiagnosticLevel::Error,
-            code: code.into(),
+            code,
             message: message.into(),
             file: "<unknown>".to_string(),
             line: 1,
@@ -106,6 +205,7 @@ impl Diagnostic {
             notes: Vec::new(),
             related: Vec::new(),
             help: None,
+            fixes: Vec::new(),
         }
     }
 
@@ -115,10 +215,12 @@ impl Diagnostic {
         message: impl Into<String>,
         span: Span,
     ) -> Self {
+        let code = code.into();
+        debug_assert_registered(&code);
         Self {
             diag_version: DIAG_VERSION,
             level: DiagnosticLevel::Warning,
-            code: code.into(),
+            code,
             message: message.into(),
             file: "<unknown>".to_string(),
             line: 1,
@@ -129,6 +231,7 @@ impl Diagnostic {
             notes: Vec::new(),
             related: Vec::new(),
             help: None,
+            fixes: Vec::new(),
         }
     }
 
@@ -184,6 +287,33 @@ impl Diagnostic {
         self
     }
 
+    /// Add a machine-applicable fix
+    pub fn with_fix(mut self, fix: TextEdit) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+
+    /// Add a labeled span pointing at another part of the same source
+    pub fn with_span_label(self, span: Span, label: impl Into<String>, style: LabelStyle) -> Self {
+        self.with_related_location(RelatedLocation::labeled(span, label, style))
+    }
+
+    /// Decide how a related location is drawn alongside this diagnostic
+    pub(crate) fn related_layout(&self, related: &RelatedLocation) -> RelatedLayout {
+        if related.label.is_empty() {
+            RelatedLayout::Note
+        } else if !self.snippet.is_empty()
+            && related.line == self.line
+            && (related.snippet.is_empty() || related.snippet == self.snippet)
+        {
+            RelatedLayout::UnderPrimary
+        } else if !related.snippet.is_empty() {
+            RelatedLayout::OwnSnippet
+        } else {
+            RelatedLayout::Note
+        }
+    }
+
     /// Format as human-readable string
     pub fn to_human_string(&self) -> String {
         let mut output = String::new();
@@ -216,6 +346,25 @@ impl Diagnostic {
                 }
                 output.push('\n');
             }
+
+            // Labeled spans on the same line
+            for related in &self.related {
+                if self.related_layout(related) == RelatedLayout::UnderPrimary {
+                    output.push_str(&format!("   | {}\n", underline(related)));
+                }
+            }
+        }
+
+        // Labeled spans on other lines
+        for related in &self.related {
+            if self.related_layout(related) == RelatedLayout::OwnSnippet {
+                output.push_str(&format!(
+                    "  ::: {}:{}:{}\n",
+                    related.file, related.line, related.column
+                ));
+                output.push_str(&format!("{:>2} | {}\n", related.line, related.snippet));
+                output.push_str(&format!("   | {}\n", underline(related)));
+            }
         }
 
         // Notes
@@ -225,10 +374,12 @@ impl Diagnostic {
 
         // Related locations
         for related in &self.related {
-            output.push_str(&format!(
-                "   = note: related location at {}:{}:{}: {}\n",
-                related.file, related.line, related.column, related.message
-            ));
+            if self.related_layout(related) == RelatedLayout::Note {
+                output.push_str(&format!(
+                    "   = note: related location at {}:{}:{}: {}\n",
+                    related.file, related.line, related.column, related.message
+                ));
+            }
         }
 
         // Help
@@ -250,6 +401,26 @@ impl Diagnostic {
     }
 }
 
+/// Padding, underline and label for a labeled span, e.g. `       ------ expected number`
+fn underline(related: &RelatedLocation) -> String {
+    let padding = " ".repeat(related.column.saturating_sub(1));
+    let marks = related
+        .style
+        .marker()
+        .to_string()
+        .repeat(related.length.max(1));
+    format!("{}{} {}", padding, marks, related.label)
+}
+
+/// Panic in debug builds if `code` has no entry in [`error_codes::ERROR_CODES`]
+fn debug_assert_registered(code: &str) {
+    debug_assert!(
+        error_codes::lookup(code).is_some(),
+        "diagnostic code {} is not registered in error_codes::ERROR_CODES",
+        code
+    );
+}
+
 /// Sort diagnostics by level (errors first), then by location
 pub fn sort_diagnostics(diagnostics: &mut [Diagnostic]) {
     diagnostics.sort_by(|a, b| {
@@ -392,6 +563,7 @@ mod tests {
                 column: 10,
                 length: 3,
                 message: "defined here".to_string(),
+                ..Default::default()
             });
 
         assert_eq!(diag.related.len(), 1);
@@ -416,7 +588,7 @@ mod tests {
         let warning = Diagnostic::warning("test", Span::new(0, 1));
         assert_eq!(warning.diag_version, DIAG_VERSION);
 
-        let warning_with_code = Diagnostic::warning_with_code("AW0001", "test", Span::new(0, 1));
+        let warning_with_code = Diagnostic::warning_with_code("AT2001", "test", Span::new(0, 1));
         assert_eq!(warning_with_code.diag_version, DIAG_VERSION);
     }
 
@@ -20,14 +20,22 @@ pub const UNDEFINED_SYMBOL: &str = "AT0002";
 pub const DIVIDE_BY_ZERO: &str = "AT0005";
 pub const ARRAY_OUT_OF_BOUNDS: &str = "AT0006";
 pub const INVALID_NUMERIC_RESULT: &str = "AT0007";
+pub const MAP_KEY_NOT_FOUND: &str = "AT0008";
+pub const INTEGER_OVERFLOW: &str = "AT0009";
+pub const CALL_DEPTH_EXCEEDED: &str = "AT0010";
+pub const STACK_OVERFLOW: &str = "AT0011";
+pub const GAS_EXHAUSTED: &str = "AT0012";
 pub const STDLIB_ARG_ERROR: &str = "AT0102";
 pub const STDLIB_VALUE_ERROR: &str = "AT0103";
+pub const INVALID_REGEX: &str = "AT0150";
 
 // AT03xx - Permission Errors
 pub const FILESYSTEM_PERMISSION_DENIED: &str = "AT0300";
 pub const NETWORK_PERMISSION_DENIED: &str = "AT0301";
 pub const PROCESS_PERMISSION_DENIED: &str = "AT0302";
 pub const ENVIRONMENT_PERMISSION_DENIED: &str = "AT0303";
+pub const RESOURCE_EXHAUSTED: &str = "AT0304";
+pub const CLOCK_PERMISSION_DENIED: &str = "AT0305";
 
 // AT1xxx - Syntax Errors
 pub const SYNTAX_ERROR: &str = "AT1000";
@@ -111,6 +119,13 @@ pub const COPY_TYPE_REQUIRED: &str = "AT3036";
 /// For example, `fn f<T: Display>(x: T)` requires `T` to implement `Display`.
 pub const TRAIT_BOUND_NOT_SATISFIED: &str = "AT3037";
 
+/// Fired when `break label;` or `continue label;` names a label that is not on an enclosing loop.
+/// Labels are written before a loop: `outer: for x in xs { ... }`.
+pub const UNDEFINED_LOOP_LABEL: &str = "AT3038";
+
+/// Fired when `unwrap` is applied to an Option or Result binding that no guard has checked.
+/// Check it first with `if (is_some(x))` / `if (is_ok(x))`, or use `expect` / `unwrap_or`.
+pub const UNCHECKED_UNWRAP: &str = "AT3039";
 // AT5xxx - Module System Errors
 pub const INVALID_MODULE_PATH: &str = "AT5001";
 pub const MODULE_NOT_FOUND: &str = "AT5002";
@@ -120,6 +135,9 @@ pub const IMPORT_RESOLUTION_FAILED: &str = "AT5005";
 pub const MODULE_NOT_EXPORTED: &str = "AT5006";
 pub const NAMESPACE_IMPORT_UNSUPPORTED: &str = "AT5007";
 pub const DUPLICATE_EXPORT: &str = "AT5008";
+/// Fired when a module imports one that is still being loaded, e.g. `a -> b -> a`.
+/// The message lists the full import cycle.
+pub const CIRCULAR_IMPORT: &str = "AT5009";
 
 // AT9xxx - Internal Errors
 pub const INTERNAL_ERROR: &str = "AT9995";
@@ -135,8 +153,10 @@ pub const GENERIC_WARNING: &str = "AW9999";
 pub struct ErrorCodeInfo {
     /// The error code string (e.g., "AT0001")
     pub code: &'static str,
-    /// Human-readable description
+    /// Human-readable description (the code's title)
     pub description: &'static str,
+    /// Long-form explanation shown by `atlas explain`
+    pub explanation: &'static str,
     /// Optional contextual help text
     pub help: Option<&'static str>,
 }
@@ -156,391 +176,553 @@ pub fn description_for(code: &str) -> Option<&'static str> {
     lookup(code).map(|e| e.description)
 }
 
+/// Render the full explanation of an error code, as printed by `atlas explain`
+///
+/// Codes are matched case-insensitively, so `at0300` finds `AT0300`.
+pub fn explain(code: &str) -> Option<String> {
+    let info = lookup(&code.to_ascii_uppercase())?;
+    let mut text = format!(
+        "{}: {}\n\n{}\n",
+        info.code, info.description, info.explanation
+    );
+    if let Some(help) = info.help {
+        text.push_str(&format!("\nhelp: {}\n", help));
+    }
+    Some(text)
+}
+
 /// All known error codes with descriptions and help
+///
+/// This is the single source of truth for codes: constructing a
+/// [`Diagnostic`](super::Diagnostic) with a code missing from this table
+/// panics in debug builds.
 pub static ERROR_CODES: &[ErrorCodeInfo] = &[
     // === AT0xxx: Runtime Errors ===
     ErrorCodeInfo {
         code: "AT0001",
         description: "Type mismatch",
+        explanation: "A runtime operation received a value of a type it cannot work with, such as adding a number to a bool. The typechecker catches most of these before the program runs; at runtime they come from values whose type is only known dynamically, like json or results of untyped stdlib calls.",
         help: Some("Ensure the types match. Use explicit type conversions if needed."),
     },
     ErrorCodeInfo {
         code: "AT0002",
         description: "Undefined symbol",
+        explanation: "A name was used that is not bound in any enclosing scope. Variables must be declared with `let` or `var` before use, and functions must be declared or imported into the module that calls them.",
         help: Some("Check spelling. The variable or function may not be in scope."),
     },
     ErrorCodeInfo {
         code: "AT0003",
         description: "Arity mismatch",
+        explanation: "A function value was called with a different number of arguments than it declares. Every parameter must be supplied; Atlas has no optional or variadic parameters for user functions.",
         help: Some("Check the function signature for the correct number of arguments."),
     },
     ErrorCodeInfo {
         code: "AT0004",
         description: "Invalid operation",
+        explanation: "An operator or builtin was applied to values it is not defined for, for example comparing two functions or negating a string.",
         help: Some("This operation is not supported for the given types."),
     },
     ErrorCodeInfo {
         code: "AT0005",
         description: "Division by zero",
+        explanation: "The right-hand side of `/` or `%` evaluated to zero. Atlas reports this as an error rather than producing Infinity or NaN, so guard the divisor when it can be zero.",
         help: Some("Check that the divisor is not zero before dividing."),
     },
     ErrorCodeInfo {
         code: "AT0006",
         description: "Array index out of bounds",
+        explanation: "An array was indexed with a position outside `0..len(array)`, or with a negative or non-integer number. Indices are checked on every access.",
         help: Some("Check array length with len() before accessing elements."),
     },
     ErrorCodeInfo {
         code: "AT0007",
         description: "Invalid numeric result (NaN or Infinity)",
+        explanation: "An arithmetic operation produced NaN or an infinite value. Atlas numbers must stay finite, so operations like `sqrt(-1)` or overflowing multiplications are reported instead of propagating NaN.",
         help: Some("Ensure the number is finite. Check inputs to math operations."),
     },
+    ErrorCodeInfo {
+        code: "AT0008",
+        description: "Map key not found",
+        explanation: "A map was indexed with a key it does not contain. Direct indexing requires the key to exist; `mapGet` returns an Option for lookups that may miss.",
+        help: Some("Check with mapHas() before indexing, or use mapGet() which returns an Option."),
+    },
+    ErrorCodeInfo {
+        code: "AT0009",
+        description: "Integer overflow",
+        explanation: "An `int` operation produced a result outside the signed 64-bit range. Integer arithmetic is checked and never wraps silently.",
+        help: Some("The int result does not fit in 64 bits. Use toFloat() for approximate arithmetic."),
+    },
+    ErrorCodeInfo {
+        code: "AT0010",
+        description: "Call depth limit exceeded",
+        explanation: "The program nested function calls deeper than the configured call depth limit. This is almost always unbounded recursion, such as a recursive function whose base case is never reached.",
+        help: Some("Check recursive functions for a missing base case."),
+    },
+    ErrorCodeInfo {
+        code: "AT0011",
+        description: "Operand stack overflow",
+        explanation: "The VM's operand stack grew past its limit while evaluating an expression. This happens with extremely deep expression nesting rather than deep recursion.",
+        help: Some("Break deeply nested expressions into intermediate variables."),
+    },
+    ErrorCodeInfo {
+        code: "AT0012",
+        description: "Gas exhausted",
+        explanation: "A gas-metered run charged more than its budget. Every executed instruction costs gas according to the run's schedule, and execution stops before the instruction that would overspend. The error cannot be caught with `try`.",
+        help: Some("The metered run spent its gas budget. Raise the limit or do less work per run."),
+    },
     // AT01xx: Stdlib errors
     ErrorCodeInfo {
         code: "AT0102",
         description: "Invalid stdlib argument",
+        explanation: "A stdlib function received an argument of the wrong type or outside its accepted range. The message names the function and the offending argument.",
         help: Some("Check the function documentation for valid argument types and ranges."),
     },
     ErrorCodeInfo {
         code: "AT0103",
         description: "Invalid value for stdlib operation",
+        explanation: "A stdlib operation received a value it cannot process, such as malformed input to a parser or decoder.",
         help: Some("The provided value is outside the expected range or type."),
     },
     ErrorCodeInfo {
         code: "AT0140",
         description: "Unhashable type",
+        explanation: "A value was used as a HashMap key or HashSet element but cannot be hashed. Only number, string, bool, and null are hashable; arrays, maps, and functions are not.",
         help: Some("Only number, string, bool, and null are hashable. Convert your value first."),
     },
+    ErrorCodeInfo {
+        code: "AT0150",
+        description: "Invalid regex pattern",
+        explanation: "A string passed to a regex function is not a valid regular expression. The message includes the parser's description of what is wrong with the pattern.",
+        help: Some("Check the pattern syntax. Use regexEscape() to match text literally."),
+    },
     // AT03xx: Permission errors
     ErrorCodeInfo {
         code: "AT0300",
         description: "Filesystem permission denied",
+        explanation: "The program tried to read or write a file, but the security context does not grant access to that path. Scripts run with no filesystem access unless it is granted explicitly, either per path or for the whole filesystem.",
         help: Some("Enable file permissions with --allow-file or adjust security settings."),
     },
     ErrorCodeInfo {
         code: "AT0301",
         description: "Network permission denied",
+        explanation: "The program tried to make a network request to a host the security context does not allow. Network access is denied by default and granted per host.",
         help: Some("Enable network permissions with --allow-network or adjust security settings."),
     },
     ErrorCodeInfo {
         code: "AT0302",
         description: "Process permission denied",
+        explanation: "The program tried to spawn a process, but the security context does not allow running that command. Process execution is denied by default.",
         help: Some("Enable process permissions with --allow-process or adjust security settings."),
     },
     ErrorCodeInfo {
         code: "AT0303",
         description: "Environment variable permission denied",
+        explanation: "The program tried to read or set an environment variable the security context does not grant. Environment access is denied by default and granted per variable.",
         help: Some("Enable environment permissions with --allow-env or adjust security settings."),
     },
+    ErrorCodeInfo {
+        code: "AT0304",
+        description: "Resource limit exceeded",
+        explanation: "The program exceeded a resource limit set in its security context, such as maximum execution time or memory.",
+        help: Some("Raise the limit in the SecurityContext or reduce the work the script does."),
+    },
+    ErrorCodeInfo {
+        code: "AT0305",
+        description: "Clock permission denied",
+        explanation: "The program tried to read the system clock, but the security context does not grant clock access. Clock access is gated so that sandboxed runs stay deterministic.",
+        help: Some(
+            "Grant clock access with SecurityContext::grant_clock or adjust security settings.",
+        ),
+    },
     // AT04xx: I/O errors
     ErrorCodeInfo {
         code: "AT0400",
         description: "I/O error",
+        explanation: "An operating system I/O operation failed after permission checks passed, for example because the file does not exist or the disk is full. The message includes the underlying OS error.",
         help: Some("Check file paths, permissions, and that the file system is accessible."),
     },
     // === AT1xxx: Syntax/Lexer Errors ===
     ErrorCodeInfo {
         code: "AT1000",
         description: "Syntax error",
+        explanation: "The source could not be parsed. This general code covers syntax errors without a more specific code; the message describes what the parser expected.",
         help: Some("Check the syntax near the indicated location."),
     },
     ErrorCodeInfo {
         code: "AT1001",
         description: "Unexpected token",
+        explanation: "The parser found a token that cannot appear at this position, such as a missing `;` followed by the next statement, or an unbalanced bracket.",
         help: Some("The parser encountered a token it didn't expect. Check for missing semicolons, brackets, or operators."),
     },
     ErrorCodeInfo {
         code: "AT1002",
         description: "Unterminated string literal",
+        explanation: "A string literal was opened with `\"` but the line or file ended before the closing quote. String literals cannot span lines without escape sequences.",
         help: Some("Add the closing quote to complete the string."),
     },
     ErrorCodeInfo {
         code: "AT1003",
         description: "Invalid escape sequence",
+        explanation: "A backslash in a string literal is followed by a character that is not a recognised escape.",
         help: Some("Valid escapes: \\n, \\t, \\r, \\\\, \\\", \\0. Use \\\\ for a literal backslash."),
     },
     ErrorCodeInfo {
         code: "AT1004",
         description: "Unterminated block comment",
+        explanation: "A `/*` block comment was never closed with `*/` before the end of the file.",
         help: Some("Add */ to close the block comment."),
     },
     ErrorCodeInfo {
         code: "AT1005",
         description: "Invalid number literal",
+        explanation: "A numeric literal is malformed, for example `1.2.3` or a number too large to represent.",
         help: Some("Check the number format. Numbers must be valid decimal or floating-point."),
     },
     ErrorCodeInfo {
         code: "AT1006",
         description: "Unexpected end of file",
+        explanation: "The file ended while the parser still expected more input, typically an unclosed `{`, `(` or `[` or a statement missing its `;`.",
         help: Some("The file ended unexpectedly. Check for missing closing brackets or semicolons."),
     },
     ErrorCodeInfo {
         code: "AT1012",
         description: "Cannot shadow prelude builtin at top level",
+        explanation: "A top-level declaration reuses the name of a prelude builtin such as `print` or `len`. Replacing builtins at module scope would change their meaning for the whole program, so it is rejected; local scopes may shadow them.",
         help: Some("Prelude builtins cannot be redefined at the top level. Use a different name or shadow in a nested scope."),
     },
     // === AT2xxx: Warnings ===
     ErrorCodeInfo {
         code: "AT2001",
         description: "Unused variable or parameter",
+        explanation: "A variable or parameter is declared but never read. Names that start with an underscore are exempt from this warning.",
         help: Some("Remove the unused binding or prefix with underscore: _name"),
     },
     ErrorCodeInfo {
         code: "AT2002",
         description: "Unreachable code",
+        explanation: "This code can never execute because every path before it returns, breaks, or continues.",
         help: Some("Remove this code or restructure your control flow."),
     },
     ErrorCodeInfo {
         code: "AT2003",
         description: "Duplicate declaration",
+        explanation: "The same name is declared twice in one scope. The later declaration hides the earlier one, which is usually a mistake.",
         help: Some("Remove the duplicate or rename one of the declarations."),
     },
     ErrorCodeInfo {
         code: "AT2004",
         description: "Unused function",
+        explanation: "A function is declared but never called or exported. Names that start with an underscore are exempt from this warning.",
         help: Some("Remove the unused function or prefix with underscore: _name"),
     },
     ErrorCodeInfo {
         code: "AT2005",
         description: "Variable shadowing",
+        explanation: "A local declaration reuses a name from an enclosing scope, hiding the outer binding for the rest of the block.",
         help: Some("This variable shadows a variable from an outer scope. Use a different name if unintentional."),
     },
     ErrorCodeInfo {
         code: "AT2006",
         description: "Constant condition",
+        explanation: "A condition evaluates to the same value every time, so one branch is dead. This usually indicates a typo or leftover debugging code.",
         help: Some("This condition is always true or always false. Simplify the expression."),
     },
     ErrorCodeInfo {
         code: "AT2007",
         description: "Unnecessary type annotation",
+        explanation: "A type annotation repeats exactly what the compiler would infer from the initializer.",
         help: Some("The type can be inferred. Consider removing the explicit annotation."),
     },
     ErrorCodeInfo {
         code: "AT2008",
         description: "Unused import",
+        explanation: "A name is imported but never used in the module.",
         help: Some("Remove the unused import statement."),
     },
     ErrorCodeInfo {
         code: "AT2009",
         description: "Deprecated type alias",
+        explanation: "A type alias marked as deprecated is still referenced. The message names the replacement when the alias declares one.",
         help: Some("Use the recommended replacement instead of the deprecated alias."),
     },
     ErrorCodeInfo {
         code: "AT2010",
         description: "`own` annotation on primitive type has no effect",
+        explanation: "Primitive values (number, bool, string) are always copied, so an `own` annotation on them has no effect.",
         help: Some("Primitive types (number, bool, string) are always copied. The `own` annotation is ignored."),
     },
     ErrorCodeInfo {
         code: "AT2011",
         description: "`borrow` annotation on `shared<T>` type is redundant",
+        explanation: "`shared<T>` values already have reference semantics, so marking a `shared<T>` parameter as `borrow` changes nothing.",
         help: Some("`shared<T>` already has reference semantics. The `borrow` annotation has no additional effect."),
     },
     ErrorCodeInfo {
         code: "AT2012",
         description: "Passing borrowed value to `own` parameter — ownership cannot transfer",
+        explanation: "A value received through a `borrow` parameter is passed on to an `own` parameter. A borrower cannot give away ownership it does not have.",
         help: Some("A `borrow` parameter cannot give up ownership. Pass an owned value instead."),
     },
     ErrorCodeInfo {
         code: "AT2013",
         description: "Non-Copy type passed without ownership annotation",
+        explanation: "A Move type is passed to a parameter without an ownership annotation, leaving it unclear whether the callee takes ownership. Annotate the parameter with `own` or `borrow`.",
         help: Some("This type is not Copy. Annotate the parameter with `own` or `borrow` to clarify ownership intent."),
     },
     // === AT3xxx: Semantic/Type Checking Errors ===
     ErrorCodeInfo {
         code: "AT3001",
         description: "Type error in expression",
+        explanation: "An expression's type does not match the type its context requires, such as passing a string where a number parameter is declared, or a `let` initializer that disagrees with its annotation.",
         help: Some("Check that the expression types are compatible."),
     },
     ErrorCodeInfo {
         code: "AT3002",
         description: "Binary operation type error",
+        explanation: "A binary operator was applied to operand types it is not defined for, such as `\"a\" - 1` or `true < false`.",
         help: Some("Ensure both operands have compatible types for this operator."),
     },
     ErrorCodeInfo {
         code: "AT3003",
         description: "Assignment to immutable variable",
+        explanation: "A variable declared with `let` was assigned after its initialization. Declare it with `var` if it needs to change.",
         help: Some("Use 'let mut' to declare a mutable variable."),
     },
     ErrorCodeInfo {
         code: "AT3004",
         description: "Missing return value",
+        explanation: "A function with a non-void return type has a code path that reaches the end of its body without returning a value.",
         help: Some("Ensure all code paths return a value of the declared return type."),
     },
     ErrorCodeInfo {
         code: "AT3005",
         description: "Function arity mismatch",
+        explanation: "A call passes a different number of arguments than the function's declared parameters.",
         help: Some("Check the function signature for the correct number of arguments."),
     },
     ErrorCodeInfo {
         code: "AT3006",
         description: "Expression is not callable",
+        explanation: "A call expression targets a value whose type is not a function.",
         help: Some("Only functions can be called. Check the type of this expression."),
     },
     ErrorCodeInfo {
         code: "AT3010",
         description: "Invalid index type",
+        explanation: "An index expression uses a key of the wrong type: arrays are indexed by number, and maps by their declared key type.",
         help: Some("Array indices must be numbers. HashMap keys must match the key type."),
     },
     ErrorCodeInfo {
         code: "AT3011",
         description: "Type is not indexable",
+        explanation: "Square-bracket indexing was applied to a type that does not support it. Only arrays, maps, and json values can be indexed.",
         help: Some("Only arrays and hashmaps can be indexed."),
     },
     ErrorCodeInfo {
         code: "AT3020",
         description: "Empty match expression",
+        explanation: "A `match` expression has no arms, so it cannot produce a value.",
         help: Some("Add at least one arm to the match expression."),
     },
     ErrorCodeInfo {
         code: "AT3021",
         description: "Match arm type mismatch",
+        explanation: "The arms of a `match` expression produce different types. Every arm must evaluate to the same type, which becomes the type of the whole expression.",
         help: Some("All match arms must return the same type."),
     },
     ErrorCodeInfo {
         code: "AT3022",
         description: "Pattern type mismatch",
+        explanation: "A `match` pattern cannot match the scrutinee's type, for example a string literal pattern against a number.",
         help: Some("The pattern type must be compatible with the matched value."),
     },
     ErrorCodeInfo {
         code: "AT3023",
         description: "Constructor arity mismatch",
+        explanation: "A constructor pattern such as `Some(x)` or `Ok(v)` lists a different number of fields than the constructor has.",
         help: Some("Check the constructor for the correct number of fields."),
     },
     ErrorCodeInfo {
         code: "AT3024",
         description: "Unknown constructor",
+        explanation: "A pattern names a constructor that is not defined for the matched type.",
         help: Some("This constructor is not defined. Check the type definition."),
     },
     ErrorCodeInfo {
         code: "AT3025",
         description: "Unsupported pattern type",
+        explanation: "The pattern form is not supported where it appears.",
         help: Some("This pattern form is not supported in this context."),
     },
     ErrorCodeInfo {
         code: "AT3026",
         description: "Array pattern type mismatch",
+        explanation: "An array pattern's elements do not fit the element type of the matched array.",
         help: Some("The array pattern must match the array element type."),
     },
     ErrorCodeInfo {
         code: "AT3027",
         description: "Non-exhaustive match",
+        explanation: "A `match` does not cover every possible value of the scrutinee. The message lists the missing cases; add arms for them or a wildcard `_` arm.",
         help: Some("Add a wildcard arm (_) or cover all possible cases."),
     },
     ErrorCodeInfo {
         code: "AT3028",
         description: "Passing non-`shared<T>` value to `shared` parameter",
+        explanation: "A plain value was passed to a parameter declared as `shared`. Shared parameters require a `shared<T>` value so that every holder sees the same reference.",
         help: Some("Wrap the value in a shared reference before passing it to a `shared` parameter."),
     },
     ErrorCodeInfo {
         code: "AT3029",
         description: "Duplicate impl block",
+        explanation: "A second `impl` of the same trait for the same type was declared. Each `(type, trait)` pair may have only one impl.",
         help: Some("A type can only implement a given trait once. Remove the duplicate impl block."),
     },
     // === AT3030+: Trait System Errors ===
     ErrorCodeInfo {
         code: "AT3030",
         description: "Cannot redefine built-in trait",
+        explanation: "A `trait` declaration uses the name of a built-in trait (Copy, Move, Drop, Display, Debug). Built-in traits are provided by the runtime and cannot be redeclared.",
         help: Some("Built-in traits (Copy, Move, Drop, Display, Debug) cannot be redeclared by user code."),
     },
     ErrorCodeInfo {
         code: "AT3031",
         description: "Trait already defined",
+        explanation: "A `trait` with this name was already declared in the same scope. Trait names must be unique.",
         help: Some("A trait with this name is already declared in scope. Use a different name."),
     },
     ErrorCodeInfo {
         code: "AT3032",
         description: "Trait not found",
+        explanation: "An `impl` block names a trait that has not been declared.",
         help: Some("The trait name was not declared. Declare it with `trait Name { ... }` before using it."),
     },
     ErrorCodeInfo {
         code: "AT3033",
         description: "impl block is missing required method",
+        explanation: "An `impl` block does not define every method its trait declares. The message lists the missing methods.",
         help: Some("The impl block must implement all methods declared in the trait."),
     },
     ErrorCodeInfo {
         code: "AT3034",
         description: "impl method signature does not match trait declaration",
+        explanation: "A method in an `impl` block has different parameter or return types than the trait's declaration of that method. Apart from `self`, the signatures must match exactly.",
         help: Some("The method's parameter types and return type must exactly match the trait definition."),
     },
     ErrorCodeInfo {
         code: "AT3035",
         description: "Type does not implement required trait",
+        explanation: "A method or bound requires a trait that the receiver's type does not implement.",
         help: Some("Add an `impl TraitName for TypeName { ... }` block to satisfy the trait requirement."),
     },
     ErrorCodeInfo {
         code: "AT3036",
         description: "Copy type required",
+        explanation: "A context that copies values implicitly received a Move type. Primitive types are Copy; user-defined types are Move unless they implement Copy.",
         help: Some("This operation requires a Copy type. Implement the Copy trait or use a value type."),
     },
     ErrorCodeInfo {
         code: "AT3037",
         description: "Trait bound not satisfied",
+        explanation: "A generic function was instantiated with a type argument that does not implement a trait required by the type parameter's bounds, such as `T: Display`.",
         help: Some("The type argument does not satisfy the required trait bound on this type parameter."),
     },
+    ErrorCodeInfo {
+        code: "AT3038",
+        description: "Undefined loop label",
+        explanation: "`break label;` or `continue label;` names a label that is not on an enclosing loop. Labels are written before the loop they name, e.g. `outer: while (...) { ... }`.",
+        help: Some("`break` and `continue` labels must name an enclosing loop, e.g. `outer: while (...) { ... }`."),
+    },
+    ErrorCodeInfo {
+        code: "AT3039",
+        description: "Unwrap of unchecked Option or Result",
+        explanation: "`unwrap` is applied to an Option or Result binding that no guard has checked, so it may fail at runtime. Check the binding first or use a non-failing alternative.",
+        help: Some("Guard the binding with `if (is_some(x))` or `if (is_ok(x))` before calling `unwrap`, or use `expect(x, message)` / `unwrap_or(x, default)`."),
+    },
     // === AT5xxx: Module System Errors ===
     ErrorCodeInfo {
         code: "AT5001",
         description: "Invalid module path",
+        explanation: "An import path is malformed. Module paths must start with `./` or `../` (relative to the importing file) or `/` (relative to the project root).",
         help: Some("Module paths must be valid file paths relative to the project root."),
     },
     ErrorCodeInfo {
         code: "AT5002",
         description: "Module not found",
+        explanation: "An import refers to a module file that does not exist at the resolved path.",
         help: Some("Check the module path and ensure the file exists."),
     },
     ErrorCodeInfo {
         code: "AT5003",
         description: "Circular dependency detected",
+        explanation: "Modules depend on each other in a cycle, so no initialization order exists.",
         help: Some("Reorganize modules to break the circular import chain."),
     },
     ErrorCodeInfo {
         code: "AT5004",
         description: "Export not found in module",
+        explanation: "An import names a symbol that the target module does not define.",
         help: Some("Check the module's exports. The symbol may not be exported."),
     },
     ErrorCodeInfo {
         code: "AT5005",
         description: "Import resolution failed",
+        explanation: "An import could not be resolved to a module. The message includes the underlying resolution error.",
         help: Some("Check the import path and module structure."),
     },
     ErrorCodeInfo {
         code: "AT5006",
         description: "Module does not export this symbol",
+        explanation: "An import names a symbol that exists in the target module but is not exported from it.",
         help: Some("Add 'export' to the symbol declaration in the source module."),
     },
     ErrorCodeInfo {
         code: "AT5007",
         description: "Namespace import not supported",
+        explanation: "Namespace imports (`import * as m from ...`) are not supported; import the names you need individually.",
         help: Some("Use named imports: import { name } from \"module\""),
     },
     ErrorCodeInfo {
         code: "AT5008",
         description: "Duplicate export",
+        explanation: "A module exports the same name more than once.",
         help: Some("Each symbol can only be exported once per module."),
     },
+    ErrorCodeInfo {
+        code: "AT5009",
+        description: "Circular import",
+        explanation: "A module imports a module that is still being loaded, for example `a -> b -> a`. The message lists the full import cycle.",
+        help: Some("Move the declarations both modules need into a third module that neither imports."),
+    },
     // === AT9xxx: Internal Errors ===
     ErrorCodeInfo {
         code: "AT9995",
         description: "Internal compiler error",
+        explanation: "The compiler reached a state it should never be in. This is a bug in Atlas, not in your program.",
         help: Some("This is a bug in the compiler. Please report it."),
     },
     ErrorCodeInfo {
         code: "AT9997",
         description: "Stack underflow",
+        explanation: "The VM tried to pop from an empty operand stack. This indicates malformed bytecode or a compiler bug.",
         help: Some("This is a VM internal error. Please report it."),
     },
     ErrorCodeInfo {
         code: "AT9998",
         description: "Unknown bytecode opcode",
+        explanation: "The VM encountered a byte that is not a valid opcode. This indicates corrupted or incompatible bytecode.",
         help: Some("This is a VM internal error. Please report it."),
     },
     ErrorCodeInfo {
         code: "AT9999",
         description: "Generic error",
+        explanation: "An error without a more specific code. Tools should treat the message as the only source of detail.",
         help: None,
     },
     ErrorCodeInfo {
         code: "AW9999",
         description: "Generic warning",
+        explanation: "A warning without a more specific code. Tools should treat the message as the only source of detail.",
         help: None,
     },
 ];
@@ -584,6 +766,29 @@ mod tests {
         assert_eq!(description_for("AT1001").unwrap(), "Unexpected token");
     }
 
+    #[test]
+    fn test_explain_includes_title_explanation_and_help() {
+        let text = explain("AT0300").unwrap();
+        assert!(text.starts_with("AT0300: Filesystem permission denied\n\n"));
+        assert!(text.contains("security context"));
+        assert!(text.ends_with(
+            "help: Enable file permissions with --allow-file or adjust security settings.\n"
+        ));
+        assert_eq!(explain("at0300"), Some(text));
+        assert!(explain("ZZZZ").is_none());
+    }
+
+    #[test]
+    fn test_all_codes_have_explanations() {
+        for entry in ERROR_CODES {
+            assert!(
+                entry.explanation.ends_with('.'),
+                "{} needs a full-sentence explanation",
+                entry.code
+            );
+        }
+    }
+
     #[test]
     fn test_all_codes_have_descriptions() {
         for entry in ERROR_CODES {
@@ -4,7 +4,7 @@
 //! terminal colors. Respects NO_COLOR environment variable and auto-detects
 //! terminal capabilities.
 
-use crate::diagnostic::{Diagnostic, DiagnosticLevel};
+use crate::diagnostic::{Diagnostic, DiagnosticLevel, LabelStyle, RelatedLayout, RelatedLocation};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 /// Color mode for diagnostic output
@@ -82,6 +82,13 @@ impl DiagnosticFormatter {
             self.write_snippet(w, diag)?;
         }
 
+        // Labeled spans on other lines
+        for related in &diag.related {
+            if diag.related_layout(related) == RelatedLayout::OwnSnippet {
+                self.write_related_snippet(w, diag, related)?;
+            }
+        }
+
         // Notes
         for note in &diag.notes {
             self.write_note(w, note)?;
@@ -89,13 +96,15 @@ impl DiagnosticFormatter {
 
         // Related locations
         for related in &diag.related {
-            self.write_note(
-                w,
-                &format!(
-                    "related location at {}:{}:{}: {}",
-                    related.file, related.line, related.column, related.message
-                ),
-            )?;
+            if diag.related_layout(related) == RelatedLayout::Note {
+                self.write_note(
+                    w,
+                    &format!(
+                        "related location at {}:{}:{}: {}",
+                        related.file, related.line, related.column, related.message
+                    ),
+                )?;
+            }
         }
 
         // Help
@@ -177,9 +186,73 @@ impl DiagnosticFormatter {
             writeln!(w)?;
         }
 
+        // Labeled spans on the same line
+        for related in &diag.related {
+            if diag.related_layout(related) == RelatedLayout::UnderPrimary {
+                self.write_underline(w, diag, related, &diag.snippet, gutter_width)?;
+            }
+        }
+
         Ok(())
     }
 
+    fn write_related_snippet(
+        &self,
+        w: &mut impl WriteColor,
+        diag: &Diagnostic,
+        related: &RelatedLocation,
+    ) -> std::io::Result<()> {
+        let gutter_width = format!("{}", related.line).len() + 1;
+
+        w.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+        write!(w, "  ::: ")?;
+        w.reset()?;
+        writeln!(w, "{}:{}:{}", related.file, related.line, related.column)?;
+
+        w.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+        write!(w, "{:>width$}| ", related.line, width = gutter_width)?;
+        w.reset()?;
+        writeln!(w, "{}", related.snippet)?;
+
+        self.write_underline(w, diag, related, &related.snippet, gutter_width)
+    }
+
+    /// Underline a labeled span, in the diagnostic's color when primary
+    fn write_underline(
+        &self,
+        w: &mut impl WriteColor,
+        diag: &Diagnostic,
+        related: &RelatedLocation,
+        snippet: &str,
+        gutter_width: usize,
+    ) -> std::io::Result<()> {
+        w.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+        write!(w, "{:>width$}| ", "", width = gutter_width)?;
+        w.reset()?;
+
+        let padding = compute_display_width(snippet, related.column.saturating_sub(1));
+        write!(w, "{}", " ".repeat(padding))?;
+
+        let color = match (related.style, diag.level) {
+            (LabelStyle::Secondary, _) => Color::Cyan,
+            (LabelStyle::Primary, DiagnosticLevel::Error) => Color::Red,
+            (LabelStyle::Primary, DiagnosticLevel::Warning) => Color::Yellow,
+        };
+        w.set_color(ColorSpec::new().set_fg(Some(color)).set_bold(true))?;
+        write!(
+            w,
+            "{} {}",
+            related
+                .style
+                .marker()
+                .to_string()
+                .repeat(related.length.max(1)),
+            related.label
+        )?;
+        w.reset()?;
+        writeln!(w)
+    }
+
     fn write_note(&self, w: &mut impl WriteColor, note: &str) -> std::io::Result<()> {
         w.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
         write!(w, "   = ")?;
@@ -253,10 +326,19 @@ pub fn offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
 }
 
 /// Enrich a diagnostic with source information (line, column, snippet)
-pub fn enrich_diagnostic(diag: Diagnostic, source: &str) -> Diagnostic {
+///
+/// Labeled related locations point into the same source and are enriched too.
+pub fn enrich_diagnostic(mut diag: Diagnostic, source: &str) -> Diagnostic {
+    for related in diag.related.iter_mut().filter(|r| !r.label.is_empty()) {
+        let (line, col) = offset_to_line_col(source, related.column.saturating_sub(1));
+        related.line = line;
+        related.column = col;
+        related.snippet = extract_snippet(source, line).unwrap_or_default();
+    }
     let span_start = diag.column.saturating_sub(1); // column is 1-based from span.start+1
-    let (line, _col) = offset_to_line_col(source, span_start);
+    let (line, col) = offset_to_line_col(source, span_start);
     let snippet = extract_snippet(source, line).unwrap_or_default();
+    diag.column = col;
     diag.with_line(line).with_snippet(snippet)
 }
 
@@ -390,9 +472,33 @@ mod tests {
         // enrich uses column-1 as offset, so offset = 11
         let enriched = enrich_diagnostic(diag, source);
         assert_eq!(enriched.line, 2);
+        assert_eq!(enriched.column, 1);
         assert!(!enriched.snippet.is_empty());
     }
 
+    #[test]
+    fn test_labeled_span_on_another_line_gets_own_snippet() {
+        let source = "let x: number =\n    \"hi\";";
+        let diag = Diagnostic::error_with_code("AT3001", "Type mismatch", Span::new(0, 25))
+            .with_label("expected number, found string")
+            .with_span_label(Span::new(7, 13), "expected `number`", LabelStyle::Secondary)
+            .with_span_label(
+                Span::new(20, 24),
+                "found `string` here",
+                LabelStyle::Primary,
+            );
+        let enriched = enrich_diagnostic(diag, source);
+        assert_eq!(enriched.related[1].line, 2);
+        assert_eq!(enriched.related[1].column, 5);
+
+        let output =
+            String::from_utf8(DiagnosticFormatter::plain().format_to_buffer(&enriched)).unwrap();
+        assert!(output.contains(" 1| let x: number =\n  | ^^^^^^^^^^^^^^^ expected number, found string\n  |        ------ expected `number`\n"));
+        assert!(output
+            .contains("  ::: <input>:2:5\n 2|     \"hi\";\n  |     ^^^^ found `string` here\n"));
+        assert!(!output.contains("related location"));
+    }
+
     #[test]
     fn test_format_to_string_matches_human_string() {
         let formatter = DiagnosticFormatter::plain();
@@ -19,10 +19,7 @@ pub fn normalize_diagnostic_for_testing(diag: &Diagnostic) -> Diagnostic {
         .iter()
         .map(|rel| RelatedLocation {
             file: normalize_path(&rel.file),
-            line: rel.line,
-            column: rel.column,
-            length: rel.length,
-            message: rel.message.clone(),
+            ..rel.clone()
         })
         .collect();
 
@@ -146,6 +143,7 @@ mod tests {
                 column: 10,
                 length: 3,
                 message: "defined here".to_string(),
+                ..Default::default()
             });
 
         let normalized = normalize_diagnostic_for_testing(&diag);
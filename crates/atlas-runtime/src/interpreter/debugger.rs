@@ -4,8 +4,10 @@
 //! achieving feature parity with the VM debugger.
 
 use crate::ast::{Block, Program, Stmt};
+use crate::debugger::inspection::Inspector;
 use crate::debugger::protocol::{
-    Breakpoint, DebugRequest, DebugResponse, DebugStackFrame, PauseReason, SourceLocation, Variable,
+    Breakpoint, DebugRequest, DebugResponse, DebugStackFrame, PauseReason, SourceLocation,
+    Variable, WatchValue,
 };
 use crate::debugger::state::{DebuggerState, StepMode};
 use crate::interpreter::Interpreter;
@@ -47,6 +49,8 @@ pub struct InterpreterDebuggerSession {
     call_stack: Vec<InterpreterStackFrame>,
     /// Current statement span (for location reporting).
     current_span: Option<Span>,
+    /// Watch expressions re-evaluated on every pause.
+    inspector: Inspector,
 }
 
 impl InterpreterDebuggerSession {
@@ -66,6 +70,7 @@ impl InterpreterDebuggerSession {
                 local_count: 0,
             }],
             current_span: None,
+            inspector: Inspector::new(),
         }
     }
 
@@ -157,6 +162,31 @@ impl InterpreterDebuggerSession {
                 let location = self.current_location();
                 DebugResponse::Location { location, ip: 0 }
             }
+
+            DebugRequest::AddWatch { expression } => {
+                self.inspector.add_watch(expression.clone());
+                DebugResponse::WatchAdded { expression }
+            }
+
+            DebugRequest::RemoveWatch { expression } => {
+                if self.inspector.remove_watch(&expression) {
+                    DebugResponse::WatchRemoved { expression }
+                } else {
+                    DebugResponse::error(format!("no watch for '{expression}'"))
+                }
+            }
+
+            DebugRequest::GetWatches { frame_index } => {
+                let watches = self
+                    .inspector
+                    .watch_expressions()
+                    .iter()
+                    .map(|expr| {
+                        WatchValue::from_eval(expr, self.evaluate_expression(expr, frame_index))
+                    })
+                    .collect();
+                DebugResponse::Watches { watches }
+            }
         }
     }
 
@@ -8,14 +8,20 @@ use std::sync::Arc;
 impl Interpreter {
     /// Evaluate an expression
     pub(super) fn eval_expr(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        if self.budget.is_active() {
+            self.charge_budget(expr.span())?;
+        }
+
         match expr {
             Expr::Literal(lit, _) => Ok(self.eval_literal(lit)),
-            Expr::Identifier(id) => self.get_variable(&id.name, id.span),
+            Expr::Identifier(id) => self.eval_identifier(id),
             Expr::Binary(binary) => self.eval_binary(binary),
             Expr::Unary(unary) => self.eval_unary(unary),
             Expr::Call(call) => self.eval_call(call),
             Expr::Index(index) => self.eval_index(index),
             Expr::ArrayLiteral(arr) => self.eval_array_literal(arr),
+            Expr::MapLiteral(map) => self.eval_map_literal(map),
+            Expr::StringInterp(interp) => self.eval_string_interp(interp),
             Expr::Group(group) => self.eval_expr(&group.expr),
             Expr::Match(match_expr) => self.eval_match(match_expr),
             Expr::Member(member) => self.eval_member(member),
@@ -23,10 +29,27 @@ impl Interpreter {
         }
     }
 
+    /// Evaluate an identifier, reading the payload of a guard-narrowed binding
+    fn eval_identifier(&self, id: &Identifier) -> Result<Value, RuntimeError> {
+        let value = self.get_variable(&id.name, id.span)?;
+        let Some(kind) = id.unwrap_payload.get() else {
+            return Ok(value);
+        };
+        match (kind, value) {
+            (PayloadKind::Option, Value::Option(Some(inner))) => Ok(*inner),
+            (PayloadKind::Result, Value::Result(Ok(inner) | Err(inner))) => Ok(*inner),
+            _ => Err(RuntimeError::TypeError {
+                msg: format!("'{}' no longer holds the value its guard checked", id.name),
+                span: id.span,
+            }),
+        }
+    }
+
     /// Evaluate a literal
     pub(super) fn eval_literal(&self, lit: &Literal) -> Value {
         match lit {
             Literal::Number(n) => Value::Number(*n),
+            Literal::Int(n) => Value::Int(*n),
             Literal::String(s) => Value::string(s.clone()),
             Literal::Bool(b) => Value::Bool(*b),
             Literal::Null => Value::Null,
@@ -74,6 +97,10 @@ impl Interpreter {
         let left = self.eval_expr(&binary.left)?;
         let right = self.eval_expr(&binary.right)?;
 
+        if let (Value::Int(a), Value::Int(b)) = (&left, &right) {
+            return crate::value::int_binary_op(binary.op, *a, *b, binary.span);
+        }
+
         match binary.op {
             BinaryOp::Add => match (&left, &right) {
                 (Value::Number(a), Value::Number(b)) => {
@@ -83,7 +110,10 @@ impl Interpreter {
                     }
                     Ok(Value::Number(result))
                 }
-                (Value::String(a), Value::String(b)) => Ok(Value::string(format!("{}{}", a, b))),
+                (Value::String(a), Value::String(b)) => {
+                    self.check_allocation(a.len() + b.len(), binary.span)?;
+                    Ok(Value::string(format!("{}{}", a, b)))
+                }
                 _ => Err(RuntimeError::TypeError {
                     msg: "Invalid operands for +".to_string(),
                     span: binary.span,
@@ -189,16 +219,17 @@ impl Interpreter {
         let operand = self.eval_expr(&unary.expr)?;
 
         match unary.op {
-            UnaryOp::Negate => {
-                if let Value::Number(n) = operand {
-                    Ok(Value::Number(-n))
-                } else {
-                    Err(RuntimeError::TypeError {
-                        msg: "Expected number for -".to_string(),
-                        span: unary.span,
-                    })
-                }
-            }
+            UnaryOp::Negate => match operand {
+                Value::Number(n) => Ok(Value::Number(-n)),
+                Value::Int(n) => n
+                    .checked_neg()
+                    .map(Value::Int)
+                    .ok_or(RuntimeError::IntegerOverflow { span: unary.span }),
+                _ => Err(RuntimeError::TypeError {
+                    msg: "Expected number for -".to_string(),
+                    span: unary.span,
+                }),
+            },
             UnaryOp::Not => {
                 if let Value::Bool(b) = operand {
                     Ok(Value::Bool(!b))
@@ -244,55 +275,7 @@ impl Interpreter {
 
         // Callee must be a function value
         match callee_value {
-            Value::Builtin(ref name) => {
-                // Check for array intrinsics (callback-based functions)
-                match name.as_ref() {
-                    "map" => return self.intrinsic_map(&args, call.span),
-                    "filter" => return self.intrinsic_filter(&args, call.span),
-                    "reduce" => return self.intrinsic_reduce(&args, call.span),
-                    "forEach" => return self.intrinsic_for_each(&args, call.span),
-                    "find" => return self.intrinsic_find(&args, call.span),
-                    "findIndex" => return self.intrinsic_find_index(&args, call.span),
-                    "flatMap" => return self.intrinsic_flat_map(&args, call.span),
-                    "some" => return self.intrinsic_some(&args, call.span),
-                    "every" => return self.intrinsic_every(&args, call.span),
-                    "sort" => return self.intrinsic_sort(&args, call.span),
-                    "sortBy" => return self.intrinsic_sort_by(&args, call.span),
-                    "result_map" => return self.intrinsic_result_map(&args, call.span),
-                    "result_map_err" => return self.intrinsic_result_map_err(&args, call.span),
-                    "result_and_then" => return self.intrinsic_result_and_then(&args, call.span),
-                    "result_or_else" => return self.intrinsic_result_or_else(&args, call.span),
-                    "hashMapForEach" => return self.intrinsic_hashmap_for_each(&args, call.span),
-                    "hashMapMap" => return self.intrinsic_hashmap_map(&args, call.span),
-                    "hashMapFilter" => return self.intrinsic_hashmap_filter(&args, call.span),
-                    "hashSetForEach" => return self.intrinsic_hashset_for_each(&args, call.span),
-                    "hashSetMap" => return self.intrinsic_hashset_map(&args, call.span),
-                    "hashSetFilter" => return self.intrinsic_hashset_filter(&args, call.span),
-                    "regexReplaceWith" => {
-                        return self.intrinsic_regex_replace_with(&args, call.span)
-                    }
-                    "regexReplaceAllWith" => {
-                        return self.intrinsic_regex_replace_all_with(&args, call.span)
-                    }
-                    _ => {}
-                }
-
-                // Stdlib builtin dispatch
-                let security = self
-                    .current_security
-                    .as_ref()
-                    .expect("Security context not set");
-                let result = crate::stdlib::call_builtin(
-                    name,
-                    &args,
-                    call.span,
-                    security,
-                    &self.output_writer,
-                )?;
-                // CoW write-back: collection mutation builtins return the new collection
-                // but the caller's variable still holds the old value. Write it back.
-                self.apply_cow_writeback(name, result, &call.args, call.span)
-            }
+            Value::Builtin(ref name) => self.call_builtin_value(name, args, call),
             Value::Function(func_ref) => {
                 // Extern function - check if it's an FFI function
                 if let Some(extern_fn) = self.extern_functions.get(&func_ref.name) {
@@ -337,6 +320,63 @@ impl Interpreter {
         }
     }
 
+    /// Call a builtin value: callback intrinsics first, then the stdlib registry
+    ///
+    /// Kept out of `eval_call` so the intrinsic dispatch's temporaries don't
+    /// enlarge the stack frame of every user function call in debug builds.
+    #[inline(never)]
+    fn call_builtin_value(
+        &mut self,
+        name: &str,
+        args: Vec<Value>,
+        call: &CallExpr,
+    ) -> Result<Value, RuntimeError> {
+        // Check for array intrinsics (callback-based functions)
+        match name {
+            "map" => return self.intrinsic_map(&args, call.span),
+            "filter" => return self.intrinsic_filter(&args, call.span),
+            "reduce" => return self.intrinsic_reduce(&args, call.span),
+            "forEach" => return self.intrinsic_for_each(&args, call.span),
+            "find" => return self.intrinsic_find(&args, call.span),
+            "findIndex" => return self.intrinsic_find_index(&args, call.span),
+            "flatMap" => return self.intrinsic_flat_map(&args, call.span),
+            "some" => return self.intrinsic_some(&args, call.span),
+            "every" => return self.intrinsic_every(&args, call.span),
+            "sort" => return self.intrinsic_sort(&args, call.span),
+            "sortBy" => return self.intrinsic_sort_by(&args, call.span),
+            "uniqueBy" => return self.intrinsic_unique_by(&args, call.span),
+            "groupBy" => return self.intrinsic_group_by(&args, call.span),
+            "partition" => return self.intrinsic_partition(&args, call.span),
+            "result_map" => return self.intrinsic_result_map(&args, call.span),
+            "result_map_err" => return self.intrinsic_result_map_err(&args, call.span),
+            "result_and_then" => return self.intrinsic_result_and_then(&args, call.span),
+            "result_or_else" => return self.intrinsic_result_or_else(&args, call.span),
+            "retry" => return self.intrinsic_retry(&args, call.span),
+            "hashMapForEach" => return self.intrinsic_hashmap_for_each(&args, call.span),
+            "hashMapMap" => return self.intrinsic_hashmap_map(&args, call.span),
+            "hashMapFilter" => return self.intrinsic_hashmap_filter(&args, call.span),
+            "hashSetForEach" => return self.intrinsic_hashset_for_each(&args, call.span),
+            "hashSetMap" => return self.intrinsic_hashset_map(&args, call.span),
+            "hashSetFilter" => return self.intrinsic_hashset_filter(&args, call.span),
+            "regexReplaceWith" => return self.intrinsic_regex_replace_with(&args, call.span),
+            "regexReplaceAllWith" => {
+                return self.intrinsic_regex_replace_all_with(&args, call.span)
+            }
+            _ => {}
+        }
+
+        // Stdlib builtin dispatch
+        let security = self
+            .current_security
+            .as_ref()
+            .expect("Security context not set");
+        let result =
+            crate::stdlib::call_builtin(name, &args, call.span, security, &self.output_writer)?;
+        // CoW write-back: collection mutation builtins return the new collection
+        // but the caller's variable still holds the old value. Write it back.
+        self.apply_cow_writeback(name, result, &call.args, call.span)
+    }
+
     /// Evaluate a member expression (method call)
     ///
     /// Desugars method calls to stdlib function calls:
@@ -345,6 +385,18 @@ impl Interpreter {
         // 1. Evaluate target expression
         let target_value = self.eval_expr(&member.target)?;
 
+        // 1a. Record field read (`e.code`): the fields live in a map
+        if member.field_access.get() {
+            let Value::Map(map) = &target_value else {
+                return Err(RuntimeError::TypeError {
+                    msg: format!("Cannot read field '{}' of this value", member.member.name),
+                    span: member.span,
+                });
+            };
+            let key = Value::string(&member.member.name);
+            return crate::stdlib::collections::map::index_get(map, &key, member.span);
+        }
+
         // 1b. Check for trait dispatch (user-defined impl methods).
         // The typechecker annotates `trait_dispatch` when a trait method is resolved.
         if let Some((type_name, trait_name)) = member.trait_dispatch.borrow().clone() {
@@ -373,6 +425,26 @@ impl Interpreter {
             return self.call_user_function(&func, args, member.span);
         }
 
+        // 1c. Check for extension dispatch (user `fn name(self: T, ...)` functions).
+        // The typechecker annotates `extension_dispatch` when no builtin method matches.
+        if let Some(func_name) = member.extension_dispatch.borrow().clone() {
+            let mut args = vec![target_value];
+            if let Some(method_args) = &member.args {
+                for arg in method_args {
+                    args.push(self.eval_expr(arg)?);
+                }
+            }
+            let func = self
+                .function_bodies
+                .get(&func_name)
+                .cloned()
+                .ok_or_else(|| RuntimeError::UnknownFunction {
+                    name: func_name.clone(),
+                    span: member.span,
+                })?;
+            return self.call_user_function(&func, args, member.span);
+        }
+
         // 2. Build desugared function name via shared dispatch table.
         // Prefer the static TypeTag set by the typechecker; fall back to dynamic dispatch
         // from the runtime value when the typechecker couldn't infer the type (e.g. `array`
@@ -489,21 +561,37 @@ impl Interpreter {
             });
         }
 
-        // Push new scope for function
-        self.push_scope();
+        let prev_function = self.current_function.replace(func.name.clone());
+        let prev_try_depth = std::mem::take(&mut self.try_depth);
+        let result = self.run_user_function_body(func, args, call_span);
+        self.current_function = prev_function;
+        self.try_depth = prev_try_depth;
+        result
+    }
 
-        // Bind parameters (parameters are mutable)
-        for (param, arg) in func.params.iter().zip(args.iter()) {
-            // Debug-mode ownership enforcement for `shared` parameters.
-            #[cfg(debug_assertions)]
-            {
-                use crate::ast::OwnershipAnnotation;
-                match &param.ownership {
-                    Some(OwnershipAnnotation::Shared) => {
-                        if !matches!(arg, Value::SharedValue(_)) {
-                            // Must pop scope before returning — we already pushed it.
-                            self.pop_scope();
-                            return Err(RuntimeError::TypeError {
+    /// Execute a user function body, looping in place for self tail calls
+    fn run_user_function_body(
+        &mut self,
+        func: &UserFunction,
+        mut args: Vec<Value>,
+        call_span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        loop {
+            // Push new scope for function
+            self.push_scope();
+
+            // Bind parameters (parameters are mutable)
+            for (param, arg) in func.params.iter().zip(args.iter()) {
+                // Debug-mode ownership enforcement for `shared` parameters.
+                #[cfg(debug_assertions)]
+                {
+                    use crate::ast::OwnershipAnnotation;
+                    match &param.ownership {
+                        Some(OwnershipAnnotation::Shared) => {
+                            if !matches!(arg, Value::SharedValue(_)) {
+                                // Must pop scope before returning — we already pushed it.
+                                self.pop_scope();
+                                return Err(RuntimeError::TypeError {
                                 msg: format!(
                                     "ownership violation: parameter '{}' expects shared<T> but received {}",
                                     param.name.name,
@@ -511,44 +599,56 @@ impl Interpreter {
                                 ),
                                 span: call_span,
                             });
+                            }
                         }
-                    }
-                    Some(ann @ OwnershipAnnotation::Own)
-                    | Some(ann @ OwnershipAnnotation::Borrow) => {
-                        if matches!(arg, Value::SharedValue(_)) {
-                            let ann_str = match ann {
-                                OwnershipAnnotation::Own => "own",
-                                OwnershipAnnotation::Borrow => "borrow",
-                                OwnershipAnnotation::Shared => unreachable!(),
-                            };
-                            eprintln!(
+                        Some(ann @ OwnershipAnnotation::Own)
+                        | Some(ann @ OwnershipAnnotation::Borrow) => {
+                            if matches!(arg, Value::SharedValue(_)) {
+                                let ann_str = match ann {
+                                    OwnershipAnnotation::Own => "own",
+                                    OwnershipAnnotation::Borrow => "borrow",
+                                    OwnershipAnnotation::Shared => unreachable!(),
+                                };
+                                eprintln!(
                                 "warning: passing shared<T> value to '{}' parameter '{}' — consider using the 'shared' annotation",
                                 ann_str, param.name.name
                             );
+                            }
                         }
+                        None => {}
                     }
-                    None => {}
                 }
+                let scope = self.locals.last_mut().unwrap();
+                scope.insert(param.name.name.clone(), (arg.clone(), true));
             }
-            let scope = self.locals.last_mut().unwrap();
-            scope.insert(param.name.name.clone(), (arg.clone(), true));
-        }
 
-        // Execute function body
-        let mut result = Value::Null;
-        for stmt in &func.body.statements {
-            result = self.eval_statement(stmt)?;
+            // Execute function body
+            let mut result = Value::Null;
+            for stmt in &func.body.statements {
+                result = self.eval_statement(stmt)?;
 
-            // Check for return
-            if let ControlFlow::Return(val) = &self.control_flow {
-                result = val.clone();
-                self.control_flow = ControlFlow::None;
-                break;
+                // Check for return
+                if let ControlFlow::Return(val) = &self.control_flow {
+                    result = val.clone();
+                    self.control_flow = ControlFlow::None;
+                    break;
+                }
+                if matches!(self.control_flow, ControlFlow::TailCall(_)) {
+                    break;
+                }
             }
-        }
 
-        self.pop_scope();
-        Ok(result)
+            self.pop_scope();
+
+            // Self tail call: rebind the parameters and run the body again
+            if let ControlFlow::TailCall(next_args) =
+                std::mem::replace(&mut self.control_flow, ControlFlow::None)
+            {
+                args = next_args;
+                continue;
+            }
+            return Ok(result);
+        }
     }
 
     /// Evaluate array indexing
@@ -604,8 +704,9 @@ impl Interpreter {
                 };
                 Ok(Value::JsonValue(Arc::new(result)))
             }
+            Value::Map(map) => crate::stdlib::collections::map::index_get(&map, &idx, index.span),
             _ => Err(RuntimeError::TypeError {
-                msg: "Cannot index non-array/string/json".to_string(),
+                msg: "Cannot index non-array/string/json/map".to_string(),
                 span: index.span,
             }),
         }
@@ -616,11 +717,44 @@ impl Interpreter {
         &mut self,
         arr: &crate::ast::ArrayLiteral,
     ) -> Result<Value, RuntimeError> {
+        self.check_array_allocation(arr.elements.len(), arr.span)?;
         let elements: Result<Vec<Value>, _> =
             arr.elements.iter().map(|e| self.eval_expr(e)).collect();
         Ok(Value::array(elements?))
     }
 
+    /// Evaluate map literal
+    /// Evaluate an interpolated string, rendering each embedded value the same
+    /// way `toString` does.
+    fn eval_string_interp(
+        &mut self,
+        interp: &crate::ast::StringInterpExpr,
+    ) -> Result<Value, RuntimeError> {
+        let mut result = String::new();
+        for part in &interp.parts {
+            match part {
+                crate::ast::StringInterpPart::Literal(text) => result.push_str(text),
+                crate::ast::StringInterpPart::Expr(expr) => {
+                    let value = self.eval_expr(expr)?;
+                    let rendered = crate::stdlib::types::to_string(&[value], expr.span())?;
+                    if let Value::String(s) = rendered {
+                        result.push_str(&s);
+                    }
+                }
+            }
+        }
+        Ok(Value::string(result))
+    }
+
+    fn eval_map_literal(&mut self, map: &crate::ast::MapLiteral) -> Result<Value, RuntimeError> {
+        let mut result = crate::value::ValueMap::new();
+        for entry in &map.entries {
+            let value = self.eval_expr(&entry.value)?;
+            result.insert(entry.key.clone(), value);
+        }
+        Ok(Value::Map(result))
+    }
+
     /// Evaluate match expression
     fn eval_match(&mut self, match_expr: &crate::ast::MatchExpr) -> Result<Value, RuntimeError> {
         // Evaluate scrutinee
@@ -841,6 +975,7 @@ impl Interpreter {
             }
         };
 
+        self.check_array_allocation(arr.len(), span)?;
         let mut result = Vec::with_capacity(arr.len());
         for elem in arr {
             // Call callback with element
@@ -1121,6 +1256,7 @@ impl Interpreter {
             let callback_result = self.call_value(callback, vec![elem], span)?;
             match callback_result {
                 Value::Array(nested) => {
+                    self.check_array_allocation(result.len() + nested.len(), span)?;
                     result.extend(nested.iter().cloned());
                 }
                 other => result.push(other),
@@ -1334,24 +1470,133 @@ impl Interpreter {
             keyed.push((key, elem));
         }
 
-        // Sort by keys (stable)
-        keyed.sort_by(|(key_a, _), (key_b, _)| match (key_a, key_b) {
-            (Value::Number(a), Value::Number(b)) => {
-                if a < b {
-                    std::cmp::Ordering::Less
-                } else if a > b {
-                    std::cmp::Ordering::Greater
-                } else {
-                    std::cmp::Ordering::Equal
-                }
+        Ok(crate::stdlib::array::sort_by_keys(keyed))
+    }
+
+    /// uniqueBy(array, keyFn) - Keep the first element for each distinct key
+    fn intrinsic_unique_by(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "uniqueBy() expects 2 arguments (array, keyFn)".to_string(),
+                span,
+            });
+        }
+
+        let arr = match &args[0] {
+            Value::Array(a) => a.iter().cloned().collect::<Vec<_>>(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "uniqueBy() first argument must be array".to_string(),
+                    span,
+                })
             }
-            (Value::String(a), Value::String(b)) => a.cmp(b),
-            _ => std::cmp::Ordering::Equal,
-        });
+        };
 
-        // Extract sorted elements
-        let sorted: Vec<Value> = keyed.into_iter().map(|(_, elem)| elem).collect();
-        Ok(Value::array(sorted))
+        let key_fn = match &args[1] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[1],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "uniqueBy() second argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let mut keyed = Vec::with_capacity(arr.len());
+        for elem in arr {
+            let key = self.call_value(key_fn, vec![elem.clone()], span)?;
+            keyed.push((key, elem));
+        }
+
+        crate::stdlib::array::unique_by_keys(keyed, span)
+    }
+
+    /// groupBy(array, keyFn) - Group elements into a json object by string key
+    fn intrinsic_group_by(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "groupBy() expects 2 arguments (array, keyFn)".to_string(),
+                span,
+            });
+        }
+
+        let arr = match &args[0] {
+            Value::Array(a) => a.iter().cloned().collect::<Vec<_>>(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "groupBy() first argument must be array".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let key_fn = match &args[1] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[1],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "groupBy() second argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let mut keyed = Vec::with_capacity(arr.len());
+        for elem in arr {
+            let key = self.call_value(key_fn, vec![elem.clone()], span)?;
+            keyed.push((key, elem));
+        }
+
+        crate::stdlib::array::group_by_keys(keyed, span)
+    }
+
+    /// partition(array, predicate) - Split into [matches, nonMatches] in one pass
+    fn intrinsic_partition(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "partition() expects 2 arguments (array, predicate)".to_string(),
+                span,
+            });
+        }
+
+        let arr = match &args[0] {
+            Value::Array(a) => a.iter().cloned().collect::<Vec<_>>(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "partition() first argument must be array".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let predicate = match &args[1] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[1],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "partition() second argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let mut flagged = Vec::with_capacity(arr.len());
+        for elem in arr {
+            let flag = self.call_value(predicate, vec![elem.clone()], span)?;
+            flagged.push((flag, elem));
+        }
+
+        crate::stdlib::array::partition_by_flags(flagged, span)
     }
 
     // ========================================================================
@@ -1506,6 +1751,58 @@ impl Interpreter {
         }
     }
 
+    /// retry(action, attempts) - Call action until it returns Ok, at most `attempts` times
+    fn intrinsic_retry(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "retry() expects 2 arguments (action, attempts)".to_string(),
+                span,
+            });
+        }
+
+        let action = match &args[0] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[0],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "retry() first argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+        let attempts = match &args[1] {
+            Value::Number(n) if *n >= 1.0 && n.fract() == 0.0 => *n as u64,
+            other => {
+                return Err(RuntimeError::InvalidStdlibArgument {
+                    msg: format!(
+                        "retry(): attempts must be a positive integer, got {}",
+                        other
+                    ),
+                    span,
+                })
+            }
+        };
+
+        let mut last = Value::Null;
+        for _ in 0..attempts {
+            last = self.call_value(action, vec![], span)?;
+            match &last {
+                Value::Result(Ok(_)) => break,
+                Value::Result(Err(_)) => {}
+                _ => {
+                    return Err(RuntimeError::TypeError {
+                        msg: "retry() action must return Result".to_string(),
+                        span,
+                    })
+                }
+            }
+        }
+        Ok(last)
+    }
+
     /// hashMapForEach(map, callback) - Iterate over map entries with side effects
     fn intrinsic_hashmap_for_each(
         &mut self,
@@ -2093,6 +2390,10 @@ impl Interpreter {
     ) -> Result<Value, RuntimeError> {
         // Builtins that return the modified collection directly
         const RETURNS_COLLECTION: &[&str] = &[
+            // Map / Set
+            "mapSet",
+            "setAdd",
+            "setRemove",
             // HashMap
             "hashMapPut",
             "hashMapClear",
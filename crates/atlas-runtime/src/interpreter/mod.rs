@@ -16,6 +16,8 @@ use crate::ast::{Block, ImportDecl, ImportSpecifier, Item, Param, Program};
 use crate::ffi::{CallbackHandle, ExternFunction, LibraryLoader};
 use crate::module_loader::ModuleLoader;
 use crate::resolver::ModuleResolver;
+use crate::security::limits::{self, ExecutionBudget};
+use crate::span::Span;
 use crate::value::{FunctionRef, RuntimeError, Value};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -25,9 +27,13 @@ use std::sync::Arc;
 #[derive(Debug, Clone, PartialEq)]
 pub(super) enum ControlFlow {
     None,
-    Break,
-    Continue,
+    /// `break`, optionally targeting a labelled loop
+    Break(Option<String>),
+    /// `continue`, optionally targeting a labelled loop
+    Continue(Option<String>),
     Return(Value),
+    /// `return f(...)` where `f` is the running function: re-enter it with these arguments
+    TailCall(Vec<Value>),
 }
 
 /// User-defined function
@@ -52,11 +58,18 @@ pub struct Interpreter {
     pub(super) function_bodies: HashMap<String, UserFunction>,
     /// Current control flow state
     pub(super) control_flow: ControlFlow,
+    /// Name of the user function currently executing (used to detect self tail calls)
+    pub(super) current_function: Option<String>,
+    /// Number of `try` bodies entered by the current function call
+    /// (self tail calls are disabled while positive)
+    pub(super) try_depth: usize,
     /// Monomorphizer for generic functions (tracks type substitutions)
     #[allow(dead_code)] // Will be used when generic runtime support is fully integrated
     pub(super) monomorphizer: crate::typechecker::generics::Monomorphizer,
     /// Security context for current evaluation (set during eval())
     pub(super) current_security: Option<std::sync::Arc<crate::security::SecurityContext>>,
+    /// Instruction and wall-time accounting for the security context's limits
+    pub(super) budget: ExecutionBudget,
     /// Output writer for print() (defaults to stdout)
     pub(super) output_writer: crate::stdlib::OutputWriter,
     /// Counter for generating unique nested function names
@@ -85,8 +98,11 @@ impl Interpreter {
             consumed_locals: vec![HashSet::new()],
             function_bodies: HashMap::new(),
             control_flow: ControlFlow::None,
+            current_function: None,
+            try_depth: 0,
             monomorphizer: crate::typechecker::generics::Monomorphizer::new(),
             current_security: None,
+            budget: ExecutionBudget::unlimited(),
             output_writer: crate::stdlib::stdout_writer(),
             next_func_id: 0,
             library_loader: LibraryLoader::new(),
@@ -148,6 +164,21 @@ impl Interpreter {
         self.globals.get(name).map(|(v, _)| v.clone())
     }
 
+    /// Remove a top-level binding (and any function body) by name.
+    ///
+    /// Builtins are never removed. Returns `true` if anything was removed.
+    pub fn remove_binding(&mut self, name: &str) -> bool {
+        let mut removed = false;
+        if let Some(scope) = self.locals.first_mut() {
+            removed |= scope.remove(name).is_some();
+        }
+        if !matches!(self.globals.get(name), None | Some((Value::Builtin(_), _))) {
+            removed |= self.globals.remove(name).is_some();
+        }
+        removed |= self.function_bodies.remove(name).is_some();
+        removed
+    }
+
     /// Snapshot of current bindings (locals + globals) sorted by name.
     pub fn bindings_snapshot(&self) -> Vec<(String, Value)> {
         let mut entries: Vec<(String, Value)> = Vec::new();
@@ -174,7 +205,44 @@ impl Interpreter {
     ) -> Result<Value, RuntimeError> {
         // Store security context for builtin calls
         self.current_security = Some(std::sync::Arc::new(security.clone()));
+        self.budget = ExecutionBudget::start(security.limits());
+
+        self.eval_items(program)
+    }
+
+    /// Charge one evaluation step against the execution budget
+    pub(super) fn charge_budget(&mut self, span: Span) -> Result<(), RuntimeError> {
+        match self.current_security.as_deref() {
+            Some(security) => self
+                .budget
+                .charge(security)
+                .map_err(|e| limits::runtime_error(e, span)),
+            None => Ok(()),
+        }
+    }
 
+    /// Check a single string or array allocation against the allocation limit
+    pub(super) fn check_allocation(&self, bytes: usize, span: Span) -> Result<(), RuntimeError> {
+        match self.current_security.as_deref() {
+            Some(security) => limits::check_allocation(security, bytes, span),
+            None => Ok(()),
+        }
+    }
+
+    /// Check an array of `len` values against the allocation limit
+    pub(super) fn check_array_allocation(
+        &self,
+        len: usize,
+        span: Span,
+    ) -> Result<(), RuntimeError> {
+        match self.current_security.as_deref() {
+            Some(security) => limits::check_array_allocation(security, len, span),
+            None => Ok(()),
+        }
+    }
+
+    /// Evaluate a program's items under the current security context and budget
+    fn eval_items(&mut self, program: &Program) -> Result<Value, RuntimeError> {
         let mut last_value = Value::Null;
 
         for item in &program.items {
@@ -258,6 +326,9 @@ impl Interpreter {
                         crate::ast::ExportItem::TypeAlias(_) => {
                             // Type aliases are compile-time only
                         }
+                        crate::ast::ExportItem::ReExport(_) => {
+                            // Forwarded values are collected when exports are extracted
+                        }
                     }
                 }
                 Item::Extern(extern_decl) => {
@@ -508,6 +579,8 @@ impl Interpreter {
             } else {
                 Err(RuntimeError::InvalidIndex { span })
             }
+        } else if let Value::Map(map) = arr {
+            crate::stdlib::collections::map::index_get(&map, &idx, span)
         } else {
             Err(RuntimeError::TypeError {
                 msg: "Cannot index non-array".to_string(),
@@ -579,6 +652,9 @@ impl Interpreter {
                 arr.set(i, value);
                 Ok(())
             }
+            (Value::Map(map), _) => {
+                crate::stdlib::collections::map::index_set(map, &idx, value, span)
+            }
             (container, _) => Err(RuntimeError::TypeError {
                 msg: format!("Cannot index-assign to type '{}'", container.type_name()),
                 span,
@@ -669,8 +745,11 @@ impl Interpreter {
                 consumed_locals: vec![HashSet::new()],
                 function_bodies: function_bodies.clone(),
                 control_flow: ControlFlow::None,
+                current_function: None,
+                try_depth: 0,
                 monomorphizer: crate::typechecker::generics::Monomorphizer::new(),
                 current_security: None,
+                budget: ExecutionBudget::unlimited(),
                 output_writer: output_writer.clone(),
                 next_func_id: 0,
                 library_loader: LibraryLoader::new(),
@@ -805,10 +884,9 @@ impl Interpreter {
         })?;
 
         // Get security context (required for eval)
-        let security = self
-            .current_security
-            .clone()
-            .unwrap_or_else(|| Arc::new(crate::security::SecurityContext::allow_all()));
+        if self.current_security.is_none() {
+            self.current_security = Some(Arc::new(crate::security::SecurityContext::allow_all()));
+        }
 
         // Evaluate each module in dependency order
         for module in &modules {
@@ -821,8 +899,8 @@ impl Interpreter {
             let prev_path = self.current_module_path.take();
             self.current_module_path = Some(module.path.clone());
 
-            // Evaluate the module
-            self.eval(&module.ast, &security)?;
+            // Evaluate the module (imports share the importer's budget)
+            self.eval_items(&module.ast)?;
 
             // Extract and cache exports
             let exports = self.extract_module_exports(module);
@@ -897,6 +975,31 @@ impl Interpreter {
                     crate::ast::ExportItem::TypeAlias(_) => {
                         // Type aliases are compile-time only
                     }
+                    crate::ast::ExportItem::ReExport(re_export) => {
+                        // The source module was evaluated first (dependency order).
+                        // Names it does not export are reported when imported.
+                        let project_root = module
+                            .path
+                            .parent()
+                            .map(|p| p.to_path_buf())
+                            .unwrap_or_else(|| PathBuf::from("."));
+                        let Ok(source_path) = ModuleResolver::new(project_root).resolve_path(
+                            &re_export.source,
+                            &module.path,
+                            re_export.span,
+                        ) else {
+                            continue;
+                        };
+                        let Some(source_exports) = self.module_exports_cache.get(&source_path)
+                        else {
+                            continue;
+                        };
+                        for name in &re_export.names {
+                            if let Some(value) = source_exports.get(&name.name) {
+                                exports.insert(name.name.clone(), value.clone());
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -7,6 +7,10 @@ use crate::value::{FunctionRef, RuntimeError, Value};
 impl Interpreter {
     /// Execute a statement
     pub(super) fn eval_statement(&mut self, stmt: &Stmt) -> Result<Value, RuntimeError> {
+        if self.budget.is_active() {
+            self.charge_budget(stmt.span())?;
+        }
+
         match stmt {
             Stmt::VarDecl(var) => self.eval_var_decl(var),
             Stmt::FunctionDecl(func) => {
@@ -59,13 +63,16 @@ impl Interpreter {
             Stmt::While(while_stmt) => self.eval_while(while_stmt),
             Stmt::For(for_stmt) => self.eval_for(for_stmt),
             Stmt::ForIn(for_in_stmt) => self.eval_for_in(for_in_stmt),
+            Stmt::TryCatch(try_catch) => self.eval_try_catch(try_catch),
             Stmt::Return(return_stmt) => self.eval_return(return_stmt),
-            Stmt::Break(_) => {
-                self.control_flow = ControlFlow::Break;
+            Stmt::Break(break_stmt) => {
+                self.control_flow =
+                    ControlFlow::Break(break_stmt.label.as_ref().map(|l| l.name.clone()));
                 Ok(Value::Null)
             }
-            Stmt::Continue(_) => {
-                self.control_flow = ControlFlow::Continue;
+            Stmt::Continue(continue_stmt) => {
+                self.control_flow =
+                    ControlFlow::Continue(continue_stmt.label.as_ref().map(|l| l.name.clone()));
                 Ok(Value::Null)
             }
             Stmt::Expr(expr_stmt) => self.eval_expr(&expr_stmt.expr),
@@ -308,20 +315,20 @@ impl Interpreter {
 
             last_value = self.eval_block(&while_stmt.body)?;
 
-            match self.control_flow {
-                ControlFlow::Break => {
+            match &self.control_flow {
+                ControlFlow::Break(target) if targets_loop(target, &while_stmt.label) => {
                     self.control_flow = ControlFlow::None;
                     break;
                 }
-                ControlFlow::Continue => {
+                ControlFlow::Continue(target) if targets_loop(target, &while_stmt.label) => {
                     self.control_flow = ControlFlow::None;
                     continue;
                 }
-                ControlFlow::Return(_) => {
-                    // Propagate return up
+                ControlFlow::None => {}
+                _ => {
+                    // Propagate return (or a jump to an outer labelled loop) up
                     break;
                 }
-                ControlFlow::None => {}
             }
         }
 
@@ -348,20 +355,20 @@ impl Interpreter {
             // Execute body
             last_value = self.eval_block(&for_stmt.body)?;
 
-            match self.control_flow {
-                ControlFlow::Break => {
+            match &self.control_flow {
+                ControlFlow::Break(target) if targets_loop(target, &for_stmt.label) => {
                     self.control_flow = ControlFlow::None;
                     break;
                 }
-                ControlFlow::Continue => {
+                ControlFlow::Continue(target) if targets_loop(target, &for_stmt.label) => {
                     self.control_flow = ControlFlow::None;
                     // Continue to step
                 }
-                ControlFlow::Return(_) => {
-                    // Propagate return up
+                ControlFlow::None => {}
+                _ => {
+                    // Propagate return (or a jump to an outer labelled loop) up
                     break;
                 }
-                ControlFlow::None => {}
             }
 
             // Execute step
@@ -403,20 +410,20 @@ impl Interpreter {
             last_value = self.eval_block(&for_in_stmt.body)?;
 
             // Handle control flow
-            match self.control_flow {
-                ControlFlow::Break => {
+            match &self.control_flow {
+                ControlFlow::Break(target) if targets_loop(target, &for_in_stmt.label) => {
                     self.control_flow = ControlFlow::None;
                     break;
                 }
-                ControlFlow::Continue => {
+                ControlFlow::Continue(target) if targets_loop(target, &for_in_stmt.label) => {
                     self.control_flow = ControlFlow::None;
                     // Continue to next iteration
                 }
-                ControlFlow::Return(_) => {
-                    // Propagate return up
+                ControlFlow::None => {}
+                _ => {
+                    // Propagate return (or a jump to an outer labelled loop) up
                     break;
                 }
-                ControlFlow::None => {}
             }
         }
 
@@ -424,8 +431,51 @@ impl Interpreter {
         Ok(last_value)
     }
 
+    /// Evaluate a try/catch statement
+    fn eval_try_catch(&mut self, try_catch: &TryCatchStmt) -> Result<Value, RuntimeError> {
+        // A failing body leaves its scopes (and any callee scopes) pushed
+        let scope_depth = self.locals.len();
+        self.try_depth += 1;
+        let result = self.eval_block(&try_catch.body);
+        self.try_depth -= 1;
+
+        let error = match result {
+            Err(error) if error.is_catchable() => error,
+            other => return other,
+        };
+        self.locals.truncate(scope_depth);
+        self.consumed_locals.truncate(scope_depth);
+        self.control_flow = ControlFlow::None;
+
+        self.push_scope();
+        self.locals.last_mut().unwrap().insert(
+            try_catch.error.name.clone(),
+            (crate::runtime::runtime_error_value(&error), false),
+        );
+        let result = self.eval_block(&try_catch.handler);
+        self.pop_scope();
+        result
+    }
+
     /// Evaluate a return statement
     fn eval_return(&mut self, return_stmt: &ReturnStmt) -> Result<Value, RuntimeError> {
+        if let Some(Expr::Call(call)) = &return_stmt.value {
+            if self.is_self_tail_call(call) {
+                // Evaluate the arguments here and let `call_user_function` re-enter
+                // the body, so deep self recursion doesn't grow the Rust stack.
+                let mut args = Vec::with_capacity(call.args.len());
+                for arg in &call.args {
+                    let val = self.eval_expr(arg)?;
+                    if self.control_flow != ControlFlow::None {
+                        return Ok(val);
+                    }
+                    args.push(val);
+                }
+                self.control_flow = ControlFlow::TailCall(args);
+                return Ok(Value::Null);
+            }
+        }
+
         let value = if let Some(expr) = &return_stmt.value {
             self.eval_expr(expr)?
         } else {
@@ -436,6 +486,25 @@ impl Interpreter {
         Ok(value)
     }
 
+    /// Check whether a returned call targets the function that is currently running
+    fn is_self_tail_call(&self, call: &CallExpr) -> bool {
+        // The call must stay inside the enclosing `try` so its errors are caught
+        if self.try_depth > 0 {
+            return false;
+        }
+        let (Some(current), Expr::Identifier(id)) = (&self.current_function, call.callee.as_ref())
+        else {
+            return false;
+        };
+        if self.extern_functions.contains_key(current) {
+            return false;
+        }
+        matches!(
+            self.get_variable(&id.name, id.span),
+            Ok(Value::Function(ref func_ref)) if &func_ref.name == current
+        )
+    }
+
     /// Evaluate a block
     pub(super) fn eval_block(&mut self, block: &Block) -> Result<Value, RuntimeError> {
         self.push_scope();
@@ -455,3 +524,12 @@ impl Interpreter {
         Ok(last_value)
     }
 }
+
+/// Whether a `break`/`continue` aimed at `target` is handled by a loop with `label`.
+/// Unlabelled jumps are handled by the innermost loop.
+fn targets_loop(target: &Option<String>, label: &Option<Identifier>) -> bool {
+    match target {
+        None => true,
+        Some(name) => label.as_ref().is_some_and(|l| &l.name == name),
+    }
+}
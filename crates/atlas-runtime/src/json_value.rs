@@ -137,6 +137,143 @@ impl JsonValue {
         }
     }
 
+    /// Look up a nested value by dotted path, e.g. `"users.0.name"`
+    /// Numeric segments index into arrays, other segments are object keys.
+    /// Returns None if any segment is missing
+    pub fn get_path(&self, path: &str) -> Option<&JsonValue> {
+        path.split('.')
+            .try_fold(self, |value, segment| match value {
+                JsonValue::Object(obj) => obj.get(segment),
+                JsonValue::Array(arr) => segment.parse::<usize>().ok().and_then(|i| arr.get(i)),
+                _ => None,
+            })
+    }
+
+    /// Name of this value's JSON type, for error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "bool",
+            JsonValue::Number(_) => "number",
+            JsonValue::String(_) => "string",
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object",
+        }
+    }
+
+    /// Check this value against a schema
+    ///
+    /// A schema is one of:
+    /// - a type name: `"null"`, `"bool"`, `"number"`, `"string"`, `"array"`,
+    ///   `"object"` or `"any"`
+    /// - an object mapping each required key to the schema of its value
+    /// - a one-element array whose element is the schema of every item
+    ///
+    /// Keys are checked in sorted order. Returns the dotted path of the
+    /// first offending field on mismatch
+    pub fn validate(&self, schema: &JsonValue) -> Result<(), String> {
+        self.validate_at(schema, "")
+    }
+
+    fn validate_at(&self, schema: &JsonValue, path: &str) -> Result<(), String> {
+        let at = |msg: String| {
+            if path.is_empty() {
+                msg
+            } else {
+                format!("{}: {}", path, msg)
+            }
+        };
+        let join = |segment: &str| {
+            if path.is_empty() {
+                segment.to_string()
+            } else {
+                format!("{}.{}", path, segment)
+            }
+        };
+
+        match schema {
+            JsonValue::String(expected) => {
+                let valid = ["null", "bool", "number", "string", "array", "object", "any"];
+                if !valid.contains(&expected.as_str()) {
+                    return Err(at(format!("invalid schema type '{}'", expected)));
+                }
+                if expected != "any" && expected != self.type_name() {
+                    return Err(at(format!(
+                        "expected {}, found {}",
+                        expected,
+                        self.type_name()
+                    )));
+                }
+                Ok(())
+            }
+            JsonValue::Object(fields) => {
+                let obj = self
+                    .as_object()
+                    .ok_or_else(|| at(format!("expected object, found {}", self.type_name())))?;
+                let mut keys: Vec<&String> = fields.keys().collect();
+                keys.sort();
+                for key in keys {
+                    let value = obj
+                        .get(key)
+                        .ok_or_else(|| format!("{}: missing required key", join(key)))?;
+                    value.validate_at(&fields[key], &join(key))?;
+                }
+                Ok(())
+            }
+            JsonValue::Array(items) if items.len() == 1 => {
+                let arr = self
+                    .as_array()
+                    .ok_or_else(|| at(format!("expected array, found {}", self.type_name())))?;
+                for (i, item) in arr.iter().enumerate() {
+                    item.validate_at(&items[0], &join(&i.to_string()))?;
+                }
+                Ok(())
+            }
+            _ => Err(at(format!(
+                "invalid schema: expected type name, object or one-element array, found {}",
+                schema.type_name()
+            ))),
+        }
+    }
+
+    /// Key/value pairs of an object as `[key, value]` arrays, sorted by key
+    /// Returns None if value is not an object
+    pub fn entries(&self) -> Option<Vec<JsonValue>> {
+        let obj = self.as_object()?;
+        let mut keys: Vec<&String> = obj.keys().collect();
+        keys.sort();
+        Some(
+            keys.into_iter()
+                .map(|key| JsonValue::Array(vec![JsonValue::String(key.clone()), obj[key].clone()]))
+                .collect(),
+        )
+    }
+
+    /// Build an object from `[key, value]` pairs, the inverse of `entries`
+    ///
+    /// Later pairs overwrite earlier ones with the same key. Returns an error
+    /// naming the index of the first element that is not a two-element array
+    /// with a string key
+    pub fn from_entries<'a>(
+        pairs: impl IntoIterator<Item = &'a JsonValue>,
+    ) -> Result<JsonValue, String> {
+        let mut obj = HashMap::new();
+        for (i, pair) in pairs.into_iter().enumerate() {
+            match pair.as_array().map(Vec::as_slice) {
+                Some([JsonValue::String(key), value]) => {
+                    obj.insert(key.clone(), value.clone());
+                }
+                _ => {
+                    return Err(format!(
+                        "entry {} must be a [string, value] pair, found {}",
+                        i, pair
+                    ))
+                }
+            }
+        }
+        Ok(JsonValue::Object(obj))
+    }
+
     /// Get the length of an array or object
     /// Returns None if value is neither array nor object
     pub fn len(&self) -> Option<usize> {
@@ -334,6 +471,114 @@ mod tests {
         assert_eq!(missing, JsonValue::Null);
     }
 
+    #[test]
+    fn test_get_path() {
+        let mut user = HashMap::new();
+        user.insert("name".to_string(), JsonValue::String("Dana".to_string()));
+        let mut data = HashMap::new();
+        data.insert(
+            "users".to_string(),
+            JsonValue::array(vec![JsonValue::object(user)]),
+        );
+        let json = JsonValue::object(data);
+
+        // Deep hit through an object, an array and an object
+        assert_eq!(
+            json.get_path("users.0.name"),
+            Some(&JsonValue::String("Dana".to_string()))
+        );
+
+        // Missing key mid-path
+        assert_eq!(json.get_path("accounts.0.name"), None);
+        assert_eq!(json.get_path("users.0.email"), None);
+
+        // Out-of-range and non-numeric array indices
+        assert_eq!(json.get_path("users.1.name"), None);
+        assert_eq!(json.get_path("users.first.name"), None);
+
+        // Indexing into a scalar
+        assert_eq!(json.get_path("users.0.name.length"), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut schema = HashMap::new();
+        schema.insert("name".to_string(), JsonValue::String("string".to_string()));
+        schema.insert(
+            "tags".to_string(),
+            JsonValue::array(vec![JsonValue::String("string".to_string())]),
+        );
+        let schema = JsonValue::object(schema);
+
+        let person = |name: JsonValue, tags: Vec<JsonValue>| {
+            let mut obj = HashMap::new();
+            obj.insert("name".to_string(), name);
+            obj.insert("tags".to_string(), JsonValue::array(tags));
+            JsonValue::object(obj)
+        };
+
+        let valid = person(
+            JsonValue::String("Eve".to_string()),
+            vec![JsonValue::String("admin".to_string())],
+        );
+        assert_eq!(valid.validate(&schema), Ok(()));
+
+        let wrong_type = person(JsonValue::Number(1.0), vec![]);
+        assert_eq!(
+            wrong_type.validate(&schema),
+            Err("name: expected string, found number".to_string())
+        );
+
+        let bad_item = person(
+            JsonValue::String("Eve".to_string()),
+            vec![JsonValue::String("a".to_string()), JsonValue::Null],
+        );
+        assert_eq!(
+            bad_item.validate(&schema),
+            Err("tags.1: expected string, found null".to_string())
+        );
+
+        let missing = JsonValue::object(HashMap::new());
+        assert_eq!(
+            missing.validate(&schema),
+            Err("name: missing required key".to_string())
+        );
+
+        assert_eq!(
+            JsonValue::Null.validate(&JsonValue::String("text".to_string())),
+            Err("invalid schema type 'text'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_entries_round_trip() {
+        let mut obj = HashMap::new();
+        obj.insert("b".to_string(), JsonValue::Number(2.0));
+        obj.insert("a".to_string(), JsonValue::Null);
+        let obj = JsonValue::object(obj);
+
+        let entries = obj.entries().unwrap();
+        assert_eq!(
+            JsonValue::array(entries.clone()).to_string(),
+            r#"[["a", null], ["b", 2]]"#
+        );
+        assert_eq!(JsonValue::from_entries(&entries), Ok(obj));
+        assert_eq!(JsonValue::Number(1.0).entries(), None);
+    }
+
+    #[test]
+    fn test_from_entries_rejects_malformed_pairs() {
+        let pair = |key: JsonValue| JsonValue::array(vec![key, JsonValue::Bool(true)]);
+        assert_eq!(
+            JsonValue::from_entries(&[
+                pair(JsonValue::String("ok".to_string())),
+                pair(JsonValue::Number(1.0))
+            ]),
+            Err("entry 1 must be a [string, value] pair, found [1, true]".to_string())
+        );
+        assert!(JsonValue::from_entries(&[JsonValue::array(vec![])]).is_err());
+    }
+
     #[test]
     fn test_extraction_methods() {
         let val = JsonValue::Bool(false);
@@ -5,12 +5,33 @@ use crate::token::{Token, TokenKind};
 
 impl Lexer {
     /// Scan a string literal
-    pub(super) fn string(&mut self) -> Token {
+    ///
+    /// Strings containing `${` are split into chunk tokens around each embedded
+    /// expression: `StringInterpStart`, then `StringInterpMiddle` after each `}`,
+    /// and finally `StringInterpEnd` at the closing quote. `continuation` is true
+    /// when resuming a string after the `}` of an interpolation.
+    pub(super) fn string(&mut self, continuation: bool) -> Token {
         let mut value = String::new();
         let mut has_error = false;
         let mut error_token = None;
 
         while !self.is_at_end() && self.peek() != '"' {
+            if self.peek() == '$' && self.peek_next() == Some('{') {
+                self.advance(); // consume $
+                self.advance(); // consume {
+                self.interpolation_depths.push(0);
+
+                if let Some(err) = error_token {
+                    return err;
+                }
+                let kind = if continuation {
+                    TokenKind::StringInterpMiddle
+                } else {
+                    TokenKind::StringInterpStart
+                };
+                return self.make_token(kind, &value);
+            }
+
             if self.peek() == '\n' {
                 self.line += 1;
                 self.column = 1;
@@ -29,6 +50,7 @@ impl Lexer {
                     't' => '\t',
                     '\\' => '\\',
                     '"' => '"',
+                    '$' => '$',
                     _ => {
                         // Record error but continue parsing to find end of string
                         if !has_error {
@@ -56,30 +78,65 @@ impl Lexer {
         // If we had an error, return that instead of a valid token
         if let Some(err) = error_token {
             err
+        } else if continuation {
+            self.make_token(TokenKind::StringInterpEnd, &value)
         } else {
             self.make_token(TokenKind::String, &value)
         }
     }
 
-    /// Scan a number literal (integer, float, or scientific notation)
+    /// Scan a number literal (integer, float, scientific notation, `0x`/`0b` radix
+    /// literal, or `i`-suffixed int), allowing `_` separators between digits
     pub(super) fn number(&mut self) -> Token {
         let start = self.current - 1; // -1 because we already advanced past first digit
 
-        // Consume all digits
-        while !self.is_at_end() && self.peek().is_ascii_digit() {
-            self.advance();
+        // Radix prefix: `0x`/`0X` (hex) or `0b`/`0B` (binary)
+        let radix = match self.chars[start] {
+            '0' if !self.is_at_end() => match self.peek() {
+                'x' | 'X' => Some((16, "hex")),
+                'b' | 'B' => Some((2, "binary")),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some((radix, name)) = radix {
+            self.advance(); // consume x/b
+            match self.chars.get(self.current) {
+                Some(c) if c.is_digit(radix) || *c == '_' => {}
+                _ => {
+                    return self.error_token(&format!("Invalid {} literal: expected digits", name))
+                }
+            }
+            if let Err(message) = self.digits(radix) {
+                return self.error_token(message);
+            }
+            if !self.is_at_end() && self.peek().is_ascii_digit() {
+                while !self.is_at_end() && (self.peek().is_ascii_digit() || self.peek() == '_') {
+                    self.advance();
+                }
+                return self.error_token(&format!("Invalid {} literal: invalid digit", name));
+            }
+            return self.int_suffix(start, true);
+        }
+
+        // Consume the rest of the integer part (the first digit is already consumed)
+        if let Err(message) = self.digits_after_first(10) {
+            return self.error_token(message);
         }
 
+        let mut integral = true;
+
         // Check for decimal point
         if !self.is_at_end() && self.peek() == '.' {
             // Look ahead to ensure there's a digit after the dot
             if let Some(c) = self.peek_next() {
                 if c.is_ascii_digit() {
+                    integral = false;
                     self.advance(); // consume .
 
                     // Consume fractional digits
-                    while !self.is_at_end() && self.peek().is_ascii_digit() {
-                        self.advance();
+                    if let Err(message) = self.digits(10) {
+                        return self.error_token(message);
                     }
                 }
             }
@@ -87,6 +144,7 @@ impl Lexer {
 
         // Check for scientific notation (e or E)
         if !self.is_at_end() && (self.peek() == 'e' || self.peek() == 'E') {
+            integral = false;
             self.advance(); // consume e/E
 
             // Optional + or - sign
@@ -100,8 +158,24 @@ impl Lexer {
             }
 
             // Consume exponent digits
-            while !self.is_at_end() && self.peek().is_ascii_digit() {
-                self.advance();
+            if let Err(message) = self.digits(10) {
+                return self.error_token(message);
+            }
+        }
+
+        self.int_suffix(start, integral)
+    }
+
+    /// Consume an optional `i` suffix and finish the number token started at `start`
+    fn int_suffix(&mut self, start: usize, integral: bool) -> Token {
+        // Int suffix: `42i` (but not the start of an identifier like `42in`)
+        let suffix_ends = self
+            .peek_next()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_'));
+        if !self.is_at_end() && self.peek() == 'i' && suffix_ends {
+            self.advance(); // consume i
+            if !integral {
+                return self.error_token("Invalid int literal: int literals must be whole numbers");
             }
         }
 
@@ -109,6 +183,38 @@ impl Lexer {
         self.make_token(TokenKind::Number, &lexeme)
     }
 
+    /// Consume a run of digits in `radix`, with `_` allowed only between two digits
+    fn digits(&mut self, radix: u32) -> Result<(), &'static str> {
+        if !self.is_at_end() && self.peek() == '_' {
+            self.advance();
+            return Err("Invalid number: `_` cannot start a number");
+        }
+        self.digits_after_first(radix)
+    }
+
+    /// Consume the digits following one already-consumed digit in `radix`
+    fn digits_after_first(&mut self, radix: u32) -> Result<(), &'static str> {
+        while !self.is_at_end() {
+            let c = self.peek();
+            if c == '_' {
+                self.advance();
+                match self.chars.get(self.current) {
+                    Some('_') => {
+                        self.advance();
+                        return Err("Invalid number: `_` separators cannot be doubled");
+                    }
+                    Some(next) if next.is_digit(radix) => {}
+                    _ => return Err("Invalid number: `_` cannot end a number"),
+                }
+            } else if c.is_digit(radix) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     /// Scan an identifier or keyword
     pub(super) fn identifier(&mut self) -> Token {
         let start = self.current - 1; // -1 because we already advanced past first char
@@ -32,6 +32,8 @@ pub struct Lexer {
     emit_comments: bool,
     /// Pending comment tokens to emit
     pending_comments: Vec<Token>,
+    /// Brace depth inside each open `${ ... }` string interpolation
+    pub(super) interpolation_depths: Vec<u32>,
 }
 
 impl Lexer {
@@ -51,6 +53,7 @@ impl Lexer {
             diagnostics: Vec::new(),
             emit_comments: false,
             pending_comments: Vec::new(),
+            interpolation_depths: Vec::new(),
         }
     }
 
@@ -111,8 +114,24 @@ impl Lexer {
             // Single-character tokens
             '(' => self.make_token(TokenKind::LeftParen, "("),
             ')' => self.make_token(TokenKind::RightParen, ")"),
-            '{' => self.make_token(TokenKind::LeftBrace, "{"),
-            '}' => self.make_token(TokenKind::RightBrace, "}"),
+            '{' => {
+                if let Some(depth) = self.interpolation_depths.last_mut() {
+                    *depth += 1;
+                }
+                self.make_token(TokenKind::LeftBrace, "{")
+            }
+            '}' => match self.interpolation_depths.last_mut() {
+                // Closing brace of `${ ... }` resumes the enclosing string
+                Some(0) => {
+                    self.interpolation_depths.pop();
+                    self.string(true)
+                }
+                Some(depth) => {
+                    *depth -= 1;
+                    self.make_token(TokenKind::RightBrace, "}")
+                }
+                None => self.make_token(TokenKind::RightBrace, "}"),
+            },
             '[' => self.make_token(TokenKind::LeftBracket, "["),
             ']' => self.make_token(TokenKind::RightBracket, "]"),
             ';' => self.make_token(TokenKind::Semicolon, ";"),
@@ -204,13 +223,15 @@ impl Lexer {
             '|' => {
                 if self.match_char('|') {
                     self.make_token(TokenKind::PipePipe, "||")
+                } else if self.match_char('>') {
+                    self.make_token(TokenKind::PipeGreater, "|>")
                 } else {
                     self.make_token(TokenKind::Pipe, "|")
                 }
             }
 
             // String literals
-            '"' => self.string(),
+            '"' => self.string(false),
 
             // Numbers
             c if c.is_ascii_digit() => self.number(),
@@ -483,7 +504,7 @@ mod tests {
 
     #[test]
     fn test_operators() {
-        let mut lexer = Lexer::new("+ - * / % ! == != < <= > >= && ||");
+        let mut lexer = Lexer::new("+ - * / % ! == != < <= > >= && || |>");
         let (tokens, _) = lexer.tokenize();
 
         let expected = vec![
@@ -501,6 +522,7 @@ mod tests {
             TokenKind::GreaterEqual,
             TokenKind::AmpAmp,
             TokenKind::PipePipe,
+            TokenKind::PipeGreater,
         ];
 
         for (i, expected_kind) in expected.iter().enumerate() {
@@ -606,6 +628,20 @@ mod tests {
         assert_eq!(tokens[4].lexeme, "1E-308");
     }
 
+    #[test]
+    fn test_int_suffix() {
+        let mut lexer = Lexer::new("42i 0i 7 in 1.5i");
+        let (tokens, diags) = lexer.tokenize();
+
+        assert_eq!(tokens[0].kind, TokenKind::Number);
+        assert_eq!(tokens[0].lexeme, "42i");
+        assert_eq!(tokens[1].lexeme, "0i");
+        assert_eq!(tokens[2].lexeme, "7");
+        assert_eq!(tokens[3].kind, TokenKind::In);
+        assert_eq!(tokens[4].kind, TokenKind::Error);
+        assert_eq!(diags.len(), 1);
+    }
+
     #[test]
     fn test_scientific_notation_invalid() {
         let mut lexer = Lexer::new("1e 1e+ 1e-");
@@ -982,4 +1018,52 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_string_interpolation_tokens() {
+        let mut lexer = Lexer::new(r#""Hello ${name}, age ${a + 1}!""#);
+        let (tokens, diagnostics) = lexer.tokenize();
+
+        assert!(diagnostics.is_empty());
+        let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::StringInterpStart,
+                TokenKind::Identifier,
+                TokenKind::StringInterpMiddle,
+                TokenKind::Identifier,
+                TokenKind::Plus,
+                TokenKind::Number,
+                TokenKind::StringInterpEnd,
+                TokenKind::Eof,
+            ]
+        );
+        assert_eq!(tokens[0].lexeme, "Hello ");
+        assert_eq!(tokens[2].lexeme, ", age ");
+        assert_eq!(tokens[6].lexeme, "!");
+    }
+
+    #[test]
+    fn test_string_interpolation_braces_inside_expression() {
+        let mut lexer = Lexer::new(r#""${ { a: 1 }["a"] } done""#);
+        let (tokens, diagnostics) = lexer.tokenize();
+
+        assert!(diagnostics.is_empty());
+        let end = tokens
+            .iter()
+            .find(|t| t.kind == TokenKind::StringInterpEnd)
+            .unwrap();
+        assert_eq!(end.lexeme, " done");
+    }
+
+    #[test]
+    fn test_escaped_interpolation_is_literal() {
+        let mut lexer = Lexer::new(r#""cost: \${price}""#);
+        let (tokens, diagnostics) = lexer.tokenize();
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(tokens[0].kind, TokenKind::String);
+        assert_eq!(tokens[0].lexeme, "cost: ${price}");
+    }
 }
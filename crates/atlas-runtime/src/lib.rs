@@ -44,8 +44,8 @@ pub mod types;
 pub mod value;
 pub mod vm;
 
-// Test utilities (only available in test builds)
-#[cfg(test)]
+// Test utilities (test builds, or other crates via the `testing` feature)
+#[cfg(any(test, feature = "testing"))]
 pub mod test_utils;
 
 // Re-export commonly used types
@@ -54,7 +54,7 @@ pub use bytecode::{Bytecode, Opcode};
 pub use compiler::Compiler;
 pub use diagnostic::{
     error_codes, formatter, normalizer, sort_diagnostics, warnings, Diagnostic, DiagnosticLevel,
-    RelatedLocation, DIAG_VERSION,
+    LabelStyle, RelatedLocation, TextEdit, DIAG_VERSION,
 };
 pub use interpreter::Interpreter;
 pub use json_value::JsonValue;
@@ -21,12 +21,15 @@ pub type ModuleResult<T> = Result<T, Vec<Diagnostic>>;
 struct ModuleCache {
     /// Map of module path -> exported symbols (name -> value)
     exports: HashMap<PathBuf, HashMap<String, Value>>,
+    /// Map of module path -> every exported name, including type aliases
+    declared: HashMap<PathBuf, Vec<String>>,
 }
 
 impl ModuleCache {
     fn new() -> Self {
         Self {
             exports: HashMap::new(),
+            declared: HashMap::new(),
         }
     }
 
@@ -34,10 +37,23 @@ impl ModuleCache {
         self.exports.contains_key(path)
     }
 
-    fn store_exports(&mut self, path: PathBuf, exports: HashMap<String, Value>) {
+    fn store_exports(
+        &mut self,
+        path: PathBuf,
+        exports: HashMap<String, Value>,
+        declared: Vec<String>,
+    ) {
+        self.declared.insert(path.clone(), declared);
         self.exports.insert(path, exports);
     }
 
+    /// Whether the module declares `name` as an export (values and type aliases)
+    fn declares(&self, path: &Path, name: &str) -> bool {
+        self.declared
+            .get(path)
+            .is_some_and(|names| names.iter().any(|n| n == name))
+    }
+
     fn get_exports(&self, path: &Path) -> Option<&HashMap<String, Value>> {
         self.exports.get(path)
     }
@@ -81,6 +97,15 @@ impl<'a> ModuleExecutor<'a> {
         }
     }
 
+    /// Implicitly import the exports of the prelude module at `path` into
+    /// every executed module (see [`ModuleLoader::with_prelude`])
+    pub fn with_prelude(self, path: PathBuf) -> Self {
+        Self {
+            loader: self.loader.with_prelude(path),
+            ..self
+        }
+    }
+
     /// Execute a module file and all its dependencies
     ///
     /// Loads and executes modules in topological order (dependencies first).
@@ -138,8 +163,9 @@ impl<'a> ModuleExecutor<'a> {
             })?;
 
         // Extract and cache exports
-        let exports = self.extract_exports(module);
-        self.cache.store_exports(module.path.clone(), exports);
+        let exports = self.extract_exports(module)?;
+        self.cache
+            .store_exports(module.path.clone(), exports, module.exports.clone());
 
         Ok(result)
     }
@@ -198,8 +224,9 @@ impl<'a> ModuleExecutor<'a> {
     /// Extract exports from an executed module
     ///
     /// Examines the module's AST to find exported items and retrieves
-    /// their values from the interpreter's globals.
-    fn extract_exports(&self, module: &LoadedModule) -> HashMap<String, Value> {
+    /// their values from the interpreter's globals. Re-exported names are
+    /// copied from the source module's cached exports.
+    fn extract_exports(&mut self, module: &LoadedModule) -> ModuleResult<HashMap<String, Value>> {
         let mut exports = HashMap::new();
 
         for item in &module.ast.items {
@@ -224,11 +251,50 @@ impl<'a> ModuleExecutor<'a> {
                     crate::ast::ExportItem::TypeAlias(_) => {
                         // Type aliases are compile-time only
                     }
+                    crate::ast::ExportItem::ReExport(re_export) => {
+                        let source_path = self
+                            .resolver
+                            .resolve_path(&re_export.source, &module.path, re_export.span)
+                            .map_err(|e| vec![e])?;
+                        let source_exports =
+                            self.cache.get_exports(&source_path).ok_or_else(|| {
+                                vec![Diagnostic::error(
+                                    format!(
+                                        "Module not yet executed: {}. This indicates a bug in topological sorting.",
+                                        source_path.display()
+                                    ),
+                                    re_export.span,
+                                )]
+                            })?;
+
+                        for name in &re_export.names {
+                            // Type aliases have no runtime value to forward
+                            if !source_exports.contains_key(&name.name)
+                                && self.cache.declares(&source_path, &name.name)
+                            {
+                                continue;
+                            }
+                            let value = source_exports.get(&name.name).ok_or_else(|| {
+                                vec![Diagnostic::error_with_code(
+                                    "AT5006",
+                                    format!(
+                                        "Module '{}' does not export '{}'",
+                                        re_export.source, name.name
+                                    ),
+                                    name.span,
+                                )
+                                .with_help(
+                                    "check the module's exports or re-export a different symbol",
+                                )]
+                            })?;
+                            exports.insert(name.name.clone(), value.clone());
+                        }
+                    }
                 }
             }
         }
 
-        exports
+        Ok(exports)
     }
 }
 
@@ -4,7 +4,10 @@
 //! This is BLOCKER 04-B - loading and caching only.
 //! Type checking happens in BLOCKER 04-C.
 
-use crate::ast::{ImportDecl, Item, Program};
+use crate::ast::{
+    ExportItem, Identifier, ImportDecl, ImportSpecifier, Item, Program, ReExportDecl, Stmt,
+};
+use crate::diagnostic::error_codes::CIRCULAR_IMPORT;
 use crate::diagnostic::Diagnostic;
 use crate::lexer::Lexer;
 use crate::parser::Parser;
@@ -13,7 +16,7 @@ use crate::span::Span;
 use crate::symbol::SymbolTable;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// A loaded module with its AST and metadata
 #[derive(Debug, Clone)]
@@ -26,6 +29,8 @@ pub struct LoadedModule {
     pub exports: Vec<String>,
     /// List of import declarations (for dependency tracking)
     pub imports: Vec<ImportDecl>,
+    /// Re-export declarations (`export { x } from "..."`), also dependencies
+    pub re_exports: Vec<ReExportDecl>,
 }
 
 /// Registry of bound modules with their symbol tables
@@ -75,27 +80,47 @@ impl Default for ModuleRegistry {
 
 /// Module loader - loads files, builds dependency graphs, performs topological sort
 pub struct ModuleLoader {
+    /// Project root (used to shorten paths in diagnostics)
+    root: PathBuf,
     /// Module resolver for path resolution
     resolver: ModuleResolver,
     /// Cache of loaded modules (by absolute path)
     cache: HashMap<PathBuf, LoadedModule>,
     /// Dependency graph (module -> its dependencies)
     dependencies: HashMap<PathBuf, Vec<PathBuf>>,
-    /// Modules currently being loaded (for cycle detection during loading)
-    loading: HashSet<PathBuf>,
+    /// Import stack of modules currently being loaded (for cycle detection)
+    loading: Vec<PathBuf>,
+    /// Prelude module imported implicitly by every other module
+    prelude: Option<PathBuf>,
+    /// Names exported by the prelude, set once it has been loaded
+    prelude_exports: Option<Vec<String>>,
 }
 
 impl ModuleLoader {
     /// Create a new module loader with the given project root
     pub fn new(root: PathBuf) -> Self {
         Self {
-            resolver: ModuleResolver::new(root),
+            resolver: ModuleResolver::new(root.clone()),
+            root,
             cache: HashMap::new(),
             dependencies: HashMap::new(),
-            loading: HashSet::new(),
+            loading: Vec::new(),
+            prelude: None,
+            prelude_exports: None,
         }
     }
 
+    /// Make `path` the prelude module
+    ///
+    /// Every module loaded afterwards implicitly imports the prelude's
+    /// exports, except names it declares or imports itself: local
+    /// declarations shadow the prelude. The prelude and the modules it
+    /// imports do not see the prelude.
+    pub fn with_prelude(mut self, path: PathBuf) -> Self {
+        self.prelude = Some(path.canonicalize().unwrap_or(path));
+        self
+    }
+
     /// Load a module and all its dependencies
     ///
     /// Returns modules in topological order (dependencies first).
@@ -109,6 +134,9 @@ impl ModuleLoader {
         &mut self,
         entry_point: &Path,
     ) -> Result<Vec<LoadedModule>, Vec<Diagnostic>> {
+        // The prelude's exports must be known before any module imports them
+        self.load_prelude()?;
+
         // Load the entry module and all dependencies recursively
         self.load_recursive(entry_point)?;
 
@@ -143,34 +171,35 @@ impl ModuleLoader {
             return Ok(());
         }
 
-        // Check if currently being loaded (circular dependency)
-        if self.loading.contains(&abs_path) {
-            return Err(vec![Diagnostic::error_with_code(
-                "AT5003",
-                "Circular dependency detected",
-                Span::dummy(),
-            )
-            .with_label(format!("module: {}", abs_path.display()))
-            .with_help(
-                "Refactor to remove circular dependencies between modules".to_string(),
-            )]);
-        }
-
         // Mark as currently loading
-        self.loading.insert(abs_path.clone());
+        self.loading.push(abs_path.clone());
 
         // Load and parse the module file
-        let loaded = self.load_and_parse(&abs_path)?;
+        let mut loaded = self.load_and_parse(&abs_path)?;
+        if let Some(import) = self.prelude_import(&loaded) {
+            loaded.imports.insert(0, import);
+        }
 
         // Extract dependencies from imports (deduplicate)
         let mut deps = Vec::new();
         let mut seen_deps = HashSet::new();
 
-        for import in &loaded.imports {
+        let sources = loaded
+            .imports
+            .iter()
+            .map(|import| (&import.source, import.span))
+            .chain(
+                loaded
+                    .re_exports
+                    .iter()
+                    .map(|re_export| (&re_export.source, re_export.span)),
+            );
+
+        for (source, span) in sources {
             // Resolve import path relative to current module
             let dep_path = self
                 .resolver
-                .resolve_path(&import.source, &abs_path, import.span)
+                .resolve_path(source, &abs_path, span)
                 .map_err(|e| vec![e])?;
 
             // Skip if already processed (multiple imports from same module)
@@ -178,6 +207,11 @@ impl ModuleLoader {
                 continue;
             }
 
+            // Importing a module that is still loading closes a cycle
+            if let Some(start) = self.loading.iter().position(|p| p == &dep_path) {
+                return Err(vec![self.circular_import_error(start, &abs_path, span)]);
+            }
+
             deps.push(dep_path.clone());
 
             // Add to resolver's dependency graph
@@ -194,12 +228,93 @@ impl ModuleLoader {
         // Cache the loaded module
         self.cache.insert(abs_path.clone(), loaded);
 
-        // Remove from loading set (done loading)
-        self.loading.remove(&abs_path);
+        // Pop from the import stack (done loading)
+        self.loading.pop();
+
+        Ok(())
+    }
+
+    /// Load the prelude module, if one is configured, and record its exports
+    fn load_prelude(&mut self) -> Result<(), Vec<Diagnostic>> {
+        let Some(prelude) = self.prelude.clone() else {
+            return Ok(());
+        };
+        if self.prelude_exports.is_some() {
+            return Ok(());
+        }
 
+        self.load_recursive(&prelude)?;
+        let exports = self
+            .cache
+            .get(&prelude)
+            .map(|module| module.exports.clone())
+            .unwrap_or_default();
+        self.prelude_exports = Some(exports);
         Ok(())
     }
 
+    /// Build the implicit import of the prelude's exports into `module`
+    ///
+    /// Returns `None` while the prelude itself is loading, for the prelude,
+    /// and when `module` shadows every prelude export.
+    fn prelude_import(&self, module: &LoadedModule) -> Option<ImportDecl> {
+        let (prelude, exports) = (self.prelude.as_ref()?, self.prelude_exports.as_ref()?);
+        if module.path == *prelude {
+            return None;
+        }
+
+        let declared = declared_names(module);
+        let specifiers: Vec<ImportSpecifier> = exports
+            .iter()
+            .filter(|name| !declared.contains(name.as_str()))
+            .map(|name| ImportSpecifier::Named {
+                name: Identifier {
+                    name: name.clone(),
+                    span: Span::dummy(),
+                    unwrap_payload: std::cell::Cell::new(None),
+                },
+                span: Span::dummy(),
+            })
+            .collect();
+        if specifiers.is_empty() {
+            return None;
+        }
+
+        Some(ImportDecl {
+            specifiers,
+            source: relative_source(&module.path, prelude),
+            span: Span::dummy(),
+        })
+    }
+
+    /// Build the diagnostic for an import that closes a cycle.
+    ///
+    /// `start` is the index in the import stack of the module being
+    /// re-imported; the reported path runs from it back to itself.
+    fn circular_import_error(&self, start: usize, importer: &Path, span: Span) -> Diagnostic {
+        let cycle = self.loading[start..]
+            .iter()
+            .chain(std::iter::once(&self.loading[start]))
+            .map(|path| self.display_path(path))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+
+        Diagnostic::error_with_code(CIRCULAR_IMPORT, format!("Circular import: {}", cycle), span)
+            .with_file(self.display_path(importer))
+            .with_label("this import closes the cycle")
+            .with_help(
+                "move the declarations both modules need into a third module that neither imports",
+            )
+    }
+
+    /// Show `path` relative to the project root when possible.
+    fn display_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
     /// Load and parse a single module file
     fn load_and_parse(&self, path: &Path) -> Result<LoadedModule, Vec<Diagnostic>> {
         // Read file contents
@@ -230,17 +345,19 @@ impl ModuleLoader {
         // Extract exports and imports
         let mut exports = Vec::new();
         let mut imports = Vec::new();
+        let mut re_exports = Vec::new();
 
         for item in &ast.items {
             match item {
-                Item::Export(export_decl) => {
-                    let name = match &export_decl.item {
-                        crate::ast::ExportItem::Function(func) => func.name.name.clone(),
-                        crate::ast::ExportItem::Variable(var) => var.name.name.clone(),
-                        crate::ast::ExportItem::TypeAlias(alias) => alias.name.name.clone(),
-                    };
-                    exports.push(name);
-                }
+                Item::Export(export_decl) => match &export_decl.item {
+                    ExportItem::Function(func) => exports.push(func.name.name.clone()),
+                    ExportItem::Variable(var) => exports.push(var.name.name.clone()),
+                    ExportItem::TypeAlias(alias) => exports.push(alias.name.name.clone()),
+                    ExportItem::ReExport(re_export) => {
+                        exports.extend(re_export.names.iter().map(|name| name.name.clone()));
+                        re_exports.push(re_export.clone());
+                    }
+                },
                 Item::Import(import_decl) => {
                     imports.push(import_decl.clone());
                 }
@@ -253,6 +370,7 @@ impl ModuleLoader {
             ast,
             exports,
             imports,
+            re_exports,
         })
     }
 
@@ -365,6 +483,64 @@ impl ModuleLoader {
     }
 }
 
+/// Top-level names a module declares or imports
+fn declared_names(module: &LoadedModule) -> HashSet<&str> {
+    let mut names = HashSet::new();
+    for item in &module.ast.items {
+        match item {
+            Item::Function(func) | Item::Statement(Stmt::FunctionDecl(func)) => {
+                names.insert(func.name.name.as_str());
+            }
+            Item::Statement(Stmt::VarDecl(var)) => {
+                names.insert(var.name.name.as_str());
+            }
+            Item::Extern(decl) => {
+                names.insert(decl.name.as_str());
+            }
+            Item::TypeAlias(alias) => {
+                names.insert(alias.name.name.as_str());
+            }
+            Item::Import(import) => {
+                for specifier in &import.specifiers {
+                    match specifier {
+                        ImportSpecifier::Named { name, .. } => names.insert(name.name.as_str()),
+                        ImportSpecifier::Namespace { alias, .. } => {
+                            names.insert(alias.name.as_str())
+                        }
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+    names.extend(module.exports.iter().map(String::as_str));
+    names
+}
+
+/// Import path that reaches `target` from the module at `importer`
+///
+/// Both paths are absolute, so the result is always `./` or `../` relative
+/// and resolves the same way whatever the loader's root is.
+fn relative_source(importer: &Path, target: &Path) -> String {
+    let from: Vec<Component> = importer
+        .parent()
+        .map(|dir| dir.components().collect())
+        .unwrap_or_default();
+    let to: Vec<Component> = target.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    if parts.is_empty() {
+        parts.push(".".to_string());
+    }
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+    parts.join("/")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,7 +666,9 @@ mod tests {
 
         assert!(result.is_err());
         let diags = result.unwrap_err();
-        assert!(diags[0].message.contains("Circular dependency"));
+        assert_eq!(diags[0].code, "AT5009");
+        assert_eq!(diags[0].message, "Circular import: a.atl -> b.atl -> a.atl");
+        assert_eq!(diags[0].file, "b.atl");
     }
 
     #[test]
@@ -747,7 +925,7 @@ mod tests {
 
         assert!(result.is_err());
         let diags = result.unwrap_err();
-        assert!(diags[0].message.contains("Circular dependency"));
+        assert_eq!(diags[0].message, "Circular import: main.atl -> main.atl");
     }
 
     #[test]
@@ -874,7 +1052,10 @@ mod tests {
 
         assert!(result.is_err());
         let diags = result.unwrap_err();
-        assert!(diags[0].message.contains("Circular dependency"));
+        assert_eq!(
+            diags[0].message,
+            "Circular import: b.atl -> a.atl -> e.atl -> d.atl -> c.atl -> b.atl"
+        );
     }
 
     #[test]
@@ -893,7 +1074,10 @@ mod tests {
 
         assert!(result.is_err());
         let diags = result.unwrap_err();
-        assert!(diags[0].message.contains("Circular dependency"));
+        assert_eq!(
+            diags[0].message,
+            "Circular import: a.atl -> b.atl -> c.atl -> a.atl"
+        );
     }
 
     #[test]
@@ -1054,4 +1238,73 @@ mod tests {
         let missing = loader.get_module(&root.join("missing.atl"));
         assert!(missing.is_none());
     }
+
+    #[test]
+    fn test_prelude_imported_into_every_module() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        let sub_dir = root.join("app");
+        fs::create_dir(&sub_dir).unwrap();
+
+        let prelude = create_module(
+            &root,
+            "prelude",
+            "export fn double(x: number) -> number { return x * 2; }",
+        );
+        create_module(
+            &sub_dir,
+            "util",
+            "export fn four() -> number { return double(2); }",
+        );
+        create_module(
+            &sub_dir,
+            "main",
+            "import { four } from \"./util\";\nfour();",
+        );
+
+        let mut loader = ModuleLoader::new(root.clone()).with_prelude(prelude.clone());
+        let entry = sub_dir.join("main.atl");
+        let modules = loader.load_module(&entry).unwrap();
+
+        // The prelude runs before the modules that use it
+        assert_eq!(modules.len(), 3);
+        assert_eq!(modules[0].path, prelude);
+        for module in &modules[1..] {
+            let import = &module.imports[0];
+            assert_eq!(import.source, "../prelude.atl");
+            assert!(matches!(
+                &import.specifiers[..],
+                [ImportSpecifier::Named { name, .. }] if name.name == "double"
+            ));
+        }
+        assert!(modules[0].imports.is_empty());
+    }
+
+    #[test]
+    fn test_local_declaration_shadows_prelude() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        let prelude = create_module(
+            &root,
+            "prelude",
+            "export fn double(x: number) -> number { return x * 2; }\nexport let BASE = 10;",
+        );
+        create_module(
+            &root,
+            "main",
+            "fn double(x: number) -> number { return x + x; }\nBASE;",
+        );
+
+        let mut loader = ModuleLoader::new(root.clone()).with_prelude(prelude);
+        let main = root.join("main.atl");
+        loader.load_module(&main).unwrap();
+
+        let imports = &loader.get_module(&main).unwrap().imports;
+        assert_eq!(imports.len(), 1);
+        assert!(matches!(
+            &imports[0].specifiers[..],
+            [ImportSpecifier::Named { name, .. }] if name.name == "BASE"
+        ));
+    }
 }
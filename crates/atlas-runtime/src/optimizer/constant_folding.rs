@@ -2,18 +2,25 @@
 //!
 //! Evaluates constant expressions at compile time:
 //! - Binary arithmetic: `Constant(a), Constant(b), Op` → `Constant(a op b)`
+//! - String concatenation: `Constant("a"), Constant("b"), Add` → `Constant("ab")`
+//! - Equality of literals (numbers, strings, bools, null)
 //! - Unary negation: `Constant(n), Negate` → `Constant(-n)`
-//! - Boolean not: `Constant(bool), Not` → `True`/`False`
-//! - Literal not: `True/False, Not` → `False/True`
+//! - Boolean not: `True/False/Null, Not` → `False/True`
+//! - Short-circuit `&&` / `||` with a literal bool on the left
+//!
+//! Operations that would fail at runtime (division by zero, non-finite
+//! results) are left unfolded so the VM still reports the error.
 //!
 //! Multiple passes are run until the bytecode stabilizes.
 
 use super::{
-    decode_instructions, encode_instructions, fix_all_references, DecodedInstruction,
+    decode_instructions, encode_instructions, fix_all_references, function_offsets,
+    is_interior_free, is_jump_opcode, jump_target, referenced_offsets, DecodedInstruction,
     OptimizationPass, OptimizationStats,
 };
 use crate::bytecode::{Bytecode, Opcode};
 use crate::value::Value;
+use std::collections::HashSet;
 
 /// Constant folding optimization pass
 ///
@@ -38,23 +45,23 @@ impl OptimizationPass for ConstantFoldingPass {
         let mut changed = true;
         while changed {
             changed = false;
+            // Offsets something jumps or calls into; a fold may only absorb
+            // instructions that are not in this set (except the first one).
+            let targets = referenced_offsets(&decoded, &constants);
             let mut new_decoded: Vec<DecodedInstruction> = Vec::with_capacity(decoded.len());
             let mut i = 0;
 
             while i < decoded.len() {
-                // ── Pattern: Constant(a), Constant(b), BinaryOp ───────────────
+                // ── Pattern: literal(a), literal(b), BinaryOp ─────────────────
                 if i + 2 < decoded.len()
-                    && decoded[i].opcode == Opcode::Constant
-                    && decoded[i + 1].opcode == Opcode::Constant
+                    && is_foldable_binary(decoded[i + 2].opcode)
+                    && is_interior_free(&decoded, &targets, i + 1..i + 3)
                 {
-                    let a_idx = decoded[i].read_u16() as usize;
-                    let b_idx = decoded[i + 1].read_u16() as usize;
-                    let op = decoded[i + 2].opcode;
-
-                    if a_idx < constants.len() && b_idx < constants.len() && is_foldable_binary(op)
-                    {
-                        if let Some(result) = fold_binary(&constants[a_idx], &constants[b_idx], op)
-                        {
+                    if let (Some(a), Some(b)) = (
+                        literal_value(&decoded[i], &constants),
+                        literal_value(&decoded[i + 1], &constants),
+                    ) {
+                        if let Some(result) = fold_binary(&a, &b, decoded[i + 2].opcode) {
                             let new_idx = constants.len() as u16;
                             constants.push(result);
                             let span = decoded[i].span.or(decoded[i + 2].span);
@@ -72,10 +79,33 @@ impl OptimizationPass for ConstantFoldingPass {
                     }
                 }
 
+                // ── Pattern: literal && / || ... ──────────────────────────────
+                match match_short_circuit(&decoded, i, &constants, &targets) {
+                    Some(ShortCircuit::KeepRight { test_len }) => {
+                        // The right operand always runs and is the result:
+                        // drop the test and let the right operand take its place
+                        decoded[i + test_len].offset = decoded[i].offset;
+                        i += test_len;
+                        stats.constants_folded += 1;
+                        changed = true;
+                        continue;
+                    }
+                    Some(ShortCircuit::SkipRight { end }) => {
+                        // The right operand never runs: the result is the literal
+                        new_decoded.push(decoded[i].clone());
+                        i = end;
+                        stats.constants_folded += 1;
+                        changed = true;
+                        continue;
+                    }
+                    None => {}
+                }
+
                 // ── Pattern: Constant(n), Negate ──────────────────────────────
                 if i + 1 < decoded.len()
                     && decoded[i].opcode == Opcode::Constant
                     && decoded[i + 1].opcode == Opcode::Negate
+                    && is_interior_free(&decoded, &targets, i + 1..i + 2)
                 {
                     let a_idx = decoded[i].read_u16() as usize;
                     if a_idx < constants.len() {
@@ -97,83 +127,31 @@ impl OptimizationPass for ConstantFoldingPass {
                     }
                 }
 
-                // ── Pattern: Constant(bool), Not ──────────────────────────────
-                if i + 1 < decoded.len()
-                    && decoded[i].opcode == Opcode::Constant
-                    && decoded[i + 1].opcode == Opcode::Not
-                {
-                    let a_idx = decoded[i].read_u16() as usize;
-                    if a_idx < constants.len() {
-                        if let Value::Bool(b) = constants[a_idx] {
-                            let new_opcode = if b { Opcode::False } else { Opcode::True };
-                            let span = decoded[i].span;
-                            new_decoded.push(DecodedInstruction {
-                                offset: decoded[i].offset,
-                                opcode: new_opcode,
-                                operands: Vec::new(),
-                                span,
-                            });
-                            i += 2;
-                            stats.constants_folded += 1;
-                            changed = true;
-                            continue;
-                        }
-                    }
-                }
-
-                // ── Pattern: True, Not → False ────────────────────────────────
-                if i + 1 < decoded.len()
-                    && decoded[i].opcode == Opcode::True
-                    && decoded[i + 1].opcode == Opcode::Not
-                {
-                    let span = decoded[i].span;
-                    new_decoded.push(DecodedInstruction {
-                        offset: decoded[i].offset,
-                        opcode: Opcode::False,
-                        operands: Vec::new(),
-                        span,
-                    });
-                    i += 2;
-                    stats.constants_folded += 1;
-                    changed = true;
-                    continue;
-                }
-
-                // ── Pattern: False, Not → True ────────────────────────────────
-                if i + 1 < decoded.len()
-                    && decoded[i].opcode == Opcode::False
-                    && decoded[i + 1].opcode == Opcode::Not
-                {
-                    let span = decoded[i].span;
-                    new_decoded.push(DecodedInstruction {
-                        offset: decoded[i].offset,
-                        opcode: Opcode::True,
-                        operands: Vec::new(),
-                        span,
-                    });
-                    i += 2;
-                    stats.constants_folded += 1;
-                    changed = true;
-                    continue;
-                }
-
-                // ── Pattern: Null, Not ────────────────────────────────────────
+                // ── Pattern: literal(bool | null), Not ────────────────────────
                 // null is falsy, so !null = true
                 if i + 1 < decoded.len()
-                    && decoded[i].opcode == Opcode::Null
                     && decoded[i + 1].opcode == Opcode::Not
+                    && is_interior_free(&decoded, &targets, i + 1..i + 2)
                 {
-                    let span = decoded[i].span;
-                    new_decoded.push(DecodedInstruction {
-                        offset: decoded[i].offset,
-                        opcode: Opcode::True,
-                        operands: Vec::new(),
-                        span,
-                    });
-                    i += 2;
-                    stats.constants_folded += 1;
-                    changed = true;
-                    continue;
+                    let negated = match literal_value(&decoded[i], &constants) {
+                        Some(Value::Bool(b)) => Some(!b),
+                        Some(Value::Null) => Some(true),
+                        _ => None,
+                    };
+                    if let Some(result) = negated {
+                        let new_opcode = if result { Opcode::True } else { Opcode::False };
+                        let span = decoded[i].span;
+                        new_decoded.push(DecodedInstruction {
+                            offset: decoded[i].offset,
+                            opcode: new_opcode,
+                            operands: Vec::new(),
+                            span,
+                        });
+                        i += 2;
+                        stats.constants_folded += 1;
+                        changed = true;
+                        continue;
+                    }
                 }
 
                 // No pattern matched — keep instruction as-is
@@ -193,6 +171,86 @@ impl OptimizationPass for ConstantFoldingPass {
     }
 }
 
+/// How a short-circuit operator with a literal left operand folds
+enum ShortCircuit {
+    /// `true && r` / `false || r`: drop the `test_len` test instructions, keep `r`
+    KeepRight { test_len: usize },
+    /// `false && r` / `true || r`: keep the literal, resume at index `end`
+    SkipRight { end: usize },
+}
+
+/// Match the code the compiler emits for `&&` / `||` at index `i`:
+///
+/// ```text
+/// &&:  left, Dup,      JumpIfFalse end, Pop, right..., end:
+/// ||:  left, Dup, Not, JumpIfFalse end, Pop, right..., end:
+/// ```
+fn match_short_circuit(
+    decoded: &[DecodedInstruction],
+    i: usize,
+    constants: &[Value],
+    targets: &HashSet<usize>,
+) -> Option<ShortCircuit> {
+    let Some(Value::Bool(left)) = literal_value(&decoded[i], constants) else {
+        return None;
+    };
+    let is_or = decoded.get(i + 2)?.opcode == Opcode::Not;
+    let jump = if is_or { i + 3 } else { i + 2 };
+    if decoded[i + 1].opcode != Opcode::Dup
+        || decoded.get(jump)?.opcode != Opcode::JumpIfFalse
+        || decoded.get(jump + 1)?.opcode != Opcode::Pop
+    {
+        return None;
+    }
+
+    let right = jump + 2;
+    if left != is_or {
+        let first = decoded.get(right)?;
+        // The right operand inherits the literal's offset, which would skew a
+        // relative jump's target
+        if is_jump_opcode(first.opcode) || !is_interior_free(decoded, targets, i + 1..right + 1) {
+            return None;
+        }
+        return Some(ShortCircuit::KeepRight {
+            test_len: right - i,
+        });
+    }
+
+    let end_offset = jump_target(&decoded[jump]);
+    let end = decoded[right..]
+        .iter()
+        .position(|instr| instr.offset == end_offset)?
+        + right;
+    // Nothing outside the skipped range may jump into it
+    let outside: HashSet<usize> = decoded[..i]
+        .iter()
+        .chain(&decoded[end..])
+        .filter(|instr| is_jump_opcode(instr.opcode) && instr.operands.len() == 2)
+        .map(jump_target)
+        .chain(function_offsets(constants))
+        .collect();
+    if !is_interior_free(decoded, &outside, i + 1..end) {
+        return None;
+    }
+    Some(ShortCircuit::SkipRight { end })
+}
+
+/// The literal value pushed by `instr`, if it pushes a compile-time constant
+fn literal_value(instr: &DecodedInstruction, constants: &[Value]) -> Option<Value> {
+    match instr.opcode {
+        Opcode::Constant => match constants.get(instr.read_u16() as usize)? {
+            value @ (Value::Number(_) | Value::String(_) | Value::Bool(_) | Value::Null) => {
+                Some(value.clone())
+            }
+            _ => None,
+        },
+        Opcode::True => Some(Value::Bool(true)),
+        Opcode::False => Some(Value::Bool(false)),
+        Opcode::Null => Some(Value::Null),
+        _ => None,
+    }
+}
+
 /// Returns true if `op` is a binary opcode that constant folding can evaluate
 fn is_foldable_binary(op: Opcode) -> bool {
     matches!(
@@ -211,47 +269,49 @@ fn is_foldable_binary(op: Opcode) -> bool {
     )
 }
 
-/// Attempt to fold a binary operation on two constant values.
+/// Attempt to fold a binary operation on two literal values.
 /// Returns `None` if the operation is not supported, or would produce a
 /// runtime error (e.g., division by zero).
 fn fold_binary(a: &Value, b: &Value, op: Opcode) -> Option<Value> {
+    match op {
+        Opcode::Equal => return Some(Value::Bool(a == b)),
+        Opcode::NotEqual => return Some(Value::Bool(a != b)),
+        _ => {}
+    }
+
     match (a, b) {
-        (Value::Number(an), Value::Number(bn)) => match op {
-            Opcode::Add => Some(Value::Number(an + bn)),
-            Opcode::Sub => Some(Value::Number(an - bn)),
-            Opcode::Mul => Some(Value::Number(an * bn)),
-            Opcode::Div => {
-                // Preserve runtime semantics: don't fold division by zero
-                if *bn == 0.0 {
-                    None
-                } else {
-                    Some(Value::Number(an / bn))
-                }
-            }
-            Opcode::Mod => {
-                if *bn == 0.0 {
-                    None
-                } else {
-                    Some(Value::Number(an % bn))
-                }
-            }
-            Opcode::Equal => Some(Value::Bool((an - bn).abs() < f64::EPSILON)),
-            Opcode::NotEqual => Some(Value::Bool((an - bn).abs() >= f64::EPSILON)),
-            Opcode::Less => Some(Value::Bool(an < bn)),
-            Opcode::LessEqual => Some(Value::Bool(an <= bn)),
-            Opcode::Greater => Some(Value::Bool(an > bn)),
-            Opcode::GreaterEqual => Some(Value::Bool(an >= bn)),
-            _ => None,
-        },
-        (Value::Bool(ab), Value::Bool(bb)) => match op {
-            Opcode::Equal => Some(Value::Bool(ab == bb)),
-            Opcode::NotEqual => Some(Value::Bool(ab != bb)),
-            _ => None,
-        },
+        (Value::Number(an), Value::Number(bn)) => fold_numeric(*an, *bn, op),
+        (Value::String(x), Value::String(y)) if op == Opcode::Add => {
+            Some(Value::string(format!("{}{}", x, y)))
+        }
         _ => None,
     }
 }
 
+/// Fold a numeric binary operation, mirroring the VM's error checks
+fn fold_numeric(a: f64, b: f64, op: Opcode) -> Option<Value> {
+    let result = match op {
+        Opcode::Add => a + b,
+        Opcode::Sub => a - b,
+        Opcode::Mul => a * b,
+        // Preserve runtime semantics: don't fold division by zero
+        Opcode::Div | Opcode::Mod if b == 0.0 => return None,
+        Opcode::Div => a / b,
+        Opcode::Mod => a % b,
+        Opcode::Less => return Some(Value::Bool(a < b)),
+        Opcode::LessEqual => return Some(Value::Bool(a <= b)),
+        Opcode::Greater => return Some(Value::Bool(a > b)),
+        Opcode::GreaterEqual => return Some(Value::Bool(a >= b)),
+        _ => return None,
+    };
+    // The VM raises InvalidNumericResult for NaN / infinity
+    if result.is_finite() {
+        Some(Value::Number(result))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +345,14 @@ mod tests {
         run_cf(bc)
     }
 
+    /// Apply constant folding and check the result matches the unoptimized run
+    fn assert_cf_preserves(source: &str) -> (Bytecode, OptimizationStats) {
+        let bc = compile_source(source);
+        let (optimized, stats) = run_cf(bc.clone());
+        assert_eq!(run_bytecode(bc), run_bytecode(optimized.clone()));
+        (optimized, stats)
+    }
+
     // ── Arithmetic folding ────────────────────────────────────────────────────
 
     #[test]
@@ -601,12 +669,96 @@ mod tests {
     }
 
     #[test]
-    fn test_no_fold_string_operations() {
-        // String concatenation is not foldable by this pass
-        let (result, stats) = cf_source("\"hello\" + \" world\";");
-        // No folding of string operations
+    fn test_no_fold_overflow() {
+        // 1e308 * 10 is infinite — the VM reports InvalidNumericResult
+        let (_result, stats) = cf_source("1e308 * 10;");
         assert_eq!(stats.constants_folded, 0);
-        let _ = result;
+    }
+
+    #[test]
+    fn test_no_fold_across_jump_target() {
+        // The `true` inside `c && true` is followed by the `&&` end label,
+        // so `true == false` must not be folded across it
+        let (optimized, stats) = assert_cf_preserves("let c = false; (c && true) == false;");
+        assert_eq!(stats.constants_folded, 0);
+        assert_eq!(run_bytecode(optimized), Some(Value::Bool(true)));
+    }
+
+    // ── String and literal folding ────────────────────────────────────────────
+
+    #[test]
+    fn test_fold_string_concatenation() {
+        let (result, stats) = cf_source("\"hello\" + \" world\";");
+        assert_eq!(stats.constants_folded, 1);
+        assert_eq!(
+            *result.constants.last().unwrap(),
+            Value::string("hello world")
+        );
+    }
+
+    #[test]
+    fn test_fold_string_equality() {
+        let (result, stats) = cf_source("\"a\" == \"a\";");
+        assert_eq!(stats.constants_folded, 1);
+        assert_eq!(*result.constants.last().unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_fold_bool_literal_equality() {
+        let (result, stats) = cf_source("true == false;");
+        assert_eq!(stats.constants_folded, 1);
+        assert_eq!(*result.constants.last().unwrap(), Value::Bool(false));
+    }
+
+    #[test]
+    fn test_fold_number_equality_is_exact() {
+        let (optimized, _stats) = assert_cf_preserves("0.1 + 0.2 == 0.3;");
+        assert_eq!(run_bytecode(optimized), Some(Value::Bool(false)));
+    }
+
+    // ── Short-circuit folding ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_fold_true_and_keeps_right() {
+        let (optimized, stats) = assert_cf_preserves("let c = false; true && c;");
+        assert_eq!(stats.constants_folded, 1);
+        assert!(!optimized
+            .instructions
+            .contains(&(Opcode::JumpIfFalse as u8)));
+    }
+
+    #[test]
+    fn test_fold_false_and_skips_right() {
+        let (optimized, stats) = assert_cf_preserves("let c = true; false && c;");
+        assert_eq!(stats.constants_folded, 1);
+        assert!(!optimized
+            .instructions
+            .contains(&(Opcode::JumpIfFalse as u8)));
+        assert_eq!(run_bytecode(optimized), Some(Value::Bool(false)));
+    }
+
+    #[test]
+    fn test_fold_true_or_skips_right() {
+        let (optimized, stats) = assert_cf_preserves("let c = false; true || c;");
+        assert_eq!(stats.constants_folded, 1);
+        assert_eq!(run_bytecode(optimized), Some(Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_fold_false_or_keeps_right() {
+        let (optimized, stats) = assert_cf_preserves("let c = true; false || c;");
+        assert_eq!(stats.constants_folded, 1);
+        assert_eq!(run_bytecode(optimized), Some(Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_fold_short_circuit_chain() {
+        let (optimized, stats) = assert_cf_preserves("(1 < 2) && (3 > 4 || \"a\" == \"a\");");
+        assert!(stats.constants_folded >= 4);
+        assert!(!optimized
+            .instructions
+            .contains(&(Opcode::JumpIfFalse as u8)));
+        assert_eq!(run_bytecode(optimized), Some(Value::Bool(true)));
     }
 
     // ── Edge cases ────────────────────────────────────────────────────────────
@@ -139,8 +139,9 @@ fn compute_reachable(
                 // NO fallthrough
             }
 
-            // Conditional jump: both fallthrough and jump target are successors
-            Opcode::JumpIfFalse => {
+            // Conditional jump (or a handler whose catch block runs on error):
+            // both fallthrough and jump target are successors
+            Opcode::JumpIfFalse | Opcode::TryBegin => {
                 if instr.operands.len() == 2 {
                     let relative = instr.read_i16();
                     let target = (offset as isize + 3 + relative as isize) as usize;
@@ -152,7 +153,7 @@ fn compute_reachable(
             }
 
             // Terminators: no successors
-            Opcode::Return | Opcode::Halt => {}
+            Opcode::Return | Opcode::TailCall | Opcode::Halt => {}
 
             // All other instructions: fallthrough to next
             _ => {
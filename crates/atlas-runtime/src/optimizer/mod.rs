@@ -26,6 +26,8 @@ pub use peephole::PeepholePass;
 use crate::bytecode::{Bytecode, DebugSpan, Opcode};
 use crate::span::Span;
 use crate::value::Value;
+use std::collections::HashSet;
+use std::ops::Range;
 
 // ============================================================================
 // Public API: OptimizationStats
@@ -308,17 +310,60 @@ pub(crate) fn operand_size(opcode: Opcode) -> usize {
         | Opcode::GetGlobal
         | Opcode::SetGlobal
         | Opcode::Array
+        | Opcode::NewMap
         | Opcode::Jump
         | Opcode::JumpIfFalse
-        | Opcode::Loop => 2,
-        Opcode::Call => 1,
+        | Opcode::Loop
+        | Opcode::TryBegin => 2,
+        Opcode::Call | Opcode::TailCall => 1,
         _ => 0,
     }
 }
 
 /// Returns true if this opcode is a jump instruction (has an i16 relative offset)
 pub(crate) fn is_jump_opcode(opcode: Opcode) -> bool {
-    matches!(opcode, Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop)
+    matches!(
+        opcode,
+        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop | Opcode::TryBegin
+    )
+}
+
+/// Absolute byte offset a jump instruction transfers control to
+pub(crate) fn jump_target(instr: &DecodedInstruction) -> usize {
+    let ip_after = instr.offset + 3; // opcode(1) + operand(2)
+    (ip_after as isize + instr.read_i16() as isize) as usize
+}
+
+/// Bytecode offsets of all functions in the constant pool
+pub(crate) fn function_offsets(constants: &[Value]) -> impl Iterator<Item = usize> + '_ {
+    constants.iter().filter_map(|constant| match constant {
+        Value::Function(func_ref) => Some(func_ref.bytecode_offset),
+        _ => None,
+    })
+}
+
+/// Offsets that are jump targets or function entry points
+pub(crate) fn referenced_offsets(
+    decoded: &[DecodedInstruction],
+    constants: &[Value],
+) -> HashSet<usize> {
+    decoded
+        .iter()
+        .filter(|instr| is_jump_opcode(instr.opcode) && instr.operands.len() == 2)
+        .map(jump_target)
+        .chain(function_offsets(constants))
+        .collect()
+}
+
+/// Check that no instruction in `range` is referenced by `targets`
+pub(crate) fn is_interior_free(
+    decoded: &[DecodedInstruction],
+    targets: &HashSet<usize>,
+    range: Range<usize>,
+) -> bool {
+    decoded[range]
+        .iter()
+        .all(|instr| !targets.contains(&instr.offset))
 }
 
 /// Returns true if this opcode terminates a basic block unconditionally.
@@ -329,7 +374,7 @@ pub(crate) fn is_jump_opcode(opcode: Opcode) -> bool {
 pub(crate) fn is_unconditional_terminator(opcode: Opcode) -> bool {
     matches!(
         opcode,
-        Opcode::Jump | Opcode::Return | Opcode::Halt | Opcode::Loop
+        Opcode::Jump | Opcode::Return | Opcode::TailCall | Opcode::Halt | Opcode::Loop
     )
 }
 
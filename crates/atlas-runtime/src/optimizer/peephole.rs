@@ -2,6 +2,7 @@
 //!
 //! Applies local pattern simplifications to the instruction stream:
 //! - `Dup, Pop` → nothing (useless dup immediately discarded)
+//! - `SetLocal n, Pop, GetLocal n` → `SetLocal n` (store then immediate reload)
 //! - `Not, Not` → nothing (double negation)
 //! - `True, Not` → `False` (constant boolean flip)
 //! - `False, Not` → `True` (constant boolean flip)
@@ -11,8 +12,8 @@
 //! Multiple passes are run until the bytecode stabilizes.
 
 use super::{
-    decode_instructions, encode_instructions, fix_all_references, DecodedInstruction,
-    OptimizationPass, OptimizationStats,
+    decode_instructions, encode_instructions, fix_all_references, is_interior_free,
+    referenced_offsets, DecodedInstruction, OptimizationPass, OptimizationStats,
 };
 use crate::bytecode::{Bytecode, Opcode};
 
@@ -39,6 +40,7 @@ impl OptimizationPass for PeepholePass {
         let mut changed = true;
         while changed {
             changed = false;
+            let targets = referenced_offsets(&decoded, &constants);
             let mut new_decoded: Vec<DecodedInstruction> = Vec::with_capacity(decoded.len());
             let mut i = 0;
 
@@ -54,6 +56,24 @@ impl OptimizationPass for PeepholePass {
                     continue;
                 }
 
+                // ── Pattern: SetLocal n, Pop, GetLocal n → SetLocal n ────────
+                // SetLocal peeks, so the stored value is still on the stack;
+                // popping it only to reload the same slot is redundant. The
+                // Pop and GetLocal must not be jump targets.
+                if i + 2 < decoded.len()
+                    && decoded[i].opcode == Opcode::SetLocal
+                    && decoded[i + 1].opcode == Opcode::Pop
+                    && decoded[i + 2].opcode == Opcode::GetLocal
+                    && decoded[i].operands == decoded[i + 2].operands
+                    && is_interior_free(&decoded, &targets, i + 1..i + 3)
+                {
+                    new_decoded.push(decoded[i].clone());
+                    i += 3;
+                    stats.peephole_patterns_applied += 1;
+                    changed = true;
+                    continue;
+                }
+
                 // ── Pattern: Not, Not → nothing ──────────────────────────────
                 if i + 1 < decoded.len()
                     && decoded[i].opcode == Opcode::Not
@@ -261,6 +281,57 @@ mod tests {
         assert!(result.instructions.contains(&(Opcode::Pop as u8)));
     }
 
+    // ── Store-reload elimination ──────────────────────────────────────────────
+
+    fn store_reload(set: u16, get: u16) -> Bytecode {
+        let mut bc = Bytecode::new();
+        bc.emit(Opcode::Null, Span::dummy());
+        bc.emit(Opcode::SetLocal, Span::dummy());
+        bc.emit_u16(set);
+        bc.emit(Opcode::Pop, Span::dummy());
+        bc.emit(Opcode::GetLocal, Span::dummy());
+        bc.emit_u16(get);
+        bc.emit(Opcode::Halt, Span::dummy());
+        bc
+    }
+
+    #[test]
+    fn test_eliminate_set_pop_get_same_slot() {
+        let (result, stats) = run_peep(store_reload(0, 0));
+        assert_eq!(stats.peephole_patterns_applied, 1);
+        assert!(result.instructions.contains(&(Opcode::SetLocal as u8)));
+        assert!(!result.instructions.contains(&(Opcode::Pop as u8)));
+        assert!(!result.instructions.contains(&(Opcode::GetLocal as u8)));
+    }
+
+    #[test]
+    fn test_keep_set_pop_get_different_slot() {
+        let (result, stats) = run_peep(store_reload(0, 1));
+        assert_eq!(stats.peephole_patterns_applied, 0);
+        assert!(result.instructions.contains(&(Opcode::GetLocal as u8)));
+    }
+
+    #[test]
+    fn test_keep_set_pop_get_when_reload_is_jump_target() {
+        // 0: Jump → 7 (the GetLocal)
+        // 3: SetLocal 0
+        // 6: Pop
+        // 7: GetLocal 0
+        let mut bc = Bytecode::new();
+        bc.emit(Opcode::Jump, Span::dummy());
+        bc.emit_i16(4);
+        bc.emit(Opcode::SetLocal, Span::dummy());
+        bc.emit_u16(0);
+        bc.emit(Opcode::Pop, Span::dummy());
+        bc.emit(Opcode::GetLocal, Span::dummy());
+        bc.emit_u16(0);
+        bc.emit(Opcode::Halt, Span::dummy());
+
+        let (result, stats) = run_peep(bc);
+        assert_eq!(stats.peephole_patterns_applied, 0);
+        assert!(result.instructions.contains(&(Opcode::GetLocal as u8)));
+    }
+
     // ── Not-Not elimination ───────────────────────────────────────────────────
 
     #[test]
@@ -421,6 +492,31 @@ mod tests {
         assert_eq!(result_orig, result_opt);
     }
 
+    #[test]
+    fn test_store_reload_shrinks_function_and_preserves_result() {
+        let source = r#"
+            fn step(n: number) -> number {
+                var x = n;
+                x = x + 1;
+                let y = x;
+                var i = 0;
+                while (i < 3) { i = i + 1; }
+                x = x * y + i;
+                return x;
+            }
+            step(4);
+        "#;
+        let bc = compile_source(source);
+        let size_orig = bc.instructions.len();
+        let result_orig = run_bytecode(bc.clone());
+        let (optimized, stats) = run_peep(bc);
+        assert!(stats.peephole_patterns_applied >= 2);
+        assert!(optimized.instructions.len() < size_orig);
+        let result_opt = run_bytecode(optimized);
+        assert_eq!(result_orig, Some(crate::value::Value::Number(28.0)));
+        assert_eq!(result_orig, result_opt);
+    }
+
     // ── Edge cases ────────────────────────────────────────────────────────────
 
     #[test]
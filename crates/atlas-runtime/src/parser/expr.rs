@@ -28,11 +28,13 @@ impl Parser {
         match self.peek().kind {
             TokenKind::Number => self.parse_number(),
             TokenKind::String => self.parse_string(),
+            TokenKind::StringInterpStart => self.parse_string_interp(),
             TokenKind::True | TokenKind::False => self.parse_bool(),
             TokenKind::Null => self.parse_null(),
             TokenKind::Identifier => self.parse_identifier(),
             TokenKind::LeftParen => self.parse_group(),
             TokenKind::LeftBracket => self.parse_array_literal(),
+            TokenKind::LeftBrace => self.parse_map_literal(),
             TokenKind::Minus | TokenKind::Bang => self.parse_unary(),
             TokenKind::Match => self.parse_match_expr(),
             _ => {
@@ -58,6 +60,7 @@ impl Parser {
             | TokenKind::GreaterEqual
             | TokenKind::AmpAmp
             | TokenKind::PipePipe => self.parse_binary(left),
+            TokenKind::PipeGreater => self.parse_pipeline(left),
             TokenKind::LeftParen => self.parse_call(left),
             TokenKind::LeftBracket => self.parse_index(left),
             TokenKind::Dot => self.parse_member(left),
@@ -81,6 +84,7 @@ impl Parser {
             | TokenKind::LessEqual
             | TokenKind::Greater
             | TokenKind::GreaterEqual => Precedence::Comparison,
+            TokenKind::PipeGreater => Precedence::Pipeline,
             TokenKind::Plus | TokenKind::Minus => Precedence::Term,
             TokenKind::Star | TokenKind::Slash | TokenKind::Percent => Precedence::Factor,
             TokenKind::LeftParen
@@ -93,11 +97,36 @@ impl Parser {
 
     /// Parse number literal
     fn parse_number(&mut self) -> Result<Expr, ()> {
+        let (literal, span) = self.number_literal();
+        Ok(Expr::Literal(literal, span))
+    }
+
+    /// Consume a number token as a number or `i`-suffixed int literal
+    fn number_literal(&mut self) -> (Literal, Span) {
         let token = self.advance();
         let span = token.span;
         let lexeme = token.lexeme.clone();
-        let value: f64 = match lexeme.parse::<f64>() {
-            Ok(value) if value.is_finite() => value,
+        // `_` separators carry no value
+        let text = lexeme.replace('_', "");
+        if let Some(digits) = text.strip_suffix('i') {
+            let value = parse_int_digits(digits, false).unwrap_or_else(|| {
+                self.error_at_with_code(
+                    E_BAD_NUMBER,
+                    &format!("Int literal out of range: '{}'", lexeme),
+                    span,
+                );
+                0
+            });
+            return (Literal::Int(value), span);
+        }
+        let value = match radix_digits(&text) {
+            Some((radix, digits)) => u64::from_str_radix(digits, radix)
+                .ok()
+                .map(|value| value as f64),
+            None => text.parse::<f64>().ok(),
+        };
+        let value: f64 = match value {
+            Some(value) if value.is_finite() => value,
             _ => {
                 self.error_at_with_code(
                     E_BAD_NUMBER,
@@ -107,7 +136,7 @@ impl Parser {
                 0.0
             }
         };
-        Ok(Expr::Literal(Literal::Number(value), span))
+        (Literal::Number(value), span)
     }
 
     /// Parse string literal
@@ -117,6 +146,44 @@ impl Parser {
         Ok(Expr::Literal(Literal::String(token.lexeme.clone()), span))
     }
 
+    /// Parse interpolated string: `"text ${expr} text"`
+    ///
+    /// The lexer emits `StringInterpStart`, the embedded expression tokens,
+    /// then `StringInterpMiddle` before each further expression and
+    /// `StringInterpEnd` after the last one.
+    fn parse_string_interp(&mut self) -> Result<Expr, ()> {
+        let start = self.advance();
+        let start_span = start.span;
+        let mut parts = Vec::new();
+        if !start.lexeme.is_empty() {
+            parts.push(StringInterpPart::Literal(start.lexeme.clone()));
+        }
+
+        loop {
+            parts.push(StringInterpPart::Expr(self.parse_expression()?));
+
+            let chunk = match self.peek().kind {
+                TokenKind::StringInterpMiddle | TokenKind::StringInterpEnd => self.advance(),
+                _ => {
+                    self.error("Expected '}' to close string interpolation");
+                    return Err(());
+                }
+            };
+            let is_end = chunk.kind == TokenKind::StringInterpEnd;
+            let chunk_span = chunk.span;
+            if !chunk.lexeme.is_empty() {
+                parts.push(StringInterpPart::Literal(chunk.lexeme.clone()));
+            }
+
+            if is_end {
+                return Ok(Expr::StringInterp(StringInterpExpr {
+                    parts,
+                    span: start_span.merge(chunk_span),
+                }));
+            }
+        }
+    }
+
     /// Parse boolean literal
     fn parse_bool(&mut self) -> Result<Expr, ()> {
         let token = self.advance();
@@ -138,6 +205,7 @@ impl Parser {
         Ok(Expr::Identifier(Identifier {
             name: token.lexeme.clone(),
             span: token.span,
+            unwrap_payload: std::cell::Cell::new(None),
         }))
     }
 
@@ -164,6 +232,10 @@ impl Parser {
                 if !self.match_token(TokenKind::Comma) {
                     break;
                 }
+                // Allow trailing comma before `]`
+                if self.check(TokenKind::RightBracket) {
+                    break;
+                }
             }
         }
 
@@ -175,6 +247,51 @@ impl Parser {
         }))
     }
 
+    /// Parse map literal: `{ key: value, "other key": value }`
+    fn parse_map_literal(&mut self) -> Result<Expr, ()> {
+        let start_span = self.consume(TokenKind::LeftBrace, "Expected '{'")?.span;
+        let mut entries = Vec::new();
+
+        if !self.check(TokenKind::RightBrace) {
+            loop {
+                let key_token = match self.peek().kind {
+                    TokenKind::Identifier | TokenKind::String => self.advance(),
+                    _ => {
+                        self.error("Expected identifier or string as map key");
+                        return Err(());
+                    }
+                };
+                let key = key_token.lexeme.clone();
+                let key_span = key_token.span;
+
+                self.consume(TokenKind::Colon, "Expected ':' after map key")?;
+                let value = self.parse_expression()?;
+                let span = key_span.merge(value.span());
+                entries.push(MapEntry {
+                    key,
+                    key_span,
+                    value,
+                    span,
+                });
+
+                if !self.match_token(TokenKind::Comma) {
+                    break;
+                }
+                // Allow trailing comma before `}`
+                if self.check(TokenKind::RightBrace) {
+                    break;
+                }
+            }
+        }
+
+        let end_span = self.consume(TokenKind::RightBrace, "Expected '}'")?.span;
+
+        Ok(Expr::MapLiteral(MapLiteral {
+            entries,
+            span: start_span.merge(end_span),
+        }))
+    }
+
     /// Parse unary expression
     fn parse_unary(&mut self) -> Result<Expr, ()> {
         let op_token = self.advance();
@@ -185,6 +302,18 @@ impl Parser {
             _ => unreachable!(),
         };
 
+        // `-9223372036854775808i` (i64::MIN) has no positive counterpart, so it
+        // is read as one negative literal instead of negating an out-of-range one
+        if matches!(op, UnaryOp::Negate) && self.check(TokenKind::Number) {
+            if let Some(value) = negative_only_int(&self.peek().lexeme) {
+                let literal_span = self.advance().span;
+                return Ok(Expr::Literal(
+                    Literal::Int(value),
+                    op_span.merge(literal_span),
+                ));
+            }
+        }
+
         let operand = self.parse_precedence(Precedence::Unary)?;
         let operand_span = operand.span();
 
@@ -243,6 +372,38 @@ impl Parser {
         }))
     }
 
+    /// Parse pipeline expression, desugaring `x |> f(args)` into `f(x, args)`
+    ///
+    /// The right side is a call or callee only, so `x |> f(a) + 1` is
+    /// `f(x, a) + 1`. A bare callee (`x |> f`) becomes `f(x)`; a method call
+    /// (`x |> obj.m(args)`) receives `x` as its first argument.
+    fn parse_pipeline(&mut self, left: Expr) -> Result<Expr, ()> {
+        let left_span = left.span();
+        self.consume(TokenKind::PipeGreater, "Expected '|>'")?;
+        let right = self.parse_precedence(Precedence::Unary)?;
+        let span = left_span.merge(right.span());
+
+        Ok(match right {
+            Expr::Call(mut call) => {
+                call.args.insert(0, left);
+                call.span = span;
+                Expr::Call(call)
+            }
+            Expr::Member(mut member) if member.args.is_some() => {
+                if let Some(args) = member.args.as_mut() {
+                    args.insert(0, left);
+                }
+                member.span = span;
+                Expr::Member(member)
+            }
+            callee => Expr::Call(CallExpr {
+                callee: Box::new(callee),
+                args: vec![left],
+                span,
+            }),
+        })
+    }
+
     /// Parse call expression
     fn parse_call(&mut self, callee: Expr) -> Result<Expr, ()> {
         let callee_span = callee.span();
@@ -255,6 +416,10 @@ impl Parser {
                 if !self.match_token(TokenKind::Comma) {
                     break;
                 }
+                // Allow trailing comma before `)`
+                if self.check(TokenKind::RightParen) {
+                    break;
+                }
             }
         }
 
@@ -291,6 +456,7 @@ impl Parser {
         let member = Identifier {
             name: member_token.lexeme.clone(),
             span: member_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         // Check for method call (with parentheses)
@@ -304,6 +470,10 @@ impl Parser {
                     if !self.match_token(TokenKind::Comma) {
                         break;
                     }
+                    // Allow trailing comma before `)`
+                    if self.check(TokenKind::RightParen) {
+                        break;
+                    }
                 }
             }
 
@@ -321,6 +491,8 @@ impl Parser {
             args,
             type_tag: std::cell::Cell::new(None),
             trait_dispatch: std::cell::RefCell::new(None),
+            extension_dispatch: std::cell::RefCell::new(None),
+            field_access: std::cell::Cell::new(false),
             span: target_span.merge(end_span),
         }))
     }
@@ -651,21 +823,8 @@ impl Parser {
         match self.peek().kind {
             // Literal patterns: numbers, strings, bools, null
             TokenKind::Number => {
-                let token = self.advance();
-                let span = token.span;
-                let lexeme = token.lexeme.clone();
-                let value: f64 = match lexeme.parse::<f64>() {
-                    Ok(value) if value.is_finite() => value,
-                    _ => {
-                        self.error_at_with_code(
-                            E_BAD_NUMBER,
-                            &format!("Invalid number literal: '{}'", lexeme),
-                            span,
-                        );
-                        0.0
-                    }
-                };
-                Ok(Pattern::Literal(Literal::Number(value), span))
+                let (literal, span) = self.number_literal();
+                Ok(Pattern::Literal(literal, span))
             }
             TokenKind::String => {
                 let token = self.advance();
@@ -702,6 +861,7 @@ impl Parser {
                 let id = Identifier {
                     name: id_token.lexeme.clone(),
                     span: id_token.span,
+                    unwrap_payload: std::cell::Cell::new(None),
                 };
 
                 // Check if this is a constructor pattern (has arguments)
@@ -781,3 +941,32 @@ impl Parser {
         })
     }
 }
+
+/// Split a `0x`/`0b` literal into its radix and digits
+/// Parse the digits of an int literal (decimal, `0x` or `0b`), optionally negated
+fn parse_int_digits(digits: &str, negative: bool) -> Option<i64> {
+    let sign = if negative { "-" } else { "" };
+    match radix_digits(digits) {
+        Some((radix, digits)) => i64::from_str_radix(&format!("{}{}", sign, digits), radix).ok(),
+        None => format!("{}{}", sign, digits).parse::<i64>().ok(),
+    }
+}
+
+/// The value of an int literal lexeme that only fits in an `int` when negated
+fn negative_only_int(lexeme: &str) -> Option<i64> {
+    let text = lexeme.replace('_', "");
+    let digits = text.strip_suffix('i')?;
+    if parse_int_digits(digits, false).is_some() {
+        return None;
+    }
+    parse_int_digits(digits, true)
+}
+
+fn radix_digits(text: &str) -> Option<(u32, &str)> {
+    let radix = match text.get(..2)? {
+        "0x" | "0X" => 16,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    Some((radix, &text[2..]))
+}
@@ -7,7 +7,7 @@ mod expr;
 mod stmt;
 
 use crate::ast::*;
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, TextEdit};
 use crate::span::Span;
 use crate::token::{Token, TokenKind};
 
@@ -34,6 +34,7 @@ pub(super) enum Precedence {
     And,        // &&
     Equality,   // == !=
     Comparison, // < <= > >=
+    Pipeline,   // |>
     Term,       // + -
     Factor,     // * / %
     Unary,      // ! -
@@ -103,6 +104,7 @@ impl Parser {
         let name = Identifier {
             name: name_token.lexeme.clone(),
             span: name_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         // Parse optional type parameters: <T, E, ...>
@@ -142,6 +144,7 @@ impl Parser {
             let param = Identifier {
                 name: param_token.lexeme.clone(),
                 span: param_token.span,
+                unwrap_payload: std::cell::Cell::new(None),
             };
             self.consume(
                 TokenKind::Colon,
@@ -189,6 +192,7 @@ impl Parser {
             let alias = Identifier {
                 name: alias_token.lexeme.clone(),
                 span: alias_token.span,
+                unwrap_payload: std::cell::Cell::new(None),
             };
             specifiers.push(ImportSpecifier::Namespace {
                 alias,
@@ -203,6 +207,7 @@ impl Parser {
                 let name = Identifier {
                     name: name_token.lexeme.clone(),
                     span: name_token.span,
+                    unwrap_payload: std::cell::Cell::new(None),
                 };
                 specifiers.push(ImportSpecifier::Named {
                     name,
@@ -242,7 +247,7 @@ impl Parser {
 
     /// Parse an export declaration
     ///
-    /// Syntax: `export fn foo() {}` or `export let x = 5`
+    /// Syntax: `export fn foo() {}`, `export let x = 5`, or `export { x } from "./path";`
     fn parse_export(&mut self) -> Result<ExportDecl, ()> {
         let export_span = self.consume(TokenKind::Export, "Expected 'export'")?.span;
 
@@ -260,8 +265,10 @@ impl Parser {
             }
         } else if self.check(TokenKind::Type) {
             ExportItem::TypeAlias(self.parse_type_alias(None)?)
+        } else if self.check(TokenKind::LeftBrace) {
+            ExportItem::ReExport(self.parse_re_export(export_span)?)
         } else {
-            self.error("Expected 'fn', 'let', 'var', or 'type' after 'export'");
+            self.error("Expected 'fn', 'let', 'var', 'type', or '{' after 'export'");
             return Err(());
         };
 
@@ -273,6 +280,46 @@ impl Parser {
         })
     }
 
+    /// Parse the `{ x, y } from "./path";` part of a re-export
+    fn parse_re_export(&mut self, export_span: Span) -> Result<ReExportDecl, ()> {
+        self.consume(TokenKind::LeftBrace, "Expected '{' for re-exports")?;
+
+        let mut names = Vec::new();
+        loop {
+            let name_token = self.consume_identifier("re-export name")?;
+            names.push(Identifier {
+                name: name_token.lexeme.clone(),
+                span: name_token.span,
+                unwrap_payload: std::cell::Cell::new(None),
+            });
+
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+
+            // Handle trailing comma
+            if self.check(TokenKind::RightBrace) {
+                break;
+            }
+        }
+
+        self.consume(TokenKind::RightBrace, "Expected '}' after re-exports")?;
+        self.consume(TokenKind::From, "Expected 'from' after re-exports")?;
+
+        let source_token = self.consume(TokenKind::String, "Expected module path string")?;
+        let source = source_token.lexeme.clone();
+
+        let end_span = self
+            .consume(TokenKind::Semicolon, "Expected ';' after re-export")?
+            .span;
+
+        Ok(ReExportDecl {
+            names,
+            source,
+            span: export_span.merge(end_span),
+        })
+    }
+
     /// Parse a type alias declaration
     fn parse_type_alias(&mut self, doc_comment: Option<String>) -> Result<TypeAliasDecl, ()> {
         let type_span = self.consume(TokenKind::Type, "Expected 'type'")?.span;
@@ -281,6 +328,7 @@ impl Parser {
         let name = Identifier {
             name: name_token.lexeme.clone(),
             span: name_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         // Parse optional type parameters: <T, E, ...>
@@ -407,6 +455,7 @@ impl Parser {
                 name: Identifier {
                     name: param_name,
                     span: param_name_span,
+                    unwrap_payload: std::cell::Cell::new(None),
                 },
                 type_ref,
                 ownership,
@@ -438,6 +487,7 @@ impl Parser {
         let name = Identifier {
             name: name_tok.lexeme.clone(),
             span: name_tok.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         let type_params = self.parse_type_params()?;
@@ -474,6 +524,7 @@ impl Parser {
         let name = Identifier {
             name: name_tok.lexeme.clone(),
             span: name_tok.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         let type_params = self.parse_type_params()?;
@@ -518,6 +569,7 @@ impl Parser {
         let trait_name = Identifier {
             name: trait_name_tok.lexeme.clone(),
             span: trait_name_tok.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         // Optional type args: `impl Functor<number> for MyType`
@@ -536,6 +588,7 @@ impl Parser {
         let type_name = Identifier {
             name: type_name_tok.lexeme.clone(),
             span: type_name_tok.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         self.consume(
@@ -574,6 +627,7 @@ impl Parser {
         let name = Identifier {
             name: name_tok.lexeme.clone(),
             span: name_tok.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         let type_params = self.parse_type_params()?;
@@ -753,6 +807,13 @@ impl Parser {
                 _ => E_UNEXPECTED,
             };
             self.error_with_code(code, message);
+            if kind == TokenKind::Semicolon && self.current > 0 {
+                // Offer to insert the `;` right after the previous token
+                let insert_at = self.tokens[self.current - 1].span.end;
+                if let Some(diag) = self.diagnostics.last_mut() {
+                    diag.fixes.push(TextEdit::insert(insert_at, ";"));
+                }
+            }
             Err(())
         }
     }
@@ -797,6 +858,8 @@ impl Parser {
                 | TokenKind::Return
                 | TokenKind::Break
                 | TokenKind::Continue
+                | TokenKind::Try
+                | TokenKind::Catch
                 | TokenKind::True
                 | TokenKind::False
                 | TokenKind::Null
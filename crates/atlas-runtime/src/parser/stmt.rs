@@ -12,6 +12,7 @@ impl Parser {
             TokenKind::Let | TokenKind::Var => self.parse_var_decl(),
             TokenKind::If => self.parse_if_stmt(),
             TokenKind::While => self.parse_while_stmt(),
+            TokenKind::Try => self.parse_try_catch_stmt(),
             TokenKind::For => {
                 // Check if it's a for-in loop or traditional for loop
                 // Peek ahead to see what comes after 'for'
@@ -26,6 +27,14 @@ impl Parser {
                     self.parse_for_in_stmt()
                 }
             }
+            TokenKind::Identifier
+                if self
+                    .tokens
+                    .get(self.current + 1)
+                    .is_some_and(|t| t.kind == TokenKind::Colon) =>
+            {
+                self.parse_labelled_loop()
+            }
             TokenKind::Return => self.parse_return_stmt(),
             TokenKind::Break => self.parse_break_stmt(),
             TokenKind::Continue => self.parse_continue_stmt(),
@@ -56,6 +65,7 @@ impl Parser {
         let name = Identifier {
             name: name_token.lexeme.clone(),
             span: name_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         let type_ref = if self.match_token(TokenKind::Colon) {
@@ -224,6 +234,48 @@ impl Parser {
         }))
     }
 
+    /// Parse a labelled loop
+    ///
+    /// Syntax: `label: while (...) { }`, `label: for (...) { }`, `label: for x in xs { }`
+    pub(super) fn parse_labelled_loop(&mut self) -> Result<Stmt, ()> {
+        let label_token = self.consume_identifier("loop label")?;
+        let label = Identifier {
+            name: label_token.lexeme.clone(),
+            span: label_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
+        };
+        self.consume(TokenKind::Colon, "Expected ':' after loop label")?;
+
+        let mut stmt = match self.peek().kind {
+            TokenKind::While => self.parse_while_stmt()?,
+            TokenKind::For => self.parse_statement()?,
+            _ => {
+                self.error(&format!(
+                    "Expected 'while' or 'for' after label '{}'",
+                    label.name
+                ));
+                return Err(());
+            }
+        };
+
+        match &mut stmt {
+            Stmt::While(WhileStmt {
+                label: slot, span, ..
+            })
+            | Stmt::For(ForStmt {
+                label: slot, span, ..
+            })
+            | Stmt::ForIn(ForInStmt {
+                label: slot, span, ..
+            }) => {
+                *span = label.span.merge(*span);
+                *slot = Some(label);
+            }
+            _ => unreachable!("labelled statement is always a loop"),
+        }
+        Ok(stmt)
+    }
+
     /// Parse while statement
     pub(super) fn parse_while_stmt(&mut self) -> Result<Stmt, ()> {
         let while_span = self.consume(TokenKind::While, "Expected 'while'")?.span;
@@ -236,12 +288,39 @@ impl Parser {
         let body_span = body.span;
 
         Ok(Stmt::While(WhileStmt {
+            label: None,
             cond,
             body,
             span: while_span.merge(body_span),
         }))
     }
 
+    /// Parse try/catch statement
+    pub(super) fn parse_try_catch_stmt(&mut self) -> Result<Stmt, ()> {
+        let try_span = self.consume(TokenKind::Try, "Expected 'try'")?.span;
+        let body = self.parse_block()?;
+
+        self.consume(TokenKind::Catch, "Expected 'catch' after try block")?;
+        self.consume(TokenKind::LeftParen, "Expected '(' after 'catch'")?;
+        let name_token = self.consume_identifier("error name after 'catch'")?;
+        let error = Identifier {
+            name: name_token.lexeme.clone(),
+            span: name_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
+        };
+        self.consume(TokenKind::RightParen, "Expected ')' after error name")?;
+
+        let handler = self.parse_block()?;
+        let handler_span = handler.span;
+
+        Ok(Stmt::TryCatch(TryCatchStmt {
+            body,
+            error,
+            handler,
+            span: try_span.merge(handler_span),
+        }))
+    }
+
     /// Parse for statement
     pub(super) fn parse_for_stmt(&mut self) -> Result<Stmt, ()> {
         let for_span = self.consume(TokenKind::For, "Expected 'for'")?.span;
@@ -353,6 +432,7 @@ impl Parser {
         let body_span = body.span;
 
         Ok(Stmt::For(ForStmt {
+            label: None,
             init,
             cond,
             step,
@@ -372,6 +452,7 @@ impl Parser {
         let variable = Identifier {
             name: name_token.lexeme.clone(),
             span: name_token.span,
+            unwrap_payload: std::cell::Cell::new(None),
         };
 
         // Expect 'in' keyword
@@ -385,6 +466,7 @@ impl Parser {
         let body_span = body.span;
 
         Ok(Stmt::ForIn(ForInStmt {
+            label: None,
             variable,
             iterable,
             body,
@@ -415,10 +497,14 @@ impl Parser {
     /// Parse break statement
     pub(super) fn parse_break_stmt(&mut self) -> Result<Stmt, ()> {
         let break_span = self.consume(TokenKind::Break, "Expected 'break'")?.span;
+        let label = self.parse_jump_label();
         let end_span = self
             .consume(TokenKind::Semicolon, "Expected ';' after break")?
             .span;
-        Ok(Stmt::Break(break_span.merge(end_span)))
+        Ok(Stmt::Break(BreakStmt {
+            label,
+            span: break_span.merge(end_span),
+        }))
     }
 
     /// Parse continue statement
@@ -426,10 +512,27 @@ impl Parser {
         let continue_span = self
             .consume(TokenKind::Continue, "Expected 'continue'")?
             .span;
+        let label = self.parse_jump_label();
         let end_span = self
             .consume(TokenKind::Semicolon, "Expected ';' after continue")?
             .span;
-        Ok(Stmt::Continue(continue_span.merge(end_span)))
+        Ok(Stmt::Continue(ContinueStmt {
+            label,
+            span: continue_span.merge(end_span),
+        }))
+    }
+
+    /// Parse the optional loop label after `break` / `continue`
+    fn parse_jump_label(&mut self) -> Option<Identifier> {
+        if !self.check(TokenKind::Identifier) {
+            return None;
+        }
+        let token = self.advance();
+        Some(Identifier {
+            name: token.lexeme.clone(),
+            span: token.span,
+            unwrap_payload: std::cell::Cell::new(None),
+        })
     }
 
     /// Parse a block
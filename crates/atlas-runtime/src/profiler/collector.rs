@@ -1,7 +1,9 @@
 //! Profile data collector
 //!
 //! Records execution statistics during VM runs: instruction counts,
-//! per-location hotness, stack depth extremes, and function call counts.
+//! per-location hotness, stack depth extremes, function call counts, heap
+//! allocations per function, and per-call-stack sample counts (for
+//! folded-stack / flamegraph output).
 
 use crate::bytecode::Opcode;
 use std::collections::HashMap;
@@ -25,6 +27,18 @@ pub struct ProfileCollector {
     function_calls: u64,
     /// Calls per named function
     function_call_counts: HashMap<String, u64>,
+    /// Total heap allocations (arrays, strings, maps) recorded
+    allocations: u64,
+    /// Allocations per executing function
+    allocation_counts: HashMap<String, u64>,
+    /// Distinct call stacks seen (outermost frame first), indexed by stack id
+    stacks: Vec<Vec<String>>,
+    /// Call stack → stack id
+    stack_ids: HashMap<Vec<String>, usize>,
+    /// Samples per stack id
+    stack_counts: Vec<u64>,
+    /// Stack id of the most recent sample
+    last_stack: Option<usize>,
 }
 
 impl ProfileCollector {
@@ -39,6 +53,12 @@ impl ProfileCollector {
             max_value_stack_depth: 0,
             function_calls: 0,
             function_call_counts: HashMap::new(),
+            allocations: 0,
+            allocation_counts: HashMap::new(),
+            stacks: Vec::new(),
+            stack_ids: HashMap::new(),
+            stack_counts: Vec::new(),
+            last_stack: None,
         }
     }
 
@@ -81,6 +101,50 @@ impl ProfileCollector {
             .or_insert(0) += 1;
     }
 
+    /// Record a heap allocation made while `function` was executing
+    pub fn record_allocation(&mut self, function: &str) {
+        self.allocations += 1;
+        match self.allocation_counts.get_mut(function) {
+            Some(count) => *count += 1,
+            None => {
+                self.allocation_counts.insert(function.to_string(), 1);
+            }
+        }
+    }
+
+    /// Record one sample of the active call stack
+    ///
+    /// `frames` yields function names from the outermost frame to the
+    /// innermost. Consecutive samples from the same frame skip the lookup.
+    pub fn record_stack_sample<'a, I>(&mut self, frames: I)
+    where
+        I: ExactSizeIterator<Item = &'a str> + DoubleEndedIterator + Clone,
+    {
+        if let Some(id) = self.last_stack {
+            let stack = &self.stacks[id];
+            if stack.len() == frames.len()
+                && stack.last().map(String::as_str) == frames.clone().next_back()
+            {
+                self.stack_counts[id] += 1;
+                return;
+            }
+        }
+
+        let stack: Vec<String> = frames.map(str::to_string).collect();
+        let id = match self.stack_ids.get(&stack) {
+            Some(&id) => id,
+            None => {
+                let id = self.stacks.len();
+                self.stack_ids.insert(stack.clone(), id);
+                self.stacks.push(stack);
+                self.stack_counts.push(0);
+                id
+            }
+        };
+        self.stack_counts[id] += 1;
+        self.last_stack = Some(id);
+    }
+
     /// Reset all counters
     pub fn reset(&mut self) {
         self.total_instructions = 0;
@@ -91,6 +155,12 @@ impl ProfileCollector {
         self.max_value_stack_depth = 0;
         self.function_calls = 0;
         self.function_call_counts.clear();
+        self.allocations = 0;
+        self.allocation_counts.clear();
+        self.stacks.clear();
+        self.stack_ids.clear();
+        self.stack_counts.clear();
+        self.last_stack = None;
     }
 
     // --- Accessors ---
@@ -145,6 +215,28 @@ impl ProfileCollector {
         &self.function_call_counts
     }
 
+    /// Total heap allocations
+    pub fn allocations(&self) -> u64 {
+        self.allocations
+    }
+
+    /// Per-function allocation counts
+    pub fn allocation_counts(&self) -> &HashMap<String, u64> {
+        &self.allocation_counts
+    }
+
+    /// Sample counts per call stack (outermost frame first), sorted by stack
+    pub fn stack_samples(&self) -> Vec<(&[String], u64)> {
+        let mut samples: Vec<(&[String], u64)> = self
+            .stacks
+            .iter()
+            .zip(&self.stack_counts)
+            .map(|(stack, &count)| (stack.as_slice(), count))
+            .collect();
+        samples.sort();
+        samples
+    }
+
     /// Top N opcodes by execution count (sorted descending)
     pub fn top_opcodes(&self, n: usize) -> Vec<(Opcode, u64)> {
         let mut pairs: Vec<(Opcode, u64)> = self
@@ -306,4 +398,39 @@ mod tests {
         assert_eq!(c.total_instructions(), 1);
         assert!(c.location_counts().is_empty());
     }
+
+    #[test]
+    fn test_record_allocation() {
+        let mut c = ProfileCollector::new();
+        c.record_allocation("<main>");
+        c.record_allocation("build");
+        c.record_allocation("build");
+        assert_eq!(c.allocations(), 3);
+        assert_eq!(c.allocation_counts().get("build"), Some(&2));
+        c.reset();
+        assert_eq!(c.allocations(), 0);
+        assert!(c.allocation_counts().is_empty());
+    }
+
+    #[test]
+    fn test_record_stack_sample() {
+        let mut c = ProfileCollector::new();
+        c.record_stack_sample(["<main>"].into_iter());
+        c.record_stack_sample(["<main>", "helper"].into_iter());
+        c.record_stack_sample(["<main>", "helper"].into_iter());
+        c.record_stack_sample(["<main>"].into_iter());
+        c.record_stack_sample(["<main>", "other"].into_iter());
+
+        let samples = c.stack_samples();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0], (&["<main>".to_string()][..], 2));
+        assert_eq!(
+            samples[1],
+            (&["<main>".to_string(), "helper".to_string()][..], 2)
+        );
+        assert_eq!(samples[2].1, 1);
+
+        c.reset();
+        assert!(c.stack_samples().is_empty());
+    }
 }
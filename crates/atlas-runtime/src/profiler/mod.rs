@@ -155,6 +155,28 @@ impl Profiler {
         }
     }
 
+    /// Record a heap allocation attributed to the executing function
+    pub fn record_allocation(&mut self, function: &str) {
+        if self.enabled {
+            self.collector.record_allocation(function);
+        }
+    }
+
+    /// Total heap allocations recorded
+    pub fn allocations(&self) -> u64 {
+        self.collector.allocations()
+    }
+
+    /// Record one sample of the active call stack (outermost frame first)
+    pub fn record_stack_sample<'a, I>(&mut self, frames: I)
+    where
+        I: ExactSizeIterator<Item = &'a str> + DoubleEndedIterator + Clone,
+    {
+        if self.enabled {
+            self.collector.record_stack_sample(frames);
+        }
+    }
+
     // --- Basic accessors (backward compat with vm/profiler.rs API) ---
 
     /// Total instructions executed
@@ -245,6 +267,15 @@ impl Profiler {
             .map(|h| (format!("{:?}", h.opcode), h.count, h.percentage))
             .collect();
 
+        let mut top_allocators: Vec<(String, u64)> = self
+            .collector
+            .allocation_counts()
+            .iter()
+            .map(|(name, &count)| (name.clone(), count))
+            .collect();
+        top_allocators.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_allocators.truncate(20);
+
         ProfileReport {
             total_instructions: total,
             elapsed_secs: self.elapsed_secs,
@@ -252,11 +283,29 @@ impl Profiler {
             max_stack_depth: self.collector.max_stack_depth(),
             max_value_stack_depth: self.collector.max_value_stack_depth(),
             function_calls: self.collector.function_calls(),
+            allocations: self.collector.allocations(),
+            top_allocators,
             top_opcodes,
             hotspots,
         }
     }
 
+    /// Folded-stack output for flamegraph tooling (inferno, flamegraph.pl)
+    ///
+    /// One `main;foo;bar count` line per distinct call stack, where `count`
+    /// is the number of instructions executed with that stack active.
+    pub fn format_folded(&self) -> String {
+        let mut out = String::new();
+        for (stack, count) in self.collector.stack_samples() {
+            let frames: Vec<&str> = stack
+                .iter()
+                .map(|name| name.trim_start_matches('<').trim_end_matches('>'))
+                .collect();
+            out.push_str(&format!("{} {}\n", frames.join(";"), count));
+        }
+        out
+    }
+
     /// Shorthand: hotspots at default 1% threshold
     pub fn hotspots(&self) -> Vec<Hotspot> {
         HotspotDetector::new().detect(&self.collector)
@@ -439,6 +488,22 @@ mod tests {
         assert!(r.contains("66.67%"));
     }
 
+    #[test]
+    fn test_format_folded() {
+        let mut p = Profiler::enabled();
+        p.record_stack_sample(["<main>"].into_iter());
+        p.record_stack_sample(["<main>", "helper"].into_iter());
+        p.record_stack_sample(["<main>", "helper"].into_iter());
+        assert_eq!(p.format_folded(), "main 1\nmain;helper 2\n");
+    }
+
+    #[test]
+    fn test_stack_sample_when_disabled() {
+        let mut p = Profiler::new();
+        p.record_stack_sample(["<main>"].into_iter());
+        assert_eq!(p.format_folded(), "");
+    }
+
     // --- generate_report ---
 
     #[test]
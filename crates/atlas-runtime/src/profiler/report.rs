@@ -19,6 +19,10 @@ pub struct ProfileReport {
     pub max_value_stack_depth: usize,
     /// Total named function calls
     pub function_calls: u64,
+    /// Total heap allocations (arrays, strings, maps)
+    pub allocations: u64,
+    /// Top allocating functions: (name, count)
+    pub top_allocators: Vec<(String, u64)>,
     /// Top opcodes: (name, count, percentage)
     pub top_opcodes: Vec<(String, u64, f64)>,
     /// Hotspot locations above the threshold
@@ -35,6 +39,7 @@ impl ProfileReport {
         if let Some(ips) = self.ips {
             s.push_str(&format!(" ({:.0} IPS)", ips));
         }
+        s.push_str(&format!("\nallocations: {}", self.allocations));
         s
     }
 
@@ -68,8 +73,22 @@ impl ProfileReport {
             self.max_value_stack_depth
         ));
         out.push_str(&format!("  Function calls     : {}\n", self.function_calls));
+        out.push_str(&format!("  Allocations        : {}\n", self.allocations));
         out.push('\n');
 
+        // --- Allocations ---
+        if !self.top_allocators.is_empty() {
+            out.push_str("[ Allocations by Function ]\n");
+            out.push_str(&format!("  {:<24} {:>12}\n", "Function", "Count"));
+            out.push_str("  ");
+            out.push_str(&"-".repeat(37));
+            out.push('\n');
+            for (name, count) in &self.top_allocators {
+                out.push_str(&format!("  {:<24} {:>12}\n", name, count));
+            }
+            out.push('\n');
+        }
+
         // --- Top opcodes ---
         if !self.top_opcodes.is_empty() {
             out.push_str("[ Top Opcodes ]\n");
@@ -143,6 +162,8 @@ mod tests {
             max_stack_depth: 4,
             max_value_stack_depth: 8,
             function_calls: 5,
+            allocations: 12,
+            top_allocators: vec![("build".to_string(), 10), ("<main>".to_string(), 2)],
             top_opcodes: vec![
                 ("Add".to_string(), 400, 40.0),
                 ("GetLocal".to_string(), 300, 30.0),
@@ -187,6 +208,8 @@ mod tests {
             max_stack_depth: 0,
             max_value_stack_depth: 0,
             function_calls: 0,
+            allocations: 0,
+            top_allocators: vec![],
             top_opcodes: vec![],
             hotspots: vec![],
         };
@@ -228,6 +251,8 @@ mod tests {
             max_stack_depth: 0,
             max_value_stack_depth: 0,
             function_calls: 0,
+            allocations: 0,
+            top_allocators: vec![],
             top_opcodes: vec![],
             hotspots: vec![],
         };
@@ -244,6 +269,8 @@ mod tests {
             max_stack_depth: 0,
             max_value_stack_depth: 0,
             function_calls: 0,
+            allocations: 0,
+            top_allocators: vec![],
             top_opcodes: vec![],
             hotspots: vec![],
         };
@@ -273,4 +300,19 @@ mod tests {
         let s = r.format_detailed();
         assert!(s.contains("5"));
     }
+
+    #[test]
+    fn test_format_summary_allocations_line() {
+        let r = sample_report();
+        let s = r.format_summary();
+        assert!(s.lines().any(|l| l == "allocations: 12"), "got: {}", s);
+    }
+
+    #[test]
+    fn test_format_detailed_allocations_by_function() {
+        let r = sample_report();
+        let s = r.format_detailed();
+        assert!(s.contains("Allocations by Function"), "got: {}", s);
+        assert!(s.contains("build"), "got: {}", s);
+    }
 }
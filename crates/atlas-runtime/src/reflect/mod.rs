@@ -59,6 +59,7 @@ use crate::value::Value;
 pub fn get_value_type_info(value: &Value) -> TypeInfo {
     match value {
         Value::Number(_) => TypeInfo::from_type(&Type::Number),
+        Value::Int(_) => TypeInfo::from_type(&Type::Int),
         Value::String(_) => TypeInfo::from_type(&Type::String),
         Value::Bool(_) => TypeInfo::from_type(&Type::Bool),
         Value::Null => TypeInfo::from_type(&Type::Null),
@@ -124,6 +125,34 @@ pub fn get_value_type_info(value: &Value) -> TypeInfo {
             }
         }
 
+        Value::Map(_) => {
+            // String-keyed map from a map literal
+            TypeInfo {
+                name: "map".to_string(),
+                kind: TypeKind::Generic,
+                fields: vec![],
+                parameters: vec![],
+                return_type: None,
+                element_type: None,
+                type_args: vec![],
+                alias_target: None,
+            }
+        }
+
+        Value::Set(_) => {
+            // Set of primitive values from setOf
+            TypeInfo {
+                name: "set".to_string(),
+                kind: TypeKind::Generic,
+                fields: vec![],
+                parameters: vec![],
+                return_type: None,
+                element_type: None,
+                type_args: vec![],
+                alias_target: None,
+            }
+        }
+
         Value::HashMap(_) => {
             // Generic HashMap type
             TypeInfo {
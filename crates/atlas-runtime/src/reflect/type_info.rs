@@ -12,6 +12,8 @@ use std::fmt;
 pub enum TypeKind {
     /// Primitive number type
     Number,
+    /// Primitive integer type
+    Int,
     /// Primitive string type
     String,
     /// Primitive boolean type
@@ -108,6 +110,17 @@ impl TypeInfo {
                 alias_target: None,
             },
 
+            Type::Int => TypeInfo {
+                name: "int".to_string(),
+                kind: TypeKind::Int,
+                fields: vec![],
+                parameters: vec![],
+                return_type: None,
+                element_type: None,
+                type_args: vec![],
+                alias_target: None,
+            },
+
             Type::String => TypeInfo {
                 name: "string".to_string(),
                 kind: TypeKind::String,
@@ -315,7 +328,7 @@ impl TypeInfo {
     pub fn is_primitive(&self) -> bool {
         matches!(
             self.kind,
-            TypeKind::Number | TypeKind::String | TypeKind::Bool | TypeKind::Null
+            TypeKind::Number | TypeKind::Int | TypeKind::String | TypeKind::Bool | TypeKind::Null
         )
     }
 
@@ -360,6 +373,7 @@ impl TypeInfo {
     pub fn describe(&self) -> String {
         match self.kind {
             TypeKind::Number => "primitive number type".to_string(),
+            TypeKind::Int => "primitive integer type".to_string(),
             TypeKind::String => "primitive string type".to_string(),
             TypeKind::Bool => "primitive boolean type".to_string(),
             TypeKind::Null => "null type".to_string(),
@@ -1,16 +1,18 @@
 //! REPL core logic (UI-agnostic)
 
-use crate::ast::{Item, Stmt};
+use crate::ast::{ExportItem, Item, Stmt};
 use crate::binder::Binder;
 use crate::diagnostic::Diagnostic;
 use crate::interpreter::Interpreter;
 use crate::lexer::Lexer;
 use crate::parser::Parser;
 use crate::security::SecurityContext;
+use crate::span::Span;
 use crate::symbol::{SymbolKind, SymbolTable};
 use crate::typechecker::TypeChecker;
 use crate::types::Type;
 use crate::value::Value;
+use std::path::{Path, PathBuf};
 
 /// A captured variable binding for REPL display
 #[derive(Debug, Clone)]
@@ -59,6 +61,12 @@ pub struct ReplCore {
     symbol_table: SymbolTable,
     /// Security context for permission checks
     security: SecurityContext,
+    /// Path of the most recent `load_file` (for `reload`)
+    loaded_file: Option<PathBuf>,
+    /// Top-level names defined by the loaded file
+    loaded_names: Vec<String>,
+    /// Source of each top-level definition, in declaration order (for `save`)
+    definitions: Vec<(String, String)>,
 }
 
 impl ReplCore {
@@ -73,6 +81,9 @@ impl ReplCore {
             interpreter: Interpreter::new(),
             symbol_table: SymbolTable::new(),
             security,
+            loaded_file: None,
+            loaded_names: Vec::new(),
+            definitions: Vec::new(),
         }
     }
 
@@ -231,15 +242,17 @@ impl ReplCore {
 
         // Phase 5: Evaluate
         match self.interpreter.eval(&ast, &self.security) {
-            Ok(value) => ReplResult {
-                value: Some(value),
-                diagnostics,
-                stdout: String::new(), // TODO: Capture stdout
-                expr_type,
-                bindings: self.collect_bindings(&declared_vars),
-            },
+            Ok(value) => {
+                self.record_definitions(input, &ast);
+                ReplResult {
+                    value: Some(value),
+                    diagnostics,
+                    stdout: String::new(), // TODO: Capture stdout
+                    expr_type,
+                    bindings: self.collect_bindings(&declared_vars),
+                }
+            }
             Err(e) => {
-                use crate::span::Span;
                 diagnostics.push(Diagnostic::error(
                     format!("Runtime error: {:?}", e),
                     Span::dummy(),
@@ -273,12 +286,28 @@ impl ReplCore {
         results
     }
 
+    /// Remember the source of each top-level definition in `input`.
+    ///
+    /// A redefinition replaces the earlier entry and moves to the end, so
+    /// anything it refers to is still declared before it.
+    fn record_definitions(&mut self, input: &str, program: &crate::ast::Program) {
+        for (name, span) in top_level_definitions(program) {
+            let Some(source) = input.get(span.start..span.end) else {
+                continue;
+            };
+            self.definitions.retain(|(existing, _)| *existing != name);
+            self.definitions.push((name, source.to_string()));
+        }
+    }
+
     /// Reset REPL state
     ///
     /// Clears all variables, functions, and type information
     pub fn reset(&mut self) {
         self.interpreter = Interpreter::new();
         self.symbol_table = SymbolTable::new();
+        self.loaded_names.clear();
+        self.definitions.clear();
     }
 }
 
@@ -522,14 +551,105 @@ impl ReplCore {
     /// # Returns
     /// * `Ok(ReplResult)` - Result of evaluating the file
     /// * `Err(String)` - Error message if file cannot be loaded
-    pub fn load_file(&mut self, path: &std::path::Path) -> Result<ReplResult, String> {
+    pub fn load_file(&mut self, path: &Path) -> Result<ReplResult, String> {
         // Read the file
         let content = std::fs::read_to_string(path)
             .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))?;
 
+        self.loaded_file = Some(path.to_path_buf());
+        self.loaded_names = collect_top_level_names(&content);
+
         // Evaluate in REPL context
         Ok(self.eval_line(&content))
     }
+
+    /// Re-run the most recently loaded file.
+    ///
+    /// Definitions that came from the previous version of the file are
+    /// removed first, so renamed or deleted functions do not linger.
+    ///
+    /// # Returns
+    /// * `Ok(ReplResult)` - Result of evaluating the file
+    /// * `Err(String)` - If no file was loaded yet or it cannot be read
+    pub fn reload(&mut self) -> Result<ReplResult, String> {
+        let path = self
+            .loaded_file
+            .clone()
+            .ok_or_else(|| "No file loaded yet. Use :load <file> first.".to_string())?;
+
+        // Make sure the new version is readable before dropping the old one
+        std::fs::metadata(&path)
+            .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))?;
+
+        for name in std::mem::take(&mut self.loaded_names) {
+            self.symbol_table.remove_top_level(&name);
+            self.interpreter.remove_binding(&name);
+            self.definitions.retain(|(existing, _)| *existing != name);
+        }
+
+        self.load_file(&path)
+    }
+
+    /// Path of the most recently loaded file, if any.
+    pub fn loaded_file(&self) -> Option<&Path> {
+        self.loaded_file.as_deref()
+    }
+
+    /// Source for every definition made this session, in declaration order.
+    ///
+    /// Only the latest version of a redefined name is kept. Assignments and
+    /// expression statements are not definitions and are left out.
+    pub fn session_source(&self) -> String {
+        let mut source = String::new();
+        for (_, definition) in &self.definitions {
+            source.push_str(definition);
+            source.push('\n');
+        }
+        source
+    }
+
+    /// Write the session's definitions to `path` as re-loadable Atlas source.
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - Number of definitions written
+    /// * `Err(String)` - Error message if the file cannot be written
+    pub fn save(&self, path: &Path) -> Result<usize, String> {
+        std::fs::write(path, self.session_source())
+            .map_err(|e| format!("Failed to write file '{}': {}", path.display(), e))?;
+        Ok(self.definitions.len())
+    }
+}
+
+/// Collect top-level names (functions, variables, type aliases) a file defines.
+fn collect_top_level_names(source: &str) -> Vec<String> {
+    let (tokens, _) = Lexer::new(source).tokenize();
+    let (program, _) = Parser::new(tokens).parse();
+
+    top_level_definitions(&program)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Name and source span of each top-level function, variable, or type alias.
+fn top_level_definitions(program: &crate::ast::Program) -> Vec<(String, Span)> {
+    let mut definitions = Vec::new();
+    for item in &program.items {
+        let (name, span) = match item {
+            Item::Function(func) => (&func.name.name, func.span),
+            Item::Statement(Stmt::VarDecl(var)) => (&var.name.name, var.span),
+            Item::TypeAlias(alias) => (&alias.name.name, alias.span),
+            Item::Export(export) => match &export.item {
+                ExportItem::Function(func) => (&func.name.name, export.span),
+                ExportItem::Variable(var) => (&var.name.name, export.span),
+                ExportItem::TypeAlias(alias) => (&alias.name.name, export.span),
+                ExportItem::ReExport(_) => continue,
+            },
+            _ => continue,
+        };
+        definitions.push((name.clone(), span));
+    }
+    definitions
 }
 
 /// Collect variable names declared in the parsed program (current REPL input).
@@ -1,20 +1,97 @@
 //! Atlas runtime API for embedding
 
 use crate::binder::Binder;
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, DiagnosticLevel};
 use crate::interpreter::Interpreter;
 use crate::lexer::Lexer;
 use crate::module_executor::ModuleExecutor;
 use crate::parser::Parser;
 use crate::security::SecurityContext;
 use crate::span::Span;
+use crate::symbol::{Symbol, SymbolKind, SymbolTable};
 use crate::typechecker::TypeChecker;
-use crate::value::{RuntimeError, Value};
+use crate::types::Type;
+use crate::value::{RuntimeError, Value, ValueMap};
 use std::cell::RefCell;
+use std::path::PathBuf;
 
 /// Result type for runtime operations
 pub type RuntimeResult<T> = Result<T, Vec<Diagnostic>>;
 
+/// Rust implementation of a host function callable from Atlas
+pub type HostFn = Box<dyn Fn(&[Value]) -> Result<Value, RuntimeError> + Send + Sync>;
+
+/// Signature of a host function, as seen by the typechecker
+///
+/// # Examples
+///
+/// ```
+/// use atlas_runtime::runtime::HostFnDescriptor;
+/// use atlas_runtime::Type;
+///
+/// let descriptor = HostFnDescriptor::new("hostAdd", 2)
+///     .with_signature(vec![Type::Number, Type::Number], Type::Number);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostFnDescriptor {
+    /// Name the function is called by from Atlas source
+    pub name: String,
+    /// Parameter types; the length is the function's arity
+    pub params: Vec<Type>,
+    /// Return type
+    pub return_type: Type,
+}
+
+impl HostFnDescriptor {
+    /// Describe a function taking `arity` arguments of any type and returning any type
+    pub fn new(name: impl Into<String>, arity: usize) -> Self {
+        Self {
+            name: name.into(),
+            params: vec![Type::Unknown; arity],
+            return_type: Type::Unknown,
+        }
+    }
+
+    /// Replace the parameter and return types with concrete ones
+    pub fn with_signature(mut self, params: Vec<Type>, return_type: Type) -> Self {
+        self.params = params;
+        self.return_type = return_type;
+        self
+    }
+
+    fn function_type(&self) -> Type {
+        Type::Function {
+            type_params: vec![],
+            params: self.params.clone(),
+            return_type: Box::new(self.return_type.clone()),
+        }
+    }
+}
+
+/// Errors that can occur when registering a host function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostFnError {
+    /// The name is already used by a builtin function
+    BuiltinCollision(String),
+    /// A host function with this name was already registered
+    AlreadyRegistered(String),
+}
+
+impl std::fmt::Display for HostFnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HostFnError::BuiltinCollision(name) => {
+                write!(f, "Host function '{}' collides with a builtin", name)
+            }
+            HostFnError::AlreadyRegistered(name) => {
+                write!(f, "Host function '{}' is already registered", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HostFnError {}
+
 /// Atlas runtime instance
 ///
 /// Provides a high-level API for embedding Atlas in host applications.
@@ -32,6 +109,10 @@ pub struct Atlas {
     interpreter: RefCell<Interpreter>,
     /// Security context for permission checks
     security: SecurityContext,
+    /// Signatures of registered host functions, declared to the typechecker on each eval
+    host_fns: RefCell<Vec<HostFnDescriptor>>,
+    /// Prelude module implicitly imported by files run with `eval_file`
+    prelude: Option<PathBuf>,
 }
 
 impl Atlas {
@@ -48,6 +129,8 @@ impl Atlas {
         Self {
             interpreter: RefCell::new(Interpreter::new()),
             security: SecurityContext::new(),
+            host_fns: RefCell::new(Vec::new()),
+            prelude: None,
         }
     }
 
@@ -65,9 +148,29 @@ impl Atlas {
         Self {
             interpreter: RefCell::new(Interpreter::new()),
             security,
+            host_fns: RefCell::new(Vec::new()),
+            prelude: None,
         }
     }
 
+    /// Set the prelude module for [`eval_file`](Self::eval_file)
+    ///
+    /// The prelude's exports are visible in every module without an import;
+    /// a module's own declarations shadow them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use atlas_runtime::Atlas;
+    ///
+    /// let runtime = Atlas::new().with_prelude("src/prelude.atl");
+    /// let result = runtime.eval_file("src/main.atl");
+    /// ```
+    pub fn with_prelude(mut self, path: impl Into<PathBuf>) -> Self {
+        self.prelude = Some(path.into());
+        self
+    }
+
     /// Evaluate Atlas source code
     ///
     /// Returns the result of evaluating the source code, or diagnostics if there are errors.
@@ -116,8 +219,8 @@ impl Atlas {
             return Err(parse_diagnostics);
         }
 
-        // Bind symbols
-        let mut binder = Binder::new();
+        // Bind symbols, starting from the registered host functions
+        let mut binder = Binder::with_symbol_table(self.host_symbol_table());
         let (mut symbol_table, bind_diagnostics) = binder.bind(&ast);
 
         if !bind_diagnostics.is_empty() {
@@ -128,7 +231,11 @@ impl Atlas {
         let mut type_checker = TypeChecker::new(&mut symbol_table);
         let type_diagnostics = type_checker.check(&ast);
 
-        if !type_diagnostics.is_empty() {
+        // Warnings (unused variables, unreachable code) do not stop evaluation
+        if type_diagnostics
+            .iter()
+            .any(|d| d.level == DiagnosticLevel::Error)
+        {
             return Err(type_diagnostics);
         }
 
@@ -141,6 +248,86 @@ impl Atlas {
         }
     }
 
+    /// Register a Rust function callable from Atlas source as a builtin
+    ///
+    /// The typechecker sees the function as taking `arity` arguments of any type.
+    /// Use [`Atlas::register_typed_host_fn`] to declare concrete types.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `name` is a builtin or an already registered host function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use atlas_runtime::{Atlas, Value};
+    ///
+    /// let runtime = Atlas::new();
+    /// runtime
+    ///     .register_host_fn("answer", 0, Box::new(|_| Ok(Value::Number(42.0))))
+    ///     .unwrap();
+    /// assert_eq!(runtime.eval("answer()").unwrap(), Value::Number(42.0));
+    /// ```
+    pub fn register_host_fn(
+        &self,
+        name: &str,
+        arity: usize,
+        implementation: HostFn,
+    ) -> Result<(), HostFnError> {
+        self.register_typed_host_fn(HostFnDescriptor::new(name, arity), implementation)
+    }
+
+    /// Register a Rust function with the signature given by `descriptor`
+    ///
+    /// Calls are typechecked against the descriptor before evaluation, and the
+    /// argument count is checked again at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the name is a builtin or an already registered host function.
+    pub fn register_typed_host_fn(
+        &self,
+        descriptor: HostFnDescriptor,
+        implementation: HostFn,
+    ) -> Result<(), HostFnError> {
+        let name = descriptor.name.clone();
+        if crate::stdlib::is_builtin(&name) || crate::stdlib::is_array_intrinsic(&name) {
+            return Err(HostFnError::BuiltinCollision(name));
+        }
+        if self.host_fns.borrow().iter().any(|d| d.name == name) {
+            return Err(HostFnError::AlreadyRegistered(name));
+        }
+
+        let native_fn = crate::api::native::NativeFunctionBuilder::new(name.as_str())
+            .with_arity(descriptor.params.len())
+            .with_implementation(implementation)
+            .build()
+            .expect("implementation is always provided");
+
+        self.interpreter
+            .borrow_mut()
+            .globals
+            .insert(name, (native_fn, false));
+        self.host_fns.borrow_mut().push(descriptor);
+        Ok(())
+    }
+
+    /// Symbol table declaring every registered host function
+    fn host_symbol_table(&self) -> SymbolTable {
+        let mut table = SymbolTable::new();
+        for descriptor in self.host_fns.borrow().iter() {
+            let _ = table.define_function(Symbol {
+                name: descriptor.name.clone(),
+                ty: descriptor.function_type(),
+                mutable: false,
+                kind: SymbolKind::Function,
+                span: Span::dummy(),
+                exported: false,
+            });
+        }
+        table
+    }
+
     /// Evaluate an Atlas source file
     ///
     /// Reads and evaluates the Atlas source code from the specified file path.
@@ -193,8 +380,9 @@ impl Atlas {
             )]
         })?;
 
-        // Check if source contains "import {" or "import *"
-        if source.contains("import {") || source.contains("import *") {
+        // Check if source contains "import {" or "import *"; a prelude is an
+        // implicit import, so it always needs the module executor
+        if self.prelude.is_some() || source.contains("import {") || source.contains("import *") {
             // Use module executor for multi-file programs
             let root = abs_path
                 .parent()
@@ -203,6 +391,9 @@ impl Atlas {
 
             let mut interpreter = self.interpreter.borrow_mut();
             let mut executor = ModuleExecutor::new(&mut interpreter, &self.security, root);
+            if let Some(prelude) = &self.prelude {
+                executor = executor.with_prelude(prelude.clone());
+            }
             executor.execute_module(&abs_path)
         } else {
             // Simple single-file program - use regular eval
@@ -219,17 +410,81 @@ impl Default for Atlas {
 
 /// Convert a RuntimeError to a Diagnostic
 fn runtime_error_to_diagnostic(error: RuntimeError) -> Diagnostic {
-    // Map runtime errors to their corresponding diagnostic codes from Atlas-SPEC.md
     // Extract span from error (all RuntimeError variants now include span)
     let span = error.span();
+    let (code, message) = runtime_error_code_and_message(&error);
+
+    let help = match error {
+        RuntimeError::DivideByZero { .. } => "division by zero is undefined",
+        RuntimeError::OutOfBounds { .. } => "check array bounds before accessing",
+        RuntimeError::InvalidNumericResult { .. } => "ensure the number is finite and not NaN",
+        RuntimeError::IntegerOverflow { .. } => {
+            "the result does not fit in a 64-bit int; use toFloat() for approximate arithmetic"
+        }
+        RuntimeError::CallDepthExceeded { .. } => {
+            "check for unbounded recursion, or raise max_call_depth in the VmConfig"
+        }
+        RuntimeError::StackOverflow { .. } => {
+            "simplify deeply nested expressions, or raise max_stack_size in the VmConfig"
+        }
+        RuntimeError::GasExhausted { .. } => {
+            "raise the gas limit passed to run_metered or reduce the work the script does"
+        }
+        RuntimeError::FilesystemPermissionDenied { .. } => {
+            "enable file permissions with --allow-file or adjust security settings"
+        }
+        RuntimeError::NetworkPermissionDenied { .. } => {
+            "enable network permissions with --allow-network or adjust security settings"
+        }
+        RuntimeError::ProcessPermissionDenied { .. } => {
+            "enable process permissions with --allow-process or adjust security settings"
+        }
+        RuntimeError::EnvironmentPermissionDenied { .. } => {
+            "enable environment permissions with --allow-env or adjust security settings"
+        }
+        RuntimeError::ClockPermissionDenied { .. } => {
+            "grant clock access with SecurityContext::grant_clock or adjust security settings"
+        }
+        RuntimeError::ResourceExhausted { .. } => {
+            "raise the limit in the SecurityContext or reduce the work the script does"
+        }
+        RuntimeError::MapKeyNotFound { .. } => {
+            "check with mapHas() or use mapGet() to handle missing keys"
+        }
+        RuntimeError::InvalidRegex { .. } => {
+            "check the pattern syntax; use regexEscape() to match text literally"
+        }
+        _ => "check the error message for details",
+    };
+
+    Diagnostic::error_with_code(code, message, span).with_help(help)
+}
 
-    let (code, message) = match &error {
+/// Map a runtime error to its diagnostic code (from Atlas-SPEC.md) and message
+pub(crate) fn runtime_error_code_and_message(error: &RuntimeError) -> (&'static str, String) {
+    match error {
         RuntimeError::DivideByZero { .. } => ("AT0005", "Divide by zero".to_string()),
         RuntimeError::OutOfBounds { .. } => ("AT0006", "Array index out of bounds".to_string()),
         RuntimeError::InvalidNumericResult { .. } => (
             "AT0007",
             "Invalid numeric result (NaN or Infinity)".to_string(),
         ),
+        RuntimeError::IntegerOverflow { .. } => ("AT0009", "Integer overflow".to_string()),
+        RuntimeError::CallDepthExceeded { limit, .. } => (
+            "AT0010",
+            format!(
+                "Call depth limit exceeded: more than {} nested calls",
+                limit
+            ),
+        ),
+        RuntimeError::StackOverflow { limit, .. } => (
+            "AT0011",
+            format!("Operand stack overflow: more than {} values", limit),
+        ),
+        RuntimeError::GasExhausted { limit, .. } => (
+            "AT0012",
+            format!("Gas exhausted: budget of {} spent", limit),
+        ),
         RuntimeError::InvalidIndex { .. } => (
             "AT0103",
             "Invalid index: array indices must be whole numbers".to_string(),
@@ -267,6 +522,21 @@ fn runtime_error_to_diagnostic(error: RuntimeError) -> Diagnostic {
             "AT0303",
             format!("Permission denied: environment variable {}", var),
         ),
+        RuntimeError::ClockPermissionDenied { .. } => {
+            ("AT0305", "Permission denied: clock access".to_string())
+        }
+        RuntimeError::ResourceExhausted {
+            resource,
+            used,
+            limit,
+            ..
+        } => (
+            "AT0304",
+            format!(
+                "Resource limit exceeded: {} ({} > {})",
+                resource, used, limit
+            ),
+        ),
         RuntimeError::IoError { message, .. } => ("AT0400", message.clone()),
         RuntimeError::UnhashableType { type_name, .. } => (
             "AT0140",
@@ -275,34 +545,33 @@ fn runtime_error_to_diagnostic(error: RuntimeError) -> Diagnostic {
                 type_name
             ),
         ),
-    };
-
-    let help = match error {
-        RuntimeError::DivideByZero { .. } => "division by zero is undefined",
-        RuntimeError::OutOfBounds { .. } => "check array bounds before accessing",
-        RuntimeError::InvalidNumericResult { .. } => "ensure the number is finite and not NaN",
-        RuntimeError::FilesystemPermissionDenied { .. } => {
-            "enable file permissions with --allow-file or adjust security settings"
-        }
-        RuntimeError::NetworkPermissionDenied { .. } => {
-            "enable network permissions with --allow-network or adjust security settings"
+        RuntimeError::MapKeyNotFound { key, .. } => {
+            ("AT0008", format!("Map key not found: '{}'", key))
         }
-        RuntimeError::ProcessPermissionDenied { .. } => {
-            "enable process permissions with --allow-process or adjust security settings"
-        }
-        RuntimeError::EnvironmentPermissionDenied { .. } => {
-            "enable environment permissions with --allow-env or adjust security settings"
-        }
-        _ => "check the error message for details",
-    };
+        RuntimeError::InvalidRegex {
+            pattern, message, ..
+        } => (
+            "AT0150",
+            format!("Invalid regex pattern '{}': {}", pattern, message),
+        ),
+    }
+}
 
-    Diagnostic::error_with_code(code, message, span).with_help(help)
+/// Build the structured error value bound by a `catch` clause
+///
+/// The value is a map with `code` and `message` string entries, matching the
+/// diagnostic the error would produce if it went uncaught.
+pub(crate) fn runtime_error_value(error: &RuntimeError) -> Value {
+    let (code, message) = runtime_error_code_and_message(error);
+    let mut map = std::collections::HashMap::new();
+    map.insert("code".to_string(), Value::string(code));
+    map.insert("message".to_string(), Value::string(message));
+    Value::Map(ValueMap::from(map))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::diagnostic::DiagnosticLevel;
 
     // Basic API Tests
 
@@ -389,6 +658,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_eval_ignores_warnings() {
+        let runtime = Atlas::new();
+        let result = runtime.eval(
+            "var total = 0; var i = 0; while (i < 4) { i = i + 1; if (i == 1) { continue; total = 100; } total = total + i; } total;",
+        );
+        assert_eq!(result.unwrap(), Value::Number(9.0));
+    }
+
     #[test]
     fn test_eval_multiple_statements() {
         let runtime = Atlas::new();
@@ -21,11 +21,13 @@ pub enum AuditEvent {
     /// Filesystem write permission denied
     FilesystemWriteDenied { path: PathBuf },
     /// Network access denied
-    NetworkDenied { host: String },
+    NetworkDenied { host: String, port: u16 },
     /// Process execution denied
     ProcessDenied { command: String },
     /// Environment variable access denied
     EnvironmentDenied { var: String },
+    /// System clock access denied
+    ClockDenied,
     /// Sandbox created
     SandboxCreated {
         sandbox_id: String,
@@ -82,8 +84,8 @@ impl fmt::Display for AuditEvent {
                     path.display()
                 )
             }
-            AuditEvent::NetworkDenied { host } => {
-                write!(f, "Permission denied: network access to {}", host)
+            AuditEvent::NetworkDenied { host, port } => {
+                write!(f, "Permission denied: network access to {}:{}", host, port)
             }
             AuditEvent::ProcessDenied { command } => {
                 write!(f, "Permission denied: process execution of {}", command)
@@ -91,6 +93,7 @@ impl fmt::Display for AuditEvent {
             AuditEvent::EnvironmentDenied { var } => {
                 write!(f, "Permission denied: environment variable {}", var)
             }
+            AuditEvent::ClockDenied => write!(f, "Permission denied: clock access"),
             AuditEvent::SandboxCreated {
                 sandbox_id,
                 memory_limit,
@@ -270,6 +273,7 @@ mod tests {
     fn test_audit_entry_creation() {
         let event = AuditEvent::NetworkDenied {
             host: "evil.com".to_string(),
+            port: 443,
         };
         let entry = AuditEntry::new(event.clone());
 
@@ -298,6 +302,7 @@ mod tests {
         });
         logger.log(AuditEvent::NetworkDenied {
             host: "api.example.com".to_string(),
+            port: 443,
         });
 
         let entries = logger.entries();
@@ -357,6 +362,7 @@ mod tests {
     fn test_timestamp_is_monotonic() {
         let entry1 = AuditEntry::new(AuditEvent::NetworkDenied {
             host: "test1.com".to_string(),
+            port: 443,
         });
 
         // Small delay to ensure different timestamp
@@ -364,6 +370,7 @@ mod tests {
 
         let entry2 = AuditEntry::new(AuditEvent::NetworkDenied {
             host: "test2.com".to_string(),
+            port: 443,
         });
 
         assert!(entry2.timestamp >= entry1.timestamp);
@@ -0,0 +1,223 @@
+//! Resource limits for untrusted execution
+//!
+//! A `SecurityContext` may carry `ResourceLimits` that cap the size of a single
+//! allocation, the number of executed instructions, and wall-clock time. The VM
+//! charges one unit per bytecode instruction; the interpreter charges one per
+//! statement and expression evaluated. String and array builders check each
+//! allocation against the allocation cap before allocating. The allocation cap
+//! bounds one string or array at a time; it is not a cumulative memory quota.
+
+use crate::security::permissions::{SecurityContext, SecurityError};
+use crate::span::Span;
+use crate::value::RuntimeError;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// How often (in charged instructions) the wall clock is consulted
+const CLOCK_CHECK_INTERVAL: u64 = 1024;
+
+/// Resource caps enforced during execution (`None` means unlimited)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Largest single string or array allocation, in bytes
+    pub max_allocation_bytes: Option<usize>,
+    /// Maximum number of instructions executed
+    pub max_instructions: Option<u64>,
+    /// Maximum wall-clock time for a run
+    pub max_wall_time: Option<Duration>,
+}
+
+impl ResourceLimits {
+    /// No limits
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Check if any limit is set
+    pub fn is_limited(&self) -> bool {
+        self.max_allocation_bytes.is_some()
+            || self.max_instructions.is_some()
+            || self.max_wall_time.is_some()
+    }
+}
+
+/// The kind of resource whose limit was exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceLimit {
+    /// Single allocation size (bytes)
+    Allocation,
+    /// Instruction budget (instructions)
+    Instructions,
+    /// Wall-clock time (milliseconds)
+    WallTime,
+}
+
+impl fmt::Display for ResourceLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceLimit::Allocation => write!(f, "max_allocation_bytes"),
+            ResourceLimit::Instructions => write!(f, "max_instructions"),
+            ResourceLimit::WallTime => write!(f, "max_wall_time_ms"),
+        }
+    }
+}
+
+/// Per-run instruction and time accounting
+#[derive(Debug, Clone)]
+pub(crate) struct ExecutionBudget {
+    executed: u64,
+    max_instructions: Option<u64>,
+    max_wall_time: Option<Duration>,
+    started: Instant,
+}
+
+impl ExecutionBudget {
+    /// Start a budget for a run under `limits`
+    pub(crate) fn start(limits: &ResourceLimits) -> Self {
+        Self {
+            executed: 0,
+            max_instructions: limits.max_instructions,
+            max_wall_time: limits.max_wall_time,
+            started: Instant::now(),
+        }
+    }
+
+    /// A budget that never runs out
+    pub(crate) fn unlimited() -> Self {
+        Self::start(&ResourceLimits::unlimited())
+    }
+
+    /// Check if charging can fail (fast path for unlimited runs)
+    #[inline]
+    pub(crate) fn is_active(&self) -> bool {
+        self.max_instructions.is_some() || self.max_wall_time.is_some()
+    }
+
+    /// Charge one instruction, failing once the instruction or time budget is spent
+    pub(crate) fn charge(&mut self, security: &SecurityContext) -> Result<(), SecurityError> {
+        self.executed += 1;
+
+        if let Some(limit) = self.max_instructions {
+            if self.executed > limit {
+                return Err(security.resource_exhausted(
+                    ResourceLimit::Instructions,
+                    self.executed,
+                    limit,
+                ));
+            }
+        }
+
+        if let Some(limit) = self.max_wall_time {
+            if self.executed.is_multiple_of(CLOCK_CHECK_INTERVAL) {
+                let elapsed = self.started.elapsed();
+                if elapsed > limit {
+                    return Err(security.resource_exhausted(
+                        ResourceLimit::WallTime,
+                        elapsed.as_millis() as u64,
+                        limit.as_millis() as u64,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Attach a source span to a resource-limit error
+pub(crate) fn runtime_error(error: SecurityError, span: Span) -> RuntimeError {
+    match error {
+        SecurityError::ResourceExhausted {
+            resource,
+            used,
+            limit,
+        } => RuntimeError::ResourceExhausted {
+            resource,
+            used,
+            limit,
+            span,
+        },
+        other => RuntimeError::TypeError {
+            msg: other.to_string(),
+            span,
+        },
+    }
+}
+
+/// Check an allocation of `bytes` against the allocation cap
+pub(crate) fn check_allocation(
+    security: &SecurityContext,
+    bytes: usize,
+    span: Span,
+) -> Result<(), RuntimeError> {
+    security
+        .check_allocation(bytes)
+        .map_err(|e| runtime_error(e, span))
+}
+
+/// Check an array of `len` values against the allocation cap
+pub(crate) fn check_array_allocation(
+    security: &SecurityContext,
+    len: usize,
+    span: Span,
+) -> Result<(), RuntimeError> {
+    check_allocation(
+        security,
+        len.saturating_mul(std::mem::size_of::<crate::value::Value>()),
+        span,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited_budget_is_inactive() {
+        let budget = ExecutionBudget::unlimited();
+        assert!(!budget.is_active());
+        assert!(!ResourceLimits::unlimited().is_limited());
+    }
+
+    #[test]
+    fn test_instruction_budget_exhausted() {
+        let ctx = SecurityContext::new();
+        let limits = ResourceLimits {
+            max_instructions: Some(3),
+            ..ResourceLimits::default()
+        };
+        let mut budget = ExecutionBudget::start(&limits);
+
+        for _ in 0..3 {
+            assert!(budget.charge(&ctx).is_ok());
+        }
+        assert_eq!(
+            budget.charge(&ctx),
+            Err(SecurityError::ResourceExhausted {
+                resource: ResourceLimit::Instructions,
+                used: 4,
+                limit: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_wall_time_budget_exhausted() {
+        let ctx = SecurityContext::new();
+        let limits = ResourceLimits {
+            max_wall_time: Some(Duration::ZERO),
+            ..ResourceLimits::default()
+        };
+        let mut budget = ExecutionBudget::start(&limits);
+        std::thread::sleep(Duration::from_millis(1));
+
+        let result = (0..CLOCK_CHECK_INTERVAL).try_for_each(|_| budget.charge(&ctx));
+        assert!(matches!(
+            result,
+            Err(SecurityError::ResourceExhausted {
+                resource: ResourceLimit::WallTime,
+                ..
+            })
+        ));
+    }
+}
@@ -3,9 +3,14 @@
 //! Defines the permission system for controlling I/O operations.
 
 use crate::security::audit::{AuditEvent, AuditLogger, NullAuditLogger};
+use crate::security::limits::{ResourceLimit, ResourceLimits};
 use std::collections::HashSet;
+use std::fmt;
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Security errors
@@ -17,8 +22,8 @@ pub enum SecurityError {
     #[error("Permission denied: filesystem write access to {path}")]
     FilesystemWriteDenied { path: PathBuf },
 
-    #[error("Permission denied: network access to {host}")]
-    NetworkDenied { host: String },
+    #[error("Permission denied: network access to {host}:{port}")]
+    NetworkDenied { host: String, port: u16 },
 
     #[error("Permission denied: process execution of {command}")]
     ProcessDenied { command: String },
@@ -26,9 +31,19 @@ pub enum SecurityError {
     #[error("Permission denied: environment variable {var}")]
     EnvironmentDenied { var: String },
 
+    #[error("Permission denied: clock access")]
+    ClockDenied,
+
     #[error("Invalid path: {0}")]
     InvalidPath(String),
 
+    #[error("Resource limit exceeded: {resource} ({used} > {limit})")]
+    ResourceExhausted {
+        resource: ResourceLimit,
+        used: u64,
+        limit: u64,
+    },
+
     #[error("Invalid permission pattern: {0}")]
     InvalidPattern(String),
 }
@@ -42,14 +57,17 @@ pub enum Permission {
     /// Filesystem write access
     FilesystemWrite { path: PathBuf, recursive: bool },
 
-    /// Network access
-    Network { host: String },
+    /// Network access to a host (exact, `*.domain`, or `*`) on a range of ports
+    Network { host: String, port: PortRange },
 
     /// Process execution
     Process { command: String },
 
     /// Environment variable access
     Environment { var: String },
+
+    /// Reading the system clock
+    Clock,
 }
 
 impl Permission {
@@ -104,29 +122,17 @@ impl Permission {
                 false
             }
 
-            // Network: check host (exact match or wildcard)
-            (Permission::Network { host: allowed }, Permission::Network { host: requested }) => {
-                // Exact match
-                if allowed == requested {
-                    return true;
-                }
-
-                // Wildcard: *.example.com matches api.example.com
-                if let Some(domain) = allowed.strip_prefix("*.") {
-                    if requested.ends_with(domain) {
-                        // Check it's a proper subdomain match
-                        if requested == domain {
-                            return true;
-                        }
-                        if let Some(prefix) = requested.strip_suffix(domain) {
-                            return prefix.ends_with('.');
-                        }
-                    }
-                }
-
-                // Wildcard: allow all
-                allowed == "*"
-            }
+            // Network: check host (exact match or wildcard) and port range
+            (
+                Permission::Network {
+                    host: allowed,
+                    port: allowed_port,
+                },
+                Permission::Network {
+                    host: requested,
+                    port: requested_port,
+                },
+            ) => allowed_port.covers(requested_port) && host_matches(allowed, requested),
 
             // Process: exact command name match
             (
@@ -144,12 +150,119 @@ impl Permission {
                 Permission::Environment { var: requested_var },
             ) => allowed_var == requested_var || allowed_var == "*",
 
+            // Clock: all-or-nothing
+            (Permission::Clock, Permission::Clock) => true,
+
             // Different permission types never match
             _ => false,
         }
     }
 }
 
+/// Check a requested host against an allowed host pattern
+fn host_matches(allowed: &str, requested: &str) -> bool {
+    // Exact match
+    if allowed == requested {
+        return true;
+    }
+
+    // Wildcard: *.example.com matches api.example.com
+    if let Some(domain) = allowed.strip_prefix("*.") {
+        if requested.ends_with(domain) {
+            // Check it's a proper subdomain match
+            if requested == domain {
+                return true;
+            }
+            if let Some(prefix) = requested.strip_suffix(domain) {
+                return prefix.ends_with('.');
+            }
+        }
+    }
+
+    // Wildcard: allow all
+    allowed == "*"
+}
+
+/// Inclusive range of network ports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl PortRange {
+    /// Every port
+    pub const ANY: PortRange = PortRange {
+        start: 0,
+        end: u16::MAX,
+    };
+
+    /// Create a range; the bounds may be given in either order
+    pub fn new(start: u16, end: u16) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// A range holding a single port
+    pub fn single(port: u16) -> Self {
+        Self::new(port, port)
+    }
+
+    /// Check if a port is in this range
+    pub fn contains(&self, port: u16) -> bool {
+        self.start <= port && port <= self.end
+    }
+
+    /// Check if every port in `other` is in this range
+    pub fn covers(&self, other: &PortRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+}
+
+impl From<u16> for PortRange {
+    fn from(port: u16) -> Self {
+        Self::single(port)
+    }
+}
+
+impl From<RangeInclusive<u16>> for PortRange {
+    fn from(range: RangeInclusive<u16>) -> Self {
+        Self::new(*range.start(), *range.end())
+    }
+}
+
+impl FromStr for PortRange {
+    type Err = SecurityError;
+
+    /// Parse `*`, `443`, or `8000-8999`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SecurityError::InvalidPattern(format!("invalid port range '{}'", s));
+        let parse_port = |p: &str| p.trim().parse::<u16>().map_err(|_| invalid());
+
+        match s.trim() {
+            "*" => Ok(Self::ANY),
+            range => match range.split_once('-') {
+                Some((start, end)) => Ok(Self::new(parse_port(start)?, parse_port(end)?)),
+                None => Ok(Self::single(parse_port(range)?)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for PortRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::ANY {
+            write!(f, "*")
+        } else if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
 /// Set of permissions
 #[derive(Debug, Clone, Default)]
 pub struct PermissionSet {
@@ -205,6 +318,8 @@ pub struct SecurityContext {
     network: PermissionSet,
     process: PermissionSet,
     environment: PermissionSet,
+    clock: PermissionSet,
+    limits: ResourceLimits,
     audit_logger: Arc<dyn AuditLogger>,
 }
 
@@ -216,6 +331,8 @@ impl Default for SecurityContext {
             network: PermissionSet::new(),
             process: PermissionSet::new(),
             environment: PermissionSet::new(),
+            clock: PermissionSet::new(),
+            limits: ResourceLimits::unlimited(),
             audit_logger: Arc::new(NullAuditLogger::new()),
         }
     }
@@ -235,10 +352,39 @@ impl SecurityContext {
             network: PermissionSet::new(),
             process: PermissionSet::new(),
             environment: PermissionSet::new(),
+            clock: PermissionSet::new(),
+            limits: ResourceLimits::unlimited(),
             audit_logger: logger,
         }
     }
 
+    /// Create a new security context with resource limits (`None` means unlimited)
+    ///
+    /// Exceeding a limit aborts execution with `SecurityError::ResourceExhausted`.
+    pub fn with_limits(
+        max_allocation_bytes: Option<usize>,
+        max_instructions: Option<u64>,
+        max_wall_time: Option<Duration>,
+    ) -> Self {
+        let mut ctx = Self::new();
+        ctx.set_limits(ResourceLimits {
+            max_allocation_bytes,
+            max_instructions,
+            max_wall_time,
+        });
+        ctx
+    }
+
+    /// Replace the resource limits
+    pub fn set_limits(&mut self, limits: ResourceLimits) {
+        self.limits = limits;
+    }
+
+    /// Get the resource limits
+    pub fn limits(&self) -> &ResourceLimits {
+        &self.limits
+    }
+
     /// Create from security configuration
     ///
     /// Note: This requires atlas-config to be available. For now, this is a placeholder
@@ -291,6 +437,7 @@ impl SecurityContext {
         }
         ctx.network.grant(Permission::Network {
             host: "*".to_string(),
+            port: PortRange::ANY,
         });
         ctx.process.grant(Permission::Process {
             command: "*".to_string(),
@@ -298,6 +445,7 @@ impl SecurityContext {
         ctx.environment.grant(Permission::Environment {
             var: "*".to_string(),
         });
+        ctx.clock.grant(Permission::Clock);
 
         ctx
     }
@@ -319,9 +467,14 @@ impl SecurityContext {
     }
 
     /// Grant network permission
-    pub fn grant_network(&mut self, host: impl Into<String>) {
-        self.network
-            .grant(Permission::Network { host: host.into() });
+    ///
+    /// `host` may be exact, a `*.domain` wildcard, or `*`. Use `PortRange::ANY`
+    /// to allow every port.
+    pub fn grant_network(&mut self, host: impl Into<String>, ports: impl Into<PortRange>) {
+        self.network.grant(Permission::Network {
+            host: host.into(),
+            port: ports.into(),
+        });
     }
 
     /// Grant process execution permission
@@ -337,6 +490,11 @@ impl SecurityContext {
             .grant(Permission::Environment { var: var.into() });
     }
 
+    /// Grant permission to read the system clock
+    pub fn grant_clock(&mut self) {
+        self.clock.grant(Permission::Clock);
+    }
+
     // Permission checking methods
 
     /// Check filesystem read permission
@@ -383,25 +541,28 @@ impl SecurityContext {
         }
     }
 
-    /// Check network permission
-    pub fn check_network(&self, host: &str) -> Result<(), SecurityError> {
+    /// Check network permission for a connection to `host:port`
+    pub fn check_network(&self, host: &str, port: u16) -> Result<(), SecurityError> {
         let requested = Permission::Network {
             host: host.to_string(),
+            port: PortRange::single(port),
         };
 
         if self.network.is_granted(&requested) {
             self.audit_logger.log(AuditEvent::PermissionCheck {
                 operation: "network".to_string(),
-                target: host.to_string(),
+                target: format!("{}:{}", host, port),
                 granted: true,
             });
             Ok(())
         } else {
             self.audit_logger.log(AuditEvent::NetworkDenied {
                 host: host.to_string(),
+                port,
             });
             Err(SecurityError::NetworkDenied {
                 host: host.to_string(),
+                port,
             })
         }
     }
@@ -452,6 +613,50 @@ impl SecurityContext {
         }
     }
 
+    /// Check system clock access permission
+    pub fn check_clock(&self) -> Result<(), SecurityError> {
+        if self.clock.is_granted(&Permission::Clock) {
+            self.audit_logger.log(AuditEvent::PermissionCheck {
+                operation: "clock".to_string(),
+                target: "system clock".to_string(),
+                granted: true,
+            });
+            Ok(())
+        } else {
+            self.audit_logger.log(AuditEvent::ClockDenied);
+            Err(SecurityError::ClockDenied)
+        }
+    }
+
+    /// Check a single allocation of `bytes` against the allocation limit
+    pub fn check_allocation(&self, bytes: usize) -> Result<(), SecurityError> {
+        match self.limits.max_allocation_bytes {
+            Some(limit) if bytes > limit => {
+                Err(self.resource_exhausted(ResourceLimit::Allocation, bytes as u64, limit as u64))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Record an exceeded resource limit and build the matching error
+    pub(crate) fn resource_exhausted(
+        &self,
+        resource: ResourceLimit,
+        used: u64,
+        limit: u64,
+    ) -> SecurityError {
+        self.audit_logger.log(AuditEvent::QuotaViolation {
+            resource: resource.to_string(),
+            limit,
+            attempted: used,
+        });
+        SecurityError::ResourceExhausted {
+            resource,
+            used,
+            limit,
+        }
+    }
+
     /// Get the audit logger (for testing)
     pub fn audit_logger(&self) -> Arc<dyn AuditLogger> {
         Arc::clone(&self.audit_logger)
@@ -529,9 +734,11 @@ mod tests {
     fn test_permission_network_exact() {
         let allowed = Permission::Network {
             host: "api.example.com".to_string(),
+            port: PortRange::ANY,
         };
         let requested = Permission::Network {
             host: "api.example.com".to_string(),
+            port: PortRange::ANY,
         };
 
         assert!(allowed.allows(&requested));
@@ -541,9 +748,11 @@ mod tests {
     fn test_permission_network_wildcard() {
         let allowed = Permission::Network {
             host: "*.example.com".to_string(),
+            port: PortRange::ANY,
         };
         let requested = Permission::Network {
             host: "api.example.com".to_string(),
+            port: PortRange::ANY,
         };
 
         assert!(allowed.allows(&requested));
@@ -553,9 +762,11 @@ mod tests {
     fn test_permission_network_wildcard_no_match() {
         let allowed = Permission::Network {
             host: "*.example.com".to_string(),
+            port: PortRange::ANY,
         };
         let requested = Permission::Network {
             host: "other.com".to_string(),
+            port: PortRange::ANY,
         };
 
         assert!(!allowed.allows(&requested));
@@ -595,7 +806,7 @@ mod tests {
         assert!(ctx
             .check_filesystem_write(Path::new("/data/file.txt"))
             .is_err());
-        assert!(ctx.check_network("api.example.com").is_err());
+        assert!(ctx.check_network("api.example.com", 443).is_err());
         assert!(ctx.check_process("git").is_err());
         assert!(ctx.check_environment("PATH").is_err());
     }
@@ -616,10 +827,39 @@ mod tests {
     #[test]
     fn test_security_context_grant_network() {
         let mut ctx = SecurityContext::new();
-        ctx.grant_network("api.example.com");
+        ctx.grant_network("api.example.com", PortRange::ANY);
 
-        assert!(ctx.check_network("api.example.com").is_ok());
-        assert!(ctx.check_network("other.com").is_err());
+        assert!(ctx.check_network("api.example.com", 443).is_ok());
+        assert!(ctx.check_network("other.com", 443).is_err());
+    }
+
+    #[test]
+    fn test_security_context_grant_network_port_range() {
+        let mut ctx = SecurityContext::new();
+        ctx.grant_network("localhost", 8000..=8999);
+
+        assert!(ctx.check_network("localhost", 8000).is_ok());
+        assert!(ctx.check_network("localhost", 8999).is_ok());
+        assert!(ctx.check_network("localhost", 9000).is_err());
+    }
+
+    #[test]
+    fn test_port_range_parse() {
+        assert_eq!("*".parse::<PortRange>().unwrap(), PortRange::ANY);
+        assert_eq!("443".parse::<PortRange>().unwrap(), PortRange::single(443));
+        assert_eq!(
+            "8000-8999".parse::<PortRange>().unwrap(),
+            PortRange::new(8000, 8999)
+        );
+        assert!("http".parse::<PortRange>().is_err());
+        assert!("70000".parse::<PortRange>().is_err());
+    }
+
+    #[test]
+    fn test_port_range_display() {
+        assert_eq!(PortRange::ANY.to_string(), "*");
+        assert_eq!(PortRange::single(443).to_string(), "443");
+        assert_eq!(PortRange::new(8000, 8999).to_string(), "8000-8999");
     }
 
     #[test]
@@ -644,7 +884,7 @@ mod tests {
                 .check_filesystem_write(Path::new(r"\\?\C:\any\path"))
                 .is_ok());
         }
-        assert!(ctx.check_network("any.host.com").is_ok());
+        assert!(ctx.check_network("any.host.com", 443).is_ok());
         assert!(ctx.check_process("any-command").is_ok());
         assert!(ctx.check_environment("ANY_VAR").is_ok());
     }
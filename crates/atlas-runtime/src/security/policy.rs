@@ -1,6 +1,6 @@
 //! Security policy definition and enforcement
 
-use crate::security::permissions::{Permission, PermissionSet};
+use crate::security::permissions::{Permission, PermissionSet, PortRange};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -269,9 +269,20 @@ impl SecurityPolicy {
                 path: PathBuf::from(&rule.pattern),
                 recursive: rule.scope.as_deref() == Some("recursive"),
             }),
-            ResourceType::NetworkConnect => Some(Permission::Network {
-                host: rule.pattern.clone(),
-            }),
+            ResourceType::NetworkConnect => {
+                // "host:ports" restricts ports; a bare host allows every port
+                let (host, port) = match rule.pattern.rsplit_once(':') {
+                    Some((host, ports)) => match ports.parse::<PortRange>() {
+                        Ok(port) => (host, port),
+                        Err(_) => (rule.pattern.as_str(), PortRange::ANY),
+                    },
+                    None => (rule.pattern.as_str(), PortRange::ANY),
+                };
+                Some(Permission::Network {
+                    host: host.to_string(),
+                    port,
+                })
+            }
             ResourceType::Process => Some(Permission::Process {
                 command: rule.pattern.clone(),
             }),
@@ -530,4 +541,23 @@ mod tests {
         let perms = policy.to_permission_set();
         assert_eq!(perms.len(), 1);
     }
+
+    #[test]
+    fn test_policy_network_rule_with_ports() {
+        let mut policy = SecurityPolicy::new("test".to_string());
+        policy.allow.push(PolicyRule {
+            resource: ResourceType::NetworkConnect,
+            pattern: "*.example.com:8000-8999".to_string(),
+            scope: None,
+            description: None,
+        });
+
+        let perms = policy.to_permission_set();
+        let request = |host: &str, port: u16| Permission::Network {
+            host: host.to_string(),
+            port: PortRange::single(port),
+        };
+        assert!(perms.is_granted(&request("api.example.com", 8080)));
+        assert!(!perms.is_granted(&request("api.example.com", 443)));
+    }
 }
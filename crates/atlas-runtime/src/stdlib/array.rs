@@ -3,8 +3,14 @@
 //! Pure array functions (no callbacks). For callback-based operations like map/filter/reduce,
 //! see interpreter/VM intrinsics.
 
+use crate::json_value::JsonValue;
 use crate::span::Span;
 use crate::value::{RuntimeError, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Maximum number of elements `range` may produce
+const MAX_RANGE_LEN: f64 = 10_000_000.0;
 
 // ============================================================================
 // Core Operations
@@ -39,6 +45,33 @@ fn compare_values_natural(a: &Value, b: &Value) -> std::cmp::Ordering {
     }
 }
 
+/// Sort elements by precomputed keys
+///
+/// Takes `(key, element)` pairs, as produced by the `sortBy` intrinsic in the
+/// interpreter and VM. The sort is stable: elements with equal keys keep their
+/// input order. Number keys compare numerically and string keys lexicographically;
+/// keys of differing types compare equal.
+pub fn sort_by_keys(mut keyed: Vec<(Value, Value)>) -> Value {
+    keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Value::Number(x), Value::Number(y)) => {
+            x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal)
+        }
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        _ => std::cmp::Ordering::Equal,
+    });
+    Value::array(keyed.into_iter().map(|(_, elem)| elem).collect())
+}
+
+/// Sort a number array in ascending order
+///
+/// Returns new sorted array; equal numbers keep their input order.
+/// Returns error if an element is not a number
+pub fn sort_numeric(arr: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let mut nums = numbers(arr, "sortNumeric", span)?;
+    nums.sort_by(f64::total_cmp);
+    Ok(Value::array(nums.into_iter().map(Value::Number).collect()))
+}
+
 /// Remove and return last element from array
 ///
 /// Returns two-element array: [removed_element, new_array]
@@ -191,10 +224,295 @@ pub fn slice(arr: &[Value], start: f64, end: f64, span: Span) -> Result<Value, R
     Ok(Value::array(sliced))
 }
 
+/// Split array into chunks of at most `size` elements
+///
+/// Returns new array of arrays; the last chunk holds any remainder.
+/// Returns error if size is not a positive integer
+pub fn chunk(arr: &[Value], size: f64, span: Span) -> Result<Value, RuntimeError> {
+    if size < 1.0 || size.fract() != 0.0 {
+        return Err(RuntimeError::InvalidStdlibArgument {
+            msg: format!("chunk(): size must be a positive integer, got {}", size),
+            span,
+        });
+    }
+
+    let chunks = arr
+        .chunks(size as usize)
+        .map(|c| Value::array(c.to_vec()))
+        .collect();
+    Ok(Value::array(chunks))
+}
+
+// ============================================================================
+// Deduplication
+// ============================================================================
+
+/// Remove duplicate elements, keeping the first occurrence of each
+///
+/// Returns new array in original order; equality is value equality
+pub fn unique(arr: &[Value]) -> Value {
+    let mut result: Vec<Value> = Vec::with_capacity(arr.len());
+    for elem in arr {
+        if !result.iter().any(|seen| values_equal(seen, elem)) {
+            result.push(elem.clone());
+        }
+    }
+    Value::array(result)
+}
+
+/// Keep the first element for each distinct key
+///
+/// Takes `(key, element)` pairs, as produced by the `uniqueBy` intrinsic in the
+/// interpreter and VM. Returns error if a key is not a number, string, or bool
+pub fn unique_by_keys(keyed: Vec<(Value, Value)>, span: Span) -> Result<Value, RuntimeError> {
+    let mut seen: Vec<Value> = Vec::new();
+    let mut result = Vec::new();
+    for (key, elem) in keyed {
+        if !matches!(key, Value::Number(_) | Value::String(_) | Value::Bool(_)) {
+            return Err(RuntimeError::TypeError {
+                msg: format!(
+                    "uniqueBy() key function must return number, string, or bool, got {}",
+                    key.type_name()
+                ),
+                span,
+            });
+        }
+        if !seen.iter().any(|k| values_equal(k, &key)) {
+            seen.push(key);
+            result.push(elem);
+        }
+    }
+    Ok(Value::array(result))
+}
+
+// ============================================================================
+// Grouping
+// ============================================================================
+
+/// Group elements into a json object keyed by string
+///
+/// Takes `(key, element)` pairs, as produced by the `groupBy` intrinsic in the
+/// interpreter and VM. Each key maps to the array of its elements in input order.
+/// Returns error if a key is not a string or an element cannot be stored in json
+pub fn group_by_keys(keyed: Vec<(Value, Value)>, span: Span) -> Result<Value, RuntimeError> {
+    let mut groups: HashMap<String, Vec<JsonValue>> = HashMap::new();
+    for (key, elem) in keyed {
+        let key = match key {
+            Value::String(s) => s.as_ref().clone(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    msg: format!(
+                        "groupBy() key function must return string, got {}",
+                        other.type_name()
+                    ),
+                    span,
+                })
+            }
+        };
+        let elem = super::json::to_json_value(&elem, span)?;
+        groups.entry(key).or_default().push(elem);
+    }
+
+    let object = groups
+        .into_iter()
+        .map(|(key, elems)| (key, JsonValue::Array(elems)))
+        .collect();
+    Ok(Value::JsonValue(Arc::new(JsonValue::Object(object))))
+}
+
+/// Split elements into matches and non-matches
+///
+/// Takes `(flag, element)` pairs, as produced by the `partition` intrinsic in the
+/// interpreter and VM. Returns `[matches, nonMatches]`, each in input order.
+/// Returns error if a flag is not a bool
+pub fn partition_by_flags(flagged: Vec<(Value, Value)>, span: Span) -> Result<Value, RuntimeError> {
+    let mut matches = Vec::new();
+    let mut rest = Vec::new();
+    for (flag, elem) in flagged {
+        match flag {
+            Value::Bool(true) => matches.push(elem),
+            Value::Bool(false) => rest.push(elem),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    msg: format!(
+                        "partition() predicate must return bool, got {}",
+                        other.type_name()
+                    ),
+                    span,
+                })
+            }
+        }
+    }
+    Ok(Value::array(vec![
+        Value::array(matches),
+        Value::array(rest),
+    ]))
+}
+
+// ============================================================================
+// Pairing
+// ============================================================================
+
+/// Pair up elements of two arrays
+///
+/// Returns an array of two-element json arrays `[a, b]`, truncated to the shorter input.
+/// Returns error if an element cannot be stored in json
+pub fn zip(a: &[Value], b: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let pairs = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| json_pair(x, y, span))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Value::array(pairs))
+}
+
+/// Pair each element with its index
+///
+/// Returns an array of two-element json arrays `[index, element]`.
+/// Returns error if an element cannot be stored in json
+pub fn enumerate(arr: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let pairs = arr
+        .iter()
+        .enumerate()
+        .map(|(i, x)| json_pair(&Value::Number(i as f64), x, span))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Value::array(pairs))
+}
+
+// ============================================================================
+// Generation
+// ============================================================================
+
+/// Numbers from `start` up to (but excluding) `end`, stepping by `step`
+///
+/// A negative step counts down. Returns an empty array when `step` points away from `end`.
+/// Returns error if step is zero, any argument is not finite, or the range is too long
+pub fn range(start: f64, end: f64, step: f64, span: Span) -> Result<Value, RuntimeError> {
+    let count = range_len(start, end, step, span)?;
+
+    // Compute each element from the start to avoid accumulating rounding error
+    let values = (0..count)
+        .map(|i| Value::Number(start + i as f64 * step))
+        .collect();
+    Ok(Value::array(values))
+}
+
+/// Number of elements `range(start, end, step)` produces
+///
+/// Returns error if step is zero, any argument is not finite, or the count
+/// exceeds `MAX_RANGE_LEN`
+pub fn range_len(start: f64, end: f64, step: f64, span: Span) -> Result<usize, RuntimeError> {
+    if step == 0.0 {
+        return Err(RuntimeError::InvalidStdlibArgument {
+            msg: "range(): step must not be zero".to_string(),
+            span,
+        });
+    }
+    if !(start.is_finite() && end.is_finite() && step.is_finite()) {
+        return Err(RuntimeError::InvalidStdlibArgument {
+            msg: format!(
+                "range(): arguments must be finite, got {}, {}, {}",
+                start, end, step
+            ),
+            span,
+        });
+    }
+
+    let count = ((end - start) / step).ceil().max(0.0);
+    if count > MAX_RANGE_LEN {
+        return Err(RuntimeError::InvalidStdlibArgument {
+            msg: format!(
+                "range(): too many elements ({}), the maximum is {}",
+                count, MAX_RANGE_LEN
+            ),
+            span,
+        });
+    }
+    Ok(count as usize)
+}
+
+// ============================================================================
+// Numeric Aggregates
+// ============================================================================
+
+/// Sum of a number array (0 for an empty array)
+pub fn sum(arr: &[Value], span: Span) -> Result<f64, RuntimeError> {
+    // `Iterator::sum` starts from -0.0, so fold from +0.0 to keep `sum([])` positive
+    Ok(numbers(arr, "sum", span)?
+        .into_iter()
+        .fold(0.0, |acc, n| acc + n))
+}
+
+/// Product of a number array (1 for an empty array)
+pub fn product(arr: &[Value], span: Span) -> Result<f64, RuntimeError> {
+    Ok(numbers(arr, "product", span)?.iter().product())
+}
+
+/// Arithmetic mean of a number array
+///
+/// Returns error if array is empty
+pub fn average(arr: &[Value], span: Span) -> Result<f64, RuntimeError> {
+    let nums = non_empty_numbers(arr, "average", span)?;
+    Ok(nums.iter().sum::<f64>() / nums.len() as f64)
+}
+
+/// Smallest element of a number array
+///
+/// Returns error if array is empty
+pub fn min_of(arr: &[Value], span: Span) -> Result<f64, RuntimeError> {
+    let nums = non_empty_numbers(arr, "minOf", span)?;
+    Ok(nums.into_iter().fold(f64::INFINITY, f64::min))
+}
+
+/// Largest element of a number array
+///
+/// Returns error if array is empty
+pub fn max_of(arr: &[Value], span: Span) -> Result<f64, RuntimeError> {
+    let nums = non_empty_numbers(arr, "maxOf", span)?;
+    Ok(nums.into_iter().fold(f64::NEG_INFINITY, f64::max))
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Extract the elements of a number array
+fn numbers(arr: &[Value], func_name: &str, span: Span) -> Result<Vec<f64>, RuntimeError> {
+    arr.iter()
+        .map(|elem| match elem {
+            Value::Number(n) => Ok(*n),
+            other => Err(RuntimeError::TypeError {
+                msg: format!(
+                    "{}() requires an array of numbers, found {}",
+                    func_name,
+                    other.type_name()
+                ),
+                span,
+            }),
+        })
+        .collect()
+}
+
+/// Extract the elements of a number array that must not be empty
+fn non_empty_numbers(arr: &[Value], func_name: &str, span: Span) -> Result<Vec<f64>, RuntimeError> {
+    if arr.is_empty() {
+        return Err(RuntimeError::TypeError {
+            msg: format!("{}() of an empty array is undefined", func_name),
+            span,
+        });
+    }
+    numbers(arr, func_name, span)
+}
+
+/// Build a two-element json array from a pair of values
+fn json_pair(first: &Value, second: &Value, span: Span) -> Result<Value, RuntimeError> {
+    let pair = JsonValue::Array(vec![
+        super::json::to_json_value(first, span)?,
+        super::json::to_json_value(second, span)?,
+    ]);
+    Ok(Value::JsonValue(Arc::new(pair)))
+}
+
 /// Check if two values are equal (for indexOf/includes)
 ///
 /// Uses Value's PartialEq implementation which handles:
@@ -209,6 +527,139 @@ fn values_equal(a: &Value, b: &Value) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_numeric_aggregates() {
+        let arr = vec![Value::Number(2.0), Value::Number(-1.0), Value::Number(5.0)];
+        let span = Span::dummy();
+        assert_eq!(sum(&arr, span).unwrap(), 6.0);
+        assert_eq!(product(&arr, span).unwrap(), -10.0);
+        assert_eq!(average(&arr, span).unwrap(), 2.0);
+        assert_eq!(min_of(&arr, span).unwrap(), -1.0);
+        assert_eq!(max_of(&arr, span).unwrap(), 5.0);
+
+        assert_eq!(sum(&[], span).unwrap(), 0.0);
+        assert_eq!(product(&[], span).unwrap(), 1.0);
+        assert!(average(&[], span).is_err());
+        assert!(min_of(&[], span).is_err());
+        assert!(max_of(&[], span).is_err());
+
+        let mixed = vec![Value::Number(1.0), Value::string("2")];
+        assert!(sum(&mixed, span).is_err());
+    }
+
+    #[test]
+    fn test_partition_by_flags_preserves_order() {
+        let flagged = vec![
+            (Value::Bool(false), Value::Number(1.0)),
+            (Value::Bool(true), Value::Number(2.0)),
+            (Value::Bool(false), Value::Number(3.0)),
+            (Value::Bool(true), Value::Number(4.0)),
+        ];
+        assert_eq!(
+            partition_by_flags(flagged, Span::dummy()).unwrap(),
+            Value::array(vec![
+                Value::array(vec![Value::Number(2.0), Value::Number(4.0)]),
+                Value::array(vec![Value::Number(1.0), Value::Number(3.0)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_partition_by_flags_rejects_non_bool() {
+        let flagged = vec![(Value::Number(1.0), Value::Number(1.0))];
+        assert!(partition_by_flags(flagged, Span::dummy()).is_err());
+    }
+
+    #[test]
+    fn test_sort_by_keys_is_stable() {
+        let keyed = vec![
+            (Value::Number(2.0), Value::string("b1")),
+            (Value::Number(1.0), Value::string("a1")),
+            (Value::Number(2.0), Value::string("b2")),
+            (Value::Number(1.0), Value::string("a2")),
+        ];
+        assert_eq!(
+            sort_by_keys(keyed),
+            Value::array(vec![
+                Value::string("a1"),
+                Value::string("a2"),
+                Value::string("b1"),
+                Value::string("b2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_numeric() {
+        let span = Span::dummy();
+        let arr = vec![Value::Number(3.0), Value::Number(-1.5), Value::Number(2.0)];
+        assert_eq!(
+            sort_numeric(&arr, span).unwrap(),
+            Value::array(vec![
+                Value::Number(-1.5),
+                Value::Number(2.0),
+                Value::Number(3.0)
+            ])
+        );
+        assert!(sort_numeric(&[Value::string("a")], span).is_err());
+    }
+
+    #[test]
+    fn test_range() {
+        let span = Span::dummy();
+        let nums = |v: Value| match v {
+            Value::Array(arr) => arr
+                .iter()
+                .map(|x| match x {
+                    Value::Number(n) => *n,
+                    other => panic!("Expected number, got {:?}", other),
+                })
+                .collect::<Vec<_>>(),
+            other => panic!("Expected array, got {:?}", other),
+        };
+
+        assert_eq!(
+            nums(range(0.0, 4.0, 1.0, span).unwrap()),
+            vec![0.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            nums(range(5.0, 0.0, -2.0, span).unwrap()),
+            vec![5.0, 3.0, 1.0]
+        );
+        assert_eq!(
+            nums(range(0.0, 1.0, 0.25, span).unwrap()),
+            vec![0.0, 0.25, 0.5, 0.75]
+        );
+        assert!(nums(range(3.0, 0.0, 1.0, span).unwrap()).is_empty());
+        assert!(range(0.0, 3.0, 0.0, span).is_err());
+        assert!(range(0.0, f64::INFINITY, 1.0, span).is_err());
+    }
+
+    #[test]
+    fn test_zip_and_enumerate() {
+        let span = Span::dummy();
+        let nums = vec![Value::Number(1.0), Value::Number(2.0), Value::Number(3.0)];
+        let strs = vec![Value::string("a"), Value::string("b")];
+        let pair =
+            |a: JsonValue, b: JsonValue| Value::JsonValue(Arc::new(JsonValue::Array(vec![a, b])));
+
+        assert_eq!(
+            zip(&nums, &strs, span).unwrap(),
+            Value::array(vec![
+                pair(JsonValue::Number(1.0), JsonValue::String("a".into())),
+                pair(JsonValue::Number(2.0), JsonValue::String("b".into())),
+            ])
+        );
+        assert_eq!(zip(&nums, &[], span).unwrap(), Value::array(vec![]));
+        assert_eq!(
+            enumerate(&strs, span).unwrap(),
+            Value::array(vec![
+                pair(JsonValue::Number(0.0), JsonValue::String("a".into())),
+                pair(JsonValue::Number(1.0), JsonValue::String("b".into())),
+            ])
+        );
+    }
+
     #[test]
     fn test_pop_normal() {
         let arr = vec![Value::Number(1.0), Value::Number(2.0), Value::Number(3.0)];
@@ -354,4 +805,83 @@ mod tests {
             _ => panic!("Expected array"),
         }
     }
+
+    #[test]
+    fn test_chunk_remainder() {
+        let arr: Vec<Value> = (1..=5).map(|n| Value::Number(n as f64)).collect();
+        let result = chunk(&arr, 2.0, Span::dummy()).unwrap();
+
+        match result {
+            Value::Array(chunks) => {
+                let sizes: Vec<usize> = chunks
+                    .as_slice()
+                    .iter()
+                    .map(|c| match c {
+                        Value::Array(inner) => inner.len(),
+                        _ => panic!("Expected inner array"),
+                    })
+                    .collect();
+                assert_eq!(sizes, vec![2, 2, 1]);
+            }
+            _ => panic!("Expected array"),
+        }
+    }
+
+    #[test]
+    fn test_unique_keeps_first_occurrence() {
+        let arr = vec![
+            Value::Number(3.0),
+            Value::Number(1.0),
+            Value::Number(3.0),
+            Value::Number(2.0),
+            Value::Number(1.0),
+        ];
+        let expected = [Value::Number(3.0), Value::Number(1.0), Value::Number(2.0)];
+
+        match unique(&arr) {
+            Value::Array(new_arr) => assert_eq!(new_arr.as_slice(), &expected[..]),
+            _ => panic!("Expected array"),
+        }
+    }
+
+    #[test]
+    fn test_unique_by_keys_rejects_non_primitive_key() {
+        let keyed = vec![(Value::Null, Value::Number(1.0))];
+        assert!(unique_by_keys(keyed, Span::dummy()).is_err());
+    }
+
+    #[test]
+    fn test_group_by_keys_preserves_order() {
+        let keyed = vec![
+            (Value::string("odd"), Value::Number(1.0)),
+            (Value::string("even"), Value::Number(2.0)),
+            (Value::string("odd"), Value::Number(3.0)),
+        ];
+
+        match group_by_keys(keyed, Span::dummy()).unwrap() {
+            Value::JsonValue(json) => {
+                let odd = json.index_str("odd");
+                assert_eq!(
+                    odd,
+                    JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Number(3.0)])
+                );
+                assert_eq!(json.index_str("even").as_array().unwrap().len(), 1);
+            }
+            _ => panic!("Expected json"),
+        }
+    }
+
+    #[test]
+    fn test_group_by_keys_rejects_non_string_key() {
+        let keyed = vec![(Value::Number(1.0), Value::Number(1.0))];
+        assert!(group_by_keys(keyed, Span::dummy()).is_err());
+    }
+
+    #[test]
+    fn test_chunk_invalid_size() {
+        let arr = vec![Value::Number(1.0)];
+        assert!(chunk(&arr, 0.0, Span::dummy()).is_err());
+        assert!(chunk(&arr, -1.0, Span::dummy()).is_err());
+        assert!(chunk(&arr, 1.5, Span::dummy()).is_err());
+    }
 }
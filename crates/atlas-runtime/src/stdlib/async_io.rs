@@ -16,6 +16,18 @@ use std::time::Duration;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
+/// Run `task` as the attached settler of `future` and wait for it
+///
+/// The I/O builtins hand back already-settled futures; running the work as
+/// the future's task still ties it to the future, so cancelling drops it.
+fn settle_now<F>(future: &AtlasFuture, task: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    future.spawn_settler(task);
+    let _ = block_on(future.wait());
+}
+
 // ============================================================================
 // Async File Operations
 // ============================================================================
@@ -79,8 +91,7 @@ pub fn read_file_async(
         }
     };
 
-    // Execute the async task
-    block_on(task);
+    settle_now(&future, task);
 
     Ok(Value::Future(Arc::new(future)))
 }
@@ -175,7 +186,7 @@ pub fn write_file_async(
         }
     };
 
-    block_on(task);
+    settle_now(&future, task);
     Ok(Value::Future(Arc::new(future)))
 }
 
@@ -294,7 +305,7 @@ pub fn append_file_async(
         }
     };
 
-    block_on(task);
+    settle_now(&future, task);
     Ok(Value::Future(Arc::new(future)))
 }
 
@@ -424,7 +435,7 @@ pub fn http_send_async(args: &[Value], span: Span) -> Result<Value, RuntimeError
         future_clone.resolve(Value::HttpResponse(Arc::new(http_response)));
     };
 
-    block_on(task);
+    settle_now(&future, task);
     Ok(Value::Future(Arc::new(future)))
 }
 
@@ -595,8 +606,8 @@ pub fn await_future(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
         }
     };
 
-    // For now, futures are immediately resolved due to block_on usage
-    // In future phases with true async, this would poll/wait for completion
+    // Block until the future's task settles it
+    let _ = block_on(future.wait());
     match future.get_state() {
         crate::async_runtime::FutureState::Resolved(value) => Ok(value),
         crate::async_runtime::FutureState::Rejected(error) => Err(RuntimeError::TypeError {
@@ -604,7 +615,7 @@ pub fn await_future(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
             span,
         }),
         crate::async_runtime::FutureState::Pending => {
-            // This shouldn't happen with current block_on implementation
+            // Nothing is left to settle it
             Err(RuntimeError::TypeError {
                 msg: "Future is still pending".to_string(),
                 span,
@@ -27,6 +27,7 @@
 //!
 //! Timeout operations:
 //! - timeout: Wrap future with timeout
+//! - withTimeout: Wait for a future, cancelling it after a deadline
 //! - retryWithTimeout: Retry operation with timeout
 //!
 //! Async mutex:
@@ -40,7 +41,6 @@ use crate::async_runtime;
 use crate::span::Span;
 use crate::value::{RuntimeError, Value};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
 
 // ============================================================================
 // Task Spawning and Management
@@ -84,16 +84,8 @@ pub fn spawn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     // Poll the AtlasFuture until it completes
     let handle = async_runtime::spawn_task(
         async move {
-            // Poll the future state until resolved or rejected
-            loop {
-                match future.get_state() {
-                    async_runtime::FutureState::Resolved(value) => return value,
-                    async_runtime::FutureState::Rejected(error) => return error,
-                    async_runtime::FutureState::Pending => {
-                        // Wait a bit before polling again
-                        tokio::time::sleep(Duration::from_millis(10)).await;
-                    }
-                }
+            match future.wait().await {
+                Ok(value) | Err(value) => value,
             }
         },
         name,
@@ -515,6 +507,41 @@ pub fn timeout_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     Ok(Value::Future(Arc::new(timeout_future)))
 }
 
+/// Wait for a future with a deadline, cancelling it if the deadline passes
+///
+/// Atlas signature: `withTimeout(task: Future<T>, milliseconds: number) -> Result<T, string>`
+pub fn with_timeout_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("withTimeout", 2, args.len(), span));
+    }
+
+    let future = match &args[0] {
+        Value::Future(f) => Arc::clone(f),
+        _ => {
+            return Err(RuntimeError::TypeError {
+                msg: format!("Expected Future, got {}", args[0].type_name()),
+                span,
+            })
+        }
+    };
+
+    let milliseconds = match &args[1] {
+        Value::Number(n) if *n >= 0.0 => *n as u64,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                msg: "Timeout duration must be a non-negative number".to_string(),
+                span,
+            })
+        }
+    };
+
+    Ok(Value::Result(
+        async_runtime::with_timeout(&future, milliseconds)
+            .map_err(Box::new)
+            .map(Box::new),
+    ))
+}
+
 // ============================================================================
 // Async Mutex
 // ============================================================================
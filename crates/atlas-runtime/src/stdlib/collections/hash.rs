@@ -14,7 +14,9 @@ use std::sync::Arc;
 ///
 /// Only Number, String, Bool, Null can be hashed.
 /// Arrays, functions, JsonValue, Option, Result are not hashable.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Keys order numbers first, then strings, bools, and null.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum HashKey {
     /// Number value with IEEE 754 canonicalization
     Number(OrderedFloat<f64>),
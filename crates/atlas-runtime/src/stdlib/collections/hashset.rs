@@ -71,6 +71,13 @@ impl AtlasHashSet {
         self.inner.iter().cloned().collect()
     }
 
+    /// Convert to vector of elements in a deterministic (sorted) order
+    pub fn sorted_keys(&self) -> Vec<HashKey> {
+        let mut keys = self.to_vec();
+        keys.sort();
+        keys
+    }
+
     /// Set union: all elements in either set
     pub fn union(&self, other: &AtlasHashSet) -> AtlasHashSet {
         AtlasHashSet {
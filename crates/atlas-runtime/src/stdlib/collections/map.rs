@@ -0,0 +1,180 @@
+//! Map collection operations
+//!
+//! Helpers for the string-keyed `Value::Map` produced by `{ key: value }` literals.
+//! Keys and values are returned in sorted key order so output is deterministic.
+
+use crate::span::Span;
+use crate::stdlib::{stdlib_arg_error, stdlib_arity_error};
+use crate::value::{RuntimeError, Value, ValueMap};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn extract_map_ref<'a>(
+    func_name: &str,
+    value: &'a Value,
+    span: Span,
+) -> Result<&'a ValueMap, RuntimeError> {
+    match value {
+        Value::Map(map) => Ok(map),
+        _ => Err(stdlib_arg_error(func_name, "map", value, span)),
+    }
+}
+
+fn extract_key<'a>(func_name: &str, value: &'a Value, span: Span) -> Result<&'a str, RuntimeError> {
+    match value {
+        Value::String(s) => Ok(s.as_ref()),
+        _ => Err(stdlib_arg_error(func_name, "string", value, span)),
+    }
+}
+
+fn index_key(key: &Value, span: Span) -> Result<&str, RuntimeError> {
+    match key {
+        Value::String(s) => Ok(s.as_ref()),
+        _ => Err(RuntimeError::TypeError {
+            msg: format!("Map key must be string, got {}", key.type_name()),
+            span,
+        }),
+    }
+}
+
+/// Read `map[key]`. Missing keys are a runtime error.
+///
+/// Shared by index expressions in the interpreter and the VM's
+/// `GetIndex`/`MapGet` handling.
+pub fn index_get(map: &ValueMap, key: &Value, span: Span) -> Result<Value, RuntimeError> {
+    let key = index_key(key, span)?;
+    map.get(key)
+        .cloned()
+        .ok_or_else(|| RuntimeError::MapKeyNotFound {
+            key: key.to_string(),
+            span,
+        })
+}
+
+/// Write `map[key] = value`, inserting the key if it is absent.
+pub fn index_set(
+    map: &mut ValueMap,
+    key: &Value,
+    value: Value,
+    span: Span,
+) -> Result<(), RuntimeError> {
+    let key = index_key(key, span)?;
+    map.insert(key.to_string(), value);
+    Ok(())
+}
+
+// ============================================================================
+// Public stdlib functions
+// ============================================================================
+
+/// Get value by key. Returns `Option`.
+pub fn get(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("mapGet", 2, args.len(), span));
+    }
+
+    let map = extract_map_ref("mapGet", &args[0], span)?;
+    let key = extract_key("mapGet", &args[1], span)?;
+
+    Ok(Value::Option(map.get(key).cloned().map(Box::new)))
+}
+
+/// Insert or update key-value pair. Returns modified map (CoW).
+pub fn set(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 3 {
+        return Err(stdlib_arity_error("mapSet", 3, args.len(), span));
+    }
+
+    let mut map = extract_map_ref("mapSet", &args[0], span)?.clone();
+    let key = extract_key("mapSet", &args[1], span)?;
+    map.insert(key.to_string(), args[2].clone());
+
+    Ok(Value::Map(map))
+}
+
+/// Check if key exists
+pub fn has(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("mapHas", 2, args.len(), span));
+    }
+
+    let map = extract_map_ref("mapHas", &args[0], span)?;
+    let key = extract_key("mapHas", &args[1], span)?;
+
+    Ok(Value::Bool(map.contains_key(key)))
+}
+
+/// Get all keys as a sorted array of strings
+pub fn keys(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("mapKeys", 1, args.len(), span));
+    }
+
+    let map = extract_map_ref("mapKeys", &args[0], span)?;
+    let keys = map
+        .sorted_entries()
+        .into_iter()
+        .map(|(k, _)| Value::string(k.as_str()))
+        .collect();
+
+    Ok(Value::array(keys))
+}
+
+/// Get all values as an array, ordered by their sorted keys
+pub fn values(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("mapValues", 1, args.len(), span));
+    }
+
+    let map = extract_map_ref("mapValues", &args[0], span)?;
+    let values = map
+        .sorted_entries()
+        .into_iter()
+        .map(|(_, v)| v.clone())
+        .collect();
+
+    Ok(Value::array(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Value {
+        let mut map = ValueMap::new();
+        map.insert("b".to_string(), Value::Number(2.0));
+        map.insert("a".to_string(), Value::Number(1.0));
+        Value::Map(map)
+    }
+
+    #[test]
+    fn test_keys_and_values_sorted() {
+        let span = Span::dummy();
+        assert_eq!(keys(&[sample()], span).unwrap().to_string(), "[a, b]");
+        assert_eq!(values(&[sample()], span).unwrap().to_string(), "[1, 2]");
+    }
+
+    #[test]
+    fn test_set_does_not_mutate_original() {
+        let span = Span::dummy();
+        let original = sample();
+        let updated = set(
+            &[original.clone(), Value::string("c"), Value::Number(3.0)],
+            span,
+        )
+        .unwrap();
+        assert_eq!(original.to_string(), "{a: 1, b: 2}");
+        assert_eq!(updated.to_string(), "{a: 1, b: 2, c: 3}");
+    }
+
+    #[test]
+    fn test_index_get_missing_key() {
+        let Value::Map(map) = sample() else {
+            unreachable!()
+        };
+        let err = index_get(&map, &Value::string("z"), Span::dummy()).unwrap_err();
+        assert!(matches!(err, RuntimeError::MapKeyNotFound { key, .. } if key == "z"));
+    }
+}
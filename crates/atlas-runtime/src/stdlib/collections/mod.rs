@@ -3,5 +3,7 @@
 pub mod hash;
 pub mod hashmap;
 pub mod hashset;
+pub mod map;
 pub mod queue;
+pub mod set;
 pub mod stack;
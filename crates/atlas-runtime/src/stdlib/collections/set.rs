@@ -0,0 +1,184 @@
+//! Set collection operations
+//!
+//! Helpers for the `Value::Set` produced by `setOf`. Elements must be hashable
+//! primitives, so membership checks are O(1). Elements are returned in sorted
+//! order so output is deterministic.
+
+use crate::span::Span;
+use crate::stdlib::collections::hash::HashKey;
+use crate::stdlib::collections::hashset::AtlasHashSet;
+use crate::stdlib::{stdlib_arg_error, stdlib_arity_error};
+use crate::value::{RuntimeError, Value, ValueHashSet};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn extract_set_ref<'a>(
+    func_name: &str,
+    value: &'a Value,
+    span: Span,
+) -> Result<&'a ValueHashSet, RuntimeError> {
+    match value {
+        Value::Set(set) => Ok(set),
+        _ => Err(stdlib_arg_error(func_name, "set", value, span)),
+    }
+}
+
+// ============================================================================
+// Public stdlib functions
+// ============================================================================
+
+/// Build a set from an array, dropping duplicates
+pub fn of(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("setOf", 1, args.len(), span));
+    }
+
+    let elements = match &args[0] {
+        Value::Array(arr) => arr,
+        other => return Err(stdlib_arg_error("setOf", "array", other, span)),
+    };
+
+    let mut set = AtlasHashSet::with_capacity(elements.len());
+    for element in elements.iter() {
+        set.insert(HashKey::from_value(element, span)?);
+    }
+
+    Ok(Value::Set(ValueHashSet::from_atlas(set)))
+}
+
+/// Add an element. Returns modified set (CoW).
+pub fn add(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("setAdd", 2, args.len(), span));
+    }
+
+    let mut set = extract_set_ref("setAdd", &args[0], span)?.clone();
+    set.inner_mut().insert(HashKey::from_value(&args[1], span)?);
+
+    Ok(Value::Set(set))
+}
+
+/// Remove an element if present. Returns modified set (CoW).
+pub fn remove(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("setRemove", 2, args.len(), span));
+    }
+
+    let mut set = extract_set_ref("setRemove", &args[0], span)?.clone();
+    set.inner_mut()
+        .remove(&HashKey::from_value(&args[1], span)?);
+
+    Ok(Value::Set(set))
+}
+
+/// Check if an element is in the set
+pub fn has(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("setHas", 2, args.len(), span));
+    }
+
+    let set = extract_set_ref("setHas", &args[0], span)?;
+    let key = HashKey::from_value(&args[1], span)?;
+
+    Ok(Value::Bool(set.inner().contains(&key)))
+}
+
+/// Elements in either set
+pub fn union(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("setUnion", 2, args.len(), span));
+    }
+
+    let a = extract_set_ref("setUnion", &args[0], span)?;
+    let b = extract_set_ref("setUnion", &args[1], span)?;
+
+    Ok(Value::Set(ValueHashSet::from_atlas(
+        a.inner().union(b.inner()),
+    )))
+}
+
+/// Elements in both sets
+pub fn intersect(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("setIntersect", 2, args.len(), span));
+    }
+
+    let a = extract_set_ref("setIntersect", &args[0], span)?;
+    let b = extract_set_ref("setIntersect", &args[1], span)?;
+
+    Ok(Value::Set(ValueHashSet::from_atlas(
+        a.inner().intersection(b.inner()),
+    )))
+}
+
+/// Elements in the first set but not the second
+pub fn difference(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("setDifference", 2, args.len(), span));
+    }
+
+    let a = extract_set_ref("setDifference", &args[0], span)?;
+    let b = extract_set_ref("setDifference", &args[1], span)?;
+
+    Ok(Value::Set(ValueHashSet::from_atlas(
+        a.inner().difference(b.inner()),
+    )))
+}
+
+/// Get all elements as a sorted array
+pub fn to_array(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("setToArray", 1, args.len(), span));
+    }
+
+    let set = extract_set_ref("setToArray", &args[0], span)?;
+    let elements = set
+        .inner()
+        .sorted_keys()
+        .iter()
+        .map(HashKey::to_value)
+        .collect();
+
+    Ok(Value::array(elements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_of(values: Vec<Value>) -> Value {
+        of(&[Value::array(values)], Span::dummy()).unwrap()
+    }
+
+    #[test]
+    fn test_of_deduplicates_and_sorts() {
+        let set = set_of(vec![
+            Value::Number(3.0),
+            Value::Number(1.0),
+            Value::Number(3.0),
+            Value::Number(2.0),
+        ]);
+        assert_eq!(set.to_string(), "{1, 2, 3}");
+        assert_eq!(
+            to_array(&[set], Span::dummy()).unwrap().to_string(),
+            "[1, 2, 3]"
+        );
+    }
+
+    #[test]
+    fn test_add_does_not_mutate_original() {
+        let original = set_of(vec![Value::Number(1.0)]);
+        let updated = add(&[original.clone(), Value::Number(2.0)], Span::dummy()).unwrap();
+        assert_eq!(original.to_string(), "{1}");
+        assert_eq!(updated.to_string(), "{1, 2}");
+    }
+
+    #[test]
+    fn test_of_rejects_unhashable_elements() {
+        let nested = Value::array(vec![Value::array(vec![])]);
+        let err = of(&[nested], Span::dummy()).unwrap_err();
+        assert!(matches!(err, RuntimeError::UnhashableType { .. }));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Provides datetime creation, component access, arithmetic, and conversion operations.
 
+use crate::security::SecurityContext;
 use crate::span::Span;
 use crate::stdlib::collections::hash::HashKey;
 use crate::stdlib::collections::hashmap::AtlasHashMap;
@@ -9,6 +10,7 @@ use crate::value::RuntimeError;
 use crate::value::Value;
 use chrono::{Datelike, Local, TimeZone, Timelike, Utc, Weekday};
 use chrono_tz::Tz;
+use std::fmt::Write;
 use std::sync::Arc;
 
 // ============================================================================
@@ -1137,6 +1139,127 @@ pub fn duration_format(args: &[Value], span: Span) -> Result<Value, RuntimeError
     Ok(Value::string(result))
 }
 
+// ============================================================================
+// Unix Millisecond Timestamps
+// ============================================================================
+
+/// Get the current time as Unix milliseconds
+///
+/// Requires clock permission, so sandboxed scripts stay deterministic.
+///
+/// Returns: number (milliseconds since Unix epoch)
+///
+/// Example:
+/// ```atlas
+/// let start = now();
+/// ```atlas
+pub fn now(args: &[Value], span: Span, security: &SecurityContext) -> Result<Value, RuntimeError> {
+    if !args.is_empty() {
+        return Err(RuntimeError::TypeError {
+            msg: "now: expected 0 arguments".to_string(),
+            span,
+        });
+    }
+
+    security
+        .check_clock()
+        .map_err(|_| RuntimeError::ClockPermissionDenied { span })?;
+
+    Ok(Value::Number(Utc::now().timestamp_millis() as f64))
+}
+
+/// Format Unix milliseconds with a strftime pattern (UTC)
+///
+/// Args:
+/// - millis: number (milliseconds since Unix epoch)
+/// - pattern: string (strftime format)
+///
+/// Returns: string
+///
+/// Example:
+/// ```atlas
+/// let s = formatTime(0, "%Y-%m-%d"); // "1970-01-01"
+/// ```atlas
+pub fn format_time(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::TypeError {
+            msg: "formatTime: expected 2 arguments (millis, pattern)".to_string(),
+            span,
+        });
+    }
+
+    let millis = expect_number(&args[0], "millis", span)?;
+    let pattern = expect_string(&args[1], "pattern", span)?;
+
+    let dt = match Utc.timestamp_millis_opt(millis as i64) {
+        chrono::LocalResult::Single(dt) if millis.is_finite() => dt,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                msg: format!("formatTime: invalid timestamp: {}", millis),
+                span,
+            })
+        }
+    };
+
+    // Invalid specifiers surface as a fmt error rather than a panic
+    let mut formatted = String::new();
+    write!(formatted, "{}", dt.format(&pattern)).map_err(|_| RuntimeError::TypeError {
+        msg: format!("formatTime: invalid pattern '{}'", pattern),
+        span,
+    })?;
+    Ok(Value::string(formatted))
+}
+
+/// Parse a string with a strftime pattern into Unix milliseconds
+///
+/// Patterns without an offset are read as UTC; date-only patterns give
+/// midnight.
+///
+/// Args:
+/// - text: string
+/// - pattern: string (strftime format)
+///
+/// Returns: number (milliseconds since Unix epoch)
+///
+/// Example:
+/// ```atlas
+/// let ms = parseTime("2024-01-15 10:30:00", "%Y-%m-%d %H:%M:%S");
+/// ```atlas
+pub fn parse_time(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::TypeError {
+            msg: "parseTime: expected 2 arguments (text, pattern)".to_string(),
+            span,
+        });
+    }
+
+    let text = expect_string(&args[0], "text", span)?;
+    let pattern = expect_string(&args[1], "pattern", span)?;
+
+    let millis = chrono::DateTime::parse_from_str(&text, &pattern)
+        .map(|dt| dt.timestamp_millis())
+        .or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(&text, &pattern)
+                .map(|naive| naive.and_utc().timestamp_millis())
+        })
+        .or_else(|_| {
+            chrono::NaiveDate::parse_from_str(&text, &pattern).map(|date| {
+                date.and_time(chrono::NaiveTime::MIN)
+                    .and_utc()
+                    .timestamp_millis()
+            })
+        })
+        .map_err(|e| RuntimeError::TypeError {
+            msg: format!(
+                "parseTime: failed to parse '{}' with pattern '{}': {}",
+                text, pattern, e
+            ),
+            span,
+        })?;
+
+    Ok(Value::Number(millis as f64))
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
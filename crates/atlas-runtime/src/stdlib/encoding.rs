@@ -0,0 +1,168 @@
+//! Base64 and hex encoding functions
+
+use super::{stdlib_arg_error, stdlib_arity_error};
+use crate::span::Span;
+use crate::value::{RuntimeError, Value};
+use base64::Engine;
+
+// ============================================================================
+// Encoding Functions
+// ============================================================================
+
+/// Encode a string's UTF-8 bytes as standard (padded) base64
+pub fn encode_base64(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "encodeBase64", span)?;
+    Ok(Value::string(
+        base64::engine::general_purpose::STANDARD.encode(s.as_bytes()),
+    ))
+}
+
+/// Decode standard (padded) base64 into a string
+///
+/// Returns error if the input is not valid base64 or the decoded bytes
+/// are not valid UTF-8.
+pub fn decode_base64(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "decodeBase64", span)?;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(s)
+        .map_err(|e| invalid_input("decodeBase64", format!("invalid base64: {}", e), span))?;
+    utf8_string(bytes, "decodeBase64", span)
+}
+
+/// Encode a string's UTF-8 bytes as lowercase hex
+pub fn encode_hex(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "encodeHex", span)?;
+    Ok(Value::string(to_hex(s.as_bytes())))
+}
+
+/// Decode hex (either case) into a string
+///
+/// Returns error if the input has odd length, contains a non-hex digit, or
+/// the decoded bytes are not valid UTF-8.
+pub fn decode_hex(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "decodeHex", span)?;
+    if s.len() % 2 != 0 {
+        return Err(invalid_input(
+            "decodeHex",
+            format!("invalid hex: odd length {}", s.len()),
+            span,
+        ));
+    }
+
+    let bytes = s
+        .as_bytes()
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| match (hex_value(pair[0]), hex_value(pair[1])) {
+            (Some(hi), Some(lo)) => Ok((hi << 4) | lo),
+            _ => Err(invalid_input(
+                "decodeHex",
+                format!("invalid hex digit at offset {}", i * 2),
+                span,
+            )),
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    utf8_string(bytes, "decodeHex", span)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Render bytes as lowercase hex, two digits per byte
+pub(super) fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        out.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|d| d as u8)
+}
+
+/// Extract the sole string argument of a one-argument builtin
+pub(super) fn single_string<'a>(
+    args: &'a [Value],
+    func_name: &str,
+    span: Span,
+) -> Result<&'a str, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error(func_name, 1, args.len(), span));
+    }
+    match &args[0] {
+        Value::String(s) => Ok(s.as_ref()),
+        other => Err(stdlib_arg_error(func_name, "string", other, span)),
+    }
+}
+
+fn utf8_string(bytes: Vec<u8>, func_name: &str, span: Span) -> Result<Value, RuntimeError> {
+    String::from_utf8(bytes).map(Value::string).map_err(|e| {
+        invalid_input(
+            func_name,
+            format!(
+                "decoded bytes are not valid UTF-8 (at byte {})",
+                e.utf8_error().valid_up_to()
+            ),
+            span,
+        )
+    })
+}
+
+fn invalid_input(func_name: &str, reason: String, span: Span) -> RuntimeError {
+    RuntimeError::InvalidStdlibArgument {
+        msg: format!("{}(): {}", func_name, reason),
+        span,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(
+        f: fn(&[Value], Span) -> Result<Value, RuntimeError>,
+        input: &str,
+    ) -> Result<Value, RuntimeError> {
+        f(&[Value::string(input)], Span::dummy())
+    }
+
+    #[test]
+    fn test_base64_known_vectors() {
+        assert_eq!(call(encode_base64, "").unwrap(), Value::string(""));
+        assert_eq!(call(encode_base64, "f").unwrap(), Value::string("Zg=="));
+        assert_eq!(
+            call(encode_base64, "hello").unwrap(),
+            Value::string("aGVsbG8=")
+        );
+        assert_eq!(
+            call(decode_base64, "aGVsbG8=").unwrap(),
+            Value::string("hello")
+        );
+    }
+
+    #[test]
+    fn test_hex_known_vectors() {
+        assert_eq!(call(encode_hex, "Hi!").unwrap(), Value::string("486921"));
+        assert_eq!(call(encode_hex, "é").unwrap(), Value::string("c3a9"));
+        assert_eq!(call(decode_hex, "C3A9").unwrap(), Value::string("é"));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_utf8() {
+        let err = call(decode_hex, "ff").unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"));
+        let err = call(decode_base64, "/w==").unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"));
+    }
+
+    #[test]
+    fn test_decode_hex_invalid_digit_offset() {
+        let err = call(decode_hex, "41zz").unwrap_err();
+        assert!(err.to_string().contains("offset 2"), "{}", err);
+    }
+}
@@ -8,10 +8,11 @@
 //! - futureCatch: Chain an error handler
 //! - futureAll: Combine multiple futures
 //! - futureRace: Get first completed future
+//! - awaitAll, tryAwaitAll: Wait for multiple futures and collect their results
 //! - futureIsPending, futureIsResolved, futureIsRejected: Status checks
 
-use super::stdlib_arity_error;
-use crate::async_runtime::{future_all, future_race, AtlasFuture};
+use super::{stdlib_arg_error, stdlib_arity_error};
+use crate::async_runtime::{await_all, future_all, future_race, AtlasFuture};
 use crate::span::Span;
 use crate::value::{RuntimeError, Value};
 use std::sync::Arc;
@@ -251,6 +252,69 @@ pub fn future_race_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError>
     Ok(Value::Future(Arc::new(result)))
 }
 
+fn extract_futures(
+    func_name: &str,
+    value: &Value,
+    span: Span,
+) -> Result<Vec<AtlasFuture>, RuntimeError> {
+    let array = match value {
+        Value::Array(arr) => arr,
+        _ => return Err(stdlib_arg_error(func_name, "array", value, span)),
+    };
+
+    array
+        .as_slice()
+        .iter()
+        .map(|item| match item {
+            Value::Future(f) => Ok((**f).clone()),
+            _ => Err(RuntimeError::TypeError {
+                msg: format!(
+                    "{}: expected array of Futures, got {}",
+                    func_name,
+                    item.type_name()
+                ),
+                span,
+            }),
+        })
+        .collect()
+}
+
+/// Wait for all futures to settle and return their results in input order
+///
+/// The futures run concurrently, so the wait takes as long as the slowest one.
+/// The first rejection aborts the wait with a runtime error.
+///
+/// Atlas signature: `awaitAll(futures: Future<T>[]) -> T[]`
+pub fn await_all_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("awaitAll", 1, args.len(), span));
+    }
+
+    let futures = extract_futures("awaitAll", &args[0], span)?;
+    match await_all(&futures) {
+        Ok(values) => Ok(Value::array(values)),
+        Err(error) => Err(RuntimeError::TypeError {
+            msg: format!("Future rejected: {}", error),
+            span,
+        }),
+    }
+}
+
+/// Like `awaitAll`, but reports the first rejection as `Err` instead of failing
+///
+/// Atlas signature: `tryAwaitAll(futures: Future<T>[]) -> Result<T[], E>`
+pub fn try_await_all_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("tryAwaitAll", 1, args.len(), span));
+    }
+
+    let futures = extract_futures("tryAwaitAll", &args[0], span)?;
+    Ok(Value::Result(match await_all(&futures) {
+        Ok(values) => Ok(Box::new(Value::array(values))),
+        Err(error) => Err(Box::new(error)),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
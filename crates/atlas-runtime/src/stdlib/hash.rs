@@ -0,0 +1,60 @@
+//! Hash functions (sha256, md5, crc32)
+//!
+//! Every function hashes the UTF-8 bytes of its string argument and returns
+//! a lowercase hex digest.
+
+use super::encoding::{single_string, to_hex};
+use crate::span::Span;
+use crate::value::{RuntimeError, Value};
+use md5::Md5;
+use sha2::{Digest, Sha256};
+
+// ============================================================================
+// Hash Functions
+// ============================================================================
+
+/// SHA-256 digest as 64 hex digits
+pub fn sha256(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "sha256", span)?;
+    Ok(Value::string(to_hex(&Sha256::digest(s.as_bytes()))))
+}
+
+/// MD5 digest as 32 hex digits
+///
+/// MD5 is not collision resistant; use it for checksums, not security.
+pub fn md5(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "md5", span)?;
+    Ok(Value::string(to_hex(&Md5::digest(s.as_bytes()))))
+}
+
+/// CRC-32 (IEEE) checksum as 8 hex digits
+pub fn crc32(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    let s = single_string(args, "crc32", span)?;
+    let checksum = crc32fast::hash(s.as_bytes());
+    Ok(Value::string(to_hex(&checksum.to_be_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(f: fn(&[Value], Span) -> Result<Value, RuntimeError>, input: &str) -> String {
+        match f(&[Value::string(input)], Span::dummy()).unwrap() {
+            Value::String(s) => s.to_string(),
+            other => panic!("Expected string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_crc32_is_zero_padded() {
+        // "a" hashes to 0xe8b7be43; a short checksum must still be 8 digits
+        assert_eq!(digest(crc32, "a"), "e8b7be43");
+        assert_eq!(digest(crc32, "").len(), 8);
+    }
+
+    #[test]
+    fn test_hashes_reject_non_string() {
+        assert!(sha256(&[Value::Number(1.0)], Span::dummy()).is_err());
+        assert!(md5(&[], Span::dummy()).is_err());
+    }
+}
@@ -12,21 +12,22 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-/// Extract the hostname from a URL string for security checking.
-fn extract_host(url: &str) -> Option<String> {
-    // Strip scheme (http:// or https://)
-    let rest = url
-        .strip_prefix("https://")
-        .or_else(|| url.strip_prefix("http://"))?;
-    // Take everything before the first '/' or end of string
-    let host_and_port = rest.split('/').next()?;
-    // Strip port if present
-    let host = host_and_port.split(':').next()?;
-    if host.is_empty() {
-        None
-    } else {
-        Some(host.to_string())
-    }
+/// Extract the hostname and port from a URL string for security checking.
+///
+/// Userinfo (`user:pass@`) is ignored, IPv6 hosts are returned without
+/// brackets, and without an explicit port the scheme's default (80 or 443) is
+/// used. Returns `None` for URLs that are not http(s) or have no host.
+fn extract_host(url: &str) -> Option<(String, u16)> {
+    let parsed = url::Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = match parsed.host()? {
+        url::Host::Domain(domain) => domain.to_string(),
+        url::Host::Ipv4(addr) => addr.to_string(),
+        url::Host::Ipv6(addr) => addr.to_string(),
+    };
+    Some((host, parsed.port_or_known_default()?))
 }
 
 /// HTTP Request configuration
@@ -589,14 +590,19 @@ pub fn http_send(
 
     // Enforce network permission before making the request
     let url = request.build_url();
-    if let Some(host) = extract_host(&url) {
-        security
-            .check_network(&host)
-            .map_err(|e| RuntimeError::TypeError {
-                msg: format!("httpSend: {}", e),
-                span,
-            })?;
-    }
+    let Some((host, port)) = extract_host(&url) else {
+        // Unparseable URL — deny
+        return Err(RuntimeError::TypeError {
+            msg: format!("httpSend: network access denied: invalid URL '{}'", url),
+            span,
+        });
+    };
+    security
+        .check_network(&host, port)
+        .map_err(|e| RuntimeError::TypeError {
+            msg: format!("httpSend: {}", e),
+            span,
+        })?;
 
     // Build reqwest client
     let client = match reqwest::blocking::Client::builder()
@@ -1224,7 +1230,7 @@ pub fn http_check_permission(
 
     let url = expect_string(&args[0], "url", span)?;
     let allowed = match extract_host(&url) {
-        Some(host) => security.check_network(&host).is_ok(),
+        Some((host, port)) => security.check_network(&host, port).is_ok(),
         None => false, // Unparseable URL — deny
     };
     Ok(Value::Bool(allowed))
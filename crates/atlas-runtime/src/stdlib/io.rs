@@ -175,6 +175,174 @@ pub fn append_file(
     Ok(Value::Null)
 }
 
+/// Read file as an array of lines
+///
+/// Checks read permission. Reads through a buffered reader so the full file
+/// contents are never held alongside the split array. Line terminators (`\n`
+/// or `\r\n`) are stripped and a trailing newline does not produce an empty
+/// final element.
+pub fn read_lines(
+    args: &[Value],
+    span: Span,
+    security: &SecurityContext,
+) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("readLines", 1, args.len(), span));
+    }
+
+    let path_str = match &args[0] {
+        Value::String(s) => s.as_ref(),
+        _ => return Err(stdlib_arg_error("readLines", "string", &args[0], span)),
+    };
+
+    let path = PathBuf::from(path_str);
+    let abs_path = path.canonicalize().map_err(|e| RuntimeError::IoError {
+        message: format!("Failed to resolve path '{}': {}", path_str, e),
+        span,
+    })?;
+
+    // Check permission
+    security.check_filesystem_read(&abs_path).map_err(|_| {
+        RuntimeError::FilesystemPermissionDenied {
+            operation: "file read".to_string(),
+            path: abs_path.display().to_string(),
+            span,
+        }
+    })?;
+
+    // Read file line by line
+    use std::io::BufRead;
+    let file = fs::File::open(&abs_path).map_err(|e| RuntimeError::IoError {
+        message: format!("Failed to open file '{}': {}", abs_path.display(), e),
+        span,
+    })?;
+
+    let lines = std::io::BufReader::new(file)
+        .lines()
+        .map(|line| {
+            line.map(Value::string).map_err(|e| RuntimeError::IoError {
+                message: format!("Failed to read file '{}': {}", abs_path.display(), e),
+                span,
+            })
+        })
+        .collect::<Result<Vec<Value>, RuntimeError>>()?;
+
+    Ok(Value::array(lines))
+}
+
+/// Read entire file as raw bytes
+///
+/// Checks read permission. Returns an array of numbers (0-255), one per byte,
+/// so binary files that are not valid UTF-8 can be processed.
+pub fn read_file_bytes(
+    args: &[Value],
+    span: Span,
+    security: &SecurityContext,
+) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("readFileBytes", 1, args.len(), span));
+    }
+
+    let path_str = match &args[0] {
+        Value::String(s) => s.as_ref(),
+        _ => return Err(stdlib_arg_error("readFileBytes", "string", &args[0], span)),
+    };
+
+    let path = PathBuf::from(path_str);
+    let abs_path = path.canonicalize().map_err(|e| RuntimeError::IoError {
+        message: format!("Failed to resolve path '{}': {}", path_str, e),
+        span,
+    })?;
+
+    // Check permission
+    security.check_filesystem_read(&abs_path).map_err(|_| {
+        RuntimeError::FilesystemPermissionDenied {
+            operation: "file read".to_string(),
+            path: abs_path.display().to_string(),
+            span,
+        }
+    })?;
+
+    // Read file
+    let bytes = fs::read(&abs_path).map_err(|e| RuntimeError::IoError {
+        message: format!("Failed to read file '{}': {}", abs_path.display(), e),
+        span,
+    })?;
+
+    Ok(Value::array(
+        bytes.into_iter().map(|b| Value::Number(b as f64)).collect(),
+    ))
+}
+
+/// Write raw bytes to file (create or overwrite)
+///
+/// Checks write permission. Every element must be an integer in 0-255.
+pub fn write_file_bytes(
+    args: &[Value],
+    span: Span,
+    security: &SecurityContext,
+) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("writeFileBytes", 2, args.len(), span));
+    }
+
+    let path_str = match &args[0] {
+        Value::String(s) => s.as_ref(),
+        _ => return Err(stdlib_arg_error("writeFileBytes", "string", &args[0], span)),
+    };
+
+    let bytes = match &args[1] {
+        Value::Array(arr) => arr
+            .as_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, v)| match v {
+                Value::Number(n) if n.fract() == 0.0 && (0.0..=255.0).contains(n) => Ok(*n as u8),
+                _ => Err(RuntimeError::InvalidStdlibArgument {
+                    msg: format!(
+                        "writeFileBytes(): byte at index {} must be an integer in 0-255, got {}",
+                        i, v
+                    ),
+                    span,
+                }),
+            })
+            .collect::<Result<Vec<u8>, RuntimeError>>()?,
+        _ => return Err(stdlib_arg_error("writeFileBytes", "array", &args[1], span)),
+    };
+
+    let path = PathBuf::from(path_str);
+
+    // Check permission (same logic as write_file)
+    let check_path = if path.exists() {
+        path.canonicalize().map_err(|e| RuntimeError::IoError {
+            message: format!("Failed to resolve path '{}': {}", path_str, e),
+            span,
+        })?
+    } else {
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        parent.canonicalize().map_err(|e| RuntimeError::IoError {
+            message: format!("Failed to resolve parent path: {}", e),
+            span,
+        })?
+    };
+
+    security.check_filesystem_write(&check_path).map_err(|_| {
+        RuntimeError::FilesystemPermissionDenied {
+            operation: "file write".to_string(),
+            path: check_path.display().to_string(),
+            span,
+        }
+    })?;
+
+    // Write file
+    fs::write(&path, bytes).map_err(|e| RuntimeError::IoError {
+        message: format!("Failed to write file '{}': {}", path_str, e),
+        span,
+    })?;
+
+    Ok(Value::Null)
+}
+
 /// Check if file or directory exists
 ///
 /// No permission check needed - just checks existence without reading.
@@ -209,7 +209,7 @@ pub fn json_as_string(args: &[Value], span: Span) -> Result<Value, RuntimeError>
             _ => Err(RuntimeError::TypeError {
                 msg: format!(
                     "Cannot extract string from JSON value of type '{}'",
-                    json_type_name(json.as_ref())
+                    json.type_name()
                 ),
                 span,
             }),
@@ -235,7 +235,7 @@ pub fn json_as_number(args: &[Value], span: Span) -> Result<Value, RuntimeError>
             _ => Err(RuntimeError::TypeError {
                 msg: format!(
                     "Cannot extract number from JSON value of type '{}'",
-                    json_type_name(json.as_ref())
+                    json.type_name()
                 ),
                 span,
             }),
@@ -261,7 +261,7 @@ pub fn json_as_bool(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
             _ => Err(RuntimeError::TypeError {
                 msg: format!(
                     "Cannot extract bool from JSON value of type '{}'",
-                    json_type_name(json.as_ref())
+                    json.type_name()
                 ),
                 span,
             }),
@@ -290,16 +290,130 @@ pub fn json_is_null(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     }
 }
 
-/// Helper: Get type name of JsonValue for error messages
-fn json_type_name(json: &JsonValue) -> &'static str {
-    match json {
-        JsonValue::Null => "null",
-        JsonValue::Bool(_) => "bool",
-        JsonValue::Number(_) => "number",
-        JsonValue::String(_) => "string",
-        JsonValue::Array(_) => "array",
-        JsonValue::Object(_) => "object",
+/// Look up a nested JsonValue by dotted path
+///
+/// Segments are object keys or array indices, e.g. `"users.0.name"`.
+/// Returns Some(json) on a hit, None if any segment is missing.
+pub fn json_path(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("jsonPath", 2, args.len(), span));
+    }
+
+    let json = match &args[0] {
+        Value::JsonValue(json) => json,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                msg: "jsonPath() requires json as first argument".to_string(),
+                span,
+            })
+        }
+    };
+    let path = match &args[1] {
+        Value::String(s) => s.as_ref(),
+        _ => {
+            return Err(RuntimeError::TypeError {
+                msg: "jsonPath() requires string path as second argument".to_string(),
+                span,
+            })
+        }
+    };
+
+    Ok(Value::Option(json.get_path(path).map(|value| {
+        Box::new(Value::JsonValue(Arc::new(value.clone())))
+    })))
+}
+
+/// Validate a JsonValue against a schema
+///
+/// The schema is a json value: a type name such as `"string"`, an object of
+/// required keys, or a one-element array describing every item.
+/// Returns Ok(true) if the value matches, Err(message) naming the path of the
+/// first offending field otherwise.
+pub fn json_validate(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("jsonValidate", 2, args.len(), span));
+    }
+
+    match (&args[0], &args[1]) {
+        (Value::JsonValue(json), Value::JsonValue(schema)) => {
+            Ok(Value::Result(match json.validate(schema) {
+                Ok(()) => Ok(Box::new(Value::Bool(true))),
+                Err(msg) => Err(Box::new(Value::string(msg))),
+            }))
+        }
+        _ => Err(RuntimeError::TypeError {
+            msg: "jsonValidate() requires json value and json schema arguments".to_string(),
+            span,
+        }),
+    }
+}
+
+/// Split a json object into `[key, value]` pairs, sorted by key
+///
+/// Returns an array of json values. Returns error if the value is not a
+/// json object.
+pub fn entries(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("entries", 1, args.len(), span));
     }
+
+    let json = match &args[0] {
+        Value::JsonValue(json) => json,
+        other => {
+            return Err(RuntimeError::TypeError {
+                msg: format!("entries() requires json object, got {}", other.type_name()),
+                span,
+            })
+        }
+    };
+    let pairs = json.entries().ok_or_else(|| RuntimeError::TypeError {
+        msg: format!(
+            "entries() requires json object, got json {}",
+            json.type_name()
+        ),
+        span,
+    })?;
+    Ok(Value::array(
+        pairs
+            .into_iter()
+            .map(|pair| Value::JsonValue(Arc::new(pair)))
+            .collect(),
+    ))
+}
+
+/// Build a json object from `[key, value]` pairs, the inverse of `entries`
+///
+/// Accepts an array of pairs or a json array of pairs, so the output of
+/// `entries` can be passed back directly. Later pairs overwrite earlier ones
+/// with the same key. Returns error naming the first malformed pair.
+pub fn from_entries(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("fromEntries", 1, args.len(), span));
+    }
+
+    let pairs = match &args[0] {
+        Value::Array(arr) => arr
+            .as_slice()
+            .iter()
+            .map(|pair| to_json_value(pair, span))
+            .collect::<Result<Vec<_>, _>>()?,
+        Value::JsonValue(json) if json.is_array() => json.as_array().cloned().unwrap_or_default(),
+        other => {
+            return Err(RuntimeError::TypeError {
+                msg: format!(
+                    "fromEntries() requires array of pairs, got {}",
+                    other.type_name()
+                ),
+                span,
+            })
+        }
+    };
+    JsonValue::from_entries(&pairs)
+        .map(|obj| Value::JsonValue(Arc::new(obj)))
+        .map_err(|msg| RuntimeError::InvalidStdlibArgument {
+            msg: format!("fromEntries(): {}", msg),
+            span,
+        })
 }
 
 // ============================================================================
@@ -327,6 +441,43 @@ fn serde_to_atlas_json(value: serde_json::Value) -> JsonValue {
     }
 }
 
+/// Convert an Atlas Value into a JsonValue
+///
+/// Supports null, bool, number, string, arrays of those, and existing json values.
+/// Returns error for any other value type.
+pub fn to_json_value(value: &Value, span: Span) -> Result<JsonValue, RuntimeError> {
+    match value {
+        Value::Null => Ok(JsonValue::Null),
+        Value::Bool(b) => Ok(JsonValue::Bool(*b)),
+        Value::Number(n) => Ok(JsonValue::Number(*n)),
+        Value::Int(n) => Ok(JsonValue::Number(*n as f64)),
+        Value::String(s) => Ok(JsonValue::String(s.as_ref().clone())),
+        Value::Array(arr) => arr
+            .as_slice()
+            .iter()
+            .map(|v| to_json_value(v, span))
+            .collect::<Result<Vec<_>, _>>()
+            .map(JsonValue::Array),
+        Value::Map(map) => map
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), to_json_value(v, span)?)))
+            .collect::<Result<_, RuntimeError>>()
+            .map(JsonValue::Object),
+        Value::Set(set) => set
+            .inner()
+            .sorted_keys()
+            .iter()
+            .map(|k| to_json_value(&k.to_value(), span))
+            .collect::<Result<Vec<_>, _>>()
+            .map(JsonValue::Array),
+        Value::JsonValue(json) => Ok(json.as_ref().clone()),
+        other => Err(RuntimeError::TypeError {
+            msg: format!("Cannot convert {} to json", other.type_name()),
+            span,
+        }),
+    }
+}
+
 /// Convert Atlas Value to JSON string with circular reference detection
 fn value_to_json(
     value: &Value,
@@ -351,6 +502,8 @@ fn value_to_json(
                 Ok(n.to_string())
             }
         }
+        // Written digit-for-digit so large ints keep their precision
+        Value::Int(n) => Ok(n.to_string()),
         Value::String(s) => {
             // Use serde_json to properly escape the string
             Ok(serde_json::to_string(s.as_ref()).unwrap())
@@ -376,6 +529,31 @@ fn value_to_json(
             let elements = elements?;
             Ok(format!("[{}]", elements.join(",")))
         }
+        Value::Map(map) => {
+            // Keys are emitted in sorted order for deterministic output
+            let entries = map
+                .sorted_entries()
+                .into_iter()
+                .map(|(k, v)| {
+                    Ok(format!(
+                        "{}:{}",
+                        serde_json::to_string(k).unwrap(),
+                        value_to_json(v, visited, span)?
+                    ))
+                })
+                .collect::<Result<Vec<String>, RuntimeError>>()?;
+            Ok(format!("{{{}}}", entries.join(",")))
+        }
+        Value::Set(set) => {
+            // Sets serialize as arrays in sorted order
+            let elements = set
+                .inner()
+                .sorted_keys()
+                .iter()
+                .map(|k| value_to_json(&k.to_value(), visited, span))
+                .collect::<Result<Vec<String>, RuntimeError>>()?;
+            Ok(format!("[{}]", elements.join(",")))
+        }
         Value::JsonValue(json) => {
             // Serialize JsonValue directly
             json_value_to_string(json, span)
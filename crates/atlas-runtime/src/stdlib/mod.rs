@@ -6,8 +6,10 @@ pub mod async_primitives;
 pub mod collections;
 pub mod compression;
 pub mod datetime;
+pub mod encoding;
 pub mod fs;
 pub mod future;
+pub mod hash;
 pub mod http;
 pub mod io;
 pub mod json;
@@ -20,6 +22,7 @@ pub mod string;
 pub mod test;
 pub mod types;
 
+use crate::security::limits;
 use crate::security::SecurityContext;
 use crate::value::{RuntimeError, Value};
 use std::collections::HashMap;
@@ -201,12 +204,13 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let result = string::char_at(s, index, span)?;
             Ok(Value::string(result))
         });
-        m.insert("repeat", |args, span, _, _| {
+        m.insert("repeat", |args, span, security, _| {
             if args.len() != 2 {
                 return Err(stdlib_arity_error("repeat", 2, args.len(), span));
             }
             let s = extract_string(&args[0], "repeat", span)?;
             let count = extract_number(&args[1], "repeat", span)?;
+            limits::check_allocation(security, s.len().saturating_mul(count as usize), span)?;
             let result = string::repeat(s, count, span)?;
             Ok(Value::string(result))
         });
@@ -219,26 +223,49 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let replacement = extract_string(&args[2], "replace", span)?;
             Ok(Value::string(string::replace(s, search, replacement)))
         });
-        m.insert("padStart", |args, span, _, _| {
+        m.insert("padStart", |args, span, security, _| {
             if args.len() != 3 {
                 return Err(stdlib_arity_error("padStart", 3, args.len(), span));
             }
             let s = extract_string(&args[0], "padStart", span)?;
             let length = extract_number(&args[1], "padStart", span)?;
             let fill = extract_string(&args[2], "padStart", span)?;
+            limits::check_allocation(security, length as usize, span)?;
             let result = string::pad_start(s, length, fill, span)?;
             Ok(Value::string(result))
         });
-        m.insert("padEnd", |args, span, _, _| {
+        m.insert("padEnd", |args, span, security, _| {
             if args.len() != 3 {
                 return Err(stdlib_arity_error("padEnd", 3, args.len(), span));
             }
             let s = extract_string(&args[0], "padEnd", span)?;
             let length = extract_number(&args[1], "padEnd", span)?;
             let fill = extract_string(&args[2], "padEnd", span)?;
+            limits::check_allocation(security, length as usize, span)?;
             let result = string::pad_end(s, length, fill, span)?;
             Ok(Value::string(result))
         });
+        m.insert("formatNumber", |args, span, _, _| {
+            if args.len() != 2 {
+                return Err(stdlib_arity_error("formatNumber", 2, args.len(), span));
+            }
+            let n = extract_number(&args[0], "formatNumber", span)?;
+            let decimals = extract_number(&args[1], "formatNumber", span)?;
+            Ok(Value::string(string::format_number(n, decimals, span)?))
+        });
+        m.insert("formatWithSeparators", |args, span, _, _| {
+            if args.len() != 2 {
+                return Err(stdlib_arity_error(
+                    "formatWithSeparators",
+                    2,
+                    args.len(),
+                    span,
+                ));
+            }
+            let n = extract_number(&args[0], "formatWithSeparators", span)?;
+            let sep = extract_string(&args[1], "formatWithSeparators", span)?;
+            Ok(Value::string(string::format_with_separators(n, sep, span)?))
+        });
         m.insert("startsWith", |args, span, _, _| {
             if args.len() != 2 {
                 return Err(stdlib_arity_error("startsWith", 2, args.len(), span));
@@ -260,11 +287,12 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
         // Array functions
         // ====================================================================
         // Method-call variants (prefixed with "array") — used by arr.method() syntax
-        m.insert("arrayPush", |args, span, _, _| {
+        m.insert("arrayPush", |args, span, security, _| {
             if args.len() != 2 {
                 return Err(stdlib_arity_error("arrayPush", 2, args.len(), span));
             }
             let arr = extract_array(&args[0], "arrayPush", span)?;
+            limits::check_array_allocation(security, arr.len() + 1, span)?;
             Ok(array::push(&arr, args[1].clone()))
         });
         m.insert("arrayPop", |args, span, _, _| {
@@ -281,11 +309,12 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let arr = extract_array(&args[0], "arrayShift", span)?;
             array::shift(&arr, span)
         });
-        m.insert("arrayUnshift", |args, span, _, _| {
+        m.insert("arrayUnshift", |args, span, security, _| {
             if args.len() != 2 {
                 return Err(stdlib_arity_error("arrayUnshift", 2, args.len(), span));
             }
             let arr = extract_array(&args[0], "arrayUnshift", span)?;
+            limits::check_array_allocation(security, arr.len() + 1, span)?;
             Ok(array::unshift(&arr, args[1].clone()))
         });
         m.insert("arrayReverse", |args, span, _, _| {
@@ -302,6 +331,13 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let arr = extract_array(&args[0], "arraySort", span)?;
             Ok(array::sort_natural(&arr))
         });
+        m.insert("sortNumeric", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("sortNumeric", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "sortNumeric", span)?;
+            array::sort_numeric(&arr, span)
+        });
         // Free-function variants (legacy names)
         m.insert("pop", |args, span, _, _| {
             if args.len() != 1 {
@@ -317,11 +353,12 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let arr = extract_array(&args[0], "shift", span)?;
             array::shift(&arr, span)
         });
-        m.insert("unshift", |args, span, _, _| {
+        m.insert("unshift", |args, span, security, _| {
             if args.len() != 2 {
                 return Err(stdlib_arity_error("unshift", 2, args.len(), span));
             }
             let arr = extract_array(&args[0], "unshift", span)?;
+            limits::check_array_allocation(security, arr.len() + 1, span)?;
             Ok(array::unshift(&arr, args[1].clone()))
         });
         m.insert("reverse", |args, span, _, _| {
@@ -331,19 +368,28 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let arr = extract_array(&args[0], "reverse", span)?;
             Ok(array::reverse(&arr))
         });
-        m.insert("concat", |args, span, _, _| {
+        m.insert("concat", |args, span, security, _| {
             if args.len() != 2 {
                 return Err(stdlib_arity_error("concat", 2, args.len(), span));
             }
             let arr1 = extract_array(&args[0], "concat", span)?;
             let arr2 = extract_array(&args[1], "concat", span)?;
+            limits::check_array_allocation(security, arr1.len() + arr2.len(), span)?;
             Ok(array::concat(&arr1, &arr2))
         });
-        m.insert("flatten", |args, span, _, _| {
+        m.insert("flatten", |args, span, security, _| {
             if args.len() != 1 {
                 return Err(stdlib_arity_error("flatten", 1, args.len(), span));
             }
             let arr = extract_array(&args[0], "flatten", span)?;
+            let len = arr
+                .iter()
+                .map(|elem| match elem {
+                    Value::Array(nested) => nested.len(),
+                    _ => 1,
+                })
+                .sum();
+            limits::check_array_allocation(security, len, span)?;
             array::flatten(&arr, span)
         });
         m.insert("arrayIndexOf", |args, span, _, _| {
@@ -376,6 +422,88 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             let end = extract_number(&args[2], "slice", span)?;
             array::slice(&arr, start, end, span)
         });
+        m.insert("chunk", |args, span, security, _| {
+            if args.len() != 2 {
+                return Err(stdlib_arity_error("chunk", 2, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "chunk", span)?;
+            limits::check_array_allocation(security, arr.len(), span)?;
+            let size = extract_number(&args[1], "chunk", span)?;
+            array::chunk(&arr, size, span)
+        });
+        m.insert("unique", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("unique", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "unique", span)?;
+            Ok(array::unique(&arr))
+        });
+        m.insert("range", |args, span, security, _| {
+            if args.len() < 2 || args.len() > 3 {
+                return Err(stdlib_arity_error("range", 2, args.len(), span));
+            }
+            let start = extract_number(&args[0], "range", span)?;
+            let end = extract_number(&args[1], "range", span)?;
+            let step = match args.get(2) {
+                Some(step) => extract_number(step, "range", span)?,
+                None => 1.0,
+            };
+            let len = array::range_len(start, end, step, span)?;
+            limits::check_array_allocation(security, len, span)?;
+            array::range(start, end, step, span)
+        });
+        m.insert("zip", |args, span, security, _| {
+            if args.len() != 2 {
+                return Err(stdlib_arity_error("zip", 2, args.len(), span));
+            }
+            let a = extract_array(&args[0], "zip", span)?;
+            let b = extract_array(&args[1], "zip", span)?;
+            limits::check_array_allocation(security, a.len().min(b.len()), span)?;
+            array::zip(&a, &b, span)
+        });
+        m.insert("enumerate", |args, span, security, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("enumerate", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "enumerate", span)?;
+            limits::check_array_allocation(security, arr.len(), span)?;
+            array::enumerate(&arr, span)
+        });
+        m.insert("sum", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("sum", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "sum", span)?;
+            Ok(Value::Number(array::sum(&arr, span)?))
+        });
+        m.insert("product", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("product", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "product", span)?;
+            Ok(Value::Number(array::product(&arr, span)?))
+        });
+        m.insert("average", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("average", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "average", span)?;
+            Ok(Value::Number(array::average(&arr, span)?))
+        });
+        m.insert("minOf", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("minOf", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "minOf", span)?;
+            Ok(Value::Number(array::min_of(&arr, span)?))
+        });
+        m.insert("maxOf", |args, span, _, _| {
+            if args.len() != 1 {
+                return Err(stdlib_arity_error("maxOf", 1, args.len(), span));
+            }
+            let arr = extract_array(&args[0], "maxOf", span)?;
+            Ok(Value::Number(array::max_of(&arr, span)?))
+        });
 
         // ====================================================================
         // Math functions
@@ -411,6 +539,25 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
         m.insert("jsonAsNumber", |a, s, _, _| json::json_as_number(a, s));
         m.insert("jsonAsBool", |a, s, _, _| json::json_as_bool(a, s));
         m.insert("jsonIsNull", |a, s, _, _| json::json_is_null(a, s));
+        m.insert("jsonPath", |a, s, _, _| json::json_path(a, s));
+        m.insert("jsonValidate", |a, s, _, _| json::json_validate(a, s));
+        m.insert("entries", |a, s, _, _| json::entries(a, s));
+        m.insert("fromEntries", |a, s, _, _| json::from_entries(a, s));
+
+        // ====================================================================
+        // Encoding functions
+        // ====================================================================
+        m.insert("encodeBase64", |a, s, _, _| encoding::encode_base64(a, s));
+        m.insert("decodeBase64", |a, s, _, _| encoding::decode_base64(a, s));
+        m.insert("encodeHex", |a, s, _, _| encoding::encode_hex(a, s));
+        m.insert("decodeHex", |a, s, _, _| encoding::decode_hex(a, s));
+
+        // ====================================================================
+        // Hash functions
+        // ====================================================================
+        m.insert("sha256", |a, s, _, _| hash::sha256(a, s));
+        m.insert("md5", |a, s, _, _| hash::md5(a, s));
+        m.insert("crc32", |a, s, _, _| hash::crc32(a, s));
 
         // ====================================================================
         // Type checking functions
@@ -433,6 +580,8 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
         // ====================================================================
         m.insert("toString", |a, s, _, _| types::to_string(a, s));
         m.insert("toNumber", |a, s, _, _| types::to_number(a, s));
+        m.insert("toInt", |a, s, _, _| types::to_int(a, s));
+        m.insert("toFloat", |a, s, _, _| types::to_float(a, s));
         m.insert("toBool", |a, s, _, _| types::to_bool(a, s));
         m.insert("parseInt", |a, s, _, _| types::parse_int(a, s));
         m.insert("parseFloat", |a, s, _, _| types::parse_float(a, s));
@@ -555,6 +704,11 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
         m.insert("readFile", |a, s, sc, _| io::read_file(a, s, sc));
         m.insert("writeFile", |a, s, sc, _| io::write_file(a, s, sc));
         m.insert("appendFile", |a, s, sc, _| io::append_file(a, s, sc));
+        m.insert("readLines", |a, s, sc, _| io::read_lines(a, s, sc));
+        m.insert("readFileBytes", |a, s, sc, _| io::read_file_bytes(a, s, sc));
+        m.insert("writeFileBytes", |a, s, sc, _| {
+            io::write_file_bytes(a, s, sc)
+        });
         m.insert("fileExists", |a, s, sc, _| io::file_exists(a, s, sc));
         m.insert("readDir", |a, s, sc, _| io::read_dir(a, s, sc));
         m.insert("createDir", |a, s, sc, _| io::create_dir(a, s, sc));
@@ -596,6 +750,35 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
         m.insert("reflect_get_function_arity", |a, s, _, _| {
             reflect::get_function_arity_fn(a, s)
         });
+        m.insert("functionName", |a, s, _, _| reflect::function_name_fn(a, s));
+        m.insert("functionArity", |a, s, _, _| {
+            reflect::function_arity_fn(a, s)
+        });
+
+        // ====================================================================
+        // Map functions (map literals)
+        // ====================================================================
+        m.insert("mapGet", |a, s, _, _| collections::map::get(a, s));
+        m.insert("mapSet", |a, s, _, _| collections::map::set(a, s));
+        m.insert("mapHas", |a, s, _, _| collections::map::has(a, s));
+        m.insert("mapKeys", |a, s, _, _| collections::map::keys(a, s));
+        m.insert("mapValues", |a, s, _, _| collections::map::values(a, s));
+
+        // ====================================================================
+        // Set functions
+        // ====================================================================
+        m.insert("setOf", |a, s, _, _| collections::set::of(a, s));
+        m.insert("setAdd", |a, s, _, _| collections::set::add(a, s));
+        m.insert("setHas", |a, s, _, _| collections::set::has(a, s));
+        m.insert("setRemove", |a, s, _, _| collections::set::remove(a, s));
+        m.insert("setUnion", |a, s, _, _| collections::set::union(a, s));
+        m.insert("setIntersect", |a, s, _, _| {
+            collections::set::intersect(a, s)
+        });
+        m.insert("setDifference", |a, s, _, _| {
+            collections::set::difference(a, s)
+        });
+        m.insert("setToArray", |a, s, _, _| collections::set::to_array(a, s));
 
         // ====================================================================
         // HashMap functions
@@ -731,11 +914,16 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
             regex::regex_match_indices(a, s)
         });
         m.insert("regexTest", |a, s, _, _| regex::regex_test(a, s));
+        m.insert("regexMatch", |a, s, _, _| regex::regex_match(a, s));
+        m.insert("regexCapture", |a, s, _, _| regex::regex_capture(a, s));
 
         // ====================================================================
         // DateTime functions
         // ====================================================================
         m.insert("dateTimeNow", |a, s, _, _| datetime::date_time_now(a, s));
+        m.insert("now", |a, s, sc, _| datetime::now(a, s, sc));
+        m.insert("formatTime", |a, s, _, _| datetime::format_time(a, s));
+        m.insert("parseTime", |a, s, _, _| datetime::parse_time(a, s));
         m.insert("dateTimeFromTimestamp", |a, s, _, _| {
             datetime::date_time_from_timestamp(a, s)
         });
@@ -927,6 +1115,8 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
         m.insert("futureCatch", |a, s, _, _| future::future_catch(a, s));
         m.insert("futureAll", |a, s, _, _| future::future_all_fn(a, s));
         m.insert("futureRace", |a, s, _, _| future::future_race_fn(a, s));
+        m.insert("awaitAll", |a, s, _, _| future::await_all_fn(a, s));
+        m.insert("tryAwaitAll", |a, s, _, _| future::try_await_all_fn(a, s));
 
         // ====================================================================
         // Async I/O functions
@@ -995,6 +1185,9 @@ fn builtin_registry() -> &'static HashMap<&'static str, BuiltinFn> {
 
         // Async primitives - timeout
         m.insert("timeout", |a, s, _, _| async_primitives::timeout_fn(a, s));
+        m.insert("withTimeout", |a, s, _, _| {
+            async_primitives::with_timeout_fn(a, s)
+        });
 
         // Async primitives - mutex
         m.insert("asyncMutex", |a, s, _, _| {
@@ -1551,38 +1744,56 @@ pub fn is_builtin(name: &str) -> bool {
     builtin_registry().contains_key(name)
 }
 
+/// Array intrinsics, which are handled in the interpreter/VM because they
+/// call back into user code
+const ARRAY_INTRINSICS: &[&str] = &[
+    "map",
+    "filter",
+    "reduce",
+    "forEach",
+    "find",
+    "findIndex",
+    "flatMap",
+    "some",
+    "every",
+    "sort",
+    "sortBy",
+    "uniqueBy",
+    "groupBy",
+    "partition",
+    // Result intrinsics (callback-based)
+    "result_map",
+    "result_map_err",
+    "result_and_then",
+    "result_or_else",
+    "retry",
+    // HashMap intrinsics (callback-based)
+    "hashMapForEach",
+    "hashMapMap",
+    "hashMapFilter",
+    // HashSet intrinsics (callback-based)
+    "hashSetForEach",
+    "hashSetMap",
+    "hashSetFilter",
+    // Regex intrinsics (callback-based)
+    "regexReplaceWith",
+    "regexReplaceAllWith",
+];
+
 /// Check if a function name is an array intrinsic (handled in interpreter/VM)
 pub fn is_array_intrinsic(name: &str) -> bool {
-    matches!(
-        name,
-        "map"
-            | "filter"
-            | "reduce"
-            | "forEach"
-            | "find"
-            | "findIndex"
-            | "flatMap"
-            | "some"
-            | "every"
-            | "sort"
-            | "sortBy"
-            // Result intrinsics (callback-based)
-            | "result_map"
-            | "result_map_err"
-            | "result_and_then"
-            | "result_or_else"
-            // HashMap intrinsics (callback-based)
-            | "hashMapForEach"
-            | "hashMapMap"
-            | "hashMapFilter"
-            // HashSet intrinsics (callback-based)
-            | "hashSetForEach"
-            | "hashSetMap"
-            | "hashSetFilter"
-            // Regex intrinsics (callback-based)
-            | "regexReplaceWith"
-            | "regexReplaceAllWith"
-    )
+    ARRAY_INTRINSICS.contains(&name)
+}
+
+/// Names of all builtins and array intrinsics, sorted
+pub fn builtin_names() -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = builtin_registry()
+        .keys()
+        .chain(ARRAY_INTRINSICS)
+        .copied()
+        .collect();
+    names.sort_unstable();
+    names
 }
 
 /// Extract string from value
@@ -1640,14 +1851,14 @@ pub fn call_builtin(
 
 /// Print a value to the configured output writer.
 ///
-/// Only accepts string, number, bool, or null per stdlib specification.
+/// Only accepts string, number, int, bool, or null per stdlib specification.
 pub fn print(
     value: &Value,
     span: crate::span::Span,
     output: &OutputWriter,
 ) -> Result<(), RuntimeError> {
     match value {
-        Value::String(_) | Value::Number(_) | Value::Bool(_) | Value::Null => {
+        Value::String(_) | Value::Number(_) | Value::Int(_) | Value::Bool(_) | Value::Null => {
             let mut w = output.lock().unwrap();
             writeln!(w, "{}", value.to_display_string()).map_err(|_| RuntimeError::TypeError {
                 msg: "write failed".into(),
@@ -1657,7 +1868,7 @@ pub fn print(
         }
         _ => Err(stdlib_arg_error(
             "print",
-            "string, number, bool, or null",
+            "string, number, int, bool, or null",
             value,
             span,
         )),
@@ -1678,13 +1889,15 @@ pub fn len(value: &Value, span: crate::span::Span) -> Result<f64, RuntimeError>
 
 /// Convert a value to a string
 ///
-/// Only accepts number, bool, or null per stdlib specification.
+/// Only accepts number, int, bool, or null per stdlib specification.
 pub fn str(value: &Value, span: crate::span::Span) -> Result<String, RuntimeError> {
     match value {
-        Value::Number(_) | Value::Bool(_) | Value::Null => Ok(value.to_display_string()),
+        Value::Number(_) | Value::Int(_) | Value::Bool(_) | Value::Null => {
+            Ok(value.to_display_string())
+        }
         _ => Err(stdlib_arg_error(
             "str",
-            "number, bool, or null",
+            "number, int, bool, or null",
             value,
             span,
         )),
@@ -1849,6 +2062,14 @@ mod tests {
         assert!(!is_builtin("foo"));
     }
 
+    #[test]
+    fn test_builtin_names_include_intrinsics() {
+        let names = builtin_names();
+        assert!(names.contains(&"split"));
+        assert!(names.contains(&"map"));
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
     #[test]
     fn test_registry_completeness() {
         // Exhaustive list of all builtin names — the single source of truth.
@@ -1875,6 +2096,8 @@ mod tests {
             "replace",
             "padStart",
             "padEnd",
+            "formatNumber",
+            "formatWithSeparators",
             "startsWith",
             "endsWith",
             // Array functions (method-call variants)
@@ -1884,6 +2107,7 @@ mod tests {
             "arrayUnshift",
             "arrayReverse",
             "arraySort",
+            "sortNumeric",
             // Array functions (free-function variants)
             "pop",
             "shift",
@@ -1895,6 +2119,16 @@ mod tests {
             "arrayLastIndexOf",
             "arrayIncludes",
             "slice",
+            "chunk",
+            "unique",
+            "range",
+            "zip",
+            "enumerate",
+            "sum",
+            "product",
+            "average",
+            "minOf",
+            "maxOf",
             // Math functions
             "abs",
             "floor",
@@ -1924,6 +2158,19 @@ mod tests {
             "jsonAsNumber",
             "jsonAsBool",
             "jsonIsNull",
+            "jsonPath",
+            "jsonValidate",
+            "entries",
+            "fromEntries",
+            // Encoding functions
+            "encodeBase64",
+            "decodeBase64",
+            "encodeHex",
+            "decodeHex",
+            // Hash functions
+            "sha256",
+            "md5",
+            "crc32",
             // Type checking functions
             "typeof",
             "isString",
@@ -1940,6 +2187,8 @@ mod tests {
             // Type conversion functions
             "toString",
             "toNumber",
+            "toInt",
+            "toFloat",
             "toBool",
             "parseInt",
             "parseFloat",
@@ -1964,6 +2213,9 @@ mod tests {
             "readFile",
             "writeFile",
             "appendFile",
+            "readLines",
+            "readFileBytes",
+            "writeFileBytes",
             "fileExists",
             "readDir",
             "createDir",
@@ -1984,6 +2236,23 @@ mod tests {
             "reflect_deep_equals",
             "reflect_get_function_name",
             "reflect_get_function_arity",
+            "functionName",
+            "functionArity",
+            // Map functions
+            "mapGet",
+            "mapSet",
+            "mapHas",
+            "mapKeys",
+            "mapValues",
+            // Set functions
+            "setOf",
+            "setAdd",
+            "setHas",
+            "setRemove",
+            "setUnion",
+            "setIntersect",
+            "setDifference",
+            "setToArray",
             // HashMap functions
             "hashMapNew",
             "hashMapFromEntries",
@@ -2046,8 +2315,13 @@ mod tests {
             "regexSplitN",
             "regexMatchIndices",
             "regexTest",
+            "regexMatch",
+            "regexCapture",
             // DateTime functions
             "dateTimeNow",
+            "now",
+            "formatTime",
+            "parseTime",
             "dateTimeFromTimestamp",
             "dateTimeFromComponents",
             "dateTimeParseIso",
@@ -2132,6 +2406,8 @@ mod tests {
             "futureCatch",
             "futureAll",
             "futureRace",
+            "awaitAll",
+            "tryAwaitAll",
             "futureIsPending",
             "futureIsResolved",
             "futureIsRejected",
@@ -2166,6 +2442,7 @@ mod tests {
             "interval",
             // Async primitives - timeout
             "timeout",
+            "withTimeout",
             // Async primitives - mutex
             "asyncMutex",
             "asyncMutexGet",
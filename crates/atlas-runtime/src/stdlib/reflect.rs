@@ -2,12 +2,12 @@
 //!
 //! Provides runtime type inspection and value introspection capabilities.
 
-use super::stdlib_arity_error;
+use super::{stdlib_arg_error, stdlib_arity_error};
 use crate::reflect::{
     get_type_name, get_value_type_info, is_callable, is_primitive_value, same_type,
 };
 use crate::span::Span;
-use crate::value::{RuntimeError, Value};
+use crate::value::{FunctionRef, RuntimeError, Value};
 
 /// Get the type name of a value as a string
 ///
@@ -275,6 +275,57 @@ pub fn get_function_arity_fn(args: &[Value], span: Span) -> Result<Value, Runtim
     }
 }
 
+fn extract_function_ref<'a>(
+    func_name: &str,
+    value: &'a Value,
+    span: Span,
+) -> Result<&'a FunctionRef, RuntimeError> {
+    match value {
+        Value::Function(func) => Ok(func),
+        Value::Closure(closure) => Ok(&closure.func),
+        Value::Builtin(_) | Value::NativeFunction(_) => Err(RuntimeError::TypeError {
+            msg: format!("{}() has no metadata for builtin functions", func_name),
+            span,
+        }),
+        _ => Err(stdlib_arg_error(func_name, "function", value, span)),
+    }
+}
+
+/// Get the declared name of a function, closure, or builtin
+///
+/// # Atlas Usage
+/// ```atlas
+/// fn foo(a: number, b: number, c: number) -> number { return a + b + c; }
+/// print(functionName(foo));  // "foo"
+/// ```
+pub fn function_name_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("functionName", 1, args.len(), span));
+    }
+
+    if let Value::Builtin(name) = &args[0] {
+        return Ok(Value::string(name.as_ref()));
+    }
+    let func = extract_function_ref("functionName", &args[0], span)?;
+    Ok(Value::string(&func.name))
+}
+
+/// Get the number of declared parameters of a function or closure
+///
+/// # Atlas Usage
+/// ```atlas
+/// fn foo(a: number, b: number, c: number) -> number { return a + b + c; }
+/// print(functionArity(foo));  // 3
+/// ```
+pub fn function_arity_fn(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("functionArity", 1, args.len(), span));
+    }
+
+    let func = extract_function_ref("functionArity", &args[0], span)?;
+    Ok(Value::Number(func.arity as f64))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,6 +12,12 @@
 //! - `regexFindAll(regex: Regex, text: string) -> Array<HashMap>` - Find all matches
 //! - `regexCaptures(regex: Regex, text: string) -> Option<Array>` - Extract capture groups by index
 //! - `regexCapturesNamed(regex: Regex, text: string) -> Option<HashMap>` - Extract named capture groups
+//!
+//! Pattern-string conveniences (compile on each call; malformed patterns raise AT0150):
+//! - `regexMatch(text: string, pattern: string) -> bool` - Test if pattern matches
+//! - `regexCapture(text: string, pattern: string) -> string[]` - Capture groups of the first match
+//! - `regexReplace(text: string, pattern: string, replacement: string) -> string`
+//! - `regexSplit(text: string, pattern: string) -> string[]`
 
 use super::stdlib_arity_error;
 use crate::span::Span;
@@ -394,13 +400,14 @@ pub fn regex_captures_named(args: &[Value], span: Span) -> Result<Value, Runtime
 /// let result = regexReplace(pattern, "a1b2c3", "[$1]");
 /// // Returns "a[1]b2c3"
 /// ```
+///
+/// Also accepts `regexReplace(text, pattern, replacement)` with a pattern string.
 pub fn regex_replace(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     if args.len() != 3 {
         return Err(stdlib_arity_error("regexReplace", 3, args.len(), span));
     }
 
-    let regex = expect_regex(&args[0], span)?;
-    let text = expect_string(&args[1], span, "text")?;
+    let (regex, text) = regex_and_text(&args[0], &args[1], span)?;
     let replacement = expect_string(&args[2], span, "replacement")?;
 
     let result = regex.replace(text, replacement);
@@ -465,13 +472,14 @@ pub fn regex_replace_all(args: &[Value], span: Span) -> Result<Value, RuntimeErr
 /// let pattern = unwrap(regexNew(","));
 /// regexSplit(pattern, "a,b,,c") // Returns ["a", "b", "", "c"]
 /// ```
+///
+/// Also accepts `regexSplit(text, pattern)` with a pattern string.
 pub fn regex_split(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     if args.len() != 2 {
         return Err(stdlib_arity_error("regexSplit", 2, args.len(), span));
     }
 
-    let regex = expect_regex(&args[0], span)?;
-    let text = expect_string(&args[1], span, "text")?;
+    let (regex, text) = regex_and_text(&args[0], &args[1], span)?;
 
     let parts: Vec<Value> = regex.split(text).map(Value::string).collect();
 
@@ -587,10 +595,105 @@ pub fn regex_test(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     }
 }
 
+/// Convenience function: test a pattern string against text
+///
+/// # Arguments
+/// - `text`: The string to test
+/// - `pattern`: The regex pattern string
+///
+/// # Returns
+/// - `true` if the pattern matches anywhere in the text
+/// - Runtime error (AT0150) if the pattern is malformed
+///
+/// # Example
+/// ```atlas
+/// regexMatch("order-42", "\\d+") // Returns true
+/// ```
+pub fn regex_match(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("regexMatch", 2, args.len(), span));
+    }
+
+    let text = expect_string(&args[0], span, "text")?;
+    let pattern = expect_string(&args[1], span, "pattern")?;
+    let regex = compile_pattern(pattern, span)?;
+
+    Ok(Value::Bool(regex.is_match(text)))
+}
+
+/// Convenience function: capture groups of the first match
+///
+/// # Arguments
+/// - `text`: The string to match against
+/// - `pattern`: The regex pattern string
+///
+/// # Returns
+/// - Array of the capture groups (excluding the full match) of the first match;
+///   groups that did not participate are empty strings
+/// - Empty array if there is no match
+/// - Runtime error (AT0150) if the pattern is malformed
+///
+/// # Example
+/// ```atlas
+/// regexCapture("2024-06-01", "(\\d+)-(\\d+)") // Returns ["2024", "06"]
+/// ```
+pub fn regex_capture(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(stdlib_arity_error("regexCapture", 2, args.len(), span));
+    }
+
+    let text = expect_string(&args[0], span, "text")?;
+    let pattern = expect_string(&args[1], span, "pattern")?;
+    let regex = compile_pattern(pattern, span)?;
+
+    let groups = match regex.captures(text) {
+        Some(caps) => caps
+            .iter()
+            .skip(1)
+            .map(|group| Value::string(group.map_or("", |m| m.as_str())))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Ok(Value::array(groups))
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Compile a pattern string, mapping syntax errors to `RuntimeError::InvalidRegex`
+fn compile_pattern(pattern: &str, span: Span) -> Result<Regex, RuntimeError> {
+    Regex::new(pattern).map_err(|err| RuntimeError::InvalidRegex {
+        pattern: pattern.to_string(),
+        message: err.to_string(),
+        span,
+    })
+}
+
+/// Resolve the leading arguments of a function that takes either
+/// `(regex, text, ...)` or `(text, pattern, ...)`
+fn regex_and_text<'a>(
+    first: &'a Value,
+    second: &'a Value,
+    span: Span,
+) -> Result<(std::borrow::Cow<'a, Regex>, &'a str), RuntimeError> {
+    match first {
+        Value::String(text) => {
+            let pattern = expect_string(second, span, "pattern")?;
+            Ok((
+                std::borrow::Cow::Owned(compile_pattern(pattern, span)?),
+                text.as_ref(),
+            ))
+        }
+        _ => {
+            let regex = expect_regex(first, span)?;
+            let text = expect_string(second, span, "text")?;
+            Ok((std::borrow::Cow::Borrowed(regex), text))
+        }
+    }
+}
+
 /// Expect a string argument
 fn expect_string<'a>(
     value: &'a Value,
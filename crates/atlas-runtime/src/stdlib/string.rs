@@ -294,6 +294,111 @@ pub fn pad_end(s: &str, length: f64, fill: &str, span: Span) -> Result<String, R
     Ok(result)
 }
 
+/// Largest `decimals` accepted by `formatNumber`
+const MAX_FORMAT_DECIMALS: f64 = 100.0;
+
+/// Format a number with exactly `decimals` fractional digits
+///
+/// Rounds half away from zero on the number's shortest decimal form, so
+/// `1.005` with 2 decimals gives "1.01". A result that rounds to zero is
+/// never negative. Returns error if `decimals` is not an integer in
+/// 0..=100 or the number is not finite.
+pub fn format_number(n: f64, decimals: f64, span: Span) -> Result<String, RuntimeError> {
+    if !(0.0..=MAX_FORMAT_DECIMALS).contains(&decimals) || decimals.fract() != 0.0 {
+        return Err(RuntimeError::InvalidStdlibArgument {
+            msg: format!(
+                "formatNumber(): decimals must be an integer between 0 and {}, got {}",
+                MAX_FORMAT_DECIMALS, decimals
+            ),
+            span,
+        });
+    }
+    require_finite(n, "formatNumber", span)?;
+
+    let decimals = decimals as usize;
+    let repr = format!("{}", n.abs());
+    let (int_part, frac_part) = repr.split_once('.').unwrap_or((&repr, ""));
+
+    let mut digits: Vec<u8> = int_part.bytes().collect();
+    digits.extend(
+        frac_part
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(decimals),
+    );
+
+    if frac_part
+        .as_bytes()
+        .get(decimals)
+        .is_some_and(|d| *d >= b'5')
+    {
+        // Propagate the carry; an all-nines prefix grows by one digit
+        match digits.iter().rposition(|d| *d != b'9') {
+            Some(i) => {
+                digits[i] += 1;
+                digits[i + 1..].fill(b'0');
+            }
+            None => {
+                digits.fill(b'0');
+                digits.insert(0, b'1');
+            }
+        }
+    }
+
+    let int_len = digits.len() - decimals;
+    let mut result = String::with_capacity(digits.len() + 2);
+    if n < 0.0 && digits.iter().any(|d| *d != b'0') {
+        result.push('-');
+    }
+    result.extend(digits[..int_len].iter().map(|d| *d as char));
+    if decimals > 0 {
+        result.push('.');
+        result.extend(digits[int_len..].iter().map(|d| *d as char));
+    }
+    Ok(result)
+}
+
+/// Group the integer digits of a number in threes using `separator`
+///
+/// The fractional part, if any, is kept as-is: `1234567.5` with "," gives
+/// "1,234,567.5". Returns error if the number is not finite.
+pub fn format_with_separators(n: f64, separator: &str, span: Span) -> Result<String, RuntimeError> {
+    require_finite(n, "formatWithSeparators", span)?;
+
+    let repr = format!("{}", n.abs());
+    let (int_part, frac_part) = match repr.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (repr.as_str(), None),
+    };
+
+    let mut result = String::with_capacity(repr.len() + int_part.len() / 3 * separator.len() + 1);
+    if n < 0.0 {
+        result.push('-');
+    }
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            result.push_str(separator);
+        }
+        result.push(digit);
+    }
+    if let Some(frac_part) = frac_part {
+        result.push('.');
+        result.push_str(frac_part);
+    }
+    Ok(result)
+}
+
+fn require_finite(n: f64, func_name: &str, span: Span) -> Result<(), RuntimeError> {
+    if n.is_finite() {
+        Ok(())
+    } else {
+        Err(RuntimeError::InvalidStdlibArgument {
+            msg: format!("{}(): value must be finite, got {}", func_name, n),
+            span,
+        })
+    }
+}
+
 /// Check if string starts with prefix
 pub fn starts_with(s: &str, prefix: &str) -> bool {
     s.starts_with(prefix)
@@ -410,6 +515,35 @@ mod tests {
         assert_eq!(result, "500");
     }
 
+    #[test]
+    fn test_format_number_carry_and_sign() {
+        let fmt = |n: f64, d: f64| format_number(n, d, Span::dummy()).unwrap();
+        assert_eq!(fmt(9.995, 2.0), "10.00");
+        assert_eq!(fmt(999.5, 0.0), "1000");
+        assert_eq!(fmt(1.005, 2.0), "1.01");
+        assert_eq!(fmt(-0.001, 2.0), "0.00");
+        assert_eq!(fmt(-2.5, 0.0), "-3");
+        assert_eq!(fmt(1e21, 1.0), "1000000000000000000000.0");
+    }
+
+    #[test]
+    fn test_format_number_invalid_arguments() {
+        assert!(format_number(1.0, -1.0, Span::dummy()).is_err());
+        assert!(format_number(1.0, 1.5, Span::dummy()).is_err());
+        assert!(format_number(1.0, 101.0, Span::dummy()).is_err());
+        assert!(format_number(f64::NAN, 2.0, Span::dummy()).is_err());
+    }
+
+    #[test]
+    fn test_format_with_separators_group_boundaries() {
+        let fmt = |n: f64| format_with_separators(n, ",", Span::dummy()).unwrap();
+        assert_eq!(fmt(0.0), "0");
+        assert_eq!(fmt(999.0), "999");
+        assert_eq!(fmt(1000.0), "1,000");
+        assert_eq!(fmt(123456.0), "123,456");
+        assert!(format_with_separators(f64::INFINITY, ",", Span::dummy()).is_err());
+    }
+
     #[test]
     fn test_starts_with() {
         assert!(starts_with("hello", "he"));
@@ -196,7 +196,7 @@ pub fn result_err(res: &Value, span: Span) -> Result<Value, RuntimeError> {
 
 /// Get the type name of a value as a string
 ///
-/// Returns one of: "null", "bool", "number", "string", "array", "function",
+/// Returns one of: "null", "bool", "number", "int", "string", "array", "function",
 /// "json", "option", "result"
 pub fn type_of(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     if args.len() != 1 {
@@ -207,8 +207,11 @@ pub fn type_of(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
         Value::Null => "null",
         Value::Bool(_) => "bool",
         Value::Number(_) => "number",
+        Value::Int(_) => "int",
         Value::String(_) => "string",
         Value::Array(_) => "array",
+        Value::Map(_) => "map",
+        Value::Set(_) => "set",
         Value::Function(_) => "function",
         Value::Builtin(_) => "builtin",
         Value::NativeFunction(_) => "function",
@@ -438,6 +441,7 @@ pub fn has_tag(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
 /// - null → "null"
 /// - bool → "true" or "false"
 /// - number → string representation (e.g., "42", "3.14")
+/// - int → decimal digits (e.g., "9007199254740993")
 /// - string → same string (identity)
 /// - array → "[Array]"
 /// - function → "[Function]"
@@ -468,8 +472,11 @@ pub fn to_string(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
                 n.to_string()
             }
         }
+        Value::Int(n) => n.to_string(),
         Value::String(s) => s.as_ref().clone(),
         Value::Array(_) => "[Array]".to_string(),
+        Value::Map(_) => "[Map]".to_string(),
+        Value::Set(_) => "[Set]".to_string(),
         Value::Function(_) => "[Function]".to_string(),
         Value::Builtin(name) => format!("[Builtin {}]", name),
         Value::NativeFunction(_) => "[Native Function]".to_string(),
@@ -502,6 +509,7 @@ pub fn to_string(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
 ///
 /// Conversion rules:
 /// - number → same number (identity)
+/// - int → nearest number (may lose precision beyond 2^53)
 /// - bool → true=1.0, false=0.0
 /// - string → parsed as number (error if invalid)
 /// - null → error
@@ -513,6 +521,7 @@ pub fn to_number(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
 
     match &args[0] {
         Value::Number(n) => Ok(Value::Number(*n)),
+        Value::Int(n) => Ok(Value::Number(*n as f64)),
         Value::Bool(b) => Ok(Value::Number(if *b { 1.0 } else { 0.0 })),
         Value::String(s) => {
             let trimmed = s.trim();
@@ -537,11 +546,74 @@ pub fn to_number(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     }
 }
 
+/// Convert value to int
+///
+/// Conversion rules:
+/// - int → same int (identity)
+/// - number → same value if it is a whole number within the int range (error otherwise)
+/// - string → parsed as a decimal integer, without going through number (error if invalid)
+/// - other types → error
+pub fn to_int(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("toInt", 1, args.len(), span));
+    }
+
+    match &args[0] {
+        Value::Int(n) => Ok(Value::Int(*n)),
+        Value::Number(n) => {
+            // i64::MAX is not representable as f64; 2^63 is the first value out of range
+            let in_range = *n >= i64::MIN as f64 && *n < 9_223_372_036_854_775_808.0;
+            if n.fract() != 0.0 || !in_range {
+                return Err(RuntimeError::TypeError {
+                    msg: format!("Cannot convert {} to int", n),
+                    span,
+                });
+            }
+            Ok(Value::Int(*n as i64))
+        }
+        Value::String(s) => {
+            s.trim()
+                .parse::<i64>()
+                .map(Value::Int)
+                .map_err(|_| RuntimeError::TypeError {
+                    msg: format!("Cannot convert '{}' to int", s),
+                    span,
+                })
+        }
+        _ => Err(RuntimeError::TypeError {
+            msg: format!("Cannot convert {} to int", type_name(&args[0])),
+            span,
+        }),
+    }
+}
+
+/// Convert int to number
+///
+/// Conversion rules:
+/// - int → nearest number (may lose precision beyond 2^53)
+/// - number → same number (identity)
+/// - other types → error
+pub fn to_float(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(stdlib_arity_error("toFloat", 1, args.len(), span));
+    }
+
+    match &args[0] {
+        Value::Int(n) => Ok(Value::Number(*n as f64)),
+        Value::Number(n) => Ok(Value::Number(*n)),
+        _ => Err(RuntimeError::TypeError {
+            msg: format!("Cannot convert {} to number", type_name(&args[0])),
+            span,
+        }),
+    }
+}
+
 /// Convert value to boolean
 ///
 /// Conversion rules (JavaScript-like):
 /// - bool → same bool (identity)
 /// - number → false if 0, NaN; true otherwise
+/// - int → false if 0; true otherwise
 /// - string → false if empty; true otherwise
 /// - null → false
 /// - array, function, json, option, result → true
@@ -553,9 +625,12 @@ pub fn to_bool(args: &[Value], span: Span) -> Result<Value, RuntimeError> {
     let bool_value = match &args[0] {
         Value::Bool(b) => *b,
         Value::Number(n) => !(*n == 0.0 || n.is_nan()),
+        Value::Int(n) => *n != 0,
         Value::String(s) => !s.is_empty(),
         Value::Null => false,
         Value::Array(_)
+        | Value::Map(_)
+        | Value::Set(_)
         | Value::Function(_)
         | Value::Builtin(_)
         | Value::NativeFunction(_)
@@ -700,8 +775,11 @@ fn type_name(value: &Value) -> &str {
         Value::Null => "null",
         Value::Bool(_) => "bool",
         Value::Number(_) => "number",
+        Value::Int(_) => "int",
         Value::String(_) => "string",
         Value::Array(_) => "array",
+        Value::Map(_) => "map",
+        Value::Set(_) => "set",
         Value::Function(_) => "function",
         Value::Builtin(_) => "builtin",
         Value::NativeFunction(_) => "function",
@@ -738,8 +816,11 @@ fn value_to_display_string(value: &Value) -> String {
                 n.to_string()
             }
         }
+        Value::Int(n) => n.to_string(),
         Value::String(s) => format!("\"{}\"", s),
         Value::Array(_) => "[Array]".to_string(),
+        Value::Map(_) => "[Map]".to_string(),
+        Value::Set(_) => "[Set]".to_string(),
         Value::Function(_) => "[Function]".to_string(),
         Value::Builtin(name) => format!("[Builtin {}]", name),
         Value::NativeFunction(_) => "[Native Function]".to_string(),
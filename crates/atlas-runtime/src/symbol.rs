@@ -2,7 +2,7 @@
 
 use crate::ast::TypeAliasDecl;
 use crate::span::Span;
-use crate::types::Type;
+use crate::types::{Type, TypeParamDef};
 use std::collections::{HashMap, HashSet};
 
 /// Symbol information
@@ -46,6 +46,11 @@ pub struct SymbolTable {
     type_aliases: HashMap<String, TypeAliasDecl>,
     /// Exported type alias names
     type_alias_exports: HashSet<String>,
+    /// Symbols forwarded from other modules (`export { x } from "..."`).
+    /// Exported but not visible to lookups in this module.
+    re_exports: HashMap<String, Symbol>,
+    /// Type aliases forwarded from other modules
+    re_exported_type_aliases: HashMap<String, TypeAliasDecl>,
 }
 
 impl SymbolTable {
@@ -56,6 +61,8 @@ impl SymbolTable {
             functions: HashMap::new(),
             type_aliases: HashMap::new(),
             type_alias_exports: HashSet::new(),
+            re_exports: HashMap::new(),
+            re_exported_type_aliases: HashMap::new(),
         };
 
         // Add prelude builtins
@@ -219,6 +226,22 @@ impl SymbolTable {
                 return_type: Box::new(Type::String),
             },
         );
+        table.define_builtin(
+            "formatNumber",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Number, Type::Number],
+                return_type: Box::new(Type::String),
+            },
+        );
+        table.define_builtin(
+            "formatWithSeparators",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Number, Type::String],
+                return_type: Box::new(Type::String),
+            },
+        );
         table.define_builtin(
             "startsWith",
             Type::Function {
@@ -325,6 +348,110 @@ impl SymbolTable {
                 return_type: Box::new(Type::Array(Box::new(Type::Unknown))),
             },
         );
+        table.define_builtin(
+            "chunk",
+            Type::Function {
+                type_params: vec![TypeParamDef {
+                    name: "T".to_string(),
+                    bound: None,
+                    trait_bounds: vec![],
+                }],
+                params: vec![
+                    Type::Array(Box::new(Type::TypeParameter {
+                        name: "T".to_string(),
+                    })),
+                    Type::Number,
+                ],
+                return_type: Box::new(Type::Array(Box::new(Type::Array(Box::new(
+                    Type::TypeParameter {
+                        name: "T".to_string(),
+                    },
+                ))))),
+            },
+        );
+
+        table.define_builtin(
+            "unique",
+            Type::Function {
+                type_params: vec![TypeParamDef {
+                    name: "T".to_string(),
+                    bound: None,
+                    trait_bounds: vec![],
+                }],
+                params: vec![Type::Array(Box::new(Type::TypeParameter {
+                    name: "T".to_string(),
+                }))],
+                return_type: Box::new(Type::Array(Box::new(Type::TypeParameter {
+                    name: "T".to_string(),
+                }))),
+            },
+        );
+        table.define_builtin(
+            "zip",
+            Type::Function {
+                type_params: vec![
+                    TypeParamDef {
+                        name: "A".to_string(),
+                        bound: None,
+                        trait_bounds: vec![],
+                    },
+                    TypeParamDef {
+                        name: "B".to_string(),
+                        bound: None,
+                        trait_bounds: vec![],
+                    },
+                ],
+                params: vec![
+                    Type::Array(Box::new(Type::TypeParameter {
+                        name: "A".to_string(),
+                    })),
+                    Type::Array(Box::new(Type::TypeParameter {
+                        name: "B".to_string(),
+                    })),
+                ],
+                return_type: Box::new(Type::Array(Box::new(Type::JsonValue))),
+            },
+        );
+        table.define_builtin(
+            "enumerate",
+            Type::Function {
+                type_params: vec![TypeParamDef {
+                    name: "T".to_string(),
+                    bound: None,
+                    trait_bounds: vec![],
+                }],
+                params: vec![Type::Array(Box::new(Type::TypeParameter {
+                    name: "T".to_string(),
+                }))],
+                return_type: Box::new(Type::Array(Box::new(Type::JsonValue))),
+            },
+        );
+        // Int conversions
+        table.define_builtin(
+            "toInt",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Union(vec![Type::Int, Type::Number, Type::String])],
+                return_type: Box::new(Type::Int),
+            },
+        );
+        table.define_builtin(
+            "toFloat",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Union(vec![Type::Int, Type::Number])],
+                return_type: Box::new(Type::Number),
+            },
+        );
+
+        table.define_builtin(
+            "sortNumeric",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Array(Box::new(Type::Number))],
+                return_type: Box::new(Type::Array(Box::new(Type::Number))),
+            },
+        );
 
         // Array intrinsics (callback-based) - use Unknown for generic array support
         table.define_builtin(
@@ -494,6 +621,314 @@ impl SymbolTable {
             },
         );
 
+        table.define_builtin(
+            "uniqueBy",
+            Type::Function {
+                type_params: vec![
+                    TypeParamDef {
+                        name: "T".to_string(),
+                        bound: None,
+                        trait_bounds: vec![],
+                    },
+                    TypeParamDef {
+                        name: "K".to_string(),
+                        // Keys are compared by value, so only primitives are allowed
+                        bound: Some(Box::new(Type::Union(vec![
+                            Type::Number,
+                            Type::String,
+                            Type::Bool,
+                        ]))),
+                        trait_bounds: vec![],
+                    },
+                ],
+                params: vec![
+                    Type::Array(Box::new(Type::TypeParameter {
+                        name: "T".to_string(),
+                    })),
+                    Type::Function {
+                        type_params: vec![],
+                        params: vec![Type::TypeParameter {
+                            name: "T".to_string(),
+                        }],
+                        return_type: Box::new(Type::TypeParameter {
+                            name: "K".to_string(),
+                        }),
+                    },
+                ],
+                return_type: Box::new(Type::Array(Box::new(Type::TypeParameter {
+                    name: "T".to_string(),
+                }))),
+            },
+        );
+
+        table.define_builtin(
+            "groupBy",
+            Type::Function {
+                type_params: vec![TypeParamDef {
+                    name: "T".to_string(),
+                    bound: None,
+                    trait_bounds: vec![],
+                }],
+                params: vec![
+                    Type::Array(Box::new(Type::TypeParameter {
+                        name: "T".to_string(),
+                    })),
+                    Type::Function {
+                        type_params: vec![],
+                        params: vec![Type::TypeParameter {
+                            name: "T".to_string(),
+                        }],
+                        return_type: Box::new(Type::String),
+                    },
+                ],
+                return_type: Box::new(Type::JsonValue),
+            },
+        );
+
+        table.define_builtin(
+            "partition",
+            Type::Function {
+                type_params: vec![TypeParamDef {
+                    name: "T".to_string(),
+                    bound: None,
+                    trait_bounds: vec![],
+                }],
+                params: vec![
+                    Type::Array(Box::new(Type::TypeParameter {
+                        name: "T".to_string(),
+                    })),
+                    Type::Function {
+                        type_params: vec![],
+                        params: vec![Type::TypeParameter {
+                            name: "T".to_string(),
+                        }],
+                        return_type: Box::new(Type::Bool),
+                    },
+                ],
+                return_type: Box::new(Type::Array(Box::new(Type::Array(Box::new(
+                    Type::TypeParameter {
+                        name: "T".to_string(),
+                    },
+                ))))),
+            },
+        );
+
+        // retry(action, attempts): calls action until it returns Ok
+        let result_te = || Type::Generic {
+            name: "Result".to_string(),
+            type_args: vec![
+                Type::TypeParameter {
+                    name: "T".to_string(),
+                },
+                Type::TypeParameter {
+                    name: "E".to_string(),
+                },
+            ],
+        };
+        table.define_builtin(
+            "retry",
+            Type::Function {
+                type_params: ["T", "E"]
+                    .into_iter()
+                    .map(|name| TypeParamDef {
+                        name: name.to_string(),
+                        bound: None,
+                        trait_bounds: vec![],
+                    })
+                    .collect(),
+                params: vec![
+                    Type::Function {
+                        type_params: vec![],
+                        params: vec![],
+                        return_type: Box::new(result_te()),
+                    },
+                    Type::Number,
+                ],
+                return_type: Box::new(result_te()),
+            },
+        );
+
+        // Map functions (map literals)
+        let map_t = || {
+            Type::map(Type::TypeParameter {
+                name: "T".to_string(),
+            })
+        };
+        let type_param_t = || {
+            vec![TypeParamDef {
+                name: "T".to_string(),
+                bound: None,
+                trait_bounds: vec![],
+            }]
+        };
+        table.define_builtin(
+            "mapGet",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![map_t(), Type::String],
+                return_type: Box::new(Type::Generic {
+                    name: "Option".to_string(),
+                    type_args: vec![Type::TypeParameter {
+                        name: "T".to_string(),
+                    }],
+                }),
+            },
+        );
+        table.define_builtin(
+            "mapSet",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![
+                    map_t(),
+                    Type::String,
+                    Type::TypeParameter {
+                        name: "T".to_string(),
+                    },
+                ],
+                return_type: Box::new(map_t()),
+            },
+        );
+        table.define_builtin(
+            "mapHas",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![map_t(), Type::String],
+                return_type: Box::new(Type::Bool),
+            },
+        );
+        table.define_builtin(
+            "mapKeys",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![map_t()],
+                return_type: Box::new(Type::Array(Box::new(Type::String))),
+            },
+        );
+        table.define_builtin(
+            "mapValues",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![map_t()],
+                return_type: Box::new(Type::Array(Box::new(Type::TypeParameter {
+                    name: "T".to_string(),
+                }))),
+            },
+        );
+
+        // Set functions (elements must be hashable primitives)
+        let t = || Type::TypeParameter {
+            name: "T".to_string(),
+        };
+        let set_t = || Type::set(t());
+        let primitive_t = || {
+            vec![TypeParamDef {
+                name: "T".to_string(),
+                bound: Some(Box::new(Type::Union(vec![
+                    Type::Number,
+                    Type::String,
+                    Type::Bool,
+                ]))),
+                trait_bounds: vec![],
+            }]
+        };
+        let set_signatures = [
+            ("setOf", vec![Type::Array(Box::new(t()))], set_t()),
+            ("setAdd", vec![set_t(), t()], set_t()),
+            ("setHas", vec![set_t(), t()], Type::Bool),
+            ("setRemove", vec![set_t(), t()], set_t()),
+            ("setUnion", vec![set_t(), set_t()], set_t()),
+            ("setIntersect", vec![set_t(), set_t()], set_t()),
+            ("setDifference", vec![set_t(), set_t()], set_t()),
+            ("setToArray", vec![set_t()], Type::Array(Box::new(t()))),
+        ];
+        for (name, params, return_type) in set_signatures {
+            table.define_builtin(
+                name,
+                Type::Function {
+                    type_params: primitive_t(),
+                    params,
+                    return_type: Box::new(return_type),
+                },
+            );
+        }
+
+        // Futures
+        table.define_builtin(
+            "futureResolve",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![t()],
+                return_type: Box::new(Type::future(t())),
+            },
+        );
+        for name in ["sleep", "timer"] {
+            table.define_builtin(
+                name,
+                Type::Function {
+                    type_params: vec![],
+                    params: vec![Type::Number],
+                    return_type: Box::new(Type::future(Type::Null)),
+                },
+            );
+        }
+
+        // Future combinators that block until their inputs settle
+        let future_array_t = || Type::Array(Box::new(Type::future(t())));
+        table.define_builtin(
+            "awaitAll",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![future_array_t()],
+                return_type: Box::new(Type::Array(Box::new(t()))),
+            },
+        );
+        table.define_builtin(
+            "tryAwaitAll",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![future_array_t()],
+                return_type: Box::new(Type::Generic {
+                    name: "Result".to_string(),
+                    type_args: vec![Type::Array(Box::new(t())), Type::Unknown],
+                }),
+            },
+        );
+
+        table.define_builtin(
+            "withTimeout",
+            Type::Function {
+                type_params: type_param_t(),
+                params: vec![Type::future(t()), Type::Number],
+                return_type: Box::new(Type::Generic {
+                    name: "Result".to_string(),
+                    type_args: vec![t(), Type::String],
+                }),
+            },
+        );
+
+        // Function reflection (`() -> unknown` accepts any function)
+        let any_function = || Type::Function {
+            type_params: vec![],
+            params: vec![],
+            return_type: Box::new(Type::Unknown),
+        };
+        table.define_builtin(
+            "functionName",
+            Type::Function {
+                type_params: vec![],
+                params: vec![any_function()],
+                return_type: Box::new(Type::String),
+            },
+        );
+        table.define_builtin(
+            "functionArity",
+            Type::Function {
+                type_params: vec![],
+                params: vec![any_function()],
+                return_type: Box::new(Type::Number),
+            },
+        );
+
         // Math functions - Basic Operations
         table.define_builtin(
             "abs",
@@ -727,6 +1162,28 @@ impl SymbolTable {
                 return_type: Box::new(Type::Bool),
             },
         );
+        table.define_builtin(
+            "jsonPath",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::JsonValue, Type::String],
+                return_type: Box::new(Type::Generic {
+                    name: "Option".to_string(),
+                    type_args: vec![Type::JsonValue],
+                }),
+            },
+        );
+        table.define_builtin(
+            "jsonValidate",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::JsonValue, Type::JsonValue],
+                return_type: Box::new(Type::Generic {
+                    name: "Result".to_string(),
+                    type_args: vec![Type::Bool, Type::String],
+                }),
+            },
+        );
         table.define_builtin(
             "prettifyJSON",
             Type::Function {
@@ -743,6 +1200,77 @@ impl SymbolTable {
                 return_type: Box::new(Type::String),
             },
         );
+        table.define_builtin(
+            "entries",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::JsonValue],
+                return_type: Box::new(Type::Array(Box::new(Type::JsonValue))),
+            },
+        );
+        table.define_builtin(
+            "fromEntries",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Array(Box::new(Type::JsonValue))],
+                return_type: Box::new(Type::JsonValue),
+            },
+        );
+
+        // Encoding and hash functions (all string -> string)
+        for name in [
+            "encodeBase64",
+            "decodeBase64",
+            "encodeHex",
+            "decodeHex",
+            "sha256",
+            "md5",
+            "crc32",
+        ] {
+            table.define_builtin(
+                name,
+                Type::Function {
+                    type_params: vec![],
+                    params: vec![Type::String],
+                    return_type: Box::new(Type::String),
+                },
+            );
+        }
+
+        // Regex pattern-string helpers
+        table.define_builtin(
+            "regexMatch",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::String, Type::String],
+                return_type: Box::new(Type::Bool),
+            },
+        );
+        table.define_builtin(
+            "regexCapture",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::String, Type::String],
+                return_type: Box::new(Type::Array(Box::new(Type::String))),
+            },
+        );
+        // First argument is a compiled Regex or the text (with a pattern string second)
+        table.define_builtin(
+            "regexReplace",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Unknown, Type::String, Type::String],
+                return_type: Box::new(Type::String),
+            },
+        );
+        table.define_builtin(
+            "regexSplit",
+            Type::Function {
+                type_params: vec![],
+                params: vec![Type::Unknown, Type::String],
+                return_type: Box::new(Type::Array(Box::new(Type::String))),
+            },
+        );
 
         table
     }
@@ -792,9 +1320,33 @@ impl SymbolTable {
                     .cloned()
                     .map(|alias| (name.clone(), alias))
             })
+            .chain(
+                self.re_exported_type_aliases
+                    .iter()
+                    .map(|(name, alias)| (name.clone(), alias.clone())),
+            )
             .collect()
     }
 
+    /// Forward a symbol exported by another module
+    ///
+    /// The symbol is included in `get_exports` but is not bound in any scope.
+    pub fn add_re_export(&mut self, symbol: Symbol) {
+        self.re_exports.insert(
+            symbol.name.clone(),
+            Symbol {
+                exported: true,
+                ..symbol
+            },
+        );
+    }
+
+    /// Forward a type alias exported by another module
+    pub fn add_re_exported_type_alias(&mut self, alias: TypeAliasDecl) {
+        self.re_exported_type_aliases
+            .insert(alias.name.name.clone(), alias);
+    }
+
     /// Enter a new scope
     pub fn enter_scope(&mut self) {
         self.scopes.push(HashMap::new());
@@ -907,6 +1459,15 @@ impl SymbolTable {
         self.scopes.len() == 1
     }
 
+    /// Iterate over the names visible from the current scope, including builtins
+    pub fn visible_names(&self) -> impl Iterator<Item = &str> {
+        self.scopes
+            .iter()
+            .flat_map(|scope| scope.keys())
+            .chain(self.functions.keys())
+            .map(String::as_str)
+    }
+
     /// Get all symbols from all scopes and functions
     /// Returns a vector of all symbols in the table
     pub fn all_symbols(&self) -> Vec<Symbol> {
@@ -929,6 +1490,29 @@ impl SymbolTable {
         symbols
     }
 
+    /// Remove a top-level variable, function, or type alias by name
+    ///
+    /// Used by the REPL to forget a file's definitions before reloading it.
+    /// Builtins are never removed. Returns `true` if anything was removed.
+    pub fn remove_top_level(&mut self, name: &str) -> bool {
+        let mut removed = false;
+        if let Some(scope) = self.scopes.first_mut() {
+            removed |= scope.remove(name).is_some();
+        }
+        if self
+            .functions
+            .get(name)
+            .is_some_and(|symbol| symbol.kind != SymbolKind::Builtin)
+        {
+            removed |= self.functions.remove(name).is_some();
+        }
+        if self.type_aliases.remove(name).is_some() {
+            self.type_alias_exports.remove(name);
+            removed = true;
+        }
+        removed
+    }
+
     /// Merge another symbol table into this one (for REPL state persistence)
     ///
     /// Adds new symbols from the other table to the top-level scope.
@@ -975,6 +1559,11 @@ impl SymbolTable {
             }
         }
 
+        // Symbols forwarded from other modules
+        for (name, symbol) in &self.re_exports {
+            exports.insert(name.clone(), symbol.clone());
+        }
+
         exports
     }
 
@@ -1006,6 +1595,37 @@ impl Default for SymbolTable {
     }
 }
 
+/// Type of a builtin that user code may redefine at the top level
+///
+/// These names are common in user code (`var sum = 0;`), so they are kept out
+/// of the prelude and only typed at call sites where nothing else is bound.
+pub fn shadowable_builtin_type(name: &str) -> Option<Type> {
+    match name {
+        "sum" | "product" | "average" | "minOf" | "maxOf" => Some(Type::Function {
+            type_params: vec![],
+            params: vec![Type::Array(Box::new(Type::Number))],
+            return_type: Box::new(Type::Number),
+        }),
+        _ => builtin_overloads(name).into_iter().next(),
+    }
+}
+
+/// Signatures of a shadowable builtin that accepts more than one arity
+///
+/// The typechecker checks a call against the signature whose parameter count
+/// matches the call, falling back to the first one.
+pub fn builtin_overloads(name: &str) -> Vec<Type> {
+    let numbers = |arity: usize| Type::Function {
+        type_params: vec![],
+        params: vec![Type::Number; arity],
+        return_type: Box::new(Type::Array(Box::new(Type::Number))),
+    };
+    match name {
+        "range" => vec![numbers(2), numbers(3)],
+        _ => vec![],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
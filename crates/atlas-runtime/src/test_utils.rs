@@ -1,8 +1,20 @@
 //! Test utilities and helpers
 //!
-//! Shared utilities for testing across the codebase.
+//! Shared utilities for testing across the codebase. Available to other
+//! crates through the `testing` feature.
 
-use crate::diagnostic::{normalizer::normalize_diagnostic_for_testing, Diagnostic};
+use crate::ast::Program;
+use crate::compiler::Compiler;
+use crate::diagnostic::{
+    normalizer::normalize_diagnostic_for_testing, Diagnostic, DiagnosticLevel,
+};
+use crate::interpreter::Interpreter;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use crate::security::SecurityContext;
+use crate::value::{RuntimeError, Value};
+use crate::vm::VM;
+use std::fmt;
 
 /// Normalize diagnostics for golden testing
 ///
@@ -39,6 +51,151 @@ pub fn assert_diagnostics_match_json(actual: &[Diagnostic], expected_json: &str)
     );
 }
 
+// ============================================================================
+// Engine parity
+// ============================================================================
+
+/// Outcome of running a program to completion in one engine
+pub type EngineResult = Result<Value, RuntimeError>;
+
+type Engine = Box<dyn Fn(&Program) -> EngineResult>;
+
+/// Runs a program through several engines and checks they agree
+///
+/// Engines agree when they all return equal values, or all fail with the
+/// same error message (spans are not compared). The interpreter and the VM
+/// are always registered; other engines, such as the JIT, are added with
+/// [`ParityHarness::with_engine`]. Programs run with all permissions granted.
+pub struct ParityHarness {
+    engines: Vec<(String, Engine)>,
+}
+
+/// Engines disagreed on the outcome of a program
+#[derive(Debug)]
+pub struct ParityMismatch {
+    /// The program that was run
+    pub source: String,
+    /// Each engine's name and outcome, in registration order
+    pub outcomes: Vec<(String, EngineResult)>,
+}
+
+impl ParityHarness {
+    /// Create a harness comparing the interpreter with the VM
+    pub fn new() -> Self {
+        Self {
+            engines: Vec::new(),
+        }
+        .with_engine("interpreter", run_interpreter)
+        .with_engine("vm", run_vm)
+    }
+
+    /// Register an additional engine to compare against the others
+    pub fn with_engine(
+        mut self,
+        name: impl Into<String>,
+        engine: impl Fn(&Program) -> EngineResult + 'static,
+    ) -> Self {
+        self.engines.push((name.into(), Box::new(engine)));
+        self
+    }
+
+    /// Run `source` in every engine and return the shared outcome
+    ///
+    /// Panics if the source does not parse, since that is a broken test
+    /// rather than an engine disagreement.
+    pub fn check(&self, source: &str) -> Result<EngineResult, ParityMismatch> {
+        let program = parse_program(source);
+        let outcomes: Vec<(String, EngineResult)> = self
+            .engines
+            .iter()
+            .map(|(name, engine)| (name.clone(), engine(&program)))
+            .collect();
+
+        let agree = outcomes
+            .windows(2)
+            .all(|pair| same_outcome(&pair[0].1, &pair[1].1));
+        if agree {
+            Ok(outcomes.into_iter().next().map_or(Ok(Value::Null), |o| o.1))
+        } else {
+            Err(ParityMismatch {
+                source: source.to_string(),
+                outcomes,
+            })
+        }
+    }
+
+    /// Like [`ParityHarness::check`], but panics on a mismatch
+    pub fn assert(&self, source: &str) -> EngineResult {
+        self.check(source)
+            .unwrap_or_else(|mismatch| panic!("{}", mismatch))
+    }
+}
+
+impl Default for ParityHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ParityMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "engine parity failure for:\n{}", self.source)?;
+        for (name, outcome) in &self.outcomes {
+            match outcome {
+                Ok(value) => writeln!(f, "  {}: Ok({:?})", name, value)?,
+                Err(err) => writeln!(f, "  {}: Err({})", name, err)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Assert the interpreter and the VM agree on `source`, returning the outcome
+pub fn assert_parity(source: &str) -> EngineResult {
+    ParityHarness::new().assert(source)
+}
+
+/// Run a program in the tree-walking interpreter
+pub fn run_interpreter(program: &Program) -> EngineResult {
+    Interpreter::new().eval(program, &SecurityContext::allow_all())
+}
+
+/// Compile a program to bytecode and run it in the VM
+///
+/// Panics if compilation fails.
+pub fn run_vm(program: &Program) -> EngineResult {
+    let bytecode = Compiler::new()
+        .compile(program)
+        .unwrap_or_else(|diags| panic!("VM compilation failed: {:?}", diags));
+    VM::new(bytecode)
+        .run(&SecurityContext::allow_all())
+        .map(|v| v.unwrap_or(Value::Null))
+}
+
+fn parse_program(source: &str) -> Program {
+    let (tokens, lex_diags) = Lexer::new(source).tokenize();
+    let (program, parse_diags) = Parser::new(tokens).parse();
+    let errors: Vec<_> = lex_diags
+        .iter()
+        .chain(&parse_diags)
+        .filter(|d| d.level == DiagnosticLevel::Error)
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "parity source failed to parse: {:?}",
+        errors
+    );
+    program
+}
+
+fn same_outcome(a: &EngineResult, b: &EngineResult) -> bool {
+    match (a, b) {
+        (Ok(a), Ok(b)) => a == b,
+        (Err(a), Err(b)) => a.to_string() == b.to_string(),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +242,49 @@ mod tests {
         assert!(json.contains("\"message\": \"test\""));
         assert!(json.contains("\"file\": \"test.atlas\""));
     }
+
+    #[test]
+    fn test_assert_parity_values() {
+        assert_eq!(assert_parity("1 + 2;"), Ok(Value::Number(3.0)));
+        assert_eq!(
+            assert_parity(r#"let xs = [1, 2, 3]; join(map(xs, str), "-");"#),
+            Ok(Value::string("1-2-3"))
+        );
+    }
+
+    #[test]
+    fn test_assert_parity_matching_errors() {
+        let err = assert_parity("let xs = [1]; xs[5];").unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{}", err);
+    }
+
+    #[test]
+    fn test_parity_detects_divergent_engine() {
+        let harness = ParityHarness::new().with_engine("off-by-one", |program| {
+            run_vm(program).map(|v| match v {
+                Value::Number(n) => Value::Number(n + 1.0),
+                other => other,
+            })
+        });
+
+        let mismatch = harness.check("40 + 2;").unwrap_err();
+        let names: Vec<_> = mismatch.outcomes.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["interpreter", "vm", "off-by-one"]);
+        let report = mismatch.to_string();
+        assert!(report.contains("off-by-one: Ok(Number(43))"), "{}", report);
+
+        // Non-numeric results are untouched, so the engines agree again
+        assert_eq!(
+            harness.check(r#""same";"#).unwrap(),
+            Ok(Value::string("same"))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "engine parity failure")]
+    fn test_assert_panics_on_error_mismatch() {
+        let _ = ParityHarness::new()
+            .with_engine("always-ok", |_| Ok(Value::Null))
+            .assert("let xs = [1]; xs[5];");
+    }
 }
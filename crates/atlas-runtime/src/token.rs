@@ -35,6 +35,12 @@ pub enum TokenKind {
     Number,
     /// String literal ("hello")
     String,
+    /// Leading chunk of an interpolated string (`"Hello ${`)
+    StringInterpStart,
+    /// Chunk between two interpolations (`}, you are ${`)
+    StringInterpMiddle,
+    /// Trailing chunk of an interpolated string (`}!"`)
+    StringInterpEnd,
     /// `true` keyword
     True,
     /// `false` keyword
@@ -69,6 +75,10 @@ pub enum TokenKind {
     Break,
     /// `continue` keyword
     Continue,
+    /// `try` keyword
+    Try,
+    /// `catch` keyword
+    Catch,
 
     // Module system (v0.2+)
     /// `import` keyword
@@ -136,6 +146,8 @@ pub enum TokenKind {
     Ampersand,
     /// `|` (type union)
     Pipe,
+    /// `|>` (pipeline)
+    PipeGreater,
 
     // Compound assignment operators
     /// `+=` (add and assign)
@@ -218,6 +230,8 @@ impl TokenKind {
             "return" => Some(TokenKind::Return),
             "break" => Some(TokenKind::Break),
             "continue" => Some(TokenKind::Continue),
+            "try" => Some(TokenKind::Try),
+            "catch" => Some(TokenKind::Catch),
             "true" => Some(TokenKind::True),
             "false" => Some(TokenKind::False),
             "null" => Some(TokenKind::Null),
@@ -243,6 +257,9 @@ impl TokenKind {
         match self {
             TokenKind::Number => "number",
             TokenKind::String => "string",
+            TokenKind::StringInterpStart => "interpolated string start",
+            TokenKind::StringInterpMiddle => "interpolated string middle",
+            TokenKind::StringInterpEnd => "interpolated string end",
             TokenKind::True => "true",
             TokenKind::False => "false",
             TokenKind::Null => "null",
@@ -259,6 +276,8 @@ impl TokenKind {
             TokenKind::Return => "return",
             TokenKind::Break => "break",
             TokenKind::Continue => "continue",
+            TokenKind::Try => "try",
+            TokenKind::Catch => "catch",
             TokenKind::Import => "import",
             TokenKind::Export => "export",
             TokenKind::From => "from",
@@ -288,6 +307,7 @@ impl TokenKind {
             TokenKind::PipePipe => "||",
             TokenKind::Ampersand => "&",
             TokenKind::Pipe => "|",
+            TokenKind::PipeGreater => "|>",
             TokenKind::PlusEqual => "+=",
             TokenKind::MinusEqual => "-=",
             TokenKind::StarEqual => "*=",
@@ -349,6 +369,8 @@ mod tests {
         assert_eq!(TokenKind::is_keyword("null"), Some(TokenKind::Null));
         assert_eq!(TokenKind::is_keyword("extends"), Some(TokenKind::Extends));
         assert_eq!(TokenKind::is_keyword("is"), Some(TokenKind::Is));
+        assert_eq!(TokenKind::is_keyword("try"), Some(TokenKind::Try));
+        assert_eq!(TokenKind::is_keyword("catch"), Some(TokenKind::Catch));
     }
 
     #[test]
@@ -426,6 +448,7 @@ mod tests {
             TokenKind::GreaterEqual,
             TokenKind::AmpAmp,
             TokenKind::PipePipe,
+            TokenKind::PipeGreater,
         ];
 
         for op in operators {
@@ -139,6 +139,7 @@ fn type_to_string(ty: &Type) -> String {
     match ty {
         Type::Never => "never".to_string(),
         Type::Number => "number".to_string(),
+        Type::Int => "int".to_string(),
         Type::String => "string".to_string(),
         Type::Bool => "bool".to_string(),
         Type::Void => "void".to_string(),
@@ -14,6 +14,7 @@ impl<'a> TypeChecker<'a> {
         match expr {
             Expr::Literal(lit, _) => match lit {
                 Literal::Number(_) => Type::Number,
+                Literal::Int(_) => Type::Int,
                 Literal::String(_) => Type::String,
                 Literal::Bool(_) => Type::Bool,
                 Literal::Null => Type::Null,
@@ -22,12 +23,18 @@ impl<'a> TypeChecker<'a> {
                 // Track that this symbol was used
                 self.used_symbols.insert(id.name.clone());
 
-                if let Some(symbol) = self.symbol_table.lookup(&id.name) {
+                // A binding narrowed by an `is_some`/`is_ok` guard reads as its payload
+                let payload = self.guarded_payload(&id.name);
+                id.unwrap_payload
+                    .set(payload.as_ref().map(|(kind, _)| *kind));
+                if let Some((_, ty)) = payload {
+                    ty
+                } else if let Some(symbol) = self.symbol_table.lookup(&id.name) {
                     symbol.ty.clone()
                 } else {
                     // Symbol not found - may be a builtin or undefined variable
                     // Binder should have caught undefined variables, so this is likely a builtin
-                    Type::Unknown
+                    crate::symbol::shadowable_builtin_type(&id.name).unwrap_or(Type::Unknown)
                 }
             }
             Expr::Binary(binary) => self.check_binary(binary),
@@ -35,6 +42,8 @@ impl<'a> TypeChecker<'a> {
             Expr::Call(call) => self.check_call(call),
             Expr::Index(index) => self.check_index(index),
             Expr::ArrayLiteral(arr) => self.check_array_literal(arr),
+            Expr::MapLiteral(map) => self.check_map_literal(map),
+            Expr::StringInterp(interp) => self.check_string_interp(interp),
             Expr::Group(group) => self.check_expr(&group.expr),
             Expr::Match(match_expr) => self.check_match(match_expr),
             Expr::Member(member) => self.check_member(member),
@@ -130,7 +139,7 @@ impl<'a> TypeChecker<'a> {
             let arg_type = if let Some(t) = pre_evaluated.get(i) {
                 t.clone()
             } else {
-                self.check_expr(arg)
+                self.check_call_arg(call, arg, params.get(i))
             };
             if let Some(expected_type) = params.get(i) {
                 if !arg_type.is_assignable_to(expected_type)
@@ -264,7 +273,23 @@ impl<'a> TypeChecker<'a> {
     /// Check a binary expression
     fn check_binary(&mut self, binary: &BinaryExpr) -> Type {
         let left_type = self.check_expr(&binary.left);
+        // The right operand of `&&`/`||` only runs when the left one is true/false
+        let (right_present, right_absent) = match binary.op {
+            BinaryOp::And => (
+                self.presence_condition(&binary.left).0,
+                self.absence_condition(&binary.left).0,
+            ),
+            BinaryOp::Or => (
+                self.presence_condition(&binary.left).1,
+                self.absence_condition(&binary.left).1,
+            ),
+            _ => Default::default(),
+        };
+        self.guarded_values.push(Default::default());
+        self.prove_present(&right_present);
+        self.prove_absent(&right_absent);
         let right_type = self.check_expr(&binary.right);
+        self.guarded_values.pop();
         let left_norm = left_type.normalized();
         let right_norm = right_type.normalized();
 
@@ -277,10 +302,13 @@ impl<'a> TypeChecker<'a> {
             BinaryOp::Add => {
                 if self.all_union_pairs_valid(&left_norm, &right_norm, |a, b| {
                     (*a == Type::Number && *b == Type::Number)
+                        || (*a == Type::Int && *b == Type::Int)
                         || (*a == Type::String && *b == Type::String)
                 }) {
                     if left_norm == Type::String || right_norm == Type::String {
                         Type::String
+                    } else if left_norm == Type::Int {
+                        Type::Int
                     } else {
                         Type::Number
                     }
@@ -309,9 +337,14 @@ impl<'a> TypeChecker<'a> {
             }
             BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => {
                 if self.all_union_pairs_valid(&left_norm, &right_norm, |a, b| {
-                    *a == Type::Number && *b == Type::Number
+                    (*a == Type::Number && *b == Type::Number)
+                        || (*a == Type::Int && *b == Type::Int)
                 }) {
-                    Type::Number
+                    if left_norm == Type::Int {
+                        Type::Int
+                    } else {
+                        Type::Number
+                    }
                 } else {
                     self.diagnostics.push(
                         Diagnostic::error_with_code(
@@ -324,7 +357,13 @@ impl<'a> TypeChecker<'a> {
                             binary.span,
                         )
                         .with_label("type mismatch")
-                        .with_help("arithmetic operators (-, *, /, %) only work with numbers"),
+                        .with_help(
+                            suggestions::suggest_int_number_mix(&left_norm, &right_norm)
+                                .unwrap_or_else(|| {
+                                    "arithmetic operators (-, *, /, %) only work with numbers"
+                                        .to_string()
+                                }),
+                        ),
                     );
                     Type::Unknown
                 }
@@ -350,7 +389,8 @@ impl<'a> TypeChecker<'a> {
             }
             BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
                 if self.all_union_pairs_valid(&left_norm, &right_norm, |a, b| {
-                    *a == Type::Number && *b == Type::Number
+                    (*a == Type::Number && *b == Type::Number)
+                        || (*a == Type::Int && *b == Type::Int)
                 }) {
                     Type::Bool
                 } else {
@@ -365,7 +405,13 @@ impl<'a> TypeChecker<'a> {
                             binary.span,
                         )
                         .with_label("type mismatch")
-                        .with_help("comparison operators (<, <=, >, >=) only work with numbers"),
+                        .with_help(
+                            suggestions::suggest_int_number_mix(&left_norm, &right_norm)
+                                .unwrap_or_else(|| {
+                                    "comparison operators (<, <=, >, >=) only work with numbers"
+                                        .to_string()
+                                }),
+                        ),
                     );
                     Type::Bool // Still return bool for error recovery
                 }
@@ -400,7 +446,7 @@ impl<'a> TypeChecker<'a> {
 
         match unary.op {
             UnaryOp::Negate => {
-                if expr_norm != Type::Number && expr_norm != Type::Unknown {
+                if !matches!(expr_norm, Type::Number | Type::Int | Type::Unknown) {
                     self.diagnostics.push(
                         Diagnostic::error_with_code(
                             "AT3002",
@@ -414,6 +460,8 @@ impl<'a> TypeChecker<'a> {
                         .with_help("negation (-) only works with numbers"),
                     );
                     Type::Unknown
+                } else if expr_norm == Type::Int {
+                    Type::Int
                 } else {
                     Type::Number
                 }
@@ -442,8 +490,13 @@ impl<'a> TypeChecker<'a> {
 
     /// Check a function call
     fn check_call(&mut self, call: &CallExpr) -> Type {
+        if let Some(ty) = self.check_option_accessor(call) {
+            return ty;
+        }
         let callee_type = self.check_expr(&call.callee);
-        let callee_norm = callee_type.normalized();
+        let callee_norm = self
+            .builtin_overload_for(call)
+            .unwrap_or_else(|| callee_type.normalized());
 
         // Extract callee name for ownership registry lookup (direct calls only)
         let callee_name = if let Expr::Identifier(id) = call.callee.as_ref() {
@@ -455,7 +508,15 @@ impl<'a> TypeChecker<'a> {
         // Pre-evaluate arg types for ownership checking (avoids double-evaluation in check_expr
         // for the `shared` param path). check_call_against_signature re-evaluates independently.
         let arg_types_for_ownership: Vec<Type> = if callee_name.is_some() {
-            call.args.iter().map(|a| self.check_expr(a)).collect()
+            let params = match &callee_norm {
+                Type::Function { params, .. } => params.clone(),
+                _ => Vec::new(),
+            };
+            call.args
+                .iter()
+                .enumerate()
+                .map(|(i, a)| self.check_call_arg(call, a, params.get(i)))
+                .collect()
         } else {
             Vec::new()
         };
@@ -543,7 +604,7 @@ impl<'a> TypeChecker<'a> {
                 // Error recovery: still check arguments for side effects (usage tracking)
                 // This ensures parameters referenced in arguments are marked as used
                 for arg in &call.args {
-                    self.check_expr(arg);
+                    self.check_call_arg(call, arg, None);
                 }
                 Type::Unknown
             }
@@ -565,6 +626,109 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Check one call argument, reading a guard-narrowed binding as its wrapper
+    /// when the parameter takes an `Option`/`Result` or the callee is one of the
+    /// Option/Result builtins
+    fn check_call_arg(&mut self, call: &CallExpr, arg: &Expr, param: Option<&Type>) -> Type {
+        let wrapped_param = param.is_some_and(|param| {
+            matches!(param.normalized(), Type::Generic { name, .. } if name == "Option" || name == "Result")
+        });
+        if wrapped_param || self.is_wrapper_builtin_call(call) {
+            self.check_wrapped_operand(arg)
+        } else {
+            self.check_expr(arg)
+        }
+    }
+
+    /// Whether `call` invokes a builtin that inspects an Option/Result wrapper
+    fn is_wrapper_builtin_call(&self, call: &CallExpr) -> bool {
+        let Expr::Identifier(callee) = call.callee.as_ref() else {
+            return false;
+        };
+        matches!(
+            callee.name.as_str(),
+            "is_some"
+                | "is_none"
+                | "is_ok"
+                | "is_err"
+                | "unwrap"
+                | "unwrap_or"
+                | "expect"
+                | "result_ok"
+                | "result_err"
+        ) && self.symbol_table.lookup(&callee.name).is_none()
+    }
+
+    /// Type `unwrap(x)` and `expect(x, message)` on an Option or Result as the inner value.
+    ///
+    /// Unwrapping a binding additionally requires it to be proven `Some`/`Ok` by an
+    /// enclosing `is_some`/`is_ok` guard; `expect` is the explicit, unchecked form.
+    /// Only bindings can be guarded, so `unwrap` on any other expression, such as
+    /// `unwrap(mapGet(m, key))`, is an explicit assertion checked at runtime.
+    fn check_option_accessor(&mut self, call: &CallExpr) -> Option<Type> {
+        let Expr::Identifier(callee) = call.callee.as_ref() else {
+            return None;
+        };
+        let checked = match (callee.name.as_str(), call.args.len()) {
+            ("unwrap", 1) => true,
+            ("expect", 2) => false,
+            _ => return None,
+        };
+        if self.symbol_table.lookup(&callee.name).is_some() {
+            return None;
+        }
+
+        let arg_types: Vec<Type> = call
+            .args
+            .iter()
+            .map(|arg| self.check_call_arg(call, arg, None))
+            .collect();
+        let (inner, guard) = match arg_types[0].normalized() {
+            Type::Generic { name, type_args } if name == "Option" && type_args.len() == 1 => {
+                (type_args[0].clone(), "is_some")
+            }
+            Type::Generic { name, type_args } if name == "Result" && type_args.len() == 2 => {
+                (type_args[0].clone(), "is_ok")
+            }
+            _ => return Some(Type::Unknown),
+        };
+
+        if let Expr::Identifier(id) = &call.args[0] {
+            if checked && !self.is_proven_present(&id.name) {
+                self.diagnostics.push(
+                    Diagnostic::error_with_code(
+                        error_codes::UNCHECKED_UNWRAP,
+                        format!(
+                            "Cannot unwrap '{}' of type {} without checking it first",
+                            id.name,
+                            arg_types[0].display_name()
+                        ),
+                        call.span,
+                    )
+                    .with_label("value may be absent here")
+                    .with_help(format!(
+                        "guard the call with `if ({}({})) {{ ... }}`, or use `expect({}, message)` or `unwrap_or({}, default)`",
+                        guard, id.name, id.name, id.name
+                    )),
+                );
+            }
+        }
+        Some(inner)
+    }
+
+    /// Pick the signature of an overloaded builtin whose arity matches the call
+    fn builtin_overload_for(&self, call: &CallExpr) -> Option<Type> {
+        let Expr::Identifier(id) = call.callee.as_ref() else {
+            return None;
+        };
+        if self.symbol_table.lookup(&id.name).is_some() {
+            return None;
+        }
+        crate::symbol::builtin_overloads(&id.name).into_iter().find(
+            |sig| matches!(sig, Type::Function { params, .. } if params.len() == call.args.len()),
+        )
+    }
+
     /// Check a generic function call with type inference
     fn check_call_with_inference(
         &mut self,
@@ -579,11 +743,45 @@ impl<'a> TypeChecker<'a> {
 
         // Check each argument and try to infer type parameters
         for (i, arg) in call.args.iter().enumerate() {
-            let arg_type = self.check_expr(arg);
+            let arg_type = self.check_call_arg(call, arg, params.get(i));
 
             if let Some(param_type) = params.get(i) {
+                // Type parameters in this position already fixed by earlier arguments
+                let earlier_bindings: Vec<(String, Type)> = type_params
+                    .iter()
+                    .filter(|p| inferer.mentions(&p.name, param_type))
+                    .filter_map(|p| {
+                        inferer
+                            .get_substitution(&p.name)
+                            .map(|ty| (p.name.clone(), ty.clone()))
+                    })
+                    .collect();
+
                 // Try to unify parameter type with argument type
                 if let Err(e) = inferer.unify(param_type, &arg_type) {
+                    if let Some((name, bound)) = earlier_bindings.first() {
+                        // Two arguments constrain the same type parameter inconsistently
+                        self.diagnostics.push(
+                            Diagnostic::error_with_code(
+                                "AT3001",
+                                format!(
+                                    "Type inference failed: argument {} has type {}, but {} was already inferred as {}",
+                                    i + 1,
+                                    arg_type.display_name(),
+                                    name,
+                                    bound.display_name()
+                                ),
+                                arg.span(),
+                            )
+                            .with_label(format!("expected {}", inferer.apply_substitutions(param_type).display_name()))
+                            .with_help(format!(
+                                "every argument of type {} must have the same type; {} was inferred from an earlier argument",
+                                name, name
+                            )),
+                        );
+                        return Type::Unknown;
+                    }
+
                     // Inference failed - report error
                     self.diagnostics.push(
                         Diagnostic::error_with_code(
@@ -701,12 +899,28 @@ impl<'a> TypeChecker<'a> {
         let method_name = &member.member.name;
         let target_norm = target_type.normalized();
 
+        // Record field read: `e.code` on a structural type with that field
+        if member.args.is_none() {
+            if let Type::Structural { members } = &target_norm {
+                if let Some(field) = members.iter().find(|m| m.name == *method_name) {
+                    member.field_access.set(true);
+                    return field.ty.clone();
+                }
+            }
+        }
+
         if let Type::Union(members) = target_norm {
             let mut return_types = Vec::new();
             let mut signatures = Vec::new();
 
             for member_ty in &members {
-                if let Some(sig) = self.method_table.lookup(member_ty, method_name).cloned() {
+                // Extension methods dispatch statically, so a union receiver can't use them
+                if let Some(sig) = self
+                    .method_table
+                    .lookup(member_ty, method_name)
+                    .filter(|sig| sig.extension.is_none())
+                    .cloned()
+                {
                     signatures.push(sig.clone());
                     return_types.push(sig.return_type);
                 } else {
@@ -798,6 +1012,30 @@ impl<'a> TypeChecker<'a> {
         let method_sig = self.method_table.lookup(&target_type, method_name).cloned();
 
         if let Some(method_sig) = method_sig {
+            // User extension function: the receiver must fit its `self` parameter
+            if let Some(extension) = &method_sig.extension {
+                if !target_type.is_assignable_to(&extension.receiver) {
+                    self.diagnostics.push(
+                        Diagnostic::error_with_code(
+                            "AT3001",
+                            format!(
+                                "Receiver has wrong type: expected {}, found {}",
+                                extension.receiver.display_name(),
+                                target_type.display_name()
+                            ),
+                            member.target.span(),
+                        )
+                        .with_label("type mismatch")
+                        .with_help(format!(
+                            "extension method '{}' is declared for {}",
+                            method_name,
+                            extension.receiver.display_name()
+                        )),
+                    );
+                }
+                *member.extension_dispatch.borrow_mut() = Some(extension.function.clone());
+            }
+
             // Check argument count
             let provided_args = member.args.as_ref().map(|args| args.len()).unwrap_or(0);
             let expected_args = method_sig.arg_types.len();
@@ -969,6 +1207,45 @@ impl<'a> TypeChecker<'a> {
                 }
                 Type::JsonValue
             }
+            // Map indexing: requires string key, returns value type
+            Type::Generic {
+                ref name,
+                ref type_args,
+            } if name == "Map" && type_args.len() == 1 => {
+                if index_norm != Type::String && index_norm != Type::Unknown {
+                    self.diagnostics.push(
+                        Diagnostic::error_with_code(
+                            "AT3001",
+                            format!(
+                                "Map key must be string, found {}",
+                                index_type.display_name()
+                            ),
+                            index.index.span(),
+                        )
+                        .with_label("type mismatch")
+                        .with_help("map keys are always strings"),
+                    );
+                }
+                type_args[0].clone()
+            }
+            // Record indexing: requires string key, returns the field types
+            Type::Structural { members } => {
+                if index_norm != Type::String && index_norm != Type::Unknown {
+                    self.diagnostics.push(
+                        Diagnostic::error_with_code(
+                            "AT3001",
+                            format!(
+                                "Record key must be string, found {}",
+                                index_type.display_name()
+                            ),
+                            index.index.span(),
+                        )
+                        .with_label("type mismatch")
+                        .with_help("index a record by field name, or use `value.field`"),
+                    );
+                }
+                Type::union(members.into_iter().map(|m| m.ty).collect())
+            }
             Type::Union(members) => {
                 let mut result_types = Vec::new();
                 for member in members {
@@ -1020,7 +1297,7 @@ impl<'a> TypeChecker<'a> {
                                     index.target.span(),
                                 )
                                 .with_label("not indexable")
-                                .with_help("only arrays and json values can be indexed"),
+                                .with_help("only arrays, maps, and json values can be indexed"),
                             );
                             return Type::Unknown;
                         }
@@ -1037,7 +1314,7 @@ impl<'a> TypeChecker<'a> {
                         index.target.span(),
                     )
                     .with_label("not indexable")
-                    .with_help("only arrays and json values can be indexed"),
+                    .with_help("only arrays, maps, and json values can be indexed"),
                 );
                 Type::Unknown
             }
@@ -1081,10 +1358,89 @@ impl<'a> TypeChecker<'a> {
         Type::Array(Box::new(first_type))
     }
 
+    fn check_map_literal(&mut self, map: &MapLiteral) -> Type {
+        if map.entries.is_empty() {
+            // Empty map - infer as map of unknown
+            return Type::map(Type::Unknown);
+        }
+
+        // Check first value to determine the map's value type
+        let first_type = self.check_expr(&map.entries[0].value);
+
+        let mut seen_keys = std::collections::HashSet::new();
+        seen_keys.insert(map.entries[0].key.as_str());
+
+        // Check that all values have the same type and keys are unique
+        for entry in map.entries.iter().skip(1) {
+            if !seen_keys.insert(entry.key.as_str()) {
+                self.diagnostics.push(
+                    Diagnostic::error_with_code(
+                        "AT3001",
+                        format!("Duplicate map key '{}'", entry.key),
+                        entry.key_span,
+                    )
+                    .with_label("duplicate key")
+                    .with_help("each key may appear only once in a map literal"),
+                );
+            }
+
+            let value_type = self.check_expr(&entry.value);
+            if !value_type.is_assignable_to(&first_type) && value_type.normalized() != Type::Unknown
+            {
+                self.diagnostics.push(
+                    Diagnostic::error_with_code(
+                        "AT3001",
+                        format!(
+                            "Map value for key '{}' has wrong type: expected {}, found {}",
+                            entry.key,
+                            first_type.display_name(),
+                            value_type.display_name()
+                        ),
+                        entry.value.span(),
+                    )
+                    .with_label("type mismatch")
+                    .with_help(format!(
+                        "all map values must be type {} (inferred from first entry)",
+                        first_type.display_name()
+                    )),
+                );
+            }
+        }
+
+        Type::map(first_type)
+    }
+
+    /// Check an interpolated string: every embedded expression must be a
+    /// primitive that `toString` renders predictably.
+    fn check_string_interp(&mut self, interp: &StringInterpExpr) -> Type {
+        let printable = Type::Union(vec![Type::Number, Type::Int, Type::String, Type::Bool]);
+        for part in &interp.parts {
+            let StringInterpPart::Expr(expr) = part else {
+                continue;
+            };
+            let expr_type = self.check_expr(expr);
+            if expr_type.normalized() != Type::Unknown && !expr_type.is_assignable_to(&printable) {
+                self.diagnostics.push(
+                    Diagnostic::error_with_code(
+                        "AT3001",
+                        format!(
+                            "Cannot interpolate value of type {} into a string",
+                            expr_type.display_name()
+                        ),
+                        expr.span(),
+                    )
+                    .with_label("type mismatch")
+                    .with_help("only number, int, string, and bool values can be interpolated; convert other values explicitly"),
+                );
+            }
+        }
+        Type::String
+    }
+
     /// Check a match expression
     fn check_match(&mut self, match_expr: &crate::ast::MatchExpr) -> Type {
         // 1. Check scrutinee type
-        let scrutinee_type = self.check_expr(&match_expr.scrutinee);
+        let scrutinee_type = self.check_wrapped_operand(&match_expr.scrutinee);
 
         if scrutinee_type.normalized() == Type::Unknown {
             // Error in scrutinee, skip match checking
@@ -1385,6 +1741,7 @@ impl<'a> TypeChecker<'a> {
                 Pattern::Literal(lit, span) => {
                     let lit_type = match lit {
                         Literal::Number(_) => Type::Number,
+                        Literal::Int(_) => Type::Int,
                         Literal::String(_) => Type::String,
                         Literal::Bool(_) => Type::Bool,
                         Literal::Null => Type::Null,
@@ -1480,6 +1837,7 @@ impl<'a> TypeChecker<'a> {
                 // Check literal type matches expected type
                 let lit_type = match lit {
                     Literal::Number(_) => Type::Number,
+                    Literal::Int(_) => Type::Int,
                     Literal::String(_) => Type::String,
                     Literal::Bool(_) => Type::Bool,
                     Literal::Null => Type::Null,
@@ -1733,7 +2091,7 @@ impl<'a> TypeChecker<'a> {
     /// Check try expression (error propagation operator ?)
     fn check_try(&mut self, try_expr: &TryExpr) -> Type {
         // Type check the expression being tried
-        let expr_type = self.check_expr(&try_expr.expr);
+        let expr_type = self.check_wrapped_operand(&try_expr.expr);
         let expr_norm = expr_type.normalized();
 
         // Skip if expression type is unknown (error already reported)
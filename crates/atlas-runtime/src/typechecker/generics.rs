@@ -267,6 +267,11 @@ impl TypeInferer {
         }
     }
 
+    /// Check whether a type refers to the given type parameter
+    pub fn mentions(&self, param: &str, ty: &Type) -> bool {
+        self.occurs_in(param, ty)
+    }
+
     /// Get the substitution for a type parameter
     pub fn get_substitution(&self, param: &str) -> Option<&Type> {
         self.substitutions.get(param)
@@ -161,6 +161,7 @@ impl LetPolymorphism {
             // Syntactic values that can be safely generalized
             Expr::Literal(_, _) => BindingKind::SyntacticValue,
             Expr::ArrayLiteral(_) => BindingKind::SyntacticValue,
+            Expr::MapLiteral(_) => BindingKind::SyntacticValue,
             // Closures/lambdas would be here too if Atlas had anonymous functions
             // For now, group expressions are transparent
             Expr::Group(g) => Self::classify_expr(&g.expr),
@@ -501,6 +502,14 @@ fn collect_return_types(
                     has_implicit_void,
                 );
             }
+            Stmt::TryCatch(try_catch) => {
+                collect_return_types(&try_catch.body.statements, return_types, has_implicit_void);
+                collect_return_types(
+                    &try_catch.handler.statements,
+                    return_types,
+                    has_implicit_void,
+                );
+            }
             _ => {}
         }
     }
@@ -514,26 +523,30 @@ pub fn infer_expr_type(expr: &Expr) -> Type {
     match expr {
         Expr::Literal(lit, _) => match lit {
             Literal::Number(_) => Type::Number,
+            Literal::Int(_) => Type::Int,
             Literal::String(_) => Type::String,
             Literal::Bool(_) => Type::Bool,
             Literal::Null => Type::Null,
         },
-        Expr::Binary(binary) => infer_binary_type(&binary.op),
+        Expr::Binary(binary) => infer_binary_type(binary),
         Expr::Unary(unary) => match unary.op {
-            UnaryOp::Negate => Type::Number,
+            UnaryOp::Negate => numeric_operand_type(&unary.expr),
             UnaryOp::Not => Type::Bool,
         },
         Expr::ArrayLiteral(_) => Type::Array(Box::new(Type::Unknown)),
+        Expr::MapLiteral(_) => Type::map(Type::Unknown),
+        Expr::StringInterp(_) => Type::String,
         Expr::Group(group) => infer_expr_type(&group.expr),
         _ => Type::Unknown,
     }
 }
 
 /// Infer the result type of a binary operation.
-fn infer_binary_type(op: &BinaryOp) -> Type {
-    match op {
+fn infer_binary_type(binary: &BinaryExpr) -> Type {
+    match binary.op {
         BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => {
-            Type::Number // Could be string for Add, but default to number
+            // Could be string for Add, but default to number
+            numeric_operand_type(&binary.left)
         }
         BinaryOp::Eq
         | BinaryOp::Ne
@@ -546,6 +559,14 @@ fn infer_binary_type(op: &BinaryOp) -> Type {
     }
 }
 
+/// Arithmetic stays in int when its operand is an int, otherwise it is number
+fn numeric_operand_type(operand: &Expr) -> Type {
+    match infer_expr_type(operand) {
+        Type::Int => Type::Int,
+        _ => Type::Number,
+    }
+}
+
 /// Check if an expected type is compatible with an inferred type,
 /// allowing the expected type to guide inference.
 ///
@@ -760,6 +781,7 @@ mod tests {
             callee: Box::new(Expr::Identifier(crate::ast::Identifier {
                 name: "f".to_string(),
                 span: Span::dummy(),
+                unwrap_payload: std::cell::Cell::new(None),
             })),
             args: vec![],
             span: Span::dummy(),
@@ -782,6 +804,7 @@ mod tests {
             callee: Box::new(Expr::Identifier(crate::ast::Identifier {
                 name: "f".to_string(),
                 span: Span::dummy(),
+                unwrap_payload: std::cell::Cell::new(None),
             })),
             args: vec![],
             span: Span::dummy(),
@@ -918,6 +941,7 @@ mod tests {
         let expr = Expr::Identifier(crate::ast::Identifier {
             name: "x".to_string(),
             span: Span::dummy(),
+            unwrap_payload: std::cell::Cell::new(None),
         });
         assert_eq!(InferenceHeuristics::infer_literal_type(&expr), None);
     }
@@ -10,6 +10,20 @@ pub struct MethodSignature {
     pub arg_types: Vec<Type>,
     /// Return type
     pub return_type: Type,
+    /// User extension function implementing the method, if not a builtin
+    pub extension: Option<ExtensionMethod>,
+}
+
+/// A top-level function whose first parameter is `self`, callable with method syntax
+///
+/// `fn shout(self: string) -> string` makes `"hi".shout()` a call to
+/// `shout("hi")`.
+#[derive(Debug, Clone)]
+pub struct ExtensionMethod {
+    /// Name of the function to call with the receiver as its first argument
+    pub function: String,
+    /// Declared type of the `self` parameter
+    pub receiver: Type,
 }
 
 /// Method table for resolving method calls
@@ -40,27 +54,61 @@ impl MethodTable {
         let sig = MethodSignature {
             arg_types,
             return_type,
+            extension: None,
         };
         self.methods.insert(key, sig);
     }
 
-    /// Look up a method for a type
-    pub fn lookup(&self, receiver_type: &Type, method_name: &str) -> Option<&MethodSignature> {
-        let receiver_type = receiver_type.normalized();
-        // Convert Type to string for lookup
-        let type_name = match receiver_type {
-            Type::JsonValue => "json",
-            Type::String => "string",
-            Type::Number => "number",
-            Type::Bool => "bool",
-            Type::Array(_) => "array",
-            _ => return None,
+    /// Register a user extension function as a method of its `self` type
+    ///
+    /// Returns false, registering nothing, if the receiver type does not
+    /// support method syntax or already has a method with this name; builtin
+    /// methods always win.
+    pub fn register_extension(
+        &mut self,
+        method_name: &str,
+        receiver: Type,
+        arg_types: Vec<Type>,
+        return_type: Type,
+    ) -> bool {
+        let Some(type_name) = Self::type_key(&receiver) else {
+            return false;
+        };
+        let key = (type_name.to_string(), method_name.to_string());
+        if self.methods.contains_key(&key) {
+            return false;
+        }
+        let sig = MethodSignature {
+            arg_types,
+            return_type,
+            extension: Some(ExtensionMethod {
+                function: method_name.to_string(),
+                receiver,
+            }),
         };
+        self.methods.insert(key, sig);
+        true
+    }
 
+    /// Look up a method for a type
+    pub fn lookup(&self, receiver_type: &Type, method_name: &str) -> Option<&MethodSignature> {
+        let type_name = Self::type_key(receiver_type)?;
         let key = (type_name.to_string(), method_name.to_string());
         self.methods.get(&key)
     }
 
+    /// Convert a receiver type to its method table key
+    fn type_key(receiver_type: &Type) -> Option<&'static str> {
+        match receiver_type.normalized() {
+            Type::JsonValue => Some("json"),
+            Type::String => Some("string"),
+            Type::Number => Some("number"),
+            Type::Bool => Some("bool"),
+            Type::Array(_) => Some("array"),
+            _ => None,
+        }
+    }
+
     /// Populate built-in methods for stdlib types
     fn populate_builtin_methods(&mut self) {
         // JSON extraction methods
@@ -156,4 +204,22 @@ mod tests {
         assert_eq!(sig.arg_types.len(), 0);
         assert_eq!(sig.return_type, Type::String);
     }
+
+    #[test]
+    fn test_register_extension_does_not_shadow_builtin() {
+        let mut table = MethodTable::new();
+        let numbers = Type::Array(Box::new(Type::Number));
+
+        assert!(table.register_extension("sum", numbers.clone(), vec![], Type::Number));
+        let sig = table.lookup(&numbers, "sum").unwrap();
+        assert_eq!(sig.extension.as_ref().unwrap().function, "sum");
+
+        assert!(!table.register_extension("len", numbers, vec![], Type::String));
+        assert!(table
+            .lookup(&Type::Array(Box::new(Type::Unknown)), "len")
+            .unwrap()
+            .extension
+            .is_none());
+        assert!(!table.register_extension("f", Type::Null, vec![], Type::Null));
+    }
 }
@@ -19,7 +19,7 @@ pub mod unification;
 
 use crate::ast::*;
 use crate::diagnostic::error_codes;
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, LabelStyle, TextEdit};
 use crate::module_loader::ModuleRegistry;
 use crate::span::Span;
 use crate::symbol::{SymbolKind, SymbolTable};
@@ -240,14 +240,21 @@ pub struct TypeChecker<'a> {
     current_function_info: Option<(String, Span)>,
     /// Whether we're inside a loop (for break/continue checking)
     in_loop: bool,
+    /// Labels of the enclosing loops, innermost last (`None` for unlabelled loops)
+    loop_labels: Vec<Option<String>>,
     /// Declared symbols in current function (name -> (span, kind))
     pub(super) declared_symbols: HashMap<String, (Span, SymbolKind)>,
     /// Used symbols in current function
     pub(super) used_symbols: HashSet<String>,
+    /// Span of the `let` keyword for each immutable variable, keyed by name span
+    let_keywords: HashMap<Span, Span>,
     /// Method table for method resolution
     pub(super) method_table: methods::MethodTable,
     /// Type guard registry for predicate-based narrowing
     pub(super) type_guards: type_guards::TypeGuardRegistry,
+    /// Option/Result bindings proven to hold `Some`/`Ok` or `None`/`Err`, one
+    /// frame per scope
+    pub(super) guarded_values: Vec<HashMap<String, narrowing::GuardedValue>>,
     /// Type alias declarations available in this module scope
     type_aliases: HashMap<String, TypeAliasDecl>,
     /// Cached alias resolutions (alias name + args -> resolved type)
@@ -295,10 +302,13 @@ impl<'a> TypeChecker<'a> {
             current_function_return_type: None,
             current_function_info: None,
             in_loop: false,
+            loop_labels: Vec::new(),
             declared_symbols: HashMap::new(),
             used_symbols: HashSet::new(),
+            let_keywords: HashMap::new(),
             method_table: methods::MethodTable::new(),
             type_guards: type_guards::TypeGuardRegistry::new(),
+            guarded_values: vec![HashMap::new()],
             type_aliases,
             alias_cache: HashMap::new(),
             alias_resolution_stack: Vec::new(),
@@ -319,6 +329,7 @@ impl<'a> TypeChecker<'a> {
     /// Type check a program
     pub fn check(&mut self, program: &Program) -> Vec<Diagnostic> {
         self.collect_type_guards(program);
+        self.collect_extension_methods(program);
         self.validate_type_aliases(program);
         for item in &program.items {
             self.check_item(item);
@@ -346,33 +357,39 @@ impl<'a> TypeChecker<'a> {
 
         for item in &program.items {
             if let Item::Export(export_decl) = item {
-                let name = match &export_decl.item {
-                    crate::ast::ExportItem::Function(func) => &func.name.name,
-                    crate::ast::ExportItem::Variable(var) => &var.name.name,
-                    crate::ast::ExportItem::TypeAlias(alias) => &alias.name.name,
+                let names: Vec<&String> = match &export_decl.item {
+                    crate::ast::ExportItem::Function(func) => vec![&func.name.name],
+                    crate::ast::ExportItem::Variable(var) => vec![&var.name.name],
+                    crate::ast::ExportItem::TypeAlias(alias) => vec![&alias.name.name],
+                    crate::ast::ExportItem::ReExport(re_export) => {
+                        re_export.names.iter().map(|name| &name.name).collect()
+                    }
                 };
 
-                if exported_names.contains(name) {
-                    self.diagnostics.push(
-                        Diagnostic::error_with_code(
-                            "AT5008",
-                            format!("Duplicate export: '{}' is exported more than once", name),
-                            export_decl.span,
-                        )
-                        .with_label("duplicate export")
-                        .with_help(format!(
-                            "remove one of the export statements for '{}'",
-                            name
-                        )),
-                    );
-                } else {
-                    exported_names.insert(name.clone());
+                for name in names {
+                    if exported_names.contains(name) {
+                        self.diagnostics.push(
+                            Diagnostic::error_with_code(
+                                "AT5008",
+                                format!("Duplicate export: '{}' is exported more than once", name),
+                                export_decl.span,
+                            )
+                            .with_label("duplicate export")
+                            .with_help(format!(
+                                "remove one of the export statements for '{}'",
+                                name
+                            )),
+                        );
+                    } else {
+                        exported_names.insert(name.clone());
+                    }
                 }
             }
         }
 
         // Type check all items (imports already validated during binding)
         self.collect_type_guards(program);
+        self.collect_extension_methods(program);
         self.validate_type_aliases(program);
         for item in &program.items {
             self.check_item(item);
@@ -381,6 +398,46 @@ impl<'a> TypeChecker<'a> {
         std::mem::take(&mut self.diagnostics)
     }
 
+    /// Register top-level `fn name(self: T, ...)` functions as methods of `T`
+    ///
+    /// Runs before any body is checked so function bodies can call extension
+    /// methods declared later in the file. Generic functions are not registered.
+    fn collect_extension_methods(&mut self, program: &Program) {
+        for item in &program.items {
+            let func = match item {
+                Item::Function(func) | Item::Statement(Stmt::FunctionDecl(func)) => func,
+                Item::Export(export_decl) => match &export_decl.item {
+                    crate::ast::ExportItem::Function(func) => func,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let Some(receiver) = func.params.first() else {
+                continue;
+            };
+            if receiver.name.name != "self" || !func.type_params.is_empty() {
+                continue;
+            }
+
+            // Unresolvable types are reported when the function itself is checked
+            let mark = self.diagnostics.len();
+            let receiver_type = self.resolve_type_ref(&receiver.type_ref);
+            let arg_types: Vec<Type> = func.params[1..]
+                .iter()
+                .map(|p| self.resolve_type_ref(&p.type_ref))
+                .collect();
+            let return_type = self.resolve_type_ref(&func.return_type);
+            self.diagnostics.truncate(mark);
+
+            self.method_table.register_extension(
+                &func.name.name,
+                receiver_type,
+                arg_types,
+                return_type,
+            );
+        }
+    }
+
     /// Check a top-level item
     fn check_item(&mut self, item: &Item) {
         match item {
@@ -400,6 +457,9 @@ impl<'a> TypeChecker<'a> {
                     crate::ast::ExportItem::TypeAlias(_) => {
                         // Type aliases are validated in a pre-pass
                     }
+                    crate::ast::ExportItem::ReExport(_) => {
+                        // Forwarded symbols keep the types resolved during binding
+                    }
                 }
             }
             Item::Extern(_) => {
@@ -864,6 +924,7 @@ impl<'a> TypeChecker<'a> {
         let prev_declared_symbols = std::mem::take(&mut self.declared_symbols);
         let prev_used_symbols = std::mem::take(&mut self.used_symbols);
         let prev_param_ownerships = std::mem::take(&mut self.current_fn_param_ownerships);
+        let prev_loop_labels = std::mem::take(&mut self.loop_labels);
 
         let return_type = self.resolve_type_ref(&func.return_type);
         self.current_function_return_type = Some(return_type.clone());
@@ -996,6 +1057,7 @@ impl<'a> TypeChecker<'a> {
         // Restore previous function context (for nested functions)
         self.current_function_return_type = prev_return_type;
         self.current_function_info = prev_function_info;
+        self.loop_labels = prev_loop_labels;
         self.declared_symbols = prev_declared_symbols;
         self.used_symbols = prev_used_symbols;
         self.current_fn_param_ownerships = prev_param_ownerships;
@@ -1039,10 +1101,10 @@ impl<'a> TypeChecker<'a> {
 
     /// Check a block
     fn check_block(&mut self, block: &Block) {
-        let mut found_return = false;
+        let mut terminated = false;
         for stmt in &block.statements {
-            if found_return {
-                // Code after return is unreachable
+            if terminated {
+                // Code after return/break/continue is unreachable
                 self.diagnostics.push(
                     Diagnostic::warning_with_code("AT2002", "Unreachable code", stmt.span())
                         .with_label("this code will never execute")
@@ -1052,9 +1114,9 @@ impl<'a> TypeChecker<'a> {
 
             self.check_statement(stmt);
 
-            // Check if this statement always returns
-            if matches!(stmt, Stmt::Return(_)) {
-                found_return = true;
+            // Check if this statement always leaves the block
+            if stmt.is_terminator() {
+                terminated = true;
             }
         }
     }
@@ -1069,6 +1131,11 @@ impl<'a> TypeChecker<'a> {
         false
     }
 
+    /// Check if a block always leaves the enclosing block (return, break or continue)
+    fn block_always_exits(&self, block: &Block) -> bool {
+        self.block_always_returns(block) || block.statements.iter().any(Stmt::is_terminator)
+    }
+
     /// Check if a statement always returns
     fn statement_always_returns(&self, stmt: &Stmt) -> bool {
         match stmt {
@@ -1081,10 +1148,44 @@ impl<'a> TypeChecker<'a> {
                     false
                 }
             }
+            Stmt::TryCatch(try_catch) => {
+                self.block_always_returns(&try_catch.body)
+                    && self.block_always_returns(&try_catch.handler)
+            }
             _ => false,
         }
     }
 
+    /// Check that a `break`/`continue` label names an enclosing loop
+    fn check_loop_label(&mut self, label: &Identifier) {
+        let found = self
+            .loop_labels
+            .iter()
+            .any(|l| l.as_deref() == Some(label.name.as_str()));
+        if !found {
+            self.diagnostics.push(
+                Diagnostic::error_with_code(
+                    error_codes::UNDEFINED_LOOP_LABEL,
+                    format!("Undefined loop label '{}'", label.name),
+                    label.span,
+                )
+                .with_label("no enclosing loop has this label")
+                .with_help(format!(
+                    "label an enclosing loop, e.g. `{}: for x in xs {{ ... }}`",
+                    label.name
+                )),
+            );
+        }
+    }
+
+    /// Attach a `let` -> `var` fix if the symbol was declared with `let`
+    fn with_mutability_fix(&self, diag: Diagnostic, declared_at: Span) -> Diagnostic {
+        match self.let_keywords.get(&declared_at) {
+            Some(&keyword) => diag.with_fix(TextEdit::replace(keyword, "var")),
+            None => diag,
+        }
+    }
+
     /// Check a statement
     fn check_statement(&mut self, stmt: &Stmt) {
         match stmt {
@@ -1092,6 +1193,10 @@ impl<'a> TypeChecker<'a> {
                 // Track this variable declaration
                 self.declared_symbols
                     .insert(var.name.name.clone(), (var.name.span, SymbolKind::Variable));
+                if !var.mutable {
+                    let keyword = Span::new(var.span.start, var.span.start + "let".len());
+                    self.let_keywords.insert(var.name.span, keyword);
+                }
 
                 let init_type = self.check_expr(&var.init);
 
@@ -1123,6 +1228,19 @@ impl<'a> TypeChecker<'a> {
                                 declared_type.display_name(),
                                 init_type.display_name()
                             ))
+                            .with_span_label(
+                                type_ref.span(),
+                                format!(
+                                    "expected `{}` because of this annotation",
+                                    declared_type.display_name()
+                                ),
+                                LabelStyle::Secondary,
+                            )
+                            .with_span_label(
+                                var.init.span(),
+                                format!("found `{}` here", init_type.display_name()),
+                                LabelStyle::Primary,
+                            )
                             .with_help(help),
                         );
                     }
@@ -1201,8 +1319,10 @@ impl<'a> TypeChecker<'a> {
                                 column: symbol.span.start + 1,
                                 length: symbol.span.end.saturating_sub(symbol.span.start),
                                 message: format!("'{}' declared here as immutable", symbol.name),
+                                ..Default::default()
                             })
                             .with_help(suggestions::suggest_mutability_fix(&id.name));
+                            let diag = self.with_mutability_fix(diag, symbol.span);
 
                             self.diagnostics.push(diag);
                         }
@@ -1258,6 +1378,7 @@ impl<'a> TypeChecker<'a> {
                                 id.span,
                             )
                             .with_label("immutable variable");
+                            let diag = self.with_mutability_fix(diag, symbol.span);
                             self.diagnostics.push(diag);
                         }
                     }
@@ -1292,6 +1413,7 @@ impl<'a> TypeChecker<'a> {
                                 id.span,
                             )
                             .with_label("immutable variable");
+                            let diag = self.with_mutability_fix(diag, symbol.span);
                             self.diagnostics.push(diag);
                         }
                     }
@@ -1326,6 +1448,7 @@ impl<'a> TypeChecker<'a> {
                                 id.span,
                             )
                             .with_label("immutable variable");
+                            let diag = self.with_mutability_fix(diag, symbol.span);
                             self.diagnostics.push(diag);
                         }
                     }
@@ -1346,18 +1469,38 @@ impl<'a> TypeChecker<'a> {
                     );
                 }
                 let (then_narrow, else_narrow) = self.narrow_condition(&if_stmt.cond);
+                let (then_present, else_present) = self.presence_condition(&if_stmt.cond);
+                let (then_absent, else_absent) = self.absence_condition(&if_stmt.cond);
                 self.enter_scope();
                 self.apply_narrowings(&then_narrow);
+                self.prove_present(&then_present);
+                self.prove_absent(&then_absent);
                 self.check_block(&if_stmt.then_block);
                 self.exit_scope();
                 if let Some(else_block) = &if_stmt.else_block {
                     self.enter_scope();
                     self.apply_narrowings(&else_narrow);
+                    self.prove_present(&else_present);
+                    self.prove_absent(&else_absent);
                     self.check_block(else_block);
                     self.exit_scope();
                 }
+                // An early exit in one branch proves the other branch's guard
+                // for the rest of the enclosing block
+                match &if_stmt.else_block {
+                    None if self.block_always_exits(&if_stmt.then_block) => {
+                        self.prove_present(&else_present);
+                        self.prove_absent(&else_absent);
+                    }
+                    Some(else_block) if self.block_always_exits(else_block) => {
+                        self.prove_present(&then_present);
+                        self.prove_absent(&then_absent);
+                    }
+                    _ => {}
+                }
             }
             Stmt::While(while_stmt) => {
+                self.forget_assigned_in(&while_stmt.body);
                 let cond_type = self.check_expr(&while_stmt.cond);
                 let cond_norm = cond_type.normalized();
                 if cond_norm != Type::Bool && cond_norm != Type::Unknown {
@@ -1373,15 +1516,23 @@ impl<'a> TypeChecker<'a> {
                 }
                 let old_in_loop = self.in_loop;
                 self.in_loop = true;
+                self.loop_labels
+                    .push(while_stmt.label.as_ref().map(|l| l.name.clone()));
                 let (then_narrow, _) = self.narrow_condition(&while_stmt.cond);
+                let (then_present, _) = self.presence_condition(&while_stmt.cond);
+                let (then_absent, _) = self.absence_condition(&while_stmt.cond);
                 self.enter_scope();
                 self.apply_narrowings(&then_narrow);
+                self.prove_present(&then_present);
+                self.prove_absent(&then_absent);
                 self.check_block(&while_stmt.body);
                 self.exit_scope();
+                self.loop_labels.pop();
                 self.in_loop = old_in_loop;
             }
             Stmt::For(for_stmt) => {
                 self.check_statement(&for_stmt.init);
+                self.forget_assigned_in(&for_stmt.body);
                 let cond_type = self.check_expr(&for_stmt.cond);
                 let cond_norm = cond_type.normalized();
                 if cond_norm != Type::Bool && cond_norm != Type::Unknown {
@@ -1399,7 +1550,10 @@ impl<'a> TypeChecker<'a> {
 
                 let old_in_loop = self.in_loop;
                 self.in_loop = true;
+                self.loop_labels
+                    .push(for_stmt.label.as_ref().map(|l| l.name.clone()));
                 self.check_block(&for_stmt.body);
+                self.loop_labels.pop();
                 self.in_loop = old_in_loop;
             }
             Stmt::Return(ret) => {
@@ -1447,34 +1601,39 @@ impl<'a> TypeChecker<'a> {
                             column: func_span.start + 1,
                             length: func_span.end.saturating_sub(func_span.start),
                             message: format!("function '{}' declared here", func_name),
+                            ..Default::default()
                         });
                     }
 
                     self.diagnostics.push(diag);
                 }
             }
-            Stmt::Break(span) => {
+            Stmt::Break(break_stmt) => {
                 if !self.in_loop {
                     self.diagnostics.push(
                         Diagnostic::error_with_code(
                             "AT3010",
                             "Break statement outside loop",
-                            *span,
+                            break_stmt.span,
                         )
                         .with_label("invalid break"),
                     );
+                } else if let Some(label) = &break_stmt.label {
+                    self.check_loop_label(label);
                 }
             }
-            Stmt::Continue(span) => {
+            Stmt::Continue(continue_stmt) => {
                 if !self.in_loop {
                     self.diagnostics.push(
                         Diagnostic::error_with_code(
                             "AT3010",
                             "Continue statement outside loop",
-                            *span,
+                            continue_stmt.span,
                         )
                         .with_label("invalid continue"),
                     );
+                } else if let Some(label) = &continue_stmt.label {
+                    self.check_loop_label(label);
                 }
             }
             Stmt::Expr(expr_stmt) => {
@@ -1525,11 +1684,33 @@ impl<'a> TypeChecker<'a> {
                 }
 
                 // Type check the loop body
+                self.forget_assigned_in(&for_in_stmt.body);
                 let old_in_loop = self.in_loop;
                 self.in_loop = true;
+                self.loop_labels
+                    .push(for_in_stmt.label.as_ref().map(|l| l.name.clone()));
                 self.check_block(&for_in_stmt.body);
+                self.loop_labels.pop();
                 self.in_loop = old_in_loop;
             }
+            Stmt::TryCatch(try_catch) => {
+                self.enter_scope();
+                self.check_block(&try_catch.body);
+                self.exit_scope();
+
+                self.enter_scope();
+                let symbol = crate::symbol::Symbol {
+                    name: try_catch.error.name.clone(),
+                    ty: Type::caught_error(),
+                    span: try_catch.error.span,
+                    mutable: false,
+                    kind: crate::symbol::SymbolKind::Variable,
+                    exported: false,
+                };
+                let _ = self.symbol_table.define(symbol);
+                self.check_block(&try_catch.handler);
+                self.exit_scope();
+            }
         }
     }
 
@@ -1553,17 +1734,20 @@ impl<'a> TypeChecker<'a> {
     fn enter_scope(&mut self) {
         self.symbol_table.enter_scope();
         self.type_guards.enter_scope();
+        self.guarded_values.push(HashMap::new());
     }
 
     fn exit_scope(&mut self) {
         self.symbol_table.exit_scope();
         self.type_guards.exit_scope();
+        self.guarded_values.pop();
     }
 
     /// Check an assignment target and return its type
     fn check_assign_target(&mut self, target: &AssignTarget) -> Type {
         match target {
             AssignTarget::Name(id) => {
+                self.forget_present(&id.name);
                 if let Some(symbol) = self.symbol_table.lookup(&id.name) {
                     symbol.ty.clone()
                 } else {
@@ -1574,9 +1758,29 @@ impl<'a> TypeChecker<'a> {
                 let target_type = self.check_expr(target);
                 let index_type = self.check_expr(index);
                 let target_norm = target_type.normalized();
+                let index_norm = index_type.normalized();
+
+                // Map assignment: string key, value type from the map
+                if let Type::Generic { name, type_args } = &target_norm {
+                    if name == "Map" && type_args.len() == 1 {
+                        if index_norm != Type::String && index_norm != Type::Unknown {
+                            self.diagnostics.push(
+                                Diagnostic::error_with_code(
+                                    "AT3001",
+                                    format!(
+                                        "Map key must be string, found {}",
+                                        index_type.display_name()
+                                    ),
+                                    index.span(),
+                                )
+                                .with_label("type mismatch"),
+                            );
+                        }
+                        return type_args[0].clone();
+                    }
+                }
 
                 // Check that index is a number
-                let index_norm = index_type.normalized();
                 if index_norm != Type::Number && index_norm != Type::Unknown {
                     self.diagnostics.push(
                         Diagnostic::error_with_code(
@@ -1622,6 +1826,9 @@ impl<'a> TypeChecker<'a> {
             "Array" => Some(1), // Array<T> is sugar for T[]
             "HashMap" => Some(2),
             "HashSet" => Some(1),
+            "Map" => Some(1),
+            "Set" => Some(1),
+            "Future" => Some(1),
             _ => None, // Unknown generic type
         }
     }
@@ -1695,6 +1902,7 @@ impl<'a> TypeChecker<'a> {
         match type_ref {
             TypeRef::Named(name, span) => match name.as_str() {
                 "number" => Type::Number,
+                "int" => Type::Int,
                 "string" => Type::String,
                 "bool" => Type::Bool,
                 "void" => Type::Void,
@@ -1898,6 +2106,7 @@ impl<'a> TypeChecker<'a> {
                 column: alias.name.span.start + 1,
                 length: alias.name.span.end.saturating_sub(alias.name.span.start),
                 message: format!("'{}' declared here", alias.name.name),
+                ..Default::default()
             });
             self.diagnostics.push(diag);
             return Type::Unknown;
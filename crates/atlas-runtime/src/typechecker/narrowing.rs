@@ -1,9 +1,19 @@
 //! Type narrowing utilities for control flow analysis.
 
-use crate::ast::{BinaryOp, Expr, Literal, UnaryOp};
+use crate::ast::{AssignTarget, BinaryOp, Block, Expr, Literal, PayloadKind, Stmt, UnaryOp};
+use crate::span::Span;
 use crate::typechecker::TypeChecker;
 use crate::types::Type;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// What a guard proved about an `Option`/`Result` binding
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GuardedValue {
+    /// Declaration span of the guarded symbol, so a shadowing binding is not narrowed
+    binding: Span,
+    /// Holds `Some`/`Ok` (true) or `None`/`Err` (false)
+    present: bool,
+}
 
 impl<'a> TypeChecker<'a> {
     pub(super) fn narrow_condition(
@@ -61,6 +71,161 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Names proven to hold `Some`/`Ok` when `expr` is true and when it is false
+    pub(super) fn presence_condition(&self, expr: &Expr) -> (HashSet<String>, HashSet<String>) {
+        self.guard_condition(expr, true)
+    }
+
+    /// Names proven to hold `None`/`Err` when `expr` is true and when it is false
+    pub(super) fn absence_condition(&self, expr: &Expr) -> (HashSet<String>, HashSet<String>) {
+        self.guard_condition(expr, false)
+    }
+
+    /// Names proven to hold `Some`/`Ok` (`present`) or `None`/`Err` (`!present`)
+    /// when `expr` is true and when it is false
+    fn guard_condition(&self, expr: &Expr, present: bool) -> (HashSet<String>, HashSet<String>) {
+        match expr {
+            Expr::Binary(binary) => {
+                let (left_true, left_false) = self.guard_condition(&binary.left, present);
+                let (right_true, right_false) = self.guard_condition(&binary.right, present);
+                match binary.op {
+                    BinaryOp::And => (&left_true | &right_true, &left_false & &right_false),
+                    BinaryOp::Or => (&left_true & &right_true, &left_false | &right_false),
+                    _ => (HashSet::new(), HashSet::new()),
+                }
+            }
+            Expr::Unary(unary) if unary.op == UnaryOp::Not => {
+                let (true_set, false_set) = self.guard_condition(&unary.expr, present);
+                (false_set, true_set)
+            }
+            Expr::Call(call) => {
+                let Some((name, positive)) = self.extract_presence_guard(call) else {
+                    return (HashSet::new(), HashSet::new());
+                };
+                let proven = HashSet::from([name]);
+                if positive == present {
+                    (proven, HashSet::new())
+                } else {
+                    (HashSet::new(), proven)
+                }
+            }
+            Expr::Group(group) => self.guard_condition(&group.expr, present),
+            _ => (HashSet::new(), HashSet::new()),
+        }
+    }
+
+    /// Match `is_some(x)`/`is_ok(x)` (positive) and `is_none(x)`/`is_err(x)` (negative)
+    fn extract_presence_guard(&self, call: &crate::ast::CallExpr) -> Option<(String, bool)> {
+        let Expr::Identifier(callee) = call.callee.as_ref() else {
+            return None;
+        };
+        // A user function named `is_some` is not the builtin guard
+        if self.symbol_table.lookup(&callee.name).is_some() {
+            return None;
+        }
+        let positive = match callee.name.as_str() {
+            "is_some" | "is_ok" => true,
+            "is_none" | "is_err" => false,
+            _ => return None,
+        };
+        match call.args.as_slice() {
+            [Expr::Identifier(arg)] => Some((arg.name.clone(), positive)),
+            _ => None,
+        }
+    }
+
+    /// Record that the named bindings hold `Some`/`Ok` until the current scope exits
+    pub(super) fn prove_present(&mut self, names: &HashSet<String>) {
+        self.record_guarded(names, true);
+    }
+
+    /// Record that the named bindings hold `None`/`Err` until the current scope exits
+    pub(super) fn prove_absent(&mut self, names: &HashSet<String>) {
+        self.record_guarded(names, false);
+    }
+
+    fn record_guarded(&mut self, names: &HashSet<String>, present: bool) {
+        for name in names {
+            let Some(binding) = self.symbol_table.lookup(name).map(|symbol| symbol.span) else {
+                continue;
+            };
+            if let Some(frame) = self.guarded_values.last_mut() {
+                frame.insert(name.clone(), GuardedValue { binding, present });
+            }
+        }
+    }
+
+    /// What the innermost guard still in force proved about `name`
+    fn guarded_value(&self, name: &str) -> Option<&GuardedValue> {
+        let symbol = self.symbol_table.lookup(name)?;
+        self.guarded_values
+            .iter()
+            .rev()
+            .filter_map(|frame| frame.get(name))
+            .find(|guarded| guarded.binding == symbol.span)
+    }
+
+    /// Whether `name` still refers to a binding proven to hold `Some`/`Ok`
+    pub(super) fn is_proven_present(&self, name: &str) -> bool {
+        self.guarded_value(name)
+            .is_some_and(|guarded| guarded.present)
+    }
+
+    /// The narrowed type of a guarded binding and the wrapper a read of it unwraps:
+    /// `T` for `Option<T>` proven `Some`, `T` or `E` for `Result<T, E>` proven `Ok`
+    /// or `Err`. An `Option` proven `None` carries no payload and is not narrowed.
+    pub(super) fn guarded_payload(&self, name: &str) -> Option<(PayloadKind, Type)> {
+        let present = self.guarded_value(name)?.present;
+        let declared = self.symbol_table.lookup(name)?.ty.normalized();
+        match declared {
+            Type::Generic {
+                name,
+                mut type_args,
+            } if name == "Option" && type_args.len() == 1 => {
+                present.then(|| (PayloadKind::Option, type_args.remove(0)))
+            }
+            Type::Generic {
+                name,
+                mut type_args,
+            } if name == "Result" && type_args.len() == 2 => {
+                let payload = type_args.remove(if present { 0 } else { 1 });
+                Some((PayloadKind::Result, payload))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check an operand that takes the `Option`/`Result` wrapper itself, so a
+    /// guard-narrowed binding is read as its declared type rather than its payload
+    pub(super) fn check_wrapped_operand(&mut self, expr: &Expr) -> Type {
+        let ty = self.check_expr(expr);
+        if let Expr::Identifier(id) = expr {
+            if id.unwrap_payload.take().is_some() {
+                if let Some(symbol) = self.symbol_table.lookup(&id.name) {
+                    return symbol.ty.clone();
+                }
+            }
+        }
+        ty
+    }
+
+    /// Drop guard proofs for a binding that is reassigned
+    pub(super) fn forget_present(&mut self, name: &str) {
+        for frame in &mut self.guarded_values {
+            frame.remove(name);
+        }
+    }
+
+    /// Drop guard proofs for every binding a loop body reassigns, since the
+    /// assignment may run before an earlier statement on the next iteration
+    pub(super) fn forget_assigned_in(&mut self, block: &Block) {
+        let mut names = HashSet::new();
+        collect_assigned_names(block, &mut names);
+        for name in names {
+            self.forget_present(&name);
+        }
+    }
+
     fn extract_literal_guard(&self, left: &Expr, right: &Expr) -> Option<(String, Type)> {
         if let (Expr::Identifier(id), Expr::Literal(lit, _)) = (left, right) {
             return Some((id.name.clone(), literal_type(lit)));
@@ -195,9 +360,55 @@ impl<'a> TypeChecker<'a> {
     }
 }
 
+fn collect_assigned_names(block: &Block, names: &mut HashSet<String>) {
+    for stmt in &block.statements {
+        collect_assigned_names_in_stmt(stmt, names);
+    }
+}
+
+fn collect_assigned_names_in_stmt(stmt: &Stmt, names: &mut HashSet<String>) {
+    let target = match stmt {
+        Stmt::Assign(assign) => Some(&assign.target),
+        Stmt::CompoundAssign(compound) => Some(&compound.target),
+        Stmt::Increment(inc) => Some(&inc.target),
+        Stmt::Decrement(dec) => Some(&dec.target),
+        Stmt::If(if_stmt) => {
+            collect_assigned_names(&if_stmt.then_block, names);
+            if let Some(else_block) = &if_stmt.else_block {
+                collect_assigned_names(else_block, names);
+            }
+            None
+        }
+        Stmt::While(while_stmt) => {
+            collect_assigned_names(&while_stmt.body, names);
+            None
+        }
+        Stmt::For(for_stmt) => {
+            collect_assigned_names_in_stmt(&for_stmt.init, names);
+            collect_assigned_names_in_stmt(&for_stmt.step, names);
+            collect_assigned_names(&for_stmt.body, names);
+            None
+        }
+        Stmt::ForIn(for_in_stmt) => {
+            collect_assigned_names(&for_in_stmt.body, names);
+            None
+        }
+        Stmt::TryCatch(try_catch) => {
+            collect_assigned_names(&try_catch.body, names);
+            collect_assigned_names(&try_catch.handler, names);
+            None
+        }
+        _ => None,
+    };
+    if let Some(AssignTarget::Name(id)) = target {
+        names.insert(id.name.clone());
+    }
+}
+
 fn literal_type(lit: &Literal) -> Type {
     match lit {
         Literal::Number(_) => Type::Number,
+        Literal::Int(_) => Type::Int,
         Literal::String(_) => Type::String,
         Literal::Bool(_) => Type::Bool,
         Literal::Null => Type::Null,
@@ -224,6 +435,7 @@ fn type_from_typeof_value(value: &str) -> Option<Type> {
     match value {
         "string" => Some(Type::String),
         "number" => Some(Type::Number),
+        "int" => Some(Type::Int),
         "bool" => Some(Type::Bool),
         "null" => Some(Type::Null),
         "array" => Some(Type::Array(Box::new(Type::Unknown))),
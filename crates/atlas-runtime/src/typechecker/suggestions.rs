@@ -44,6 +44,17 @@ pub fn suggest_type_mismatch(expected: &Type, found: &Type) -> Option<String> {
     }
 }
 
+/// Suggest an explicit conversion when an int meets a number
+pub fn suggest_int_number_mix(left: &Type, right: &Type) -> Option<String> {
+    match (left, right) {
+        (Type::Int, Type::Number) | (Type::Number, Type::Int) => Some(
+            "int and number do not mix implicitly; convert one side with `toInt(x)` or `toFloat(x)`"
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
 /// Suggest a fix for a binary operator type error.
 pub fn suggest_binary_operator_fix(op: &str, left: &Type, right: &Type) -> Option<String> {
     let left_norm = left.normalized();
@@ -57,6 +68,12 @@ pub fn suggest_binary_operator_fix(op: &str, left: &Type, right: &Type) -> Optio
         ("+", Type::String, Type::Number) => {
             Some("convert the number to string first: `left + str(right)`".to_string())
         }
+        // int + number → suggest an explicit conversion
+        ("+" | "-" | "*" | "/" | "%", _, _)
+            if suggest_int_number_mix(&left_norm, &right_norm).is_some() =>
+        {
+            suggest_int_number_mix(&left_norm, &right_norm)
+        }
         // string - string → suggest wrong operator
         ("-" | "*" | "/" | "%", Type::String, Type::String) => Some(
             "arithmetic operators only work with numbers; for strings, use string methods"
@@ -175,6 +192,16 @@ pub fn suggest_similar_name<'a>(
     unknown: &str,
     known_names: impl Iterator<Item = &'a str>,
 ) -> Option<String> {
+    closest_name(unknown, known_names).map(|name| format!("did you mean '{}'?", name))
+}
+
+/// Find the known name closest to `unknown` within the typo threshold.
+///
+/// Ties are broken alphabetically so the result does not depend on iteration order.
+pub fn closest_name<'a>(
+    unknown: &str,
+    known_names: impl Iterator<Item = &'a str>,
+) -> Option<&'a str> {
     let max_distance = match unknown.len() {
         0..=2 => 1,
         3..=5 => 2,
@@ -184,13 +211,22 @@ pub fn suggest_similar_name<'a>(
     let mut best: Option<(&str, usize)> = None;
 
     for name in known_names {
+        if name == unknown {
+            continue;
+        }
         let dist = levenshtein_distance(unknown, name);
-        if dist <= max_distance && (best.is_none() || dist < best.unwrap().1) {
+        let closer = match best {
+            None => true,
+            Some((best_name, best_dist)) => {
+                dist < best_dist || (dist == best_dist && name < best_name)
+            }
+        };
+        if dist <= max_distance && closer {
             best = Some((name, dist));
         }
     }
 
-    best.map(|(name, _)| format!("did you mean '{}'?", name))
+    best.map(|(name, _)| name)
 }
 
 /// Compute Levenshtein edit distance between two strings.
@@ -300,6 +336,16 @@ mod tests {
         assert!(suggestion.unwrap().contains("print"));
     }
 
+    #[test]
+    fn test_closest_name_tie_breaks_alphabetically() {
+        let names = ["bat", "cat", "hat"];
+        assert_eq!(closest_name("rat", names.iter().copied()), Some("bat"));
+        assert_eq!(
+            closest_name("rat", names.iter().rev().copied()),
+            Some("bat")
+        );
+    }
+
     #[test]
     fn test_suggest_similar_name_no_match() {
         let names = ["print", "len", "str"];
@@ -27,8 +27,10 @@ pub struct StructuralMemberType {
 pub enum Type {
     /// Never type (empty set of values)
     Never,
-    /// Number type (unified int/float)
+    /// Number type (IEEE 754 double-precision)
     Number,
+    /// Integer type (64-bit, overflow-checked)
+    Int,
     /// String type
     String,
     /// Boolean type
@@ -71,6 +73,47 @@ pub enum Type {
 }
 
 impl Type {
+    /// Construct the type of a string-keyed map with values of type `value`.
+    ///
+    /// Represented as the built-in generic `Map<T>` and displayed as `{string: T}`.
+    pub fn map(value: Type) -> Type {
+        Type::Generic {
+            name: "Map".to_string(),
+            type_args: vec![value],
+        }
+    }
+
+    /// Construct the type of the error bound by a `catch` clause.
+    ///
+    /// A record with `code` and `message` string fields, readable as `e.code`.
+    pub fn caught_error() -> Type {
+        Type::Structural {
+            members: ["code", "message"]
+                .into_iter()
+                .map(|name| StructuralMemberType {
+                    name: name.to_string(),
+                    ty: Type::String,
+                })
+                .collect(),
+        }
+    }
+
+    /// Construct the type of a `setOf` set with elements of type `element`.
+    pub fn set(element: Type) -> Type {
+        Type::Generic {
+            name: "Set".to_string(),
+            type_args: vec![element],
+        }
+    }
+
+    /// Construct the type of a future that resolves to `value`.
+    pub fn future(value: Type) -> Type {
+        Type::Generic {
+            name: "Future".to_string(),
+            type_args: vec![value],
+        }
+    }
+
     /// Construct a normalized union type from members.
     pub fn union(mut members: Vec<Type>) -> Type {
         let mut flat = Vec::new();
@@ -271,6 +314,7 @@ impl Type {
         match self {
             Type::Never => "never".to_string(),
             Type::Number => "number".to_string(),
+            Type::Int => "int".to_string(),
             Type::String => "string".to_string(),
             Type::Bool => "bool".to_string(),
             Type::Null => "null".to_string(),
@@ -306,6 +350,9 @@ impl Type {
                 result
             }
             Type::JsonValue => "json".to_string(),
+            Type::Generic { name, type_args } if name == "Map" && type_args.len() == 1 => {
+                format!("{{string: {}}}", type_args[0].display_name())
+            }
             Type::Generic { name, type_args } => {
                 let args = type_args
                     .iter()
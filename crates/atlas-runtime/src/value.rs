@@ -13,6 +13,8 @@
 //! ### Copy-on-write (cheap to clone via refcount, independent on mutation)
 //! - `String(Arc<String>)` — immutable interned string, shared until reassigned
 //! - `Array(ValueArray)` — `Arc<Vec<Value>>` with `Arc::make_mut` CoW
+//! - `Map(ValueMap)` — `Arc<HashMap<String, Value>>` with CoW (map literals)
+//! - `Set(ValueHashSet)` — `Arc<AtlasHashSet>` with CoW (`setOf`)
 //! - `HashMap(ValueHashMap)` — `Arc<AtlasHashMap>` with CoW
 //! - `HashSet(ValueHashSet)` — `Arc<AtlasHashSet>` with CoW
 //! - `Queue(ValueQueue)` — `Arc<VecDeque<Value>>` with CoW
@@ -204,6 +206,13 @@ impl ValueMap {
         self.0.values()
     }
 
+    /// Entries sorted by key, for deterministic display and serialization.
+    pub fn sorted_entries(&self) -> Vec<(&String, &Value)> {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+
     pub fn is_exclusively_owned(&self) -> bool {
         Arc::strong_count(&self.0) == 1
     }
@@ -406,6 +415,8 @@ pub type NativeFn = Arc<dyn Fn(&[Value]) -> Result<Value, RuntimeError> + Send +
 pub enum Value {
     /// Numeric value (IEEE 754 double-precision)
     Number(f64),
+    /// Integer value (64-bit, arithmetic is overflow-checked)
+    Int(i64),
     /// String value (reference-counted, immutable)
     String(Arc<String>),
     /// Boolean value
@@ -414,6 +425,8 @@ pub enum Value {
     Null,
     /// Array value (copy-on-write, value semantics)
     Array(ValueArray),
+    /// Map value from a `{ key: value }` literal (string keys, copy-on-write)
+    Map(ValueMap),
     /// Function reference (bytecode or builtin)
     Function(FunctionRef),
     /// Builtin stdlib function (dispatched through the registry by name)
@@ -430,6 +443,8 @@ pub enum Value {
     HashMap(ValueHashMap),
     /// HashSet collection (unique values)
     HashSet(ValueHashSet),
+    /// Set of primitive values built by `setOf`
+    Set(ValueHashSet),
     /// Queue collection (FIFO)
     Queue(ValueQueue),
     /// Stack collection (LIFO)
@@ -506,10 +521,12 @@ impl Value {
     pub fn type_name(&self) -> &str {
         match self {
             Value::Number(_) => "number",
+            Value::Int(_) => "int",
             Value::String(_) => "string",
             Value::Bool(_) => "bool",
             Value::Null => "null",
             Value::Array(_) => "array",
+            Value::Map(_) => "map",
             Value::Function(_) => "function",
             Value::Builtin(_) => "builtin",
             Value::NativeFunction(_) => "function",
@@ -518,6 +535,7 @@ impl Value {
             Value::Result(_) => "Result",
             Value::HashMap(_) => "hashmap",
             Value::HashSet(_) => "hashset",
+            Value::Set(_) => "set",
             Value::Queue(_) => "queue",
             Value::Stack(_) => "stack",
             Value::Regex(_) => "regex",
@@ -553,8 +571,8 @@ impl PartialEq for Value {
     /// Equality contract:
     ///
     /// **Value types** (content equality — two equal values may be different allocations):
-    /// - Number, String, Bool, Null: primitive equality
-    /// - Array, HashMap, HashSet, Queue, Stack: CoW wrappers compare by content
+    /// - Number, Int, String, Bool, Null: primitive equality (an Int never equals a Number)
+    /// - Array, Map, HashMap, HashSet, Queue, Stack: CoW wrappers compare by content
     /// - Regex: compare by pattern string
     /// - DateTime: compare timestamps
     /// - HttpRequest, HttpResponse: compare by field content
@@ -571,12 +589,15 @@ impl PartialEq for Value {
         match (self, other) {
             // --- Value types: content equality ---
             (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
             (Value::String(a), Value::String(b)) => a == b,
             (Value::Bool(a), Value::Bool(b)) => a == b,
             (Value::Null, Value::Null) => true,
             (Value::Array(a), Value::Array(b)) => a == b,
+            (Value::Map(a), Value::Map(b)) => a == b,
             (Value::HashMap(a), Value::HashMap(b)) => a == b,
             (Value::HashSet(a), Value::HashSet(b)) => a == b,
+            (Value::Set(a), Value::Set(b)) => a == b,
             (Value::Queue(a), Value::Queue(b)) => a == b,
             (Value::Stack(a), Value::Stack(b)) => a == b,
             (Value::Regex(a), Value::Regex(b)) => a.as_str() == b.as_str(),
@@ -616,6 +637,7 @@ impl fmt::Display for Value {
                     write!(f, "{}", n)
                 }
             }
+            Value::Int(n) => write!(f, "{}", n),
             Value::String(s) => write!(f, "{}", s.as_ref()),
             Value::Bool(b) => write!(f, "{}", b),
             Value::Null => write!(f, "null"),
@@ -623,6 +645,14 @@ impl fmt::Display for Value {
                 let elements: Vec<String> = arr.iter().map(|v| v.to_string()).collect();
                 write!(f, "[{}]", elements.join(", "))
             }
+            Value::Map(map) => {
+                let entries: Vec<String> = map
+                    .sorted_entries()
+                    .into_iter()
+                    .map(|(k, v)| format!("{}: {}", k, v))
+                    .collect();
+                write!(f, "{{{}}}", entries.join(", "))
+            }
             Value::Function(func) => write!(f, "<fn {}>", func.name),
             Value::Builtin(name) => write!(f, "<builtin {}>", name),
             Value::NativeFunction(_) => write!(f, "<native fn>"),
@@ -637,6 +667,15 @@ impl fmt::Display for Value {
             },
             Value::HashMap(map) => write!(f, "<HashMap size={}>", map.inner().len()),
             Value::HashSet(set) => write!(f, "<HashSet size={}>", set.inner().len()),
+            Value::Set(set) => {
+                let elements: Vec<String> = set
+                    .inner()
+                    .sorted_keys()
+                    .iter()
+                    .map(|k| k.to_value().to_string())
+                    .collect();
+                write!(f, "{{{}}}", elements.join(", "))
+            }
             Value::Queue(queue) => write!(f, "<Queue size={}>", queue.inner().len()),
             Value::Stack(stack) => write!(f, "<Stack size={}>", stack.inner().len()),
             Value::Regex(r) => write!(f, "<Regex /{}/>", r.as_str()),
@@ -658,10 +697,12 @@ impl fmt::Debug for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Value::Number(n) => write!(f, "Number({})", n),
+            Value::Int(n) => write!(f, "Int({})", n),
             Value::String(s) => write!(f, "String({:?})", s),
             Value::Bool(b) => write!(f, "Bool({})", b),
             Value::Null => write!(f, "Null"),
             Value::Array(arr) => write!(f, "Array({:?})", arr.as_slice()),
+            Value::Map(map) => write!(f, "Map({:?})", map.sorted_entries()),
             Value::Function(func) => write!(f, "Function({:?})", func),
             Value::Builtin(name) => write!(f, "Builtin({:?})", name),
             Value::NativeFunction(_) => write!(f, "NativeFunction(<closure>)"),
@@ -670,6 +711,7 @@ impl fmt::Debug for Value {
             Value::Result(res) => write!(f, "Result({:?})", res),
             Value::HashMap(map) => write!(f, "HashMap(size={})", map.inner().len()),
             Value::HashSet(set) => write!(f, "HashSet(size={})", set.inner().len()),
+            Value::Set(set) => write!(f, "Set({:?})", set.inner().sorted_keys()),
             Value::Queue(queue) => write!(f, "Queue(size={})", queue.inner().len()),
             Value::Stack(stack) => write!(f, "Stack(size={})", stack.inner().len()),
             Value::Regex(r) => write!(f, "Regex(/{}/)", r.as_str()),
@@ -711,12 +753,30 @@ pub enum RuntimeError {
     /// Invalid numeric result (NaN, Infinity)
     #[error("Invalid numeric result")]
     InvalidNumericResult { span: crate::span::Span },
+    /// Integer arithmetic overflowed the 64-bit range
+    #[error("Integer overflow")]
+    IntegerOverflow { span: crate::span::Span },
     /// Unknown opcode (VM error)
     #[error("Unknown opcode")]
     UnknownOpcode { span: crate::span::Span },
     /// Stack underflow (VM error)
     #[error("Stack underflow")]
     StackUnderflow { span: crate::span::Span },
+
+    #[error("Operand stack overflow: more than {limit} values")]
+    StackOverflow {
+        limit: usize,
+        span: crate::span::Span,
+    },
+
+    #[error("Call depth limit exceeded: more than {limit} nested calls")]
+    CallDepthExceeded {
+        limit: usize,
+        span: crate::span::Span,
+    },
+    /// Gas budget of a metered VM run spent
+    #[error("Gas exhausted: budget of {limit} spent")]
+    GasExhausted { limit: u64, span: crate::span::Span },
     /// Unknown function
     #[error("Unknown function: {name}")]
     UnknownFunction {
@@ -757,6 +817,17 @@ pub enum RuntimeError {
         var: String,
         span: crate::span::Span,
     },
+    /// Permission denied - system clock
+    #[error("Permission denied: clock access")]
+    ClockPermissionDenied { span: crate::span::Span },
+    /// Resource limit exceeded (heap, instructions, or wall time)
+    #[error("Resource limit exceeded: {resource} ({used} > {limit})")]
+    ResourceExhausted {
+        resource: crate::security::ResourceLimit,
+        used: u64,
+        limit: u64,
+        span: crate::span::Span,
+    },
     /// I/O error (file operations)
     #[error("I/O error: {message}")]
     IoError {
@@ -769,6 +840,19 @@ pub enum RuntimeError {
         type_name: String,
         span: crate::span::Span,
     },
+    /// Map indexed with a key it does not contain
+    #[error("Map key not found: '{key}'")]
+    MapKeyNotFound {
+        key: String,
+        span: crate::span::Span,
+    },
+    /// Malformed regular expression pattern
+    #[error("Invalid regex pattern '{pattern}': {message}")]
+    InvalidRegex {
+        pattern: String,
+        message: String,
+        span: crate::span::Span,
+    },
 }
 
 impl RuntimeError {
@@ -780,8 +864,12 @@ impl RuntimeError {
             RuntimeError::DivideByZero { span } => *span,
             RuntimeError::OutOfBounds { span } => *span,
             RuntimeError::InvalidNumericResult { span } => *span,
+            RuntimeError::IntegerOverflow { span } => *span,
             RuntimeError::UnknownOpcode { span } => *span,
             RuntimeError::StackUnderflow { span } => *span,
+            RuntimeError::StackOverflow { span, .. } => *span,
+            RuntimeError::CallDepthExceeded { span, .. } => *span,
+            RuntimeError::GasExhausted { span, .. } => *span,
             RuntimeError::UnknownFunction { span, .. } => *span,
             RuntimeError::InvalidStdlibArgument { span, .. } => *span,
             RuntimeError::InvalidIndex { span } => *span,
@@ -789,10 +877,67 @@ impl RuntimeError {
             RuntimeError::NetworkPermissionDenied { span, .. } => *span,
             RuntimeError::ProcessPermissionDenied { span, .. } => *span,
             RuntimeError::EnvironmentPermissionDenied { span, .. } => *span,
+            RuntimeError::ClockPermissionDenied { span } => *span,
+            RuntimeError::ResourceExhausted { span, .. } => *span,
             RuntimeError::IoError { span, .. } => *span,
             RuntimeError::UnhashableType { span, .. } => *span,
+            RuntimeError::MapKeyNotFound { span, .. } => *span,
+            RuntimeError::InvalidRegex { span, .. } => *span,
         }
     }
+
+    /// Whether a `try`/`catch` statement may handle this error
+    ///
+    /// Exhausted resource budgets, VM limits, and VM-internal faults always
+    /// propagate, so a script cannot swallow a limit imposed by its host.
+    pub fn is_catchable(&self) -> bool {
+        !matches!(
+            self,
+            RuntimeError::ResourceExhausted { .. }
+                | RuntimeError::CallDepthExceeded { .. }
+                | RuntimeError::GasExhausted { .. }
+                | RuntimeError::StackOverflow { .. }
+                | RuntimeError::UnknownOpcode { .. }
+                | RuntimeError::StackUnderflow { .. }
+        )
+    }
+}
+
+/// Apply an arithmetic or comparison operator to two ints
+///
+/// Shared by the interpreter and VM so both engines agree on overflow and
+/// division semantics. Arithmetic is overflow-checked; division and modulo
+/// truncate toward zero.
+pub fn int_binary_op(
+    op: crate::ast::BinaryOp,
+    a: i64,
+    b: i64,
+    span: crate::span::Span,
+) -> Result<Value, RuntimeError> {
+    use crate::ast::BinaryOp;
+
+    let overflow = || RuntimeError::IntegerOverflow { span };
+    let result = match op {
+        BinaryOp::Add => a.checked_add(b).ok_or_else(overflow)?,
+        BinaryOp::Sub => a.checked_sub(b).ok_or_else(overflow)?,
+        BinaryOp::Mul => a.checked_mul(b).ok_or_else(overflow)?,
+        BinaryOp::Div | BinaryOp::Mod if b == 0 => return Err(RuntimeError::DivideByZero { span }),
+        BinaryOp::Div => a.checked_div(b).ok_or_else(overflow)?,
+        BinaryOp::Mod => a.checked_rem(b).ok_or_else(overflow)?,
+        BinaryOp::Lt => return Ok(Value::Bool(a < b)),
+        BinaryOp::Le => return Ok(Value::Bool(a <= b)),
+        BinaryOp::Gt => return Ok(Value::Bool(a > b)),
+        BinaryOp::Ge => return Ok(Value::Bool(a >= b)),
+        BinaryOp::Eq => return Ok(Value::Bool(a == b)),
+        BinaryOp::Ne => return Ok(Value::Bool(a != b)),
+        BinaryOp::And | BinaryOp::Or => {
+            return Err(RuntimeError::TypeError {
+                msg: "Expected bool operands for logical operator".to_string(),
+                span,
+            })
+        }
+    };
+    Ok(Value::Int(result))
 }
 
 #[cfg(test)]
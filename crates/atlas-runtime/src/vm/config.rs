@@ -0,0 +1,59 @@
+//! VM configuration
+
+/// Default maximum number of values on the operand stack
+pub const DEFAULT_MAX_STACK_SIZE: usize = 1 << 20;
+
+/// Default maximum number of active call frames
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 10_000;
+
+/// Structural limits enforced by the VM while executing
+///
+/// Exceeding a limit stops execution with a runtime error instead of
+/// exhausting memory: `AT0010` for the call depth and `AT0011` for the
+/// operand stack.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_runtime::vm::VmConfig;
+///
+/// let config = VmConfig::new()
+///     .with_max_call_depth(256)
+///     .with_max_stack_size(4096);
+/// assert_eq!(config.max_call_depth, 256);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VmConfig {
+    /// Maximum number of values on the operand stack (locals included)
+    pub max_stack_size: usize,
+    /// Maximum number of active call frames, counting the `<main>` frame
+    pub max_call_depth: usize,
+}
+
+impl VmConfig {
+    /// Create a config with the default limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum operand stack size
+    pub fn with_max_stack_size(mut self, max_stack_size: usize) -> Self {
+        self.max_stack_size = max_stack_size;
+        self
+    }
+
+    /// Set the maximum call depth
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+}
+
+impl Default for VmConfig {
+    fn default() -> Self {
+        Self {
+            max_stack_size: DEFAULT_MAX_STACK_SIZE,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+        }
+    }
+}
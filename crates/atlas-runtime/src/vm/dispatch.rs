@@ -50,15 +50,21 @@ static OPCODE_TABLE: [Option<Opcode>; 256] = {
     table[0x50] = Some(Opcode::Jump);
     table[0x51] = Some(Opcode::JumpIfFalse);
     table[0x52] = Some(Opcode::Loop);
+    table[0x53] = Some(Opcode::TryBegin);
+    table[0x54] = Some(Opcode::TryEnd);
 
     // Functions (0x60-0x61)
     table[0x60] = Some(Opcode::Call);
     table[0x61] = Some(Opcode::Return);
+    table[0x62] = Some(Opcode::TailCall);
 
-    // Arrays (0x70-0x72)
+    // Arrays and maps (0x70-0x75)
     table[0x70] = Some(Opcode::Array);
     table[0x71] = Some(Opcode::GetIndex);
     table[0x72] = Some(Opcode::SetIndex);
+    table[0x73] = Some(Opcode::NewMap);
+    table[0x74] = Some(Opcode::MapGet);
+    table[0x75] = Some(Opcode::MapSet);
 
     // Stack manipulation (0x80-0x81)
     table[0x80] = Some(Opcode::Pop);
@@ -100,13 +106,14 @@ pub fn operand_size(opcode: Opcode) -> usize {
         | Opcode::SetGlobal
         | Opcode::GetUpvalue
         | Opcode::SetUpvalue
-        | Opcode::Array => 2,
+        | Opcode::Array
+        | Opcode::NewMap => 2,
         // MakeClosure: two u16 operands (func_const_idx, n_upvalues) = 4 bytes
         Opcode::MakeClosure => 4,
         // i16 operand
-        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop => 2,
+        Opcode::Jump | Opcode::JumpIfFalse | Opcode::Loop | Opcode::TryBegin => 2,
         // u8 operand
-        Opcode::Call => 1,
+        Opcode::Call | Opcode::TailCall => 1,
         // No operand
         _ => 0,
     }
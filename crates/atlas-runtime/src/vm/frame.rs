@@ -1,5 +1,9 @@
 //! Call frame implementation for function calls
 
+use crate::debugger::protocol::SourceLocation;
+use crate::span::Span;
+use std::fmt;
+
 /// Call frame for function calls
 ///
 /// Each function call creates a new frame that tracks:
@@ -38,3 +42,40 @@ pub struct CallFrame {
     /// Upvalues captured at closure creation time (empty for plain functions)
     pub upvalues: std::sync::Arc<Vec<crate::value::Value>>,
 }
+
+/// Error handler installed by `TryBegin` and removed by `TryEnd`
+///
+/// When a catchable runtime error occurs while the handler is active, the VM
+/// unwinds the call frames and operand stack back to the recorded heights,
+/// pushes the structured error value and jumps to `catch_ip`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ErrorHandler {
+    /// Offset of the first instruction of the catch block
+    pub catch_ip: usize,
+    /// Operand stack height when the handler was installed
+    pub stack_len: usize,
+    /// Number of active call frames when the handler was installed
+    pub frame_depth: usize,
+}
+
+/// One entry of a runtime error's stack trace
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceFrame {
+    /// Function name ("<main>" for top-level code)
+    pub function_name: String,
+    /// Span of the failing instruction (innermost frame) or of the call site
+    pub span: Option<Span>,
+    /// Line and column of `span`, if the VM was given the source text
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for TraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at {}", self.function_name)?;
+        match (&self.location, self.span) {
+            (Some(loc), _) => write!(f, " ({}:{}:{})", loc.file, loc.line, loc.column),
+            (None, Some(span)) => write!(f, " (offset {})", span.start),
+            (None, None) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Gas metering
+//!
+//! A metered run charges every executed opcode a cost from a [`GasSchedule`]
+//! and stops with `AT0012` once the budget cannot cover the next
+//! instruction. Unlike the wall-time limit, the gas used by a program is
+//! deterministic: the same bytecode and input always consume the same amount.
+
+use crate::bytecode::Opcode;
+use crate::value::Value;
+
+/// Cost of an opcode not listed in [`GasSchedule::new`]
+pub const DEFAULT_OPCODE_COST: u64 = 1;
+
+/// Per-opcode gas costs
+///
+/// The default schedule charges 1 per instruction, with calls, closures and
+/// collection construction costing more since they allocate or do work
+/// proportional to their operands.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_runtime::bytecode::Opcode;
+/// use atlas_runtime::vm::GasSchedule;
+///
+/// let schedule = GasSchedule::new().with_cost(Opcode::Call, 50);
+/// assert_eq!(schedule.cost(Opcode::Call), 50);
+/// assert_eq!(schedule.cost(Opcode::Add), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasSchedule {
+    costs: [u64; 256],
+}
+
+impl GasSchedule {
+    /// Create the default schedule
+    pub fn new() -> Self {
+        Self::uniform(DEFAULT_OPCODE_COST)
+            .with_cost(Opcode::Call, 10)
+            .with_cost(Opcode::TailCall, 10)
+            .with_cost(Opcode::MakeClosure, 5)
+            .with_cost(Opcode::Array, 5)
+            .with_cost(Opcode::NewMap, 5)
+            .with_cost(Opcode::GetIndex, 2)
+            .with_cost(Opcode::SetIndex, 2)
+            .with_cost(Opcode::MapGet, 2)
+            .with_cost(Opcode::MapSet, 2)
+            .with_cost(Opcode::Halt, 0)
+    }
+
+    /// Create a schedule charging `cost` for every opcode
+    pub fn uniform(cost: u64) -> Self {
+        Self { costs: [cost; 256] }
+    }
+
+    /// Set the cost of one opcode
+    pub fn with_cost(mut self, opcode: Opcode, cost: u64) -> Self {
+        self.costs[opcode as usize] = cost;
+        self
+    }
+
+    /// The cost charged for executing `opcode`
+    pub fn cost(&self, opcode: Opcode) -> u64 {
+        self.costs[opcode as usize]
+    }
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Outcome of a successful gas-metered run
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeteredRun {
+    /// The program's result, as returned by [`VM::run`](super::VM::run)
+    pub value: Option<Value>,
+    /// Gas charged for the instructions executed
+    pub gas_used: u64,
+    /// Budget left over
+    pub gas_remaining: u64,
+}
+
+/// Gas accounting for one metered run
+#[derive(Debug, Clone)]
+pub(crate) struct GasMeter {
+    schedule: GasSchedule,
+    limit: u64,
+    used: u64,
+}
+
+impl GasMeter {
+    pub(crate) fn new(limit: u64, schedule: GasSchedule) -> Self {
+        Self {
+            schedule,
+            limit,
+            used: 0,
+        }
+    }
+
+    /// Charge `opcode`; returns `false`, charging nothing, if the budget cannot cover it
+    #[inline]
+    pub(crate) fn charge(&mut self, opcode: Opcode) -> bool {
+        let used = self.used.saturating_add(self.schedule.cost(opcode));
+        if used > self.limit {
+            return false;
+        }
+        self.used = used;
+        true
+    }
+
+    pub(crate) fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    pub(crate) fn used(&self) -> u64 {
+        self.used
+    }
+
+    pub(crate) fn remaining(&self) -> u64 {
+        self.limit - self.used
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meter_stops_before_overspending() {
+        let mut meter = GasMeter::new(25, GasSchedule::new());
+        assert!(meter.charge(Opcode::Call));
+        assert!(meter.charge(Opcode::Call));
+        assert!(!meter.charge(Opcode::Call));
+        // The failed charge is not recorded; cheaper opcodes still fit
+        assert_eq!(meter.used(), 20);
+        assert!(meter.charge(Opcode::Add));
+        assert_eq!(meter.remaining(), 4);
+    }
+
+    #[test]
+    fn test_zero_cost_opcodes_are_free() {
+        let mut meter = GasMeter::new(0, GasSchedule::uniform(0));
+        assert!(meter.charge(Opcode::Loop));
+        assert_eq!(meter.used(), 0);
+    }
+}
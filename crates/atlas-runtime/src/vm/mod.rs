@@ -5,19 +5,28 @@
 //! - Variables are stored in locals (stack) or globals (HashMap)
 //! - Control flow uses jumps and loops
 
+mod config;
 mod debugger;
 pub mod dispatch;
 mod frame;
+mod gas;
 mod profiler;
 
+pub use config::{VmConfig, DEFAULT_MAX_CALL_DEPTH, DEFAULT_MAX_STACK_SIZE};
 pub use debugger::{DebugAction, DebugHook, Debugger};
-pub use frame::CallFrame;
+use frame::ErrorHandler;
+pub use frame::{CallFrame, TraceFrame};
+use gas::GasMeter;
+pub use gas::{GasSchedule, MeteredRun, DEFAULT_OPCODE_COST};
 pub use profiler::Profiler;
 
+use crate::ast::BinaryOp;
 use crate::bytecode::{Bytecode, Opcode};
+use crate::debugger::source_map::SourceMap;
 use crate::ffi::{ExternFunction, LibraryLoader};
+use crate::security::limits::{self, ExecutionBudget};
 use crate::span::Span;
-use crate::value::{RuntimeError, Value, ValueArray, ValueHashMap, ValueHashSet};
+use crate::value::{RuntimeError, Value, ValueArray, ValueHashMap, ValueHashSet, ValueMap};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -56,6 +65,8 @@ pub struct VM {
     stack: Vec<Value>,
     /// Call frames (for function calls)
     frames: Vec<CallFrame>,
+    /// Active `try` handlers, innermost last
+    handlers: Vec<ErrorHandler>,
     /// Global variables
     globals: HashMap<String, Value>,
     /// Bytecode to execute
@@ -69,8 +80,21 @@ pub struct VM {
     /// Set to `true` by the execute_loop when the debugger requests a pause.
     /// Cleared by `run_debuggable` after it reads the flag.
     debug_pause_pending: bool,
+    /// Offset of the instruction the debugger last paused on. The hook is
+    /// skipped for that instruction once on resume so execution advances.
+    debug_resume_ip: Option<usize>,
     /// Security context for current execution (set during run())
     current_security: Option<std::sync::Arc<crate::security::SecurityContext>>,
+    /// Instruction and wall-time accounting for the security context's limits
+    budget: ExecutionBudget,
+    /// Gas accounting, present only during `run_metered`
+    gas: Option<GasMeter>,
+    /// Operand stack and call depth limits
+    config: VmConfig,
+    /// Line/column lookup for error traces (set via `set_source`)
+    source_map: Option<SourceMap>,
+    /// Call stack captured when the last `run` failed, innermost first
+    error_trace: Vec<TraceFrame>,
     /// Output writer for print() (defaults to stdout)
     output_writer: crate::stdlib::OutputWriter,
     /// FFI library loader (phase-10b)
@@ -115,13 +139,20 @@ impl VM {
         Self {
             stack: Vec::with_capacity(1024),
             frames: vec![main_frame],
+            handlers: Vec::new(),
             globals: HashMap::new(),
             bytecode,
             ip: 0,
             profiler: None,
             debugger: None,
             debug_pause_pending: false,
+            debug_resume_ip: None,
             current_security: None,
+            budget: ExecutionBudget::unlimited(),
+            gas: None,
+            config: VmConfig::default(),
+            source_map: None,
+            error_trace: Vec::new(),
             output_writer: crate::stdlib::stdout_writer(),
             library_loader: LibraryLoader::new(),
             extern_functions: HashMap::new(),
@@ -149,6 +180,23 @@ impl VM {
         vm
     }
 
+    /// Create a new VM with custom stack and call depth limits
+    pub fn with_config(bytecode: Bytecode, config: VmConfig) -> Self {
+        let mut vm = Self::new(bytecode);
+        vm.config = config;
+        vm
+    }
+
+    /// Replace the stack and call depth limits
+    pub fn set_config(&mut self, config: VmConfig) {
+        self.config = config;
+    }
+
+    /// The stack and call depth limits in effect
+    pub fn config(&self) -> &VmConfig {
+        &self.config
+    }
+
     /// Set the output writer (used by Runtime to redirect print() output)
     pub fn set_output_writer(&mut self, writer: crate::stdlib::OutputWriter) {
         self.output_writer = writer;
@@ -170,6 +218,23 @@ impl VM {
         &self.globals
     }
 
+    /// Call a global function by name
+    ///
+    /// Must follow a completed `run()`, which defines the program's functions
+    /// and installs the security context. Used by `atlas bench` to invoke the
+    /// same function repeatedly without recompiling a call expression.
+    pub fn call_global(&mut self, name: &str, args: Vec<Value>) -> Result<Value, RuntimeError> {
+        let func =
+            self.globals
+                .get(name)
+                .cloned()
+                .ok_or_else(|| RuntimeError::UndefinedVariable {
+                    name: name.to_string(),
+                    span: Span::dummy(),
+                })?;
+        self.vm_call_function_value(&func, args, Span::dummy())
+    }
+
     /// Load extern declarations from AST (phase-10b)
     ///
     /// Processes extern function declarations by loading libraries and looking up symbols.
@@ -330,6 +395,25 @@ impl VM {
         self.bytecode.get_span_for_offset(self.ip - 1)
     }
 
+    /// Attach the original source so error traces carry line/column
+    ///
+    /// Builds a [`SourceMap`] from the per-instruction spans the compiler
+    /// recorded in the bytecode's debug info.
+    pub fn set_source(&mut self, file: &str, source: &str) {
+        self.source_map = Some(SourceMap::from_debug_spans(
+            &self.bytecode.debug_info,
+            file,
+            Some(source),
+        ));
+    }
+
+    /// Stack trace captured when the last [`VM::run`] failed, innermost first
+    ///
+    /// Empty if the last run succeeded.
+    pub fn error_trace(&self) -> &[TraceFrame] {
+        &self.error_trace
+    }
+
     /// Get the source span for a specific instruction offset
     pub fn span_for_offset(&self, offset: usize) -> Option<crate::span::Span> {
         self.bytecode.get_span_for_offset(offset)
@@ -435,6 +519,7 @@ impl VM {
 
         // Run the execute loop (profiling hooks still active).
         self.current_security = Some(std::sync::Arc::new(security.clone()));
+        self.budget = ExecutionBudget::start(security.limits());
         if let Some(ref mut profiler) = self.profiler {
             if profiler.is_enabled() {
                 profiler.start_timing();
@@ -459,6 +544,7 @@ impl VM {
             debug_state.pause(reason, location, ip);
             debug_state.clear_step_mode();
             self.debug_pause_pending = false;
+            self.debug_resume_ip = Some(ip);
             Ok(VmRunResult::Paused { ip })
         } else {
             debug_state.stop();
@@ -473,13 +559,18 @@ impl VM {
     ) -> Result<Option<Value>, RuntimeError> {
         // Store security context for builtin calls
         self.current_security = Some(std::sync::Arc::new(security.clone()));
+        self.budget = ExecutionBudget::start(security.limits());
         // Start profiling timer if profiler is enabled
         if let Some(ref mut profiler) = self.profiler {
             if profiler.is_enabled() {
                 profiler.start_timing();
             }
         }
+        self.error_trace.clear();
         let result = self.execute_until_end();
+        if result.is_err() {
+            self.error_trace = self.capture_trace();
+        }
         // Stop profiling timer
         if let Some(ref mut profiler) = self.profiler {
             if profiler.is_enabled() {
@@ -489,6 +580,28 @@ impl VM {
         result
     }
 
+    /// Execute the bytecode with a gas budget of `limit`
+    ///
+    /// Each executed opcode is charged its cost from `schedule`. Execution
+    /// stops with `RuntimeError::GasExhausted` before the first instruction
+    /// the remaining gas cannot cover; otherwise the result reports the gas
+    /// consumed and left over.
+    pub fn run_metered(
+        &mut self,
+        security: &crate::security::SecurityContext,
+        limit: u64,
+        schedule: GasSchedule,
+    ) -> Result<MeteredRun, RuntimeError> {
+        self.gas = Some(GasMeter::new(limit, schedule));
+        let result = self.run(security);
+        let meter = self.gas.take().expect("gas meter removed during run");
+        Ok(MeteredRun {
+            value: result?,
+            gas_used: meter.used(),
+            gas_remaining: meter.remaining(),
+        })
+    }
+
     /// Execute bytecode until reaching the end of instructions
     fn execute_until_end(&mut self) -> Result<Option<Value>, RuntimeError> {
         self.execute_loop(None)
@@ -497,9 +610,44 @@ impl VM {
     /// Execute bytecode until a specific frame depth is reached (for function calls)
     /// If target_frame_depth is Some(n), stops when frames.len() <= n
     /// If target_frame_depth is None, runs until end of bytecode
+    ///
+    /// Catchable errors raised while a handler installed during this call is
+    /// active resume execution at that handler's catch block.
     fn execute_loop(
         &mut self,
         target_frame_depth: Option<usize>,
+    ) -> Result<Option<Value>, RuntimeError> {
+        let handler_floor = self.handlers.len();
+        loop {
+            match self.execute_instructions(target_frame_depth) {
+                Err(error) if error.is_catchable() && self.handlers.len() > handler_floor => {
+                    self.unwind_to_handler(&error);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Unwind to the innermost handler and enter its catch block
+    fn unwind_to_handler(&mut self, error: &RuntimeError) {
+        let Some(handler) = self.handlers.pop() else {
+            return;
+        };
+        self.frames.truncate(handler.frame_depth);
+        #[cfg(debug_assertions)]
+        self.consumed_slots.truncate(handler.frame_depth);
+        self.stack.truncate(handler.stack_len);
+        #[cfg(debug_assertions)]
+        self.value_origins.truncate(handler.stack_len);
+        self.push(crate::runtime::runtime_error_value(error));
+        self.ip = handler.catch_ip;
+    }
+
+    /// Dispatch instructions until the program ends, the target frame returns,
+    /// or an instruction fails
+    fn execute_instructions(
+        &mut self,
+        target_frame_depth: Option<usize>,
     ) -> Result<Option<Value>, RuntimeError> {
         loop {
             // Check termination conditions
@@ -517,10 +665,27 @@ impl VM {
 
             let opcode = self.read_opcode()?;
 
+            // Resource limits (zero overhead when no budget is set)
+            if self.budget.is_active() {
+                self.charge_budget()?;
+            }
+            if self.gas.is_some() {
+                self.charge_gas(opcode)?;
+            }
+
+            if self.stack.len() > self.config.max_stack_size {
+                return Err(RuntimeError::StackOverflow {
+                    limit: self.config.max_stack_size,
+                    span: self.current_span().unwrap_or_else(Span::dummy),
+                });
+            }
+
             // Debugger hook: before instruction (zero overhead when disabled)
             if let Some(ref mut debugger) = self.debugger {
-                if debugger.is_enabled() {
-                    let current_ip = self.ip - 1;
+                let current_ip = self.ip - 1;
+                if self.debug_resume_ip.take() == Some(current_ip) {
+                    // Resuming from a pause on this instruction – run it.
+                } else if debugger.is_enabled() {
                     let frame_depth = self.frames.len();
                     let action =
                         debugger.before_instruction_with_depth(current_ip, opcode, frame_depth);
@@ -545,6 +710,8 @@ impl VM {
                     profiler.record_instruction_at(opcode, instruction_ip);
                     profiler.update_value_stack_depth(self.stack.len());
                     profiler.update_frame_depth(self.frames.len());
+                    profiler
+                        .record_stack_sample(self.frames.iter().map(|f| f.function_name.as_str()));
                 }
             }
 
@@ -630,6 +797,13 @@ impl VM {
                         }
                     }
                     self.stack[absolute_index] = value;
+                    // The value left on the stack is now a copy of this local,
+                    // exactly as if it had been reloaded with GetLocal.
+                    #[cfg(debug_assertions)]
+                    {
+                        *self.value_origins.last_mut().unwrap() =
+                            Some(StackValueOrigin::Local(index));
+                    }
                 }
                 Opcode::GetGlobal => {
                     let name_index = self.read_u16()? as usize;
@@ -783,6 +957,9 @@ impl VM {
 
                 // ===== Arithmetic =====
                 Opcode::Add => {
+                    if self.try_int_binary_op(BinaryOp::Add)? {
+                        continue;
+                    }
                     let b = self.pop();
                     let a = self.pop();
                     match (&a, &b) {
@@ -798,11 +975,13 @@ impl VM {
                             self.push(Value::Number(result));
                         }
                         (Value::String(x), Value::String(y)) => {
+                            self.check_allocation(x.len() + y.len())?;
                             // Reuse string buffer to reduce allocations
                             self.string_buffer.clear();
                             self.string_buffer.push_str(x);
                             self.string_buffer.push_str(y);
                             self.push(Value::String(Arc::new(self.string_buffer.clone())));
+                            self.record_allocation();
                         }
                         _ => {
                             return Err(RuntimeError::TypeError {
@@ -812,9 +991,20 @@ impl VM {
                         }
                     }
                 }
-                Opcode::Sub => self.binary_numeric_op(|a, b| a - b)?,
-                Opcode::Mul => self.binary_numeric_op(|a, b| a * b)?,
+                Opcode::Sub => {
+                    if !self.try_int_binary_op(BinaryOp::Sub)? {
+                        self.binary_numeric_op(|a, b| a - b)?
+                    }
+                }
+                Opcode::Mul => {
+                    if !self.try_int_binary_op(BinaryOp::Mul)? {
+                        self.binary_numeric_op(|a, b| a * b)?
+                    }
+                }
                 Opcode::Div => {
+                    if self.try_int_binary_op(BinaryOp::Div)? {
+                        continue;
+                    }
                     let b = self.pop_number()?;
                     let a = self.pop_number()?;
                     if b == 0.0 {
@@ -831,6 +1021,9 @@ impl VM {
                     self.push(Value::Number(result));
                 }
                 Opcode::Mod => {
+                    if self.try_int_binary_op(BinaryOp::Mod)? {
+                        continue;
+                    }
                     let b = self.pop_number()?;
                     let a = self.pop_number()?;
                     if b == 0.0 {
@@ -850,6 +1043,16 @@ impl VM {
                     let value = self.pop();
                     match value {
                         Value::Number(n) => self.push(Value::Number(-n)),
+                        Value::Int(n) => {
+                            let negated =
+                                n.checked_neg()
+                                    .ok_or_else(|| RuntimeError::IntegerOverflow {
+                                        span: self
+                                            .current_span()
+                                            .unwrap_or_else(crate::span::Span::dummy),
+                                    })?;
+                            self.push(Value::Int(negated));
+                        }
                         _ => {
                             return Err(RuntimeError::TypeError {
                                 msg: "Cannot negate non-number".to_string(),
@@ -871,21 +1074,33 @@ impl VM {
                     self.push(Value::Bool(a != b));
                 }
                 Opcode::Less => {
+                    if self.try_int_binary_op(BinaryOp::Lt)? {
+                        continue;
+                    }
                     let b = self.pop_number()?;
                     let a = self.pop_number()?;
                     self.push(Value::Bool(a < b));
                 }
                 Opcode::LessEqual => {
+                    if self.try_int_binary_op(BinaryOp::Le)? {
+                        continue;
+                    }
                     let b = self.pop_number()?;
                     let a = self.pop_number()?;
                     self.push(Value::Bool(a <= b));
                 }
                 Opcode::Greater => {
+                    if self.try_int_binary_op(BinaryOp::Gt)? {
+                        continue;
+                    }
                     let b = self.pop_number()?;
                     let a = self.pop_number()?;
                     self.push(Value::Bool(a > b));
                 }
                 Opcode::GreaterEqual => {
+                    if self.try_int_binary_op(BinaryOp::Ge)? {
+                        continue;
+                    }
                     let b = self.pop_number()?;
                     let a = self.pop_number()?;
                     self.push(Value::Bool(a >= b));
@@ -927,6 +1142,21 @@ impl VM {
                     let offset = self.read_i16()?;
                     self.ip = (self.ip as isize + offset as isize) as usize;
                 }
+                Opcode::TryBegin => {
+                    let offset = self.read_i16()?;
+                    self.handlers.push(ErrorHandler {
+                        catch_ip: (self.ip as isize + offset as isize) as usize,
+                        stack_len: self.stack.len(),
+                        frame_depth: self.frames.len(),
+                    });
+                }
+                Opcode::TryEnd => {
+                    if let Some(handler) = self.handlers.pop() {
+                        self.stack.truncate(handler.stack_len);
+                        #[cfg(debug_assertions)]
+                        self.value_origins.truncate(handler.stack_len);
+                    }
+                }
 
                 // ===== Functions =====
                 Opcode::Call => {
@@ -947,6 +1177,7 @@ impl VM {
                                 self.pop(); // Pop function value
 
                                 let result = self.call_array_intrinsic(name, &args)?;
+                                self.record_result_allocation(&result);
                                 self.push(result);
                             } else {
                                 // Stdlib builtin - call directly
@@ -969,6 +1200,7 @@ impl VM {
                                     &self.output_writer,
                                 )?;
 
+                                self.record_result_allocation(&result);
                                 self.push(result);
                             }
                         }
@@ -1113,7 +1345,7 @@ impl VM {
                                 }
 
                                 // Push the frame (and its consumed-slot tracking vector)
-                                self.frames.push(frame);
+                                self.push_frame(frame)?;
                                 #[cfg(debug_assertions)]
                                 self.consumed_slots.push(vec![false; func.local_count]);
                                 // Record function call in profiler
@@ -1238,7 +1470,7 @@ impl VM {
                                 upvalues,
                             };
 
-                            self.frames.push(frame);
+                            self.push_frame(frame)?;
                             #[cfg(debug_assertions)]
                             self.consumed_slots.push(vec![false; func.local_count]);
                             if let Some(ref mut profiler) = self.profiler {
@@ -1275,6 +1507,67 @@ impl VM {
                         }
                     }
                 }
+                Opcode::TailCall => {
+                    // Self tail call: `return f(...)` inside `f`. The compiler only emits
+                    // this for plain user functions, so the current frame can be reused
+                    // instead of pushing a new one.
+                    let arg_count = self.read_u8()? as usize;
+                    let func = match self.peek(arg_count) {
+                        Value::Function(func) if func.bytecode_offset != 0 => func.clone(),
+                        other => {
+                            return Err(RuntimeError::TypeError {
+                                msg: format!(
+                                    "Tail call target must be a compiled function, got {}",
+                                    other.type_name()
+                                ),
+                                span: self.current_span().unwrap_or_else(crate::span::Span::dummy),
+                            });
+                        }
+                    };
+
+                    if arg_count != func.arity {
+                        return Err(RuntimeError::TypeError {
+                            msg: format!(
+                                "Function {} expects {} arguments, got {}",
+                                func.name, func.arity, arg_count
+                            ),
+                            span: self.current_span().unwrap_or_else(crate::span::Span::dummy),
+                        });
+                    }
+
+                    let mut args = Vec::with_capacity(arg_count);
+                    for _ in 0..arg_count {
+                        args.push(self.pop());
+                    }
+                    args.reverse();
+                    self.pop(); // Pop function value
+
+                    // Discard the current frame's locals and temporaries, then lay the
+                    // new arguments down where the old ones were.
+                    let stack_base = self.current_frame().stack_base;
+                    self.stack.truncate(stack_base);
+                    #[cfg(debug_assertions)]
+                    self.value_origins.truncate(stack_base);
+                    for arg in args {
+                        self.push(arg);
+                    }
+
+                    if let Some(frame) = self.frames.last_mut() {
+                        frame.function_name = func.name.clone();
+                        frame.local_count = func.local_count;
+                    }
+                    #[cfg(debug_assertions)]
+                    if let Some(consumed) = self.consumed_slots.last_mut() {
+                        *consumed = vec![false; func.local_count];
+                    }
+                    if let Some(ref mut profiler) = self.profiler {
+                        if profiler.is_enabled() {
+                            profiler.record_function_call(&func.name);
+                        }
+                    }
+
+                    self.ip = func.bytecode_offset;
+                }
                 Opcode::Return => {
                     // Pop the return value from stack (if any)
                     let return_value = if self.stack.is_empty() {
@@ -1288,6 +1581,16 @@ impl VM {
                     #[cfg(debug_assertions)]
                     self.consumed_slots.pop();
 
+                    // Drop handlers installed by the returning frame
+                    let depth = self.frames.len();
+                    while self
+                        .handlers
+                        .last()
+                        .is_some_and(|handler| handler.frame_depth > depth)
+                    {
+                        self.handlers.pop();
+                    }
+
                     if let Some(f) = frame {
                         // Clean up the stack (remove locals, arguments, and function value)
                         self.stack.truncate(f.stack_base);
@@ -1316,12 +1619,46 @@ impl VM {
                 // ===== Arrays =====
                 Opcode::Array => {
                     let size = self.read_u16()? as usize;
+                    self.check_array_allocation(size)?;
                     let mut elements = Vec::with_capacity(size);
                     for _ in 0..size {
                         elements.push(self.pop());
                     }
                     elements.reverse(); // Stack is LIFO, so reverse to get correct order
                     self.push(Value::Array(ValueArray::from_vec(elements)));
+                    self.record_allocation();
+                }
+                Opcode::NewMap => {
+                    let count = self.read_u16()? as usize;
+                    let span = self.current_span().unwrap_or_else(crate::span::Span::dummy);
+                    let mut pairs = Vec::with_capacity(count);
+                    for _ in 0..count {
+                        let value = self.pop();
+                        let key = self.pop();
+                        pairs.push((key, value));
+                    }
+                    pairs.reverse(); // Stack is LIFO, so reverse to keep source order
+                    let mut map = ValueMap::new();
+                    for (key, value) in pairs {
+                        crate::stdlib::collections::map::index_set(&mut map, &key, value, span)?;
+                    }
+                    self.push(Value::Map(map));
+                    self.record_allocation();
+                }
+                Opcode::MapGet => {
+                    let key = self.pop();
+                    let map = self.pop();
+                    let span = self.current_span().unwrap_or_else(crate::span::Span::dummy);
+                    let result = crate::stdlib::collections::map::get(&[map, key], span)?;
+                    self.push(result);
+                }
+                Opcode::MapSet => {
+                    let value = self.pop();
+                    let key = self.pop();
+                    let map = self.pop();
+                    let span = self.current_span().unwrap_or_else(crate::span::Span::dummy);
+                    let result = crate::stdlib::collections::map::set(&[map, key, value], span)?;
+                    self.push(result);
                 }
                 Opcode::GetIndex => {
                     let index_val = self.pop();
@@ -1370,9 +1707,15 @@ impl VM {
                             };
                             self.push(Value::JsonValue(Arc::new(result)));
                         }
+                        Value::Map(map) => {
+                            let span = self.current_span().unwrap_or_else(crate::span::Span::dummy);
+                            let value =
+                                crate::stdlib::collections::map::index_get(&map, &index_val, span)?;
+                            self.push(value);
+                        }
                         _ => {
                             return Err(RuntimeError::TypeError {
-                                msg: "Cannot index non-array/json".to_string(),
+                                msg: "Cannot index non-array/json/map".to_string(),
                                 span: self.current_span().unwrap_or_else(crate::span::Span::dummy),
                             })
                         }
@@ -1380,10 +1723,24 @@ impl VM {
                 }
                 Opcode::SetIndex => {
                     let value = self.pop();
-                    let index = self.pop_number()?;
+                    let index_val = self.pop();
                     let mut array = self.pop();
                     match &mut array {
+                        Value::Map(map) => {
+                            let span = self.current_span().unwrap_or_else(crate::span::Span::dummy);
+                            crate::stdlib::collections::map::index_set(
+                                map, &index_val, value, span,
+                            )?;
+                        }
                         Value::Array(arr) => {
+                            let Value::Number(index) = index_val else {
+                                return Err(RuntimeError::TypeError {
+                                    msg: "Expected number".to_string(),
+                                    span: self
+                                        .current_span()
+                                        .unwrap_or_else(crate::span::Span::dummy),
+                                });
+                            };
                             if index.fract() != 0.0 || index < 0.0 {
                                 return Err(RuntimeError::InvalidIndex {
                                     span: self
@@ -1501,6 +1858,11 @@ impl VM {
             }
         }
 
+        // A debug pause leaves the stack intact so execution can resume.
+        if self.debug_pause_pending {
+            return Ok(None);
+        }
+
         // Return top of stack if present
         Ok(if self.stack.is_empty() {
             None
@@ -1518,6 +1880,87 @@ impl VM {
         self.value_origins.push(None);
     }
 
+    /// Push a call frame, failing once the call depth limit is reached
+    fn push_frame(&mut self, frame: CallFrame) -> Result<(), RuntimeError> {
+        if self.frames.len() >= self.config.max_call_depth {
+            return Err(RuntimeError::CallDepthExceeded {
+                limit: self.config.max_call_depth,
+                span: self.current_span().unwrap_or_else(Span::dummy),
+            });
+        }
+        self.frames.push(frame);
+        Ok(())
+    }
+
+    /// Charge the current instruction against the execution budget
+    fn charge_budget(&mut self) -> Result<(), RuntimeError> {
+        let Some(security) = self.current_security.as_deref() else {
+            return Ok(());
+        };
+        self.budget
+            .charge(security)
+            .map_err(|e| limits::runtime_error(e, self.current_span().unwrap_or_else(Span::dummy)))
+    }
+
+    /// Charge the current instruction against the gas budget of a metered run
+    fn charge_gas(&mut self, opcode: Opcode) -> Result<(), RuntimeError> {
+        let Some(gas) = self.gas.as_mut() else {
+            return Ok(());
+        };
+        if gas.charge(opcode) {
+            return Ok(());
+        }
+        let limit = gas.limit();
+        Err(RuntimeError::GasExhausted {
+            limit,
+            span: self.current_span().unwrap_or_else(Span::dummy),
+        })
+    }
+
+    /// Check a single string or array allocation against the allocation limit
+    fn check_allocation(&self, bytes: usize) -> Result<(), RuntimeError> {
+        let Some(security) = self.current_security.as_deref() else {
+            return Ok(());
+        };
+        security
+            .check_allocation(bytes)
+            .map_err(|e| limits::runtime_error(e, self.current_span().unwrap_or_else(Span::dummy)))
+    }
+
+    /// Check an array of `len` values against the allocation limit
+    fn check_array_allocation(&self, len: usize) -> Result<(), RuntimeError> {
+        match self.current_security.as_deref() {
+            Some(security) => limits::check_array_allocation(
+                security,
+                len,
+                self.current_span().unwrap_or_else(Span::dummy),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Notify the profiler of a heap allocation by the executing function
+    #[inline]
+    fn record_allocation(&mut self) {
+        if let Some(ref mut profiler) = self.profiler {
+            if profiler.is_enabled() {
+                let function = self
+                    .frames
+                    .last()
+                    .map_or("<main>", |f| f.function_name.as_str());
+                profiler.record_allocation(function);
+            }
+        }
+    }
+
+    /// Count a builtin's result as an allocation if it is a fresh array, string, or map
+    #[inline]
+    fn record_result_allocation(&mut self, result: &Value) {
+        if matches!(result, Value::Array(_) | Value::String(_) | Value::Map(_)) {
+            self.record_allocation();
+        }
+    }
+
     #[inline(always)]
     fn pop(&mut self) -> Value {
         #[cfg(debug_assertions)]
@@ -1561,6 +2004,23 @@ impl VM {
         Ok(())
     }
 
+    /// Apply `op` when the top two stack values are both ints
+    ///
+    /// Returns false, leaving the stack untouched, for any other operands.
+    #[inline(always)]
+    fn try_int_binary_op(&mut self, op: BinaryOp) -> Result<bool, RuntimeError> {
+        let (Value::Int(a), Value::Int(b)) = (self.peek(1), self.peek(0)) else {
+            return Ok(false);
+        };
+        let (a, b) = (*a, *b);
+        let span = self.current_span().unwrap_or_else(crate::span::Span::dummy);
+        self.pop();
+        self.pop();
+        let result = crate::value::int_binary_op(op, a, b, span)?;
+        self.push(result);
+        Ok(true)
+    }
+
     #[inline(always)]
     fn read_opcode(&mut self) -> Result<Opcode, RuntimeError> {
         if self.ip >= self.bytecode.instructions.len() {
@@ -1614,14 +2074,30 @@ impl VM {
         unsafe { self.frames.last().unwrap_unchecked() }
     }
 
-    /// Generate a stack trace from the current call frames
-    /// Returns a vector of function names from innermost to outermost
-    #[allow(dead_code)]
-    fn stack_trace(&self) -> Vec<String> {
+    /// Build a stack trace from the current call frames, innermost first
+    ///
+    /// The innermost frame points at the instruction that was executing; each
+    /// outer frame points at the call that is still in progress, which is the
+    /// instruction just before the inner frame's return address.
+    fn capture_trace(&self) -> Vec<TraceFrame> {
+        let mut offset = self.ip.checked_sub(1);
         self.frames
             .iter()
             .rev()
-            .map(|frame| frame.function_name.clone())
+            .map(|frame| {
+                let span = offset.and_then(|o| self.bytecode.get_span_for_offset(o));
+                let location = offset.and_then(|o| {
+                    self.source_map
+                        .as_ref()
+                        .and_then(|map| map.location_for_offset(o).cloned())
+                });
+                offset = frame.return_ip.checked_sub(1);
+                TraceFrame {
+                    function_name: frame.function_name.clone(),
+                    span,
+                    location,
+                }
+            })
             .collect()
     }
 
@@ -1648,11 +2124,15 @@ impl VM {
             "every" => self.vm_intrinsic_every(args, span),
             "sort" => self.vm_intrinsic_sort(args, span),
             "sortBy" => self.vm_intrinsic_sort_by(args, span),
+            "uniqueBy" => self.vm_intrinsic_unique_by(args, span),
+            "groupBy" => self.vm_intrinsic_group_by(args, span),
+            "partition" => self.vm_intrinsic_partition(args, span),
             // Result intrinsics (callback-based)
             "result_map" => self.vm_intrinsic_result_map(args, span),
             "result_map_err" => self.vm_intrinsic_result_map_err(args, span),
             "result_and_then" => self.vm_intrinsic_result_and_then(args, span),
             "result_or_else" => self.vm_intrinsic_result_or_else(args, span),
+            "retry" => self.vm_intrinsic_retry(args, span),
             // HashMap intrinsics (callback-based)
             "hashMapForEach" => self.vm_intrinsic_hashmap_for_each(args, span),
             "hashMapMap" => self.vm_intrinsic_hashmap_map(args, span),
@@ -1703,6 +2183,7 @@ impl VM {
             }
         };
 
+        self.check_array_allocation(arr.len())?;
         let mut result = Vec::with_capacity(arr.len());
 
         for elem in arr {
@@ -1975,6 +2456,7 @@ impl VM {
             let callback_result = self.vm_call_function_value(callback, vec![elem], span)?;
             match callback_result {
                 Value::Array(nested) => {
+                    self.check_array_allocation(result.len() + nested.len())?;
                     result.extend(nested.iter().cloned());
                 }
                 other => result.push(other),
@@ -2180,43 +2662,130 @@ impl VM {
             keyed.push((key, elem));
         }
 
-        // Sort by keys (insertion sort for stability)
-        for i in 1..keyed.len() {
-            let mut j = i;
-            while j > 0 {
-                let cmp = match (&keyed[j].0, &keyed[j - 1].0) {
-                    (Value::Number(a), Value::Number(b)) => {
-                        if a < b {
-                            -1
-                        } else if a > b {
-                            1
-                        } else {
-                            0
-                        }
-                    }
-                    (Value::String(a), Value::String(b)) => {
-                        if a < b {
-                            -1
-                        } else if a > b {
-                            1
-                        } else {
-                            0
-                        }
-                    }
-                    _ => 0,
-                };
+        Ok(crate::stdlib::array::sort_by_keys(keyed))
+    }
 
-                if cmp < 0 {
-                    keyed.swap(j, j - 1);
-                    j -= 1;
-                } else {
-                    break;
-                }
+    fn vm_intrinsic_unique_by(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "uniqueBy() expects 2 arguments".to_string(),
+                span,
+            });
+        }
+
+        let arr = match &args[0] {
+            Value::Array(a) => a.iter().cloned().collect::<Vec<_>>(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "uniqueBy() first argument must be array".to_string(),
+                    span,
+                })
             }
+        };
+
+        let key_fn = match &args[1] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[1],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "uniqueBy() second argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let mut keyed = Vec::with_capacity(arr.len());
+        for elem in arr {
+            let key = self.vm_call_function_value(key_fn, vec![elem.clone()], span)?;
+            keyed.push((key, elem));
         }
 
-        let sorted: Vec<Value> = keyed.into_iter().map(|(_, elem)| elem).collect();
-        Ok(Value::array(sorted))
+        crate::stdlib::array::unique_by_keys(keyed, span)
+    }
+
+    fn vm_intrinsic_group_by(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "groupBy() expects 2 arguments".to_string(),
+                span,
+            });
+        }
+
+        let arr = match &args[0] {
+            Value::Array(a) => a.iter().cloned().collect::<Vec<_>>(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "groupBy() first argument must be array".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let key_fn = match &args[1] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[1],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "groupBy() second argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let mut keyed = Vec::with_capacity(arr.len());
+        for elem in arr {
+            let key = self.vm_call_function_value(key_fn, vec![elem.clone()], span)?;
+            keyed.push((key, elem));
+        }
+
+        crate::stdlib::array::group_by_keys(keyed, span)
+    }
+
+    fn vm_intrinsic_partition(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "partition() expects 2 arguments".to_string(),
+                span,
+            });
+        }
+
+        let arr = match &args[0] {
+            Value::Array(a) => a.iter().cloned().collect::<Vec<_>>(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "partition() first argument must be array".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let predicate = match &args[1] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[1],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "partition() second argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+
+        let mut flagged = Vec::with_capacity(arr.len());
+        for elem in arr {
+            let flag = self.vm_call_function_value(predicate, vec![elem.clone()], span)?;
+            flagged.push((flag, elem));
+        }
+
+        crate::stdlib::array::partition_by_flags(flagged, span)
     }
 
     // ========================================================================
@@ -2369,6 +2938,57 @@ impl VM {
         }
     }
 
+    fn vm_intrinsic_retry(
+        &mut self,
+        args: &[Value],
+        span: crate::span::Span,
+    ) -> Result<Value, RuntimeError> {
+        if args.len() != 2 {
+            return Err(RuntimeError::TypeError {
+                msg: "retry() expects 2 arguments (action, attempts)".to_string(),
+                span,
+            });
+        }
+
+        let action = match &args[0] {
+            Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => &args[0],
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    msg: "retry() first argument must be function".to_string(),
+                    span,
+                })
+            }
+        };
+        let attempts = match &args[1] {
+            Value::Number(n) if *n >= 1.0 && n.fract() == 0.0 => *n as u64,
+            other => {
+                return Err(RuntimeError::InvalidStdlibArgument {
+                    msg: format!(
+                        "retry(): attempts must be a positive integer, got {}",
+                        other
+                    ),
+                    span,
+                })
+            }
+        };
+
+        let mut last = Value::Null;
+        for _ in 0..attempts {
+            last = self.vm_call_function_value(action, vec![], span)?;
+            match &last {
+                Value::Result(Ok(_)) => break,
+                Value::Result(Err(_)) => {}
+                _ => {
+                    return Err(RuntimeError::TypeError {
+                        msg: "retry() action must return Result".to_string(),
+                        span,
+                    })
+                }
+            }
+        }
+        Ok(last)
+    }
+
     fn vm_intrinsic_hashmap_for_each(
         &mut self,
         args: &[Value],
@@ -2935,6 +3555,11 @@ impl VM {
                     });
                 }
 
+                // Push the callee slot, mirroring a regular Call, so that
+                // Return pops it instead of a value owned by the caller
+                self.push(func.clone());
+                let stack_base = stack_base + 1;
+
                 // Push arguments onto stack (they become the function's locals)
                 for arg in args {
                     self.push(arg);
@@ -2948,7 +3573,7 @@ impl VM {
                     local_count: func_ref.local_count,
                     upvalues: std::sync::Arc::new(Vec::new()),
                 };
-                self.frames.push(frame);
+                self.push_frame(frame)?;
                 #[cfg(debug_assertions)]
                 self.consumed_slots.push(vec![false; func_ref.local_count]);
 
@@ -2960,10 +3585,10 @@ impl VM {
 
                 // Get the return value from stack
                 let return_value = result.unwrap_or(Value::Null);
-                // Clean up stack to original base
-                self.stack.truncate(stack_base);
+                // Clean up stack to original base (below the callee slot)
+                self.stack.truncate(stack_base - 1);
                 #[cfg(debug_assertions)]
-                self.value_origins.truncate(stack_base);
+                self.value_origins.truncate(stack_base - 1);
 
                 // Restore IP
                 self.ip = saved_ip;
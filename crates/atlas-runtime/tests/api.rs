@@ -5,6 +5,7 @@ use atlas_runtime::api::{
     ConversionError, EvalError, ExecutionMode, FromAtlas, Runtime, RuntimeConfig, ToAtlas,
 };
 use atlas_runtime::reflect::{get_value_type_info, TypeInfo, TypeKind, ValueInfo};
+use atlas_runtime::runtime::{HostFn, HostFnDescriptor, HostFnError};
 use atlas_runtime::span::Span;
 use atlas_runtime::types::Type;
 use atlas_runtime::value::RuntimeError;
@@ -271,6 +272,28 @@ fn test_eval_type_error_arithmetic_on_string() {
     assert!(matches!(result, Err(EvalError::TypeError(_))));
 }
 
+#[rstest]
+#[case::interpreter(ExecutionMode::Interpreter)]
+#[case::vm(ExecutionMode::VM)]
+fn test_eval_runs_despite_unreachable_code_warning(#[case] mode: ExecutionMode) {
+    let mut runtime = Runtime::new(mode);
+    let source = r#"
+        var total: number = 0;
+        var i: number = 0;
+        while (i < 4) {
+            i = i + 1;
+            if (i == 1) {
+                continue;
+                total = 100;
+            }
+            total = total + i;
+        }
+        total;
+    "#;
+    let result = runtime.eval(source).unwrap();
+    assert!(matches!(result, Value::Number(n) if n == 9.0));
+}
+
 #[test]
 fn test_eval_runtime_error_divide_by_zero() {
     let mut runtime = Runtime::new(ExecutionMode::Interpreter);
@@ -1589,6 +1612,73 @@ fn test_native_with_complex_logic(#[case] mode: ExecutionMode) {
     assert_eq!(result, Value::Number(55.0));
 }
 
+// ============================================================================
+// Host Functions (Atlas::register_host_fn)
+// ============================================================================
+
+fn host_add() -> HostFn {
+    Box::new(|args| match (&args[0], &args[1]) {
+        (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a + b)),
+        _ => Err(RuntimeError::TypeError {
+            msg: "hostAdd expects numbers".to_string(),
+            span: Span::dummy(),
+        }),
+    })
+}
+
+#[test]
+fn test_host_fn_callable_from_atlas() {
+    let runtime = Atlas::new();
+    runtime.register_host_fn("hostAdd", 2, host_add()).unwrap();
+
+    let result = runtime.eval("let x = hostAdd(10, 20); x + hostAdd(1, 2)");
+    assert_eq!(result.unwrap(), Value::Number(33.0));
+}
+
+#[test]
+fn test_host_fn_typed_descriptor_checks_arguments() {
+    let runtime = Atlas::new();
+    let descriptor = HostFnDescriptor::new("hostAdd", 2)
+        .with_signature(vec![Type::Number, Type::Number], Type::Number);
+    runtime
+        .register_typed_host_fn(descriptor, host_add())
+        .unwrap();
+
+    assert_eq!(
+        runtime.eval("let n: number = hostAdd(2, 3); n").unwrap(),
+        Value::Number(5.0)
+    );
+
+    let diagnostics = runtime.eval(r#"hostAdd("a", 1)"#).unwrap_err();
+    assert_eq!(diagnostics[0].code, "AT3001");
+}
+
+#[test]
+fn test_host_fn_wrong_arity_is_rejected_before_running() {
+    let runtime = Atlas::new();
+    runtime.register_host_fn("hostAdd", 2, host_add()).unwrap();
+    assert!(runtime.eval("hostAdd(1)").is_err());
+}
+
+#[test]
+fn test_host_fn_builtin_collision_errors_at_registration() {
+    let runtime = Atlas::new();
+    let err = runtime
+        .register_host_fn("print", 1, Box::new(|_| Ok(Value::Null)))
+        .unwrap_err();
+    assert_eq!(err, HostFnError::BuiltinCollision("print".to_string()));
+}
+
+#[test]
+fn test_host_fn_duplicate_registration_errors() {
+    let runtime = Atlas::new();
+    runtime.register_host_fn("hostAdd", 2, host_add()).unwrap();
+    let err = runtime
+        .register_host_fn("hostAdd", 2, host_add())
+        .unwrap_err();
+    assert_eq!(err, HostFnError::AlreadyRegistered("hostAdd".to_string()));
+}
+
 // --- Sandboxing ---
 
 // Tests for Runtime sandboxing and configuration
@@ -1618,6 +1708,34 @@ fn test_runtime_captures_print_output() {
     assert_eq!(s, "captured\n");
 }
 
+#[test]
+fn test_runtime_prints_int_with_exact_digits() {
+    use atlas_runtime::stdlib::OutputWriter;
+    for mode in [ExecutionMode::Interpreter, ExecutionMode::VM] {
+        let buf: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(Vec::new()));
+        let output: OutputWriter = Arc::new(Mutex::new(Box::new(VecWriter(buf.clone()))));
+        let config = RuntimeConfig::new().with_output(output);
+        let mut runtime = Runtime::with_config(mode, config);
+        runtime
+            .eval(
+                r#"
+                let big = 9007199254740993i;
+                print(big);
+                print(str(big));
+                print("n=${big}");
+                print(-9223372036854775808i);
+                "#,
+            )
+            .unwrap();
+        let s = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            s, "9007199254740993\n9007199254740993\nn=9007199254740993\n-9223372036854775808\n",
+            "{:?}",
+            mode
+        );
+    }
+}
+
 #[test]
 fn test_default_config_is_permissive() {
     let config = RuntimeConfig::default();
@@ -2475,6 +2593,37 @@ fn test_parity_deep_equals() {
     }
 }
 
+// ============================================================================
+// Function Introspection (functionName / functionArity)
+// ============================================================================
+
+const FOO: &str = "fn foo(a: number, b: number, c: number) -> number { return a + b + c; }";
+
+#[rstest]
+#[case("functionArity(foo)", Value::Number(3.0))]
+#[case("functionName(foo)", Value::string("foo"))]
+#[case("let alias = foo; functionName(alias)", Value::string("foo"))]
+#[case("functionName(len)", Value::string("len"))]
+fn test_function_introspection(#[case] expr: &str, #[case] expected: Value) {
+    let code = format!("{} {}", FOO, expr);
+    assert_eq!(run_interpreter(&code), expected);
+    assert_eq!(run_vm(&code), expected);
+}
+
+#[rstest]
+#[case("functionArity(42)")]
+#[case("functionName(\"foo\")")]
+fn test_function_introspection_rejects_non_functions(#[case] code: &str) {
+    let diagnostics = Atlas::new().eval(code).unwrap_err();
+    assert_eq!(diagnostics[0].code, "AT3001");
+}
+
+#[test]
+fn test_function_arity_of_builtin_is_runtime_error() {
+    let result = Atlas::new().eval("functionArity(len)");
+    assert!(result.is_err());
+}
+
 // --- JSON value handling ---
 
 // Integration tests for JsonValue type
@@ -1512,6 +1512,86 @@ fn test_await_rejected_future() {
     assert!(result.is_err());
 }
 
+// ============================================================================
+// awaitAll Tests
+// ============================================================================
+
+#[rstest]
+#[case::interpreter(api::ExecutionMode::Interpreter)]
+#[case::vm(api::ExecutionMode::VM)]
+fn test_await_all_runs_sleeps_concurrently(#[case] mode: api::ExecutionMode) {
+    let mut runtime = Runtime::new(mode);
+    let start = std::time::Instant::now();
+    let result = runtime
+        .eval("len(awaitAll([sleep(150), sleep(200), sleep(150)]))")
+        .unwrap();
+    let elapsed = start.elapsed();
+
+    assert_eq!(result, Value::Number(3.0));
+    assert!(elapsed.as_millis() >= 200, "finished early: {:?}", elapsed);
+    // Sequential waiting would take 500ms
+    assert!(
+        elapsed.as_millis() < 400,
+        "sleeps did not overlap: {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn test_await_all_preserves_input_order() {
+    let code = r#"
+        let values: number[] = awaitAll([futureResolve(1), futureResolve(2), futureResolve(3)]);
+        values[0] * 100 + values[1] * 10 + values[2]
+    "#;
+    assert_eq!(eval_ok(code), Value::Number(123.0));
+    assert_eq!(eval_vm(code).unwrap(), Value::Number(123.0));
+}
+
+#[test]
+fn test_await_all_rejection_is_runtime_error() {
+    let result = eval(r#"awaitAll([futureResolve(1), futureReject("boom")])"#);
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("boom"), "{}", err);
+}
+
+#[test]
+fn test_await_all_pending_forever_errors() {
+    let result = eval("awaitAll([futureNew()])");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_try_await_all_short_circuits_on_rejection() {
+    let code = r#"
+        let slow = sleep(500);
+        let outcome = match tryAwaitAll([slow, futureReject("boom")]) {
+            Ok(values) => "ok",
+            Err(e) => e
+        };
+        outcome
+    "#;
+    let begin = std::time::Instant::now();
+    assert_eq!(eval_ok(code), Value::string("boom"));
+    assert!(begin.elapsed().as_millis() < 400, "{:?}", begin.elapsed());
+}
+
+#[test]
+fn test_try_await_all_success() {
+    let code = r#"
+        let product = match tryAwaitAll([futureResolve(2), futureResolve(3)]) {
+            Ok(values) => values[0] * values[1],
+            Err(e) => -1
+        };
+        product
+    "#;
+    assert_eq!(eval_ok(code), Value::Number(6.0));
+}
+
+#[test]
+fn test_await_all_requires_array_of_futures() {
+    assert!(eval("awaitAll([1, 2])").is_err());
+}
+
 // --- Async primitives (tasks, channels, timers, mutex, timeout) ---
 
 // Comprehensive async primitives tests (Phase-11c)
@@ -2099,6 +2179,58 @@ fn test_timeout_returns_result() {
     assert_eq!(result, Value::Bool(true));
 }
 
+#[rstest]
+#[case::interpreter(api::ExecutionMode::Interpreter)]
+#[case::vm(api::ExecutionMode::VM)]
+fn test_with_timeout_slow_task_times_out(#[case] mode: api::ExecutionMode) {
+    let start = std::time::Instant::now();
+    let result = Runtime::new(mode)
+        .eval("is_err(withTimeout(sleep(500), 100))")
+        .unwrap();
+    assert_eq!(result, Value::Bool(true));
+    assert!(start.elapsed().as_millis() < 400, "{:?}", start.elapsed());
+}
+
+#[rstest]
+#[case::interpreter(api::ExecutionMode::Interpreter)]
+#[case::vm(api::ExecutionMode::VM)]
+fn test_with_timeout_fast_task_returns_ok(#[case] mode: api::ExecutionMode) {
+    let mut runtime = Runtime::new(mode);
+    let slept = runtime.eval("unwrap(withTimeout(sleep(10), 500))").unwrap();
+    assert_eq!(slept, Value::Null);
+    let resolved = runtime
+        .eval("unwrap(withTimeout(futureResolve(42), 100))")
+        .unwrap();
+    assert_eq!(resolved, Value::Number(42.0));
+}
+
+#[test]
+fn test_with_timeout_error_describes_deadline() {
+    let code = r#"
+        let outcome = match withTimeout(sleep(500), 100) {
+            Ok(value) => "ok",
+            Err(msg) => msg
+        };
+        outcome
+    "#;
+    assert_eq!(eval_ok(code), Value::string("Timed out after 100ms"));
+}
+
+#[test]
+fn test_with_timeout_cancels_the_future() {
+    let code = r#"
+        let slow = sleep(500);
+        withTimeout(slow, 20);
+        futureIsRejected(slow)
+    "#;
+    assert_eq!(eval_ok(code), Value::Bool(true));
+}
+
+#[test]
+fn test_with_timeout_rejects_negative_duration() {
+    assert!(eval("withTimeout(sleep(1), -5)").is_err());
+}
+
 // ============================================================================
 // Async Mutex Tests (6 tests)
 // ============================================================================
@@ -309,6 +309,90 @@ fn test_loop_with_compound_assignment() {
     assert_eq!(result.unwrap(), Some(Value::Number(10.0)));
 }
 
+// ============================================================================
+// Unreachable Statements
+// ============================================================================
+
+#[test]
+fn test_statements_after_return_not_compiled() {
+    let source = r#"
+        fn early() -> number {
+            return 1;
+            let dead = 424242;
+            return dead;
+        }
+        early();
+    "#;
+    let bc = compile(source);
+    assert!(!bc.constants.contains(&Value::Number(424242.0)));
+    assert!(!bc.constants.contains(&Value::string("dead")));
+    assert_eq!(execute_source(source).unwrap(), Some(Value::Number(1.0)));
+}
+
+#[test]
+fn test_statements_after_break_and_continue_not_compiled() {
+    let source = r#"
+        var i = 0;
+        while (i < 3) {
+            i++;
+            if (i < 3) {
+                continue;
+                i = 515151;
+            }
+            break;
+            i = 626262;
+        }
+        i;
+    "#;
+    let bc = compile(source);
+    assert!(!bc.constants.contains(&Value::Number(515151.0)));
+    assert!(!bc.constants.contains(&Value::Number(626262.0)));
+    assert_eq!(execute_source(source).unwrap(), Some(Value::Number(3.0)));
+}
+
+// ============================================================================
+// Function Replacement
+// ============================================================================
+
+const TWO_FUNCTIONS: &str = r#"
+fn a() -> number { return 1; }
+fn b() -> number {
+    var s = 0;
+    var i = 0;
+    while (i < 3) { s = s + i; i = i + 1; }
+    return s;
+}
+a() + b();
+"#;
+
+const PATCHED_B: &str = r#"
+fn b() -> number {
+    var s = 0;
+    var i = 0;
+    while (i < 4) { s = s + i; i = i + 1; }
+    return s;
+}
+"#;
+
+#[test]
+fn test_replace_functions_repoints_changed_function() {
+    let mut bc = compile(TWO_FUNCTIONS);
+    assert_eq!(run(bc.clone()), Some(Value::Number(4.0)));
+
+    bc.replace_functions(compile(PATCHED_B), &["b".to_string()]);
+    assert_eq!(run(bc), Some(Value::Number(7.0)));
+}
+
+#[test]
+fn test_replace_functions_survives_serialization() {
+    let cached = Bytecode::from_bytes(&compile(TWO_FUNCTIONS).to_bytes()).unwrap();
+    let mut bc = cached;
+    bc.replace_functions(compile(PATCHED_B), &["b".to_string()]);
+
+    let reloaded = Bytecode::from_bytes(&bc.to_bytes()).unwrap();
+    assert_eq!(run(reloaded), Some(Value::Number(7.0)));
+}
+
 // ============================================================================
 // From optimizer_tests.rs
 // ============================================================================
@@ -630,6 +714,39 @@ fn test_cf_preserves_semantics_comparison() {
     assert_same_result("let x = 5 > 3;");
 }
 
+#[test]
+fn test_cf_let_emits_single_folded_constant() {
+    let bc = compile_optimized("let x = 2 * 3 + 4;");
+    let listing = atlas_runtime::bytecode::disassemble(&bc);
+    let loads: Vec<&str> = listing
+        .lines()
+        .filter(|line| line.contains("  Constant "))
+        .collect();
+    assert_eq!(loads.len(), 1, "expected one constant load:\n{}", listing);
+
+    let idx: usize = loads[0].split_whitespace().nth(2).unwrap().parse().unwrap();
+    assert_eq!(bc.constants[idx], Value::Number(10.0));
+}
+
+#[rstest]
+#[case("\"con\" + \"cat\";")]
+#[case("true && 1 < 2;")]
+#[case("false && 1 < 2;")]
+#[case("true || 1 > 2;")]
+#[case("false || 1 > 2;")]
+#[case("let c = true; (c || false) == true;")]
+#[case("let c = 1; c > 0 && (2 * 60 * 60 == 7200);")]
+fn test_cf_preserves_semantics_literals(#[case] source: &str) {
+    assert_same_result(source);
+}
+
+#[test]
+fn test_cf_preserves_divide_by_zero_error() {
+    let bc = compile_optimized("let x = 1 / 0;");
+    let mut vm = VM::new(bc);
+    assert!(vm.run(&SecurityContext::allow_all()).is_err());
+}
+
 // ============================================================================
 // Dead code elimination tests
 // ============================================================================
@@ -1901,6 +2018,55 @@ fn test_vm_report_ips_present_after_run() {
     assert!(report.ips.unwrap() > 0.0);
 }
 
+#[test]
+fn test_vm_profiling_counts_allocations_per_function() {
+    let source = r#"
+        fn build(n: number) -> number {
+            var total = 0;
+            var i = 0;
+            while (i < n) {
+                let row = [i, i + 1];
+                total = total + len(row);
+                i = i + 1;
+            }
+            return total;
+        }
+        let small = build(10);
+        let large = build(100);
+    "#;
+    let mut vm = VM::with_profiling(compile(source));
+    vm.run(&SecurityContext::allow_all()).unwrap();
+
+    let p = vm.profiler().unwrap();
+    let build = p.collector().allocation_counts().get("build").copied();
+    // One array per loop iteration across both calls
+    assert_eq!(build, Some(110));
+    assert!(p.allocations() >= 110);
+
+    let report = p.generate_report(1.0);
+    assert_eq!(report.top_allocators[0], ("build".to_string(), 110));
+    assert!(report
+        .format_summary()
+        .contains(&format!("allocations: {}", report.allocations)));
+}
+
+#[test]
+fn test_vm_profiling_counts_string_concat_allocations() {
+    let source = r#"
+        var s = "";
+        var i = 0;
+        while (i < 5) {
+            s = s + "x";
+            i = i + 1;
+        }
+    "#;
+    let mut vm = VM::with_profiling(compile(source));
+    vm.run(&SecurityContext::allow_all()).unwrap();
+
+    let p = vm.profiler().unwrap();
+    assert_eq!(p.collector().allocation_counts().get("<main>"), Some(&5));
+}
+
 // ============================================================================
 // From nested_function_parity_tests.rs
 // ============================================================================
@@ -2931,3 +3097,259 @@ fn test_result_map() {
 // - Error handling
 // - Pattern binding scope
 // - Multiple pattern types in one match
+
+// ============================================================================
+// String Interpolation Parity
+// ============================================================================
+
+#[rstest]
+#[case::basic(
+    r#"let name = "Ada"; let age = 36; "Hello ${name}, you are ${age}";"#,
+    r#"let name = "Ada"; let age = 36; "Hello " + name + ", you are " + toString(age);"#
+)]
+#[case::fraction(r#"let x = 1.5; "x=${x}";"#, r#"let x = 1.5; "x=" + toString(x);"#)]
+#[case::bool(r#"let ok = true; "${ok}!";"#, r#"let ok = true; toString(ok) + "!";"#)]
+#[case::expression(
+    r#"let n = 4; "${n * 2} items";"#,
+    r#"let n = 4; toString(n * 2) + " items";"#
+)]
+#[case::nested(
+    r#"let x = 1; "a ${"b ${x}"} c";"#,
+    r#"let x = 1; "a " + ("b " + toString(x)) + " c";"#
+)]
+#[case::in_function(
+    r#"fn greet(n: string) -> string { return "hi ${n}"; } greet("bob");"#,
+    r#"fn greet(n: string) -> string { return "hi " + n; } greet("bob");"#
+)]
+fn parity_string_interpolation_matches_concatenation(
+    #[case] interpolated: &str,
+    #[case] concatenated: &str,
+) {
+    let expected = run_interpreter(concatenated).unwrap();
+    assert_eq!(run_interpreter(interpolated).unwrap(), expected);
+    assert_eq!(run_vm(interpolated).unwrap(), expected);
+}
+
+#[test]
+fn parity_string_interpolation_escaped() {
+    let source = r#"let price = 3; "cost: \${price} = ${price}";"#;
+    let expected = Value::string("cost: ${price} = 3");
+    assert_eq!(run_interpreter(source).unwrap(), expected);
+    assert_eq!(run_vm(source).unwrap(), expected);
+}
+
+#[rstest]
+#[case::some(
+    r#"
+    fn f(opt: Option<number>) -> number {
+        if (is_some(opt)) { return opt + 1; }
+        return 0;
+    }
+    f(Some(41));
+    "#,
+    Value::Number(42.0)
+)]
+#[case::err_else(
+    r#"
+    fn f(res: Result<number, string>) -> string {
+        if (is_ok(res)) { return "ok"; } else { return res; }
+    }
+    f(Err("bad"));
+    "#,
+    Value::string("bad")
+)]
+#[case::early_return(
+    r#"
+    fn f(opt: Option<number>) -> number {
+        if (is_none(opt)) { return 0; }
+        return opt * 2;
+    }
+    f(Some(4));
+    "#,
+    Value::Number(8.0)
+)]
+#[case::wrapper_contexts(
+    r#"
+    fn f(opt: Option<number>) -> number {
+        if (is_some(opt)) {
+            let doubled = match opt { Some(x) => x * 2, None => 0 };
+            return opt + unwrap(opt) + doubled;
+        }
+        return 0;
+    }
+    f(Some(5));
+    "#,
+    Value::Number(20.0)
+)]
+#[case::and_operand(
+    r#"
+    fn f(opt: Option<number>) -> bool { return is_some(opt) && opt > 3; }
+    f(Some(5));
+    "#,
+    Value::Bool(true)
+)]
+fn parity_option_guard_reads_payload(#[case] source: &str, #[case] expected: Value) {
+    assert_eq!(run_interpreter(source).unwrap(), expected);
+    assert_eq!(run_vm(source).unwrap(), expected);
+}
+
+#[test]
+fn test_string_interpolation_rejects_non_primitive() {
+    let source = r#"let xs = [1, 2]; let s = "xs: ${xs}";"#;
+    let mut lexer = Lexer::new(source.to_string());
+    let (tokens, _) = lexer.tokenize();
+    let mut parser = Parser::new(tokens);
+    let (program, _) = parser.parse();
+    let mut binder = atlas_runtime::binder::Binder::new();
+    let (mut table, _) = binder.bind(&program);
+    let mut typechecker = TypeChecker::new(&mut table);
+    let diagnostics = typechecker.check(&program);
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.code == "AT3001" && d.message.contains("Cannot interpolate")),
+        "expected interpolation type error, got {:?}",
+        diagnostics
+    );
+}
+
+// ============================================================================
+// Tail calls
+// ============================================================================
+
+const TAIL_COUNTDOWN: &str = r#"
+    fn countdown(n: number, acc: number) -> number {
+        if (n == 0) { return acc; }
+        return countdown(n - 1, acc + 1);
+    }
+    countdown(1000000, 0);
+"#;
+
+#[test]
+fn parity_tail_recursive_countdown_does_not_overflow() {
+    let expected = Value::Number(1_000_000.0);
+    assert_eq!(run_interpreter(TAIL_COUNTDOWN).unwrap(), expected);
+    assert_eq!(run_vm(TAIL_COUNTDOWN).unwrap(), expected);
+}
+
+#[rstest]
+#[case("fn fact(n: number, acc: number) -> number { if (n <= 1) { return acc; } return fact(n - 1, acc * n); } fact(10, 1);", 3628800.0)]
+#[case(
+    "fn fact(n: number) -> number { if (n <= 1) { return 1; } return n * fact(n - 1); } fact(10);",
+    3628800.0
+)]
+#[case("fn sum(xs: number[], i: number, acc: number) -> number { if (i >= len(xs)) { return acc; } let next = acc + xs[i]; return sum(xs, i + 1, next); } sum([1, 2, 3, 4], 0, 0);", 10.0)]
+#[case("fn a(n: number) -> number { if (n == 0) { return 0; } return b(n - 1); } fn b(n: number) -> number { if (n == 0) { return 1; } return a(n - 1); } a(7);", 1.0)]
+fn parity_tail_and_non_tail_recursion(#[case] source: &str, #[case] expected: f64) {
+    assert_eq!(run_interpreter(source).unwrap(), Value::Number(expected));
+    assert_eq!(run_vm(source).unwrap(), Value::Number(expected));
+}
+
+#[test]
+fn test_self_tail_call_emits_tail_call_opcode() {
+    let disasm = atlas_runtime::bytecode::disassemble(&compile(TAIL_COUNTDOWN));
+    assert!(disasm.contains("TailCall 2"), "{}", disasm);
+}
+
+#[test]
+fn test_non_tail_recursion_emits_regular_call() {
+    let bc = compile(
+        "fn fact(n: number) -> number { if (n <= 1) { return 1; } return n * fact(n - 1); }",
+    );
+    let disasm = atlas_runtime::bytecode::disassemble(&bc);
+    assert!(!disasm.contains("TailCall"), "{}", disasm);
+    assert!(disasm.contains("Call 1"), "{}", disasm);
+}
+
+// ============================================================================
+// Disassembler
+// ============================================================================
+
+const DISASM_PROGRAM: &str = "let x = 1 + 2;
+fn double(n: number) -> number {
+    return n * 2;
+}
+print(double(x));
+";
+
+/// Opcode mnemonic of each instruction line, in listing order
+fn mnemonics(listing: &str) -> Vec<&str> {
+    listing
+        .lines()
+        .skip_while(|line| *line != "=== Instructions ===")
+        .skip(1)
+        .filter(|line| !line.starts_with('<'))
+        .filter_map(|line| {
+            line.split_whitespace()
+                .find(|word| word.starts_with(|c: char| c.is_ascii_uppercase()))
+        })
+        .collect()
+}
+
+#[test]
+fn test_disassemble_lists_opcodes_in_order() {
+    let listing = compile(DISASM_PROGRAM).disassemble();
+    assert_eq!(
+        mnemonics(&listing),
+        vec![
+            "Constant",
+            "Constant",
+            "Add",
+            "SetGlobal",
+            "Pop",
+            "Constant",
+            "SetGlobal",
+            "Pop",
+            "Jump",
+            "GetLocal",
+            "Constant",
+            "Mul",
+            "Return",
+            "Null",
+            "Return",
+            "GetGlobal",
+            "GetGlobal",
+            "GetGlobal",
+            "Call",
+            "Call",
+            "Pop",
+            "Halt",
+        ],
+        "{}",
+        listing
+    );
+}
+
+#[test]
+fn test_disassemble_resolves_constants_and_labels_functions() {
+    let listing = compile(DISASM_PROGRAM).disassemble();
+    assert!(listing.contains("0000  Constant 0 (1)"), "{}", listing);
+    assert!(listing.contains("SetGlobal 2 (\"x\")"), "{}", listing);
+    assert!(
+        listing.contains("Constant 3 (<fn double(1)>)"),
+        "{}",
+        listing
+    );
+    assert!(
+        listing.contains("<fn double>:\n0021  GetLocal 0"),
+        "{}",
+        listing
+    );
+}
+
+#[test]
+fn test_disassemble_with_source_shows_lines() {
+    let listing = compile(DISASM_PROGRAM).disassemble_with_source(DISASM_PROGRAM);
+    assert!(listing.contains("0000    1  Constant 0 (1)"), "{}", listing);
+    assert!(listing.contains("0003    |  Constant 1 (2)"), "{}", listing);
+    assert!(listing.contains("0021    3  GetLocal 0"), "{}", listing);
+    assert!(
+        listing.contains("0031    5  GetGlobal 6 (\"print\")"),
+        "{}",
+        listing
+    );
+    assert_eq!(
+        mnemonics(&listing),
+        mnemonics(&compile(DISASM_PROGRAM).disassemble())
+    );
+}
@@ -8,7 +8,9 @@ use atlas_runtime::typechecker::generics::Monomorphizer;
 use atlas_runtime::types::{Type, TypeParamDef};
 use atlas_runtime::value::Value;
 use atlas_runtime::Atlas;
-use common::{assert_error_code, assert_eval_bool, assert_eval_number, assert_eval_string};
+use common::{
+    assert_error_code, assert_eval_bool, assert_eval_number, assert_eval_string, assert_has_error,
+};
 use pretty_assertions::assert_eq;
 
 // ============================================================================
@@ -213,8 +215,8 @@ fn test_hashhm_entries() {
         let hm = hashMapNew();
         hashMapPut(hm, "a", 1);
         hashMapPut(hm, "b", 2);
-        let entries = hashMapEntries(hm);
-        len(entries)
+        let pairs = hashMapEntries(hm);
+        len(pairs)
     "#;
     assert_eval_number(code, 2.0);
 }
@@ -1637,8 +1639,8 @@ fn test_hashmap_clear_empties_map() {
 #[test]
 fn test_hashmap_from_entries_roundtrip() {
     let code = r#"
-        let entries = [["k", "v"]];
-        let m = hashMapFromEntries(entries);
+        let pairs = [["k", "v"]];
+        let m = hashMapFromEntries(pairs);
         unwrap(hashMapGet(m, "k"))
     "#;
     assert_eval_string(code, "v");
@@ -1846,3 +1848,195 @@ fn test_stack_to_array_bottom_to_top() {
     "#;
     assert_eval_number(code, 10.0);
 }
+
+// ============================================================================
+// Map literals
+// ============================================================================
+
+/// Evaluate in the interpreter and the VM, assert both agree, and return the display string.
+fn map_eval_both(code: &str) -> String {
+    let interp = common::eval_ok(code);
+    let bytecode = common::compile_source(code).expect("compile failed");
+    let vm = common::run_bytecode(bytecode)
+        .expect("vm failed")
+        .unwrap_or(Value::Null);
+    assert_eq!(interp, vm, "interpreter/VM mismatch for: {}", code);
+    interp.to_string()
+}
+
+#[test]
+fn test_map_literal_display_sorted() {
+    assert_eq!(map_eval_both("let m = { b: 2, a: 1 }; m;"), "{a: 1, b: 2}");
+}
+
+#[test]
+fn test_map_literal_empty() {
+    assert_eq!(map_eval_both("let m = {}; m;"), "{}");
+}
+
+#[test]
+fn test_map_literal_string_keys() {
+    let code = r#"let m = { "first name": "Ada", last: "Lovelace" }; m["first name"];"#;
+    assert_eq!(map_eval_both(code), "Ada");
+}
+
+#[test]
+fn test_map_literal_nested_values() {
+    let code = r#"let m = { xs: [1, 2, 3] }; m["xs"][1];"#;
+    assert_eq!(map_eval_both(code), "2");
+}
+
+#[test]
+fn test_map_index_assignment_inserts_and_updates() {
+    let code = r#"
+        var m = { a: 1 };
+        m["a"] = 10;
+        m["b"] = 20;
+        m;
+    "#;
+    assert_eq!(map_eval_both(code), "{a: 10, b: 20}");
+}
+
+#[test]
+fn test_map_get_returns_option() {
+    let code = r#"
+        let m = { a: 1 };
+        let hit = unwrap(mapGet(m, "a"));
+        let miss = is_none(mapGet(m, "z"));
+        [toString(hit), toString(miss)];
+    "#;
+    assert_eq!(map_eval_both(code), "[1, true]");
+}
+
+#[test]
+fn test_map_set_and_has() {
+    let code = r#"
+        let m = { a: 1 };
+        let updated = mapSet(m, "b", 2);
+        [mapHas(updated, "b"), mapHas(updated, "c")];
+    "#;
+    assert_eq!(map_eval_both(code), "[true, false]");
+}
+
+#[test]
+fn test_map_keys_and_values_sorted() {
+    let code = r#"
+        let m = { c: 3, a: 1, b: 2 };
+        let keys: string[] = mapKeys(m);
+        let values: number[] = mapValues(m);
+        join(keys, ",") + "=" + toString(values[0] + values[1] + values[2]);
+    "#;
+    assert_eq!(map_eval_both(code), "a,b,c=6");
+}
+
+#[test]
+fn test_map_to_json() {
+    let code = r#"
+        let name = "Ada";
+        toJSON({ name: name, role: "admin" });
+    "#;
+    assert_eq!(map_eval_both(code), r#"{"name":"Ada","role":"admin"}"#);
+}
+
+#[test]
+fn test_map_missing_key_is_runtime_error() {
+    assert_error_code(r#"let m = { a: 1 }; m["z"];"#, "AT0008");
+}
+
+#[test]
+fn test_map_missing_key_is_runtime_error_vm() {
+    let bytecode = common::compile_source(r#"let m = { a: 1 }; m["z"];"#).unwrap();
+    let err = common::run_bytecode(bytecode).unwrap_err();
+    assert!(matches!(
+        err,
+        atlas_runtime::value::RuntimeError::MapKeyNotFound { ref key, .. } if key == "z"
+    ));
+}
+
+#[test]
+fn test_map_literal_mismatched_value_types() {
+    assert_error_code(r#"let m = { a: 1, b: "two" };"#, "AT3001");
+}
+
+#[test]
+fn test_map_literal_duplicate_key() {
+    assert_error_code(r#"let m = { a: 1, a: 2 };"#, "AT3001");
+}
+
+#[test]
+fn test_map_index_requires_string_key() {
+    assert_error_code(r#"let m = { a: 1 }; m[0];"#, "AT3001");
+}
+
+#[test]
+fn test_map_typed_value_flows_through_index() {
+    assert_error_code(r#"let m = { a: 1 }; let s: string = m["a"];"#, "AT3001");
+}
+
+#[test]
+fn test_map_type_of() {
+    assert_eval_string(r#"typeof({ a: 1 })"#, "map");
+}
+
+// ============================================================================
+// Set
+// ============================================================================
+
+#[test]
+fn test_set_of_deduplicates() {
+    let code = "let xs: number[] = setToArray(setOf([1, 1, 2, 3, 3])); len(xs);";
+    assert_eq!(map_eval_both(code), "3");
+}
+
+#[test]
+fn test_set_intersect() {
+    let code = "let s = setIntersect(setOf([1, 2, 3]), setOf([2, 3, 4])); s;";
+    assert_eq!(map_eval_both(code), "{2, 3}");
+}
+
+#[test]
+fn test_set_union_and_difference() {
+    let code = r#"
+        let a = setOf(["x", "y"]);
+        let b = setOf(["y", "z"]);
+        [setToArray(setUnion(a, b)), setToArray(setDifference(a, b))];
+    "#;
+    assert_eq!(map_eval_both(code), "[[x, y, z], [x]]");
+}
+
+#[test]
+fn test_set_add_has_remove() {
+    let code = r#"
+        var s = setOf([1, 2]);
+        setAdd(s, 3);
+        setRemove(s, 1);
+        [setHas(s, 3), setHas(s, 1), setHas(s, 2)];
+    "#;
+    assert_eq!(map_eval_both(code), "[true, false, true]");
+}
+
+#[test]
+fn test_set_equality_ignores_insertion_order() {
+    let code = "setOf([3, 1, 2]) == setOf([1, 2, 3, 3]);";
+    assert_eq!(map_eval_both(code), "true");
+}
+
+#[test]
+fn test_set_to_json() {
+    assert_eq!(map_eval_both("toJSON(setOf([2, 1, 2]));"), "[1,2]");
+}
+
+#[test]
+fn test_set_element_type_mismatch() {
+    assert_error_code(r#"let s = setOf([1, 2]); setHas(s, "1");"#, "AT3001");
+}
+
+#[test]
+fn test_set_rejects_non_primitive_elements() {
+    assert_has_error("let s = setOf([[1], [2]]);");
+}
+
+#[test]
+fn test_set_type_of() {
+    assert_eval_string("typeof(setOf([1]))", "set");
+}
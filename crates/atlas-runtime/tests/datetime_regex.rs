@@ -1,6 +1,6 @@
 // datetime_regex.rs — DateTime and Regex stdlib extension tests
 
-use atlas_runtime::{Atlas, Value};
+use atlas_runtime::{Atlas, SecurityContext, Value};
 
 // --- DateTime core ---
 
@@ -734,6 +734,55 @@ fn test_duration_format_zero_seconds() {
     assert_eq!(result, Value::string("0s".to_string()));
 }
 
+// ============================================================================
+// Unix Millisecond Timestamp Tests (5 tests)
+// ============================================================================
+
+#[test]
+fn test_format_time_round_trips_parse_time() {
+    let code = r#"
+        let pattern = "%Y-%m-%d %H:%M:%S";
+        let millis = parseTime("2024-01-15 10:30:00", pattern);
+        formatTime(millis, pattern)
+    "#;
+    assert_eq!(eval_ok(code), "2024-01-15 10:30:00");
+}
+
+#[test]
+fn test_parse_time_date_only_is_midnight_utc() {
+    let runtime = Atlas::new();
+    let result = runtime
+        .eval(r#"parseTime("1970-01-02", "%Y-%m-%d")"#)
+        .unwrap();
+    assert_eq!(result, Value::Number(86_400_000.0));
+}
+
+#[test]
+fn test_parse_time_invalid_input() {
+    assert!(eval_expect_error(
+        r#"parseTime("not a date", "%Y-%m-%d %H:%M:%S")"#
+    ));
+}
+
+#[test]
+fn test_now_requires_clock_permission() {
+    let runtime = Atlas::new();
+    let diagnostics = runtime.eval("now()").unwrap_err();
+    assert_eq!(diagnostics[0].code, "AT0305");
+}
+
+#[test]
+fn test_now_with_clock_permission() {
+    let mut security = SecurityContext::new();
+    security.grant_clock();
+    let runtime = Atlas::new_with_security(security);
+    match runtime.eval("now()").unwrap() {
+        // Later than 2020-01-01
+        Value::Number(millis) => assert!(millis > 1_577_836_800_000.0),
+        other => panic!("expected number, got {:?}", other),
+    }
+}
+
 // --- Regex core ---
 
 // Regex core functionality tests (Phase-08a)
@@ -1794,3 +1843,73 @@ fn test_regex_split_n_limit_one_returns_two_parts() {
     "#;
     assert_eq!(eval_ok(code), "2");
 }
+
+// --- Pattern-string helpers ---
+
+fn eval_error_code(code: &str) -> String {
+    let atlas = Atlas::new();
+    let diags = atlas.eval(code).expect_err("Execution should fail");
+    diags[0].code.clone()
+}
+
+#[test]
+fn test_regex_match_pattern_string() {
+    assert_eq!(eval_ok(r#"regexMatch("order-42", "\\d+")"#), "true");
+    assert_eq!(eval_ok(r#"regexMatch("no digits", "\\d+")"#), "false");
+}
+
+#[test]
+fn test_regex_replace_pattern_string() {
+    let code = r#"regexReplace("a1b2c3", "\\d", "X")"#;
+    assert_eq!(eval_ok(code), "aXb2c3");
+}
+
+#[test]
+fn test_regex_replace_pattern_string_group_reference() {
+    let code = r#"regexReplace("abc 123-xyz", "(\\d+)-(\\w+)", "[$2:$1]")"#;
+    assert_eq!(eval_ok(code), "abc [xyz:123]");
+}
+
+#[test]
+fn test_regex_split_pattern_string() {
+    let code = r#"
+        let parts = regexSplit("hello   world  test", "\\s+");
+        parts[0] + "|" + parts[1] + "|" + parts[2]
+    "#;
+    assert_eq!(eval_ok(code), "hello|world|test");
+}
+
+#[test]
+fn test_regex_capture_groups() {
+    let code = r#"regexCapture("date: 2024-06-01", "(\\d{4})-(\\d{2})-(\\d{2})")"#;
+    assert_eq!(eval_ok(code), "[2024, 06, 01]");
+}
+
+#[test]
+fn test_regex_capture_no_match_is_empty() {
+    let code = r#"len(regexCapture("no date here", "(\\d{4})"))"#;
+    assert_eq!(eval_ok(code), "0");
+}
+
+#[test]
+fn test_regex_capture_unmatched_group_is_empty_string() {
+    let code = r#"
+        let groups = regexCapture("ac", "a(b)?(c)");
+        len(groups[0]) * 10 + len(groups[1])
+    "#;
+    assert_eq!(eval_ok(code), "1");
+}
+
+#[test]
+fn test_regex_malformed_pattern_errors() {
+    assert_eq!(
+        eval_error_code(r#"regexMatch("abc", "(unclosed")"#),
+        "AT0150"
+    );
+    assert_eq!(eval_error_code(r#"regexCapture("abc", "[a-")"#), "AT0150");
+    assert_eq!(
+        eval_error_code(r#"regexReplace("abc", "*", "x")"#),
+        "AT0150"
+    );
+    assert_eq!(eval_error_code(r#"regexSplit("abc", "a{2,1}")"#), "AT0150");
+}
@@ -11,7 +11,7 @@ use atlas_runtime::debugger::inspection::{
 use atlas_runtime::debugger::protocol::{
     deserialize_event, deserialize_request, deserialize_response, serialize_event,
     serialize_request, serialize_response, Breakpoint, DebugEvent, DebugRequest, DebugResponse,
-    DebugStackFrame, PauseReason, SourceLocation, Variable,
+    DebugStackFrame, PauseReason, SourceLocation, Variable, WatchValue,
 };
 use atlas_runtime::debugger::source_map::{
     byte_offset_to_line_column, compute_line_offsets, SourceMap,
@@ -1853,6 +1853,115 @@ fn e2e_step_into_causes_paused_state() {
     assert!(session.is_paused() || session.is_stopped());
 }
 
+#[test]
+fn e2e_step_into_advances_past_paused_instruction() {
+    let source = "let x = 1;\nlet y = 2;";
+    let mut session = new_session(source);
+    let mut ips = Vec::new();
+    for _ in 0..3 {
+        session.process_request(DebugRequest::StepInto);
+        match session.run_until_pause(&security()) {
+            DebugResponse::Paused { ip, .. } => ips.push(ip),
+            r => panic!("{:?}", r),
+        }
+    }
+    assert!(ips.windows(2).all(|w| w[0] < w[1]), "ips: {:?}", ips);
+}
+
+#[test]
+fn e2e_continue_moves_to_next_breakpoint() {
+    let source = "var a = 1;\na = 2;\na = 3;";
+    let mut session = new_session(source);
+    session.process_request(DebugRequest::SetBreakpoint { location: loc(2) });
+    session.process_request(DebugRequest::SetBreakpoint { location: loc(3) });
+    let mut lines = Vec::new();
+    for _ in 0..2 {
+        session.process_request(DebugRequest::Continue);
+        match session.run_until_pause(&security()) {
+            DebugResponse::Paused {
+                location: Some(location),
+                ..
+            } => lines.push(location.line),
+            r => panic!("{:?}", r),
+        }
+    }
+    assert_eq!(lines, vec![2, 3]);
+}
+
+// ═════════════════════════════════════════════════════════════════════════════
+// Watch expressions (session)
+// ═════════════════════════════════════════════════════════════════════════════
+
+fn watches(session: &mut DebuggerSession) -> Vec<WatchValue> {
+    match session.process_request(DebugRequest::GetWatches { frame_index: 0 }) {
+        DebugResponse::Watches { watches } => watches,
+        r => panic!("expected Watches, got {:?}", r),
+    }
+}
+
+#[test]
+fn e2e_watch_tracks_value_across_steps() {
+    let source = "var total = 0;\ntotal = total + 1;\ntotal = total + 2;\ntotal = total + 3;";
+    let mut session = new_session(source);
+    session.process_request(DebugRequest::SetBreakpoint { location: loc(3) });
+    session.process_request(DebugRequest::SetBreakpoint { location: loc(4) });
+    match session.process_request(DebugRequest::AddWatch {
+        expression: "total".into(),
+    }) {
+        DebugResponse::WatchAdded { expression } => assert_eq!(expression, "total"),
+        r => panic!("{:?}", r),
+    }
+
+    let mut seen = Vec::new();
+    for _ in 0..3 {
+        session.process_request(DebugRequest::Continue);
+        session.run_until_pause(&security());
+        let current = watches(&mut session);
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].expression, "total");
+        seen.push(current[0].value.clone().expect("watch should evaluate"));
+    }
+    assert_eq!(seen, vec!["1", "3", "6"]);
+    assert!(session.is_stopped());
+}
+
+#[test]
+fn e2e_watch_reports_error_when_not_in_scope() {
+    let mut session = new_session("let x = 1;");
+    session.process_request(DebugRequest::AddWatch {
+        expression: "missing".into(),
+    });
+    let current = watches(&mut session);
+    assert_eq!(current[0].value, None);
+    assert!(current[0].error.as_deref().unwrap().contains("missing"));
+}
+
+#[test]
+fn e2e_unwatch_removes_expression() {
+    let mut session = new_session("let x = 1;");
+    session.process_request(DebugRequest::AddWatch {
+        expression: "x".into(),
+    });
+    session.process_request(DebugRequest::AddWatch {
+        expression: "x".into(),
+    });
+    assert_eq!(watches(&mut session).len(), 1);
+
+    match session.process_request(DebugRequest::RemoveWatch {
+        expression: "x".into(),
+    }) {
+        DebugResponse::WatchRemoved { expression } => assert_eq!(expression, "x"),
+        r => panic!("{:?}", r),
+    }
+    assert!(watches(&mut session).is_empty());
+    assert!(matches!(
+        session.process_request(DebugRequest::RemoveWatch {
+            expression: "x".into(),
+        }),
+        DebugResponse::Error { .. }
+    ));
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // INTERPRETER DEBUGGER TESTS (Phase 01 - Parity with VM debugger)
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -2352,3 +2461,30 @@ fn parity_both_support_continue() {
         r => panic!("expected both Resumed, got {:?}", r),
     }
 }
+
+#[test]
+fn parity_both_support_watches() {
+    let source = "let x = 41;";
+
+    let mut vm_session = new_session(source);
+    let mut interp_session = interp_session(source);
+    vm_session.run_until_pause(&security());
+    interp_session.run_until_pause(&security());
+
+    let watch = DebugRequest::AddWatch {
+        expression: "x + 1".into(),
+    };
+    vm_session.process_request(watch.clone());
+    interp_session.process_request(watch);
+
+    let vm_resp = vm_session.process_request(DebugRequest::GetWatches { frame_index: 0 });
+    let interp_resp = interp_session.process_request(DebugRequest::GetWatches { frame_index: 0 });
+
+    match (vm_resp, interp_resp) {
+        (DebugResponse::Watches { watches: w1 }, DebugResponse::Watches { watches: w2 }) => {
+            assert_eq!(w1[0].value.as_deref(), Some("42"));
+            assert_eq!(w1, w2);
+        }
+        r => panic!("expected both Watches, got {:?}", r),
+    }
+}
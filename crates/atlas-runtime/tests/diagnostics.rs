@@ -4,7 +4,7 @@
 use atlas_runtime::bytecode::{Bytecode, DebugSpan};
 use atlas_runtime::diagnostic::error_codes;
 use atlas_runtime::diagnostic::formatter::{
-    extract_snippet, offset_to_line_col, DiagnosticFormatter,
+    enrich_diagnostic, extract_snippet, offset_to_line_col, DiagnosticFormatter,
 };
 use atlas_runtime::diagnostic::{normalizer::normalize_diagnostics_for_testing, sort_diagnostics};
 use atlas_runtime::sourcemap::encoder::{
@@ -15,7 +15,8 @@ use atlas_runtime::sourcemap::{
     generate_from_debug_spans, generate_inline_source_map, generate_source_map, SourceMapOptions,
 };
 use atlas_runtime::{
-    Binder, Diagnostic, DiagnosticLevel, Lexer, Parser, Span, TypeChecker, DIAG_VERSION,
+    Binder, Diagnostic, DiagnosticLevel, LabelStyle, Lexer, Parser, Span, TextEdit, TypeChecker,
+    DIAG_VERSION,
 };
 use rstest::rstest;
 use std::path::Path;
@@ -667,6 +668,109 @@ fn test_multiple_redeclarations_each_have_related_span() {
     }
 }
 
+#[test]
+fn test_let_type_mismatch_labels_annotation_and_value() {
+    let source = r#"let x: number = "hello";"#;
+
+    let (ast, _) = parse(source);
+    let (mut table, _) = bind_program(&ast);
+    let diags = typecheck_program(&ast, &mut table);
+    let diag = diags
+        .iter()
+        .find(|d| d.code == "AT3001")
+        .expect("Expected a type mismatch");
+
+    assert_eq!(diag.related.len(), 2);
+    let annotation = &diag.related[0];
+    assert_eq!(
+        annotation.label,
+        "expected `number` because of this annotation"
+    );
+    assert_eq!(annotation.style, LabelStyle::Secondary);
+    assert_eq!((annotation.column, annotation.length), (8, 6));
+    let value = &diag.related[1];
+    assert_eq!(value.label, "found `string` here");
+    assert_eq!(value.style, LabelStyle::Primary);
+    assert_eq!((value.column, value.length), (17, 7));
+
+    let output = enrich_diagnostic(diag.clone(), source).to_human_string();
+    assert!(
+        output.contains(concat!(
+            " 1 | let x: number = \"hello\";\n",
+            "   | ^^^^^^^^^^^^^^^^^^^^^^^^ expected number, found string\n",
+            "   |        ------ expected `number` because of this annotation\n",
+            "   |                 ^^^^^^^ found `string` here\n",
+        )),
+        "{}",
+        output
+    );
+    assert!(!output.contains("related location"));
+}
+
+#[test]
+fn test_labeled_spans_round_trip_through_json() {
+    let diag = Diagnostic::error_with_code("AT3001", "Type mismatch", Span::new(0, 24))
+        .with_span_label(Span::new(7, 13), "expected `number`", LabelStyle::Secondary)
+        .with_span_label(Span::new(16, 23), "found `string`", LabelStyle::Primary);
+
+    let json = diag.to_json_compact().unwrap();
+    // Secondary is the default style and is omitted
+    assert_eq!(json.matches("\"style\"").count(), 1);
+    assert!(json.contains("\"style\":\"primary\""));
+    let back: Diagnostic = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, diag);
+}
+
+#[test]
+fn test_unknown_symbol_typo_suggests_closest_name() {
+    let source = r#"
+        let length = 3;
+        let doubled = lenght * 2;
+    "#;
+
+    let (ast, parse_diags) = parse(source);
+    assert!(parse_diags.is_empty(), "Should parse without errors");
+
+    let (_, bind_diags) = bind_program(&ast);
+    assert_eq!(bind_diags.len(), 1);
+
+    let diag = &bind_diags[0];
+    assert_eq!(diag.message, "Unknown symbol 'lenght'");
+    assert_eq!(diag.help.as_deref(), Some("did you mean 'length'?"));
+    assert_eq!(diag.related.len(), 1);
+    assert_eq!(diag.related[0].message, "'length' declared here");
+}
+
+#[test]
+fn test_unknown_function_typo_suggests_builtin() {
+    let (ast, _) = parse(r#"pirnt("hi");"#);
+    let (_, bind_diags) = bind_program(&ast);
+
+    assert_eq!(bind_diags.len(), 1);
+    assert_eq!(bind_diags[0].help.as_deref(), Some("did you mean 'print'?"));
+    // Builtins have no source location to point at
+    assert!(bind_diags[0].related.is_empty());
+}
+
+#[test]
+fn test_unknown_symbol_unrelated_name_has_no_suggestion() {
+    let source = r#"
+        let length = 3;
+        let total = completelyDifferent + 1;
+    "#;
+
+    let (ast, _) = parse(source);
+    let (_, bind_diags) = bind_program(&ast);
+
+    assert_eq!(bind_diags.len(), 1);
+    let diag = &bind_diags[0];
+    assert_eq!(
+        diag.help.as_deref(),
+        Some("declare 'completelyDifferent' before using it, or check for typos")
+    );
+    assert!(diag.related.is_empty());
+}
+
 #[test]
 fn test_related_span_serializes_to_json() {
     let source = r#"
@@ -740,6 +844,51 @@ fn test_no_related_span_for_undefined_variable() {
     }
 }
 
+// ============================================================================
+// Fix-it Tests
+// ============================================================================
+
+#[test]
+fn test_missing_semicolon_fix_serializes_to_json() {
+    let source = "let x = 1\nlet y = 2;";
+
+    let (_, parse_diags) = parse(source);
+    let diag = parse_diags
+        .iter()
+        .find(|d| d.code == "AT1002")
+        .expect("Expected missing semicolon error");
+    assert_eq!(diag.fixes, vec![TextEdit::insert(9, ";")]);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&diag.to_json_string().unwrap()).expect("valid JSON");
+    assert_eq!(
+        json["fixes"],
+        serde_json::json!([{ "span": { "start": 9, "end": 9 }, "replacement": ";" }])
+    );
+}
+
+#[test]
+fn test_immutable_assignment_fix_replaces_let_with_var() {
+    let source = "let x = 5;\nx = 10;";
+
+    let (ast, _) = parse(source);
+    let (mut symbol_table, _) = bind_program(&ast);
+    let type_diags = typecheck_program(&ast, &mut symbol_table);
+
+    let diag = type_diags
+        .iter()
+        .find(|d| d.code == "AT3003")
+        .expect("Expected immutable assignment error");
+    assert_eq!(diag.fixes, vec![TextEdit::replace(Span::new(0, 3), "var")]);
+}
+
+#[test]
+fn test_diagnostic_without_fixes_omits_json_field() {
+    let diag = Diagnostic::error("boom", Span::new(0, 1));
+    let json = diag.to_json_string().unwrap();
+    assert!(!json.contains("\"fixes\""), "JSON: {}", json);
+}
+
 // ============================================================================
 // Enhanced Error Tests (from enhanced_errors_tests.rs)
 // ============================================================================
@@ -829,6 +978,58 @@ fn test_error_codes_count() {
     );
 }
 
+/// Collect every `"ATxxxx"` / `"AWxxxx"` string literal in the runtime sources
+fn emitted_codes(dir: &Path, codes: &mut std::collections::BTreeSet<String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            emitted_codes(&path, codes);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let source = std::fs::read_to_string(&path).unwrap();
+            for literal in source.split('"').skip(1).step_by(2) {
+                let bytes = literal.as_bytes();
+                if bytes.len() == 6
+                    && (literal.starts_with("AT") || literal.starts_with("AW"))
+                    && bytes[2..].iter().all(u8::is_ascii_digit)
+                {
+                    codes.insert(literal.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_every_emitted_code_is_registered() {
+    let mut codes = std::collections::BTreeSet::new();
+    emitted_codes(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut codes,
+    );
+    assert!(codes.len() >= 40, "scan found only {} codes", codes.len());
+    let missing: Vec<_> = codes
+        .iter()
+        .filter(|code| error_codes::lookup(code).is_none())
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "codes missing from the registry: {:?}",
+        missing
+    );
+}
+
+#[rstest]
+#[case("AT0300", "Filesystem permission denied")]
+#[case("AT3027", "Non-exhaustive match")]
+#[case("AT5009", "Circular import")]
+fn test_explain_renders_registry_entry(#[case] code: &str, #[case] title: &str) {
+    let info = error_codes::lookup(code).unwrap();
+    let text = error_codes::explain(code).unwrap();
+    assert!(text.starts_with(&format!("{}: {}\n", code, title)));
+    assert!(text.contains(info.explanation));
+    assert!(text.contains(info.help.unwrap()));
+}
+
 #[rstest]
 #[case("AT0001", "Type mismatch")]
 #[case("AT0005", "Division by zero")]
@@ -1096,6 +1297,7 @@ fn test_format_related_location() {
             column: 3,
             length: 4,
             message: "originally defined here".to_string(),
+            ..Default::default()
         });
 
     let buf = formatter.format_to_buffer(&diag);
@@ -519,6 +519,7 @@ fn test_diagnostic_with_notes_and_related_locations() {
             column: 1,
             length: 3,
             message: "'bar' defined here".to_string(),
+            ..Default::default()
         })
         .with_help("Check spelling or import the correct module");
 
@@ -1207,6 +1208,7 @@ fn test_diagnostic_json_with_related_locations() {
             column: 10,
             length: 3,
             message: "related".to_string(),
+            ..Default::default()
         },
     );
 
@@ -1597,6 +1599,7 @@ fn test_diagnostic_builder_all_fields() {
             column: 5,
             length: 7,
             message: "defined here".to_string(),
+            ..Default::default()
         })
         .with_help("Check the return type");
 
@@ -1629,6 +1632,7 @@ fn test_complete_program_construction() {
                 name: Identifier {
                     name: "add".to_string(),
                     span: Span::new(5, 8),
+                    unwrap_payload: std::cell::Cell::new(None),
                 },
                 type_params: vec![],
                 params: vec![
@@ -1636,6 +1640,7 @@ fn test_complete_program_construction() {
                         name: Identifier {
                             name: "a".to_string(),
                             span: Span::new(9, 10),
+                            unwrap_payload: std::cell::Cell::new(None),
                         },
                         type_ref: TypeRef::Named("number".to_string(), Span::new(12, 18)),
                         ownership: None,
@@ -1645,6 +1650,7 @@ fn test_complete_program_construction() {
                         name: Identifier {
                             name: "b".to_string(),
                             span: Span::new(20, 21),
+                            unwrap_payload: std::cell::Cell::new(None),
                         },
                         type_ref: TypeRef::Named("number".to_string(), Span::new(23, 29)),
                         ownership: None,
@@ -1661,10 +1667,12 @@ fn test_complete_program_construction() {
                             left: Box::new(Expr::Identifier(Identifier {
                                 name: "a".to_string(),
                                 span: Span::new(50, 51),
+                                unwrap_payload: std::cell::Cell::new(None),
                             })),
                             right: Box::new(Expr::Identifier(Identifier {
                                 name: "b".to_string(),
                                 span: Span::new(54, 55),
+                                unwrap_payload: std::cell::Cell::new(None),
                             })),
                             span: Span::new(50, 55),
                         })),
@@ -1680,12 +1688,14 @@ fn test_complete_program_construction() {
                 name: Identifier {
                     name: "result".to_string(),
                     span: Span::new(64, 70),
+                    unwrap_payload: std::cell::Cell::new(None),
                 },
                 type_ref: Some(TypeRef::Named("number".to_string(), Span::new(72, 78))),
                 init: Expr::Call(CallExpr {
                     callee: Box::new(Expr::Identifier(Identifier {
                         name: "add".to_string(),
                         span: Span::new(81, 84),
+                        unwrap_payload: std::cell::Cell::new(None),
                     })),
                     args: vec![
                         Expr::Literal(Literal::Number(5.0), Span::new(85, 86)),
@@ -1728,6 +1738,7 @@ fn test_all_statement_types() {
             name: Identifier {
                 name: "x".to_string(),
                 span: Span::new(0, 1),
+                unwrap_payload: std::cell::Cell::new(None),
             },
             type_ref: None,
             init: Expr::Literal(Literal::Number(42.0), Span::new(4, 6)),
@@ -1738,6 +1749,7 @@ fn test_all_statement_types() {
             target: AssignTarget::Name(Identifier {
                 name: "x".to_string(),
                 span: Span::new(0, 1),
+                unwrap_payload: std::cell::Cell::new(None),
             }),
             value: Expr::Literal(Literal::Number(100.0), Span::new(4, 7)),
             span: Span::new(0, 8),
@@ -1757,6 +1769,7 @@ fn test_all_statement_types() {
         }),
         // While loop
         Stmt::While(WhileStmt {
+            label: None,
             cond: Expr::Literal(Literal::Bool(true), Span::new(6, 10)),
             body: Block {
                 statements: vec![],
@@ -1766,11 +1779,13 @@ fn test_all_statement_types() {
         }),
         // For loop
         Stmt::For(ForStmt {
+            label: None,
             init: Box::new(Stmt::VarDecl(VarDecl {
                 mutable: true,
                 name: Identifier {
                     name: "i".to_string(),
                     span: Span::new(8, 9),
+                    unwrap_payload: std::cell::Cell::new(None),
                 },
                 type_ref: None,
                 init: Expr::Literal(Literal::Number(0.0), Span::new(12, 13)),
@@ -1781,6 +1796,7 @@ fn test_all_statement_types() {
                 left: Box::new(Expr::Identifier(Identifier {
                     name: "i".to_string(),
                     span: Span::new(16, 17),
+                    unwrap_payload: std::cell::Cell::new(None),
                 })),
                 right: Box::new(Expr::Literal(Literal::Number(10.0), Span::new(20, 22))),
                 span: Span::new(16, 22),
@@ -1789,12 +1805,14 @@ fn test_all_statement_types() {
                 target: AssignTarget::Name(Identifier {
                     name: "i".to_string(),
                     span: Span::new(24, 25),
+                    unwrap_payload: std::cell::Cell::new(None),
                 }),
                 value: Expr::Binary(BinaryExpr {
                     op: BinaryOp::Add,
                     left: Box::new(Expr::Identifier(Identifier {
                         name: "i".to_string(),
                         span: Span::new(28, 29),
+                        unwrap_payload: std::cell::Cell::new(None),
                     })),
                     right: Box::new(Expr::Literal(Literal::Number(1.0), Span::new(32, 33))),
                     span: Span::new(28, 33),
@@ -1813,15 +1831,22 @@ fn test_all_statement_types() {
             span: Span::new(0, 10),
         }),
         // Break statement
-        Stmt::Break(Span::new(0, 5)),
+        Stmt::Break(BreakStmt {
+            label: None,
+            span: Span::new(0, 5),
+        }),
         // Continue statement
-        Stmt::Continue(Span::new(0, 8)),
+        Stmt::Continue(ContinueStmt {
+            label: None,
+            span: Span::new(0, 8),
+        }),
         // Expression statement
         Stmt::Expr(ExprStmt {
             expr: Expr::Call(CallExpr {
                 callee: Box::new(Expr::Identifier(Identifier {
                     name: "print".to_string(),
                     span: Span::new(0, 5),
+                    unwrap_payload: std::cell::Cell::new(None),
                 })),
                 args: vec![Expr::Literal(
                     Literal::String("hello".to_string()),
@@ -1859,6 +1884,7 @@ fn test_all_expression_types() {
         Expr::Identifier(Identifier {
             name: "x".to_string(),
             span: Span::new(0, 1),
+            unwrap_payload: std::cell::Cell::new(None),
         }),
         // Unary expressions
         Expr::Unary(UnaryExpr {
@@ -1883,6 +1909,7 @@ fn test_all_expression_types() {
             callee: Box::new(Expr::Identifier(Identifier {
                 name: "func".to_string(),
                 span: Span::new(0, 4),
+                unwrap_payload: std::cell::Cell::new(None),
             })),
             args: vec![],
             span: Span::new(0, 6),
@@ -1892,6 +1919,7 @@ fn test_all_expression_types() {
             target: Box::new(Expr::Identifier(Identifier {
                 name: "arr".to_string(),
                 span: Span::new(0, 3),
+                unwrap_payload: std::cell::Cell::new(None),
             })),
             index: Box::new(Expr::Literal(Literal::Number(0.0), Span::new(4, 5))),
             span: Span::new(0, 6),
@@ -2024,6 +2052,7 @@ fn test_assignment_target_variants() {
     let name_target = AssignTarget::Name(Identifier {
         name: "x".to_string(),
         span: Span::new(0, 1),
+        unwrap_payload: std::cell::Cell::new(None),
     });
 
     assert!(matches!(name_target, AssignTarget::Name(_)));
@@ -2033,6 +2062,7 @@ fn test_assignment_target_variants() {
         target: Box::new(Expr::Identifier(Identifier {
             name: "arr".to_string(),
             span: Span::new(0, 3),
+            unwrap_payload: std::cell::Cell::new(None),
         })),
         index: Box::new(Expr::Literal(Literal::Number(0.0), Span::new(4, 5))),
         span: Span::new(0, 6),
@@ -2050,6 +2080,7 @@ fn test_ast_serialization() {
             name: Identifier {
                 name: "x".to_string(),
                 span: Span::new(4, 5),
+                unwrap_payload: std::cell::Cell::new(None),
             },
             type_ref: Some(TypeRef::Named("number".to_string(), Span::new(7, 13))),
             init: Expr::Literal(Literal::Number(42.0), Span::new(16, 18)),
@@ -72,6 +72,14 @@ fn test_string_literals_errors(#[case] input: &str, #[case] error_message: &str)
 #[case("3.14", "3.14")]
 #[case("99.999", "99.999")]
 #[case("0.5", "0.5")]
+#[case("1_000_000", "1_000_000")]
+#[case("3.141_592", "3.141_592")]
+#[case("1_0e1_0", "1_0e1_0")]
+#[case("0xFF", "0xFF")]
+#[case("0XdEaD_bEeF", "0XdEaD_bEeF")]
+#[case("0b1010", "0b1010")]
+#[case("0B1111_0000", "0B1111_0000")]
+#[case("0xFFi", "0xFFi")]
 fn test_number_literals(#[case] input: &str, #[case] expected: &str) {
     let (tokens, diagnostics) = lex(input);
 
@@ -80,6 +88,63 @@ fn test_number_literals(#[case] input: &str, #[case] expected: &str) {
     assert_eq!(tokens[0].lexeme, expected);
 }
 
+#[rstest]
+#[case("1__000", "cannot be doubled")]
+#[case("1000_", "cannot end a number")]
+#[case("1_.5", "cannot end a number")]
+#[case("1.5_", "cannot end a number")]
+#[case("1e_5", "exponent requires digits")]
+#[case("0x_FF", "cannot start a number")]
+#[case("0xF__F", "cannot be doubled")]
+#[case("0xFF_", "cannot end a number")]
+#[case("0b", "expected digits")]
+#[case("0xG", "expected digits")]
+#[case("0b102", "invalid digit")]
+fn test_number_literal_errors(#[case] input: &str, #[case] error_message: &str) {
+    let (tokens, diagnostics) = lex(input);
+
+    assert!(
+        tokens.iter().any(|t| t.kind == TokenKind::Error) || !diagnostics.is_empty(),
+        "Expected a lexer error for {}",
+        input
+    );
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.message.contains(error_message)),
+        "Expected error containing '{}', got {:?}",
+        error_message,
+        diagnostics
+    );
+}
+
+#[rstest]
+#[case("1_000_000;", Literal::Number(1_000_000.0))]
+#[case("0.000_1;", Literal::Number(0.0001))]
+#[case("0xFF;", Literal::Number(255.0))]
+#[case("0x7fff_ffff;", Literal::Number(2_147_483_647.0))]
+#[case("0b1010;", Literal::Number(10.0))]
+#[case("0b1111_0000;", Literal::Number(240.0))]
+#[case("1_024i;", Literal::Int(1024))]
+#[case("0xFFi;", Literal::Int(255))]
+#[case("0b11i;", Literal::Int(3))]
+#[case("-9223372036854775808i;", Literal::Int(i64::MIN))]
+#[case("-0x8000_0000_0000_0000i;", Literal::Int(i64::MIN))]
+fn test_number_literal_values(#[case] source: &str, #[case] expected: Literal) {
+    let (tokens, _) = lex(source);
+    let mut parser = Parser::new(tokens);
+    let (program, diagnostics) = parser.parse();
+
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    match &program.items[0] {
+        Item::Statement(Stmt::Expr(stmt)) => match &stmt.expr {
+            Expr::Literal(literal, _) => assert_eq!(literal, &expected),
+            other => panic!("Expected literal, got {:?}", other),
+        },
+        other => panic!("Expected expression statement, got {:?}", other),
+    }
+}
+
 // ============================================================================
 // Keyword Tests - Single parameterized test instead of 20+ individual tests
 // ============================================================================
@@ -124,6 +189,7 @@ fn test_keywords(#[case] keyword: &str, #[case] expected_kind: TokenKind) {
 #[case(">=", TokenKind::GreaterEqual)]
 #[case("&&", TokenKind::AmpAmp)]
 #[case("||", TokenKind::PipePipe)]
+#[case("|>", TokenKind::PipeGreater)]
 #[case("!", TokenKind::Bang)]
 #[case("=", TokenKind::Equal)]
 #[case("+=", TokenKind::PlusEqual)]
@@ -439,6 +505,7 @@ fn test_parse_assignments(#[case] source: &str, #[case] snapshot_name: &str) {
 #[case::if_else("if (true) { x; } else { y; }", "if_else_statement")]
 #[case::while_loop("while (true) { x; }", "while_loop")]
 #[case::for_loop("for (let i = 0; i < 10; i = i + 1) { x; }", "for_loop")]
+#[case::try_catch("try { x; } catch (e) { y; }", "try_catch")]
 fn test_parse_control_flow(#[case] source: &str, #[case] snapshot_name: &str) {
     let (program, diagnostics) = parse_source(source);
     assert_eq!(diagnostics.len(), 0);
@@ -454,6 +521,8 @@ fn test_parse_control_flow(#[case] source: &str, #[case] snapshot_name: &str) {
 #[case::return_void("return;", "return_no_value")]
 #[case::break_stmt("break;", "break_statement")]
 #[case::continue_stmt("continue;", "continue_statement")]
+#[case::labelled_break("outer: while (true) { break outer; }", "labelled_break")]
+#[case::labelled_continue("outer: for x in xs { continue outer; }", "labelled_continue")]
 fn test_parse_flow_control_statements(#[case] source: &str, #[case] snapshot_name: &str) {
     let (program, diagnostics) = parse_source(source);
     assert_eq!(diagnostics.len(), 0);
@@ -821,11 +890,21 @@ fn assert_has_parser_error(
 #[case("return 42", "';'")]
 #[case("break", "';'")]
 #[case("continue", "';'")]
+#[case("continue outer", "';'")]
 fn test_missing_semicolons(#[case] source: &str, #[case] expected: &str) {
     let diagnostics = parse_errors(source);
     assert_has_parser_error(&diagnostics, expected);
 }
 
+#[test]
+fn test_label_must_precede_loop() {
+    let diagnostics = parse_errors("outer: let x = 1;");
+    assert_has_parser_error(
+        &diagnostics,
+        "Expected 'while' or 'for' after label 'outer'",
+    );
+}
+
 // ============================================================================
 // Variable Declaration Errors
 // ============================================================================
@@ -945,6 +1024,79 @@ fn test_array_literal_unclosed() {
     assert!(!diagnostics.is_empty(), "Expected error for unclosed array");
 }
 
+// ============================================================================
+// String Interpolation
+// ============================================================================
+
+fn interp_parts(source: &str) -> Vec<StringInterpPart> {
+    let (program, diagnostics) = parse_source(source);
+    assert!(
+        diagnostics.is_empty(),
+        "unexpected errors: {:?}",
+        diagnostics
+    );
+    match &program.items[0] {
+        Item::Statement(Stmt::Expr(ExprStmt {
+            expr: Expr::StringInterp(interp),
+            ..
+        })) => interp.parts.clone(),
+        other => panic!("expected interpolated string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_string_interp_parts() {
+    let parts = interp_parts(r#""Hello ${name}, you are ${age}";"#);
+    assert_eq!(parts.len(), 4);
+    assert!(matches!(&parts[0], StringInterpPart::Literal(s) if s == "Hello "));
+    assert!(matches!(&parts[1], StringInterpPart::Expr(Expr::Identifier(id)) if id.name == "name"));
+    assert!(matches!(&parts[2], StringInterpPart::Literal(s) if s == ", you are "));
+    assert!(matches!(&parts[3], StringInterpPart::Expr(Expr::Identifier(id)) if id.name == "age"));
+}
+
+#[test]
+fn test_string_interp_nested() {
+    let parts = interp_parts(r#""outer ${"inner ${x}"} end";"#);
+    assert_eq!(parts.len(), 3);
+    match &parts[1] {
+        StringInterpPart::Expr(Expr::StringInterp(inner)) => {
+            assert_eq!(inner.parts.len(), 2);
+            assert!(matches!(&inner.parts[0], StringInterpPart::Literal(s) if s == "inner "));
+        }
+        other => panic!("expected nested interpolation, got {:?}", other),
+    }
+    assert!(matches!(&parts[2], StringInterpPart::Literal(s) if s == " end"));
+}
+
+#[test]
+fn test_string_interp_expression_only() {
+    let parts = interp_parts(r#""${a + b}";"#);
+    assert_eq!(parts.len(), 1);
+    assert!(matches!(&parts[0], StringInterpPart::Expr(Expr::Binary(_))));
+}
+
+#[test]
+fn test_string_interp_unclosed() {
+    let diagnostics = parse_errors(r#"let s = "value ${x";"#);
+    assert!(
+        !diagnostics.is_empty(),
+        "Expected error for unclosed interpolation"
+    );
+}
+
+// ============================================================================
+// Map Literal Errors
+// ============================================================================
+
+#[rstest]
+#[case("let m = { 1: 2 };", "identifier or string as map key")]
+#[case("let m = { a 1 };", "Expected ':' after map key")]
+#[case("let m = { a: 1", "Expected '}'")]
+fn test_map_literal_errors(#[case] source: &str, #[case] expected: &str) {
+    let diagnostics = parse_errors(source);
+    assert_has_parser_error(&diagnostics, expected);
+}
+
 // ============================================================================
 // Unary Operator Errors
 // ============================================================================
@@ -1001,6 +1153,12 @@ fn parse_valid(source: &str) -> atlas_runtime::ast::Program {
 // Array indexing (highest precedence)
 #[case("array_index_in_arithmetic", "arr[0] + 2 * 3;")]
 #[case("array_index_in_comparison", "arr[i] < 10;")]
+// Pipeline: looser than `+`, tighter than comparison, desugared into calls
+#[case("pipeline_chain", "xs |> filter(isEven) |> map(double) |> sum;")]
+#[case("pipeline_after_add", "a + 1 |> f(2);")]
+#[case("pipeline_before_comparison", "xs |> len > 2;")]
+#[case("pipeline_call_then_add", "x |> f(a) + 1;")]
+#[case("pipeline_method_call", "x |> obj.apply(1);")]
 fn test_operator_precedence(#[case] name: &str, #[case] source: &str) {
     let program = parse_valid(source);
 
@@ -1692,6 +1850,16 @@ fn test_import_with_trailing_comma() {
     );
 }
 
+#[rstest]
+#[case("let xs = [1, 2,];")]
+#[case("let m = { a: 1, b: 2, };")]
+#[case("foo(1, 2,);")]
+#[case("xs.push(1,);")]
+fn test_expression_lists_accept_trailing_comma(#[case] source: &str) {
+    let (success, msgs) = parse(source);
+    assert!(success, "Should parse {}: {:?}", source, msgs);
+}
+
 #[test]
 fn test_import_empty_list() {
     let source = r#"import { } from "./mod""#;
@@ -1371,7 +1371,7 @@ fn test_valid_block_scoping(#[case] source: &str) {
     "AT2002"
 )]
 #[case::for_body_out_of_scope(
-    r#"for (let i: number = 0; i < 10; i = i + 1) { let sum: number = 0; } let x = sum;"#,
+    r#"for (let i: number = 0; i < 10; i = i + 1) { let total: number = 0; } let x = total;"#,
     "AT2002"
 )]
 fn test_out_of_scope_errors(#[case] source: &str, #[case] expected_code: &str) {
@@ -5473,3 +5473,100 @@ fn test_parity_block03_scenario_j_interpreter() {
         .expect("scenario J should succeed");
     assert_eq!(result, Value::Number(14.0));
 }
+
+// ============================================================================
+// Pipeline operator `|>` — parity with the equivalent nested calls
+// ============================================================================
+
+const PIPELINE_PRELUDE: &str = "
+    fn isEven(n: number) -> bool { return n % 2 == 0; }
+    fn double(n: number) -> number { return n * 2; }
+    fn add(a: number, b: number) -> number { return a + b; }
+    fn show(n: number) -> string { return str(n); }
+    let xs: number[] = [1, 2, 3, 4, 5, 6];
+";
+
+#[rstest]
+#[case(
+    "xs |> filter(isEven) |> map(double) |> sum",
+    "sum(map(filter(xs, isEven), double))"
+)]
+#[case("3 |> add(4) |> double", "double(add(3, 4))")]
+#[case("1 + 2 |> double", "double(1 + 2)")]
+#[case("xs |> len > 5", "len(xs) > 5")]
+#[case(
+    r#"xs |> map(double) |> map(show) |> join(", ")"#,
+    r#"join(map(map(xs, double), show), ", ")"#
+)]
+fn test_pipeline_matches_nested_calls(#[case] pipeline: &str, #[case] nested: &str) {
+    let piped = Atlas::new()
+        .eval(&format!("{}{}", PIPELINE_PRELUDE, pipeline))
+        .expect("pipeline should succeed");
+    let expected = Atlas::new()
+        .eval(&format!("{}{}", PIPELINE_PRELUDE, nested))
+        .expect("nested calls should succeed");
+    assert_eq!(piped, expected);
+}
+
+#[test]
+fn test_pipeline_value() {
+    let result = Atlas::new()
+        .eval(&format!(
+            "{}xs |> filter(isEven) |> map(double) |> sum",
+            PIPELINE_PRELUDE
+        ))
+        .expect("pipeline should succeed");
+    assert_eq!(result, Value::Number(24.0));
+}
+
+#[test]
+fn test_pipeline_type_error_reported_on_rewritten_call() {
+    let result = Atlas::new().eval(&format!("{}\"a\" |> double", PIPELINE_PRELUDE));
+    assert!(
+        result.is_err(),
+        "string piped into number param should fail"
+    );
+}
+
+// ============================================================================
+// Extension methods — top-level `fn name(self: T, ...)` called as `value.name()`
+// ============================================================================
+
+#[test]
+fn test_extension_method_on_string() {
+    let code = r#"
+        fn shout(self: string) -> string { return toUpperCase(self) + "!"; }
+        let r: string = "hi".shout();
+        r
+    "#;
+    let result = Atlas::new().eval(code).expect("Should succeed");
+    assert_eq!(result, Value::string("HI!"));
+}
+
+#[test]
+fn test_extension_method_on_array_with_args() {
+    let code = "
+        fn sumAbove(self: number[], min: number) -> number {
+            var total: number = 0;
+            for x in self { if (x > min) { total = total + x; } }
+            return total;
+        }
+        let xs: number[] = [1, 5, 2, 8];
+        let r: number = xs.sumAbove(2);
+        r
+    ";
+    let result = Atlas::new().eval(code).expect("Should succeed");
+    assert_eq!(result, Value::Number(13.0));
+}
+
+#[test]
+fn test_extension_methods_chain() {
+    let code = r#"
+        fn twice(self: string) -> string { return self + self; }
+        fn shout(self: string) -> string { return toUpperCase(self) + "!"; }
+        let r: string = "ab".twice().shout();
+        r
+    "#;
+    let result = Atlas::new().eval(code).expect("Should succeed");
+    assert_eq!(result, Value::string("ABAB!"));
+}
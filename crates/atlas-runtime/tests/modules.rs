@@ -57,7 +57,6 @@ fn bind_module_with_registry(
 }
 
 /// Helper to type check with modules
-#[allow(dead_code)] // Preserved for future test expansion
 fn typecheck_module_with_registry(
     source: &str,
     module_path: &str,
@@ -374,6 +373,75 @@ export fn bar() -> number {
     assert!(exports.contains_key("bar"));
 }
 
+#[test]
+fn test_re_export_function_preserves_type() {
+    let mut registry = ModuleRegistry::new();
+
+    let math = r#"
+export fn add(a: number, b: number) -> number {
+    return a + b;
+}
+"#;
+    let (math_symbols, _) = bind_module(math);
+    let original_ty = math_symbols.get_exports()["add"].ty.clone();
+    registry.register(PathBuf::from("/math.atl"), math_symbols);
+
+    // The API module forwards 'add' without binding it locally
+    let api = r#"export { add } from "/math.atl";"#;
+    let (api_symbols, diags) = bind_module_with_registry(api, "/api.atl", &registry);
+    assert!(
+        diags.is_empty(),
+        "Expected no diagnostics, got: {:?}",
+        diags
+    );
+    assert!(api_symbols.lookup("add").is_none());
+    assert_eq!(api_symbols.get_exports()["add"].ty, original_ty);
+    registry.register(PathBuf::from("/api.atl"), api_symbols);
+
+    // Importers see the original signature
+    let main = r#"
+import { add } from "/api.atl";
+let total: number = add(1, 2);
+"#;
+    let diags = typecheck_module_with_registry(main, "/main.atl", &registry);
+    assert!(
+        diags.is_empty(),
+        "Expected no diagnostics, got: {:?}",
+        diags
+    );
+
+    let bad = r#"
+import { add } from "/api.atl";
+let total: string = add(1, 2);
+"#;
+    let diags = typecheck_module_with_registry(bad, "/bad.atl", &registry);
+    assert!(
+        diags.iter().any(|d| d.code == "AT3001"),
+        "Expected type mismatch, got: {:?}",
+        diags
+    );
+}
+
+#[test]
+fn test_re_export_nonexistent_name() {
+    let mut registry = ModuleRegistry::new();
+
+    let (math_symbols, _) =
+        bind_module("export fn add(a: number, b: number) -> number { return a + b; }");
+    registry.register(PathBuf::from("/math.atl"), math_symbols);
+
+    let api = r#"export { add, subtract } from "/math.atl";"#;
+    let (api_symbols, diags) = bind_module_with_registry(api, "/api.atl", &registry);
+    assert_eq!(diags.len(), 1, "Expected one diagnostic, got: {:?}", diags);
+    assert_eq!(diags[0].code, "AT5006");
+    assert_eq!(
+        diags[0].message,
+        "Module '/math.atl' does not export 'subtract'"
+    );
+    // The valid name is still forwarded
+    assert!(api_symbols.get_exports().contains_key("add"));
+}
+
 // --- Module execution (interpreter) ---
 
 // Module Execution Tests (BLOCKER 04-D)
@@ -499,6 +567,63 @@ add(5, 7);
     }
 }
 
+#[test]
+fn test_import_re_exported_function() {
+    let temp_dir = TempDir::new().unwrap();
+
+    create_module(
+        temp_dir.path(),
+        "math",
+        "export fn add(a: number, b: number) -> number { return a + b; }",
+    );
+    create_module(temp_dir.path(), "api", r#"export { add } from "./math";"#);
+
+    let main = create_module(
+        temp_dir.path(),
+        "main",
+        r#"
+import { add } from "./api";
+add(5, 7);
+"#,
+    );
+
+    let mut interp = atlas_runtime::Interpreter::new();
+    let sec = SecurityContext::allow_all();
+    let mut executor = ModuleExecutor::new(&mut interp, &sec, temp_dir.path().to_path_buf());
+    let result = executor.execute_module(&main);
+
+    match result {
+        Ok(Value::Number(n)) => assert_eq!(n, 12.0),
+        Ok(v) => panic!("Expected Number(12.0), got {:?}", v),
+        Err(e) => panic!("Execution failed: {:?}", e),
+    }
+}
+
+#[test]
+fn test_re_export_missing_name_fails_at_load() {
+    let temp_dir = TempDir::new().unwrap();
+
+    create_module(
+        temp_dir.path(),
+        "math",
+        "export fn add(a: number, b: number) -> number { return a + b; }",
+    );
+    create_module(temp_dir.path(), "api", r#"export { sub } from "./math";"#);
+
+    let main = create_module(temp_dir.path(), "main", r#"import { sub } from "./api";"#);
+
+    let mut interp = atlas_runtime::Interpreter::new();
+    let sec = SecurityContext::allow_all();
+    let mut executor = ModuleExecutor::new(&mut interp, &sec, temp_dir.path().to_path_buf());
+    let diagnostics = executor.execute_module(&main).unwrap_err();
+
+    assert_eq!(diagnostics[0].code, "AT5006");
+    assert_eq!(
+        diagnostics[0].message,
+        "Module './math' does not export 'sub'"
+    );
+}
+
 #[test]
 fn test_import_multiple_functions() {
     let temp_dir = TempDir::new().unwrap();
@@ -940,11 +1065,10 @@ a_val;
     assert!(result.is_err());
     if let Err(diagnostics) = result {
         assert!(!diagnostics.is_empty());
-        assert!(
-            diagnostics[0].message.contains("Circular")
-                || diagnostics[0].message.contains("circular"),
-            "Expected circular dependency error, got: {}",
-            diagnostics[0].message
+        assert_eq!(diagnostics[0].code, "AT5009");
+        assert_eq!(
+            diagnostics[0].message,
+            "Circular import: a.atl -> b.atl -> a.atl"
         );
     }
 }
@@ -1131,6 +1255,84 @@ PORT;
     }
 }
 
+// ============================================================================
+// Prelude
+// ============================================================================
+
+#[test]
+fn test_prelude_function_callable_without_import() {
+    let temp_dir = TempDir::new().unwrap();
+    let prelude = create_module(
+        temp_dir.path(),
+        "prelude",
+        "export fn shout(s: string) -> string { return s + \"!\"; }",
+    );
+    let main = create_module(temp_dir.path(), "main", "shout(\"hi\");");
+
+    let mut interp = atlas_runtime::Interpreter::new();
+    let sec = SecurityContext::allow_all();
+    let mut executor =
+        ModuleExecutor::new(&mut interp, &sec, temp_dir.path().to_path_buf()).with_prelude(prelude);
+    let result = executor.execute_module(&main);
+
+    match result {
+        Ok(Value::String(s)) => assert_eq!(*s, "hi!"),
+        Ok(v) => panic!("Expected String, got {:?}", v),
+        Err(e) => panic!("Execution failed: {:?}", e),
+    }
+}
+
+#[test]
+fn test_local_definition_shadows_prelude() {
+    let temp_dir = TempDir::new().unwrap();
+    let prelude = create_module(
+        temp_dir.path(),
+        "prelude",
+        "export fn shout(s: string) -> string { return s + \"!\"; }\nexport let SUFFIX: string = \"?\";",
+    );
+    let main = create_module(
+        temp_dir.path(),
+        "main",
+        r#"
+fn shout(s: string) -> string { return s + SUFFIX + SUFFIX; }
+shout("hi");
+"#,
+    );
+
+    let mut interp = atlas_runtime::Interpreter::new();
+    let sec = SecurityContext::allow_all();
+    let mut executor =
+        ModuleExecutor::new(&mut interp, &sec, temp_dir.path().to_path_buf()).with_prelude(prelude);
+    let result = executor.execute_module(&main);
+
+    match result {
+        Ok(Value::String(s)) => assert_eq!(*s, "hi??"),
+        Ok(v) => panic!("Expected String, got {:?}", v),
+        Err(e) => panic!("Execution failed: {:?}", e),
+    }
+}
+
+#[test]
+fn test_atlas_eval_file_with_prelude() {
+    let temp_dir = TempDir::new().unwrap();
+    let prelude = create_module(
+        temp_dir.path(),
+        "prelude",
+        "export fn twice(x: number) -> number { return x * 2; }",
+    );
+    let main = create_module(temp_dir.path(), "main", "twice(21);");
+
+    let runtime =
+        atlas_runtime::Atlas::new_with_security(SecurityContext::allow_all()).with_prelude(prelude);
+    let result = runtime.eval_file(main.to_str().unwrap());
+
+    match result {
+        Ok(Value::Number(n)) => assert_eq!(n, 42.0),
+        Ok(v) => panic!("Expected Number(42.0), got {:?}", v),
+        Err(e) => panic!("Execution failed: {:?}", e),
+    }
+}
+
 // ============================================================================
 // Type Safety Across Modules
 // ============================================================================
@@ -1010,6 +1010,30 @@ fn test_parity_array_pattern() {
     );
 }
 
+#[test]
+fn test_parity_top_level_match_unwraps_result() {
+    assert_parity_number(
+        r#"fn parse(flag: bool) -> Result<number, string> {
+            if (flag) { return Ok(7); }
+            return Err("bad");
+        }
+        let ok: number = match parse(true) { Ok(x) => x, Err(e) => 0 };
+        let err: number = match parse(false) { Ok(x) => x, Err(e) => len(e) };
+        ok * 10 + err;"#,
+        73.0,
+    );
+}
+
+#[test]
+fn test_parity_top_level_match_unwraps_option() {
+    assert_parity_number(
+        r#"let opt: Option<number> = Some(4);
+        let none: Option<number> = None();
+        (match opt { Some(x) => x, None => 0 }) + (match none { Some(x) => x, None => 1 });"#,
+        5.0,
+    );
+}
+
 // ============================================================================
 // Real-world Patterns
 // ============================================================================
@@ -751,6 +751,106 @@ fn repl_load_file_nonexistent_error() {
     assert!(result.is_err());
 }
 
+#[test]
+fn repl_reload_picks_up_edits() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("script.atlas");
+    std::fs::write(
+        &path,
+        "fn greet() -> string { return \"v1\"; }\nfn old_helper() -> number { return 1; }\nlet version = 1;",
+    )
+    .unwrap();
+
+    let mut repl = ReplCore::new();
+    let loaded = repl.load_file(&path).unwrap();
+    assert!(loaded.diagnostics.is_empty(), "{:?}", loaded.diagnostics);
+    assert_eq!(eval_ok(&mut repl, "greet();"), Value::string("v1"));
+
+    std::fs::write(
+        &path,
+        "fn greet() -> string { return \"v2\"; }\nlet version = 2;",
+    )
+    .unwrap();
+
+    let reloaded = repl.reload().unwrap();
+    assert!(
+        reloaded.diagnostics.is_empty(),
+        "{:?}",
+        reloaded.diagnostics
+    );
+    assert_eq!(repl.loaded_file(), Some(path.as_path()));
+    assert_eq!(eval_ok(&mut repl, "greet();"), Value::string("v2"));
+    assert_eq!(eval_ok(&mut repl, "version;"), Value::Number(2.0));
+    // Definitions removed from the file do not linger
+    eval_err(&mut repl, "old_helper();");
+}
+
+#[test]
+fn repl_reload_keeps_repl_definitions() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("script.atlas");
+    std::fs::write(&path, "let fromFile = 1;").unwrap();
+
+    let mut repl = ReplCore::new();
+    repl.load_file(&path).unwrap();
+    eval_ok(&mut repl, "let typed = 10;");
+    let reloaded = repl.reload().unwrap();
+    assert!(
+        reloaded.diagnostics.is_empty(),
+        "{:?}",
+        reloaded.diagnostics
+    );
+    assert_eq!(eval_ok(&mut repl, "typed + fromFile;"), Value::Number(11.0));
+}
+
+#[test]
+fn repl_reload_without_load_is_error() {
+    let mut repl = ReplCore::new();
+    let err = repl.reload().err().unwrap();
+    assert!(err.contains(":load"), "got: {}", err);
+}
+
+#[test]
+fn repl_session_source_keeps_latest_definitions() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("lib.atlas");
+    std::fs::write(&path, "fn square(x: number) -> number { return x; }").unwrap();
+
+    let mut repl = ReplCore::new();
+    repl.load_file(&path).unwrap();
+    eval_ok(&mut repl, "let base = square(3);");
+    eval_ok(&mut repl, "base + 1;");
+    // Failed definitions are not recorded
+    eval_err(&mut repl, "let broken: number = \"no\";");
+
+    std::fs::write(&path, "fn square(x: number) -> number { return x * x; }").unwrap();
+    repl.reload().unwrap();
+
+    assert_eq!(
+        repl.session_source(),
+        "let base = square(3);\nfn square(x: number) -> number { return x * x; }\n"
+    );
+}
+
+#[test]
+fn repl_save_writes_reloadable_source() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.atl");
+
+    let mut repl = ReplCore::new();
+    eval_ok(
+        &mut repl,
+        "fn double(n: number) -> number { return n * 2; }",
+    );
+    eval_ok(&mut repl, "let start = double(4);");
+    assert_eq!(repl.save(&path).unwrap(), 2);
+
+    let mut fresh = ReplCore::new();
+    let loaded = fresh.load_file(&path).unwrap();
+    assert!(loaded.diagnostics.is_empty(), "{:?}", loaded.diagnostics);
+    assert_eq!(eval_ok(&mut fresh, "start;"), Value::Number(8.0));
+}
+
 // --- Additional REPL state tests ---
 
 #[test]
@@ -674,35 +674,9 @@ fn test_text_formatting_pipeline() {
 #[test]
 fn test_deduplication_pipeline() {
     let code = r#"
-        // Manual deduplication since we don't have Set yet
-        fn notInList(items: string[], item: string) -> bool {
-            return !arrayIncludes(items, item);
-        }
-
         let words: string[] = ["apple", "banana", "apple", "cherry", "banana", "date"];
-        var unique: string[] = [];
-
-        // Manual dedup (simplified for test)
-        if (notInList(unique, words[0])) {
-            unique = concat(unique, [words[0]]);
-        }
-        if (notInList(unique, words[1])) {
-            unique = concat(unique, [words[1]]);
-        }
-        if (notInList(unique, words[2])) {
-            unique = concat(unique, [words[2]]);
-        }
-        if (notInList(unique, words[3])) {
-            unique = concat(unique, [words[3]]);
-        }
-        if (notInList(unique, words[4])) {
-            unique = concat(unique, [words[4]]);
-        }
-        if (notInList(unique, words[5])) {
-            unique = concat(unique, [words[5]]);
-        }
-
-        len(unique)
+        let distinct: string[] = unique(words);
+        len(distinct)
     "#;
     assert_eval_number(code, 4.0); // apple, banana, cherry, date
 }
@@ -1074,6 +1048,106 @@ fn test_product_reduce() {
     assert_eval_number(code, 24.0); // 2 * 3 * 4
 }
 
+#[rstest]
+#[case::single("[7]")]
+#[case::mixed_signs("[3, -2, 8, 0, 12]")]
+#[case::fractions("[0.5, 1.25, 2.75, 4]")]
+fn test_aggregates_match_reduce(#[case] array: &str) {
+    let code = format!(
+        r#"
+        fn add(a: number, b: number) -> number {{
+            return a + b;
+        }}
+        fn multiply(a: number, b: number) -> number {{
+            return a * b;
+        }}
+        fn smaller(a: number, b: number) -> number {{
+            if (a < b) {{ return a; }}
+            return b;
+        }}
+        fn larger(a: number, b: number) -> number {{
+            if (a > b) {{ return a; }}
+            return b;
+        }}
+
+        let xs: number[] = {array};
+        sum(xs) == reduce(xs, add, 0)
+            && product(xs) == reduce(xs, multiply, 1)
+            && average(xs) == reduce(xs, add, 0) / len(xs)
+            && minOf(xs) == reduce(xs, smaller, xs[0])
+            && maxOf(xs) == reduce(xs, larger, xs[0])
+    "#
+    );
+    assert_eval_bool(&code, true);
+}
+
+#[test]
+fn test_sum_and_product_of_empty_array() {
+    assert_eval_number("sum([])", 0.0);
+    assert_eval_number("product([])", 1.0);
+}
+
+#[rstest]
+#[case::average("average")]
+#[case::min_of("minOf")]
+#[case::max_of("maxOf")]
+fn test_aggregate_of_empty_array_is_error(#[case] func: &str) {
+    let runtime = Atlas::new();
+    let diagnostics = runtime.eval(&format!("{}([])", func)).unwrap_err();
+    assert_eq!(diagnostics[0].code, "AT0001");
+    assert!(
+        diagnostics[0].message.contains("empty array"),
+        "got: {}",
+        diagnostics[0].message
+    );
+}
+
+#[test]
+fn test_aggregate_requires_number_array() {
+    assert_error_code(r#"sum(["a", "b"])"#, "AT3001");
+}
+
+#[test]
+fn test_aggregate_names_can_be_redefined() {
+    let code = r#"
+        var sum: number = 0;
+        for x in [1, 2, 3] {
+            sum = sum + x;
+        }
+        sum
+    "#;
+    assert_eval_number(code, 6.0);
+}
+
+#[test]
+fn test_zip_truncates_to_shorter_array() {
+    let code = r#"
+        let pairs = zip([1, 2, 3], ["a", "b"]);
+        str(len(pairs)) + " " + toJSON(pairs[0]) + " " + toJSON(pairs[1])
+    "#;
+    assert_eval_string(code, r#"2 [1,"a"] [2,"b"]"#);
+}
+
+#[test]
+fn test_enumerate_pairs_index_with_value() {
+    let code = r#"
+        let pairs = enumerate(["x", "y"]);
+        toJSON(pairs[0]) + " " + toJSON(pairs[1])
+    "#;
+    assert_eval_string(code, r#"[0,"x"] [1,"y"]"#);
+}
+
+#[test]
+fn test_zip_and_enumerate_of_empty_arrays() {
+    assert_eval_number("len(zip([], [1, 2]))", 0.0);
+    assert_eval_number("len(enumerate([]))", 0.0);
+}
+
+#[test]
+fn test_zip_requires_arrays() {
+    assert_error_code(r#"zip([1], "ab")"#, "AT3001");
+}
+
 #[test]
 fn test_ceil_floor_pipeline() {
     let code = r#"
@@ -3237,6 +3311,163 @@ fn test_read_file_with_bom() {
     assert!(result.is_ok());
 }
 
+// ============================================================================
+// readLines tests
+// ============================================================================
+
+#[test]
+fn test_read_lines_strips_trailing_newline() {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("lines.txt");
+    fs::write(&test_file, "alpha\nbeta\r\n\ngamma\n").unwrap();
+
+    let code = format!(r#"readLines("{}")"#, path_for_atlas(&test_file));
+    let result = runtime.eval(&code).unwrap();
+
+    assert_eq!(result.to_string(), "[alpha, beta, , gamma]");
+}
+
+#[test]
+fn test_read_lines_empty_file() {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("empty.txt");
+    fs::write(&test_file, "").unwrap();
+
+    let code = format!(r#"len(readLines("{}"))"#, path_for_atlas(&test_file));
+    let result = runtime.eval(&code).unwrap();
+
+    assert_eq!(result, Value::Number(0.0));
+}
+
+#[test]
+fn test_read_lines_large_file() {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("large.log");
+    let line_count = 100_000;
+    let content: String = (0..line_count)
+        .map(|i| format!("2024-01-01T00:00:00Z INFO request {} handled\n", i))
+        .collect();
+    assert!(content.len() > 4 * 1024 * 1024);
+    fs::write(&test_file, &content).unwrap();
+
+    let code = format!(
+        r#"let lines = readLines("{}"); len(lines) * 10 + len(lines[len(lines) - 1])"#,
+        path_for_atlas(&test_file)
+    );
+    let result = runtime.eval(&code).unwrap();
+
+    let last_line_len = format!(
+        "2024-01-01T00:00:00Z INFO request {} handled",
+        line_count - 1
+    )
+    .len();
+    assert_eq!(
+        result,
+        Value::Number((line_count * 10 + last_line_len) as f64)
+    );
+}
+
+#[test]
+fn test_read_lines_permission_denied() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("secret.log");
+    fs::write(&test_file, "a\nb\n").unwrap();
+
+    let runtime = Atlas::new();
+    let code = format!(r#"readLines("{}")"#, path_for_atlas(&test_file));
+    let diagnostics = runtime.eval(&code).unwrap_err();
+
+    assert_eq!(diagnostics[0].code, "AT0300");
+}
+
+// ============================================================================
+// readFileBytes / writeFileBytes tests
+// ============================================================================
+
+#[test]
+fn test_read_file_bytes_invalid_utf8() {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("binary.bin");
+    fs::write(&test_file, [0xFF, 0xFE, 0xFD]).unwrap();
+
+    let code = format!(r#"readFileBytes("{}")"#, path_for_atlas(&test_file));
+    let result = runtime.eval(&code).unwrap();
+
+    assert_eq!(result.to_string(), "[255, 254, 253]");
+}
+
+#[test]
+fn test_write_file_bytes_round_trip() {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("roundtrip.bin");
+    let path = path_for_atlas(&test_file);
+
+    let code = format!(
+        r#"writeFileBytes("{}", [255, 254, 253]); readFileBytes("{}")"#,
+        path, path
+    );
+    let result = runtime.eval(&code).unwrap();
+
+    assert_eq!(fs::read(&test_file).unwrap(), vec![0xFF, 0xFE, 0xFD]);
+    assert_eq!(result.to_string(), "[255, 254, 253]");
+}
+
+#[test]
+fn test_write_file_bytes_empty() {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("empty.bin");
+
+    let code = format!(r#"writeFileBytes("{}", [])"#, path_for_atlas(&test_file));
+    runtime.eval(&code).unwrap();
+
+    assert!(fs::read(&test_file).unwrap().is_empty());
+}
+
+#[rstest]
+#[case::too_large("[1, 256]")]
+#[case::negative("[-1]")]
+#[case::fractional("[1.5]")]
+fn test_write_file_bytes_invalid_element(#[case] bytes: &str) {
+    let (runtime, temp_dir) = test_runtime_with_io();
+    let test_file = temp_dir.path().join("invalid.bin");
+
+    let code = format!(
+        r#"writeFileBytes("{}", {})"#,
+        path_for_atlas(&test_file),
+        bytes
+    );
+    let diagnostics = runtime.eval(&code).unwrap_err();
+
+    assert_eq!(diagnostics[0].code, "AT0102");
+    assert!(!test_file.exists());
+}
+
+#[test]
+fn test_read_file_bytes_permission_denied() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("secret.bin");
+    fs::write(&test_file, [1, 2, 3]).unwrap();
+
+    let runtime = Atlas::new();
+    let code = format!(r#"readFileBytes("{}")"#, path_for_atlas(&test_file));
+    let diagnostics = runtime.eval(&code).unwrap_err();
+
+    assert_eq!(diagnostics[0].code, "AT0300");
+}
+
+#[test]
+fn test_write_file_bytes_permission_denied() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("output.bin");
+
+    let runtime = Atlas::new();
+    let code = format!(r#"writeFileBytes("{}", [1])"#, path_for_atlas(&test_file));
+    let diagnostics = runtime.eval(&code).unwrap_err();
+
+    assert_eq!(diagnostics[0].code, "AT0300");
+    assert!(!test_file.exists());
+}
+
 // ============================================================================
 // writeFile - Additional edge case tests
 // ============================================================================
@@ -5276,6 +5507,74 @@ fn test_json_empty_array() {
     assert_eval_bool_with_io(code, true);
 }
 
+#[test]
+fn test_json_path_deep_hit() {
+    let code = r#"
+        let jsonStr: string = "{\"users\": [{\"name\": \"Alice\"}, {\"name\": \"Bob\"}]}";
+        let data: json = parseJSON(jsonStr);
+        jsonAsString(unwrap(jsonPath(data, "users.1.name")))
+    "#;
+    assert_eval_string_with_io(code, "Bob");
+}
+
+#[test]
+fn test_json_path_missing_key_mid_path() {
+    let code = r#"
+        let jsonStr: string = "{\"users\": [{\"name\": \"Alice\"}]}";
+        let data: json = parseJSON(jsonStr);
+        is_none(jsonPath(data, "accounts.0.name"))
+    "#;
+    assert_eval_bool_with_io(code, true);
+}
+
+#[test]
+fn test_json_path_index_out_of_range() {
+    let code = r#"
+        let jsonStr: string = "{\"users\": [{\"name\": \"Alice\"}]}";
+        let data: json = parseJSON(jsonStr);
+        is_none(jsonPath(data, "users.5.name"))
+    "#;
+    assert_eval_bool_with_io(code, true);
+}
+
+#[test]
+fn test_json_validate_passing_object() {
+    let code = r#"
+        let data: json = parseJSON("{\"name\": \"Alice\", \"age\": 30}");
+        let schema: json = parseJSON("{\"name\": \"string\", \"age\": \"number\"}");
+        unwrap(jsonValidate(data, schema))
+    "#;
+    assert_eval_bool_with_io(code, true);
+}
+
+#[test]
+fn test_json_validate_wrong_type_field() {
+    let code = r#"
+        let data: json = parseJSON("{\"user\": {\"name\": \"Alice\", \"age\": \"30\"}}");
+        let schema: json = parseJSON("{\"user\": {\"name\": \"string\", \"age\": \"number\"}}");
+        let result: string = match jsonValidate(data, schema) {
+            Ok(_) => "valid",
+            Err(msg) => msg
+        };
+        result
+    "#;
+    assert_eval_string_with_io(code, "user.age: expected number, found string");
+}
+
+#[test]
+fn test_json_validate_missing_required_key() {
+    let code = r#"
+        let data: json = parseJSON("{\"name\": \"Alice\"}");
+        let schema: json = parseJSON("{\"name\": \"string\", \"age\": \"number\"}");
+        let result: string = match jsonValidate(data, schema) {
+            Ok(_) => "valid",
+            Err(msg) => msg
+        };
+        result
+    "#;
+    assert_eval_string_with_io(code, "age: missing required key");
+}
+
 #[test]
 fn test_json_prettify_output() {
     let code = r#"
@@ -8073,11 +8372,11 @@ fn test_result_or_else_preserves_ok() {
 #[test]
 fn test_result_or_else_can_return_error() {
     let code = r#"
-        fn retry(_e: string) -> Result<number, string> {
+        fn try_again(_e: string) -> Result<number, string> {
             return Err("retry failed");
         }
         let result = Err("initial");
-        let recovered = result_or_else(result, retry);
+        let recovered = result_or_else(result, try_again);
         is_err(recovered)
     "#;
     assert_eval_bool(code, true);
@@ -10696,6 +10995,740 @@ fn test_parity_hashset_filter() {
     assert_eq!(result, Value::Number(2.0));
 }
 
+// ============================================================================
+// Interpreter / VM parity helpers for array utility builtins
+// ============================================================================
+
+fn run_interpreter(source: &str) -> Result<Value, RuntimeError> {
+    let mut lexer = Lexer::new(source);
+    let (tokens, _) = lexer.tokenize();
+    let mut parser = Parser::new(tokens);
+    let (program, _) = parser.parse();
+    let mut interpreter = atlas_runtime::interpreter::Interpreter::new();
+    interpreter.eval(&program, &SecurityContext::allow_all())
+}
+
+fn run_vm(source: &str) -> Result<Value, RuntimeError> {
+    let mut lexer = Lexer::new(source);
+    let (tokens, _) = lexer.tokenize();
+    let mut parser = Parser::new(tokens);
+    let (program, _) = parser.parse();
+    let bytecode = atlas_runtime::compiler::Compiler::new()
+        .compile(&program)
+        .expect("compile failed");
+    let mut vm = atlas_runtime::vm::VM::new(bytecode);
+    vm.run(&SecurityContext::allow_all())
+        .map(|v| v.unwrap_or(Value::Null))
+}
+
+/// Evaluate in both engines, assert identical results, and return the value.
+fn eval_both(source: &str) -> Value {
+    let interp = run_interpreter(source).expect("interpreter failed");
+    let vm = run_vm(source).expect("VM failed");
+    assert_eq!(interp, vm, "Parity failure for: {}", source);
+    interp
+}
+
+/// Evaluate in both engines and assert both fail with the same message.
+fn eval_both_err(source: &str) -> String {
+    let interp = run_interpreter(source).expect_err("interpreter succeeded");
+    let vm = run_vm(source).expect_err("VM succeeded");
+    assert_eq!(
+        interp.to_string(),
+        vm.to_string(),
+        "Parity failure for: {}",
+        source
+    );
+    interp.to_string()
+}
+
+fn num_matrix(value: &Value) -> Vec<Vec<f64>> {
+    match value {
+        Value::Array(outer) => outer
+            .as_slice()
+            .iter()
+            .map(|inner| match inner {
+                Value::Array(arr) => arr
+                    .as_slice()
+                    .iter()
+                    .map(|v| match v {
+                        Value::Number(n) => *n,
+                        other => panic!("Expected number, got {:?}", other),
+                    })
+                    .collect(),
+                other => panic!("Expected inner array, got {:?}", other),
+            })
+            .collect(),
+        other => panic!("Expected array, got {:?}", other),
+    }
+}
+
+// ============================================================================
+// chunk() tests
+// ============================================================================
+
+#[test]
+fn test_chunk_even_split() {
+    let result = eval_both("chunk([1, 2, 3, 4], 2);");
+    assert_eq!(num_matrix(&result), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+}
+
+#[test]
+fn test_chunk_remainder_in_last_chunk() {
+    let result = eval_both("chunk([1, 2, 3, 4, 5], 2);");
+    assert_eq!(
+        num_matrix(&result),
+        vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0]]
+    );
+}
+
+#[test]
+fn test_chunk_size_larger_than_array() {
+    let result = eval_both("chunk([1, 2, 3], 10);");
+    assert_eq!(num_matrix(&result), vec![vec![1.0, 2.0, 3.0]]);
+}
+
+#[test]
+fn test_chunk_empty_input() {
+    let result = eval_both("let empty: number[] = []; chunk(empty, 3);");
+    assert!(num_matrix(&result).is_empty());
+}
+
+#[test]
+fn test_chunk_strings() {
+    let result = eval_both(r#"join(chunk(["a", "b", "c"], 2)[1], ",");"#);
+    assert_eq!(result, Value::string("c"));
+}
+
+#[rstest]
+#[case::zero("chunk([1, 2, 3], 0);")]
+#[case::negative("chunk([1, 2, 3], -2);")]
+#[case::fractional("chunk([1, 2, 3], 1.5);")]
+fn test_chunk_invalid_size(#[case] code: &str) {
+    let msg = eval_both_err(code);
+    assert!(
+        msg.contains("chunk(): size must be a positive integer"),
+        "unexpected error: {}",
+        msg
+    );
+}
+
+#[test]
+fn test_chunk_infers_nested_array_type() {
+    let diags = get_all_diagnostics("let rows: number[][] = chunk([1, 2, 3], 2);");
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics("let rows: number[] = chunk([1, 2, 3], 2);");
+    assert!(
+        diags.iter().any(|d| d.code == "AT3001"),
+        "expected type mismatch, got: {:?}",
+        diags
+    );
+}
+
+// ============================================================================
+// unique() / uniqueBy() tests
+// ============================================================================
+
+#[rstest]
+#[case::numbers("unique([3, 1, 3, 2, 1]);", "[3, 1, 2]")]
+#[case::strings(r#"unique(["b", "a", "b", "c"]);"#, "[b, a, c]")]
+#[case::bools("unique([true, true, false, true]);", "[true, false]")]
+#[case::empty("let empty: number[] = []; unique(empty);", "[]")]
+#[case::by_modulo(
+    "fn parity(x: number) -> number { return x % 2; } uniqueBy([4, 7, 2, 9, 1], parity);",
+    "[4, 7]"
+)]
+#[case::by_string_key(
+    r#"fn first(s: string) -> string { return charAt(s, 0); }
+       uniqueBy(["apple", "avocado", "banana", "blueberry", "cherry"], first);"#,
+    "[apple, banana, cherry]"
+)]
+#[case::by_bool_key(
+    "fn big(x: number) -> bool { return x > 10; } uniqueBy([1, 20, 3, 30], big);",
+    "[1, 20]"
+)]
+fn test_unique_parity(#[case] code: &str, #[case] expected: &str) {
+    let result = eval_both(code);
+    assert_eq!(result.to_string(), expected);
+}
+
+#[test]
+fn test_unique_by_non_primitive_key_is_runtime_error() {
+    let msg =
+        eval_both_err("fn wrap(x: number) -> number[] { return [x]; } uniqueBy([1, 2], wrap);");
+    assert!(msg.contains("uniqueBy() key function must return number, string, or bool"));
+}
+
+#[test]
+fn test_unique_signatures_are_generic() {
+    let diags = get_all_diagnostics(
+        r#"
+        fn len2(s: string) -> number { return len(s); }
+        let a: number[] = unique([1, 2, 2]);
+        let b: string[] = uniqueBy(["a", "bb", "cc"], len2);
+        "#,
+    );
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics("let a: string[] = unique([1, 2, 2]);");
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+
+    let diags = get_all_diagnostics(
+        "fn wrap(x: number) -> number[] { return [x]; } uniqueBy([1, 2], wrap);",
+    );
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// groupBy() tests
+// ============================================================================
+
+const LOG_LEVELS_SOURCE: &str = r#"
+    fn level(line: string) -> string { return split(line, ":")[0]; }
+    let logs: string[] = ["ERROR: disk full", "INFO: started", "ERROR: timeout", "WARN: slow", "INFO: ready"];
+    let buckets: json = groupBy(logs, level);
+"#;
+
+#[rstest]
+#[case::error_bucket(
+    r#"toJSON(buckets["ERROR"])"#,
+    r#"["ERROR: disk full","ERROR: timeout"]"#
+)]
+#[case::info_bucket(r#"toJSON(buckets["INFO"])"#, r#"["INFO: started","INFO: ready"]"#)]
+#[case::warn_bucket(r#"toJSON(buckets["WARN"])"#, r#"["WARN: slow"]"#)]
+#[case::input_order(r#"jsonAsString(buckets["ERROR"][1])"#, "ERROR: timeout")]
+#[case::missing_key(r#"jsonIsNull(buckets["DEBUG"])"#, "true")]
+fn test_group_by_log_levels(#[case] query: &str, #[case] expected: &str) {
+    let result = eval_both(&format!("{}{};", LOG_LEVELS_SOURCE, query));
+    assert_eq!(result.to_string(), expected);
+}
+
+#[test]
+fn test_group_by_empty_input() {
+    let result = eval_both(
+        r#"fn id(s: string) -> string { return s; } let empty: string[] = []; toJSON(groupBy(empty, id));"#,
+    );
+    assert_eq!(result, Value::string("{}"));
+}
+
+#[test]
+fn test_group_by_numbers() {
+    let result = eval_both(
+        r#"
+        fn bucket(n: number) -> string { if (n < 10) { return "small"; } return "large"; }
+        toJSON(groupBy([3, 42, 7, 100], bucket)["large"]);
+        "#,
+    );
+    assert_eq!(result, Value::string("[42,100]"));
+}
+
+#[test]
+fn test_group_by_non_string_key_is_runtime_error() {
+    let msg =
+        eval_both_err("fn half(n: number) -> number { return n / 2; } groupBy([1, 2], half);");
+    assert!(msg.contains("groupBy() key function must return string"));
+}
+
+#[test]
+fn test_group_by_typechecks_as_json() {
+    let diags = get_all_diagnostics(&format!(
+        "{}{}",
+        LOG_LEVELS_SOURCE, "let n: number = len(buckets);"
+    ));
+    assert!(
+        diags.iter().all(|d| d.code != "AT3001"),
+        "unexpected diagnostics: {:?}",
+        diags
+    );
+
+    let diags = get_all_diagnostics(
+        "fn half(n: number) -> number { return n / 2; } let g: json = groupBy([1, 2], half);",
+    );
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// Sort stability and sortNumeric() tests
+// ============================================================================
+
+const SCORED_RECORDS_SOURCE: &str = r#"
+    fn score(record: string) -> number { return parseFloat(split(record, ":")[1]); }
+    fn byScore(a: string, b: string) -> number { return score(a) - score(b); }
+    let records: string[] = ["carol:2", "alice:1", "bob:2", "dave:1", "erin:0"];
+"#;
+
+#[rstest]
+#[case::sort_by("sortBy(records, score);")]
+#[case::sort_with_comparator("sort(records, byScore);")]
+fn test_sort_is_stable_for_equal_keys(#[case] query: &str) {
+    let result = eval_both(&format!("{}{}", SCORED_RECORDS_SOURCE, query));
+    assert_eq!(
+        result.to_string(),
+        "[erin:0, alice:1, dave:1, carol:2, bob:2]"
+    );
+}
+
+#[rstest]
+#[case::unsorted("sortNumeric([3, 1, 2]);", "[1, 2, 3]")]
+#[case::negatives_and_fractions("sortNumeric([0.5, -2, 10, -2.5]);", "[-2.5, -2, 0.5, 10]")]
+#[case::empty("let empty: number[] = []; sortNumeric(empty);", "[]")]
+fn test_sort_numeric_parity(#[case] code: &str, #[case] expected: &str) {
+    let result = eval_both(code);
+    assert_eq!(result.to_string(), expected);
+}
+
+#[test]
+fn test_sort_numeric_requires_number_array() {
+    assert_error_code(r#"sortNumeric(["b", "a"])"#, "AT3001");
+}
+
+// ============================================================================
+// sum() tests
+// ============================================================================
+
+#[test]
+fn test_sum_of_empty_array_is_positive_zero_parity() {
+    match eval_both("sum([]);") {
+        Value::Number(n) => assert!(n == 0.0 && n.is_sign_positive(), "got: {:?}", n),
+        other => panic!("expected number, got {:?}", other),
+    }
+    assert_eq!(eval_both("toString(sum([]));"), Value::string("0"));
+}
+
+// ============================================================================
+// range() tests
+// ============================================================================
+
+#[rstest]
+#[case::ascending("range(0, 5);", "[0, 1, 2, 3, 4]")]
+#[case::ascending_step("range(1, 10, 3);", "[1, 4, 7]")]
+#[case::descending("range(5, 0, -1);", "[5, 4, 3, 2, 1]")]
+#[case::descending_step("range(10, 1, -4);", "[10, 6, 2]")]
+#[case::wrong_direction("range(5, 0);", "[]")]
+#[case::drives_map(
+    "fn square(n: number) -> number { return n * n; } map(range(1, 4), square);",
+    "[1, 4, 9]"
+)]
+fn test_range_parity(#[case] code: &str, #[case] expected: &str) {
+    let result = eval_both(code);
+    assert_eq!(result.to_string(), expected);
+}
+
+#[test]
+fn test_range_zero_step_is_runtime_error() {
+    let msg = eval_both_err("range(0, 10, 0);");
+    assert!(
+        msg.contains("range(): step must not be zero"),
+        "got: {}",
+        msg
+    );
+}
+
+#[test]
+fn test_range_too_long_is_runtime_error() {
+    let msg = eval_both_err("range(0, 1000000000000000);");
+    assert!(msg.contains("range(): too many elements"), "got: {}", msg);
+}
+
+#[test]
+fn test_range_respects_allocation_limit() {
+    let security = SecurityContext::with_limits(Some(1024), None, None);
+    let runtime = Atlas::new_with_security(security);
+    let diagnostics = runtime.eval("range(0, 1000);").unwrap_err();
+    assert_eq!(diagnostics[0].code, "AT0304");
+}
+
+#[test]
+fn test_range_typechecks_as_number_array() {
+    for code in [
+        "let xs: number[] = range(0, 3);",
+        "let xs: number[] = range(3, 0, -1);",
+    ] {
+        let diags = get_all_diagnostics(code);
+        assert!(
+            diags.is_empty(),
+            "unexpected diagnostics for {}: {:?}",
+            code,
+            diags
+        );
+    }
+
+    let diags = get_all_diagnostics("let xs: string[] = range(0, 3);");
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+
+    let diags = get_all_diagnostics(r#"let xs = range(0, "3");"#);
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+
+    let diags = get_all_diagnostics("let xs = range(0);");
+    assert!(diags.iter().any(|d| d.code == "AT3005"), "got: {:?}", diags);
+}
+
+#[test]
+fn test_range_name_can_be_redefined() {
+    let result = eval_both("let range: number = 4; range * 2;");
+    assert_eq!(result, Value::Number(8.0));
+}
+
+// ============================================================================
+// partition() tests
+// ============================================================================
+
+#[test]
+fn test_partition_by_evenness() {
+    let result = eval_both(
+        "fn even(n: number) -> bool { return n % 2 == 0; } partition([1, 2, 3, 4], even);",
+    );
+    assert_eq!(num_matrix(&result), vec![vec![2.0, 4.0], vec![1.0, 3.0]]);
+}
+
+#[rstest]
+#[case::strings(
+    r#"fn short(s: string) -> bool { return len(s) < 4; }
+       partition(["pear", "fig", "banana", "kiwi", "yam"], short);"#,
+    "[[fig, yam], [pear, banana, kiwi]]"
+)]
+#[case::all_match(
+    "fn yes(n: number) -> bool { return true; } partition([1, 2], yes);",
+    "[[1, 2], []]"
+)]
+#[case::empty(
+    "fn yes(n: number) -> bool { return true; } let empty: number[] = []; partition(empty, yes);",
+    "[[], []]"
+)]
+fn test_partition_parity(#[case] code: &str, #[case] expected: &str) {
+    let result = eval_both(code);
+    assert_eq!(result.to_string(), expected);
+}
+
+#[test]
+fn test_partition_non_bool_predicate_is_runtime_error() {
+    let msg =
+        eval_both_err("fn half(n: number) -> number { return n / 2; } partition([1, 2], half);");
+    assert!(msg.contains("partition() predicate must return bool"));
+}
+
+#[test]
+fn test_partition_typechecks_as_nested_array() {
+    let diags = get_all_diagnostics(
+        "fn even(n: number) -> bool { return n % 2 == 0; } let parts: number[][] = partition([1, 2], even);",
+    );
+    assert!(
+        diags.iter().all(|d| d.code != "AT3001"),
+        "unexpected diagnostics: {:?}",
+        diags
+    );
+
+    let diags = get_all_diagnostics(
+        "fn even(n: number) -> bool { return n % 2 == 0; } let parts: string[][] = partition([1, 2], even);",
+    );
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// Base64 / hex encoding tests
+// ============================================================================
+
+#[rstest]
+#[case::empty("")]
+#[case::ascii("hello, world")]
+#[case::unicode("héllo ✓ 🌍")]
+#[case::padding("ab")]
+fn test_encoding_round_trips(#[case] input: &str) {
+    let base64 = eval_both(&format!("decodeBase64(encodeBase64({:?}));", input));
+    assert_eq!(base64, Value::string(input));
+    let hex = eval_both(&format!("decodeHex(encodeHex({:?}));", input));
+    assert_eq!(hex, Value::string(input));
+}
+
+#[test]
+fn test_encode_base64_and_hex_output() {
+    assert_eq!(
+        eval_both(r#"encodeBase64("Atlas");"#),
+        Value::string("QXRsYXM=")
+    );
+    assert_eq!(
+        eval_both(r#"encodeHex("Atlas");"#),
+        Value::string("41746c6173")
+    );
+    assert_eq!(
+        eval_both(r#"decodeHex("41746C6173");"#),
+        Value::string("Atlas")
+    );
+}
+
+#[rstest]
+#[case::bad_base64_char(r#"decodeBase64("not base64!");"#, "decodeBase64(): invalid base64")]
+#[case::bad_base64_padding(r#"decodeBase64("QXRsYXM");"#, "decodeBase64(): invalid base64")]
+#[case::base64_not_utf8(
+    r#"decodeBase64("/w==");"#,
+    "decodeBase64(): decoded bytes are not valid UTF-8"
+)]
+#[case::hex_odd_length(r#"decodeHex("abc");"#, "decodeHex(): invalid hex: odd length 3")]
+#[case::hex_bad_digit(r#"decodeHex("4g");"#, "decodeHex(): invalid hex digit at offset 0")]
+#[case::hex_not_utf8(
+    r#"decodeHex("c328");"#,
+    "decodeHex(): decoded bytes are not valid UTF-8"
+)]
+fn test_decode_invalid_input_is_runtime_error(#[case] code: &str, #[case] expected: &str) {
+    let msg = eval_both_err(code);
+    assert!(msg.contains(expected), "unexpected error: {}", msg);
+}
+
+#[test]
+fn test_encoding_typechecks_as_string_to_string() {
+    let diags = get_all_diagnostics(r#"let s: string = decodeHex(encodeBase64("x"));"#);
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics(r#"let n: number = encodeHex("x");"#);
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+
+    let diags = get_all_diagnostics("encodeBase64(42);");
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// Hash function tests
+// ============================================================================
+
+#[rstest]
+#[case::sha256_empty(
+    "sha256",
+    "",
+    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+)]
+#[case::sha256_abc(
+    "sha256",
+    "abc",
+    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+)]
+#[case::md5_empty("md5", "", "d41d8cd98f00b204e9800998ecf8427e")]
+#[case::md5_abc("md5", "abc", "900150983cd24fb0d6963f7d28e17f72")]
+#[case::crc32_empty("crc32", "", "00000000")]
+#[case::crc32_abc("crc32", "abc", "352441c2")]
+fn test_hash_reference_vectors(#[case] func: &str, #[case] input: &str, #[case] expected: &str) {
+    let result = eval_both(&format!("{}({:?});", func, input));
+    assert_eq!(result, Value::string(expected));
+}
+
+#[test]
+fn test_hash_uses_utf8_bytes() {
+    // "é" is the two bytes c3 a9, so its digest matches the hex-decoded input
+    let result = eval_both(r#"sha256("é") == sha256(decodeHex("c3a9"));"#);
+    assert_eq!(result, Value::Bool(true));
+    assert_eq!(
+        eval_both(r#"md5("é");"#),
+        Value::string("66ddcd97cfdeabb2f6fb8a999b4bc76f")
+    );
+}
+
+#[test]
+fn test_hash_typechecks_as_string_to_string() {
+    let diags = get_all_diagnostics(r#"let d: string = sha256(md5(crc32("x")));"#);
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics("sha256(1);");
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// formatNumber() / formatWithSeparators() tests
+// ============================================================================
+
+#[rstest]
+#[case::two_decimals("formatNumber(3.14159, 2);", "3.14")]
+#[case::half_up("formatNumber(2.5, 0);", "3")]
+#[case::half_up_decimal("formatNumber(1.005, 2);", "1.01")]
+#[case::pads_zeros("formatNumber(7, 3);", "7.000")]
+#[case::zero_decimals("formatNumber(1234.4, 0);", "1234")]
+#[case::negative("formatNumber(-3.14159, 3);", "-3.142")]
+#[case::negative_half("formatNumber(-2.5, 0);", "-3")]
+#[case::negative_rounds_to_zero("formatNumber(-0.004, 2);", "0.00")]
+#[case::grouping("formatWithSeparators(1234567, \",\");", "1,234,567")]
+#[case::grouping_negative("formatWithSeparators(-1234567.25, \" \");", "-1 234 567.25")]
+#[case::grouping_small("formatWithSeparators(999, \",\");", "999")]
+fn test_number_formatting(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(eval_both(code), Value::string(expected));
+}
+
+#[test]
+fn test_format_number_composes_with_grouping_via_string_ops() {
+    let code = r#"
+        let total: number = 1234567.891;
+        let whole: string = formatWithSeparators(floor(total), ",");
+        let cents: string = substring(formatNumber(total, 2), 8, 10);
+        whole + "." + cents;
+    "#;
+    assert_eq!(eval_both(code), Value::string("1,234,567.89"));
+}
+
+#[rstest]
+#[case::negative_decimals("formatNumber(1, -1);", "decimals must be an integer")]
+#[case::fractional_decimals("formatNumber(1, 1.5);", "decimals must be an integer")]
+#[case::nan("formatNumber(sqrt(-1), 2);", "value must be finite")]
+fn test_format_number_invalid_arguments(#[case] code: &str, #[case] expected: &str) {
+    let msg = eval_both_err(code);
+    assert!(msg.contains(expected), "unexpected error: {}", msg);
+}
+
+#[test]
+fn test_format_number_typechecks() {
+    let diags = get_all_diagnostics(
+        r#"let a: string = formatNumber(1.5, 1); let b: string = formatWithSeparators(1000, ",");"#,
+    );
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics(r#"formatNumber(1.5, "2");"#);
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// entries() / fromEntries() tests
+// ============================================================================
+
+#[test]
+fn test_entries_pairs_sorted_by_key() {
+    let result = eval_both(r#"toJSON(entries(parseJSON("{\"b\": 2, \"a\": [true]}")));"#);
+    assert_eq!(result, Value::string(r#"[["a",[true]],["b",2]]"#));
+}
+
+#[test]
+fn test_from_entries_round_trips_object() {
+    let code = r#"
+        let obj: json = parseJSON("{\"id\": 7, \"name\": \"atlas\", \"tags\": [\"x\"], \"meta\": null}");
+        fromEntries(entries(obj)) == obj;
+    "#;
+    assert_eq!(eval_both(code), Value::Bool(true));
+}
+
+#[test]
+fn test_from_entries_later_pairs_win() {
+    let code = r#"
+        let pairs: json[] = [parseJSON("[\"k\", 1]"), parseJSON("[\"k\", 2]")];
+        jsonAsNumber(fromEntries(pairs)["k"]);
+    "#;
+    assert_eq!(eval_both(code), Value::Number(2.0));
+}
+
+#[test]
+fn test_entries_empty_object() {
+    let result = eval_both(r#"len(entries(parseJSON("{}")));"#);
+    assert_eq!(result, Value::Number(0.0));
+}
+
+#[rstest]
+#[case::entries_of_array(
+    r#"entries(parseJSON("[1, 2]"));"#,
+    "entries() requires json object, got json array"
+)]
+#[case::entries_of_number(
+    r#"entries(parseJSON("3"));"#,
+    "entries() requires json object, got json number"
+)]
+#[case::bad_pair_key(
+    r#"let pairs: json[] = [parseJSON("[1, 2]")]; fromEntries(pairs);"#,
+    "fromEntries(): entry 0 must be a [string, value] pair"
+)]
+#[case::bad_pair_shape(
+    r#"let pairs: json[] = [parseJSON("[\"a\"]")]; fromEntries(pairs);"#,
+    "fromEntries(): entry 0 must be a [string, value] pair"
+)]
+fn test_entries_invalid_input(#[case] code: &str, #[case] expected: &str) {
+    let msg = eval_both_err(code);
+    assert!(msg.contains(expected), "unexpected error: {}", msg);
+}
+
+#[test]
+fn test_entries_typecheck() {
+    let diags = get_all_diagnostics(
+        r#"let obj: json = parseJSON("{}"); let pairs: json[] = entries(obj); let back: json = fromEntries(pairs);"#,
+    );
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics(r#"entries("not json");"#);
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
+// ============================================================================
+// retry() tests
+// ============================================================================
+
+const FLAKY_ACTION: &str = r#"
+    var calls = 0;
+    fn flaky() -> Result<number, string> {
+        calls = calls + 1;
+        if (calls < 3) {
+            return Err("attempt " + str(calls) + " failed");
+        }
+        return Ok(calls * 10);
+    }
+"#;
+
+#[test]
+fn test_retry_succeeds_on_third_try() {
+    let code = format!("{FLAKY_ACTION} let r = retry(flaky, 5); unwrap(r) + calls;");
+    // Ok(30) after exactly three calls; no call after the first Ok
+    assert_eq!(eval_both(&code), Value::Number(33.0));
+}
+
+#[test]
+fn test_retry_returns_last_err_when_attempts_run_out() {
+    let code = format!("{FLAKY_ACTION} retry(flaky, 2);");
+    assert_eq!(
+        eval_both(&code),
+        Value::Result(Err(Box::new(Value::string("attempt 2 failed"))))
+    );
+}
+
+#[test]
+fn test_retry_all_failures() {
+    let code = r#"
+        var calls = 0;
+        fn broken() -> Result<number, string> {
+            calls = calls + 1;
+            return Err("failure " + str(calls));
+        }
+        let r = retry(broken, 4);
+        [is_err(r), calls];
+    "#;
+    assert_eq!(
+        eval_both(code),
+        Value::array(vec![Value::Bool(true), Value::Number(4.0)])
+    );
+}
+
+#[rstest]
+#[case::zero_attempts(0.0, "retry(): attempts must be a positive integer, got 0")]
+#[case::fractional_attempts(1.5, "retry(): attempts must be a positive integer, got 1.5")]
+fn test_retry_invalid_attempts(#[case] attempts: f64, #[case] expected: &str) {
+    let msg = eval_both_err(&format!("{FLAKY_ACTION} retry(flaky, {attempts});"));
+    assert!(msg.contains(expected), "unexpected error: {}", msg);
+}
+
+#[test]
+fn test_retry_requires_function_returning_result() {
+    let msg = eval_both_err("retry(3, 1);");
+    assert!(
+        msg.contains("retry() first argument must be function"),
+        "{}",
+        msg
+    );
+
+    let msg = eval_both_err("fn plain() -> number { return 1; } retry(plain, 2);");
+    assert!(msg.contains("retry() action must return Result"), "{}", msg);
+}
+
+#[test]
+fn test_retry_typecheck() {
+    let diags = get_all_diagnostics(&format!(
+        "{FLAKY_ACTION} let r: Result<number, string> = retry(flaky, 3);"
+    ));
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+
+    let diags = get_all_diagnostics(&format!("{FLAKY_ACTION} retry(flaky, \"3\");"));
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "got: {:?}", diags);
+}
+
 // ============================================================================
 // VM stdlib tests (co-located to eliminate duplicate binary pairs)
 // Tests run with separate binary name prefix via submodule
@@ -11970,6 +13003,21 @@ mod vm_stdlib {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn vm_test_file_bytes_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("roundtrip.bin");
+        let path = path_for_atlas(&test_file);
+
+        let code = format!(
+            r#"writeFileBytes("{}", [255, 254, 253]); let x = readFileBytes("{}"); x;"#,
+            path, path
+        );
+        let result = execute_with_io(&code, &temp_dir).unwrap();
+
+        assert_eq!(result.to_string(), "[255, 254, 253]");
+    }
+
     #[test]
     fn vm_test_read_file_multiline() {
         let temp_dir = TempDir::new().unwrap();
@@ -13587,11 +14635,11 @@ mod vm_stdlib {
     #[test]
     fn test_result_or_else_can_return_error() {
         let code = r#"
-        fn retry(_e: string) -> Result<number, string> {
+        fn try_again(_e: string) -> Result<number, string> {
             return Err("retry failed");
         }
         let result = Err("initial");
-        let recovered = result_or_else(result, retry);
+        let recovered = result_or_else(result, try_again);
         is_err(recovered)
     "#;
         assert_eval_bool(code, true);
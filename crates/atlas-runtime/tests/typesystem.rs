@@ -2711,6 +2711,92 @@ fn test_null_unary_errors(#[case] source: &str) {
     assert_has_error(&diagnostics, "AT3002");
 }
 
+// ========== Int Type ==========
+
+#[rstest]
+#[case::arithmetic("let x: int = 1i + 2i * 3i - 4i / 2i;")]
+#[case::modulo("let x: int = 7i % 2i;")]
+#[case::negate("let x: int = -5i;")]
+#[case::comparison("let b: bool = 1i < 2i;")]
+#[case::to_int("let x: int = toInt(3);")]
+#[case::to_float("let x: number = toFloat(3i);")]
+#[case::annotated_param("fn double(n: int) -> int { return n * 2i; } let x: int = double(21i);")]
+fn test_int_valid(#[case] source: &str) {
+    assert_no_errors(&typecheck_source(source));
+}
+
+#[rstest]
+#[case::int_plus_number("let x = 1i + 1.5;")]
+#[case::number_times_int("let x = 2 * 3i;")]
+#[case::int_less_number("let b = 1i < 2;")]
+fn test_int_number_mix_errors(#[case] source: &str) {
+    let diagnostics = typecheck_source(source);
+    assert_has_error(&diagnostics, "AT3002");
+    assert!(
+        diagnostics[0].help.iter().any(|h| h.contains("toFloat")),
+        "expected a conversion hint, got {:?}",
+        diagnostics[0].help
+    );
+}
+
+#[rstest]
+#[case::int_to_number("let x: number = 1i;")]
+#[case::number_to_int("let x: int = 1;")]
+fn test_int_number_not_assignable(#[case] source: &str) {
+    assert_has_error(&typecheck_source(source), "AT3001");
+}
+
+// ========== Try/Catch ==========
+
+#[test]
+fn test_try_catch_valid() {
+    let diagnostics = typecheck_source(
+        r#"fn f(xs: number[]) -> string {
+            try {
+                return toString(xs[3]);
+            } catch (e) {
+                let code: string = e["code"];
+                return code;
+            }
+        }"#,
+    );
+    assert_no_errors(&diagnostics);
+}
+
+#[test]
+fn test_catch_binding_fields_are_strings() {
+    let diagnostics =
+        typecheck_source(r#"try { let x = 1; } catch (e) { let n: number = e["message"]; }"#);
+    assert_has_error(&diagnostics, "AT3001");
+}
+
+#[test]
+fn test_catch_binding_field_access() {
+    let diagnostics = typecheck_source(
+        r#"fn describe(err: { code: string, message: string }) -> string {
+            return err.code + ": " + err.message;
+        }
+        try { let x = 1; } catch (e) {
+            let code: string = e.code;
+            let text: string = describe(e);
+        }"#,
+    );
+    assert_no_errors(&diagnostics);
+
+    let diagnostics =
+        typecheck_source("try { let x = 1; } catch (e) { let n: number = e.message; }");
+    assert_has_error(&diagnostics, "AT3001");
+
+    let diagnostics = typecheck_source("try { let x = 1; } catch (e) { let s = e.stack; }");
+    assert_has_error(&diagnostics, "AT3010");
+}
+
+#[test]
+fn test_catch_binding_is_immutable() {
+    let diagnostics = typecheck_source("try { let x = 1; } catch (e) { e = e; }");
+    assert_has_error(&diagnostics, "AT3003");
+}
+
 // ========== Null in Arrays ==========
 
 #[rstest]
@@ -3710,6 +3796,267 @@ fn test_is_type_guard(#[case] source: &str) {
     assert!(diags.is_empty(), "Expected no errors, got: {:?}", diags);
 }
 
+// =============================================================================
+// Option/Result guards
+// =============================================================================
+
+#[rstest]
+#[case::is_some(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) { let n: number = unwrap(opt); return n + 1; }
+        return 0;
+    }
+    "#
+)]
+#[case::is_ok(
+    r#"
+    fn test(res: Result<string, number>) -> string {
+        if (is_ok(res)) { return unwrap(res); }
+        return "";
+    }
+    "#
+)]
+#[case::is_none_else(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_none(opt)) { return 0; } else { return unwrap(opt); }
+    }
+    "#
+)]
+#[case::negated(
+    r#"
+    fn test(res: Result<number, string>) -> number {
+        if (!is_err(res)) { return unwrap(res); }
+        return 0;
+    }
+    "#
+)]
+#[case::early_return(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_none(opt)) { return 0; }
+        return unwrap(opt);
+    }
+    "#
+)]
+#[case::and_operand(
+    r#"
+    fn test(opt: Option<number>) -> bool {
+        return is_some(opt) && unwrap(opt) > 3;
+    }
+    "#
+)]
+#[case::while_guard(
+    r#"
+    fn test(start: Option<number>) -> number {
+        var opt = start;
+        var total = 0;
+        while (is_some(opt)) { total = total + unwrap(opt); opt = None; }
+        return total;
+    }
+    "#
+)]
+#[case::expect_unchecked(
+    r#"
+    fn test(opt: Option<number>) -> number { return expect(opt, "missing") + 1; }
+    "#
+)]
+fn test_option_guard_allows_unwrap(#[case] source: &str) {
+    let diags = errors(source);
+    assert!(diags.is_empty(), "Expected no errors, got: {:?}", diags);
+}
+
+#[rstest]
+#[case::no_guard(
+    r#"
+    fn test(opt: Option<number>) -> number { return unwrap(opt); }
+    "#
+)]
+#[case::wrong_branch(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) { return 1; } else { return unwrap(opt); }
+    }
+    "#
+)]
+#[case::after_branch(
+    r#"
+    fn test(res: Result<number, string>) -> number {
+        if (is_ok(res)) { print("ok"); }
+        return unwrap(res);
+    }
+    "#
+)]
+#[case::or_operand(
+    r#"
+    fn test(opt: Option<number>) -> bool {
+        return is_some(opt) || unwrap(opt) > 3;
+    }
+    "#
+)]
+#[case::reassigned(
+    r#"
+    fn test(start: Option<number>) -> number {
+        var opt = start;
+        if (is_some(opt)) { opt = None; return unwrap(opt); }
+        return 0;
+    }
+    "#
+)]
+#[case::reassigned_in_loop(
+    r#"
+    fn test(start: Option<number>) -> number {
+        var opt = start;
+        var total = 0;
+        if (is_some(opt)) {
+            while (total < 3) { total = total + unwrap(opt); opt = None; }
+        }
+        return total;
+    }
+    "#
+)]
+fn test_option_unwrap_without_guard_is_rejected(#[case] source: &str) {
+    let diags = errors(source);
+    assert!(
+        diags.iter().any(|d| d.code == "AT3039"),
+        "Expected AT3039, got: {:?}",
+        diags
+    );
+}
+
+#[test]
+fn test_option_guard_unwrap_has_inner_type() {
+    let diags = errors(
+        r#"
+        fn test(opt: Option<number>) -> string {
+            if (is_some(opt)) { let s: string = unwrap(opt); return s; }
+            return "";
+        }
+        "#,
+    );
+    assert!(diags.iter().any(|d| d.code == "AT3001"), "{:?}", diags);
+}
+
+#[rstest]
+#[case::is_some_then(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) { let n: number = opt; return n + 1; }
+        return 0;
+    }
+    "#
+)]
+#[case::is_none_else(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_none(opt)) { return 0; } else { let n: number = opt; return n; }
+    }
+    "#
+)]
+#[case::is_ok_then(
+    r#"
+    fn test(res: Result<number, string>) -> number {
+        if (is_ok(res)) { let n: number = res; return n; }
+        return 0;
+    }
+    "#
+)]
+#[case::is_ok_else_is_error_type(
+    r#"
+    fn test(res: Result<number, string>) -> string {
+        if (is_ok(res)) { return ""; } else { let message: string = res; return message; }
+    }
+    "#
+)]
+#[case::is_err_then(
+    r#"
+    fn test(res: Result<number, string>) -> string {
+        if (is_err(res)) { return res; }
+        return "";
+    }
+    "#
+)]
+#[case::early_return(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_none(opt)) { return 0; }
+        return opt + 1;
+    }
+    "#
+)]
+#[case::and_operand(
+    r#"
+    fn test(opt: Option<number>) -> bool {
+        return is_some(opt) && opt > 3;
+    }
+    "#
+)]
+#[case::wrapper_contexts(
+    r#"
+    fn take(opt: Option<number>) -> number { return unwrap_or(opt, 0); }
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) {
+            let inner: number = match opt { Some(x) => x, None => 0 };
+            return opt + unwrap(opt) + take(opt) + inner;
+        }
+        return 0;
+    }
+    "#
+)]
+fn test_option_guard_narrows_binding(#[case] source: &str) {
+    let diags = errors(source);
+    assert!(diags.is_empty(), "Expected no errors, got: {:?}", diags);
+}
+
+#[rstest]
+#[case::inner_type(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) { let s: string = opt; }
+        return 0;
+    }
+    "#
+)]
+#[case::no_guard(
+    r#"
+    fn test(opt: Option<number>) -> number { let n: number = opt; return n; }
+    "#
+)]
+#[case::none_branch(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) { return 0; } else { let n: number = opt; return n; }
+    }
+    "#
+)]
+#[case::after_branch(
+    r#"
+    fn test(opt: Option<number>) -> number {
+        if (is_some(opt)) { print("some"); }
+        let n: number = opt;
+        return n;
+    }
+    "#
+)]
+#[case::reassigned(
+    r#"
+    fn test(start: Option<number>) -> number {
+        var opt = start;
+        if (is_some(opt)) { opt = None; let n: number = opt; return n; }
+        return 0;
+    }
+    "#
+)]
+fn test_option_guard_narrowing_is_scoped(#[case] source: &str) {
+    let diags = errors(source);
+    assert!(
+        diags.iter().any(|d| d.code == "AT3001"),
+        "Expected AT3001, got: {:?}",
+        diags
+    );
+}
+
 // =============================================================================
 // Runtime guard behavior
 // =============================================================================
@@ -4258,6 +4605,48 @@ fn test_unreachable_code_warning() {
     assert!(diags[0].message.contains("Unreachable"));
 }
 
+#[test]
+fn test_unreachable_code_after_break_and_continue() {
+    let source = r#"
+        var i = 0;
+        while (i < 3) {
+            i = i + 1;
+            if (i == 1) {
+                continue;
+                i = 10;
+            }
+            break;
+            i = 20;
+        }
+    "#;
+    let diags = warnings(source);
+    let dead: Vec<&str> = diags
+        .iter()
+        .filter(|d| d.code == "AT2002")
+        .map(|d| &source[d.column - 1..d.column - 1 + d.length])
+        .collect();
+    assert_eq!(dead, vec!["i = 10;", "i = 20;"]);
+}
+
+#[test]
+fn test_no_unreachable_warning_after_conditional_return() {
+    let diags = warnings(
+        r#"
+        fn foo(x: number) -> number {
+            if (x > 0) {
+                return 1;
+            }
+            return 2;
+        }
+    "#,
+    );
+    assert!(
+        diags.iter().all(|d| d.code != "AT2002"),
+        "unexpected warnings: {:?}",
+        diags
+    );
+}
+
 // ============================================================================
 // 16. Unused variable warnings
 // ============================================================================
@@ -4309,6 +4698,27 @@ fn test_continue_outside_loop_error() {
     assert_eq!(diags[0].code, "AT3010");
 }
 
+#[test]
+fn test_break_undefined_label_error() {
+    let diags = errors("outer: while (true) { break inner; }");
+    assert!(!diags.is_empty());
+    assert_eq!(diags[0].code, "AT3038");
+    assert!(diags[0].message.contains("'inner'"));
+}
+
+#[test]
+fn test_continue_enclosing_label_ok() {
+    let diags = errors("outer: for x in [1, 2] { for y in [3, 4] { continue outer; } }");
+    assert!(diags.is_empty(), "unexpected diagnostics: {:?}", diags);
+}
+
+#[test]
+fn test_label_not_visible_in_nested_function() {
+    let diags =
+        errors("outer: while (true) { fn f() -> void { while (true) { break outer; } } break; }");
+    assert!(diags.iter().any(|d| d.code == "AT3038"), "{:?}", diags);
+}
+
 // ============================================================================
 // 18. Return outside function
 // ============================================================================
@@ -6721,6 +7131,59 @@ fn test_multiple_bounds_one_missing_is_error() {
     );
 }
 
+// ── Extension methods ──────────────────────────────────────────────────────
+
+#[test]
+fn test_extension_method_return_type_flows_to_call_site() {
+    let diags = typecheck_source(
+        "
+        fn shout(self: string) -> string { return self + \"!\"; }
+        let ok: string = \"hi\".shout();
+        let bad: number = \"hi\".shout();
+    ",
+    );
+    let type_errors: Vec<_> = diags.iter().filter(|d| d.code == "AT3001").collect();
+    assert_eq!(type_errors.len(), 1, "{diags:?}");
+}
+
+#[test]
+fn test_extension_method_checks_receiver_and_args() {
+    let diags = typecheck_source(
+        "
+        fn total(self: number[], scale: number) -> number { return sum(self) * scale; }
+        let names: string[] = [\"a\"];
+        let a: number = names.total(2);
+        let b: number = [1, 2].total(\"x\");
+        let c: number = [1, 2].total();
+    ",
+    );
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.code == "AT3001" && d.message.contains("Receiver has wrong type")),
+        "{diags:?}"
+    );
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.code == "AT3001" && d.message.contains("Argument 1")),
+        "{diags:?}"
+    );
+    assert!(diags.iter().any(|d| d.code == "AT3005"), "{diags:?}");
+}
+
+#[test]
+fn test_extension_method_requires_self_first() {
+    // Only a leading `self` parameter makes a function callable with method syntax
+    let diags = typecheck_source(
+        "
+        fn shout(s: string) -> string { return s + \"!\"; }
+        let r: string = \"hi\".shout();
+    ",
+    );
+    assert!(diags.iter().any(|d| d.code == "AT3010"), "{diags:?}");
+}
+
 // ============================================================
 // Phase 11 — AT3xxx Error Code Coverage Tests
 // ============================================================
@@ -6805,3 +7268,117 @@ fn test_at3xxx_codes_in_expected_range() {
     // AT2013 is a warning, correctly in AT2xxx range
     assert!(error_codes::MOVE_TYPE_REQUIRES_OWNERSHIP_ANNOTATION.starts_with("AT2"));
 }
+
+// ============================================================================
+// Generic user-defined functions
+// ============================================================================
+
+#[test]
+fn test_generic_first_infers_element_type() {
+    let diagnostics = typecheck_source(
+        r#"
+        fn first<T>(xs: T[]) -> T {
+            return xs[0];
+        }
+        let n: number = first([1, 2, 3]);
+        let s: string = first(["a", "b"]);
+    "#,
+    );
+    assert_eq!(diagnostics.len(), 0, "Diagnostics: {:?}", diagnostics);
+}
+
+#[test]
+fn test_generic_first_return_type_flows_to_call_site() {
+    let diagnostics = typecheck_source(
+        r#"
+        fn first<T>(xs: T[]) -> T {
+            return xs[0];
+        }
+        let n: number = first(["a", "b"]);
+    "#,
+    );
+    assert!(
+        diagnostics.iter().any(|d| d.code == "AT3001"),
+        "Diagnostics: {:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn test_generic_identity_runs() {
+    let runtime = Atlas::new();
+    let result = runtime
+        .eval(
+            r#"
+        fn identity<T>(x: T) -> T {
+            return x;
+        }
+        identity(5) + identity(2);
+    "#,
+        )
+        .unwrap();
+    assert_eq!(result, Value::Number(7.0));
+}
+
+#[test]
+fn test_generic_inconsistent_constraints_name_type_parameter() {
+    let diagnostics = typecheck_source(
+        r#"
+        fn pair<T>(a: T, b: T) -> T[] {
+            return [a, b];
+        }
+        let _p = pair(1, "two");
+    "#,
+    );
+    let err = diagnostics
+        .iter()
+        .find(|d| d.code == "AT3001")
+        .expect("expected inference error");
+    assert!(
+        err.message
+            .contains("argument 2 has type string, but T was already inferred as number"),
+        "unexpected message: {}",
+        err.message
+    );
+}
+
+#[test]
+fn test_generic_inconsistent_constraints_through_array() {
+    let diagnostics = typecheck_source(
+        r#"
+        fn append<T>(xs: T[], x: T) -> T[] {
+            return xs;
+        }
+        let _xs = append([1, 2], "three");
+    "#,
+    );
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.message.contains("T was already inferred as number")),
+        "Diagnostics: {:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn test_regex_pattern_helpers_are_typed() {
+    assert_no_errors(&typecheck_source(
+        r#"
+        let _m: bool = regexMatch("order-42", "\\d+");
+        let _c: string[] = regexCapture("2024-06", "(\\d+)-(\\d+)");
+        let _r: string = regexReplace("a1", "\\d", "_");
+        let _s: string[] = regexSplit("a,b", ",");
+        let _t: string = regexReplace(unwrap(regexNew("\\d")), "a1", "_");
+    "#,
+    ));
+
+    assert_has_error(
+        &typecheck_source(r#"let _x: number = regexMatch("a", "a");"#),
+        "AT3001",
+    );
+    assert_has_error(
+        &typecheck_source(r#"let _x: string = regexCapture("a", 1);"#),
+        "AT3001",
+    );
+}
@@ -3,7 +3,7 @@
 mod common;
 
 use atlas_runtime::binder::Binder;
-use atlas_runtime::bytecode::Bytecode;
+use atlas_runtime::bytecode::{Bytecode, Opcode};
 use atlas_runtime::compiler::Compiler;
 use atlas_runtime::debugger::{DebugRequest, DebugResponse, DebuggerSession, SourceLocation};
 use atlas_runtime::interpreter::Interpreter;
@@ -14,8 +14,8 @@ use atlas_runtime::security::SecurityContext;
 use atlas_runtime::typechecker::generics::Monomorphizer;
 use atlas_runtime::typechecker::TypeChecker;
 use atlas_runtime::types::{Type, TypeParamDef};
-use atlas_runtime::value::Value;
-use atlas_runtime::vm::{Profiler, VM};
+use atlas_runtime::value::{RuntimeError, Value};
+use atlas_runtime::vm::{GasSchedule, MeteredRun, Profiler, VmConfig, DEFAULT_MAX_CALL_DEPTH, VM};
 use atlas_runtime::Atlas;
 use common::{assert_error_code, assert_eval_null, assert_eval_number, assert_eval_string};
 use pretty_assertions::assert_eq;
@@ -4697,6 +4697,111 @@ fn test_for_in_in_function() {
     assert_eq!(result.unwrap(), Value::Number(60.0));
 }
 
+// ============================================================================
+// Labelled break/continue
+// ============================================================================
+
+#[test]
+fn test_labelled_break_exits_two_levels() {
+    let source = r#"
+        var hits = 0;
+        outer: for a in [1, 2, 3] {
+            for b in [1, 2, 3] {
+                if (a == 2 && b == 2) {
+                    break outer;
+                }
+                hits = hits + 1;
+            }
+        }
+        hits;
+    "#;
+    assert_parity(source);
+    assert_eq!(vm_number(source), 4.0);
+}
+
+#[test]
+fn test_labelled_break_from_three_levels() {
+    let source = r#"
+        var count = 0;
+        outer: for a in [1, 2] {
+            for b in [1, 2] {
+                for c in [1, 2] {
+                    if (c == 2) {
+                        break outer;
+                    }
+                    count = count + 1;
+                }
+            }
+        }
+        count;
+    "#;
+    assert_parity(source);
+    assert_eq!(vm_number(source), 1.0);
+}
+
+#[test]
+fn test_labelled_continue_skips_rest_of_inner_loop() {
+    let source = r#"
+        var hits = 0;
+        outer: for a in [1, 2, 3] {
+            for b in [1, 2, 3] {
+                if (b == 2) {
+                    continue outer;
+                }
+                hits = hits + a;
+            }
+        }
+        hits;
+    "#;
+    assert_parity(source);
+    assert_eq!(vm_number(source), 6.0);
+}
+
+#[test]
+fn test_labelled_while_in_function() {
+    let source = r#"
+        fn search() -> number {
+            var n = 0;
+            var i = 0;
+            var j = 0;
+            outer: while (i < 5) {
+                i = i + 1;
+                j = 0;
+                while (j < 5) {
+                    j = j + 1;
+                    if (j == 3) {
+                        continue outer;
+                    }
+                    if (i == 4) {
+                        break outer;
+                    }
+                    n = n + 1;
+                }
+            }
+            return n * 10 + i;
+        }
+        search();
+    "#;
+    assert_parity(source);
+    assert_eq!(vm_number(source), 64.0);
+}
+
+#[test]
+fn test_unlabelled_break_inside_labelled_loop_exits_inner_only() {
+    let source = r#"
+        var rows = 0;
+        outer: for a in [1, 2, 3] {
+            for b in [1, 2, 3] {
+                break;
+            }
+            rows = rows + 1;
+        }
+        rows;
+    "#;
+    assert_parity(source);
+    assert_eq!(vm_number(source), 3.0);
+}
+
 // ============================================================================
 // Correctness-04: Callback intrinsic parity tests
 // ============================================================================
@@ -4759,6 +4864,13 @@ fn test_parity_sort_by_invalid_callback() {
     assert_error_parity(r#"sortBy([1,2,3], "not a function");"#);
 }
 
+#[test]
+fn test_parity_intrinsic_result_as_call_argument() {
+    // The callback frame must not consume the outer callee slot (`len`/`toJSON`)
+    assert_parity("fn neg(n: number) -> number { return 0 - n; } len(sortBy([3, 1, 2], neg));");
+    assert_parity("fn neg(n: number) -> number { return 0 - n; } toJSON(map([1, 2], neg));");
+}
+
 #[test]
 fn test_parity_result_map_invalid_callback() {
     assert_error_parity(r#"result_map(Ok(1), "not a function");"#);
@@ -5424,6 +5536,35 @@ fn test_vm_impl_method_return_bool() {
     assert_eq!(result.unwrap(), "Bool(true)");
 }
 
+#[test]
+fn test_vm_extension_method_on_string() {
+    let result = run_vm(
+        r#"
+        fn shout(self: string) -> string { return toUpperCase(self) + "!"; }
+        let r: string = "hi".shout();
+        r
+    "#,
+    );
+    assert_eq!(result.unwrap(), r#"String("HI!")"#);
+}
+
+#[test]
+fn test_vm_extension_method_on_array_with_args() {
+    let result = run_vm(
+        "
+        fn sumAbove(self: number[], min: number) -> number {
+            var total: number = 0;
+            for x in self { if (x > min) { total = total + x; } }
+            return total;
+        }
+        let xs: number[] = [1, 5, 2, 8];
+        let r: number = xs.sumAbove(2);
+        r
+    ",
+    );
+    assert_eq!(result.unwrap(), "Number(13)");
+}
+
 #[test]
 fn test_vm_trait_compiles_without_bytecode() {
     // Trait declarations alone should compile cleanly with no runtime effect
@@ -5706,3 +5847,438 @@ fn test_parity_block03_scenario_j_vm() {
     );
     assert_eq!(result.unwrap(), "Number(14)");
 }
+
+// ============================================================================
+// Runtime error stack traces
+// ============================================================================
+
+const DEEP_OUT_OF_BOUNDS: &str = "fn inner(a: number[], i: number) -> number {
+    return a[i];
+}
+fn middle(a: number[]) -> number {
+    return inner(a, 5) + 1;
+}
+fn outer() -> number {
+    let arr = [1, 2, 3];
+    return middle(arr);
+}
+outer();
+";
+
+#[test]
+fn test_runtime_error_span_points_into_nested_function() {
+    let src = DEEP_OUT_OF_BOUNDS;
+    let mut vm = VM::new(compile(src));
+    let err = vm
+        .run(&SecurityContext::allow_all())
+        .expect_err("index 5 is out of bounds");
+
+    let span = err.span();
+    assert_eq!(&src[span.start..span.end], "a[i]");
+    let line = src[..span.start].matches('\n').count() + 1;
+    assert_eq!(src.lines().nth(line - 1), Some("    return a[i];"));
+}
+
+#[test]
+fn test_runtime_error_trace_has_line_and_column_per_frame() {
+    let mut vm = VM::new(compile(DEEP_OUT_OF_BOUNDS));
+    vm.set_source("deep.atlas", DEEP_OUT_OF_BOUNDS);
+    assert!(vm.run(&SecurityContext::allow_all()).is_err());
+
+    let trace: Vec<(String, Option<SourceLocation>)> = vm
+        .error_trace()
+        .iter()
+        .map(|frame| (frame.function_name.clone(), frame.location.clone()))
+        .collect();
+    assert_eq!(
+        trace,
+        vec![
+            (
+                "inner".to_string(),
+                Some(SourceLocation::new("deep.atlas", 2, 12))
+            ),
+            (
+                "middle".to_string(),
+                Some(SourceLocation::new("deep.atlas", 5, 12))
+            ),
+            (
+                "outer".to_string(),
+                Some(SourceLocation::new("deep.atlas", 9, 12))
+            ),
+            (
+                "<main>".to_string(),
+                Some(SourceLocation::new("deep.atlas", 11, 1))
+            ),
+        ]
+    );
+    assert_eq!(
+        vm.error_trace()[0].to_string(),
+        "at inner (deep.atlas:2:12)"
+    );
+}
+
+#[test]
+fn test_error_trace_empty_after_successful_run() {
+    let mut vm = VM::new(compile("fn f() -> number { return 1; } f();"));
+    assert!(vm.run(&SecurityContext::allow_all()).is_ok());
+    assert!(vm.error_trace().is_empty());
+}
+
+// ============================================================================
+// Int type: overflow-checked integer arithmetic
+// ============================================================================
+
+#[rstest]
+#[case("1i + 2i;")]
+#[case("10i - 3i;")]
+#[case("6i * 7i;")]
+#[case("-7i / 2i;")]
+#[case("17i % 5i;")]
+#[case("-5i;")]
+#[case("3i < 5i;")]
+#[case("5i <= 5i;")]
+#[case("3i > 5i;")]
+#[case("5i >= 6i;")]
+#[case("4i == 4i;")]
+#[case("4i == 4;")]
+#[case("toInt(\"9223372036854775807\");")]
+#[case("toFloat(3i);")]
+fn test_parity_int_arithmetic(#[case] code: &str) {
+    assert_parity(code);
+}
+
+#[test]
+fn test_int_division_truncates() {
+    assert_eq!(vm_eval("-7i / 2i;"), Some(Value::Int(-3)));
+}
+
+#[test]
+fn test_int_large_arithmetic_is_precise() {
+    // 2^53 + 1 is not representable as an f64; ints keep every digit.
+    assert_eq!(
+        vm_eval("9007199254740993i + 2i;"),
+        Some(Value::Int(9007199254740995))
+    );
+    assert_eq!(
+        vm_string("toString(9007199254740993i * 1000i);"),
+        "9007199254740993000"
+    );
+    assert_eq!(
+        interp_eval("9223372036854775806i + 1i;"),
+        Value::Int(i64::MAX)
+    );
+}
+
+#[rstest]
+#[case("9223372036854775807i + 1i;")]
+#[case("-9223372036854775807i - 2i;")]
+#[case("4611686018427387904i * 2i;")]
+#[case("-(-9223372036854775807i - 1i);")]
+fn test_int_overflow_errors(#[case] code: &str) {
+    assert_error_parity(code);
+    assert_error_code(code, "AT0009");
+}
+
+#[test]
+fn test_int_divide_by_zero() {
+    assert_error_parity("1i / 0i;");
+    assert_error_code("1i / 0i;", "AT0005");
+    assert_error_code("1i % 0i;", "AT0005");
+}
+
+#[test]
+fn test_int_conversions() {
+    assert_eq!(vm_eval("toInt(42);"), Some(Value::Int(42)));
+    assert_eq!(vm_eval("toFloat(42i);"), Some(Value::Number(42.0)));
+    assert_error_code("toInt(1.5);", "AT0001");
+}
+
+// ============================================================================
+// VmConfig: operand stack and call depth limits
+// ============================================================================
+
+fn vm_run_with_config(source: &str, config: VmConfig) -> Result<Option<Value>, RuntimeError> {
+    let mut vm = VM::with_config(compile(source), config);
+    vm.run(&SecurityContext::allow_all())
+}
+
+/// `1 + (1 + (1 + ... 1))` nested `depth` times: every level pushes a value
+/// before any addition can run.
+fn nested_sum(depth: usize) -> String {
+    format!("{}1{};", "1 + (".repeat(depth), ")".repeat(depth))
+}
+
+#[test]
+fn test_unbounded_recursion_hits_call_depth_limit() {
+    let source = "fn dive(n: number) -> number { return dive(n + 1) + 1; } dive(0);";
+    let err = vm_run_with_config(source, VmConfig::default()).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            RuntimeError::CallDepthExceeded {
+                limit: DEFAULT_MAX_CALL_DEPTH,
+                ..
+            }
+        ),
+        "expected CallDepthExceeded, got {:?}",
+        err
+    );
+}
+
+#[test]
+fn test_call_depth_limit_is_configurable() {
+    let source = "fn down(n: number) -> number { if (n == 0) { return 0; } return down(n - 1) + 1; } down(40);";
+    let shallow = VmConfig::new().with_max_call_depth(32);
+    let err = vm_run_with_config(source, shallow).unwrap_err();
+    assert!(matches!(
+        err,
+        RuntimeError::CallDepthExceeded { limit: 32, .. }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Call depth limit exceeded: more than 32 nested calls"
+    );
+
+    let roomy = VmConfig::new().with_max_call_depth(64);
+    assert_eq!(
+        vm_run_with_config(source, roomy).unwrap(),
+        Some(Value::Number(40.0))
+    );
+}
+
+#[test]
+fn test_nested_expression_hits_stack_limit() {
+    let source = nested_sum(200);
+    let config = VmConfig::new().with_max_stack_size(100);
+    let err = vm_run_with_config(&source, config).unwrap_err();
+    assert!(
+        matches!(err, RuntimeError::StackOverflow { limit: 100, .. }),
+        "expected StackOverflow, got {:?}",
+        err
+    );
+
+    assert_eq!(
+        vm_run_with_config(&source, VmConfig::default()).unwrap(),
+        Some(Value::Number(201.0))
+    );
+}
+
+#[test]
+fn test_vm_config_accessors() {
+    let mut vm = VM::new(compile("1;"));
+    assert_eq!(*vm.config(), VmConfig::default());
+    vm.set_config(VmConfig::new().with_max_stack_size(8));
+    assert_eq!(vm.config().max_stack_size, 8);
+    assert_eq!(vm.config().max_call_depth, DEFAULT_MAX_CALL_DEPTH);
+}
+
+// ============================================================================
+// Gas-metered execution
+// ============================================================================
+
+fn vm_run_metered(
+    source: &str,
+    limit: u64,
+    schedule: GasSchedule,
+) -> Result<MeteredRun, RuntimeError> {
+    VM::new(compile(source)).run_metered(&SecurityContext::allow_all(), limit, schedule)
+}
+
+#[test]
+fn test_tight_loop_exhausts_small_budget() {
+    let err = vm_run_metered("while (true) { }", 1_000, GasSchedule::new()).unwrap_err();
+    assert!(
+        matches!(err, RuntimeError::GasExhausted { limit: 1_000, .. }),
+        "expected GasExhausted, got {:?}",
+        err
+    );
+    assert_eq!(err.to_string(), "Gas exhausted: budget of 1000 spent");
+    assert!(!err.is_catchable());
+}
+
+#[test]
+fn test_gas_exhaustion_cannot_be_caught() {
+    let source = "try { while (true) { } } catch (e) { 1; }";
+    let err = vm_run_metered(source, 500, GasSchedule::new()).unwrap_err();
+    assert!(matches!(err, RuntimeError::GasExhausted { .. }));
+}
+
+#[test]
+fn test_gas_exhausted_error_code_is_registered() {
+    assert_eq!(
+        atlas_runtime::error_codes::description_for("AT0012"),
+        Some("Gas exhausted")
+    );
+}
+
+#[test]
+fn test_short_program_reports_gas_usage() {
+    let source =
+        "var total = 0; var i = 0; while (i < 10) { total = total + i; i = i + 1; } total;";
+    let run = vm_run_metered(source, 1_000_000, GasSchedule::uniform(1)).unwrap();
+    assert_eq!(run.value, Some(Value::Number(45.0)));
+    assert_eq!(run.gas_used + run.gas_remaining, 1_000_000);
+
+    // At one gas per opcode, gas used is exactly the instruction count
+    let mut profiled = VM::with_profiling(compile(source));
+    profiled.run(&SecurityContext::allow_all()).unwrap();
+    let executed = profiled.profiler().unwrap().total_instructions();
+    assert_eq!(run.gas_used, executed);
+    assert!(
+        (50..500).contains(&run.gas_used),
+        "gas used: {}",
+        run.gas_used
+    );
+}
+
+#[test]
+fn test_gas_usage_is_deterministic_and_follows_schedule() {
+    let source = "fn sq(n: number) -> number { return n * n; } sq(3) + sq(4);";
+    let first = vm_run_metered(source, 10_000, GasSchedule::new()).unwrap();
+    let second = vm_run_metered(source, 10_000, GasSchedule::new()).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first.value, Some(Value::Number(25.0)));
+
+    // Two calls: raising the call cost by 100 adds exactly 200 gas
+    let pricey =
+        GasSchedule::new().with_cost(Opcode::Call, GasSchedule::new().cost(Opcode::Call) + 100);
+    let third = vm_run_metered(source, 10_000, pricey).unwrap();
+    assert_eq!(third.gas_used, first.gas_used + 200);
+}
+
+#[test]
+fn test_exact_budget_succeeds_and_one_less_fails() {
+    let source = "1 + 2;";
+    let used = vm_run_metered(source, u64::MAX, GasSchedule::new())
+        .unwrap()
+        .gas_used;
+    let exact = vm_run_metered(source, used, GasSchedule::new()).unwrap();
+    assert_eq!(exact.gas_remaining, 0);
+    assert!(matches!(
+        vm_run_metered(source, used - 1, GasSchedule::new()),
+        Err(RuntimeError::GasExhausted { .. })
+    ));
+}
+
+// ============================================================================
+// try/catch: structured error values and handler frames
+// ============================================================================
+
+#[rstest]
+#[case(r#"var code = ""; try { let xs = [1, 2]; xs[5]; } catch (e) { code = e["code"]; } code;"#)]
+#[case(r#"var msg = ""; try { 1 / 0; } catch (e) { msg = e["message"]; } msg;"#)]
+#[case("fn safeDiv(a: number, b: number) -> number { try { return a / b; } catch (e) { return -1; } } safeDiv(1, 0) + safeDiv(6, 3);")]
+#[case("fn boom(xs: number[]) -> number { return xs[10]; } var r = 0; try { r = boom([1]); } catch (e) { r = 99; } r;")]
+#[case("fn f() -> number { let a = 1; try { let b = 2; let c = [b][3]; } catch (e) { let d = 5; return a + d; } return 0; } f();")]
+#[case("fn g() -> number { let a = 1; try { let b = 2; } catch (e) { return 0; } let c = 3; return a + c; } g();")]
+#[case("var n = 0; for x in [1, 2, 3] { try { if (x == 2) { break; } n = n + x; } catch (e) { n = -1; } } n;")]
+#[case("var n = 0; for x in [1, 2, 3] { try { if (x == 2) { continue; } n = n + x; } catch (e) { n = -1; } } n;")]
+#[case(r#"var log = ""; try { try { [1][3]; } catch (inner) { log = log + "inner"; } log = log + ",after"; } catch (outer) { log = log + ",outer"; } log;"#)]
+#[case(r#"var log = ""; try { try { [1][3]; } catch (inner) { 1 / 0; } } catch (outer) { log = outer["code"]; } log;"#)]
+#[case("fn down(n: number) -> number { try { if (n == 0) { return [0][1]; } return down(n - 1); } catch (e) { return n; } } down(5);")]
+fn test_parity_try_catch(#[case] code: &str) {
+    assert_parity(code);
+    assert_eq!(vm_eval_opt(code), vm_eval(code));
+}
+
+#[test]
+fn test_catch_index_out_of_bounds() {
+    let source = r#"
+        var caught = "";
+        try {
+            let xs = [1, 2, 3];
+            xs[10];
+        } catch (e) {
+            caught = e["code"] + ": " + e["message"];
+        }
+        caught;
+    "#;
+    assert_eq!(vm_string(source), "AT0006: Array index out of bounds");
+    assert_eq!(
+        interp_eval(source),
+        Value::string("AT0006: Array index out of bounds")
+    );
+}
+
+#[test]
+fn test_catch_division_by_zero() {
+    let source = r#"
+        fn ratio(a: number, b: number) -> string {
+            try {
+                return toString(a / b);
+            } catch (e) {
+                return e["code"];
+            }
+        }
+        ratio(1, 0) + " " + ratio(6, 3);
+    "#;
+    assert_eq!(vm_string(source), "AT0005 2");
+    assert_eq!(interp_eval(source), Value::string("AT0005 2"));
+}
+
+#[test]
+fn test_catch_binding_field_access() {
+    let source = r#"
+        fn describe(err: { code: string, message: string }) -> string {
+            return err.code + ": " + err.message;
+        }
+        var caught = "";
+        try {
+            let xs = [1, 2, 3];
+            xs[10];
+        } catch (e) {
+            caught = describe(e) + " (" + e.code + ")";
+        }
+        caught;
+    "#;
+    let expected = "AT0006: Array index out of bounds (AT0006)";
+    assert_eq!(run_vm(source).unwrap(), format!("String({:?})", expected));
+    assert_eval_string(source, expected);
+}
+
+#[test]
+fn test_uncaught_errors_still_propagate() {
+    assert_error_parity("[1][5];");
+    assert_error_parity("try { [1][5]; } catch (e) { 1 / 0; }");
+    assert_error_code("try { [1][5]; } catch (e) { 1 / 0; }", "AT0005");
+    assert_error_code(
+        "fn f() -> number { try { return 1; } catch (e) { return 0; } } f(); [1][5];",
+        "AT0006",
+    );
+}
+
+#[test]
+fn test_call_depth_limit_is_not_catchable() {
+    let source = "fn down(n: number) -> number { if (n == 0) { return 0; } return down(n - 1) + 1; } var r = 0; try { r = down(100); } catch (e) { r = -1; } r;";
+    let err = vm_run_with_config(source, VmConfig::new().with_max_call_depth(16)).unwrap_err();
+    assert!(matches!(err, RuntimeError::CallDepthExceeded { .. }));
+}
+
+// ============================================================================
+// VM::call_global: calling a defined function after run()
+// ============================================================================
+
+#[test]
+fn test_call_global_repeats_function_call() {
+    let source = "var calls: number = 0; fn bump(by: number) -> number { calls = calls + by; return calls; }";
+    let mut vm = VM::new(compile(source));
+    vm.run(&SecurityContext::allow_all()).unwrap();
+
+    for expected in [2.0, 4.0, 6.0] {
+        let result = vm.call_global("bump", vec![Value::Number(2.0)]).unwrap();
+        assert_eq!(result, Value::Number(expected));
+    }
+    assert_eq!(vm.stack_size(), 0);
+}
+
+#[test]
+fn test_call_global_undefined_function() {
+    let mut vm = VM::new(compile("let x: number = 1;"));
+    vm.run(&SecurityContext::allow_all()).unwrap();
+    let err = vm.call_global("missing", vec![]).unwrap_err();
+    assert!(
+        matches!(err, RuntimeError::UndefinedVariable { ref name, .. } if name == "missing"),
+        "{:?}",
+        err
+    );
+}